@@ -10,6 +10,7 @@ import (
 	"sync"
 	"time"
 
+	"github.com/google/uuid"
 	"github.com/gorilla/websocket"
 	"github.com/orhaniscoding/goconnect/cli/internal/config"
 	"github.com/orhaniscoding/goconnect/cli/internal/logger"
@@ -21,6 +22,7 @@ type Client struct {
 	config     *config.Config
 	keyring    *storage.KeyringStore
 	httpClient *http.Client
+	clock      *clockTransport
 
 	// WebSocket fields
 	wsConn          *websocket.Conn
@@ -38,16 +40,56 @@ type SignalCallbacks struct {
 
 // NewClient creates a new API client
 func NewClient(cfg *config.Config) *Client {
+	clock := &clockTransport{base: http.DefaultTransport}
 	return &Client{
 		config:  cfg,
 		keyring: cfg.Keyring,
 		httpClient: &http.Client{
-			Timeout: 10 * time.Second,
+			Timeout:   10 * time.Second,
+			Transport: clock,
 		},
+		clock:    clock,
 		stopChan: make(chan struct{}),
 	}
 }
 
+// clockTransport records how far the server's clock is from ours, using the
+// Date header of every response
+type clockTransport struct {
+	base   http.RoundTripper
+	mu     sync.RWMutex
+	offset time.Duration
+	seen   bool
+}
+
+func (t *clockTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	resp, err := t.base.RoundTrip(req)
+	if err != nil {
+		return resp, err
+	}
+	if date, err := http.ParseTime(resp.Header.Get("Date")); err == nil {
+		t.mu.Lock()
+		t.offset = time.Until(date)
+		t.seen = true
+		t.mu.Unlock()
+	}
+	return resp, nil
+}
+
+// ServerTime returns the server's current time as estimated from its last
+// response. It reports false until the server has answered at least once.
+func (c *Client) ServerTime() (time.Time, bool) {
+	if c.clock == nil {
+		return time.Time{}, false
+	}
+	c.clock.mu.RLock()
+	defer c.clock.mu.RUnlock()
+	if !c.clock.seen {
+		return time.Time{}, false
+	}
+	return time.Now().Add(c.clock.offset), true
+}
+
 // getAuthToken retrieves the authentication token from the keyring.
 func (c *Client) getAuthToken() (string, error) {
 	if c.keyring == nil {
@@ -821,6 +863,51 @@ func (c *Client) GenerateInvite(ctx context.Context, networkID string, maxUses i
 	return &result, nil
 }
 
+// InviteValidation maps to the server's public invite validation response
+type InviteValidation struct {
+	Valid       bool      `json:"valid"`
+	NetworkID   string    `json:"network_id"`
+	NetworkName string    `json:"network_name"`
+	MemberCount int       `json:"member_count"`
+	OnlineCount int       `json:"online_count"`
+	OwnerID     string    `json:"owner_id"`
+	OwnerName   string    `json:"owner_name"`
+	ExpiresAt   time.Time `json:"expires_at"`
+	UsesMax     int       `json:"uses_max"`
+	UsesLeft    int       `json:"uses_left"`
+}
+
+// ValidateInvite checks an invite token without using it.
+// The endpoint is public, so no auth token is sent.
+func (c *Client) ValidateInvite(ctx context.Context, token string) (*InviteValidation, error) {
+	if token == "" {
+		return nil, fmt.Errorf("token is required")
+	}
+
+	endpoint := fmt.Sprintf("%s/v1/invites/%s/validate", c.config.Server.URL, url.PathEscape(token))
+	httpReq, err := http.NewRequestWithContext(ctx, "GET", endpoint, nil)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create http request: %w", err)
+	}
+
+	resp, err := c.httpClient.Do(httpReq)
+	if err != nil {
+		return nil, fmt.Errorf("failed to send http request: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("validate invite failed with status: %d", resp.StatusCode)
+	}
+
+	var result InviteValidation
+	if err := json.NewDecoder(resp.Body).Decode(&result); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return &result, nil
+}
+
 // KickPeer removes a peer from a network (admin/owner only)
 func (c *Client) KickPeer(ctx context.Context, networkID, peerID, reason string) error {
 	authToken, err := c.getAuthToken()
@@ -905,3 +992,134 @@ func (c *Client) UnbanPeer(ctx context.Context, networkID, peerID string) error
 	}
 	return nil
 }
+
+// TransferOwnership makes another member the network owner (owner only)
+func (c *Client) TransferOwnership(ctx context.Context, networkID, newOwnerID string) error {
+	authToken, err := c.getAuthToken()
+	if err != nil {
+		return fmt.Errorf("transfer ownership failed: %w", err)
+	}
+
+	body, _ := json.Marshal(map[string]string{"new_owner_id": newOwnerID})
+	url := fmt.Sprintf("%s/v1/networks/%s/transfer-ownership", c.config.Server.URL, networkID)
+	httpReq, err := http.NewRequestWithContext(ctx, "POST", url, bytes.NewBuffer(body))
+	if err != nil {
+		return fmt.Errorf("failed to create http request: %w", err)
+	}
+
+	httpReq.Header.Set("Authorization", "Bearer "+authToken)
+	httpReq.Header.Set("Content-Type", "application/json")
+	httpReq.Header.Set("Idempotency-Key", uuid.NewString())
+
+	resp, err := c.httpClient.Do(httpReq)
+	if err != nil {
+		return fmt.Errorf("failed to send http request: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK && resp.StatusCode != http.StatusNoContent {
+		var errorBody struct {
+			Message string `json:"message"`
+		}
+		if err := json.NewDecoder(resp.Body).Decode(&errorBody); err == nil && errorBody.Message != "" {
+			return fmt.Errorf("transfer ownership failed: %s", errorBody.Message)
+		}
+		return fmt.Errorf("transfer ownership failed with status: %d", resp.StatusCode)
+	}
+	return nil
+}
+
+// ReportedMessage is a chat message quoted in a peer report
+type ReportedMessage struct {
+	ID      string    `json:"id"`
+	From    string    `json:"from"`
+	Content string    `json:"content"`
+	SentAt  time.Time `json:"sent_at"`
+}
+
+// ReportPeer reports a member to the network's admins
+func (c *Client) ReportPeer(ctx context.Context, networkID, peerID, reason string, messages []ReportedMessage) error {
+	authToken, err := c.getAuthToken()
+	if err != nil {
+		return fmt.Errorf("report peer failed: %w", err)
+	}
+
+	body, _ := json.Marshal(map[string]interface{}{"peer_id": peerID, "reason": reason, "messages": messages})
+	url := fmt.Sprintf("%s/v1/networks/%s/reports", c.config.Server.URL, networkID)
+	httpReq, err := http.NewRequestWithContext(ctx, "POST", url, bytes.NewBuffer(body))
+	if err != nil {
+		return fmt.Errorf("failed to create http request: %w", err)
+	}
+
+	httpReq.Header.Set("Authorization", "Bearer "+authToken)
+	httpReq.Header.Set("Content-Type", "application/json")
+	httpReq.Header.Set("Idempotency-Key", uuid.NewString())
+
+	resp, err := c.httpClient.Do(httpReq)
+	if err != nil {
+		return fmt.Errorf("failed to send http request: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK && resp.StatusCode != http.StatusNoContent {
+		var errorBody struct {
+			Message string `json:"message"`
+		}
+		if err := json.NewDecoder(resp.Body).Decode(&errorBody); err == nil && errorBody.Message != "" {
+			return fmt.Errorf("report peer failed: %s", errorBody.Message)
+		}
+		return fmt.Errorf("report peer failed with status: %d", resp.StatusCode)
+	}
+	return nil
+}
+
+// JoinRequest is a pending request to join a network
+type JoinRequest struct {
+	ID        string    `json:"id"`
+	NetworkID string    `json:"network_id"`
+	UserID    string    `json:"user_id"`
+	UserName  string    `json:"user_name"`
+	Status    string    `json:"status"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+// ListJoinRequests returns the pending join requests of a network (admin/owner only)
+func (c *Client) ListJoinRequests(ctx context.Context, networkID string) ([]JoinRequest, error) {
+	authToken, err := c.getAuthToken()
+	if err != nil {
+		return nil, fmt.Errorf("list join requests failed: %w", err)
+	}
+
+	url := fmt.Sprintf("%s/v1/networks/%s/join-requests", c.config.Server.URL, networkID)
+	httpReq, err := http.NewRequestWithContext(ctx, "GET", url, nil)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create http request: %w", err)
+	}
+
+	httpReq.Header.Set("Authorization", "Bearer "+authToken)
+
+	resp, err := c.httpClient.Do(httpReq)
+	if err != nil {
+		return nil, fmt.Errorf("failed to send http request: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		var errorBody struct {
+			Message string `json:"message"`
+		}
+		if err := json.NewDecoder(resp.Body).Decode(&errorBody); err == nil && errorBody.Message != "" {
+			return nil, fmt.Errorf("list join requests failed: %s", errorBody.Message)
+		}
+		return nil, fmt.Errorf("list join requests failed with status: %d", resp.StatusCode)
+	}
+
+	var result struct {
+		Data []JoinRequest `json:"data"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&result); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return result.Data, nil
+}
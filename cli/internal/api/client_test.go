@@ -937,6 +937,28 @@ func setupMockClient(t *testing.T, handler http.Handler) (*Client, *httptest.Ser
 	return NewClient(cfg), server
 }
 
+func TestServerTime(t *testing.T) {
+	serverNow := time.Now().Add(-time.Hour).UTC()
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Date", serverNow.Format(http.TimeFormat))
+		w.WriteHeader(http.StatusOK)
+		_ = json.NewEncoder(w).Encode([]NetworkResponse{})
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	_, ok := client.ServerTime()
+	assert.False(t, ok, "no server time before the first response")
+
+	_, err := client.GetNetworks(context.Background())
+	require.NoError(t, err)
+
+	got, ok := client.ServerTime()
+	require.True(t, ok)
+	assert.WithinDuration(t, serverNow, got, 2*time.Second)
+}
+
 func TestGetNetworks_Success(t *testing.T) {
 	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
 		if r.Method != "GET" {
@@ -2116,6 +2138,165 @@ func TestGenerateInvite_ZeroExpiry(t *testing.T) {
 	require.NoError(t, err)
 }
 
+func TestValidateInvite_Success(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "GET", r.Method)
+		assert.Equal(t, "/v1/invites/abc123/validate", r.URL.Path)
+		assert.Empty(t, r.Header.Get("Authorization"))
+
+		_ = json.NewEncoder(w).Encode(map[string]interface{}{
+			"valid":        true,
+			"network_id":   "net-1",
+			"network_name": "Home",
+			"member_count": 4,
+			"online_count": 2,
+			"owner_id":     "user-1",
+			"owner_name":   "Ada",
+			"uses_max":     5,
+			"uses_left":    2,
+		})
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	result, err := client.ValidateInvite(context.Background(), "abc123")
+	require.NoError(t, err)
+	assert.True(t, result.Valid)
+	assert.Equal(t, "net-1", result.NetworkID)
+	assert.Equal(t, "Home", result.NetworkName)
+	assert.Equal(t, 4, result.MemberCount)
+	assert.Equal(t, 2, result.OnlineCount)
+	assert.Equal(t, "Ada", result.OwnerName)
+	assert.Equal(t, 5, result.UsesMax)
+	assert.Equal(t, 2, result.UsesLeft)
+}
+
+func TestValidateInvite_ServerError(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusInternalServerError)
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	_, err := client.ValidateInvite(context.Background(), "abc123")
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "status: 500")
+}
+
+func TestTransferOwnership_Success(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "POST", r.Method)
+		assert.Equal(t, "/v1/networks/net-1/transfer-ownership", r.URL.Path)
+		assert.NotEmpty(t, r.Header.Get("Idempotency-Key"))
+
+		var body map[string]string
+		_ = json.NewDecoder(r.Body).Decode(&body)
+		assert.Equal(t, "user-2", body["new_owner_id"])
+
+		w.WriteHeader(http.StatusOK)
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	require.NoError(t, client.TransferOwnership(context.Background(), "net-1", "user-2"))
+}
+
+func TestTransferOwnership_ServerError(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusForbidden)
+		_ = json.NewEncoder(w).Encode(map[string]string{"message": "Only the network owner can transfer ownership"})
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	err := client.TransferOwnership(context.Background(), "net-1", "user-2")
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "Only the network owner")
+}
+
+func TestReportPeer_Success(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "POST", r.Method)
+		assert.Equal(t, "/v1/networks/net-1/reports", r.URL.Path)
+		assert.NotEmpty(t, r.Header.Get("Idempotency-Key"))
+
+		var body struct {
+			PeerID   string            `json:"peer_id"`
+			Reason   string            `json:"reason"`
+			Messages []ReportedMessage `json:"messages"`
+		}
+		_ = json.NewDecoder(r.Body).Decode(&body)
+		assert.Equal(t, "user-2", body.PeerID)
+		assert.Equal(t, "spam", body.Reason)
+		require.Len(t, body.Messages, 1)
+		assert.Equal(t, "msg-1", body.Messages[0].ID)
+
+		w.WriteHeader(http.StatusOK)
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	messages := []ReportedMessage{{ID: "msg-1", From: "user-2", Content: "buy now"}}
+	require.NoError(t, client.ReportPeer(context.Background(), "net-1", "user-2", "spam", messages))
+}
+
+func TestReportPeer_ServerError(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusBadRequest)
+		_ = json.NewEncoder(w).Encode(map[string]string{"message": "You cannot report yourself"})
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	err := client.ReportPeer(context.Background(), "net-1", "user-1", "", nil)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "cannot report yourself")
+}
+
+func TestListJoinRequests_Success(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "GET", r.Method)
+		assert.Equal(t, "/v1/networks/net-1/join-requests", r.URL.Path)
+
+		w.WriteHeader(http.StatusOK)
+		_ = json.NewEncoder(w).Encode(map[string]interface{}{
+			"data": []map[string]interface{}{
+				{"id": "jr-1", "network_id": "net-1", "user_id": "user-2", "user_name": "Grace", "status": "pending", "created_at": "2024-01-01T00:00:00Z"},
+			},
+		})
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	requests, err := client.ListJoinRequests(context.Background(), "net-1")
+	require.NoError(t, err)
+	require.Len(t, requests, 1)
+	assert.Equal(t, "user-2", requests[0].UserID)
+	assert.Equal(t, "Grace", requests[0].UserName)
+	assert.Equal(t, 2024, requests[0].CreatedAt.Year())
+}
+
+func TestListJoinRequests_Forbidden(t *testing.T) {
+	handler := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusForbidden)
+		_ = json.NewEncoder(w).Encode(map[string]string{"message": "Admin or owner role required"})
+	})
+
+	client, server := setupMockClient(t, handler)
+	defer server.Close()
+
+	_, err := client.ListJoinRequests(context.Background(), "net-1")
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "Admin or owner role required")
+}
+
 // ==================== GetNetworks Error Tests ====================
 
 func TestGetNetworks_ServerError(t *testing.T) {
@@ -240,6 +240,29 @@ func (m *Manager) GetMessages(networkID string, limit int, beforeID string) []Me
 	return result
 }
 
+// GetMessage looks a message up by ID, however far back in the history it is
+func (m *Manager) GetMessage(id string) (Message, bool) {
+	if m.storage != nil {
+		msg, ok, err := m.storage.GetMessage(id)
+		if err != nil {
+			logger.Warn("Failed to get message from storage", "error", err)
+			// Fall through to memory cache
+		} else {
+			return msg, ok
+		}
+	}
+
+	m.messagesMu.RLock()
+	defer m.messagesMu.RUnlock()
+
+	for i := len(m.messages) - 1; i >= 0; i-- {
+		if m.messages[i].ID == id {
+			return m.messages[i], true
+		}
+	}
+	return Message{}, false
+}
+
 // SearchMessages searches for messages containing the query string
 func (m *Manager) SearchMessages(query string, limit int) []Message {
 	if m.storage != nil {
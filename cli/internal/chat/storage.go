@@ -179,6 +179,29 @@ func (s *Storage) GetMessages(networkID string, limit int, beforeID string) ([]M
 	return messages, nil
 }
 
+// GetMessage retrieves a single message by ID; the boolean is false when it does not exist
+func (s *Storage) GetMessage(id string) (Message, bool, error) {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+
+	var msg Message
+	var timestamp int64
+	err := s.db.QueryRow(`
+		SELECT id, from_peer, content, timestamp, network_id 
+		FROM messages 
+		WHERE id = ?
+	`, id).Scan(&msg.ID, &msg.From, &msg.Content, &timestamp, &msg.NetworkID)
+	if err == sql.ErrNoRows {
+		return Message{}, false, nil
+	}
+	if err != nil {
+		return Message{}, false, fmt.Errorf("failed to get message: %w", err)
+	}
+	msg.Time = time.Unix(timestamp, 0)
+
+	return msg, true, nil
+}
+
 // GetMessagesByPeer retrieves messages from a specific peer
 func (s *Storage) GetMessagesByPeer(peerID string, limit int) ([]Message, error) {
 	s.mu.RLock()
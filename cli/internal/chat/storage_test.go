@@ -1,6 +1,7 @@
 package chat
 
 import (
+	"fmt"
 	"os"
 	"path/filepath"
 	"testing"
@@ -176,6 +177,33 @@ func TestStorage_GetMessagesByPeer(t *testing.T) {
 	}
 }
 
+func TestStorage_GetMessage(t *testing.T) {
+	tmpDir := t.TempDir()
+	storage, err := NewStorage(tmpDir)
+	if err != nil {
+		t.Fatalf("NewStorage failed: %v", err)
+	}
+	defer storage.Close()
+
+	// More than one page of GetMessages, so the first message is beyond its default limit
+	base := time.Now().Add(-time.Hour)
+	for i := 0; i < 150; i++ {
+		_ = storage.SaveMessage(Message{ID: fmt.Sprintf("msg-%d", i), From: "peer", Content: "Hi", NetworkID: "net-1", Time: base.Add(time.Duration(i) * time.Second)})
+	}
+
+	msg, ok, err := storage.GetMessage("msg-0")
+	if err != nil {
+		t.Fatalf("GetMessage failed: %v", err)
+	}
+	if !ok || msg.ID != "msg-0" || msg.NetworkID != "net-1" {
+		t.Errorf("Expected msg-0 in net-1, got %+v (found=%v)", msg, ok)
+	}
+
+	if _, ok, err := storage.GetMessage("missing"); err != nil || ok {
+		t.Errorf("Expected missing message to be not found, got found=%v err=%v", ok, err)
+	}
+}
+
 func TestStorage_DeleteMessage(t *testing.T) {
 	tmpDir := t.TempDir()
 	storage, err := NewStorage(tmpDir)
@@ -40,6 +40,8 @@ type Config struct {
 		NotificationsEnabled bool   `yaml:"notifications_enabled"`
 		DownloadPath         string `yaml:"download_path"`
 		LogLevel             string `yaml:"log_level"`
+		MaxUploadSpeedKbps   int    `yaml:"max_upload_speed_kbps"`
+		MaxDownloadSpeedKbps int    `yaml:"max_download_speed_kbps"`
 	} `yaml:"settings"`
 
 	// Runtime fields
@@ -2,6 +2,8 @@ package daemon
 
 import (
 	"context"
+	"encoding/base64"
+	"encoding/json"
 	"fmt"
 	"net"
 	"os"
@@ -10,11 +12,16 @@ import (
 	"sync"
 	"time"
 
+	"github.com/google/uuid"
 	"github.com/kardianos/service"
+	"github.com/orhaniscoding/goconnect/cli/internal/api"
+	"github.com/orhaniscoding/goconnect/cli/internal/chat"
 	pb "github.com/orhaniscoding/goconnect/cli/internal/proto"
+	"github.com/orhaniscoding/goconnect/cli/internal/transfer"
 	"github.com/orhaniscoding/goconnect/cli/internal/voice"
 	"google.golang.org/grpc"
 	"google.golang.org/grpc/codes"
+	grpcpeer "google.golang.org/grpc/peer"
 	"google.golang.org/grpc/status"
 	"google.golang.org/protobuf/types/known/emptypb"
 	"google.golang.org/protobuf/types/known/timestamppb"
@@ -40,6 +47,14 @@ type GRPCServer struct {
 	subscribers   map[chan *pb.DaemonEvent]struct{}
 	subscribersMu sync.RWMutex
 
+	// Settings subscribers
+	settingsSubscribers   map[chan *pb.Settings]struct{}
+	settingsSubscribersMu sync.Mutex
+
+	// When a rejected IPC call was last reported
+	lastAuthFailure   time.Time
+	lastAuthFailureMu sync.Mutex
+
 	// Version info
 	version   string
 	buildDate string
@@ -55,15 +70,19 @@ func NewGRPCServer(daemon *DaemonService, version, buildDate, commit string) *GR
 		ipcAuth = NewIPCAuth()
 	}
 
-	return &GRPCServer{
-		daemon:      daemon,
-		logf:        daemon.logf,
-		subscribers: make(map[chan *pb.DaemonEvent]struct{}),
-		version:     version,
-		buildDate:   buildDate,
-		commit:      commit,
-		ipcAuth:     ipcAuth,
+	s := &GRPCServer{
+		daemon:              daemon,
+		logf:                daemon.logf,
+		subscribers:         make(map[chan *pb.DaemonEvent]struct{}),
+		settingsSubscribers: make(map[chan *pb.Settings]struct{}),
+		version:             version,
+		buildDate:           buildDate,
+		commit:              commit,
+		ipcAuth:             ipcAuth,
 	}
+	ipcAuth.SetOnFailure(s.reportIPCAuthFailure)
+
+	return s
 }
 
 // Start starts the gRPC server.
@@ -111,6 +130,8 @@ func (s *GRPCServer) Start(ctx context.Context) error {
 		}
 	}()
 
+	go s.rotateIPCToken(ctx)
+
 	// Handle context cancellation
 	go func() {
 		<-ctx.Done()
@@ -120,6 +141,25 @@ func (s *GRPCServer) Start(ctx context.Context) error {
 	return nil
 }
 
+// rotateIPCToken issues a new IPC token every TokenRotationInterval until ctx is done.
+func (s *GRPCServer) rotateIPCToken(ctx context.Context) {
+	ticker := time.NewTicker(TokenRotationInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ticker.C:
+			if err := s.ipcAuth.GenerateAndSave(); err != nil {
+				s.logf.Errorf("Failed to rotate IPC auth token: %v", err)
+				continue
+			}
+			s.logf.Info("IPC auth token rotated")
+		case <-ctx.Done():
+			return
+		}
+	}
+}
+
 // Stop gracefully stops the gRPC server.
 func (s *GRPCServer) Stop() {
 	if s.grpcServer != nil {
@@ -296,14 +336,20 @@ func (s *GRPCServer) GetStatus(ctx context.Context, req *pb.GetStatusRequest) (*
 
 // GetVersion returns daemon version information.
 func (s *GRPCServer) GetVersion(ctx context.Context, req *emptypb.Empty) (*pb.VersionResponse, error) {
-	return &pb.VersionResponse{
-		Version:   s.version,
-		BuildDate: s.buildDate,
-		Commit:    s.commit,
-		GoVersion: runtime.Version(),
-		Os:        runtime.GOOS,
-		Arch:      runtime.GOARCH,
-	}, nil
+	resp := &pb.VersionResponse{
+		Version:    s.version,
+		BuildDate:  s.buildDate,
+		Commit:     s.commit,
+		GoVersion:  runtime.Version(),
+		Os:         runtime.GOOS,
+		Arch:       runtime.GOARCH,
+		DaemonTime: timestamppb.Now(),
+	}
+	if serverTime, ok := s.daemon.engine.ServerTime(); ok {
+		resp.ServerTime = timestamppb.New(serverTime)
+	}
+
+	return resp, nil
 }
 
 // Shutdown gracefully stops the daemon.
@@ -321,6 +367,31 @@ func (s *GRPCServer) Shutdown(ctx context.Context, req *emptypb.Empty) (*emptypb
 	return &emptypb.Empty{}, nil
 }
 
+// GetCredentials lists the credentials the daemon holds and when they expire.
+func (s *GRPCServer) GetCredentials(ctx context.Context, req *emptypb.Empty) (*pb.GetCredentialsResponse, error) {
+	resp := &pb.GetCredentialsResponse{}
+
+	if s.ipcAuth != nil && s.ipcAuth.GetToken() != "" {
+		resp.Credentials = append(resp.Credentials, &pb.Credential{
+			Kind:      "ipc_token",
+			Name:      s.ipcAuth.GetTokenPath(),
+			IssuedAt:  timestamppb.New(s.ipcAuth.IssuedAt()),
+			ExpiresAt: timestamppb.New(s.ipcAuth.ExpiresAt()),
+		})
+	}
+
+	if s.daemon.config != nil && s.daemon.config.Keyring != nil {
+		if token, err := s.daemon.config.Keyring.RetrieveAuthToken(); err == nil && token != "" {
+			resp.Credentials = append(resp.Credentials, tokenCredential("account_token", token))
+		}
+		if token, err := s.daemon.config.Keyring.RetrieveRefreshToken(); err == nil && token != "" {
+			resp.Credentials = append(resp.Credentials, tokenCredential("refresh_token", token))
+		}
+	}
+
+	return resp, nil
+}
+
 // Subscribe streams daemon events to the client.
 func (s *GRPCServer) Subscribe(req *pb.SubscribeRequest, stream pb.DaemonService_SubscribeServer) error {
 	eventChan := make(chan *pb.DaemonEvent, 100)
@@ -382,6 +453,128 @@ func (s *GRPCServer) BroadcastEvent(event *pb.DaemonEvent) {
 	}
 }
 
+// authFailureReportInterval keeps a client retrying with a stale token from
+// flooding the security feed.
+const authFailureReportInterval = time.Minute
+
+// reportIPCAuthFailure raises a security event for a call the IPC auth rejected.
+func (s *GRPCServer) reportIPCAuthFailure(ctx context.Context, method string) {
+	s.lastAuthFailureMu.Lock()
+	if time.Since(s.lastAuthFailure) < authFailureReportInterval {
+		s.lastAuthFailureMu.Unlock()
+		return
+	}
+	s.lastAuthFailure = time.Now()
+	s.lastAuthFailureMu.Unlock()
+
+	var source string
+	if p, ok := grpcpeer.FromContext(ctx); ok && p.Addr != nil {
+		source = p.Addr.String()
+	}
+	s.logf.Warningf("Rejected %s: missing or invalid IPC token", method)
+	s.BroadcastEvent(securityEvent(&pb.SecurityEvent{
+		Kind:    pb.SecurityEventKind_SECURITY_EVENT_KIND_IPC_AUTH_FAILED,
+		Message: fmt.Sprintf("A local program called %s without a valid IPC token", method),
+		Source:  source,
+	}))
+}
+
+// securityEvent wraps a security event for subscribers, giving it an ID.
+func securityEvent(event *pb.SecurityEvent) *pb.DaemonEvent {
+	event.Id = uuid.NewString()
+	return &pb.DaemonEvent{
+		Type:    pb.EventType_EVENT_TYPE_SECURITY,
+		Payload: &pb.DaemonEvent_SecurityEvent{SecurityEvent: event},
+	}
+}
+
+// peerKeyChangedEvent builds the event for a known peer presenting a new public key.
+func peerKeyChangedEvent(peer api.PeerConfig) *pb.DaemonEvent {
+	name := peer.Name
+	if name == "" {
+		name = peer.ID
+	}
+	return securityEvent(&pb.SecurityEvent{
+		Kind:    pb.SecurityEventKind_SECURITY_EVENT_KIND_PEER_KEY_CHANGED,
+		Message: fmt.Sprintf("%s now presents a different public key", name),
+		PeerId:  peer.ID,
+	})
+}
+
+// peerEvent builds the event for a peer joining or leaving the network.
+func peerEvent(peer api.PeerConfig, joined bool) *pb.DaemonEvent {
+	eventType := pb.EventType_EVENT_TYPE_PEER_LEFT
+	if joined {
+		eventType = pb.EventType_EVENT_TYPE_PEER_JOINED
+	}
+	return &pb.DaemonEvent{
+		Type: eventType,
+		Payload: &pb.DaemonEvent_PeerEvent{PeerEvent: &pb.PeerEvent{
+			Peer: &pb.Peer{
+				Id:          peer.ID,
+				Name:        peer.Name,
+				DisplayName: peer.Hostname,
+				VirtualIp:   getFirstIP(peer.AllowedIPs),
+			},
+			Joined: joined,
+		}},
+	}
+}
+
+// chatMessageEvent builds the event for a received chat message.
+func chatMessageEvent(msg chat.Message) *pb.DaemonEvent {
+	return &pb.DaemonEvent{
+		Type: pb.EventType_EVENT_TYPE_CHAT_MESSAGE,
+		Payload: &pb.DaemonEvent_ChatMessage{ChatMessage: &pb.ChatMessage{
+			Id:        msg.ID,
+			NetworkId: msg.NetworkID,
+			SenderId:  msg.From,
+			Content:   msg.Content,
+			SentAt:    timestamppb.New(msg.Time),
+		}},
+	}
+}
+
+// transferRequestEvent builds the event for a file offered by a peer. The
+// transfer ID is the one AcceptTransfer and RejectTransfer expect.
+func transferRequestEvent(req transfer.Request, senderID string) *pb.DaemonEvent {
+	return &pb.DaemonEvent{
+		Type: pb.EventType_EVENT_TYPE_TRANSFER_REQUEST,
+		Payload: &pb.DaemonEvent_TransferEvent{TransferEvent: &pb.TransferEvent{
+			Transfer: &pb.FileTransfer{
+				Id:         req.ID,
+				PeerId:     senderID,
+				Filename:   req.FileName,
+				SizeBytes:  req.FileSize,
+				Status:     pb.TransferStatus_TRANSFER_STATUS_PENDING,
+				IsIncoming: true,
+				Sha256:     req.SHA256,
+			},
+		}},
+	}
+}
+
+// transferProgressEvent builds the event for a transfer making progress.
+func transferProgressEvent(session transfer.Session) *pb.DaemonEvent {
+	return &pb.DaemonEvent{
+		Type: pb.EventType_EVENT_TYPE_TRANSFER_PROGRESS,
+		Payload: &pb.DaemonEvent_TransferEvent{TransferEvent: &pb.TransferEvent{
+			Transfer: &pb.FileTransfer{
+				Id:               session.ID,
+				PeerId:           session.PeerID,
+				Filename:         session.FileName,
+				SizeBytes:        session.FileSize,
+				TransferredBytes: session.SentBytes,
+				Status:           mapTransferStatus(string(session.Status)),
+				IsIncoming:       !session.IsSender,
+				ErrorMessage:     session.Error,
+				Sha256:           session.SHA256,
+				MaxSpeedKbps:     int32(session.MaxSpeedKbps),
+			},
+		}},
+	}
+}
+
 // =============================================================================
 // NETWORK SERVICE IMPLEMENTATION
 // =============================================================================
@@ -522,6 +715,96 @@ func (s *GRPCServer) GenerateInvite(ctx context.Context, req *pb.GenerateInviteR
 	return resp, nil
 }
 
+// PreviewInvite describes the network behind an invite code without joining it.
+func (s *GRPCServer) PreviewInvite(ctx context.Context, req *pb.PreviewInviteRequest) (*pb.InvitePreview, error) {
+	if req.InviteCode == "" {
+		return nil, status.Error(codes.InvalidArgument, "invite code is required")
+	}
+
+	invite, err := s.daemon.engine.PreviewInvite(req.InviteCode)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to preview invite: %v", err)
+	}
+	if !invite.Valid {
+		// The server does not say why a code is unusable
+		return &pb.InvitePreview{InvalidReason: "unknown"}, nil
+	}
+
+	resp := &pb.InvitePreview{
+		Valid:         true,
+		NetworkId:     invite.NetworkID,
+		NetworkName:   invite.NetworkName,
+		MemberCount:   int32(invite.MemberCount),
+		OnlineCount:   int32(invite.OnlineCount),
+		OwnerId:       invite.OwnerID,
+		OwnerName:     invite.OwnerName,
+		UsesRemaining: -1,
+	}
+	if invite.UsesMax > 0 {
+		resp.UsesRemaining = int32(invite.UsesLeft)
+	}
+	if !invite.ExpiresAt.IsZero() {
+		resp.ExpiresAt = timestamppb.New(invite.ExpiresAt)
+	}
+
+	return resp, nil
+}
+
+// TransferOwnership makes another member the owner (owner only).
+func (s *GRPCServer) TransferOwnership(ctx context.Context, req *pb.TransferOwnershipRequest) (*emptypb.Empty, error) {
+	if req.NetworkId == "" {
+		return nil, status.Error(codes.InvalidArgument, "network_id is required")
+	}
+	if req.NewOwnerId == "" {
+		return nil, status.Error(codes.InvalidArgument, "new_owner_id is required")
+	}
+
+	if err := s.daemon.engine.TransferOwnership(req.NetworkId, req.NewOwnerId); err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to transfer ownership: %v", err)
+	}
+
+	return &emptypb.Empty{}, nil
+}
+
+// ConnectNetwork brings up the tunnel. All joined networks share it, so they connect together.
+func (s *GRPCServer) ConnectNetwork(ctx context.Context, req *emptypb.Empty) (*emptypb.Empty, error) {
+	s.daemon.engine.Connect()
+
+	return &emptypb.Empty{}, nil
+}
+
+// DisconnectNetwork tears down the active tunnel without leaving the network.
+func (s *GRPCServer) DisconnectNetwork(ctx context.Context, req *emptypb.Empty) (*emptypb.Empty, error) {
+	s.daemon.engine.Disconnect()
+
+	return &emptypb.Empty{}, nil
+}
+
+// ListJoinRequests returns requests waiting for approval to join a network (admin only).
+func (s *GRPCServer) ListJoinRequests(ctx context.Context, req *pb.ListJoinRequestsRequest) (*pb.ListJoinRequestsResponse, error) {
+	if req.NetworkId == "" {
+		return nil, status.Error(codes.InvalidArgument, "network_id is required")
+	}
+
+	requests, err := s.daemon.engine.ListJoinRequests(req.NetworkId)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to list join requests: %v", err)
+	}
+
+	resp := &pb.ListJoinRequestsResponse{
+		Requests: make([]*pb.JoinRequest, 0, len(requests)),
+	}
+	for _, r := range requests {
+		resp.Requests = append(resp.Requests, &pb.JoinRequest{
+			PeerId:      r.UserID,
+			Name:        r.UserName,
+			RequestedAt: timestamppb.New(r.CreatedAt),
+		})
+	}
+
+	return resp, nil
+}
+
 // =============================================================================
 // PEER SERVICE IMPLEMENTATION
 // =============================================================================
@@ -626,6 +909,22 @@ func (s *GRPCServer) UnbanPeer(ctx context.Context, req *pb.UnbanPeerRequest) (*
 	return &emptypb.Empty{}, nil
 }
 
+// ReportPeer forwards a report with the offending messages to the network's admins.
+func (s *GRPCServer) ReportPeer(ctx context.Context, req *pb.ReportPeerRequest) (*emptypb.Empty, error) {
+	if req.PeerId == "" {
+		return nil, status.Error(codes.InvalidArgument, "peer_id is required")
+	}
+	if req.NetworkId == "" {
+		return nil, status.Error(codes.InvalidArgument, "network_id is required")
+	}
+
+	if err := s.daemon.engine.ReportPeer(req.NetworkId, req.PeerId, req.Reason, req.MessageIds); err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to report peer: %v", err)
+	}
+
+	return &emptypb.Empty{}, nil
+}
+
 // =============================================================================
 // CHAT SERVICE IMPLEMENTATION
 // =============================================================================
@@ -767,6 +1066,32 @@ func (s *GRPCServer) CancelTransfer(ctx context.Context, req *pb.CancelTransferR
 	return &emptypb.Empty{}, nil
 }
 
+// SetTransferRateLimit caps one transfer's speed, overriding the settings.
+func (s *GRPCServer) SetTransferRateLimit(ctx context.Context, req *pb.SetTransferRateLimitRequest) (*emptypb.Empty, error) {
+	if req.TransferId == "" {
+		return nil, status.Error(codes.InvalidArgument, "transfer_id is required")
+	}
+	if req.MaxSpeedKbps < 0 {
+		return nil, status.Error(codes.InvalidArgument, "max_speed_kbps cannot be negative")
+	}
+
+	if err := s.daemon.engine.SetTransferRateLimit(req.TransferId, int(req.MaxSpeedKbps)); err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to set transfer rate limit: %v", err)
+	}
+
+	return &emptypb.Empty{}, nil
+}
+
+// ListDirectory lists a directory on the daemon's host, for picking files to send.
+func (s *GRPCServer) ListDirectory(ctx context.Context, req *pb.ListDirectoryRequest) (*pb.ListDirectoryResponse, error) {
+	return s.browsePolicy().listDirectory(req.Path, req.IncludeHidden)
+}
+
+// StatPath describes one path on the daemon's host.
+func (s *GRPCServer) StatPath(ctx context.Context, req *pb.StatPathRequest) (*pb.RemoteFileEntry, error) {
+	return s.browsePolicy().statPath(req.Path)
+}
+
 // ListTransfers returns all active/recent transfers.
 func (s *GRPCServer) ListTransfers(ctx context.Context, req *emptypb.Empty) (*pb.ListTransfersResponse, error) {
 	transfers := s.daemon.engine.GetTransfers()
@@ -781,6 +1106,8 @@ func (s *GRPCServer) ListTransfers(ctx context.Context, req *emptypb.Empty) (*pb
 			TransferredBytes: t.SentBytes,
 			Status:           mapTransferStatus(string(t.Status)),
 			IsIncoming:       !t.IsSender,
+			Sha256:           t.SHA256,
+			MaxSpeedKbps:     int32(t.MaxSpeedKbps),
 		}
 	}
 
@@ -809,6 +1136,8 @@ func (s *GRPCServer) SubscribeTransfers(req *emptypb.Empty, stream pb.TransferSe
 					TransferredBytes: session.SentBytes,
 					Status:           mapTransferStatus(string(session.Status)),
 					IsIncoming:       !session.IsSender,
+					Sha256:           session.SHA256,
+					MaxSpeedKbps:     int32(session.MaxSpeedKbps),
 				},
 			}
 
@@ -833,6 +1162,8 @@ func (s *GRPCServer) GetSettings(ctx context.Context, req *emptypb.Empty) (*pb.S
 		AutoConnect:          cfg.Settings.AutoConnect,
 		NotificationsEnabled: cfg.Settings.NotificationsEnabled,
 		DownloadPath:         cfg.Settings.DownloadPath,
+		MaxUploadSpeedKbps:   int32(cfg.Settings.MaxUploadSpeedKbps),
+		MaxDownloadSpeedKbps: int32(cfg.Settings.MaxDownloadSpeedKbps),
 	}, nil
 }
 
@@ -841,6 +1172,9 @@ func (s *GRPCServer) UpdateSettings(ctx context.Context, req *pb.UpdateSettingsR
 	if req.Settings == nil {
 		return nil, status.Error(codes.InvalidArgument, "settings are required")
 	}
+	if req.Settings.MaxUploadSpeedKbps < 0 || req.Settings.MaxDownloadSpeedKbps < 0 {
+		return nil, status.Error(codes.InvalidArgument, "speed limits cannot be negative")
+	}
 
 	cfg := s.daemon.config
 	cfg.Settings.AutoConnect = req.Settings.AutoConnect
@@ -848,6 +1182,9 @@ func (s *GRPCServer) UpdateSettings(ctx context.Context, req *pb.UpdateSettingsR
 	if req.Settings.DownloadPath != "" {
 		cfg.Settings.DownloadPath = req.Settings.DownloadPath
 	}
+	cfg.Settings.MaxUploadSpeedKbps = int(req.Settings.MaxUploadSpeedKbps)
+	cfg.Settings.MaxDownloadSpeedKbps = int(req.Settings.MaxDownloadSpeedKbps)
+	s.daemon.engine.SetTransferRateLimits(cfg.Settings.MaxUploadSpeedKbps, cfg.Settings.MaxDownloadSpeedKbps)
 
 	// Save config to disk
 	if cfg.ConfigPath != "" {
@@ -856,7 +1193,9 @@ func (s *GRPCServer) UpdateSettings(ctx context.Context, req *pb.UpdateSettingsR
 		}
 	}
 
-	return s.GetSettings(ctx, &emptypb.Empty{})
+	settings, _ := s.GetSettings(ctx, &emptypb.Empty{})
+	s.broadcastSettings(settings)
+	return settings, nil
 }
 
 // ResetSettings resets settings to defaults.
@@ -864,6 +1203,9 @@ func (s *GRPCServer) ResetSettings(ctx context.Context, req *emptypb.Empty) (*pb
 	cfg := s.daemon.config
 	cfg.Settings.AutoConnect = false
 	cfg.Settings.NotificationsEnabled = true
+	cfg.Settings.MaxUploadSpeedKbps = 0
+	cfg.Settings.MaxDownloadSpeedKbps = 0
+	s.daemon.engine.SetTransferRateLimits(0, 0)
 	// Keep DownloadPath as it's system-specific
 
 	// Save config
@@ -873,7 +1215,62 @@ func (s *GRPCServer) ResetSettings(ctx context.Context, req *emptypb.Empty) (*pb
 		}
 	}
 
-	return s.GetSettings(ctx, &emptypb.Empty{})
+	settings, _ := s.GetSettings(ctx, &emptypb.Empty{})
+	s.broadcastSettings(settings)
+	return settings, nil
+}
+
+// SubscribeSettings streams the current settings, then every change to them.
+func (s *GRPCServer) SubscribeSettings(req *emptypb.Empty, stream pb.SettingsService_SubscribeSettingsServer) error {
+	settingsChan := make(chan *pb.Settings, 1)
+
+	// Register before reading the current settings so no change is missed
+	s.settingsSubscribersMu.Lock()
+	s.settingsSubscribers[settingsChan] = struct{}{}
+	s.settingsSubscribersMu.Unlock()
+
+	defer func() {
+		s.settingsSubscribersMu.Lock()
+		delete(s.settingsSubscribers, settingsChan)
+		s.settingsSubscribersMu.Unlock()
+		close(settingsChan)
+	}()
+
+	current, _ := s.GetSettings(stream.Context(), &emptypb.Empty{})
+	if err := stream.Send(current); err != nil {
+		return err
+	}
+
+	for {
+		select {
+		case settings := <-settingsChan:
+			if err := stream.Send(settings); err != nil {
+				return err
+			}
+
+		case <-stream.Context().Done():
+			return stream.Context().Err()
+		}
+	}
+}
+
+// broadcastSettings sends changed settings to all settings subscribers.
+func (s *GRPCServer) broadcastSettings(settings *pb.Settings) {
+	s.settingsSubscribersMu.Lock()
+	defer s.settingsSubscribersMu.Unlock()
+
+	for ch := range s.settingsSubscribers {
+		select {
+		case ch <- settings:
+		default:
+			// Subscriber hasn't read the previous change yet; only the latest matters
+			select {
+			case <-ch:
+			default:
+			}
+			ch <- settings
+		}
+	}
 }
 
 // =============================================================================
@@ -966,6 +1363,42 @@ func getFirstIP(ips []string) string {
 	return ip
 }
 
+// tokenCredential describes a server-issued JWT. The claims are read without
+// checking the signature since they are only used for expiry warnings.
+func tokenCredential(kind, token string) *pb.Credential {
+	cred := &pb.Credential{Kind: kind}
+
+	parts := strings.Split(token, ".")
+	if len(parts) != 3 {
+		return cred
+	}
+	payload, err := base64.RawURLEncoding.DecodeString(parts[1])
+	if err != nil {
+		return cred
+	}
+	var claims struct {
+		Email     string `json:"email"`
+		Subject   string `json:"sub"`
+		IssuedAt  int64  `json:"iat"`
+		ExpiresAt int64  `json:"exp"`
+	}
+	if err := json.Unmarshal(payload, &claims); err != nil {
+		return cred
+	}
+
+	cred.Name = claims.Email
+	if cred.Name == "" {
+		cred.Name = claims.Subject
+	}
+	if claims.IssuedAt > 0 {
+		cred.IssuedAt = timestamppb.New(time.Unix(claims.IssuedAt, 0))
+	}
+	if claims.ExpiresAt > 0 {
+		cred.ExpiresAt = timestamppb.New(time.Unix(claims.ExpiresAt, 0))
+	}
+	return cred
+}
+
 func mapTransferStatus(status string) pb.TransferStatus {
 	switch status {
 	case "pending":
@@ -2,17 +2,26 @@ package daemon
 
 import (
 	"context"
+	"encoding/base64"
+	"encoding/json"
 	"fmt"
+	"net/http"
 	"path/filepath"
+	"strings"
+	"sync"
 	"testing"
+	"time"
 
 	"github.com/orhaniscoding/goconnect/cli/internal/api"
 	"github.com/orhaniscoding/goconnect/cli/internal/chat"
 	"github.com/orhaniscoding/goconnect/cli/internal/config"
 	"github.com/orhaniscoding/goconnect/cli/internal/identity"
 	pb "github.com/orhaniscoding/goconnect/cli/internal/proto"
+	"github.com/orhaniscoding/goconnect/cli/internal/storage"
 	"github.com/orhaniscoding/goconnect/cli/internal/transfer"
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"google.golang.org/grpc"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
 	"google.golang.org/protobuf/types/known/emptypb"
@@ -92,16 +101,70 @@ func TestGRPCServer_GetStatus_Real(t *testing.T) {
 }
 
 func TestGRPCServer_GetVersion_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+
+	t.Run("before the server has answered", func(t *testing.T) {
+		mockEng.On("ServerTime").Return(time.Time{}, false).Once()
+
+		resp, err := srv.GetVersion(context.Background(), &emptypb.Empty{})
+		assert.NoError(t, err)
+		assert.Equal(t, "1.0.0", resp.Version)
+		assert.Equal(t, "2024-01-01", resp.BuildDate)
+		assert.Equal(t, "abc123", resp.Commit)
+		assert.NotEmpty(t, resp.GoVersion)
+		assert.NotEmpty(t, resp.Os)
+		assert.NotEmpty(t, resp.Arch)
+		assert.NotNil(t, resp.DaemonTime)
+		assert.Nil(t, resp.ServerTime)
+	})
+
+	t.Run("with server time", func(t *testing.T) {
+		serverTime := time.Now().Add(-time.Hour)
+		mockEng.On("ServerTime").Return(serverTime, true).Once()
+
+		resp, err := srv.GetVersion(context.Background(), &emptypb.Empty{})
+		assert.NoError(t, err)
+		assert.Equal(t, serverTime.Unix(), resp.ServerTime.Seconds)
+	})
+}
+
+func TestGRPCServer_GetCredentials_Real(t *testing.T) {
 	srv, _ := setupRealGRPCServer(t)
+	tmpDir := t.TempDir()
 
-	resp, err := srv.GetVersion(context.Background(), &emptypb.Empty{})
-	assert.NoError(t, err)
-	assert.Equal(t, "1.0.0", resp.Version)
-	assert.Equal(t, "2024-01-01", resp.BuildDate)
-	assert.Equal(t, "abc123", resp.Commit)
-	assert.NotEmpty(t, resp.GoVersion)
-	assert.NotEmpty(t, resp.Os)
-	assert.NotEmpty(t, resp.Arch)
+	t.Run("nothing held yet", func(t *testing.T) {
+		resp, err := srv.GetCredentials(context.Background(), &emptypb.Empty{})
+		assert.NoError(t, err)
+		assert.Empty(t, resp.Credentials)
+	})
+
+	t.Run("ipc and account tokens", func(t *testing.T) {
+		srv.ipcAuth = NewIPCAuthWithPath(filepath.Join(tmpDir, "ipc.token"))
+		require.NoError(t, srv.ipcAuth.GenerateAndSave())
+
+		kr, err := storage.NewTestKeyring(tmpDir)
+		require.NoError(t, err)
+		claims := base64.RawURLEncoding.EncodeToString([]byte(`{"email":"a@example.com","iat":1700000000,"exp":1700000900}`))
+		require.NoError(t, kr.StoreAuthToken("header."+claims+".signature"))
+		require.NoError(t, kr.StoreRefreshToken("opaque-refresh-token"))
+		srv.daemon.config.Keyring = kr
+
+		resp, err := srv.GetCredentials(context.Background(), &emptypb.Empty{})
+		assert.NoError(t, err)
+		require.Len(t, resp.Credentials, 3)
+
+		assert.Equal(t, "ipc_token", resp.Credentials[0].Kind)
+		require.NotNil(t, resp.Credentials[0].IssuedAt)
+		require.NotNil(t, resp.Credentials[0].ExpiresAt)
+		assert.Equal(t, TokenLifetime, resp.Credentials[0].ExpiresAt.AsTime().Sub(resp.Credentials[0].IssuedAt.AsTime()))
+
+		assert.Equal(t, "account_token", resp.Credentials[1].Kind)
+		assert.Equal(t, "a@example.com", resp.Credentials[1].Name)
+		assert.Equal(t, int64(1700000900), resp.Credentials[1].ExpiresAt.Seconds)
+
+		assert.Equal(t, "refresh_token", resp.Credentials[2].Kind)
+		assert.Nil(t, resp.Credentials[2].ExpiresAt)
+	})
 }
 
 func TestGRPCServer_Shutdown_Real(t *testing.T) {
@@ -347,6 +410,173 @@ func TestGRPCServer_GenerateInvite_Real(t *testing.T) {
 	})
 }
 
+func TestGRPCServer_PreviewInvite_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+
+	t.Run("describes the network", func(t *testing.T) {
+		mockEng.On("PreviewInvite", "CODE1").Return(&api.InviteValidation{
+			Valid:       true,
+			NetworkID:   "net-1",
+			NetworkName: "Home",
+			MemberCount: 4,
+			OnlineCount: 2,
+			OwnerID:     "user-1",
+			OwnerName:   "Ada",
+			UsesMax:     5,
+			UsesLeft:    3,
+		}, nil).Once()
+
+		resp, err := srv.PreviewInvite(context.Background(), &pb.PreviewInviteRequest{InviteCode: "CODE1"})
+		assert.NoError(t, err)
+		assert.True(t, resp.Valid)
+		assert.Equal(t, "net-1", resp.NetworkId)
+		assert.Equal(t, "Home", resp.NetworkName)
+		assert.Equal(t, int32(4), resp.MemberCount)
+		assert.Equal(t, int32(2), resp.OnlineCount)
+		assert.Equal(t, "user-1", resp.OwnerId)
+		assert.Equal(t, "Ada", resp.OwnerName)
+		assert.Equal(t, int32(3), resp.UsesRemaining)
+		assert.Nil(t, resp.ExpiresAt)
+	})
+
+	t.Run("unlimited uses", func(t *testing.T) {
+		mockEng.On("PreviewInvite", "CODE2").Return(&api.InviteValidation{
+			Valid:     true,
+			NetworkID: "net-2",
+		}, nil).Once()
+
+		resp, err := srv.PreviewInvite(context.Background(), &pb.PreviewInviteRequest{InviteCode: "CODE2"})
+		assert.NoError(t, err)
+		assert.Equal(t, int32(-1), resp.UsesRemaining)
+	})
+
+	t.Run("invalid code", func(t *testing.T) {
+		mockEng.On("PreviewInvite", "BAD").Return(&api.InviteValidation{Valid: false}, nil).Once()
+
+		resp, err := srv.PreviewInvite(context.Background(), &pb.PreviewInviteRequest{InviteCode: "BAD"})
+		assert.NoError(t, err)
+		assert.False(t, resp.Valid)
+		assert.Equal(t, "unknown", resp.InvalidReason)
+	})
+
+	t.Run("empty invite code", func(t *testing.T) {
+		_, err := srv.PreviewInvite(context.Background(), &pb.PreviewInviteRequest{})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
+	})
+}
+
+func TestGRPCServer_TransferOwnership_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+
+	t.Run("success", func(t *testing.T) {
+		mockEng.On("TransferOwnership", "net-1", "peer-2").Return(nil).Once()
+
+		_, err := srv.TransferOwnership(context.Background(), &pb.TransferOwnershipRequest{
+			NetworkId:  "net-1",
+			NewOwnerId: "peer-2",
+		})
+		assert.NoError(t, err)
+	})
+
+	t.Run("server refuses", func(t *testing.T) {
+		mockEng.On("TransferOwnership", "net-1", "peer-3").Return(fmt.Errorf("not the owner")).Once()
+
+		_, err := srv.TransferOwnership(context.Background(), &pb.TransferOwnershipRequest{
+			NetworkId:  "net-1",
+			NewOwnerId: "peer-3",
+		})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.Internal, st.Code())
+	})
+
+	t.Run("missing new owner", func(t *testing.T) {
+		_, err := srv.TransferOwnership(context.Background(), &pb.TransferOwnershipRequest{NetworkId: "net-1"})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
+	})
+}
+
+func TestGRPCServer_ReportPeer_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+
+	t.Run("success", func(t *testing.T) {
+		mockEng.On("ReportPeer", "net-1", "peer-2", "spam", []string{"msg-1"}).Return(nil).Once()
+
+		_, err := srv.ReportPeer(context.Background(), &pb.ReportPeerRequest{
+			NetworkId:  "net-1",
+			PeerId:     "peer-2",
+			Reason:     "spam",
+			MessageIds: []string{"msg-1"},
+		})
+		assert.NoError(t, err)
+	})
+
+	t.Run("server refuses", func(t *testing.T) {
+		mockEng.On("ReportPeer", "net-1", "peer-3", "", []string(nil)).Return(fmt.Errorf("member not found")).Once()
+
+		_, err := srv.ReportPeer(context.Background(), &pb.ReportPeerRequest{NetworkId: "net-1", PeerId: "peer-3"})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.Internal, st.Code())
+	})
+
+	t.Run("missing peer", func(t *testing.T) {
+		_, err := srv.ReportPeer(context.Background(), &pb.ReportPeerRequest{NetworkId: "net-1"})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
+	})
+}
+
+func TestGRPCServer_ListJoinRequests_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+
+	t.Run("success", func(t *testing.T) {
+		requested := time.Date(2024, 1, 1, 0, 0, 0, 0, time.UTC)
+		mockEng.On("ListJoinRequests", "net-1").Return([]api.JoinRequest{
+			{ID: "jr-1", NetworkID: "net-1", UserID: "user-2", UserName: "Grace", Status: "pending", CreatedAt: requested},
+		}, nil).Once()
+
+		resp, err := srv.ListJoinRequests(context.Background(), &pb.ListJoinRequestsRequest{NetworkId: "net-1"})
+		assert.NoError(t, err)
+		assert.Len(t, resp.Requests, 1)
+		assert.Equal(t, "user-2", resp.Requests[0].PeerId)
+		assert.Equal(t, "Grace", resp.Requests[0].Name)
+		assert.Equal(t, requested.Unix(), resp.Requests[0].RequestedAt.Seconds)
+	})
+
+	t.Run("not an admin", func(t *testing.T) {
+		mockEng.On("ListJoinRequests", "net-2").Return(nil, fmt.Errorf("Admin or owner role required")).Once()
+
+		_, err := srv.ListJoinRequests(context.Background(), &pb.ListJoinRequestsRequest{NetworkId: "net-2"})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.Internal, st.Code())
+	})
+
+	t.Run("missing network", func(t *testing.T) {
+		_, err := srv.ListJoinRequests(context.Background(), &pb.ListJoinRequestsRequest{})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
+	})
+}
+
+func TestGRPCServer_ConnectNetwork_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+	mockEng.On("Connect").Return().Once()
+
+	_, err := srv.ConnectNetwork(context.Background(), &emptypb.Empty{})
+	assert.NoError(t, err)
+	mockEng.AssertExpectations(t)
+}
+
+func TestGRPCServer_DisconnectNetwork_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+	mockEng.On("Disconnect").Return().Once()
+
+	_, err := srv.DisconnectNetwork(context.Background(), &emptypb.Empty{})
+	assert.NoError(t, err)
+	mockEng.AssertExpectations(t)
+}
+
 // ==================== PeerService gRPC Methods ====================
 
 func TestGRPCServer_GetPeers_Real(t *testing.T) {
@@ -624,7 +854,7 @@ func TestGRPCServer_ListTransfers_Real(t *testing.T) {
 	t.Run("with transfers", func(t *testing.T) {
 		mockEng.On("GetTransfers").Return([]transfer.Session{
 			{ID: "t-1", PeerID: "peer-1", FileName: "file1.txt", FileSize: 1024, Status: transfer.StatusPending, IsSender: true},
-			{ID: "t-2", PeerID: "peer-2", FileName: "file2.txt", FileSize: 2048, Status: transfer.StatusCompleted, IsSender: false},
+			{ID: "t-2", PeerID: "peer-2", FileName: "file2.txt", FileSize: 2048, Status: transfer.StatusCompleted, IsSender: false, SHA256: "abc123"},
 		}).Once()
 
 		resp, err := srv.ListTransfers(context.Background(), &emptypb.Empty{})
@@ -633,6 +863,36 @@ func TestGRPCServer_ListTransfers_Real(t *testing.T) {
 		assert.Equal(t, "t-1", resp.Transfers[0].Id)
 		assert.False(t, resp.Transfers[0].IsIncoming) // IsSender=true means not incoming
 		assert.True(t, resp.Transfers[1].IsIncoming)  // IsSender=false means incoming
+		assert.Equal(t, "abc123", resp.Transfers[1].Sha256)
+	})
+}
+
+func TestGRPCServer_SetTransferRateLimit_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+
+	t.Run("success", func(t *testing.T) {
+		mockEng.On("SetTransferRateLimit", "t-1", 256).Return(nil).Once()
+
+		_, err := srv.SetTransferRateLimit(context.Background(), &pb.SetTransferRateLimitRequest{TransferId: "t-1", MaxSpeedKbps: 256})
+		assert.NoError(t, err)
+	})
+
+	t.Run("unknown transfer", func(t *testing.T) {
+		mockEng.On("SetTransferRateLimit", "t-x", 256).Return(fmt.Errorf("session not found")).Once()
+
+		_, err := srv.SetTransferRateLimit(context.Background(), &pb.SetTransferRateLimitRequest{TransferId: "t-x", MaxSpeedKbps: 256})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.Internal, st.Code())
+	})
+
+	t.Run("invalid request", func(t *testing.T) {
+		_, err := srv.SetTransferRateLimit(context.Background(), &pb.SetTransferRateLimitRequest{MaxSpeedKbps: 256})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
+
+		_, err = srv.SetTransferRateLimit(context.Background(), &pb.SetTransferRateLimitRequest{TransferId: "t-1", MaxSpeedKbps: -5})
+		st, _ = status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
 	})
 }
 
@@ -649,20 +909,35 @@ func TestGRPCServer_GetSettings_Real(t *testing.T) {
 }
 
 func TestGRPCServer_UpdateSettings_Real(t *testing.T) {
-	srv, _ := setupRealGRPCServer(t)
+	srv, mockEng := setupRealGRPCServer(t)
 
 	t.Run("update settings", func(t *testing.T) {
+		mockEng.On("SetTransferRateLimits", 512, 2048).Return().Once()
+
 		resp, err := srv.UpdateSettings(context.Background(), &pb.UpdateSettingsRequest{
 			Settings: &pb.Settings{
 				AutoConnect:          false,
 				NotificationsEnabled: false,
 				DownloadPath:         "/new/path",
+				MaxUploadSpeedKbps:   512,
+				MaxDownloadSpeedKbps: 2048,
 			},
 		})
 		assert.NoError(t, err)
 		assert.False(t, resp.AutoConnect)
 		assert.False(t, resp.NotificationsEnabled)
 		assert.Equal(t, "/new/path", resp.DownloadPath)
+		assert.Equal(t, int32(512), resp.MaxUploadSpeedKbps)
+		assert.Equal(t, int32(2048), resp.MaxDownloadSpeedKbps)
+		mockEng.AssertExpectations(t)
+	})
+
+	t.Run("negative speed limit", func(t *testing.T) {
+		_, err := srv.UpdateSettings(context.Background(), &pb.UpdateSettingsRequest{
+			Settings: &pb.Settings{MaxUploadSpeedKbps: -1},
+		})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
 	})
 
 	t.Run("nil settings", func(t *testing.T) {
@@ -676,12 +951,96 @@ func TestGRPCServer_UpdateSettings_Real(t *testing.T) {
 }
 
 func TestGRPCServer_ResetSettings_Real(t *testing.T) {
-	srv, _ := setupRealGRPCServer(t)
+	srv, mockEng := setupRealGRPCServer(t)
+	mockEng.On("SetTransferRateLimits", 0, 0).Return().Once()
 
 	resp, err := srv.ResetSettings(context.Background(), &emptypb.Empty{})
 	assert.NoError(t, err)
 	assert.False(t, resp.AutoConnect)
 	assert.True(t, resp.NotificationsEnabled)
+	assert.Zero(t, resp.MaxUploadSpeedKbps)
+	mockEng.AssertExpectations(t)
+}
+
+// settingsStream records the settings SubscribeSettings sends.
+type settingsStream struct {
+	grpc.ServerStream
+	ctx  context.Context
+	sent chan *pb.Settings
+}
+
+func (s *settingsStream) Context() context.Context {
+	return s.ctx
+}
+
+func (s *settingsStream) Send(settings *pb.Settings) error {
+	s.sent <- settings
+	return nil
+}
+
+func TestGRPCServer_SubscribeSettings_Real(t *testing.T) {
+	srv, mockEng := setupRealGRPCServer(t)
+	mockEng.On("SetTransferRateLimits", 256, 0).Return().Once()
+
+	ctx, cancel := context.WithCancel(context.Background())
+	stream := &settingsStream{ctx: ctx, sent: make(chan *pb.Settings, 10)}
+	done := make(chan error, 1)
+	go func() {
+		done <- srv.SubscribeSettings(&emptypb.Empty{}, stream)
+	}()
+
+	// The current settings come first
+	select {
+	case settings := <-stream.sent:
+		assert.True(t, settings.AutoConnect)
+		assert.Equal(t, "/tmp/downloads", settings.DownloadPath)
+	case <-time.After(time.Second):
+		t.Fatal("timeout waiting for current settings")
+	}
+
+	// Then each change
+	_, err := srv.UpdateSettings(context.Background(), &pb.UpdateSettingsRequest{
+		Settings: &pb.Settings{NotificationsEnabled: true, MaxUploadSpeedKbps: 256},
+	})
+	require.NoError(t, err)
+
+	select {
+	case settings := <-stream.sent:
+		assert.False(t, settings.AutoConnect)
+		assert.Equal(t, int32(256), settings.MaxUploadSpeedKbps)
+	case <-time.After(time.Second):
+		t.Fatal("timeout waiting for changed settings")
+	}
+
+	cancel()
+	select {
+	case err := <-done:
+		assert.ErrorIs(t, err, context.Canceled)
+	case <-time.After(time.Second):
+		t.Fatal("SubscribeSettings did not return after the client left")
+	}
+
+	srv.settingsSubscribersMu.Lock()
+	assert.Empty(t, srv.settingsSubscribers)
+	srv.settingsSubscribersMu.Unlock()
+	mockEng.AssertExpectations(t)
+}
+
+func TestGRPCServer_BroadcastSettings_KeepsLatest(t *testing.T) {
+	srv, _ := setupRealGRPCServer(t)
+
+	settingsChan := make(chan *pb.Settings, 1)
+	srv.settingsSubscribers[settingsChan] = struct{}{}
+
+	srv.broadcastSettings(&pb.Settings{DownloadPath: "/first"})
+	srv.broadcastSettings(&pb.Settings{DownloadPath: "/second"})
+
+	select {
+	case settings := <-settingsChan:
+		assert.Equal(t, "/second", settings.DownloadPath)
+	default:
+		t.Fatal("expected settings to be delivered")
+	}
 }
 
 // ==================== BroadcastEvent Test ====================
@@ -711,6 +1070,114 @@ func TestGRPCServer_BroadcastEvent_Real(t *testing.T) {
 	}
 }
 
+func TestDaemonService_EngineEventsReachSubscribers(t *testing.T) {
+	var mu sync.Mutex
+	configRequests := 0
+	apiHandler := func(w http.ResponseWriter, r *http.Request) {
+		if strings.Contains(r.URL.Path, "/config") {
+			mu.Lock()
+			configRequests++
+			peers := []api.PeerConfig{{ID: "peer-0", Name: "Zero"}}
+			if configRequests > 1 {
+				peers = append(peers, api.PeerConfig{ID: "peer-1", Name: "One", AllowedIPs: []string{"10.0.0.5/32"}})
+			}
+			mu.Unlock()
+			_ = json.NewEncoder(w).Encode(api.DeviceConfig{Peers: peers})
+			return
+		}
+		_ = json.NewEncoder(w).Encode([]api.NetworkResponse{})
+	}
+
+	svc, apiServer := setupTestDaemonWithAPI(t, apiHandler)
+	defer apiServer.Close()
+	require.NoError(t, svc.config.Keyring.StoreAuthToken("token"))
+	require.NoError(t, svc.idManager.Update("device-1"))
+
+	svc.grpcServer = NewGRPCServer(svc, "1.0.0", "", "")
+	svc.setupEngineCallbacks()
+
+	eventChan := make(chan *pb.DaemonEvent, 10)
+	svc.grpcServer.subscribersMu.Lock()
+	svc.grpcServer.subscribers[eventChan] = struct{}{}
+	svc.grpcServer.subscribersMu.Unlock()
+
+	// The first sync learns the peers already there
+	svc.engine.Connect()
+	require.Eventually(t, func() bool {
+		_, ok := svc.engine.GetPeerByID("peer-0")
+		return ok
+	}, 2*time.Second, 10*time.Millisecond)
+
+	// The next one reports the peer that joined since
+	svc.engine.Connect()
+	select {
+	case event := <-eventChan:
+		assert.Equal(t, pb.EventType_EVENT_TYPE_PEER_JOINED, event.Type)
+		peer := event.GetPeerEvent().GetPeer()
+		assert.Equal(t, "peer-1", peer.GetId())
+		assert.Equal(t, "10.0.0.5", peer.GetVirtualIp())
+		assert.True(t, event.GetPeerEvent().GetJoined())
+	case <-time.After(2 * time.Second):
+		t.Fatal("timeout waiting for the peer joined event")
+	}
+}
+
+func TestGRPCServer_ReportIPCAuthFailure_Real(t *testing.T) {
+	srv, _ := setupRealGRPCServer(t)
+
+	eventChan := make(chan *pb.DaemonEvent, 10)
+	srv.subscribersMu.Lock()
+	srv.subscribers[eventChan] = struct{}{}
+	srv.subscribersMu.Unlock()
+
+	srv.reportIPCAuthFailure(context.Background(), "/daemon.DaemonService/GetStatus")
+	srv.reportIPCAuthFailure(context.Background(), "/daemon.DaemonService/GetStatus")
+
+	select {
+	case event := <-eventChan:
+		assert.Equal(t, pb.EventType_EVENT_TYPE_SECURITY, event.Type)
+		sec := event.GetSecurityEvent()
+		assert.Equal(t, pb.SecurityEventKind_SECURITY_EVENT_KIND_IPC_AUTH_FAILED, sec.GetKind())
+		assert.NotEmpty(t, sec.GetId())
+		assert.Contains(t, sec.GetMessage(), "GetStatus")
+	default:
+		t.Fatal("expected a security event")
+	}
+	assert.Empty(t, eventChan, "repeated failures within a minute are reported once")
+}
+
+func TestEngineEvents(t *testing.T) {
+	t.Run("chat message", func(t *testing.T) {
+		event := chatMessageEvent(chat.Message{ID: "m-1", From: "peer-1", Content: "hi", NetworkID: "net-1"})
+		assert.Equal(t, pb.EventType_EVENT_TYPE_CHAT_MESSAGE, event.Type)
+		assert.Equal(t, "net-1", event.GetChatMessage().GetNetworkId())
+		assert.Equal(t, "peer-1", event.GetChatMessage().GetSenderId())
+	})
+
+	t.Run("transfer request", func(t *testing.T) {
+		event := transferRequestEvent(transfer.Request{ID: "req-1", FileName: "a.txt", FileSize: 10}, "peer-1")
+		assert.Equal(t, pb.EventType_EVENT_TYPE_TRANSFER_REQUEST, event.Type)
+		ft := event.GetTransferEvent().GetTransfer()
+		assert.Equal(t, "req-1", ft.GetId())
+		assert.Equal(t, pb.TransferStatus_TRANSFER_STATUS_PENDING, ft.GetStatus())
+		assert.True(t, ft.GetIsIncoming())
+	})
+
+	t.Run("peer key changed", func(t *testing.T) {
+		event := peerKeyChangedEvent(api.PeerConfig{ID: "peer-1", Name: "Alice"})
+		assert.Equal(t, pb.EventType_EVENT_TYPE_SECURITY, event.Type)
+		assert.Equal(t, pb.SecurityEventKind_SECURITY_EVENT_KIND_PEER_KEY_CHANGED, event.GetSecurityEvent().GetKind())
+		assert.Equal(t, "peer-1", event.GetSecurityEvent().GetPeerId())
+		assert.Contains(t, event.GetSecurityEvent().GetMessage(), "Alice")
+	})
+
+	t.Run("peer left", func(t *testing.T) {
+		event := peerEvent(api.PeerConfig{ID: "peer-1"}, false)
+		assert.Equal(t, pb.EventType_EVENT_TYPE_PEER_LEFT, event.Type)
+		assert.False(t, event.GetPeerEvent().GetJoined())
+	})
+}
+
 // ==================== Logging Interceptor Tests ====================
 
 func TestGRPCServer_LoggingInterceptors(t *testing.T) {
@@ -2,6 +2,8 @@ package daemon
 
 import (
 	"context"
+	"time"
+
 	"github.com/orhaniscoding/goconnect/cli/internal/api"
 	"github.com/orhaniscoding/goconnect/cli/internal/chat"
 	"github.com/orhaniscoding/goconnect/cli/internal/transfer"
@@ -15,20 +17,29 @@ type DaemonEngine interface {
 	Connect()
 	Disconnect()
 	GetStatus() map[string]interface{}
+	ServerTime() (time.Time, bool)
 	ManualConnect(peerID string) error
 	SendChatMessage(peerID, content string) error
 	SendFileRequest(peerID, filePath string) (*transfer.Session, error)
 	AcceptFile(requestID, savePath string) error
 	SetOnChatMessage(handler func(chat.Message))
 	SetTransferCallbacks(onProgress func(session transfer.Session), onRequest func(req transfer.Request, senderID string))
+	SetOnPeerChange(handler func(peer api.PeerConfig, joined bool))
+	SetOnPeerKeyChange(handler func(peer api.PeerConfig))
 	GetPeerByID(peerID string) (*api.PeerConfig, bool)
 	GenerateInvite(networkID string, maxUses int, expiresHours int) (*api.InviteTokenResponse, error)
+	PreviewInvite(inviteCode string) (*api.InviteValidation, error)
 	KickPeer(networkID, peerID, reason string) error
 	BanPeer(networkID, peerID, reason string) error
 	UnbanPeer(networkID, peerID string) error
+	TransferOwnership(networkID, newOwnerID string) error
+	ReportPeer(networkID, peerID, reason string, messageIDs []string) error
+	ListJoinRequests(networkID string) ([]api.JoinRequest, error)
 	GetChatMessages(networkID string, limit int, beforeID string) []chat.Message
 	RejectTransfer(transferID string) error
 	CancelTransfer(transferID string) error
+	SetTransferRateLimits(uploadKbps, downloadKbps int)
+	SetTransferRateLimit(transferID string, kbps int) error
 	GetTransfers() []transfer.Session
 	SubscribeTransfers() chan transfer.Session
 	UnsubscribeTransfers(ch chan transfer.Session)
@@ -11,6 +11,7 @@ import (
 	"runtime"
 	"strings"
 	"sync"
+	"time"
 
 	"google.golang.org/grpc"
 	"google.golang.org/grpc/codes"
@@ -27,20 +28,40 @@ const (
 
 	// TokenFilePermissions restricts token file to owner-only read/write
 	TokenFilePermissions = 0600
+
+	// TokenLifetime is how long a token is accepted after it was issued
+	TokenLifetime = 7 * 24 * time.Hour
+
+	// TokenRotationInterval is how often a running daemon issues a new token. The
+	// previous token stays valid for the rest of its lifetime, so clients holding
+	// it have time to re-read the token file.
+	TokenRotationInterval = TokenLifetime / 2
 )
 
+// errExpiredToken rejects a token that was issued by this daemon but outlived TokenLifetime.
+var errExpiredToken = status.Error(codes.Unauthenticated, "expired IPC auth token")
+
 // IPCAuth handles token-based authentication for local IPC connections.
 // This implements Zero-Trust IPC as described in the architecture.
 type IPCAuth struct {
 	mu        sync.RWMutex
 	token     string
 	tokenPath string
+	issuedAt  time.Time
+	onFailure func(ctx context.Context, method string)
+
+	// The token replaced by the last rotation
+	previous         string
+	previousIssuedAt time.Time
+
+	now func() time.Time
 }
 
 // NewIPCAuth creates a new IPC authentication handler.
 func NewIPCAuth() *IPCAuth {
 	return &IPCAuth{
 		tokenPath: getDefaultTokenPath(),
+		now:       time.Now,
 	}
 }
 
@@ -48,6 +69,7 @@ func NewIPCAuth() *IPCAuth {
 func NewIPCAuthWithPath(tokenPath string) *IPCAuth {
 	return &IPCAuth{
 		tokenPath: tokenPath,
+		now:       time.Now,
 	}
 }
 
@@ -76,7 +98,8 @@ func getDefaultTokenPath() string {
 }
 
 // GenerateAndSave generates a new random token and saves it to the token file.
-// This should be called on daemon startup.
+// This should be called on daemon startup, and again to rotate the token; the
+// token it replaces stays valid until it expires.
 func (a *IPCAuth) GenerateAndSave() error {
 	a.mu.Lock()
 	defer a.mu.Unlock()
@@ -86,7 +109,7 @@ func (a *IPCAuth) GenerateAndSave() error {
 	if _, err := rand.Read(tokenBytes); err != nil {
 		return fmt.Errorf("failed to generate random token: %w", err)
 	}
-	a.token = hex.EncodeToString(tokenBytes)
+	token := hex.EncodeToString(tokenBytes)
 
 	// Ensure directory exists
 	dir := filepath.Dir(a.tokenPath)
@@ -95,10 +118,14 @@ func (a *IPCAuth) GenerateAndSave() error {
 	}
 
 	// Write token to file with restricted permissions
-	if err := os.WriteFile(a.tokenPath, []byte(a.token), TokenFilePermissions); err != nil {
+	if err := os.WriteFile(a.tokenPath, []byte(token), TokenFilePermissions); err != nil {
 		return fmt.Errorf("failed to write token file: %w", err)
 	}
 
+	// Only a token clients can read replaces the current one
+	a.previous, a.previousIssuedAt = a.token, a.issuedAt
+	a.token, a.issuedAt = token, a.now()
+
 	return nil
 }
 
@@ -108,22 +135,44 @@ func (a *IPCAuth) Cleanup() error {
 	defer a.mu.Unlock()
 
 	a.token = ""
+	a.previous = ""
 	if err := os.Remove(a.tokenPath); err != nil && !os.IsNotExist(err) {
 		return fmt.Errorf("failed to remove token file: %w", err)
 	}
 	return nil
 }
 
-// ValidateToken checks if the provided token matches the current valid token.
+// ValidateToken checks if the provided token matches a token that has not expired.
 func (a *IPCAuth) ValidateToken(token string) bool {
+	return a.checkToken(token) == nil
+}
+
+// checkToken returns nil for a valid token, errExpiredToken for one of ours that
+// outlived TokenLifetime, and an invalid token error otherwise.
+func (a *IPCAuth) checkToken(token string) error {
 	a.mu.RLock()
 	defer a.mu.RUnlock()
 
-	// Use constant-time comparison to prevent timing attacks
-	if len(token) != len(a.token) {
-		return false
+	issued := [...]struct {
+		token string
+		at    time.Time
+	}{{a.token, a.issuedAt}, {a.previous, a.previousIssuedAt}}
+
+	now := a.now()
+	for _, t := range issued {
+		// Use constant-time comparison to prevent timing attacks
+		if t.token == "" || len(token) != len(t.token) {
+			continue
+		}
+		if subtle.ConstantTimeCompare([]byte(token), []byte(t.token)) != 1 {
+			continue
+		}
+		if now.Sub(t.at) > TokenLifetime {
+			return errExpiredToken
+		}
+		return nil
 	}
-	return subtle.ConstantTimeCompare([]byte(token), []byte(a.token)) == 1
+	return status.Error(codes.Unauthenticated, "invalid IPC auth token")
 }
 
 // GetToken returns the current token (for testing/debugging only).
@@ -133,11 +182,42 @@ func (a *IPCAuth) GetToken() string {
 	return a.token
 }
 
+// IssuedAt returns when the current token was generated.
+func (a *IPCAuth) IssuedAt() time.Time {
+	a.mu.RLock()
+	defer a.mu.RUnlock()
+	return a.issuedAt
+}
+
+// ExpiresAt returns when the current token stops being accepted.
+func (a *IPCAuth) ExpiresAt() time.Time {
+	a.mu.RLock()
+	defer a.mu.RUnlock()
+	return a.issuedAt.Add(TokenLifetime)
+}
+
 // GetTokenPath returns the path to the token file.
 func (a *IPCAuth) GetTokenPath() string {
 	return a.tokenPath
 }
 
+// SetOnFailure sets a callback for calls rejected for a missing or wrong token.
+func (a *IPCAuth) SetOnFailure(handler func(ctx context.Context, method string)) {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+	a.onFailure = handler
+}
+
+func (a *IPCAuth) reportFailure(ctx context.Context, method string) {
+	a.mu.RLock()
+	onFailure := a.onFailure
+	a.mu.RUnlock()
+
+	if onFailure != nil {
+		onFailure(ctx, method)
+	}
+}
+
 // UnaryServerInterceptor returns a gRPC unary interceptor that validates IPC tokens.
 func (a *IPCAuth) UnaryServerInterceptor() grpc.UnaryServerInterceptor {
 	return func(
@@ -152,6 +232,10 @@ func (a *IPCAuth) UnaryServerInterceptor() grpc.UnaryServerInterceptor {
 		}
 
 		if err := a.validateFromContext(ctx); err != nil {
+			// An expired token is ours, so it is no sign of an intruder
+			if err != errExpiredToken {
+				a.reportFailure(ctx, info.FullMethod)
+			}
 			return nil, err
 		}
 
@@ -173,6 +257,9 @@ func (a *IPCAuth) StreamServerInterceptor() grpc.StreamServerInterceptor {
 		}
 
 		if err := a.validateFromContext(ss.Context()); err != nil {
+			if err != errExpiredToken {
+				a.reportFailure(ss.Context(), info.FullMethod)
+			}
 			return err
 		}
 
@@ -192,12 +279,7 @@ func (a *IPCAuth) validateFromContext(ctx context.Context) error {
 		return status.Error(codes.Unauthenticated, "missing IPC auth token")
 	}
 
-	token := tokens[0]
-	if !a.ValidateToken(token) {
-		return status.Error(codes.Unauthenticated, "invalid IPC auth token")
-	}
-
-	return nil
+	return a.checkToken(tokens[0])
 }
 
 // isPublicMethod returns true for methods that don't require authentication.
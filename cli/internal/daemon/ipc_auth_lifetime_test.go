@@ -0,0 +1,49 @@
+package daemon
+
+import (
+	"context"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/metadata"
+)
+
+func TestIPCAuth_TokenLifetime(t *testing.T) {
+	auth := NewIPCAuthWithPath(filepath.Join(t.TempDir(), "test.token"))
+	now := time.Now()
+	auth.now = func() time.Time { return now }
+	require.NoError(t, auth.GenerateAndSave())
+	token := auth.GetToken()
+
+	assert.Equal(t, now.Add(TokenLifetime), auth.ExpiresAt())
+
+	now = now.Add(TokenLifetime - time.Minute)
+	assert.True(t, auth.ValidateToken(token))
+
+	now = now.Add(2 * time.Minute)
+	assert.False(t, auth.ValidateToken(token))
+	assert.Equal(t, errExpiredToken, auth.checkToken(token))
+	assert.NotEqual(t, errExpiredToken, auth.checkToken("wrong"))
+
+	t.Run("expired tokens are not reported as failures", func(t *testing.T) {
+		var failures []string
+		auth.SetOnFailure(func(ctx context.Context, method string) {
+			failures = append(failures, method)
+		})
+
+		interceptor := auth.UnaryServerInterceptor()
+		info := &grpc.UnaryServerInfo{FullMethod: "/daemon.DaemonService/GetStatus"}
+		handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+			return "ok", nil
+		}
+
+		ctx := metadata.NewIncomingContext(context.Background(), metadata.Pairs(TokenHeaderKey, token))
+		_, err := interceptor(ctx, nil, info, handler)
+		assert.Equal(t, errExpiredToken, err)
+		assert.Empty(t, failures)
+	})
+}
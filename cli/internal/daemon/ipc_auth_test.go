@@ -1,12 +1,15 @@
 package daemon_test
 
 import (
+	"context"
 	"os"
 	"path/filepath"
 	"runtime"
 	"testing"
 
 	"github.com/orhaniscoding/goconnect/cli/internal/daemon"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/metadata"
 )
 
 func TestIPCAuth_GenerateAndSave(t *testing.T) {
@@ -91,6 +94,42 @@ func TestIPCAuth_ValidateToken(t *testing.T) {
 	}
 }
 
+func TestIPCAuth_Rotation(t *testing.T) {
+	tokenPath := filepath.Join(t.TempDir(), "test.token")
+	auth := daemon.NewIPCAuthWithPath(tokenPath)
+
+	if err := auth.GenerateAndSave(); err != nil {
+		t.Fatalf("GenerateAndSave failed: %v", err)
+	}
+	first := auth.GetToken()
+
+	// Rotate: the new token is written out, the old one keeps working
+	if err := auth.GenerateAndSave(); err != nil {
+		t.Fatalf("GenerateAndSave failed: %v", err)
+	}
+	second := auth.GetToken()
+	if second == first {
+		t.Fatal("Rotation kept the same token")
+	}
+	if loaded, _ := daemon.LoadClientTokenFromPath(tokenPath); loaded != second {
+		t.Error("Token file was not updated on rotation")
+	}
+	if !auth.ValidateToken(first) || !auth.ValidateToken(second) {
+		t.Error("Both the rotated and the new token should be valid")
+	}
+
+	// Only the token just replaced is kept
+	if err := auth.GenerateAndSave(); err != nil {
+		t.Fatalf("GenerateAndSave failed: %v", err)
+	}
+	if auth.ValidateToken(first) {
+		t.Error("Token from two rotations ago should be invalid")
+	}
+	if !auth.ValidateToken(second) {
+		t.Error("Previous token should still be valid")
+	}
+}
+
 func TestIPCAuth_Cleanup(t *testing.T) {
 	tmpDir := t.TempDir()
 	tokenPath := filepath.Join(tmpDir, "test.token")
@@ -257,6 +296,40 @@ func TestIPCAuth_UnaryServerInterceptor(t *testing.T) {
 	}
 }
 
+func TestIPCAuth_UnaryServerInterceptor_ReportsFailures(t *testing.T) {
+	auth := daemon.NewIPCAuthWithPath(filepath.Join(t.TempDir(), "test.token"))
+	if err := auth.GenerateAndSave(); err != nil {
+		t.Fatalf("GenerateAndSave failed: %v", err)
+	}
+
+	var failures []string
+	auth.SetOnFailure(func(ctx context.Context, method string) {
+		failures = append(failures, method)
+	})
+
+	interceptor := auth.UnaryServerInterceptor()
+	info := &grpc.UnaryServerInfo{FullMethod: "/daemon.DaemonService/GetStatus"}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return "ok", nil
+	}
+
+	ctx := metadata.NewIncomingContext(context.Background(), metadata.Pairs(daemon.TokenHeaderKey, auth.GetToken()))
+	if _, err := interceptor(ctx, nil, info, handler); err != nil {
+		t.Fatalf("valid token rejected: %v", err)
+	}
+	if len(failures) != 0 {
+		t.Fatalf("valid token reported as a failure: %v", failures)
+	}
+
+	ctx = metadata.NewIncomingContext(context.Background(), metadata.Pairs(daemon.TokenHeaderKey, "wrong"))
+	if _, err := interceptor(ctx, nil, info, handler); err == nil {
+		t.Fatal("wrong token accepted")
+	}
+	if len(failures) != 1 || failures[0] != info.FullMethod {
+		t.Errorf("expected one failure for %s, got %v", info.FullMethod, failures)
+	}
+}
+
 func TestIPCAuth_GenerateAndSave_CreatesDirectory(t *testing.T) {
 	tmpDir := t.TempDir()
 	// Nested path that doesn't exist
@@ -7,6 +7,7 @@ import (
 	"github.com/orhaniscoding/goconnect/cli/internal/transfer"
 	"github.com/orhaniscoding/goconnect/cli/internal/voice"
 	"github.com/stretchr/testify/mock"
+	"time"
 )
 
 // MockEngine is a mock implementation of DaemonEngine
@@ -35,6 +36,11 @@ func (m *MockEngine) GetStatus() map[string]interface{} {
 	return args.Get(0).(map[string]interface{})
 }
 
+func (m *MockEngine) ServerTime() (time.Time, bool) {
+	args := m.Called()
+	return args.Get(0).(time.Time), args.Bool(1)
+}
+
 func (m *MockEngine) ManualConnect(peerID string) error {
 	args := m.Called(peerID)
 	return args.Error(0)
@@ -66,6 +72,14 @@ func (m *MockEngine) SetTransferCallbacks(onProgress func(session transfer.Sessi
 	// m.Called(onProgress, onRequest)
 }
 
+func (m *MockEngine) SetOnPeerChange(handler func(peer api.PeerConfig, joined bool)) {
+	// m.Called(handler)
+}
+
+func (m *MockEngine) SetOnPeerKeyChange(handler func(peer api.PeerConfig)) {
+	// m.Called(handler)
+}
+
 func (m *MockEngine) GetPeerByID(peerID string) (*api.PeerConfig, bool) {
 	args := m.Called(peerID)
 	if args.Get(0) == nil {
@@ -99,6 +113,14 @@ func (m *MockEngine) GenerateInvite(networkID string, maxUses int, expiresHours
 	return args.Get(0).(*api.InviteTokenResponse), args.Error(1)
 }
 
+func (m *MockEngine) PreviewInvite(inviteCode string) (*api.InviteValidation, error) {
+	args := m.Called(inviteCode)
+	if args.Get(0) == nil {
+		return nil, args.Error(1)
+	}
+	return args.Get(0).(*api.InviteValidation), args.Error(1)
+}
+
 func (m *MockEngine) KickPeer(networkID, peerID, reason string) error {
 	args := m.Called(networkID, peerID, reason)
 	return args.Error(0)
@@ -114,6 +136,24 @@ func (m *MockEngine) UnbanPeer(networkID, peerID string) error {
 	return args.Error(0)
 }
 
+func (m *MockEngine) TransferOwnership(networkID, newOwnerID string) error {
+	args := m.Called(networkID, newOwnerID)
+	return args.Error(0)
+}
+
+func (m *MockEngine) ReportPeer(networkID, peerID, reason string, messageIDs []string) error {
+	args := m.Called(networkID, peerID, reason, messageIDs)
+	return args.Error(0)
+}
+
+func (m *MockEngine) ListJoinRequests(networkID string) ([]api.JoinRequest, error) {
+	args := m.Called(networkID)
+	if args.Get(0) == nil {
+		return nil, args.Error(1)
+	}
+	return args.Get(0).([]api.JoinRequest), args.Error(1)
+}
+
 func (m *MockEngine) GetChatMessages(networkID string, limit int, beforeID string) []chat.Message {
 	args := m.Called(networkID, limit, beforeID)
 	if args.Get(0) == nil {
@@ -132,6 +172,15 @@ func (m *MockEngine) CancelTransfer(transferID string) error {
 	return args.Error(0)
 }
 
+func (m *MockEngine) SetTransferRateLimits(uploadKbps, downloadKbps int) {
+	m.Called(uploadKbps, downloadKbps)
+}
+
+func (m *MockEngine) SetTransferRateLimit(transferID string, kbps int) error {
+	args := m.Called(transferID, kbps)
+	return args.Error(0)
+}
+
 func (m *MockEngine) GetTransfers() []transfer.Session {
 	args := m.Called()
 	if args.Get(0) == nil {
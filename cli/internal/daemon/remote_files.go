@@ -0,0 +1,217 @@
+package daemon
+
+import (
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	pb "github.com/orhaniscoding/goconnect/cli/internal/proto"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/types/known/timestamppb"
+)
+
+// pathPolicy decides which parts of the host's file system remote clients may
+// browse. Only the daemon user's home and download folders are shared, and
+// never the daemon's own credentials or the user's keys inside them.
+type pathPolicy struct {
+	roots  []string
+	denied []string
+}
+
+// newPathPolicy resolves the shared roots and denied paths once, so symlinks
+// cannot be used to step around them.
+func newPathPolicy(roots, denied []string) *pathPolicy {
+	p := &pathPolicy{}
+	for _, root := range roots {
+		if root == "" {
+			continue
+		}
+		if resolved, err := filepath.EvalSymlinks(root); err == nil {
+			p.roots = append(p.roots, resolved)
+		}
+	}
+	for _, path := range denied {
+		if path == "" {
+			continue
+		}
+		if resolved, err := filepath.EvalSymlinks(path); err == nil {
+			path = resolved
+		}
+		p.denied = append(p.denied, filepath.Clean(path))
+	}
+	return p
+}
+
+// browsePolicy builds the policy for this daemon's configuration.
+func (s *GRPCServer) browsePolicy() *pathPolicy {
+	home, _ := os.UserHomeDir()
+
+	roots := []string{home}
+	denied := []string{}
+	if home != "" {
+		denied = append(denied,
+			filepath.Join(home, ".goconnect"), // config and file keyring
+			filepath.Join(home, ".ssh"),
+			filepath.Join(home, ".gnupg"),
+		)
+	}
+	if s.ipcAuth != nil {
+		denied = append(denied, filepath.Dir(s.ipcAuth.GetTokenPath()))
+	}
+	if cfg := s.daemon.config; cfg != nil {
+		roots = append(roots, cfg.Settings.DownloadPath)
+		denied = append(denied, cfg.ConfigPath, cfg.IdentityPath)
+	}
+
+	return newPathPolicy(roots, denied)
+}
+
+// resolve turns a client's path into a real path the policy allows. An empty
+// path means the first shared root.
+func (p *pathPolicy) resolve(path string) (string, error) {
+	if path == "" {
+		if len(p.roots) == 0 {
+			return "", status.Error(codes.FailedPrecondition, "no folders are shared on this host")
+		}
+		return p.roots[0], nil
+	}
+	if !filepath.IsAbs(path) {
+		return "", status.Error(codes.InvalidArgument, "path must be absolute")
+	}
+
+	resolved, err := filepath.EvalSymlinks(filepath.Clean(path))
+	if err != nil {
+		if os.IsNotExist(err) {
+			return "", status.Error(codes.NotFound, "path not found")
+		}
+		return "", status.Errorf(codes.Internal, "failed to resolve path: %v", err)
+	}
+	if !p.allows(resolved) {
+		return "", status.Error(codes.PermissionDenied, "path is outside the folders this daemon shares")
+	}
+	return resolved, nil
+}
+
+// allows reports whether a resolved path may be shown to remote clients.
+func (p *pathPolicy) allows(path string) bool {
+	for _, denied := range p.denied {
+		if within(path, denied) {
+			return false
+		}
+	}
+	for _, root := range p.roots {
+		if within(path, root) {
+			return true
+		}
+	}
+	return false
+}
+
+// within reports whether path is dir itself or somewhere below it.
+func within(path, dir string) bool {
+	rel, err := filepath.Rel(dir, path)
+	if err != nil || filepath.IsAbs(rel) {
+		return false
+	}
+	return rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator))
+}
+
+// listDirectory lists the entries of a shared directory that the policy allows.
+func (p *pathPolicy) listDirectory(path string, includeHidden bool) (*pb.ListDirectoryResponse, error) {
+	dir, err := p.resolve(path)
+	if err != nil {
+		return nil, err
+	}
+	info, err := os.Stat(dir)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to stat directory: %v", err)
+	}
+	if !info.IsDir() {
+		return nil, status.Error(codes.InvalidArgument, "path is not a directory")
+	}
+
+	items, err := os.ReadDir(dir)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to list directory: %v", err)
+	}
+
+	resp := &pb.ListDirectoryResponse{Path: dir}
+	if parent := filepath.Dir(dir); parent != dir && p.allows(parent) {
+		resp.Parent = parent
+	}
+
+	for _, item := range items {
+		if !includeHidden && strings.HasPrefix(item.Name(), ".") {
+			continue
+		}
+		full := filepath.Join(dir, item.Name())
+
+		// Links may point anywhere, so check where they end up
+		target, err := filepath.EvalSymlinks(full)
+		if err != nil || !p.allows(target) {
+			continue
+		}
+		info, err := os.Stat(full)
+		if err != nil {
+			continue
+		}
+		resp.Entries = append(resp.Entries, fileEntry(full, info))
+	}
+
+	// Folders first, then by name
+	sort.Slice(resp.Entries, func(i, j int) bool {
+		a, b := resp.Entries[i], resp.Entries[j]
+		if a.IsDir != b.IsDir {
+			return a.IsDir
+		}
+		return strings.ToLower(a.Name) < strings.ToLower(b.Name)
+	})
+
+	return resp, nil
+}
+
+// statPath describes one shared path.
+func (p *pathPolicy) statPath(path string) (*pb.RemoteFileEntry, error) {
+	if path == "" {
+		return nil, status.Error(codes.InvalidArgument, "path is required")
+	}
+	resolved, err := p.resolve(path)
+	if err != nil {
+		return nil, err
+	}
+	info, err := os.Stat(resolved)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to stat path: %v", err)
+	}
+
+	entry := fileEntry(resolved, info)
+	// Report the path as asked so clients can match it to their request
+	entry.Path = filepath.Clean(path)
+	entry.Name = filepath.Base(entry.Path)
+	return entry, nil
+}
+
+func fileEntry(path string, info os.FileInfo) *pb.RemoteFileEntry {
+	entry := &pb.RemoteFileEntry{
+		Name:       info.Name(),
+		Path:       path,
+		IsDir:      info.IsDir(),
+		ModifiedAt: timestamppb.New(info.ModTime()),
+		Readable:   canOpen(path),
+	}
+	if !info.IsDir() {
+		entry.SizeBytes = info.Size()
+	}
+	return entry
+}
+
+func canOpen(path string) bool {
+	f, err := os.Open(path)
+	if err != nil {
+		return false
+	}
+	f.Close()
+	return true
+}
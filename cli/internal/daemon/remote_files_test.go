@@ -0,0 +1,141 @@
+package daemon
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	pb "github.com/orhaniscoding/goconnect/cli/internal/proto"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+// setupSharedDir creates a shared folder with a file, a hidden file, a
+// subfolder, a denied folder and a link that escapes the share.
+func setupSharedDir(t *testing.T) (shared, outside string) {
+	shared, err := filepath.EvalSymlinks(t.TempDir())
+	require.NoError(t, err)
+	outside, err = filepath.EvalSymlinks(t.TempDir())
+	require.NoError(t, err)
+
+	require.NoError(t, os.WriteFile(filepath.Join(shared, "b.txt"), []byte("hello"), 0600))
+	require.NoError(t, os.WriteFile(filepath.Join(shared, ".hidden"), []byte("x"), 0600))
+	require.NoError(t, os.Mkdir(filepath.Join(shared, "A folder"), 0700))
+	require.NoError(t, os.Mkdir(filepath.Join(shared, ".ssh"), 0700))
+	require.NoError(t, os.WriteFile(filepath.Join(outside, "secret.txt"), []byte("x"), 0600))
+	if err := os.Symlink(filepath.Join(outside, "secret.txt"), filepath.Join(shared, "escape.txt")); err != nil {
+		t.Logf("symlinks unavailable: %v", err)
+	}
+	return shared, outside
+}
+
+func TestPathPolicy_Resolve(t *testing.T) {
+	shared, outside := setupSharedDir(t)
+	policy := newPathPolicy([]string{shared}, []string{filepath.Join(shared, ".ssh")})
+
+	resolved, err := policy.resolve("")
+	require.NoError(t, err)
+	assert.Equal(t, shared, resolved)
+
+	resolved, err = policy.resolve(filepath.Join(shared, "A folder", "..", "b.txt"))
+	require.NoError(t, err)
+	assert.Equal(t, filepath.Join(shared, "b.txt"), resolved)
+
+	tests := []struct {
+		name string
+		path string
+		code codes.Code
+	}{
+		{"relative", "b.txt", codes.InvalidArgument},
+		{"missing", filepath.Join(shared, "missing.txt"), codes.NotFound},
+		{"outside", filepath.Join(outside, "secret.txt"), codes.PermissionDenied},
+		{"dot dot out of the share", filepath.Join(shared, "..", filepath.Base(outside), "secret.txt"), codes.PermissionDenied},
+		{"denied", filepath.Join(shared, ".ssh"), codes.PermissionDenied},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			_, err := policy.resolve(tt.path)
+			st, _ := status.FromError(err)
+			assert.Equal(t, tt.code, st.Code())
+		})
+	}
+
+	if _, err := os.Lstat(filepath.Join(shared, "escape.txt")); err == nil {
+		_, err := policy.resolve(filepath.Join(shared, "escape.txt"))
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.PermissionDenied, st.Code(), "links must not lead out of the share")
+	}
+}
+
+func TestWithin(t *testing.T) {
+	dir := filepath.Join(string(filepath.Separator), "home", "user")
+
+	assert.True(t, within(dir, dir))
+	assert.True(t, within(filepath.Join(dir, "docs", "a.txt"), dir))
+	assert.True(t, within(filepath.Join(dir, "..hidden"), dir))
+	assert.False(t, within(filepath.Join(string(filepath.Separator), "home", "username"), dir))
+	assert.False(t, within(filepath.Join(string(filepath.Separator), "home"), dir))
+}
+
+func TestGRPCServer_ListDirectory_Real(t *testing.T) {
+	srv, _ := setupRealGRPCServer(t)
+	shared, _ := setupSharedDir(t)
+	srv.daemon.config.Settings.DownloadPath = shared
+
+	t.Run("visible entries", func(t *testing.T) {
+		resp, err := srv.ListDirectory(context.Background(), &pb.ListDirectoryRequest{Path: shared})
+		require.NoError(t, err)
+		assert.Equal(t, shared, resp.Path)
+
+		var names []string
+		for _, e := range resp.Entries {
+			names = append(names, e.Name)
+		}
+		assert.Equal(t, []string{"A folder", "b.txt"}, names)
+		assert.True(t, resp.Entries[0].IsDir)
+		assert.Equal(t, int64(5), resp.Entries[1].SizeBytes)
+		assert.True(t, resp.Entries[1].Readable)
+	})
+
+	t.Run("hidden entries", func(t *testing.T) {
+		resp, err := srv.ListDirectory(context.Background(), &pb.ListDirectoryRequest{Path: shared, IncludeHidden: true})
+		require.NoError(t, err)
+
+		var names []string
+		for _, e := range resp.Entries {
+			names = append(names, e.Name)
+		}
+		assert.Contains(t, names, ".hidden")
+		assert.Contains(t, names, ".ssh", "only the daemon user's own .ssh is denied")
+	})
+
+	t.Run("not a directory", func(t *testing.T) {
+		_, err := srv.ListDirectory(context.Background(), &pb.ListDirectoryRequest{Path: filepath.Join(shared, "b.txt")})
+		st, _ := status.FromError(err)
+		assert.Equal(t, codes.InvalidArgument, st.Code())
+	})
+}
+
+func TestGRPCServer_StatPath_Real(t *testing.T) {
+	srv, _ := setupRealGRPCServer(t)
+	shared, outside := setupSharedDir(t)
+	srv.daemon.config.Settings.DownloadPath = shared
+
+	entry, err := srv.StatPath(context.Background(), &pb.StatPathRequest{Path: filepath.Join(shared, "b.txt")})
+	require.NoError(t, err)
+	assert.Equal(t, "b.txt", entry.Name)
+	assert.False(t, entry.IsDir)
+	assert.True(t, entry.Readable)
+	assert.NotNil(t, entry.ModifiedAt)
+
+	_, err = srv.StatPath(context.Background(), &pb.StatPathRequest{Path: filepath.Join(outside, "secret.txt")})
+	st, _ := status.FromError(err)
+	assert.Equal(t, codes.PermissionDenied, st.Code())
+
+	_, err = srv.StatPath(context.Background(), &pb.StatPathRequest{})
+	st, _ = status.FromError(err)
+	assert.Equal(t, codes.InvalidArgument, st.Code())
+}
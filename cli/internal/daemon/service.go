@@ -111,42 +111,9 @@ func (s *DaemonService) Start(srv service.Service) error {
 		return err
 	}
 
-	// Setup Chat Callback for SSE
-	s.engine.SetOnChatMessage(func(msg chat.Message) {
-		// Broadcast to SSE clients
-		payload := map[string]interface{}{
-			"type": "chat_message",
-			"data": msg,
-		}
-		jsonBytes, err := json.Marshal(payload)
-		if err == nil {
-			s.broadcastSSE(string(jsonBytes))
-		}
-	})
-
-	// Setup Transfer Callbacks for SSE
-	s.engine.SetTransferCallbacks(func(session transfer.Session) {
-		payload := map[string]interface{}{
-			"type": "file_progress",
-			"data": session,
-		}
-		jsonBytes, err := json.Marshal(payload)
-		if err == nil {
-			s.broadcastSSE(string(jsonBytes))
-		}
-	}, func(req transfer.Request, senderID string) {
-		payload := map[string]interface{}{
-			"type": "file_request",
-			"data": map[string]interface{}{
-				"request":   req,
-				"sender_id": senderID,
-			},
-		}
-		jsonBytes, err := json.Marshal(payload)
-		if err == nil {
-			s.broadcastSSE(string(jsonBytes))
-		}
-	})
+	// Create the gRPC server first so engine events can reach its subscribers
+	s.grpcServer = NewGRPCServer(s, s.daemonVersion, s.buildDate, s.commit)
+	s.setupEngineCallbacks()
 
 	s.engine.Start()
 
@@ -170,7 +137,6 @@ func (s *DaemonService) Start(srv service.Service) error {
 	}()
 
 	// Start gRPC server
-	s.grpcServer = NewGRPCServer(s, s.daemonVersion, s.buildDate, s.commit)
 	if err := s.grpcServer.Start(ctx); err != nil {
 		s.logf.Error("Failed to start gRPC server: ", err.Error())
 		// Continue without gRPC - HTTP bridge still works
@@ -192,6 +158,58 @@ func (s *DaemonService) Start(srv service.Service) error {
 	return nil
 }
 
+// setupEngineCallbacks forwards engine events to SSE clients and gRPC subscribers.
+func (s *DaemonService) setupEngineCallbacks() {
+	// Setup Chat Callback for SSE
+	s.engine.SetOnChatMessage(func(msg chat.Message) {
+		// Broadcast to SSE clients
+		payload := map[string]interface{}{
+			"type": "chat_message",
+			"data": msg,
+		}
+		jsonBytes, err := json.Marshal(payload)
+		if err == nil {
+			s.broadcastSSE(string(jsonBytes))
+		}
+		s.grpcServer.BroadcastEvent(chatMessageEvent(msg))
+	})
+
+	// Setup Transfer Callbacks for SSE
+	s.engine.SetTransferCallbacks(func(session transfer.Session) {
+		payload := map[string]interface{}{
+			"type": "file_progress",
+			"data": session,
+		}
+		jsonBytes, err := json.Marshal(payload)
+		if err == nil {
+			s.broadcastSSE(string(jsonBytes))
+		}
+		s.grpcServer.BroadcastEvent(transferProgressEvent(session))
+	}, func(req transfer.Request, senderID string) {
+		payload := map[string]interface{}{
+			"type": "file_request",
+			"data": map[string]interface{}{
+				"request":   req,
+				"sender_id": senderID,
+			},
+		}
+		jsonBytes, err := json.Marshal(payload)
+		if err == nil {
+			s.broadcastSSE(string(jsonBytes))
+		}
+		s.grpcServer.BroadcastEvent(transferRequestEvent(req, senderID))
+	})
+
+	s.engine.SetOnPeerChange(func(peer api.PeerConfig, joined bool) {
+		s.grpcServer.BroadcastEvent(peerEvent(peer, joined))
+	})
+
+	s.engine.SetOnPeerKeyChange(func(peer api.PeerConfig) {
+		s.logf.Warningf("Peer %s presented a different public key", peer.ID)
+		s.grpcServer.BroadcastEvent(peerKeyChangedEvent(peer))
+	})
+}
+
 // Stop is called when the service is stopping.
 func (s *DaemonService) Stop(srk service.Service) error {
 	s.logf.Info("GoConnect Daemon stopping...")
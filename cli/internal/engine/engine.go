@@ -49,13 +49,16 @@ type Engine struct {
 	paused        bool
 
 	// State
-	peerMap  map[string]api.PeerConfig
-	networks []api.NetworkResponse
-	mu       sync.RWMutex
+	peerMap     map[string]api.PeerConfig
+	peersSynced bool
+	networks    []api.NetworkResponse
+	mu          sync.RWMutex
 
 	onChatMessage      func(chat.Message)
 	onTransferProgress func(transfer.Session)
 	onTransferRequest  func(transfer.Request, string)
+	onPeerChange       func(api.PeerConfig, bool)
+	onPeerKeyChange    func(api.PeerConfig)
 }
 
 // SetTransferCallbacks sets the callbacks for transfer events
@@ -69,6 +72,20 @@ func (e *Engine) SetOnChatMessage(handler func(chat.Message)) {
 	e.onChatMessage = handler
 }
 
+// SetOnPeerChange sets the callback for peers joining or leaving between config syncs
+func (e *Engine) SetOnPeerChange(handler func(peer api.PeerConfig, joined bool)) {
+	e.mu.Lock()
+	e.onPeerChange = handler
+	e.mu.Unlock()
+}
+
+// SetOnPeerKeyChange sets the callback for a known peer presenting a different public key
+func (e *Engine) SetOnPeerKeyChange(handler func(peer api.PeerConfig)) {
+	e.mu.Lock()
+	e.onPeerKeyChange = handler
+	e.mu.Unlock()
+}
+
 // NewEngine creates a new Engine instance
 func NewEngine(cfg *config.Config, idMgr *identity.Manager, wgClient WireGuardClient, apiClient *api.Client, logger service.Logger) (*Engine, error) {
 	sysConf := system.NewConfigurator()
@@ -82,6 +99,7 @@ func NewEngine(cfg *config.Config, idMgr *identity.Manager, wgClient WireGuardCl
 
 	// Initialize Transfer Manager
 	transferMgr := transfer.NewManager()
+	transferMgr.SetRateLimits(cfg.Settings.MaxUploadSpeedKbps, cfg.Settings.MaxDownloadSpeedKbps)
 
 	// Initialize Voice Manager
 	voiceMgr := voice.NewManager()
@@ -226,15 +244,47 @@ func (e *Engine) syncConfig() {
 		e.mu.Unlock()
 	}
 
-	// Update peer map
+	// Update peer map, noting who joined, left or changed keys since the last sync
+	var joined, left, rekeyed []api.PeerConfig
 	e.mu.Lock()
+	peers := make(map[string]api.PeerConfig, len(config.Peers))
 	for _, peer := range config.Peers {
-		if peer.ID != "" {
-			e.peerMap[peer.ID] = peer
+		if peer.ID == "" {
+			continue
+		}
+		peers[peer.ID] = peer
+		if known, ok := e.peerMap[peer.ID]; !ok {
+			joined = append(joined, peer)
+		} else if known.PublicKey != "" && known.PublicKey != peer.PublicKey {
+			rekeyed = append(rekeyed, peer)
+		}
+	}
+	for peerID, peer := range e.peerMap {
+		if _, ok := peers[peerID]; !ok {
+			left = append(left, peer)
 		}
 	}
+	e.peerMap = peers
+	// The first sync only learns who is already there
+	notify := e.peersSynced && e.onPeerChange != nil
+	onPeerChange, onPeerKeyChange := e.onPeerChange, e.onPeerKeyChange
+	e.peersSynced = true
 	e.mu.Unlock()
 
+	if notify {
+		for _, peer := range joined {
+			onPeerChange(peer, true)
+		}
+		for _, peer := range left {
+			onPeerChange(peer, false)
+		}
+	}
+	if onPeerKeyChange != nil {
+		for _, peer := range rekeyed {
+			onPeerKeyChange(peer)
+		}
+	}
+
 	// Trigger P2P connections
 	if e.config.P2P.Enabled {
 		for _, peer := range config.Peers {
@@ -340,6 +390,7 @@ func (e *Engine) syncConfig() {
 				// Start Transfer Listener
 				e.transferMgr.Stop()
 				e.transferMgr = transfer.NewManager()
+				e.transferMgr.SetRateLimits(e.config.Settings.MaxUploadSpeedKbps, e.config.Settings.MaxDownloadSpeedKbps)
 				e.transferMgr.SetCallbacks(func(s transfer.Session) {
 					if e.onTransferProgress != nil {
 						e.onTransferProgress(s)
@@ -370,6 +421,11 @@ func (e *Engine) syncConfig() {
 	}
 }
 
+// ServerTime returns the coordination server's clock as last seen, advanced to now
+func (e *Engine) ServerTime() (time.Time, bool) {
+	return e.apiClient.ServerTime()
+}
+
 // GetStatus returns the current engine status
 func (e *Engine) GetStatus() map[string]interface{} {
 	status := map[string]interface{}{
@@ -532,6 +588,7 @@ func (e *Engine) SendFileRequest(peerID, filePath string) (*transfer.Session, er
 		ID:       session.ID,
 		FileName: session.FileName,
 		FileSize: session.FileSize,
+		SHA256:   session.SHA256,
 	}
 	reqBytes, _ := json.Marshal(req)
 	if err := e.SendChatMessage(peerID, string(reqBytes)); err != nil {
@@ -698,6 +755,13 @@ func (e *Engine) GenerateInvite(networkID string, maxUses int, expiresHours int)
 	return e.apiClient.GenerateInvite(ctx, networkID, maxUses, expiresHours)
 }
 
+// PreviewInvite checks an invite code without joining its network
+func (e *Engine) PreviewInvite(inviteCode string) (*api.InviteValidation, error) {
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+	defer cancel()
+	return e.apiClient.ValidateInvite(ctx, inviteCode)
+}
+
 // KickPeer removes a peer from a network
 func (e *Engine) KickPeer(networkID, peerID, reason string) error {
 	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
@@ -719,6 +783,56 @@ func (e *Engine) UnbanPeer(networkID, peerID string) error {
 	return e.apiClient.UnbanPeer(ctx, networkID, peerID)
 }
 
+// TransferOwnership makes another member the owner of a network
+func (e *Engine) TransferOwnership(networkID, newOwnerID string) error {
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+	defer cancel()
+
+	if err := e.apiClient.TransferOwnership(ctx, networkID, newOwnerID); err != nil {
+		return err
+	}
+
+	// Our role changed; refresh the cached networks
+	go e.syncConfig()
+
+	return nil
+}
+
+// ReportPeer reports a peer to the network's admins, quoting the given chat messages.
+// Only messages still in the local history can be quoted.
+func (e *Engine) ReportPeer(networkID, peerID, reason string, messageIDs []string) error {
+	var quoted []api.ReportedMessage
+	seen := make(map[string]bool, len(messageIDs))
+	for _, id := range messageIDs {
+		if seen[id] {
+			continue
+		}
+		seen[id] = true
+
+		msg, ok := e.chatMgr.GetMessage(id)
+		if !ok || msg.NetworkID != networkID {
+			continue
+		}
+		quoted = append(quoted, api.ReportedMessage{
+			ID:      msg.ID,
+			From:    msg.From,
+			Content: msg.Content,
+			SentAt:  msg.Time,
+		})
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+	defer cancel()
+	return e.apiClient.ReportPeer(ctx, networkID, peerID, reason, quoted)
+}
+
+// ListJoinRequests returns the requests waiting for approval to join a network
+func (e *Engine) ListJoinRequests(networkID string) ([]api.JoinRequest, error) {
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+	defer cancel()
+	return e.apiClient.ListJoinRequests(ctx, networkID)
+}
+
 // =============================================================================
 // CHAT MANAGEMENT METHODS
 // =============================================================================
@@ -767,6 +881,16 @@ func (e *Engine) CancelTransfer(transferID string) error {
 	return e.transferMgr.CancelTransfer(transferID)
 }
 
+// SetTransferRateLimits caps the speed of all transfers in kilobits per second (0 = unlimited)
+func (e *Engine) SetTransferRateLimits(uploadKbps, downloadKbps int) {
+	e.transferMgr.SetRateLimits(uploadKbps, downloadKbps)
+}
+
+// SetTransferRateLimit caps one transfer's speed, overriding the limits for all transfers
+func (e *Engine) SetTransferRateLimit(transferID string, kbps int) error {
+	return e.transferMgr.SetSessionRateLimit(transferID, kbps)
+}
+
 // =============================================================================
 // VOICE SIGNALING METHODS
 // =============================================================================
@@ -10,6 +10,7 @@ import (
 	"os"
 	"path/filepath"
 	"strings"
+	"sync"
 	"testing"
 	"time"
 
@@ -960,6 +961,97 @@ func TestEngine_SyncConfig_EmptyPeerID(t *testing.T) {
 	assert.False(t, exists, "Empty ID peer should not be in map")
 }
 
+func TestEngine_SyncConfig_PeerChanges(t *testing.T) {
+	var mu sync.Mutex
+	peers := []api.PeerConfig{
+		{ID: "peer-1", Name: "One"},
+		{ID: "peer-2", Name: "Two"},
+	}
+	apiHandler := func(w http.ResponseWriter, r *http.Request) {
+		if strings.Contains(r.URL.Path, "/config") {
+			mu.Lock()
+			defer mu.Unlock()
+			_ = json.NewEncoder(w).Encode(api.DeviceConfig{Peers: peers})
+			return
+		}
+		if r.URL.Path == "/v1/networks" {
+			_ = json.NewEncoder(w).Encode([]api.NetworkResponse{})
+			return
+		}
+	}
+
+	eng, server, _ := setupTestEngine(t, apiHandler)
+	defer server.Close()
+	eng.config.P2P.Enabled = false
+	_ = eng.idMgr.Update("device-test-id")
+
+	var joined, left []string
+	eng.SetOnPeerChange(func(peer api.PeerConfig, isJoin bool) {
+		if isJoin {
+			joined = append(joined, peer.ID)
+		} else {
+			left = append(left, peer.ID)
+		}
+	})
+
+	// The first sync reports nobody
+	eng.syncConfig()
+	assert.Empty(t, joined)
+	assert.Empty(t, left)
+
+	mu.Lock()
+	peers = []api.PeerConfig{
+		{ID: "peer-2", Name: "Two"},
+		{ID: "peer-3", Name: "Three"},
+	}
+	mu.Unlock()
+	eng.syncConfig()
+	assert.Equal(t, []string{"peer-3"}, joined)
+	assert.Equal(t, []string{"peer-1"}, left)
+
+	_, ok := eng.GetPeerByID("peer-1")
+	assert.False(t, ok, "peers that left should be forgotten")
+}
+
+func TestEngine_SyncConfig_PeerKeyChange(t *testing.T) {
+	var mu sync.Mutex
+	publicKey := "key-1"
+	apiHandler := func(w http.ResponseWriter, r *http.Request) {
+		if strings.Contains(r.URL.Path, "/config") {
+			mu.Lock()
+			defer mu.Unlock()
+			_ = json.NewEncoder(w).Encode(api.DeviceConfig{
+				Peers: []api.PeerConfig{{ID: "peer-1", Name: "One", PublicKey: publicKey}},
+			})
+			return
+		}
+		if r.URL.Path == "/v1/networks" {
+			_ = json.NewEncoder(w).Encode([]api.NetworkResponse{})
+			return
+		}
+	}
+
+	eng, server, _ := setupTestEngine(t, apiHandler)
+	defer server.Close()
+	eng.config.P2P.Enabled = false
+	_ = eng.idMgr.Update("device-test-id")
+
+	var rekeyed []string
+	eng.SetOnPeerKeyChange(func(peer api.PeerConfig) {
+		rekeyed = append(rekeyed, peer.PublicKey)
+	})
+
+	eng.syncConfig()
+	eng.syncConfig()
+	assert.Empty(t, rekeyed, "an unchanged key is not reported")
+
+	mu.Lock()
+	publicKey = "key-2"
+	mu.Unlock()
+	eng.syncConfig()
+	assert.Equal(t, []string{"key-2"}, rekeyed)
+}
+
 // ==================== ManualConnect Tests ====================
 
 func TestEngine_ManualConnect_Success(t *testing.T) {
@@ -2245,3 +2337,40 @@ func TestEngine_Connect_TriggersSync(t *testing.T) {
 	assert.False(t, eng.paused)
 	assert.True(t, syncCalled)
 }
+
+func TestEngine_ReportPeer_QuotesMessagesByID(t *testing.T) {
+	var body struct {
+		PeerID   string                `json:"peer_id"`
+		Messages []api.ReportedMessage `json:"messages"`
+	}
+	eng, server, tmpDir := setupTestEngine(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path == "/v1/networks/net-1/reports" {
+			_ = json.NewDecoder(r.Body).Decode(&body)
+			w.WriteHeader(http.StatusOK)
+			return
+		}
+		w.WriteHeader(http.StatusNotFound)
+	})
+	defer server.Close()
+
+	chatMgr, err := chat.NewManagerWithStorage(tmpDir)
+	require.NoError(t, err)
+	eng.chatMgr = chatMgr
+	store := chatMgr.GetStorage()
+
+	// The first message is further back than one page of history
+	base := time.Now().Add(-time.Hour)
+	for i := 0; i < 150; i++ {
+		require.NoError(t, store.SaveMessage(chat.Message{ID: fmt.Sprintf("msg-%d", i), From: "peer-2", Content: fmt.Sprintf("spam %d", i), NetworkID: "net-1", Time: base.Add(time.Duration(i) * time.Second)}))
+	}
+	require.NoError(t, store.SaveMessage(chat.Message{ID: "other-net", From: "peer-2", Content: "elsewhere", NetworkID: "net-2", Time: time.Now()}))
+
+	err = eng.ReportPeer("net-1", "peer-2", "spam", []string{"msg-0", "msg-149", "msg-0", "other-net", "missing"})
+	require.NoError(t, err)
+
+	assert.Equal(t, "peer-2", body.PeerID)
+	require.Len(t, body.Messages, 2)
+	assert.Equal(t, "msg-0", body.Messages[0].ID)
+	assert.Equal(t, "spam 0", body.Messages[0].Content)
+	assert.Equal(t, "msg-149", body.Messages[1].ID)
+}
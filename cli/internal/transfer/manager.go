@@ -1,6 +1,8 @@
 package transfer
 
 import (
+	"crypto/sha256"
+	"encoding/hex"
 	"encoding/json"
 	"fmt"
 	"io"
@@ -35,6 +37,10 @@ type Manager struct {
 	// Subscribers for real-time updates
 	subscribers   map[chan Session]struct{}
 	subscribersMu sync.RWMutex
+
+	// Speed caps for all transfers in kilobits per second (0 = unlimited)
+	uploadKbps   int
+	downloadKbps int
 }
 
 type pendingRequest struct {
@@ -134,6 +140,7 @@ func (m *Manager) sendFileData(conn net.Conn, session *Session) {
 
 	m.updateStatus(session.ID, StatusInProgress)
 
+	var pace pacer
 	buf := make([]byte, ChunkSize)
 	for {
 		n, err := file.Read(buf)
@@ -155,11 +162,85 @@ func (m *Manager) sendFileData(conn net.Conn, session *Session) {
 		session.SentBytes += int64(n)
 		m.mu.Unlock()
 		m.notifyProgress(session.ID)
+		pace.wait(n, m.rateLimit(session))
 	}
 
 	m.updateStatus(session.ID, StatusCompleted)
 }
 
+// SetRateLimits caps the speed of all uploads and downloads in kilobits per
+// second. 0 means unlimited.
+func (m *Manager) SetRateLimits(uploadKbps, downloadKbps int) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.uploadKbps = uploadKbps
+	m.downloadKbps = downloadKbps
+}
+
+// SetSessionRateLimit caps one transfer's speed in kilobits per second,
+// overriding the limits for all transfers. 0 goes back to those limits.
+func (m *Manager) SetSessionRateLimit(id string, kbps int) error {
+	if kbps < 0 {
+		return fmt.Errorf("rate limit cannot be negative")
+	}
+
+	m.mu.Lock()
+	session, ok := m.sessions[id]
+	if ok {
+		session.MaxSpeedKbps = kbps
+	}
+	m.mu.Unlock()
+
+	if !ok {
+		return fmt.Errorf("session not found")
+	}
+	m.notifyProgress(id)
+	return nil
+}
+
+// rateLimit returns the speed cap in bytes per second that applies to a
+// session, or 0 when it is unlimited
+func (m *Manager) rateLimit(session *Session) int64 {
+	m.mu.RLock()
+	defer m.mu.RUnlock()
+
+	kbps := session.MaxSpeedKbps
+	if kbps == 0 {
+		if session.IsSender {
+			kbps = m.uploadKbps
+		} else {
+			kbps = m.downloadKbps
+		}
+	}
+	return int64(kbps) * 1000 / 8
+}
+
+// pacer keeps a transfer under its speed cap by sleeping between chunks
+type pacer struct {
+	limit int64 // Bytes per second
+	start time.Time
+	bytes int64
+}
+
+// wait accounts for n more bytes and sleeps until they fit under limit. A new
+// limit restarts the measurement so it takes effect straight away.
+func (p *pacer) wait(n int, limit int64) {
+	if limit != p.limit || p.start.IsZero() {
+		p.limit = limit
+		p.start = time.Now()
+		p.bytes = 0
+	}
+	if limit <= 0 {
+		return
+	}
+
+	p.bytes += int64(n)
+	due := time.Duration(float64(p.bytes) / float64(limit) * float64(time.Second))
+	if ahead := due - time.Since(p.start); ahead > 0 {
+		time.Sleep(ahead)
+	}
+}
+
 // CreateSendSession creates a new session for sending a file
 func (m *Manager) CreateSendSession(peerID, filePath string) (*Session, error) {
 	// Validate file path
@@ -172,6 +253,11 @@ func (m *Manager) CreateSendSession(peerID, filePath string) (*Session, error) {
 		return nil, fmt.Errorf("cannot transfer directories")
 	}
 
+	digest, err := fileSHA256(cleanPath)
+	if err != nil {
+		return nil, err
+	}
+
 	id := uuid.New().String()
 	session := &Session{
 		ID:        id,
@@ -182,6 +268,7 @@ func (m *Manager) CreateSendSession(peerID, filePath string) (*Session, error) {
 		Status:    StatusPending,
 		IsSender:  true,
 		StartTime: time.Now(),
+		SHA256:    digest,
 	}
 
 	m.mu.Lock()
@@ -191,6 +278,21 @@ func (m *Manager) CreateSendSession(peerID, filePath string) (*Session, error) {
 	return session, nil
 }
 
+// fileSHA256 returns the hex SHA-256 digest of a file's contents
+func fileSHA256(path string) (string, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return "", err
+	}
+	defer file.Close()
+
+	h := sha256.New()
+	if _, err := io.Copy(h, file); err != nil {
+		return "", err
+	}
+	return hex.EncodeToString(h.Sum(nil)), nil
+}
+
 // CreateReceiveSession creates a new session for receiving a file
 func (m *Manager) CreateReceiveSession(req Request, peerID, savePath string) (*Session, error) {
 	// Validate save path
@@ -218,6 +320,7 @@ func (m *Manager) CreateReceiveSession(req Request, peerID, savePath string) (*S
 		Status:    StatusPending,
 		IsSender:  false,
 		StartTime: time.Now(),
+		SHA256:    req.SHA256,
 	}
 
 	m.mu.Lock()
@@ -264,6 +367,7 @@ func (m *Manager) StartDownload(sessionID string, senderIP string) error {
 		m.updateStatus(sessionID, StatusInProgress)
 
 		// Read loop
+		var pace pacer
 		buf := make([]byte, ChunkSize)
 		for {
 			n, err := conn.Read(buf)
@@ -284,6 +388,7 @@ func (m *Manager) StartDownload(sessionID string, senderIP string) error {
 			session.SentBytes += int64(n)
 			m.mu.Unlock()
 			m.notifyProgress(sessionID)
+			pace.wait(n, m.rateLimit(session))
 		}
 
 		// Verify size
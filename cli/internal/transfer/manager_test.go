@@ -56,6 +56,10 @@ func TestCreateSendSession(t *testing.T) {
 	if session.Status != StatusPending {
 		t.Errorf("Expected Status to be Pending, got %s", session.Status)
 	}
+	// sha256("hello world")
+	if session.SHA256 != "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9" {
+		t.Errorf("Expected SHA256 of the file contents, got %s", session.SHA256)
+	}
 }
 
 func TestCreateReceiveSession(t *testing.T) {
@@ -1822,3 +1826,45 @@ func TestCleanupOld_NoEndTime(t *testing.T) {
 	removed := tm.CleanupOld(0) // 0 duration means everything old should be removed
 	assert.Equal(t, 0, removed, "Session without EndTime should not be cleaned")
 }
+
+// ==================== Rate Limits ====================
+
+func TestSetSessionRateLimit(t *testing.T) {
+	tm := NewManager()
+	tm.SetRateLimits(800, 1600)
+
+	tm.mu.Lock()
+	tm.sessions["up"] = &Session{ID: "up", IsSender: true, Status: StatusInProgress}
+	tm.sessions["down"] = &Session{ID: "down", IsSender: false, Status: StatusInProgress}
+	tm.mu.Unlock()
+
+	// 800 kbit/s = 100000 bytes/s
+	assert.Equal(t, int64(100000), tm.rateLimit(tm.GetSession("up")))
+	assert.Equal(t, int64(200000), tm.rateLimit(tm.GetSession("down")))
+
+	require.NoError(t, tm.SetSessionRateLimit("up", 80))
+	assert.Equal(t, 80, tm.GetSession("up").MaxSpeedKbps)
+	assert.Equal(t, int64(10000), tm.rateLimit(tm.GetSession("up")))
+
+	require.NoError(t, tm.SetSessionRateLimit("up", 0))
+	assert.Equal(t, int64(100000), tm.rateLimit(tm.GetSession("up")))
+
+	assert.Error(t, tm.SetSessionRateLimit("missing", 80))
+	assert.Error(t, tm.SetSessionRateLimit("up", -1))
+}
+
+func TestPacer(t *testing.T) {
+	var p pacer
+
+	start := time.Now()
+	p.wait(1000, 0)
+	p.wait(1000, 0)
+	assert.Less(t, time.Since(start), 50*time.Millisecond, "unlimited transfers should not wait")
+
+	// 10000 bytes at 100000 bytes/s takes at least 100ms
+	start = time.Now()
+	for i := 0; i < 10; i++ {
+		p.wait(1000, 100000)
+	}
+	assert.GreaterOrEqual(t, time.Since(start), 90*time.Millisecond)
+}
@@ -26,6 +26,10 @@ type Session struct {
 	StartTime time.Time `json:"start_time"`
 	EndTime   time.Time `json:"end_time"`
 	Error     string    `json:"error,omitempty"`
+	// Hex SHA-256 of the sender's file, taken when the transfer is offered
+	SHA256 string `json:"sha256,omitempty"`
+	// Speed cap for this transfer alone in kilobits per second; 0 = the manager-wide limit
+	MaxSpeedKbps int `json:"max_speed_kbps,omitempty"`
 }
 
 // Progress returns the transfer progress as a percentage (0-100)
@@ -78,6 +82,7 @@ type Request struct {
 	ID       string `json:"id"`
 	FileName string `json:"file_name"`
 	FileSize int64  `json:"file_size"`
+	SHA256   string `json:"sha256,omitempty"`
 }
 
 // ListOptions configures how sessions are listed
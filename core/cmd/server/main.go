@@ -214,6 +214,7 @@ type Repositories struct {
 	Network         repository.NetworkRepository
 	Membership      repository.MembershipRepository
 	JoinRequest     repository.JoinRequestRepository
+	PeerReport      repository.PeerReportRepository
 	Device          repository.DeviceRepository
 	Peer            repository.PeerRepository
 	Chat            repository.ChatRepository
@@ -283,6 +284,7 @@ func initRepositories(db *sql.DB, cfg *config.Config) *Repositories {
 			Network:         repository.NewSQLiteNetworkRepository(db),
 			Membership:      repository.NewSQLiteMembershipRepository(db),
 			JoinRequest:     repository.NewSQLiteJoinRequestRepository(db),
+			PeerReport:      repository.NewSQLitePeerReportRepository(db),
 			Device:          repository.NewSQLiteDeviceRepository(db),
 			Peer:            repository.NewSQLitePeerRepository(db),
 			Chat:            repository.NewSQLiteChatRepository(db),
@@ -307,6 +309,7 @@ func initRepositories(db *sql.DB, cfg *config.Config) *Repositories {
 		Network:         repository.NewPostgresNetworkRepository(db),
 		Membership:      repository.NewPostgresMembershipRepository(db),
 		JoinRequest:     repository.NewPostgresJoinRequestRepository(db),
+		PeerReport:      repository.NewPostgresPeerReportRepository(db),
 		Device:          repository.NewPostgresDeviceRepository(db),
 		Peer:            repository.NewPostgresPeerRepository(db),
 		Chat:            repository.NewPostgresChatRepository(db),
@@ -352,6 +355,8 @@ func initServices(repos *Repositories, cfg *config.Config) (*Services, audit.Aud
 	networkService := service.NewNetworkService(repos.Network, repos.Idempotency)
 	membershipService := service.NewMembershipService(repos.Network, repos.Membership, repos.JoinRequest, repos.Idempotency)
 	membershipService.SetInviteTokenRepository(repos.InviteToken)
+	membershipService.SetPeerReportRepository(repos.PeerReport)
+	membershipService.SetUserRepository(repos.User)
 	deviceService := service.NewDeviceService(repos.Device, repos.User, repos.Peer, repos.Network, cfg.WireGuard)
 	peerService := service.NewPeerService(repos.Peer, repos.Device, repos.Network)
 	chatService := service.NewChatService(repos.Chat, repos.User)
@@ -361,6 +366,7 @@ func initServices(repos *Repositories, cfg *config.Config) (*Services, audit.Aud
 	baseURL := buildBaseURL(cfg)
 	inviteService := service.NewInviteService(repos.InviteToken, repos.Network, repos.Membership, baseURL)
 	inviteService.SetAuditor(auditor)
+	inviteService.SetPreviewRepositories(repos.Peer, repos.User)
 	adminService := service.NewAdminService(repos.User, repos.Admin, repos.Tenant, repos.Network, repos.Device, repos.Chat, auditor, redisClient, func() int { return 0 })
 	gdprService := service.NewGDPRService(repos.User, repos.Device, repos.Network, repos.Membership, repos.DeletionRequest)
 	postService := service.NewPostService(repos.Post, repos.User)
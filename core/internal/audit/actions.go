@@ -3,15 +3,17 @@ package audit
 // Action constants centralize audit action names to avoid typos.
 // NOTE: Do not log PII in details; actor/object are redacted downstream.
 const (
-	ActionNetworkCreated     = "NETWORK_CREATED"
-	ActionNetworkUpdated     = "NETWORK_UPDATED"
-	ActionNetworkDeleted     = "NETWORK_DELETED"
-	ActionNetworkJoinApprove = "NETWORK_JOIN_APPROVE"
-	ActionNetworkMemberBan   = "NETWORK_MEMBER_BAN"
-	ActionNetworkJoin        = "NETWORK_JOIN"
-	ActionNetworkJoinRequest = "NETWORK_JOIN_REQUEST"
-	ActionNetworkJoinDeny    = "NETWORK_JOIN_DENY"
-	ActionNetworkMemberKick  = "NETWORK_MEMBER_KICK"
-	ActionIPAllocated        = "IP_ALLOCATED"
-	ActionIPReleased         = "IP_RELEASED"
+	ActionNetworkCreated       = "NETWORK_CREATED"
+	ActionNetworkUpdated       = "NETWORK_UPDATED"
+	ActionNetworkDeleted       = "NETWORK_DELETED"
+	ActionNetworkJoinApprove   = "NETWORK_JOIN_APPROVE"
+	ActionNetworkMemberBan     = "NETWORK_MEMBER_BAN"
+	ActionNetworkJoin          = "NETWORK_JOIN"
+	ActionNetworkJoinRequest   = "NETWORK_JOIN_REQUEST"
+	ActionNetworkJoinDeny      = "NETWORK_JOIN_DENY"
+	ActionNetworkMemberKick    = "NETWORK_MEMBER_KICK"
+	ActionNetworkOwnerTransfer = "NETWORK_OWNER_TRANSFER"
+	ActionNetworkPeerReport    = "NETWORK_PEER_REPORT"
+	ActionIPAllocated          = "IP_ALLOCATED"
+	ActionIPReleased           = "IP_RELEASED"
 )
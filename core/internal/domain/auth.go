@@ -97,6 +97,18 @@ func (u *User) Sanitize() *User {
 	}
 }
 
+// DisplayName returns the name to show other users: the full name, else the username.
+// The email is never used, so it is empty when the user has set neither.
+func (u *User) DisplayName() string {
+	if u.FullName != nil && *u.FullName != "" {
+		return *u.FullName
+	}
+	if u.Username != nil {
+		return *u.Username
+	}
+	return ""
+}
+
 // Enable2FARequest is the request body for enabling 2FA
 type Enable2FARequest struct {
 	Secret string `json:"secret" binding:"required"`
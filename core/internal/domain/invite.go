@@ -41,6 +41,16 @@ type InviteTokenResponse struct {
 	IsActive  bool      `json:"is_active"`
 }
 
+// InvitePreview describes the network behind a valid invite to someone who has not joined yet
+type InvitePreview struct {
+	Token       *InviteToken
+	NetworkName string
+	MemberCount int
+	OnlineCount int // Active peers
+	OwnerID     string
+	OwnerName   string // Empty when the owner has set no name
+}
+
 // IsValid checks if the invite token is still valid
 func (t *InviteToken) IsValid() bool {
 	if t.RevokedAt != nil {
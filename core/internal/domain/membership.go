@@ -42,6 +42,32 @@ type JoinRequest struct {
 	Status    string     `json:"status" db:"status"` // pending|approved|denied
 	CreatedAt time.Time  `json:"created_at" db:"created_at"`
 	DecidedAt *time.Time `json:"decided_at,omitempty" db:"decided_at"`
+	UserName  string     `json:"user_name,omitempty" db:"-"`
+}
+
+// ReportedMessage is a chat message quoted as evidence in a peer report
+type ReportedMessage struct {
+	ID      string    `json:"id" binding:"required"`
+	From    string    `json:"from"`
+	Content string    `json:"content"`
+	SentAt  time.Time `json:"sent_at"`
+}
+
+// PeerReport represents peer_reports table: a member's report about another member, for the network's admins
+type PeerReport struct {
+	ID         string    `json:"id" db:"id"`
+	NetworkID  string    `json:"network_id" db:"network_id"`
+	ReporterID string    `json:"reporter_id" db:"reporter_id"`
+	TargetID   string    `json:"target_id" db:"target_id"`
+	Reason     string    `json:"reason" db:"reason"`
+	CreatedAt  time.Time `json:"created_at" db:"created_at"`
+	// Quoted by the reporter's client from its local chat history; the server cannot check them
+	UnverifiedMessages []ReportedMessage `json:"unverified_messages" db:"messages"`
+}
+
+// GeneratePeerReportID generates a new ID for peer reports
+func GeneratePeerReportID() string {
+	return "rpt_" + GenerateNetworkID()[4:] // reuse network ID generator, change prefix
 }
 
 // ListMembersRequest filters
@@ -169,17 +169,17 @@ func (h *InviteHandler) RevokeInvite(c *gin.Context) {
 
 // ValidateInvite handles GET /v1/invites/:token/validate
 // @Summary Validate invite token
-// @Description Validate an invite token without using it (public endpoint)
+// @Description Validate an invite token without using it and describe the network it leads to (public endpoint)
 // @Tags Invites
 // @Produce json
 // @Param token path string true "Invite Token"
-// @Success 200 {object} object{valid:bool,network_id:string,network_name:string}
+// @Success 200 {object} object{valid:bool,network_id:string,network_name:string,member_count:int,online_count:int,owner_id:string,owner_name:string,expires_at:string,uses_max:int,uses_left:int}
 // @Failure 400 {object} domain.Error
 // @Router /v1/invites/{token}/validate [get]
 func (h *InviteHandler) ValidateInvite(c *gin.Context) {
 	tokenStr := c.Param("token")
 
-	token, err := h.inviteService.ValidateInvite(c.Request.Context(), tokenStr)
+	preview, err := h.inviteService.PreviewInvite(c.Request.Context(), tokenStr)
 	if err != nil {
 		// For invalid tokens, return a generic response
 		c.JSON(http.StatusOK, gin.H{
@@ -189,9 +189,16 @@ func (h *InviteHandler) ValidateInvite(c *gin.Context) {
 	}
 
 	c.JSON(http.StatusOK, gin.H{
-		"valid":      true,
-		"network_id": token.NetworkID,
-		"expires_at": token.ExpiresAt,
+		"valid":        true,
+		"network_id":   preview.Token.NetworkID,
+		"network_name": preview.NetworkName,
+		"member_count": preview.MemberCount,
+		"online_count": preview.OnlineCount,
+		"owner_id":     preview.OwnerID,
+		"owner_name":   preview.OwnerName,
+		"expires_at":   preview.Token.ExpiresAt,
+		"uses_max":     preview.Token.UsesMax,
+		"uses_left":    preview.Token.UsesLeft,
 	})
 }
 
@@ -410,9 +410,9 @@ func TestInviteHandler_ValidateInvite(t *testing.T) {
 
 		var response map[string]interface{}
 		require.NoError(t, json.Unmarshal(w.Body.Bytes(), &response))
-		// Validation may succeed or fail depending on implementation
-		// Just check we get a response
-		assert.NotNil(t, response["valid"])
+		assert.Equal(t, true, response["valid"])
+		assert.Equal(t, "Test Network", response["network_name"])
+		assert.Equal(t, float64(0), response["member_count"])
 	})
 
 	t.Run("Invalid Token", func(t *testing.T) {
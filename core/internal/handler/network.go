@@ -279,6 +279,9 @@ func RegisterNetworkRoutes(r *gin.Engine, handler *NetworkHandler, authService T
 	networks.POST("/:id/config", rl, handler.GenerateConfig)
 
 	networks.POST("/:id/ban", rl, RequireNetworkAdmin(), handler.Ban)
+	networks.POST("/:id/transfer-ownership", rl, RequireNetworkAdmin(), handler.TransferOwnership)
+	networks.POST("/:id/reports", rl, handler.ReportPeer)
+	networks.GET("/:id/reports", RequireNetworkAdmin(), handler.ListPeerReports)
 	networks.GET("/:id/members", handler.ListMembers)
 	networks.GET("/:id/join-requests", RequireNetworkAdmin(), handler.ListJoinRequests)
 }
@@ -456,6 +459,74 @@ func (h *NetworkHandler) Ban(c *gin.Context) {
 	c.JSON(http.StatusOK, gin.H{"ok": true})
 }
 
+// TransferOwnership handles POST /v1/networks/:id/transfer-ownership (owner only)
+func (h *NetworkHandler) TransferOwnership(c *gin.Context) {
+	networkID := c.Param("id")
+	actor := c.MustGet("user_id").(string)
+	tenantID := c.MustGet("tenant_id").(string)
+	if c.GetHeader("Idempotency-Key") == "" {
+		slog.Warn("TransferOwnership: Idempotency-Key header is required", "network_id", networkID, "actor_id", actor)
+		errorResponse(c, domain.NewError(domain.ErrInvalidRequest, "Idempotency-Key header is required for mutation operations", map[string]string{"required_header": "Idempotency-Key"}))
+		return
+	}
+	var body struct {
+		NewOwnerID string `json:"new_owner_id" binding:"required"`
+	}
+	if err := c.ShouldBindJSON(&body); err != nil {
+		slog.Warn("TransferOwnership: Invalid body", "error", err, "network_id", networkID, "actor_id", actor)
+		errorResponse(c, domain.NewError(domain.ErrInvalidRequest, "Invalid body", nil))
+		return
+	}
+	if err := h.memberService.TransferOwnership(c.Request.Context(), networkID, body.NewOwnerID, actor, tenantID); err != nil {
+		var derr *domain.Error
+		if errors.As(err, &derr) {
+			slog.Error("TransferOwnership: Service error", "error", derr, "network_id", networkID, "new_owner_id", body.NewOwnerID, "actor_id", actor, "tenant_id", tenantID)
+			errorResponse(c, derr)
+			return
+		}
+		slog.Error("TransferOwnership: Internal server error", "error", err, "network_id", networkID, "new_owner_id", body.NewOwnerID, "actor_id", actor, "tenant_id", tenantID)
+		errorResponse(c, domain.NewError(domain.ErrInternalServer, "Internal server error", nil))
+		return
+	}
+	slog.Info("Network ownership transferred", "network_id", networkID, "new_owner_id", body.NewOwnerID, "actor_id", actor, "tenant_id", tenantID)
+	c.JSON(http.StatusOK, gin.H{"ok": true})
+}
+
+// ReportPeer handles POST /v1/networks/:id/reports
+func (h *NetworkHandler) ReportPeer(c *gin.Context) {
+	networkID := c.Param("id")
+	actor := c.MustGet("user_id").(string)
+	tenantID := c.MustGet("tenant_id").(string)
+	if c.GetHeader("Idempotency-Key") == "" {
+		slog.Warn("ReportPeer: Idempotency-Key header is required", "network_id", networkID, "actor_id", actor)
+		errorResponse(c, domain.NewError(domain.ErrInvalidRequest, "Idempotency-Key header is required for mutation operations", map[string]string{"required_header": "Idempotency-Key"}))
+		return
+	}
+	var body struct {
+		PeerID   string                   `json:"peer_id" binding:"required"`
+		Reason   string                   `json:"reason"`
+		Messages []domain.ReportedMessage `json:"messages" binding:"dive"`
+	}
+	if err := c.ShouldBindJSON(&body); err != nil {
+		slog.Warn("ReportPeer: Invalid body", "error", err, "network_id", networkID, "actor_id", actor)
+		errorResponse(c, domain.NewError(domain.ErrInvalidRequest, "Invalid body", nil))
+		return
+	}
+	if err := h.memberService.ReportPeer(c.Request.Context(), networkID, body.PeerID, actor, tenantID, body.Reason, body.Messages); err != nil {
+		var derr *domain.Error
+		if errors.As(err, &derr) {
+			slog.Error("ReportPeer: Service error", "error", derr, "network_id", networkID, "target_user_id", body.PeerID, "actor_id", actor, "tenant_id", tenantID)
+			errorResponse(c, derr)
+			return
+		}
+		slog.Error("ReportPeer: Internal server error", "error", err, "network_id", networkID, "target_user_id", body.PeerID, "actor_id", actor, "tenant_id", tenantID)
+		errorResponse(c, domain.NewError(domain.ErrInternalServer, "Internal server error", nil))
+		return
+	}
+	slog.Info("Peer reported", "network_id", networkID, "target_user_id", body.PeerID, "messages", len(body.Messages), "actor_id", actor, "tenant_id", tenantID)
+	c.JSON(http.StatusOK, gin.H{"ok": true})
+}
+
 func (h *NetworkHandler) ListMembers(c *gin.Context) {
 	networkID := c.Param("id")
 	status := c.Query("status")
@@ -507,6 +578,29 @@ func (h *NetworkHandler) ListJoinRequests(c *gin.Context) {
 	c.JSON(http.StatusOK, gin.H{"data": requests})
 }
 
+// ListPeerReports handles GET /v1/networks/:id/reports (admin/owner only)
+func (h *NetworkHandler) ListPeerReports(c *gin.Context) {
+	networkID := c.Param("id")
+	tenantID := c.MustGet("tenant_id").(string)
+	limit := parseIntWithDefault(c.Query("limit"), 50)
+
+	reports, err := h.memberService.ListPeerReports(c.Request.Context(), networkID, tenantID, limit)
+	if err != nil {
+		var derr *domain.Error
+		if errors.As(err, &derr) {
+			slog.Error("ListPeerReports: Service error", "error", derr, "network_id", networkID, "tenant_id", tenantID)
+			errorResponse(c, derr)
+			return
+		}
+		slog.Error("ListPeerReports: Internal server error", "error", err, "network_id", networkID, "tenant_id", tenantID)
+		errorResponse(c, domain.NewError(domain.ErrInternalServer, "Internal server error", nil))
+		return
+	}
+
+	slog.Info("Peer reports listed successfully", "network_id", networkID, "count", len(reports), "tenant_id", tenantID)
+	c.JSON(http.StatusOK, gin.H{"data": reports})
+}
+
 // AllocateIP handles POST /v1/networks/:id/ip-allocations
 func (h *NetworkHandler) AllocateIP(c *gin.Context) {
 	networkID := c.Param("id")
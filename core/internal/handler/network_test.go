@@ -515,6 +515,40 @@ func TestListJoinRequests_AdminSuccess(t *testing.T) {
 	assert.Contains(t, resp, "data")
 }
 
+func TestListPeerReports_AdminSuccess(t *testing.T) {
+	router, _, networkRepo, membershipRepo, membershipService := setupNetworkWithRepos()
+	membershipService.SetPeerReportRepository(repository.NewInMemoryPeerReportRepository())
+
+	net := &domain.Network{ID: "net-reports", TenantID: "t1", Name: "ReportsNet", Visibility: domain.NetworkVisibilityPublic, JoinPolicy: domain.JoinPolicyOpen, CIDR: "10.82.0.0/24", CreatedBy: "admin_user"}
+	require.NoError(t, networkRepo.Create(context.Background(), net))
+	_, _ = membershipRepo.UpsertApproved(context.Background(), net.ID, "admin_user", domain.RoleOwner, time.Now())
+	_, _ = membershipRepo.UpsertApproved(context.Background(), net.ID, "user_dev", domain.RoleMember, time.Now())
+	_, _ = membershipRepo.UpsertApproved(context.Background(), net.ID, "spammer", domain.RoleMember, time.Now())
+	messages := []domain.ReportedMessage{{ID: "m1", From: "spammer", Content: "buy now"}}
+	require.NoError(t, membershipService.ReportPeer(context.Background(), net.ID, "spammer", "user_dev", "t1", "spam", messages))
+
+	w := httptest.NewRecorder()
+	req, _ := http.NewRequest("GET", "/v1/networks/"+net.ID+"/reports", nil)
+	req.Header.Set("Authorization", "Bearer admin") // admin token
+
+	router.ServeHTTP(w, req)
+
+	assert.Equal(t, http.StatusOK, w.Code)
+	var resp struct {
+		Data []domain.PeerReport `json:"data"`
+	}
+	require.NoError(t, json.Unmarshal(w.Body.Bytes(), &resp))
+	require.Len(t, resp.Data, 1)
+	assert.Equal(t, "spammer", resp.Data[0].TargetID)
+	assert.Len(t, resp.Data[0].UnverifiedMessages, 1)
+
+	w = httptest.NewRecorder()
+	req, _ = http.NewRequest("GET", "/v1/networks/"+net.ID+"/reports", nil)
+	req.Header.Set("Authorization", "Bearer dev") // non-admin
+	router.ServeHTTP(w, req)
+	assert.Equal(t, http.StatusForbidden, w.Code)
+}
+
 func TestReleaseIP_NotImplemented(t *testing.T) {
 	gin.SetMode(gin.TestMode)
 	networkRepo := repository.NewInMemoryNetworkRepository()
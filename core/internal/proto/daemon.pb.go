@@ -248,6 +248,7 @@ const (
 	EventType_EVENT_TYPE_TRANSFER_REQUEST  EventType = 5
 	EventType_EVENT_TYPE_TRANSFER_PROGRESS EventType = 6
 	EventType_EVENT_TYPE_NOTIFICATION      EventType = 7
+	EventType_EVENT_TYPE_SECURITY          EventType = 8
 )
 
 // Enum value maps for EventType.
@@ -261,6 +262,7 @@ var (
 		5: "EVENT_TYPE_TRANSFER_REQUEST",
 		6: "EVENT_TYPE_TRANSFER_PROGRESS",
 		7: "EVENT_TYPE_NOTIFICATION",
+		8: "EVENT_TYPE_SECURITY",
 	}
 	EventType_value = map[string]int32{
 		"EVENT_TYPE_UNSPECIFIED":       0,
@@ -271,6 +273,7 @@ var (
 		"EVENT_TYPE_TRANSFER_REQUEST":  5,
 		"EVENT_TYPE_TRANSFER_PROGRESS": 6,
 		"EVENT_TYPE_NOTIFICATION":      7,
+		"EVENT_TYPE_SECURITY":          8,
 	}
 )
 
@@ -301,6 +304,61 @@ func (EventType) EnumDescriptor() ([]byte, []int) {
 	return file_daemon_proto_rawDescGZIP(), []int{4}
 }
 
+type SecurityEventKind int32
+
+const (
+	SecurityEventKind_SECURITY_EVENT_KIND_UNSPECIFIED SecurityEventKind = 0
+	// A new device signed in to the account
+	SecurityEventKind_SECURITY_EVENT_KIND_DEVICE_ADDED SecurityEventKind = 1
+	// A peer presented a different public key than before
+	SecurityEventKind_SECURITY_EVENT_KIND_PEER_KEY_CHANGED SecurityEventKind = 2
+	// A local client called the daemon with a wrong IPC token
+	SecurityEventKind_SECURITY_EVENT_KIND_IPC_AUTH_FAILED SecurityEventKind = 3
+)
+
+// Enum value maps for SecurityEventKind.
+var (
+	SecurityEventKind_name = map[int32]string{
+		0: "SECURITY_EVENT_KIND_UNSPECIFIED",
+		1: "SECURITY_EVENT_KIND_DEVICE_ADDED",
+		2: "SECURITY_EVENT_KIND_PEER_KEY_CHANGED",
+		3: "SECURITY_EVENT_KIND_IPC_AUTH_FAILED",
+	}
+	SecurityEventKind_value = map[string]int32{
+		"SECURITY_EVENT_KIND_UNSPECIFIED":      0,
+		"SECURITY_EVENT_KIND_DEVICE_ADDED":     1,
+		"SECURITY_EVENT_KIND_PEER_KEY_CHANGED": 2,
+		"SECURITY_EVENT_KIND_IPC_AUTH_FAILED":  3,
+	}
+)
+
+func (x SecurityEventKind) Enum() *SecurityEventKind {
+	p := new(SecurityEventKind)
+	*p = x
+	return p
+}
+
+func (x SecurityEventKind) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (SecurityEventKind) Descriptor() protoreflect.EnumDescriptor {
+	return file_daemon_proto_enumTypes[5].Descriptor()
+}
+
+func (SecurityEventKind) Type() protoreflect.EnumType {
+	return &file_daemon_proto_enumTypes[5]
+}
+
+func (x SecurityEventKind) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use SecurityEventKind.Descriptor instead.
+func (SecurityEventKind) EnumDescriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{5}
+}
+
 type LoginRequest struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Optional client metadata
@@ -592,6 +650,121 @@ func (x *LoginError) GetMessage() string {
 	return ""
 }
 
+type Credential struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// "ipc_token", "account_token" or "refresh_token"
+	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"`
+	// What it is for, e.g. the account email or the token file
+	Name     string                 `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	IssuedAt *timestamppb.Timestamp `protobuf:"bytes,3,opt,name=issued_at,json=issuedAt,proto3" json:"issued_at,omitempty"`
+	// Unset when it does not expire
+	ExpiresAt     *timestamppb.Timestamp `protobuf:"bytes,4,opt,name=expires_at,json=expiresAt,proto3" json:"expires_at,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *Credential) Reset() {
+	*x = Credential{}
+	mi := &file_daemon_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *Credential) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Credential) ProtoMessage() {}
+
+func (x *Credential) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Credential.ProtoReflect.Descriptor instead.
+func (*Credential) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *Credential) GetKind() string {
+	if x != nil {
+		return x.Kind
+	}
+	return ""
+}
+
+func (x *Credential) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *Credential) GetIssuedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.IssuedAt
+	}
+	return nil
+}
+
+func (x *Credential) GetExpiresAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.ExpiresAt
+	}
+	return nil
+}
+
+type GetCredentialsResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Credentials   []*Credential          `protobuf:"bytes,1,rep,name=credentials,proto3" json:"credentials,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetCredentialsResponse) Reset() {
+	*x = GetCredentialsResponse{}
+	mi := &file_daemon_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetCredentialsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetCredentialsResponse) ProtoMessage() {}
+
+func (x *GetCredentialsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetCredentialsResponse.ProtoReflect.Descriptor instead.
+func (*GetCredentialsResponse) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *GetCredentialsResponse) GetCredentials() []*Credential {
+	if x != nil {
+		return x.Credentials
+	}
+	return nil
+}
+
 type Peer struct {
 	state          protoimpl.MessageState `protogen:"open.v1"`
 	Id             string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
@@ -610,7 +783,7 @@ type Peer struct {
 
 func (x *Peer) Reset() {
 	*x = Peer{}
-	mi := &file_daemon_proto_msgTypes[5]
+	mi := &file_daemon_proto_msgTypes[7]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -622,7 +795,7 @@ func (x *Peer) String() string {
 func (*Peer) ProtoMessage() {}
 
 func (x *Peer) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[5]
+	mi := &file_daemon_proto_msgTypes[7]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -635,7 +808,7 @@ func (x *Peer) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Peer.ProtoReflect.Descriptor instead.
 func (*Peer) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{5}
+	return file_daemon_proto_rawDescGZIP(), []int{7}
 }
 
 func (x *Peer) GetId() string {
@@ -726,7 +899,7 @@ type Network struct {
 
 func (x *Network) Reset() {
 	*x = Network{}
-	mi := &file_daemon_proto_msgTypes[6]
+	mi := &file_daemon_proto_msgTypes[8]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -738,7 +911,7 @@ func (x *Network) String() string {
 func (*Network) ProtoMessage() {}
 
 func (x *Network) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[6]
+	mi := &file_daemon_proto_msgTypes[8]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -751,7 +924,7 @@ func (x *Network) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Network.ProtoReflect.Descriptor instead.
 func (*Network) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{6}
+	return file_daemon_proto_rawDescGZIP(), []int{8}
 }
 
 func (x *Network) GetId() string {
@@ -839,7 +1012,7 @@ type ChatMessage struct {
 
 func (x *ChatMessage) Reset() {
 	*x = ChatMessage{}
-	mi := &file_daemon_proto_msgTypes[7]
+	mi := &file_daemon_proto_msgTypes[9]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -851,7 +1024,7 @@ func (x *ChatMessage) String() string {
 func (*ChatMessage) ProtoMessage() {}
 
 func (x *ChatMessage) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[7]
+	mi := &file_daemon_proto_msgTypes[9]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -864,7 +1037,7 @@ func (x *ChatMessage) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ChatMessage.ProtoReflect.Descriptor instead.
 func (*ChatMessage) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{7}
+	return file_daemon_proto_rawDescGZIP(), []int{9}
 }
 
 func (x *ChatMessage) GetId() string {
@@ -928,13 +1101,17 @@ type FileTransfer struct {
 	IsIncoming       bool                   `protobuf:"varint,8,opt,name=is_incoming,json=isIncoming,proto3" json:"is_incoming,omitempty"`
 	ErrorMessage     string                 `protobuf:"bytes,9,opt,name=error_message,json=errorMessage,proto3" json:"error_message,omitempty"`
 	StartedAt        *timestamppb.Timestamp `protobuf:"bytes,10,opt,name=started_at,json=startedAt,proto3" json:"started_at,omitempty"`
-	unknownFields    protoimpl.UnknownFields
-	sizeCache        protoimpl.SizeCache
+	// Hex SHA-256 of the sender's file; empty until the daemon knows it
+	Sha256 string `protobuf:"bytes,11,opt,name=sha256,proto3" json:"sha256,omitempty"`
+	// Speed cap for this transfer alone; 0 = the limit from the settings
+	MaxSpeedKbps  int32 `protobuf:"varint,12,opt,name=max_speed_kbps,json=maxSpeedKbps,proto3" json:"max_speed_kbps,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
 }
 
 func (x *FileTransfer) Reset() {
 	*x = FileTransfer{}
-	mi := &file_daemon_proto_msgTypes[8]
+	mi := &file_daemon_proto_msgTypes[10]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -946,7 +1123,7 @@ func (x *FileTransfer) String() string {
 func (*FileTransfer) ProtoMessage() {}
 
 func (x *FileTransfer) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[8]
+	mi := &file_daemon_proto_msgTypes[10]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -959,7 +1136,7 @@ func (x *FileTransfer) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use FileTransfer.ProtoReflect.Descriptor instead.
 func (*FileTransfer) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{8}
+	return file_daemon_proto_rawDescGZIP(), []int{10}
 }
 
 func (x *FileTransfer) GetId() string {
@@ -1032,6 +1209,20 @@ func (x *FileTransfer) GetStartedAt() *timestamppb.Timestamp {
 	return nil
 }
 
+func (x *FileTransfer) GetSha256() string {
+	if x != nil {
+		return x.Sha256
+	}
+	return ""
+}
+
+func (x *FileTransfer) GetMaxSpeedKbps() int32 {
+	if x != nil {
+		return x.MaxSpeedKbps
+	}
+	return 0
+}
+
 type Settings struct {
 	state                protoimpl.MessageState `protogen:"open.v1"`
 	AutoConnect          bool                   `protobuf:"varint,1,opt,name=auto_connect,json=autoConnect,proto3" json:"auto_connect,omitempty"`
@@ -1049,7 +1240,7 @@ type Settings struct {
 
 func (x *Settings) Reset() {
 	*x = Settings{}
-	mi := &file_daemon_proto_msgTypes[9]
+	mi := &file_daemon_proto_msgTypes[11]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1061,7 +1252,7 @@ func (x *Settings) String() string {
 func (*Settings) ProtoMessage() {}
 
 func (x *Settings) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[9]
+	mi := &file_daemon_proto_msgTypes[11]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1074,7 +1265,7 @@ func (x *Settings) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Settings.ProtoReflect.Descriptor instead.
 func (*Settings) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{9}
+	return file_daemon_proto_rawDescGZIP(), []int{11}
 }
 
 func (x *Settings) GetAutoConnect() bool {
@@ -1151,6 +1342,7 @@ type DaemonEvent struct {
 	//	*DaemonEvent_ChatMessage
 	//	*DaemonEvent_TransferEvent
 	//	*DaemonEvent_Notification
+	//	*DaemonEvent_SecurityEvent
 	Payload       isDaemonEvent_Payload `protobuf_oneof:"payload"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
@@ -1158,7 +1350,7 @@ type DaemonEvent struct {
 
 func (x *DaemonEvent) Reset() {
 	*x = DaemonEvent{}
-	mi := &file_daemon_proto_msgTypes[10]
+	mi := &file_daemon_proto_msgTypes[12]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1170,7 +1362,7 @@ func (x *DaemonEvent) String() string {
 func (*DaemonEvent) ProtoMessage() {}
 
 func (x *DaemonEvent) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[10]
+	mi := &file_daemon_proto_msgTypes[12]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1183,7 +1375,7 @@ func (x *DaemonEvent) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use DaemonEvent.ProtoReflect.Descriptor instead.
 func (*DaemonEvent) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{10}
+	return file_daemon_proto_rawDescGZIP(), []int{12}
 }
 
 func (x *DaemonEvent) GetType() EventType {
@@ -1252,6 +1444,15 @@ func (x *DaemonEvent) GetNotification() *Notification {
 	return nil
 }
 
+func (x *DaemonEvent) GetSecurityEvent() *SecurityEvent {
+	if x != nil {
+		if x, ok := x.Payload.(*DaemonEvent_SecurityEvent); ok {
+			return x.SecurityEvent
+		}
+	}
+	return nil
+}
+
 type isDaemonEvent_Payload interface {
 	isDaemonEvent_Payload()
 }
@@ -1276,6 +1477,10 @@ type DaemonEvent_Notification struct {
 	Notification *Notification `protobuf:"bytes,14,opt,name=notification,proto3,oneof"`
 }
 
+type DaemonEvent_SecurityEvent struct {
+	SecurityEvent *SecurityEvent `protobuf:"bytes,15,opt,name=security_event,json=securityEvent,proto3,oneof"`
+}
+
 func (*DaemonEvent_StatusChanged) isDaemonEvent_Payload() {}
 
 func (*DaemonEvent_PeerEvent) isDaemonEvent_Payload() {}
@@ -1286,6 +1491,104 @@ func (*DaemonEvent_TransferEvent) isDaemonEvent_Payload() {}
 
 func (*DaemonEvent_Notification) isDaemonEvent_Payload() {}
 
+func (*DaemonEvent_SecurityEvent) isDaemonEvent_Payload() {}
+
+type SecurityEvent struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	Id    string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Kind  SecurityEventKind      `protobuf:"varint,2,opt,name=kind,proto3,enum=daemon.SecurityEventKind" json:"kind,omitempty"`
+	// Human-readable description
+	Message string `protobuf:"bytes,3,opt,name=message,proto3" json:"message,omitempty"`
+	// Set for peer events
+	PeerId    string `protobuf:"bytes,4,opt,name=peer_id,json=peerId,proto3" json:"peer_id,omitempty"`
+	NetworkId string `protobuf:"bytes,5,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
+	// Set for device events
+	DeviceName string `protobuf:"bytes,6,opt,name=device_name,json=deviceName,proto3" json:"device_name,omitempty"`
+	// Where a failed IPC call came from, when known
+	Source        string `protobuf:"bytes,7,opt,name=source,proto3" json:"source,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SecurityEvent) Reset() {
+	*x = SecurityEvent{}
+	mi := &file_daemon_proto_msgTypes[13]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SecurityEvent) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SecurityEvent) ProtoMessage() {}
+
+func (x *SecurityEvent) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[13]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SecurityEvent.ProtoReflect.Descriptor instead.
+func (*SecurityEvent) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{13}
+}
+
+func (x *SecurityEvent) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *SecurityEvent) GetKind() SecurityEventKind {
+	if x != nil {
+		return x.Kind
+	}
+	return SecurityEventKind_SECURITY_EVENT_KIND_UNSPECIFIED
+}
+
+func (x *SecurityEvent) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+func (x *SecurityEvent) GetPeerId() string {
+	if x != nil {
+		return x.PeerId
+	}
+	return ""
+}
+
+func (x *SecurityEvent) GetNetworkId() string {
+	if x != nil {
+		return x.NetworkId
+	}
+	return ""
+}
+
+func (x *SecurityEvent) GetDeviceName() string {
+	if x != nil {
+		return x.DeviceName
+	}
+	return ""
+}
+
+func (x *SecurityEvent) GetSource() string {
+	if x != nil {
+		return x.Source
+	}
+	return ""
+}
+
 type StatusChangedEvent struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	OldStatus     ConnectionStatus       `protobuf:"varint,1,opt,name=old_status,json=oldStatus,proto3,enum=daemon.ConnectionStatus" json:"old_status,omitempty"`
@@ -1297,7 +1600,7 @@ type StatusChangedEvent struct {
 
 func (x *StatusChangedEvent) Reset() {
 	*x = StatusChangedEvent{}
-	mi := &file_daemon_proto_msgTypes[11]
+	mi := &file_daemon_proto_msgTypes[14]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1309,7 +1612,7 @@ func (x *StatusChangedEvent) String() string {
 func (*StatusChangedEvent) ProtoMessage() {}
 
 func (x *StatusChangedEvent) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[11]
+	mi := &file_daemon_proto_msgTypes[14]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1322,7 +1625,7 @@ func (x *StatusChangedEvent) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use StatusChangedEvent.ProtoReflect.Descriptor instead.
 func (*StatusChangedEvent) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{11}
+	return file_daemon_proto_rawDescGZIP(), []int{14}
 }
 
 func (x *StatusChangedEvent) GetOldStatus() ConnectionStatus {
@@ -1356,7 +1659,7 @@ type PeerEvent struct {
 
 func (x *PeerEvent) Reset() {
 	*x = PeerEvent{}
-	mi := &file_daemon_proto_msgTypes[12]
+	mi := &file_daemon_proto_msgTypes[15]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1368,7 +1671,7 @@ func (x *PeerEvent) String() string {
 func (*PeerEvent) ProtoMessage() {}
 
 func (x *PeerEvent) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[12]
+	mi := &file_daemon_proto_msgTypes[15]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1381,7 +1684,7 @@ func (x *PeerEvent) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeerEvent.ProtoReflect.Descriptor instead.
 func (*PeerEvent) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{12}
+	return file_daemon_proto_rawDescGZIP(), []int{15}
 }
 
 func (x *PeerEvent) GetPeer() *Peer {
@@ -1407,7 +1710,7 @@ type TransferEvent struct {
 
 func (x *TransferEvent) Reset() {
 	*x = TransferEvent{}
-	mi := &file_daemon_proto_msgTypes[13]
+	mi := &file_daemon_proto_msgTypes[16]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1419,7 +1722,7 @@ func (x *TransferEvent) String() string {
 func (*TransferEvent) ProtoMessage() {}
 
 func (x *TransferEvent) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[13]
+	mi := &file_daemon_proto_msgTypes[16]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1432,7 +1735,7 @@ func (x *TransferEvent) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use TransferEvent.ProtoReflect.Descriptor instead.
 func (*TransferEvent) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{13}
+	return file_daemon_proto_rawDescGZIP(), []int{16}
 }
 
 func (x *TransferEvent) GetTransfer() *FileTransfer {
@@ -1453,7 +1756,7 @@ type Notification struct {
 
 func (x *Notification) Reset() {
 	*x = Notification{}
-	mi := &file_daemon_proto_msgTypes[14]
+	mi := &file_daemon_proto_msgTypes[17]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1465,7 +1768,7 @@ func (x *Notification) String() string {
 func (*Notification) ProtoMessage() {}
 
 func (x *Notification) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[14]
+	mi := &file_daemon_proto_msgTypes[17]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1478,7 +1781,7 @@ func (x *Notification) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Notification.ProtoReflect.Descriptor instead.
 func (*Notification) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{14}
+	return file_daemon_proto_rawDescGZIP(), []int{17}
 }
 
 func (x *Notification) GetTitle() string {
@@ -1510,7 +1813,7 @@ type GetStatusRequest struct {
 
 func (x *GetStatusRequest) Reset() {
 	*x = GetStatusRequest{}
-	mi := &file_daemon_proto_msgTypes[15]
+	mi := &file_daemon_proto_msgTypes[18]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1522,7 +1825,7 @@ func (x *GetStatusRequest) String() string {
 func (*GetStatusRequest) ProtoMessage() {}
 
 func (x *GetStatusRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[15]
+	mi := &file_daemon_proto_msgTypes[18]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1535,7 +1838,7 @@ func (x *GetStatusRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetStatusRequest.ProtoReflect.Descriptor instead.
 func (*GetStatusRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{15}
+	return file_daemon_proto_rawDescGZIP(), []int{18}
 }
 
 type GetStatusResponse struct {
@@ -1551,7 +1854,7 @@ type GetStatusResponse struct {
 
 func (x *GetStatusResponse) Reset() {
 	*x = GetStatusResponse{}
-	mi := &file_daemon_proto_msgTypes[16]
+	mi := &file_daemon_proto_msgTypes[19]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1563,7 +1866,7 @@ func (x *GetStatusResponse) String() string {
 func (*GetStatusResponse) ProtoMessage() {}
 
 func (x *GetStatusResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[16]
+	mi := &file_daemon_proto_msgTypes[19]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1576,7 +1879,7 @@ func (x *GetStatusResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetStatusResponse.ProtoReflect.Descriptor instead.
 func (*GetStatusResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{16}
+	return file_daemon_proto_rawDescGZIP(), []int{19}
 }
 
 func (x *GetStatusResponse) GetStatus() ConnectionStatus {
@@ -1615,20 +1918,25 @@ func (x *GetStatusResponse) GetCurrentNetworkName() string {
 }
 
 type VersionResponse struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Version       string                 `protobuf:"bytes,1,opt,name=version,proto3" json:"version,omitempty"`
-	BuildDate     string                 `protobuf:"bytes,2,opt,name=build_date,json=buildDate,proto3" json:"build_date,omitempty"`
-	Commit        string                 `protobuf:"bytes,3,opt,name=commit,proto3" json:"commit,omitempty"`
-	GoVersion     string                 `protobuf:"bytes,4,opt,name=go_version,json=goVersion,proto3" json:"go_version,omitempty"`
-	Os            string                 `protobuf:"bytes,5,opt,name=os,proto3" json:"os,omitempty"`
-	Arch          string                 `protobuf:"bytes,6,opt,name=arch,proto3" json:"arch,omitempty"`
+	state     protoimpl.MessageState `protogen:"open.v1"`
+	Version   string                 `protobuf:"bytes,1,opt,name=version,proto3" json:"version,omitempty"`
+	BuildDate string                 `protobuf:"bytes,2,opt,name=build_date,json=buildDate,proto3" json:"build_date,omitempty"`
+	Commit    string                 `protobuf:"bytes,3,opt,name=commit,proto3" json:"commit,omitempty"`
+	GoVersion string                 `protobuf:"bytes,4,opt,name=go_version,json=goVersion,proto3" json:"go_version,omitempty"`
+	Os        string                 `protobuf:"bytes,5,opt,name=os,proto3" json:"os,omitempty"`
+	Arch      string                 `protobuf:"bytes,6,opt,name=arch,proto3" json:"arch,omitempty"`
+	// The daemon's clock when it answered
+	DaemonTime *timestamppb.Timestamp `protobuf:"bytes,7,opt,name=daemon_time,json=daemonTime,proto3" json:"daemon_time,omitempty"`
+	// The coordination server's clock as last seen by the daemon, advanced to
+	// now; unset until the daemon has talked to the server
+	ServerTime    *timestamppb.Timestamp `protobuf:"bytes,8,opt,name=server_time,json=serverTime,proto3" json:"server_time,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *VersionResponse) Reset() {
 	*x = VersionResponse{}
-	mi := &file_daemon_proto_msgTypes[17]
+	mi := &file_daemon_proto_msgTypes[20]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1640,7 +1948,7 @@ func (x *VersionResponse) String() string {
 func (*VersionResponse) ProtoMessage() {}
 
 func (x *VersionResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[17]
+	mi := &file_daemon_proto_msgTypes[20]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1653,7 +1961,7 @@ func (x *VersionResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VersionResponse.ProtoReflect.Descriptor instead.
 func (*VersionResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{17}
+	return file_daemon_proto_rawDescGZIP(), []int{20}
 }
 
 func (x *VersionResponse) GetVersion() string {
@@ -1698,6 +2006,20 @@ func (x *VersionResponse) GetArch() string {
 	return ""
 }
 
+func (x *VersionResponse) GetDaemonTime() *timestamppb.Timestamp {
+	if x != nil {
+		return x.DaemonTime
+	}
+	return nil
+}
+
+func (x *VersionResponse) GetServerTime() *timestamppb.Timestamp {
+	if x != nil {
+		return x.ServerTime
+	}
+	return nil
+}
+
 type SubscribeRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	EventTypes    []EventType            `protobuf:"varint,1,rep,packed,name=event_types,json=eventTypes,proto3,enum=daemon.EventType" json:"event_types,omitempty"` // Empty = all events
@@ -1707,7 +2029,7 @@ type SubscribeRequest struct {
 
 func (x *SubscribeRequest) Reset() {
 	*x = SubscribeRequest{}
-	mi := &file_daemon_proto_msgTypes[18]
+	mi := &file_daemon_proto_msgTypes[21]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1719,7 +2041,7 @@ func (x *SubscribeRequest) String() string {
 func (*SubscribeRequest) ProtoMessage() {}
 
 func (x *SubscribeRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[18]
+	mi := &file_daemon_proto_msgTypes[21]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1732,7 +2054,7 @@ func (x *SubscribeRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SubscribeRequest.ProtoReflect.Descriptor instead.
 func (*SubscribeRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{18}
+	return file_daemon_proto_rawDescGZIP(), []int{21}
 }
 
 func (x *SubscribeRequest) GetEventTypes() []EventType {
@@ -1753,7 +2075,7 @@ type CreateNetworkRequest struct {
 
 func (x *CreateNetworkRequest) Reset() {
 	*x = CreateNetworkRequest{}
-	mi := &file_daemon_proto_msgTypes[19]
+	mi := &file_daemon_proto_msgTypes[22]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1765,7 +2087,7 @@ func (x *CreateNetworkRequest) String() string {
 func (*CreateNetworkRequest) ProtoMessage() {}
 
 func (x *CreateNetworkRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[19]
+	mi := &file_daemon_proto_msgTypes[22]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1778,7 +2100,7 @@ func (x *CreateNetworkRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreateNetworkRequest.ProtoReflect.Descriptor instead.
 func (*CreateNetworkRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{19}
+	return file_daemon_proto_rawDescGZIP(), []int{22}
 }
 
 func (x *CreateNetworkRequest) GetName() string {
@@ -1812,7 +2134,7 @@ type CreateNetworkResponse struct {
 
 func (x *CreateNetworkResponse) Reset() {
 	*x = CreateNetworkResponse{}
-	mi := &file_daemon_proto_msgTypes[20]
+	mi := &file_daemon_proto_msgTypes[23]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1824,7 +2146,7 @@ func (x *CreateNetworkResponse) String() string {
 func (*CreateNetworkResponse) ProtoMessage() {}
 
 func (x *CreateNetworkResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[20]
+	mi := &file_daemon_proto_msgTypes[23]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1837,7 +2159,7 @@ func (x *CreateNetworkResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreateNetworkResponse.ProtoReflect.Descriptor instead.
 func (*CreateNetworkResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{20}
+	return file_daemon_proto_rawDescGZIP(), []int{23}
 }
 
 func (x *CreateNetworkResponse) GetNetwork() *Network {
@@ -1863,7 +2185,7 @@ type JoinNetworkRequest struct {
 
 func (x *JoinNetworkRequest) Reset() {
 	*x = JoinNetworkRequest{}
-	mi := &file_daemon_proto_msgTypes[21]
+	mi := &file_daemon_proto_msgTypes[24]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1875,7 +2197,7 @@ func (x *JoinNetworkRequest) String() string {
 func (*JoinNetworkRequest) ProtoMessage() {}
 
 func (x *JoinNetworkRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[21]
+	mi := &file_daemon_proto_msgTypes[24]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1888,7 +2210,7 @@ func (x *JoinNetworkRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use JoinNetworkRequest.ProtoReflect.Descriptor instead.
 func (*JoinNetworkRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{21}
+	return file_daemon_proto_rawDescGZIP(), []int{24}
 }
 
 func (x *JoinNetworkRequest) GetInviteCode() string {
@@ -1907,7 +2229,7 @@ type JoinNetworkResponse struct {
 
 func (x *JoinNetworkResponse) Reset() {
 	*x = JoinNetworkResponse{}
-	mi := &file_daemon_proto_msgTypes[22]
+	mi := &file_daemon_proto_msgTypes[25]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1919,7 +2241,7 @@ func (x *JoinNetworkResponse) String() string {
 func (*JoinNetworkResponse) ProtoMessage() {}
 
 func (x *JoinNetworkResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[22]
+	mi := &file_daemon_proto_msgTypes[25]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1932,7 +2254,7 @@ func (x *JoinNetworkResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use JoinNetworkResponse.ProtoReflect.Descriptor instead.
 func (*JoinNetworkResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{22}
+	return file_daemon_proto_rawDescGZIP(), []int{25}
 }
 
 func (x *JoinNetworkResponse) GetNetwork() *Network {
@@ -1951,7 +2273,7 @@ type LeaveNetworkRequest struct {
 
 func (x *LeaveNetworkRequest) Reset() {
 	*x = LeaveNetworkRequest{}
-	mi := &file_daemon_proto_msgTypes[23]
+	mi := &file_daemon_proto_msgTypes[26]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1963,7 +2285,7 @@ func (x *LeaveNetworkRequest) String() string {
 func (*LeaveNetworkRequest) ProtoMessage() {}
 
 func (x *LeaveNetworkRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[23]
+	mi := &file_daemon_proto_msgTypes[26]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1976,7 +2298,7 @@ func (x *LeaveNetworkRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use LeaveNetworkRequest.ProtoReflect.Descriptor instead.
 func (*LeaveNetworkRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{23}
+	return file_daemon_proto_rawDescGZIP(), []int{26}
 }
 
 func (x *LeaveNetworkRequest) GetNetworkId() string {
@@ -1995,7 +2317,7 @@ type LeaveNetworkResponse struct {
 
 func (x *LeaveNetworkResponse) Reset() {
 	*x = LeaveNetworkResponse{}
-	mi := &file_daemon_proto_msgTypes[24]
+	mi := &file_daemon_proto_msgTypes[27]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2007,7 +2329,7 @@ func (x *LeaveNetworkResponse) String() string {
 func (*LeaveNetworkResponse) ProtoMessage() {}
 
 func (x *LeaveNetworkResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[24]
+	mi := &file_daemon_proto_msgTypes[27]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2020,7 +2342,7 @@ func (x *LeaveNetworkResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use LeaveNetworkResponse.ProtoReflect.Descriptor instead.
 func (*LeaveNetworkResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{24}
+	return file_daemon_proto_rawDescGZIP(), []int{27}
 }
 
 func (x *LeaveNetworkResponse) GetSuccess() bool {
@@ -2039,7 +2361,7 @@ type GetNetworkRequest struct {
 
 func (x *GetNetworkRequest) Reset() {
 	*x = GetNetworkRequest{}
-	mi := &file_daemon_proto_msgTypes[25]
+	mi := &file_daemon_proto_msgTypes[28]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2051,7 +2373,7 @@ func (x *GetNetworkRequest) String() string {
 func (*GetNetworkRequest) ProtoMessage() {}
 
 func (x *GetNetworkRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[25]
+	mi := &file_daemon_proto_msgTypes[28]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2064,7 +2386,7 @@ func (x *GetNetworkRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetNetworkRequest.ProtoReflect.Descriptor instead.
 func (*GetNetworkRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{25}
+	return file_daemon_proto_rawDescGZIP(), []int{28}
 }
 
 func (x *GetNetworkRequest) GetNetworkId() string {
@@ -2085,7 +2407,7 @@ type UpdateNetworkRequest struct {
 
 func (x *UpdateNetworkRequest) Reset() {
 	*x = UpdateNetworkRequest{}
-	mi := &file_daemon_proto_msgTypes[26]
+	mi := &file_daemon_proto_msgTypes[29]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2097,7 +2419,7 @@ func (x *UpdateNetworkRequest) String() string {
 func (*UpdateNetworkRequest) ProtoMessage() {}
 
 func (x *UpdateNetworkRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[26]
+	mi := &file_daemon_proto_msgTypes[29]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2110,7 +2432,7 @@ func (x *UpdateNetworkRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UpdateNetworkRequest.ProtoReflect.Descriptor instead.
 func (*UpdateNetworkRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{26}
+	return file_daemon_proto_rawDescGZIP(), []int{29}
 }
 
 func (x *UpdateNetworkRequest) GetNetworkId() string {
@@ -2143,7 +2465,7 @@ type DeleteNetworkRequest struct {
 
 func (x *DeleteNetworkRequest) Reset() {
 	*x = DeleteNetworkRequest{}
-	mi := &file_daemon_proto_msgTypes[27]
+	mi := &file_daemon_proto_msgTypes[30]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2155,7 +2477,7 @@ func (x *DeleteNetworkRequest) String() string {
 func (*DeleteNetworkRequest) ProtoMessage() {}
 
 func (x *DeleteNetworkRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[27]
+	mi := &file_daemon_proto_msgTypes[30]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2168,7 +2490,7 @@ func (x *DeleteNetworkRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use DeleteNetworkRequest.ProtoReflect.Descriptor instead.
 func (*DeleteNetworkRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{27}
+	return file_daemon_proto_rawDescGZIP(), []int{30}
 }
 
 func (x *DeleteNetworkRequest) GetNetworkId() string {
@@ -2178,6 +2500,162 @@ func (x *DeleteNetworkRequest) GetNetworkId() string {
 	return ""
 }
 
+type ListJoinRequestsRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	NetworkId     string                 `protobuf:"bytes,1,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListJoinRequestsRequest) Reset() {
+	*x = ListJoinRequestsRequest{}
+	mi := &file_daemon_proto_msgTypes[31]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListJoinRequestsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListJoinRequestsRequest) ProtoMessage() {}
+
+func (x *ListJoinRequestsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[31]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListJoinRequestsRequest.ProtoReflect.Descriptor instead.
+func (*ListJoinRequestsRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{31}
+}
+
+func (x *ListJoinRequestsRequest) GetNetworkId() string {
+	if x != nil {
+		return x.NetworkId
+	}
+	return ""
+}
+
+type JoinRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	PeerId        string                 `protobuf:"bytes,1,opt,name=peer_id,json=peerId,proto3" json:"peer_id,omitempty"`
+	Name          string                 `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	Message       string                 `protobuf:"bytes,3,opt,name=message,proto3" json:"message,omitempty"` // Optional note from the requester
+	RequestedAt   *timestamppb.Timestamp `protobuf:"bytes,4,opt,name=requested_at,json=requestedAt,proto3" json:"requested_at,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *JoinRequest) Reset() {
+	*x = JoinRequest{}
+	mi := &file_daemon_proto_msgTypes[32]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *JoinRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*JoinRequest) ProtoMessage() {}
+
+func (x *JoinRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[32]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use JoinRequest.ProtoReflect.Descriptor instead.
+func (*JoinRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{32}
+}
+
+func (x *JoinRequest) GetPeerId() string {
+	if x != nil {
+		return x.PeerId
+	}
+	return ""
+}
+
+func (x *JoinRequest) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *JoinRequest) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+func (x *JoinRequest) GetRequestedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.RequestedAt
+	}
+	return nil
+}
+
+type ListJoinRequestsResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Requests      []*JoinRequest         `protobuf:"bytes,1,rep,name=requests,proto3" json:"requests,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListJoinRequestsResponse) Reset() {
+	*x = ListJoinRequestsResponse{}
+	mi := &file_daemon_proto_msgTypes[33]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListJoinRequestsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListJoinRequestsResponse) ProtoMessage() {}
+
+func (x *ListJoinRequestsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[33]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListJoinRequestsResponse.ProtoReflect.Descriptor instead.
+func (*ListJoinRequestsResponse) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{33}
+}
+
+func (x *ListJoinRequestsResponse) GetRequests() []*JoinRequest {
+	if x != nil {
+		return x.Requests
+	}
+	return nil
+}
+
 type GenerateInviteRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	NetworkId     string                 `protobuf:"bytes,1,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
@@ -2189,7 +2667,7 @@ type GenerateInviteRequest struct {
 
 func (x *GenerateInviteRequest) Reset() {
 	*x = GenerateInviteRequest{}
-	mi := &file_daemon_proto_msgTypes[28]
+	mi := &file_daemon_proto_msgTypes[34]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2201,7 +2679,7 @@ func (x *GenerateInviteRequest) String() string {
 func (*GenerateInviteRequest) ProtoMessage() {}
 
 func (x *GenerateInviteRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[28]
+	mi := &file_daemon_proto_msgTypes[34]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2214,7 +2692,7 @@ func (x *GenerateInviteRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GenerateInviteRequest.ProtoReflect.Descriptor instead.
 func (*GenerateInviteRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{28}
+	return file_daemon_proto_rawDescGZIP(), []int{34}
 }
 
 func (x *GenerateInviteRequest) GetNetworkId() string {
@@ -2249,7 +2727,7 @@ type GenerateInviteResponse struct {
 
 func (x *GenerateInviteResponse) Reset() {
 	*x = GenerateInviteResponse{}
-	mi := &file_daemon_proto_msgTypes[29]
+	mi := &file_daemon_proto_msgTypes[35]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2261,7 +2739,7 @@ func (x *GenerateInviteResponse) String() string {
 func (*GenerateInviteResponse) ProtoMessage() {}
 
 func (x *GenerateInviteResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[29]
+	mi := &file_daemon_proto_msgTypes[35]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2274,7 +2752,7 @@ func (x *GenerateInviteResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GenerateInviteResponse.ProtoReflect.Descriptor instead.
 func (*GenerateInviteResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{29}
+	return file_daemon_proto_rawDescGZIP(), []int{35}
 }
 
 func (x *GenerateInviteResponse) GetInviteCode() string {
@@ -2298,6 +2776,250 @@ func (x *GenerateInviteResponse) GetExpiresAt() *timestamppb.Timestamp {
 	return nil
 }
 
+type PreviewInviteRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	InviteCode    string                 `protobuf:"bytes,1,opt,name=invite_code,json=inviteCode,proto3" json:"invite_code,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *PreviewInviteRequest) Reset() {
+	*x = PreviewInviteRequest{}
+	mi := &file_daemon_proto_msgTypes[36]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PreviewInviteRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PreviewInviteRequest) ProtoMessage() {}
+
+func (x *PreviewInviteRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[36]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PreviewInviteRequest.ProtoReflect.Descriptor instead.
+func (*PreviewInviteRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{36}
+}
+
+func (x *PreviewInviteRequest) GetInviteCode() string {
+	if x != nil {
+		return x.InviteCode
+	}
+	return ""
+}
+
+type InvitePreview struct {
+	state            protoimpl.MessageState `protogen:"open.v1"`
+	Valid            bool                   `protobuf:"varint,1,opt,name=valid,proto3" json:"valid,omitempty"`
+	InvalidReason    string                 `protobuf:"bytes,2,opt,name=invalid_reason,json=invalidReason,proto3" json:"invalid_reason,omitempty"` // Set when !valid: expired, used up, revoked, unknown
+	NetworkId        string                 `protobuf:"bytes,3,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
+	NetworkName      string                 `protobuf:"bytes,4,opt,name=network_name,json=networkName,proto3" json:"network_name,omitempty"`
+	Description      string                 `protobuf:"bytes,5,opt,name=description,proto3" json:"description,omitempty"`
+	MemberCount      int32                  `protobuf:"varint,6,opt,name=member_count,json=memberCount,proto3" json:"member_count,omitempty"`
+	OnlineCount      int32                  `protobuf:"varint,7,opt,name=online_count,json=onlineCount,proto3" json:"online_count,omitempty"`
+	OwnerId          string                 `protobuf:"bytes,8,opt,name=owner_id,json=ownerId,proto3" json:"owner_id,omitempty"`
+	OwnerName        string                 `protobuf:"bytes,9,opt,name=owner_name,json=ownerName,proto3" json:"owner_name,omitempty"`
+	RequiresApproval bool                   `protobuf:"varint,10,opt,name=requires_approval,json=requiresApproval,proto3" json:"requires_approval,omitempty"` // Joining waits for an admin to accept
+	MinTrustLevel    string                 `protobuf:"bytes,11,opt,name=min_trust_level,json=minTrustLevel,proto3" json:"min_trust_level,omitempty"`         // Empty = anyone with the code
+	Permissions      []string               `protobuf:"bytes,12,rep,name=permissions,proto3" json:"permissions,omitempty"`                                    // What members are allowed to do, e.g. "chat", "file_transfer"
+	ExpiresAt        *timestamppb.Timestamp `protobuf:"bytes,13,opt,name=expires_at,json=expiresAt,proto3" json:"expires_at,omitempty"`
+	UsesRemaining    int32                  `protobuf:"varint,14,opt,name=uses_remaining,json=usesRemaining,proto3" json:"uses_remaining,omitempty"` // -1 = unlimited
+	unknownFields    protoimpl.UnknownFields
+	sizeCache        protoimpl.SizeCache
+}
+
+func (x *InvitePreview) Reset() {
+	*x = InvitePreview{}
+	mi := &file_daemon_proto_msgTypes[37]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *InvitePreview) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InvitePreview) ProtoMessage() {}
+
+func (x *InvitePreview) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[37]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InvitePreview.ProtoReflect.Descriptor instead.
+func (*InvitePreview) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{37}
+}
+
+func (x *InvitePreview) GetValid() bool {
+	if x != nil {
+		return x.Valid
+	}
+	return false
+}
+
+func (x *InvitePreview) GetInvalidReason() string {
+	if x != nil {
+		return x.InvalidReason
+	}
+	return ""
+}
+
+func (x *InvitePreview) GetNetworkId() string {
+	if x != nil {
+		return x.NetworkId
+	}
+	return ""
+}
+
+func (x *InvitePreview) GetNetworkName() string {
+	if x != nil {
+		return x.NetworkName
+	}
+	return ""
+}
+
+func (x *InvitePreview) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *InvitePreview) GetMemberCount() int32 {
+	if x != nil {
+		return x.MemberCount
+	}
+	return 0
+}
+
+func (x *InvitePreview) GetOnlineCount() int32 {
+	if x != nil {
+		return x.OnlineCount
+	}
+	return 0
+}
+
+func (x *InvitePreview) GetOwnerId() string {
+	if x != nil {
+		return x.OwnerId
+	}
+	return ""
+}
+
+func (x *InvitePreview) GetOwnerName() string {
+	if x != nil {
+		return x.OwnerName
+	}
+	return ""
+}
+
+func (x *InvitePreview) GetRequiresApproval() bool {
+	if x != nil {
+		return x.RequiresApproval
+	}
+	return false
+}
+
+func (x *InvitePreview) GetMinTrustLevel() string {
+	if x != nil {
+		return x.MinTrustLevel
+	}
+	return ""
+}
+
+func (x *InvitePreview) GetPermissions() []string {
+	if x != nil {
+		return x.Permissions
+	}
+	return nil
+}
+
+func (x *InvitePreview) GetExpiresAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.ExpiresAt
+	}
+	return nil
+}
+
+func (x *InvitePreview) GetUsesRemaining() int32 {
+	if x != nil {
+		return x.UsesRemaining
+	}
+	return 0
+}
+
+type TransferOwnershipRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	NetworkId     string                 `protobuf:"bytes,1,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
+	NewOwnerId    string                 `protobuf:"bytes,2,opt,name=new_owner_id,json=newOwnerId,proto3" json:"new_owner_id,omitempty"` // Peer id of an existing member
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TransferOwnershipRequest) Reset() {
+	*x = TransferOwnershipRequest{}
+	mi := &file_daemon_proto_msgTypes[38]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TransferOwnershipRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TransferOwnershipRequest) ProtoMessage() {}
+
+func (x *TransferOwnershipRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[38]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TransferOwnershipRequest.ProtoReflect.Descriptor instead.
+func (*TransferOwnershipRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{38}
+}
+
+func (x *TransferOwnershipRequest) GetNetworkId() string {
+	if x != nil {
+		return x.NetworkId
+	}
+	return ""
+}
+
+func (x *TransferOwnershipRequest) GetNewOwnerId() string {
+	if x != nil {
+		return x.NewOwnerId
+	}
+	return ""
+}
+
 type ListNetworksResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Networks      []*Network             `protobuf:"bytes,1,rep,name=networks,proto3" json:"networks,omitempty"`
@@ -2307,7 +3029,7 @@ type ListNetworksResponse struct {
 
 func (x *ListNetworksResponse) Reset() {
 	*x = ListNetworksResponse{}
-	mi := &file_daemon_proto_msgTypes[30]
+	mi := &file_daemon_proto_msgTypes[39]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2319,7 +3041,7 @@ func (x *ListNetworksResponse) String() string {
 func (*ListNetworksResponse) ProtoMessage() {}
 
 func (x *ListNetworksResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[30]
+	mi := &file_daemon_proto_msgTypes[39]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2332,7 +3054,7 @@ func (x *ListNetworksResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ListNetworksResponse.ProtoReflect.Descriptor instead.
 func (*ListNetworksResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{30}
+	return file_daemon_proto_rawDescGZIP(), []int{39}
 }
 
 func (x *ListNetworksResponse) GetNetworks() []*Network {
@@ -2351,7 +3073,7 @@ type GetPeersRequest struct {
 
 func (x *GetPeersRequest) Reset() {
 	*x = GetPeersRequest{}
-	mi := &file_daemon_proto_msgTypes[31]
+	mi := &file_daemon_proto_msgTypes[40]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2363,7 +3085,7 @@ func (x *GetPeersRequest) String() string {
 func (*GetPeersRequest) ProtoMessage() {}
 
 func (x *GetPeersRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[31]
+	mi := &file_daemon_proto_msgTypes[40]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2376,7 +3098,7 @@ func (x *GetPeersRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetPeersRequest.ProtoReflect.Descriptor instead.
 func (*GetPeersRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{31}
+	return file_daemon_proto_rawDescGZIP(), []int{40}
 }
 
 func (x *GetPeersRequest) GetNetworkId() string {
@@ -2395,7 +3117,7 @@ type GetPeersResponse struct {
 
 func (x *GetPeersResponse) Reset() {
 	*x = GetPeersResponse{}
-	mi := &file_daemon_proto_msgTypes[32]
+	mi := &file_daemon_proto_msgTypes[41]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2407,7 +3129,7 @@ func (x *GetPeersResponse) String() string {
 func (*GetPeersResponse) ProtoMessage() {}
 
 func (x *GetPeersResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[32]
+	mi := &file_daemon_proto_msgTypes[41]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2420,7 +3142,7 @@ func (x *GetPeersResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetPeersResponse.ProtoReflect.Descriptor instead.
 func (*GetPeersResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{32}
+	return file_daemon_proto_rawDescGZIP(), []int{41}
 }
 
 func (x *GetPeersResponse) GetPeers() []*Peer {
@@ -2439,7 +3161,7 @@ type GetPeerRequest struct {
 
 func (x *GetPeerRequest) Reset() {
 	*x = GetPeerRequest{}
-	mi := &file_daemon_proto_msgTypes[33]
+	mi := &file_daemon_proto_msgTypes[42]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2451,7 +3173,7 @@ func (x *GetPeerRequest) String() string {
 func (*GetPeerRequest) ProtoMessage() {}
 
 func (x *GetPeerRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[33]
+	mi := &file_daemon_proto_msgTypes[42]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2464,7 +3186,7 @@ func (x *GetPeerRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetPeerRequest.ProtoReflect.Descriptor instead.
 func (*GetPeerRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{33}
+	return file_daemon_proto_rawDescGZIP(), []int{42}
 }
 
 func (x *GetPeerRequest) GetPeerId() string {
@@ -2485,7 +3207,7 @@ type KickPeerRequest struct {
 
 func (x *KickPeerRequest) Reset() {
 	*x = KickPeerRequest{}
-	mi := &file_daemon_proto_msgTypes[34]
+	mi := &file_daemon_proto_msgTypes[43]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2497,7 +3219,7 @@ func (x *KickPeerRequest) String() string {
 func (*KickPeerRequest) ProtoMessage() {}
 
 func (x *KickPeerRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[34]
+	mi := &file_daemon_proto_msgTypes[43]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2510,7 +3232,7 @@ func (x *KickPeerRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use KickPeerRequest.ProtoReflect.Descriptor instead.
 func (*KickPeerRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{34}
+	return file_daemon_proto_rawDescGZIP(), []int{43}
 }
 
 func (x *KickPeerRequest) GetNetworkId() string {
@@ -2545,7 +3267,7 @@ type BanPeerRequest struct {
 
 func (x *BanPeerRequest) Reset() {
 	*x = BanPeerRequest{}
-	mi := &file_daemon_proto_msgTypes[35]
+	mi := &file_daemon_proto_msgTypes[44]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2557,7 +3279,7 @@ func (x *BanPeerRequest) String() string {
 func (*BanPeerRequest) ProtoMessage() {}
 
 func (x *BanPeerRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[35]
+	mi := &file_daemon_proto_msgTypes[44]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2570,7 +3292,7 @@ func (x *BanPeerRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BanPeerRequest.ProtoReflect.Descriptor instead.
 func (*BanPeerRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{35}
+	return file_daemon_proto_rawDescGZIP(), []int{44}
 }
 
 func (x *BanPeerRequest) GetNetworkId() string {
@@ -2604,7 +3326,7 @@ type UnbanPeerRequest struct {
 
 func (x *UnbanPeerRequest) Reset() {
 	*x = UnbanPeerRequest{}
-	mi := &file_daemon_proto_msgTypes[36]
+	mi := &file_daemon_proto_msgTypes[45]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2616,7 +3338,7 @@ func (x *UnbanPeerRequest) String() string {
 func (*UnbanPeerRequest) ProtoMessage() {}
 
 func (x *UnbanPeerRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[36]
+	mi := &file_daemon_proto_msgTypes[45]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2629,7 +3351,7 @@ func (x *UnbanPeerRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UnbanPeerRequest.ProtoReflect.Descriptor instead.
 func (*UnbanPeerRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{36}
+	return file_daemon_proto_rawDescGZIP(), []int{45}
 }
 
 func (x *UnbanPeerRequest) GetNetworkId() string {
@@ -2646,6 +3368,74 @@ func (x *UnbanPeerRequest) GetPeerId() string {
 	return ""
 }
 
+type ReportPeerRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	NetworkId     string                 `protobuf:"bytes,1,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
+	PeerId        string                 `protobuf:"bytes,2,opt,name=peer_id,json=peerId,proto3" json:"peer_id,omitempty"`
+	Reason        string                 `protobuf:"bytes,3,opt,name=reason,proto3" json:"reason,omitempty"`
+	MessageIds    []string               `protobuf:"bytes,4,rep,name=message_ids,json=messageIds,proto3" json:"message_ids,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ReportPeerRequest) Reset() {
+	*x = ReportPeerRequest{}
+	mi := &file_daemon_proto_msgTypes[46]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ReportPeerRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReportPeerRequest) ProtoMessage() {}
+
+func (x *ReportPeerRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[46]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReportPeerRequest.ProtoReflect.Descriptor instead.
+func (*ReportPeerRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{46}
+}
+
+func (x *ReportPeerRequest) GetNetworkId() string {
+	if x != nil {
+		return x.NetworkId
+	}
+	return ""
+}
+
+func (x *ReportPeerRequest) GetPeerId() string {
+	if x != nil {
+		return x.PeerId
+	}
+	return ""
+}
+
+func (x *ReportPeerRequest) GetReason() string {
+	if x != nil {
+		return x.Reason
+	}
+	return ""
+}
+
+func (x *ReportPeerRequest) GetMessageIds() []string {
+	if x != nil {
+		return x.MessageIds
+	}
+	return nil
+}
+
 type SendMessageRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	NetworkId     string                 `protobuf:"bytes,1,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
@@ -2657,7 +3447,7 @@ type SendMessageRequest struct {
 
 func (x *SendMessageRequest) Reset() {
 	*x = SendMessageRequest{}
-	mi := &file_daemon_proto_msgTypes[37]
+	mi := &file_daemon_proto_msgTypes[47]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2669,7 +3459,7 @@ func (x *SendMessageRequest) String() string {
 func (*SendMessageRequest) ProtoMessage() {}
 
 func (x *SendMessageRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[37]
+	mi := &file_daemon_proto_msgTypes[47]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2682,7 +3472,7 @@ func (x *SendMessageRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SendMessageRequest.ProtoReflect.Descriptor instead.
 func (*SendMessageRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{37}
+	return file_daemon_proto_rawDescGZIP(), []int{47}
 }
 
 func (x *SendMessageRequest) GetNetworkId() string {
@@ -2715,7 +3505,7 @@ type SendMessageResponse struct {
 
 func (x *SendMessageResponse) Reset() {
 	*x = SendMessageResponse{}
-	mi := &file_daemon_proto_msgTypes[38]
+	mi := &file_daemon_proto_msgTypes[48]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2727,7 +3517,7 @@ func (x *SendMessageResponse) String() string {
 func (*SendMessageResponse) ProtoMessage() {}
 
 func (x *SendMessageResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[38]
+	mi := &file_daemon_proto_msgTypes[48]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2740,7 +3530,7 @@ func (x *SendMessageResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SendMessageResponse.ProtoReflect.Descriptor instead.
 func (*SendMessageResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{38}
+	return file_daemon_proto_rawDescGZIP(), []int{48}
 }
 
 func (x *SendMessageResponse) GetMessage() *ChatMessage {
@@ -2761,7 +3551,7 @@ type GetMessagesRequest struct {
 
 func (x *GetMessagesRequest) Reset() {
 	*x = GetMessagesRequest{}
-	mi := &file_daemon_proto_msgTypes[39]
+	mi := &file_daemon_proto_msgTypes[49]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2773,7 +3563,7 @@ func (x *GetMessagesRequest) String() string {
 func (*GetMessagesRequest) ProtoMessage() {}
 
 func (x *GetMessagesRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[39]
+	mi := &file_daemon_proto_msgTypes[49]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2786,7 +3576,7 @@ func (x *GetMessagesRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetMessagesRequest.ProtoReflect.Descriptor instead.
 func (*GetMessagesRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{39}
+	return file_daemon_proto_rawDescGZIP(), []int{49}
 }
 
 func (x *GetMessagesRequest) GetNetworkId() string {
@@ -2820,7 +3610,7 @@ type GetMessagesResponse struct {
 
 func (x *GetMessagesResponse) Reset() {
 	*x = GetMessagesResponse{}
-	mi := &file_daemon_proto_msgTypes[40]
+	mi := &file_daemon_proto_msgTypes[50]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2832,7 +3622,7 @@ func (x *GetMessagesResponse) String() string {
 func (*GetMessagesResponse) ProtoMessage() {}
 
 func (x *GetMessagesResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[40]
+	mi := &file_daemon_proto_msgTypes[50]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2845,7 +3635,7 @@ func (x *GetMessagesResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetMessagesResponse.ProtoReflect.Descriptor instead.
 func (*GetMessagesResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{40}
+	return file_daemon_proto_rawDescGZIP(), []int{50}
 }
 
 func (x *GetMessagesResponse) GetMessages() []*ChatMessage {
@@ -2871,7 +3661,7 @@ type SubscribeMessagesRequest struct {
 
 func (x *SubscribeMessagesRequest) Reset() {
 	*x = SubscribeMessagesRequest{}
-	mi := &file_daemon_proto_msgTypes[41]
+	mi := &file_daemon_proto_msgTypes[51]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2883,7 +3673,7 @@ func (x *SubscribeMessagesRequest) String() string {
 func (*SubscribeMessagesRequest) ProtoMessage() {}
 
 func (x *SubscribeMessagesRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[41]
+	mi := &file_daemon_proto_msgTypes[51]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2896,7 +3686,7 @@ func (x *SubscribeMessagesRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SubscribeMessagesRequest.ProtoReflect.Descriptor instead.
 func (*SubscribeMessagesRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{41}
+	return file_daemon_proto_rawDescGZIP(), []int{51}
 }
 
 func (x *SubscribeMessagesRequest) GetNetworkId() string {
@@ -2916,7 +3706,7 @@ type SendFileRequest struct {
 
 func (x *SendFileRequest) Reset() {
 	*x = SendFileRequest{}
-	mi := &file_daemon_proto_msgTypes[42]
+	mi := &file_daemon_proto_msgTypes[52]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2928,7 +3718,7 @@ func (x *SendFileRequest) String() string {
 func (*SendFileRequest) ProtoMessage() {}
 
 func (x *SendFileRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[42]
+	mi := &file_daemon_proto_msgTypes[52]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2941,7 +3731,7 @@ func (x *SendFileRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SendFileRequest.ProtoReflect.Descriptor instead.
 func (*SendFileRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{42}
+	return file_daemon_proto_rawDescGZIP(), []int{52}
 }
 
 func (x *SendFileRequest) GetPeerId() string {
@@ -2967,7 +3757,7 @@ type SendFileResponse struct {
 
 func (x *SendFileResponse) Reset() {
 	*x = SendFileResponse{}
-	mi := &file_daemon_proto_msgTypes[43]
+	mi := &file_daemon_proto_msgTypes[53]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2979,7 +3769,7 @@ func (x *SendFileResponse) String() string {
 func (*SendFileResponse) ProtoMessage() {}
 
 func (x *SendFileResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[43]
+	mi := &file_daemon_proto_msgTypes[53]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2992,7 +3782,7 @@ func (x *SendFileResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SendFileResponse.ProtoReflect.Descriptor instead.
 func (*SendFileResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{43}
+	return file_daemon_proto_rawDescGZIP(), []int{53}
 }
 
 func (x *SendFileResponse) GetTransferId() string {
@@ -3012,7 +3802,7 @@ type AcceptTransferRequest struct {
 
 func (x *AcceptTransferRequest) Reset() {
 	*x = AcceptTransferRequest{}
-	mi := &file_daemon_proto_msgTypes[44]
+	mi := &file_daemon_proto_msgTypes[54]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3024,7 +3814,7 @@ func (x *AcceptTransferRequest) String() string {
 func (*AcceptTransferRequest) ProtoMessage() {}
 
 func (x *AcceptTransferRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[44]
+	mi := &file_daemon_proto_msgTypes[54]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3037,7 +3827,7 @@ func (x *AcceptTransferRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AcceptTransferRequest.ProtoReflect.Descriptor instead.
 func (*AcceptTransferRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{44}
+	return file_daemon_proto_rawDescGZIP(), []int{54}
 }
 
 func (x *AcceptTransferRequest) GetTransferId() string {
@@ -3063,7 +3853,7 @@ type RejectTransferRequest struct {
 
 func (x *RejectTransferRequest) Reset() {
 	*x = RejectTransferRequest{}
-	mi := &file_daemon_proto_msgTypes[45]
+	mi := &file_daemon_proto_msgTypes[55]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3075,7 +3865,7 @@ func (x *RejectTransferRequest) String() string {
 func (*RejectTransferRequest) ProtoMessage() {}
 
 func (x *RejectTransferRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[45]
+	mi := &file_daemon_proto_msgTypes[55]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3088,7 +3878,7 @@ func (x *RejectTransferRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use RejectTransferRequest.ProtoReflect.Descriptor instead.
 func (*RejectTransferRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{45}
+	return file_daemon_proto_rawDescGZIP(), []int{55}
 }
 
 func (x *RejectTransferRequest) GetTransferId() string {
@@ -3107,7 +3897,7 @@ type CancelTransferRequest struct {
 
 func (x *CancelTransferRequest) Reset() {
 	*x = CancelTransferRequest{}
-	mi := &file_daemon_proto_msgTypes[46]
+	mi := &file_daemon_proto_msgTypes[56]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3119,7 +3909,7 @@ func (x *CancelTransferRequest) String() string {
 func (*CancelTransferRequest) ProtoMessage() {}
 
 func (x *CancelTransferRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[46]
+	mi := &file_daemon_proto_msgTypes[56]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3132,7 +3922,7 @@ func (x *CancelTransferRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CancelTransferRequest.ProtoReflect.Descriptor instead.
 func (*CancelTransferRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{46}
+	return file_daemon_proto_rawDescGZIP(), []int{56}
 }
 
 func (x *CancelTransferRequest) GetTransferId() string {
@@ -3142,6 +3932,298 @@ func (x *CancelTransferRequest) GetTransferId() string {
 	return ""
 }
 
+type SetTransferRateLimitRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	TransferId    string                 `protobuf:"bytes,1,opt,name=transfer_id,json=transferId,proto3" json:"transfer_id,omitempty"`
+	MaxSpeedKbps  int32                  `protobuf:"varint,2,opt,name=max_speed_kbps,json=maxSpeedKbps,proto3" json:"max_speed_kbps,omitempty"` // 0 = back to the limit from the settings
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SetTransferRateLimitRequest) Reset() {
+	*x = SetTransferRateLimitRequest{}
+	mi := &file_daemon_proto_msgTypes[57]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SetTransferRateLimitRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetTransferRateLimitRequest) ProtoMessage() {}
+
+func (x *SetTransferRateLimitRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[57]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetTransferRateLimitRequest.ProtoReflect.Descriptor instead.
+func (*SetTransferRateLimitRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{57}
+}
+
+func (x *SetTransferRateLimitRequest) GetTransferId() string {
+	if x != nil {
+		return x.TransferId
+	}
+	return ""
+}
+
+func (x *SetTransferRateLimitRequest) GetMaxSpeedKbps() int32 {
+	if x != nil {
+		return x.MaxSpeedKbps
+	}
+	return 0
+}
+
+type ListDirectoryRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Path          string                 `protobuf:"bytes,1,opt,name=path,proto3" json:"path,omitempty"` // empty = the daemon user's home directory
+	IncludeHidden bool                   `protobuf:"varint,2,opt,name=include_hidden,json=includeHidden,proto3" json:"include_hidden,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListDirectoryRequest) Reset() {
+	*x = ListDirectoryRequest{}
+	mi := &file_daemon_proto_msgTypes[58]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListDirectoryRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListDirectoryRequest) ProtoMessage() {}
+
+func (x *ListDirectoryRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[58]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListDirectoryRequest.ProtoReflect.Descriptor instead.
+func (*ListDirectoryRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{58}
+}
+
+func (x *ListDirectoryRequest) GetPath() string {
+	if x != nil {
+		return x.Path
+	}
+	return ""
+}
+
+func (x *ListDirectoryRequest) GetIncludeHidden() bool {
+	if x != nil {
+		return x.IncludeHidden
+	}
+	return false
+}
+
+type ListDirectoryResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Path          string                 `protobuf:"bytes,1,opt,name=path,proto3" json:"path,omitempty"`     // absolute, as resolved by the daemon
+	Parent        string                 `protobuf:"bytes,2,opt,name=parent,proto3" json:"parent,omitempty"` // empty at the root
+	Entries       []*RemoteFileEntry     `protobuf:"bytes,3,rep,name=entries,proto3" json:"entries,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListDirectoryResponse) Reset() {
+	*x = ListDirectoryResponse{}
+	mi := &file_daemon_proto_msgTypes[59]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListDirectoryResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListDirectoryResponse) ProtoMessage() {}
+
+func (x *ListDirectoryResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[59]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListDirectoryResponse.ProtoReflect.Descriptor instead.
+func (*ListDirectoryResponse) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{59}
+}
+
+func (x *ListDirectoryResponse) GetPath() string {
+	if x != nil {
+		return x.Path
+	}
+	return ""
+}
+
+func (x *ListDirectoryResponse) GetParent() string {
+	if x != nil {
+		return x.Parent
+	}
+	return ""
+}
+
+func (x *ListDirectoryResponse) GetEntries() []*RemoteFileEntry {
+	if x != nil {
+		return x.Entries
+	}
+	return nil
+}
+
+type StatPathRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Path          string                 `protobuf:"bytes,1,opt,name=path,proto3" json:"path,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *StatPathRequest) Reset() {
+	*x = StatPathRequest{}
+	mi := &file_daemon_proto_msgTypes[60]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *StatPathRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StatPathRequest) ProtoMessage() {}
+
+func (x *StatPathRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[60]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StatPathRequest.ProtoReflect.Descriptor instead.
+func (*StatPathRequest) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{60}
+}
+
+func (x *StatPathRequest) GetPath() string {
+	if x != nil {
+		return x.Path
+	}
+	return ""
+}
+
+type RemoteFileEntry struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Name          string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	Path          string                 `protobuf:"bytes,2,opt,name=path,proto3" json:"path,omitempty"` // absolute
+	IsDir         bool                   `protobuf:"varint,3,opt,name=is_dir,json=isDir,proto3" json:"is_dir,omitempty"`
+	SizeBytes     int64                  `protobuf:"varint,4,opt,name=size_bytes,json=sizeBytes,proto3" json:"size_bytes,omitempty"`
+	ModifiedAt    *timestamppb.Timestamp `protobuf:"bytes,5,opt,name=modified_at,json=modifiedAt,proto3" json:"modified_at,omitempty"`
+	Readable      bool                   `protobuf:"varint,6,opt,name=readable,proto3" json:"readable,omitempty"` // the daemon can open it
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RemoteFileEntry) Reset() {
+	*x = RemoteFileEntry{}
+	mi := &file_daemon_proto_msgTypes[61]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RemoteFileEntry) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RemoteFileEntry) ProtoMessage() {}
+
+func (x *RemoteFileEntry) ProtoReflect() protoreflect.Message {
+	mi := &file_daemon_proto_msgTypes[61]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RemoteFileEntry.ProtoReflect.Descriptor instead.
+func (*RemoteFileEntry) Descriptor() ([]byte, []int) {
+	return file_daemon_proto_rawDescGZIP(), []int{61}
+}
+
+func (x *RemoteFileEntry) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *RemoteFileEntry) GetPath() string {
+	if x != nil {
+		return x.Path
+	}
+	return ""
+}
+
+func (x *RemoteFileEntry) GetIsDir() bool {
+	if x != nil {
+		return x.IsDir
+	}
+	return false
+}
+
+func (x *RemoteFileEntry) GetSizeBytes() int64 {
+	if x != nil {
+		return x.SizeBytes
+	}
+	return 0
+}
+
+func (x *RemoteFileEntry) GetModifiedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.ModifiedAt
+	}
+	return nil
+}
+
+func (x *RemoteFileEntry) GetReadable() bool {
+	if x != nil {
+		return x.Readable
+	}
+	return false
+}
+
 type ListTransfersResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Transfers     []*FileTransfer        `protobuf:"bytes,1,rep,name=transfers,proto3" json:"transfers,omitempty"`
@@ -3151,7 +4233,7 @@ type ListTransfersResponse struct {
 
 func (x *ListTransfersResponse) Reset() {
 	*x = ListTransfersResponse{}
-	mi := &file_daemon_proto_msgTypes[47]
+	mi := &file_daemon_proto_msgTypes[62]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3163,7 +4245,7 @@ func (x *ListTransfersResponse) String() string {
 func (*ListTransfersResponse) ProtoMessage() {}
 
 func (x *ListTransfersResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[47]
+	mi := &file_daemon_proto_msgTypes[62]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3176,7 +4258,7 @@ func (x *ListTransfersResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ListTransfersResponse.ProtoReflect.Descriptor instead.
 func (*ListTransfersResponse) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{47}
+	return file_daemon_proto_rawDescGZIP(), []int{62}
 }
 
 func (x *ListTransfersResponse) GetTransfers() []*FileTransfer {
@@ -3195,7 +4277,7 @@ type UpdateSettingsRequest struct {
 
 func (x *UpdateSettingsRequest) Reset() {
 	*x = UpdateSettingsRequest{}
-	mi := &file_daemon_proto_msgTypes[48]
+	mi := &file_daemon_proto_msgTypes[63]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3207,7 +4289,7 @@ func (x *UpdateSettingsRequest) String() string {
 func (*UpdateSettingsRequest) ProtoMessage() {}
 
 func (x *UpdateSettingsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[48]
+	mi := &file_daemon_proto_msgTypes[63]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3220,7 +4302,7 @@ func (x *UpdateSettingsRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UpdateSettingsRequest.ProtoReflect.Descriptor instead.
 func (*UpdateSettingsRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{48}
+	return file_daemon_proto_rawDescGZIP(), []int{63}
 }
 
 func (x *UpdateSettingsRequest) GetSettings() *Settings {
@@ -3244,7 +4326,7 @@ type VoiceSignal struct {
 
 func (x *VoiceSignal) Reset() {
 	*x = VoiceSignal{}
-	mi := &file_daemon_proto_msgTypes[49]
+	mi := &file_daemon_proto_msgTypes[64]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3256,7 +4338,7 @@ func (x *VoiceSignal) String() string {
 func (*VoiceSignal) ProtoMessage() {}
 
 func (x *VoiceSignal) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[49]
+	mi := &file_daemon_proto_msgTypes[64]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3269,7 +4351,7 @@ func (x *VoiceSignal) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VoiceSignal.ProtoReflect.Descriptor instead.
 func (*VoiceSignal) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{49}
+	return file_daemon_proto_rawDescGZIP(), []int{64}
 }
 
 func (x *VoiceSignal) GetType() string {
@@ -3323,7 +4405,7 @@ type SendSignalRequest struct {
 
 func (x *SendSignalRequest) Reset() {
 	*x = SendSignalRequest{}
-	mi := &file_daemon_proto_msgTypes[50]
+	mi := &file_daemon_proto_msgTypes[65]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3335,7 +4417,7 @@ func (x *SendSignalRequest) String() string {
 func (*SendSignalRequest) ProtoMessage() {}
 
 func (x *SendSignalRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_daemon_proto_msgTypes[50]
+	mi := &file_daemon_proto_msgTypes[65]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3348,7 +4430,7 @@ func (x *SendSignalRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SendSignalRequest.ProtoReflect.Descriptor instead.
 func (*SendSignalRequest) Descriptor() ([]byte, []int) {
-	return file_daemon_proto_rawDescGZIP(), []int{50}
+	return file_daemon_proto_rawDescGZIP(), []int{65}
 }
 
 func (x *SendSignalRequest) GetSignal() *VoiceSignal {
@@ -3379,7 +4461,16 @@ const file_daemon_proto_rawDesc = "" +
 	"\x05email\x18\x02 \x01(\tR\x05email\"&\n" +
 	"\n" +
 	"LoginError\x12\x18\n" +
-	"\amessage\x18\x01 \x01(\tR\amessage\"\xf9\x02\n" +
+	"\amessage\x18\x01 \x01(\tR\amessage\"\xa8\x01\n" +
+	"\n" +
+	"Credential\x12\x12\n" +
+	"\x04kind\x18\x01 \x01(\tR\x04kind\x12\x12\n" +
+	"\x04name\x18\x02 \x01(\tR\x04name\x127\n" +
+	"\tissued_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\bissuedAt\x129\n" +
+	"\n" +
+	"expires_at\x18\x04 \x01(\v2\x1a.google.protobuf.TimestampR\texpiresAt\"N\n" +
+	"\x16GetCredentialsResponse\x124\n" +
+	"\vcredentials\x18\x01 \x03(\v2\x12.daemon.CredentialR\vcredentials\"\xf9\x02\n" +
 	"\x04Peer\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\tR\x02id\x12\x12\n" +
 	"\x04name\x18\x02 \x01(\tR\x04name\x12!\n" +
@@ -3418,7 +4509,7 @@ const file_daemon_proto_rawDesc = "" +
 	"senderName\x12\x18\n" +
 	"\acontent\x18\x05 \x01(\tR\acontent\x123\n" +
 	"\asent_at\x18\x06 \x01(\v2\x1a.google.protobuf.TimestampR\x06sentAt\x12\x1b\n" +
-	"\tis_system\x18\a \x01(\bR\bisSystem\"\xed\x02\n" +
+	"\tis_system\x18\a \x01(\bR\bisSystem\"\xab\x03\n" +
 	"\fFileTransfer\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\tR\x02id\x12\x17\n" +
 	"\apeer_id\x18\x02 \x01(\tR\x06peerId\x12\x1b\n" +
@@ -3433,7 +4524,9 @@ const file_daemon_proto_rawDesc = "" +
 	"\rerror_message\x18\t \x01(\tR\ferrorMessage\x129\n" +
 	"\n" +
 	"started_at\x18\n" +
-	" \x01(\v2\x1a.google.protobuf.TimestampR\tstartedAt\"\xf8\x02\n" +
+	" \x01(\v2\x1a.google.protobuf.TimestampR\tstartedAt\x12\x16\n" +
+	"\x06sha256\x18\v \x01(\tR\x06sha256\x12$\n" +
+	"\x0emax_speed_kbps\x18\f \x01(\x05R\fmaxSpeedKbps\"\xf8\x02\n" +
 	"\bSettings\x12!\n" +
 	"\fauto_connect\x18\x01 \x01(\bR\vautoConnect\x12'\n" +
 	"\x0fstart_minimized\x18\x02 \x01(\bR\x0estartMinimized\x123\n" +
@@ -3443,7 +4536,7 @@ const file_daemon_proto_rawDesc = "" +
 	"\x15max_upload_speed_kbps\x18\x06 \x01(\x05R\x12maxUploadSpeedKbps\x125\n" +
 	"\x17max_download_speed_kbps\x18\a \x01(\x05R\x14maxDownloadSpeedKbps\x12\x14\n" +
 	"\x05theme\x18\b \x01(\tR\x05theme\x12\x1a\n" +
-	"\blanguage\x18\t \x01(\tR\blanguage\"\xa8\x03\n" +
+	"\blanguage\x18\t \x01(\tR\blanguage\"\xe8\x03\n" +
 	"\vDaemonEvent\x12%\n" +
 	"\x04type\x18\x01 \x01(\x0e2\x11.daemon.EventTypeR\x04type\x128\n" +
 	"\ttimestamp\x18\x02 \x01(\v2\x1a.google.protobuf.TimestampR\ttimestamp\x12C\n" +
@@ -3453,8 +4546,19 @@ const file_daemon_proto_rawDesc = "" +
 	"peer_event\x18\v \x01(\v2\x11.daemon.PeerEventH\x00R\tpeerEvent\x128\n" +
 	"\fchat_message\x18\f \x01(\v2\x13.daemon.ChatMessageH\x00R\vchatMessage\x12>\n" +
 	"\x0etransfer_event\x18\r \x01(\v2\x15.daemon.TransferEventH\x00R\rtransferEvent\x12:\n" +
-	"\fnotification\x18\x0e \x01(\v2\x14.daemon.NotificationH\x00R\fnotificationB\t\n" +
-	"\apayload\"\xa5\x01\n" +
+	"\fnotification\x18\x0e \x01(\v2\x14.daemon.NotificationH\x00R\fnotification\x12>\n" +
+	"\x0esecurity_event\x18\x0f \x01(\v2\x15.daemon.SecurityEventH\x00R\rsecurityEventB\t\n" +
+	"\apayload\"\xd9\x01\n" +
+	"\rSecurityEvent\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\x12-\n" +
+	"\x04kind\x18\x02 \x01(\x0e2\x19.daemon.SecurityEventKindR\x04kind\x12\x18\n" +
+	"\amessage\x18\x03 \x01(\tR\amessage\x12\x17\n" +
+	"\apeer_id\x18\x04 \x01(\tR\x06peerId\x12\x1d\n" +
+	"\n" +
+	"network_id\x18\x05 \x01(\tR\tnetworkId\x12\x1f\n" +
+	"\vdevice_name\x18\x06 \x01(\tR\n" +
+	"deviceName\x12\x16\n" +
+	"\x06source\x18\a \x01(\tR\x06source\"\xa5\x01\n" +
 	"\x12StatusChangedEvent\x127\n" +
 	"\n" +
 	"old_status\x18\x01 \x01(\x0e2\x18.daemon.ConnectionStatusR\toldStatus\x127\n" +
@@ -3478,7 +4582,7 @@ const file_daemon_proto_rawDesc = "" +
 	"virtual_ip\x18\x02 \x01(\tR\tvirtualIp\x12!\n" +
 	"\factive_peers\x18\x03 \x01(\x05R\vactivePeers\x12,\n" +
 	"\x12current_network_id\x18\x04 \x01(\tR\x10currentNetworkId\x120\n" +
-	"\x14current_network_name\x18\x05 \x01(\tR\x12currentNetworkName\"\xa5\x01\n" +
+	"\x14current_network_name\x18\x05 \x01(\tR\x12currentNetworkName\"\x9f\x02\n" +
 	"\x0fVersionResponse\x12\x18\n" +
 	"\aversion\x18\x01 \x01(\tR\aversion\x12\x1d\n" +
 	"\n" +
@@ -3487,7 +4591,11 @@ const file_daemon_proto_rawDesc = "" +
 	"\n" +
 	"go_version\x18\x04 \x01(\tR\tgoVersion\x12\x0e\n" +
 	"\x02os\x18\x05 \x01(\tR\x02os\x12\x12\n" +
-	"\x04arch\x18\x06 \x01(\tR\x04arch\"F\n" +
+	"\x04arch\x18\x06 \x01(\tR\x04arch\x12;\n" +
+	"\vdaemon_time\x18\a \x01(\v2\x1a.google.protobuf.TimestampR\n" +
+	"daemonTime\x12;\n" +
+	"\vserver_time\x18\b \x01(\v2\x1a.google.protobuf.TimestampR\n" +
+	"serverTime\"F\n" +
 	"\x10SubscribeRequest\x122\n" +
 	"\vevent_types\x18\x01 \x03(\x0e2\x11.daemon.EventTypeR\n" +
 	"eventTypes\"`\n" +
@@ -3519,7 +4627,17 @@ const file_daemon_proto_rawDesc = "" +
 	"\vdescription\x18\x03 \x01(\tR\vdescription\"5\n" +
 	"\x14DeleteNetworkRequest\x12\x1d\n" +
 	"\n" +
-	"network_id\x18\x01 \x01(\tR\tnetworkId\"v\n" +
+	"network_id\x18\x01 \x01(\tR\tnetworkId\"8\n" +
+	"\x17ListJoinRequestsRequest\x12\x1d\n" +
+	"\n" +
+	"network_id\x18\x01 \x01(\tR\tnetworkId\"\x93\x01\n" +
+	"\vJoinRequest\x12\x17\n" +
+	"\apeer_id\x18\x01 \x01(\tR\x06peerId\x12\x12\n" +
+	"\x04name\x18\x02 \x01(\tR\x04name\x12\x18\n" +
+	"\amessage\x18\x03 \x01(\tR\amessage\x12=\n" +
+	"\frequested_at\x18\x04 \x01(\v2\x1a.google.protobuf.TimestampR\vrequestedAt\"K\n" +
+	"\x18ListJoinRequestsResponse\x12/\n" +
+	"\brequests\x18\x01 \x03(\v2\x13.daemon.JoinRequestR\brequests\"v\n" +
 	"\x15GenerateInviteRequest\x12\x1d\n" +
 	"\n" +
 	"network_id\x18\x01 \x01(\tR\tnetworkId\x12\x19\n" +
@@ -3531,7 +4649,34 @@ const file_daemon_proto_rawDesc = "" +
 	"\n" +
 	"invite_url\x18\x02 \x01(\tR\tinviteUrl\x129\n" +
 	"\n" +
-	"expires_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\texpiresAt\"C\n" +
+	"expires_at\x18\x03 \x01(\v2\x1a.google.protobuf.TimestampR\texpiresAt\"7\n" +
+	"\x14PreviewInviteRequest\x12\x1f\n" +
+	"\vinvite_code\x18\x01 \x01(\tR\n" +
+	"inviteCode\"\x89\x04\n" +
+	"\rInvitePreview\x12\x14\n" +
+	"\x05valid\x18\x01 \x01(\bR\x05valid\x12%\n" +
+	"\x0einvalid_reason\x18\x02 \x01(\tR\rinvalidReason\x12\x1d\n" +
+	"\n" +
+	"network_id\x18\x03 \x01(\tR\tnetworkId\x12!\n" +
+	"\fnetwork_name\x18\x04 \x01(\tR\vnetworkName\x12 \n" +
+	"\vdescription\x18\x05 \x01(\tR\vdescription\x12!\n" +
+	"\fmember_count\x18\x06 \x01(\x05R\vmemberCount\x12!\n" +
+	"\fonline_count\x18\a \x01(\x05R\vonlineCount\x12\x19\n" +
+	"\bowner_id\x18\b \x01(\tR\aownerId\x12\x1d\n" +
+	"\n" +
+	"owner_name\x18\t \x01(\tR\townerName\x12+\n" +
+	"\x11requires_approval\x18\n" +
+	" \x01(\bR\x10requiresApproval\x12&\n" +
+	"\x0fmin_trust_level\x18\v \x01(\tR\rminTrustLevel\x12 \n" +
+	"\vpermissions\x18\f \x03(\tR\vpermissions\x129\n" +
+	"\n" +
+	"expires_at\x18\r \x01(\v2\x1a.google.protobuf.TimestampR\texpiresAt\x12%\n" +
+	"\x0euses_remaining\x18\x0e \x01(\x05R\rusesRemaining\"[\n" +
+	"\x18TransferOwnershipRequest\x12\x1d\n" +
+	"\n" +
+	"network_id\x18\x01 \x01(\tR\tnetworkId\x12 \n" +
+	"\fnew_owner_id\x18\x02 \x01(\tR\n" +
+	"newOwnerId\"C\n" +
 	"\x14ListNetworksResponse\x12+\n" +
 	"\bnetworks\x18\x01 \x03(\v2\x0f.daemon.NetworkR\bnetworks\"0\n" +
 	"\x0fGetPeersRequest\x12\x1d\n" +
@@ -3554,7 +4699,14 @@ const file_daemon_proto_rawDesc = "" +
 	"\x10UnbanPeerRequest\x12\x1d\n" +
 	"\n" +
 	"network_id\x18\x01 \x01(\tR\tnetworkId\x12\x17\n" +
-	"\apeer_id\x18\x02 \x01(\tR\x06peerId\"p\n" +
+	"\apeer_id\x18\x02 \x01(\tR\x06peerId\"\x84\x01\n" +
+	"\x11ReportPeerRequest\x12\x1d\n" +
+	"\n" +
+	"network_id\x18\x01 \x01(\tR\tnetworkId\x12\x17\n" +
+	"\apeer_id\x18\x02 \x01(\tR\x06peerId\x12\x16\n" +
+	"\x06reason\x18\x03 \x01(\tR\x06reason\x12\x1f\n" +
+	"\vmessage_ids\x18\x04 \x03(\tR\n" +
+	"messageIds\"p\n" +
 	"\x12SendMessageRequest\x12\x1d\n" +
 	"\n" +
 	"network_id\x18\x01 \x01(\tR\tnetworkId\x12\x18\n" +
@@ -3588,7 +4740,29 @@ const file_daemon_proto_rawDesc = "" +
 	"transferId\"8\n" +
 	"\x15CancelTransferRequest\x12\x1f\n" +
 	"\vtransfer_id\x18\x01 \x01(\tR\n" +
-	"transferId\"K\n" +
+	"transferId\"d\n" +
+	"\x1bSetTransferRateLimitRequest\x12\x1f\n" +
+	"\vtransfer_id\x18\x01 \x01(\tR\n" +
+	"transferId\x12$\n" +
+	"\x0emax_speed_kbps\x18\x02 \x01(\x05R\fmaxSpeedKbps\"Q\n" +
+	"\x14ListDirectoryRequest\x12\x12\n" +
+	"\x04path\x18\x01 \x01(\tR\x04path\x12%\n" +
+	"\x0einclude_hidden\x18\x02 \x01(\bR\rincludeHidden\"v\n" +
+	"\x15ListDirectoryResponse\x12\x12\n" +
+	"\x04path\x18\x01 \x01(\tR\x04path\x12\x16\n" +
+	"\x06parent\x18\x02 \x01(\tR\x06parent\x121\n" +
+	"\aentries\x18\x03 \x03(\v2\x17.daemon.RemoteFileEntryR\aentries\"%\n" +
+	"\x0fStatPathRequest\x12\x12\n" +
+	"\x04path\x18\x01 \x01(\tR\x04path\"\xc8\x01\n" +
+	"\x0fRemoteFileEntry\x12\x12\n" +
+	"\x04name\x18\x01 \x01(\tR\x04name\x12\x12\n" +
+	"\x04path\x18\x02 \x01(\tR\x04path\x12\x15\n" +
+	"\x06is_dir\x18\x03 \x01(\bR\x05isDir\x12\x1d\n" +
+	"\n" +
+	"size_bytes\x18\x04 \x01(\x03R\tsizeBytes\x12;\n" +
+	"\vmodified_at\x18\x05 \x01(\v2\x1a.google.protobuf.TimestampR\n" +
+	"modifiedAt\x12\x1a\n" +
+	"\breadable\x18\x06 \x01(\bR\breadable\"K\n" +
 	"\x15ListTransfersResponse\x122\n" +
 	"\ttransfers\x18\x01 \x03(\v2\x14.daemon.FileTransferR\ttransfers\"E\n" +
 	"\x15UpdateSettingsRequest\x12,\n" +
@@ -3624,7 +4798,7 @@ const file_daemon_proto_rawDesc = "" +
 	"\x1bTRANSFER_STATUS_IN_PROGRESS\x10\x02\x12\x1d\n" +
 	"\x19TRANSFER_STATUS_COMPLETED\x10\x03\x12\x1a\n" +
 	"\x16TRANSFER_STATUS_FAILED\x10\x04\x12\x1d\n" +
-	"\x19TRANSFER_STATUS_CANCELLED\x10\x05*\xf9\x01\n" +
+	"\x19TRANSFER_STATUS_CANCELLED\x10\x05*\x92\x02\n" +
 	"\tEventType\x12\x1a\n" +
 	"\x16EVENT_TYPE_UNSPECIFIED\x10\x00\x12\x1d\n" +
 	"\x19EVENT_TYPE_STATUS_CHANGED\x10\x01\x12\x1a\n" +
@@ -3633,14 +4807,21 @@ const file_daemon_proto_rawDesc = "" +
 	"\x17EVENT_TYPE_CHAT_MESSAGE\x10\x04\x12\x1f\n" +
 	"\x1bEVENT_TYPE_TRANSFER_REQUEST\x10\x05\x12 \n" +
 	"\x1cEVENT_TYPE_TRANSFER_PROGRESS\x10\x06\x12\x1b\n" +
-	"\x17EVENT_TYPE_NOTIFICATION\x10\a2\xc0\x02\n" +
+	"\x17EVENT_TYPE_NOTIFICATION\x10\a\x12\x17\n" +
+	"\x13EVENT_TYPE_SECURITY\x10\b*\xb1\x01\n" +
+	"\x11SecurityEventKind\x12#\n" +
+	"\x1fSECURITY_EVENT_KIND_UNSPECIFIED\x10\x00\x12$\n" +
+	" SECURITY_EVENT_KIND_DEVICE_ADDED\x10\x01\x12(\n" +
+	"$SECURITY_EVENT_KIND_PEER_KEY_CHANGED\x10\x02\x12'\n" +
+	"#SECURITY_EVENT_KIND_IPC_AUTH_FAILED\x10\x032\x8a\x03\n" +
 	"\rDaemonService\x12@\n" +
 	"\tGetStatus\x12\x18.daemon.GetStatusRequest\x1a\x19.daemon.GetStatusResponse\x12=\n" +
 	"\n" +
 	"GetVersion\x12\x16.google.protobuf.Empty\x1a\x17.daemon.VersionResponse\x12:\n" +
 	"\bShutdown\x12\x16.google.protobuf.Empty\x1a\x16.google.protobuf.Empty\x12<\n" +
 	"\tSubscribe\x12\x18.daemon.SubscribeRequest\x1a\x13.daemon.DaemonEvent0\x01\x124\n" +
-	"\x05Login\x12\x14.daemon.LoginRequest\x1a\x13.daemon.LoginUpdate0\x012\xc9\x04\n" +
+	"\x05Login\x12\x14.daemon.LoginRequest\x1a\x13.daemon.LoginUpdate0\x01\x12H\n" +
+	"\x0eGetCredentials\x12\x16.google.protobuf.Empty\x1a\x1e.daemon.GetCredentialsResponse2\xbc\a\n" +
 	"\x0eNetworkService\x12L\n" +
 	"\rCreateNetwork\x12\x1c.daemon.CreateNetworkRequest\x1a\x1d.daemon.CreateNetworkResponse\x12F\n" +
 	"\vJoinNetwork\x12\x1a.daemon.JoinNetworkRequest\x1a\x1b.daemon.JoinNetworkResponse\x12I\n" +
@@ -3650,32 +4831,43 @@ const file_daemon_proto_rawDesc = "" +
 	"GetNetwork\x12\x19.daemon.GetNetworkRequest\x1a\x0f.daemon.Network\x12>\n" +
 	"\rUpdateNetwork\x12\x1c.daemon.UpdateNetworkRequest\x1a\x0f.daemon.Network\x12E\n" +
 	"\rDeleteNetwork\x12\x1c.daemon.DeleteNetworkRequest\x1a\x16.google.protobuf.Empty\x12O\n" +
-	"\x0eGenerateInvite\x12\x1d.daemon.GenerateInviteRequest\x1a\x1e.daemon.GenerateInviteResponse2\xb4\x02\n" +
+	"\x0eGenerateInvite\x12\x1d.daemon.GenerateInviteRequest\x1a\x1e.daemon.GenerateInviteResponse\x12D\n" +
+	"\rPreviewInvite\x12\x1c.daemon.PreviewInviteRequest\x1a\x15.daemon.InvitePreview\x12M\n" +
+	"\x11TransferOwnership\x12 .daemon.TransferOwnershipRequest\x1a\x16.google.protobuf.Empty\x12@\n" +
+	"\x0eConnectNetwork\x12\x16.google.protobuf.Empty\x1a\x16.google.protobuf.Empty\x12C\n" +
+	"\x11DisconnectNetwork\x12\x16.google.protobuf.Empty\x1a\x16.google.protobuf.Empty\x12U\n" +
+	"\x10ListJoinRequests\x12\x1f.daemon.ListJoinRequestsRequest\x1a .daemon.ListJoinRequestsResponse2\xf5\x02\n" +
 	"\vPeerService\x12=\n" +
 	"\bGetPeers\x12\x17.daemon.GetPeersRequest\x1a\x18.daemon.GetPeersResponse\x12/\n" +
 	"\aGetPeer\x12\x16.daemon.GetPeerRequest\x1a\f.daemon.Peer\x12;\n" +
 	"\bKickPeer\x12\x17.daemon.KickPeerRequest\x1a\x16.google.protobuf.Empty\x129\n" +
 	"\aBanPeer\x12\x16.daemon.BanPeerRequest\x1a\x16.google.protobuf.Empty\x12=\n" +
-	"\tUnbanPeer\x12\x18.daemon.UnbanPeerRequest\x1a\x16.google.protobuf.Empty2\xeb\x01\n" +
+	"\tUnbanPeer\x12\x18.daemon.UnbanPeerRequest\x1a\x16.google.protobuf.Empty\x12?\n" +
+	"\n" +
+	"ReportPeer\x12\x19.daemon.ReportPeerRequest\x1a\x16.google.protobuf.Empty2\xeb\x01\n" +
 	"\vChatService\x12F\n" +
 	"\vSendMessage\x12\x1a.daemon.SendMessageRequest\x1a\x1b.daemon.SendMessageResponse\x12F\n" +
 	"\vGetMessages\x12\x1a.daemon.GetMessagesRequest\x1a\x1b.daemon.GetMessagesResponse\x12L\n" +
-	"\x11SubscribeMessages\x12 .daemon.SubscribeMessagesRequest\x1a\x13.daemon.ChatMessage0\x012\xba\x03\n" +
+	"\x11SubscribeMessages\x12 .daemon.SubscribeMessagesRequest\x1a\x13.daemon.ChatMessage0\x012\x9b\x05\n" +
 	"\x0fTransferService\x12=\n" +
 	"\bSendFile\x12\x17.daemon.SendFileRequest\x1a\x18.daemon.SendFileResponse\x12G\n" +
 	"\x0eAcceptTransfer\x12\x1d.daemon.AcceptTransferRequest\x1a\x16.google.protobuf.Empty\x12G\n" +
 	"\x0eRejectTransfer\x12\x1d.daemon.RejectTransferRequest\x1a\x16.google.protobuf.Empty\x12G\n" +
 	"\x0eCancelTransfer\x12\x1d.daemon.CancelTransferRequest\x1a\x16.google.protobuf.Empty\x12F\n" +
 	"\rListTransfers\x12\x16.google.protobuf.Empty\x1a\x1d.daemon.ListTransfersResponse\x12E\n" +
-	"\x12SubscribeTransfers\x12\x16.google.protobuf.Empty\x1a\x15.daemon.TransferEvent0\x012\x92\x01\n" +
+	"\x12SubscribeTransfers\x12\x16.google.protobuf.Empty\x1a\x15.daemon.TransferEvent0\x01\x12S\n" +
+	"\x14SetTransferRateLimit\x12#.daemon.SetTransferRateLimitRequest\x1a\x16.google.protobuf.Empty\x12L\n" +
+	"\rListDirectory\x12\x1c.daemon.ListDirectoryRequest\x1a\x1d.daemon.ListDirectoryResponse\x12<\n" +
+	"\bStatPath\x12\x17.daemon.StatPathRequest\x1a\x17.daemon.RemoteFileEntry2\x92\x01\n" +
 	"\fVoiceService\x12?\n" +
 	"\n" +
 	"SendSignal\x12\x19.daemon.SendSignalRequest\x1a\x16.google.protobuf.Empty\x12A\n" +
-	"\x10SubscribeSignals\x12\x16.google.protobuf.Empty\x1a\x13.daemon.VoiceSignal0\x012\xc8\x01\n" +
+	"\x10SubscribeSignals\x12\x16.google.protobuf.Empty\x1a\x13.daemon.VoiceSignal0\x012\x89\x02\n" +
 	"\x0fSettingsService\x127\n" +
 	"\vGetSettings\x12\x16.google.protobuf.Empty\x1a\x10.daemon.Settings\x12A\n" +
 	"\x0eUpdateSettings\x12\x1d.daemon.UpdateSettingsRequest\x1a\x10.daemon.Settings\x129\n" +
-	"\rResetSettings\x12\x16.google.protobuf.Empty\x1a\x10.daemon.SettingsB:Z8github.com/orhaniscoding/goconnect/server/internal/protob\x06proto3"
+	"\rResetSettings\x12\x16.google.protobuf.Empty\x1a\x10.daemon.Settings\x12?\n" +
+	"\x11SubscribeSettings\x12\x16.google.protobuf.Empty\x1a\x10.daemon.Settings0\x01B:Z8github.com/orhaniscoding/goconnect/server/internal/protob\x06proto3"
 
 var (
 	file_daemon_proto_rawDescOnce sync.Once
@@ -3689,174 +4881,224 @@ func file_daemon_proto_rawDescGZIP() []byte {
 	return file_daemon_proto_rawDescData
 }
 
-var file_daemon_proto_enumTypes = make([]protoimpl.EnumInfo, 5)
-var file_daemon_proto_msgTypes = make([]protoimpl.MessageInfo, 51)
+var file_daemon_proto_enumTypes = make([]protoimpl.EnumInfo, 6)
+var file_daemon_proto_msgTypes = make([]protoimpl.MessageInfo, 66)
 var file_daemon_proto_goTypes = []any{
-	(ConnectionStatus)(0),            // 0: daemon.ConnectionStatus
-	(ConnectionType)(0),              // 1: daemon.ConnectionType
-	(NetworkRole)(0),                 // 2: daemon.NetworkRole
-	(TransferStatus)(0),              // 3: daemon.TransferStatus
-	(EventType)(0),                   // 4: daemon.EventType
-	(*LoginRequest)(nil),             // 5: daemon.LoginRequest
-	(*LoginUpdate)(nil),              // 6: daemon.LoginUpdate
-	(*LoginInstructions)(nil),        // 7: daemon.LoginInstructions
-	(*LoginSuccess)(nil),             // 8: daemon.LoginSuccess
-	(*LoginError)(nil),               // 9: daemon.LoginError
-	(*Peer)(nil),                     // 10: daemon.Peer
-	(*Network)(nil),                  // 11: daemon.Network
-	(*ChatMessage)(nil),              // 12: daemon.ChatMessage
-	(*FileTransfer)(nil),             // 13: daemon.FileTransfer
-	(*Settings)(nil),                 // 14: daemon.Settings
-	(*DaemonEvent)(nil),              // 15: daemon.DaemonEvent
-	(*StatusChangedEvent)(nil),       // 16: daemon.StatusChangedEvent
-	(*PeerEvent)(nil),                // 17: daemon.PeerEvent
-	(*TransferEvent)(nil),            // 18: daemon.TransferEvent
-	(*Notification)(nil),             // 19: daemon.Notification
-	(*GetStatusRequest)(nil),         // 20: daemon.GetStatusRequest
-	(*GetStatusResponse)(nil),        // 21: daemon.GetStatusResponse
-	(*VersionResponse)(nil),          // 22: daemon.VersionResponse
-	(*SubscribeRequest)(nil),         // 23: daemon.SubscribeRequest
-	(*CreateNetworkRequest)(nil),     // 24: daemon.CreateNetworkRequest
-	(*CreateNetworkResponse)(nil),    // 25: daemon.CreateNetworkResponse
-	(*JoinNetworkRequest)(nil),       // 26: daemon.JoinNetworkRequest
-	(*JoinNetworkResponse)(nil),      // 27: daemon.JoinNetworkResponse
-	(*LeaveNetworkRequest)(nil),      // 28: daemon.LeaveNetworkRequest
-	(*LeaveNetworkResponse)(nil),     // 29: daemon.LeaveNetworkResponse
-	(*GetNetworkRequest)(nil),        // 30: daemon.GetNetworkRequest
-	(*UpdateNetworkRequest)(nil),     // 31: daemon.UpdateNetworkRequest
-	(*DeleteNetworkRequest)(nil),     // 32: daemon.DeleteNetworkRequest
-	(*GenerateInviteRequest)(nil),    // 33: daemon.GenerateInviteRequest
-	(*GenerateInviteResponse)(nil),   // 34: daemon.GenerateInviteResponse
-	(*ListNetworksResponse)(nil),     // 35: daemon.ListNetworksResponse
-	(*GetPeersRequest)(nil),          // 36: daemon.GetPeersRequest
-	(*GetPeersResponse)(nil),         // 37: daemon.GetPeersResponse
-	(*GetPeerRequest)(nil),           // 38: daemon.GetPeerRequest
-	(*KickPeerRequest)(nil),          // 39: daemon.KickPeerRequest
-	(*BanPeerRequest)(nil),           // 40: daemon.BanPeerRequest
-	(*UnbanPeerRequest)(nil),         // 41: daemon.UnbanPeerRequest
-	(*SendMessageRequest)(nil),       // 42: daemon.SendMessageRequest
-	(*SendMessageResponse)(nil),      // 43: daemon.SendMessageResponse
-	(*GetMessagesRequest)(nil),       // 44: daemon.GetMessagesRequest
-	(*GetMessagesResponse)(nil),      // 45: daemon.GetMessagesResponse
-	(*SubscribeMessagesRequest)(nil), // 46: daemon.SubscribeMessagesRequest
-	(*SendFileRequest)(nil),          // 47: daemon.SendFileRequest
-	(*SendFileResponse)(nil),         // 48: daemon.SendFileResponse
-	(*AcceptTransferRequest)(nil),    // 49: daemon.AcceptTransferRequest
-	(*RejectTransferRequest)(nil),    // 50: daemon.RejectTransferRequest
-	(*CancelTransferRequest)(nil),    // 51: daemon.CancelTransferRequest
-	(*ListTransfersResponse)(nil),    // 52: daemon.ListTransfersResponse
-	(*UpdateSettingsRequest)(nil),    // 53: daemon.UpdateSettingsRequest
-	(*VoiceSignal)(nil),              // 54: daemon.VoiceSignal
-	(*SendSignalRequest)(nil),        // 55: daemon.SendSignalRequest
-	(*timestamppb.Timestamp)(nil),    // 56: google.protobuf.Timestamp
-	(*emptypb.Empty)(nil),            // 57: google.protobuf.Empty
+	(ConnectionStatus)(0),               // 0: daemon.ConnectionStatus
+	(ConnectionType)(0),                 // 1: daemon.ConnectionType
+	(NetworkRole)(0),                    // 2: daemon.NetworkRole
+	(TransferStatus)(0),                 // 3: daemon.TransferStatus
+	(EventType)(0),                      // 4: daemon.EventType
+	(SecurityEventKind)(0),              // 5: daemon.SecurityEventKind
+	(*LoginRequest)(nil),                // 6: daemon.LoginRequest
+	(*LoginUpdate)(nil),                 // 7: daemon.LoginUpdate
+	(*LoginInstructions)(nil),           // 8: daemon.LoginInstructions
+	(*LoginSuccess)(nil),                // 9: daemon.LoginSuccess
+	(*LoginError)(nil),                  // 10: daemon.LoginError
+	(*Credential)(nil),                  // 11: daemon.Credential
+	(*GetCredentialsResponse)(nil),      // 12: daemon.GetCredentialsResponse
+	(*Peer)(nil),                        // 13: daemon.Peer
+	(*Network)(nil),                     // 14: daemon.Network
+	(*ChatMessage)(nil),                 // 15: daemon.ChatMessage
+	(*FileTransfer)(nil),                // 16: daemon.FileTransfer
+	(*Settings)(nil),                    // 17: daemon.Settings
+	(*DaemonEvent)(nil),                 // 18: daemon.DaemonEvent
+	(*SecurityEvent)(nil),               // 19: daemon.SecurityEvent
+	(*StatusChangedEvent)(nil),          // 20: daemon.StatusChangedEvent
+	(*PeerEvent)(nil),                   // 21: daemon.PeerEvent
+	(*TransferEvent)(nil),               // 22: daemon.TransferEvent
+	(*Notification)(nil),                // 23: daemon.Notification
+	(*GetStatusRequest)(nil),            // 24: daemon.GetStatusRequest
+	(*GetStatusResponse)(nil),           // 25: daemon.GetStatusResponse
+	(*VersionResponse)(nil),             // 26: daemon.VersionResponse
+	(*SubscribeRequest)(nil),            // 27: daemon.SubscribeRequest
+	(*CreateNetworkRequest)(nil),        // 28: daemon.CreateNetworkRequest
+	(*CreateNetworkResponse)(nil),       // 29: daemon.CreateNetworkResponse
+	(*JoinNetworkRequest)(nil),          // 30: daemon.JoinNetworkRequest
+	(*JoinNetworkResponse)(nil),         // 31: daemon.JoinNetworkResponse
+	(*LeaveNetworkRequest)(nil),         // 32: daemon.LeaveNetworkRequest
+	(*LeaveNetworkResponse)(nil),        // 33: daemon.LeaveNetworkResponse
+	(*GetNetworkRequest)(nil),           // 34: daemon.GetNetworkRequest
+	(*UpdateNetworkRequest)(nil),        // 35: daemon.UpdateNetworkRequest
+	(*DeleteNetworkRequest)(nil),        // 36: daemon.DeleteNetworkRequest
+	(*ListJoinRequestsRequest)(nil),     // 37: daemon.ListJoinRequestsRequest
+	(*JoinRequest)(nil),                 // 38: daemon.JoinRequest
+	(*ListJoinRequestsResponse)(nil),    // 39: daemon.ListJoinRequestsResponse
+	(*GenerateInviteRequest)(nil),       // 40: daemon.GenerateInviteRequest
+	(*GenerateInviteResponse)(nil),      // 41: daemon.GenerateInviteResponse
+	(*PreviewInviteRequest)(nil),        // 42: daemon.PreviewInviteRequest
+	(*InvitePreview)(nil),               // 43: daemon.InvitePreview
+	(*TransferOwnershipRequest)(nil),    // 44: daemon.TransferOwnershipRequest
+	(*ListNetworksResponse)(nil),        // 45: daemon.ListNetworksResponse
+	(*GetPeersRequest)(nil),             // 46: daemon.GetPeersRequest
+	(*GetPeersResponse)(nil),            // 47: daemon.GetPeersResponse
+	(*GetPeerRequest)(nil),              // 48: daemon.GetPeerRequest
+	(*KickPeerRequest)(nil),             // 49: daemon.KickPeerRequest
+	(*BanPeerRequest)(nil),              // 50: daemon.BanPeerRequest
+	(*UnbanPeerRequest)(nil),            // 51: daemon.UnbanPeerRequest
+	(*ReportPeerRequest)(nil),           // 52: daemon.ReportPeerRequest
+	(*SendMessageRequest)(nil),          // 53: daemon.SendMessageRequest
+	(*SendMessageResponse)(nil),         // 54: daemon.SendMessageResponse
+	(*GetMessagesRequest)(nil),          // 55: daemon.GetMessagesRequest
+	(*GetMessagesResponse)(nil),         // 56: daemon.GetMessagesResponse
+	(*SubscribeMessagesRequest)(nil),    // 57: daemon.SubscribeMessagesRequest
+	(*SendFileRequest)(nil),             // 58: daemon.SendFileRequest
+	(*SendFileResponse)(nil),            // 59: daemon.SendFileResponse
+	(*AcceptTransferRequest)(nil),       // 60: daemon.AcceptTransferRequest
+	(*RejectTransferRequest)(nil),       // 61: daemon.RejectTransferRequest
+	(*CancelTransferRequest)(nil),       // 62: daemon.CancelTransferRequest
+	(*SetTransferRateLimitRequest)(nil), // 63: daemon.SetTransferRateLimitRequest
+	(*ListDirectoryRequest)(nil),        // 64: daemon.ListDirectoryRequest
+	(*ListDirectoryResponse)(nil),       // 65: daemon.ListDirectoryResponse
+	(*StatPathRequest)(nil),             // 66: daemon.StatPathRequest
+	(*RemoteFileEntry)(nil),             // 67: daemon.RemoteFileEntry
+	(*ListTransfersResponse)(nil),       // 68: daemon.ListTransfersResponse
+	(*UpdateSettingsRequest)(nil),       // 69: daemon.UpdateSettingsRequest
+	(*VoiceSignal)(nil),                 // 70: daemon.VoiceSignal
+	(*SendSignalRequest)(nil),           // 71: daemon.SendSignalRequest
+	(*timestamppb.Timestamp)(nil),       // 72: google.protobuf.Timestamp
+	(*emptypb.Empty)(nil),               // 73: google.protobuf.Empty
 }
 var file_daemon_proto_depIdxs = []int32{
-	7,  // 0: daemon.LoginUpdate.instructions:type_name -> daemon.LoginInstructions
-	8,  // 1: daemon.LoginUpdate.success:type_name -> daemon.LoginSuccess
-	9,  // 2: daemon.LoginUpdate.error:type_name -> daemon.LoginError
-	0,  // 3: daemon.Peer.status:type_name -> daemon.ConnectionStatus
-	1,  // 4: daemon.Peer.connection_type:type_name -> daemon.ConnectionType
-	56, // 5: daemon.Peer.last_seen:type_name -> google.protobuf.Timestamp
-	2,  // 6: daemon.Peer.role:type_name -> daemon.NetworkRole
-	2,  // 7: daemon.Network.my_role:type_name -> daemon.NetworkRole
-	56, // 8: daemon.Network.created_at:type_name -> google.protobuf.Timestamp
-	56, // 9: daemon.Network.joined_at:type_name -> google.protobuf.Timestamp
-	56, // 10: daemon.ChatMessage.sent_at:type_name -> google.protobuf.Timestamp
-	3,  // 11: daemon.FileTransfer.status:type_name -> daemon.TransferStatus
-	56, // 12: daemon.FileTransfer.started_at:type_name -> google.protobuf.Timestamp
-	4,  // 13: daemon.DaemonEvent.type:type_name -> daemon.EventType
-	56, // 14: daemon.DaemonEvent.timestamp:type_name -> google.protobuf.Timestamp
-	16, // 15: daemon.DaemonEvent.status_changed:type_name -> daemon.StatusChangedEvent
-	17, // 16: daemon.DaemonEvent.peer_event:type_name -> daemon.PeerEvent
-	12, // 17: daemon.DaemonEvent.chat_message:type_name -> daemon.ChatMessage
-	18, // 18: daemon.DaemonEvent.transfer_event:type_name -> daemon.TransferEvent
-	19, // 19: daemon.DaemonEvent.notification:type_name -> daemon.Notification
-	0,  // 20: daemon.StatusChangedEvent.old_status:type_name -> daemon.ConnectionStatus
-	0,  // 21: daemon.StatusChangedEvent.new_status:type_name -> daemon.ConnectionStatus
-	10, // 22: daemon.PeerEvent.peer:type_name -> daemon.Peer
-	13, // 23: daemon.TransferEvent.transfer:type_name -> daemon.FileTransfer
-	0,  // 24: daemon.GetStatusResponse.status:type_name -> daemon.ConnectionStatus
-	4,  // 25: daemon.SubscribeRequest.event_types:type_name -> daemon.EventType
-	11, // 26: daemon.CreateNetworkResponse.network:type_name -> daemon.Network
-	11, // 27: daemon.JoinNetworkResponse.network:type_name -> daemon.Network
-	56, // 28: daemon.GenerateInviteResponse.expires_at:type_name -> google.protobuf.Timestamp
-	11, // 29: daemon.ListNetworksResponse.networks:type_name -> daemon.Network
-	10, // 30: daemon.GetPeersResponse.peers:type_name -> daemon.Peer
-	12, // 31: daemon.SendMessageResponse.message:type_name -> daemon.ChatMessage
-	12, // 32: daemon.GetMessagesResponse.messages:type_name -> daemon.ChatMessage
-	13, // 33: daemon.ListTransfersResponse.transfers:type_name -> daemon.FileTransfer
-	14, // 34: daemon.UpdateSettingsRequest.settings:type_name -> daemon.Settings
-	54, // 35: daemon.SendSignalRequest.signal:type_name -> daemon.VoiceSignal
-	20, // 36: daemon.DaemonService.GetStatus:input_type -> daemon.GetStatusRequest
-	57, // 37: daemon.DaemonService.GetVersion:input_type -> google.protobuf.Empty
-	57, // 38: daemon.DaemonService.Shutdown:input_type -> google.protobuf.Empty
-	23, // 39: daemon.DaemonService.Subscribe:input_type -> daemon.SubscribeRequest
-	5,  // 40: daemon.DaemonService.Login:input_type -> daemon.LoginRequest
-	24, // 41: daemon.NetworkService.CreateNetwork:input_type -> daemon.CreateNetworkRequest
-	26, // 42: daemon.NetworkService.JoinNetwork:input_type -> daemon.JoinNetworkRequest
-	28, // 43: daemon.NetworkService.LeaveNetwork:input_type -> daemon.LeaveNetworkRequest
-	57, // 44: daemon.NetworkService.ListNetworks:input_type -> google.protobuf.Empty
-	30, // 45: daemon.NetworkService.GetNetwork:input_type -> daemon.GetNetworkRequest
-	31, // 46: daemon.NetworkService.UpdateNetwork:input_type -> daemon.UpdateNetworkRequest
-	32, // 47: daemon.NetworkService.DeleteNetwork:input_type -> daemon.DeleteNetworkRequest
-	33, // 48: daemon.NetworkService.GenerateInvite:input_type -> daemon.GenerateInviteRequest
-	36, // 49: daemon.PeerService.GetPeers:input_type -> daemon.GetPeersRequest
-	38, // 50: daemon.PeerService.GetPeer:input_type -> daemon.GetPeerRequest
-	39, // 51: daemon.PeerService.KickPeer:input_type -> daemon.KickPeerRequest
-	40, // 52: daemon.PeerService.BanPeer:input_type -> daemon.BanPeerRequest
-	41, // 53: daemon.PeerService.UnbanPeer:input_type -> daemon.UnbanPeerRequest
-	42, // 54: daemon.ChatService.SendMessage:input_type -> daemon.SendMessageRequest
-	44, // 55: daemon.ChatService.GetMessages:input_type -> daemon.GetMessagesRequest
-	46, // 56: daemon.ChatService.SubscribeMessages:input_type -> daemon.SubscribeMessagesRequest
-	47, // 57: daemon.TransferService.SendFile:input_type -> daemon.SendFileRequest
-	49, // 58: daemon.TransferService.AcceptTransfer:input_type -> daemon.AcceptTransferRequest
-	50, // 59: daemon.TransferService.RejectTransfer:input_type -> daemon.RejectTransferRequest
-	51, // 60: daemon.TransferService.CancelTransfer:input_type -> daemon.CancelTransferRequest
-	57, // 61: daemon.TransferService.ListTransfers:input_type -> google.protobuf.Empty
-	57, // 62: daemon.TransferService.SubscribeTransfers:input_type -> google.protobuf.Empty
-	55, // 63: daemon.VoiceService.SendSignal:input_type -> daemon.SendSignalRequest
-	57, // 64: daemon.VoiceService.SubscribeSignals:input_type -> google.protobuf.Empty
-	57, // 65: daemon.SettingsService.GetSettings:input_type -> google.protobuf.Empty
-	53, // 66: daemon.SettingsService.UpdateSettings:input_type -> daemon.UpdateSettingsRequest
-	57, // 67: daemon.SettingsService.ResetSettings:input_type -> google.protobuf.Empty
-	21, // 68: daemon.DaemonService.GetStatus:output_type -> daemon.GetStatusResponse
-	22, // 69: daemon.DaemonService.GetVersion:output_type -> daemon.VersionResponse
-	57, // 70: daemon.DaemonService.Shutdown:output_type -> google.protobuf.Empty
-	15, // 71: daemon.DaemonService.Subscribe:output_type -> daemon.DaemonEvent
-	6,  // 72: daemon.DaemonService.Login:output_type -> daemon.LoginUpdate
-	25, // 73: daemon.NetworkService.CreateNetwork:output_type -> daemon.CreateNetworkResponse
-	27, // 74: daemon.NetworkService.JoinNetwork:output_type -> daemon.JoinNetworkResponse
-	29, // 75: daemon.NetworkService.LeaveNetwork:output_type -> daemon.LeaveNetworkResponse
-	35, // 76: daemon.NetworkService.ListNetworks:output_type -> daemon.ListNetworksResponse
-	11, // 77: daemon.NetworkService.GetNetwork:output_type -> daemon.Network
-	11, // 78: daemon.NetworkService.UpdateNetwork:output_type -> daemon.Network
-	57, // 79: daemon.NetworkService.DeleteNetwork:output_type -> google.protobuf.Empty
-	34, // 80: daemon.NetworkService.GenerateInvite:output_type -> daemon.GenerateInviteResponse
-	37, // 81: daemon.PeerService.GetPeers:output_type -> daemon.GetPeersResponse
-	10, // 82: daemon.PeerService.GetPeer:output_type -> daemon.Peer
-	57, // 83: daemon.PeerService.KickPeer:output_type -> google.protobuf.Empty
-	57, // 84: daemon.PeerService.BanPeer:output_type -> google.protobuf.Empty
-	57, // 85: daemon.PeerService.UnbanPeer:output_type -> google.protobuf.Empty
-	43, // 86: daemon.ChatService.SendMessage:output_type -> daemon.SendMessageResponse
-	45, // 87: daemon.ChatService.GetMessages:output_type -> daemon.GetMessagesResponse
-	12, // 88: daemon.ChatService.SubscribeMessages:output_type -> daemon.ChatMessage
-	48, // 89: daemon.TransferService.SendFile:output_type -> daemon.SendFileResponse
-	57, // 90: daemon.TransferService.AcceptTransfer:output_type -> google.protobuf.Empty
-	57, // 91: daemon.TransferService.RejectTransfer:output_type -> google.protobuf.Empty
-	57, // 92: daemon.TransferService.CancelTransfer:output_type -> google.protobuf.Empty
-	52, // 93: daemon.TransferService.ListTransfers:output_type -> daemon.ListTransfersResponse
-	18, // 94: daemon.TransferService.SubscribeTransfers:output_type -> daemon.TransferEvent
-	57, // 95: daemon.VoiceService.SendSignal:output_type -> google.protobuf.Empty
-	54, // 96: daemon.VoiceService.SubscribeSignals:output_type -> daemon.VoiceSignal
-	14, // 97: daemon.SettingsService.GetSettings:output_type -> daemon.Settings
-	14, // 98: daemon.SettingsService.UpdateSettings:output_type -> daemon.Settings
-	14, // 99: daemon.SettingsService.ResetSettings:output_type -> daemon.Settings
-	68, // [68:100] is the sub-list for method output_type
-	36, // [36:68] is the sub-list for method input_type
-	36, // [36:36] is the sub-list for extension type_name
-	36, // [36:36] is the sub-list for extension extendee
-	0,  // [0:36] is the sub-list for field type_name
+	8,  // 0: daemon.LoginUpdate.instructions:type_name -> daemon.LoginInstructions
+	9,  // 1: daemon.LoginUpdate.success:type_name -> daemon.LoginSuccess
+	10, // 2: daemon.LoginUpdate.error:type_name -> daemon.LoginError
+	72, // 3: daemon.Credential.issued_at:type_name -> google.protobuf.Timestamp
+	72, // 4: daemon.Credential.expires_at:type_name -> google.protobuf.Timestamp
+	11, // 5: daemon.GetCredentialsResponse.credentials:type_name -> daemon.Credential
+	0,  // 6: daemon.Peer.status:type_name -> daemon.ConnectionStatus
+	1,  // 7: daemon.Peer.connection_type:type_name -> daemon.ConnectionType
+	72, // 8: daemon.Peer.last_seen:type_name -> google.protobuf.Timestamp
+	2,  // 9: daemon.Peer.role:type_name -> daemon.NetworkRole
+	2,  // 10: daemon.Network.my_role:type_name -> daemon.NetworkRole
+	72, // 11: daemon.Network.created_at:type_name -> google.protobuf.Timestamp
+	72, // 12: daemon.Network.joined_at:type_name -> google.protobuf.Timestamp
+	72, // 13: daemon.ChatMessage.sent_at:type_name -> google.protobuf.Timestamp
+	3,  // 14: daemon.FileTransfer.status:type_name -> daemon.TransferStatus
+	72, // 15: daemon.FileTransfer.started_at:type_name -> google.protobuf.Timestamp
+	4,  // 16: daemon.DaemonEvent.type:type_name -> daemon.EventType
+	72, // 17: daemon.DaemonEvent.timestamp:type_name -> google.protobuf.Timestamp
+	20, // 18: daemon.DaemonEvent.status_changed:type_name -> daemon.StatusChangedEvent
+	21, // 19: daemon.DaemonEvent.peer_event:type_name -> daemon.PeerEvent
+	15, // 20: daemon.DaemonEvent.chat_message:type_name -> daemon.ChatMessage
+	22, // 21: daemon.DaemonEvent.transfer_event:type_name -> daemon.TransferEvent
+	23, // 22: daemon.DaemonEvent.notification:type_name -> daemon.Notification
+	19, // 23: daemon.DaemonEvent.security_event:type_name -> daemon.SecurityEvent
+	5,  // 24: daemon.SecurityEvent.kind:type_name -> daemon.SecurityEventKind
+	0,  // 25: daemon.StatusChangedEvent.old_status:type_name -> daemon.ConnectionStatus
+	0,  // 26: daemon.StatusChangedEvent.new_status:type_name -> daemon.ConnectionStatus
+	13, // 27: daemon.PeerEvent.peer:type_name -> daemon.Peer
+	16, // 28: daemon.TransferEvent.transfer:type_name -> daemon.FileTransfer
+	0,  // 29: daemon.GetStatusResponse.status:type_name -> daemon.ConnectionStatus
+	72, // 30: daemon.VersionResponse.daemon_time:type_name -> google.protobuf.Timestamp
+	72, // 31: daemon.VersionResponse.server_time:type_name -> google.protobuf.Timestamp
+	4,  // 32: daemon.SubscribeRequest.event_types:type_name -> daemon.EventType
+	14, // 33: daemon.CreateNetworkResponse.network:type_name -> daemon.Network
+	14, // 34: daemon.JoinNetworkResponse.network:type_name -> daemon.Network
+	72, // 35: daemon.JoinRequest.requested_at:type_name -> google.protobuf.Timestamp
+	38, // 36: daemon.ListJoinRequestsResponse.requests:type_name -> daemon.JoinRequest
+	72, // 37: daemon.GenerateInviteResponse.expires_at:type_name -> google.protobuf.Timestamp
+	72, // 38: daemon.InvitePreview.expires_at:type_name -> google.protobuf.Timestamp
+	14, // 39: daemon.ListNetworksResponse.networks:type_name -> daemon.Network
+	13, // 40: daemon.GetPeersResponse.peers:type_name -> daemon.Peer
+	15, // 41: daemon.SendMessageResponse.message:type_name -> daemon.ChatMessage
+	15, // 42: daemon.GetMessagesResponse.messages:type_name -> daemon.ChatMessage
+	67, // 43: daemon.ListDirectoryResponse.entries:type_name -> daemon.RemoteFileEntry
+	72, // 44: daemon.RemoteFileEntry.modified_at:type_name -> google.protobuf.Timestamp
+	16, // 45: daemon.ListTransfersResponse.transfers:type_name -> daemon.FileTransfer
+	17, // 46: daemon.UpdateSettingsRequest.settings:type_name -> daemon.Settings
+	70, // 47: daemon.SendSignalRequest.signal:type_name -> daemon.VoiceSignal
+	24, // 48: daemon.DaemonService.GetStatus:input_type -> daemon.GetStatusRequest
+	73, // 49: daemon.DaemonService.GetVersion:input_type -> google.protobuf.Empty
+	73, // 50: daemon.DaemonService.Shutdown:input_type -> google.protobuf.Empty
+	27, // 51: daemon.DaemonService.Subscribe:input_type -> daemon.SubscribeRequest
+	6,  // 52: daemon.DaemonService.Login:input_type -> daemon.LoginRequest
+	73, // 53: daemon.DaemonService.GetCredentials:input_type -> google.protobuf.Empty
+	28, // 54: daemon.NetworkService.CreateNetwork:input_type -> daemon.CreateNetworkRequest
+	30, // 55: daemon.NetworkService.JoinNetwork:input_type -> daemon.JoinNetworkRequest
+	32, // 56: daemon.NetworkService.LeaveNetwork:input_type -> daemon.LeaveNetworkRequest
+	73, // 57: daemon.NetworkService.ListNetworks:input_type -> google.protobuf.Empty
+	34, // 58: daemon.NetworkService.GetNetwork:input_type -> daemon.GetNetworkRequest
+	35, // 59: daemon.NetworkService.UpdateNetwork:input_type -> daemon.UpdateNetworkRequest
+	36, // 60: daemon.NetworkService.DeleteNetwork:input_type -> daemon.DeleteNetworkRequest
+	40, // 61: daemon.NetworkService.GenerateInvite:input_type -> daemon.GenerateInviteRequest
+	42, // 62: daemon.NetworkService.PreviewInvite:input_type -> daemon.PreviewInviteRequest
+	44, // 63: daemon.NetworkService.TransferOwnership:input_type -> daemon.TransferOwnershipRequest
+	73, // 64: daemon.NetworkService.ConnectNetwork:input_type -> google.protobuf.Empty
+	73, // 65: daemon.NetworkService.DisconnectNetwork:input_type -> google.protobuf.Empty
+	37, // 66: daemon.NetworkService.ListJoinRequests:input_type -> daemon.ListJoinRequestsRequest
+	46, // 67: daemon.PeerService.GetPeers:input_type -> daemon.GetPeersRequest
+	48, // 68: daemon.PeerService.GetPeer:input_type -> daemon.GetPeerRequest
+	49, // 69: daemon.PeerService.KickPeer:input_type -> daemon.KickPeerRequest
+	50, // 70: daemon.PeerService.BanPeer:input_type -> daemon.BanPeerRequest
+	51, // 71: daemon.PeerService.UnbanPeer:input_type -> daemon.UnbanPeerRequest
+	52, // 72: daemon.PeerService.ReportPeer:input_type -> daemon.ReportPeerRequest
+	53, // 73: daemon.ChatService.SendMessage:input_type -> daemon.SendMessageRequest
+	55, // 74: daemon.ChatService.GetMessages:input_type -> daemon.GetMessagesRequest
+	57, // 75: daemon.ChatService.SubscribeMessages:input_type -> daemon.SubscribeMessagesRequest
+	58, // 76: daemon.TransferService.SendFile:input_type -> daemon.SendFileRequest
+	60, // 77: daemon.TransferService.AcceptTransfer:input_type -> daemon.AcceptTransferRequest
+	61, // 78: daemon.TransferService.RejectTransfer:input_type -> daemon.RejectTransferRequest
+	62, // 79: daemon.TransferService.CancelTransfer:input_type -> daemon.CancelTransferRequest
+	73, // 80: daemon.TransferService.ListTransfers:input_type -> google.protobuf.Empty
+	73, // 81: daemon.TransferService.SubscribeTransfers:input_type -> google.protobuf.Empty
+	63, // 82: daemon.TransferService.SetTransferRateLimit:input_type -> daemon.SetTransferRateLimitRequest
+	64, // 83: daemon.TransferService.ListDirectory:input_type -> daemon.ListDirectoryRequest
+	66, // 84: daemon.TransferService.StatPath:input_type -> daemon.StatPathRequest
+	71, // 85: daemon.VoiceService.SendSignal:input_type -> daemon.SendSignalRequest
+	73, // 86: daemon.VoiceService.SubscribeSignals:input_type -> google.protobuf.Empty
+	73, // 87: daemon.SettingsService.GetSettings:input_type -> google.protobuf.Empty
+	69, // 88: daemon.SettingsService.UpdateSettings:input_type -> daemon.UpdateSettingsRequest
+	73, // 89: daemon.SettingsService.ResetSettings:input_type -> google.protobuf.Empty
+	73, // 90: daemon.SettingsService.SubscribeSettings:input_type -> google.protobuf.Empty
+	25, // 91: daemon.DaemonService.GetStatus:output_type -> daemon.GetStatusResponse
+	26, // 92: daemon.DaemonService.GetVersion:output_type -> daemon.VersionResponse
+	73, // 93: daemon.DaemonService.Shutdown:output_type -> google.protobuf.Empty
+	18, // 94: daemon.DaemonService.Subscribe:output_type -> daemon.DaemonEvent
+	7,  // 95: daemon.DaemonService.Login:output_type -> daemon.LoginUpdate
+	12, // 96: daemon.DaemonService.GetCredentials:output_type -> daemon.GetCredentialsResponse
+	29, // 97: daemon.NetworkService.CreateNetwork:output_type -> daemon.CreateNetworkResponse
+	31, // 98: daemon.NetworkService.JoinNetwork:output_type -> daemon.JoinNetworkResponse
+	33, // 99: daemon.NetworkService.LeaveNetwork:output_type -> daemon.LeaveNetworkResponse
+	45, // 100: daemon.NetworkService.ListNetworks:output_type -> daemon.ListNetworksResponse
+	14, // 101: daemon.NetworkService.GetNetwork:output_type -> daemon.Network
+	14, // 102: daemon.NetworkService.UpdateNetwork:output_type -> daemon.Network
+	73, // 103: daemon.NetworkService.DeleteNetwork:output_type -> google.protobuf.Empty
+	41, // 104: daemon.NetworkService.GenerateInvite:output_type -> daemon.GenerateInviteResponse
+	43, // 105: daemon.NetworkService.PreviewInvite:output_type -> daemon.InvitePreview
+	73, // 106: daemon.NetworkService.TransferOwnership:output_type -> google.protobuf.Empty
+	73, // 107: daemon.NetworkService.ConnectNetwork:output_type -> google.protobuf.Empty
+	73, // 108: daemon.NetworkService.DisconnectNetwork:output_type -> google.protobuf.Empty
+	39, // 109: daemon.NetworkService.ListJoinRequests:output_type -> daemon.ListJoinRequestsResponse
+	47, // 110: daemon.PeerService.GetPeers:output_type -> daemon.GetPeersResponse
+	13, // 111: daemon.PeerService.GetPeer:output_type -> daemon.Peer
+	73, // 112: daemon.PeerService.KickPeer:output_type -> google.protobuf.Empty
+	73, // 113: daemon.PeerService.BanPeer:output_type -> google.protobuf.Empty
+	73, // 114: daemon.PeerService.UnbanPeer:output_type -> google.protobuf.Empty
+	73, // 115: daemon.PeerService.ReportPeer:output_type -> google.protobuf.Empty
+	54, // 116: daemon.ChatService.SendMessage:output_type -> daemon.SendMessageResponse
+	56, // 117: daemon.ChatService.GetMessages:output_type -> daemon.GetMessagesResponse
+	15, // 118: daemon.ChatService.SubscribeMessages:output_type -> daemon.ChatMessage
+	59, // 119: daemon.TransferService.SendFile:output_type -> daemon.SendFileResponse
+	73, // 120: daemon.TransferService.AcceptTransfer:output_type -> google.protobuf.Empty
+	73, // 121: daemon.TransferService.RejectTransfer:output_type -> google.protobuf.Empty
+	73, // 122: daemon.TransferService.CancelTransfer:output_type -> google.protobuf.Empty
+	68, // 123: daemon.TransferService.ListTransfers:output_type -> daemon.ListTransfersResponse
+	22, // 124: daemon.TransferService.SubscribeTransfers:output_type -> daemon.TransferEvent
+	73, // 125: daemon.TransferService.SetTransferRateLimit:output_type -> google.protobuf.Empty
+	65, // 126: daemon.TransferService.ListDirectory:output_type -> daemon.ListDirectoryResponse
+	67, // 127: daemon.TransferService.StatPath:output_type -> daemon.RemoteFileEntry
+	73, // 128: daemon.VoiceService.SendSignal:output_type -> google.protobuf.Empty
+	70, // 129: daemon.VoiceService.SubscribeSignals:output_type -> daemon.VoiceSignal
+	17, // 130: daemon.SettingsService.GetSettings:output_type -> daemon.Settings
+	17, // 131: daemon.SettingsService.UpdateSettings:output_type -> daemon.Settings
+	17, // 132: daemon.SettingsService.ResetSettings:output_type -> daemon.Settings
+	17, // 133: daemon.SettingsService.SubscribeSettings:output_type -> daemon.Settings
+	91, // [91:134] is the sub-list for method output_type
+	48, // [48:91] is the sub-list for method input_type
+	48, // [48:48] is the sub-list for extension type_name
+	48, // [48:48] is the sub-list for extension extendee
+	0,  // [0:48] is the sub-list for field type_name
 }
 
 func init() { file_daemon_proto_init() }
@@ -3869,20 +5111,21 @@ func file_daemon_proto_init() {
 		(*LoginUpdate_Success)(nil),
 		(*LoginUpdate_Error)(nil),
 	}
-	file_daemon_proto_msgTypes[10].OneofWrappers = []any{
+	file_daemon_proto_msgTypes[12].OneofWrappers = []any{
 		(*DaemonEvent_StatusChanged)(nil),
 		(*DaemonEvent_PeerEvent)(nil),
 		(*DaemonEvent_ChatMessage)(nil),
 		(*DaemonEvent_TransferEvent)(nil),
 		(*DaemonEvent_Notification)(nil),
+		(*DaemonEvent_SecurityEvent)(nil),
 	}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_daemon_proto_rawDesc), len(file_daemon_proto_rawDesc)),
-			NumEnums:      5,
-			NumMessages:   51,
+			NumEnums:      6,
+			NumMessages:   66,
 			NumExtensions: 0,
 			NumServices:   7,
 		},
@@ -20,11 +20,12 @@ import (
 const _ = grpc.SupportPackageIsVersion9
 
 const (
-	DaemonService_GetStatus_FullMethodName  = "/daemon.DaemonService/GetStatus"
-	DaemonService_GetVersion_FullMethodName = "/daemon.DaemonService/GetVersion"
-	DaemonService_Shutdown_FullMethodName   = "/daemon.DaemonService/Shutdown"
-	DaemonService_Subscribe_FullMethodName  = "/daemon.DaemonService/Subscribe"
-	DaemonService_Login_FullMethodName      = "/daemon.DaemonService/Login"
+	DaemonService_GetStatus_FullMethodName      = "/daemon.DaemonService/GetStatus"
+	DaemonService_GetVersion_FullMethodName     = "/daemon.DaemonService/GetVersion"
+	DaemonService_Shutdown_FullMethodName       = "/daemon.DaemonService/Shutdown"
+	DaemonService_Subscribe_FullMethodName      = "/daemon.DaemonService/Subscribe"
+	DaemonService_Login_FullMethodName          = "/daemon.DaemonService/Login"
+	DaemonService_GetCredentials_FullMethodName = "/daemon.DaemonService/GetCredentials"
 )
 
 // DaemonServiceClient is the client API for DaemonService service.
@@ -45,6 +46,8 @@ type DaemonServiceClient interface {
 	Subscribe(ctx context.Context, in *SubscribeRequest, opts ...grpc.CallOption) (grpc.ServerStreamingClient[DaemonEvent], error)
 	// Login initiates the device authentication flow and streams status updates.
 	Login(ctx context.Context, in *LoginRequest, opts ...grpc.CallOption) (grpc.ServerStreamingClient[LoginUpdate], error)
+	// GetCredentials lists the credentials the daemon holds and when they expire.
+	GetCredentials(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*GetCredentialsResponse, error)
 }
 
 type daemonServiceClient struct {
@@ -123,6 +126,16 @@ func (c *daemonServiceClient) Login(ctx context.Context, in *LoginRequest, opts
 // This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
 type DaemonService_LoginClient = grpc.ServerStreamingClient[LoginUpdate]
 
+func (c *daemonServiceClient) GetCredentials(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*GetCredentialsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(GetCredentialsResponse)
+	err := c.cc.Invoke(ctx, DaemonService_GetCredentials_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // DaemonServiceServer is the server API for DaemonService service.
 // All implementations must embed UnimplementedDaemonServiceServer
 // for forward compatibility.
@@ -141,6 +154,8 @@ type DaemonServiceServer interface {
 	Subscribe(*SubscribeRequest, grpc.ServerStreamingServer[DaemonEvent]) error
 	// Login initiates the device authentication flow and streams status updates.
 	Login(*LoginRequest, grpc.ServerStreamingServer[LoginUpdate]) error
+	// GetCredentials lists the credentials the daemon holds and when they expire.
+	GetCredentials(context.Context, *emptypb.Empty) (*GetCredentialsResponse, error)
 	mustEmbedUnimplementedDaemonServiceServer()
 }
 
@@ -166,6 +181,9 @@ func (UnimplementedDaemonServiceServer) Subscribe(*SubscribeRequest, grpc.Server
 func (UnimplementedDaemonServiceServer) Login(*LoginRequest, grpc.ServerStreamingServer[LoginUpdate]) error {
 	return status.Error(codes.Unimplemented, "method Login not implemented")
 }
+func (UnimplementedDaemonServiceServer) GetCredentials(context.Context, *emptypb.Empty) (*GetCredentialsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method GetCredentials not implemented")
+}
 func (UnimplementedDaemonServiceServer) mustEmbedUnimplementedDaemonServiceServer() {}
 func (UnimplementedDaemonServiceServer) testEmbeddedByValue()                       {}
 
@@ -263,6 +281,24 @@ func _DaemonService_Login_Handler(srv interface{}, stream grpc.ServerStream) err
 // This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
 type DaemonService_LoginServer = grpc.ServerStreamingServer[LoginUpdate]
 
+func _DaemonService_GetCredentials_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(emptypb.Empty)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(DaemonServiceServer).GetCredentials(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: DaemonService_GetCredentials_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(DaemonServiceServer).GetCredentials(ctx, req.(*emptypb.Empty))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // DaemonService_ServiceDesc is the grpc.ServiceDesc for DaemonService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -282,6 +318,10 @@ var DaemonService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Shutdown",
 			Handler:    _DaemonService_Shutdown_Handler,
 		},
+		{
+			MethodName: "GetCredentials",
+			Handler:    _DaemonService_GetCredentials_Handler,
+		},
 	},
 	Streams: []grpc.StreamDesc{
 		{
@@ -299,14 +339,19 @@ var DaemonService_ServiceDesc = grpc.ServiceDesc{
 }
 
 const (
-	NetworkService_CreateNetwork_FullMethodName  = "/daemon.NetworkService/CreateNetwork"
-	NetworkService_JoinNetwork_FullMethodName    = "/daemon.NetworkService/JoinNetwork"
-	NetworkService_LeaveNetwork_FullMethodName   = "/daemon.NetworkService/LeaveNetwork"
-	NetworkService_ListNetworks_FullMethodName   = "/daemon.NetworkService/ListNetworks"
-	NetworkService_GetNetwork_FullMethodName     = "/daemon.NetworkService/GetNetwork"
-	NetworkService_UpdateNetwork_FullMethodName  = "/daemon.NetworkService/UpdateNetwork"
-	NetworkService_DeleteNetwork_FullMethodName  = "/daemon.NetworkService/DeleteNetwork"
-	NetworkService_GenerateInvite_FullMethodName = "/daemon.NetworkService/GenerateInvite"
+	NetworkService_CreateNetwork_FullMethodName     = "/daemon.NetworkService/CreateNetwork"
+	NetworkService_JoinNetwork_FullMethodName       = "/daemon.NetworkService/JoinNetwork"
+	NetworkService_LeaveNetwork_FullMethodName      = "/daemon.NetworkService/LeaveNetwork"
+	NetworkService_ListNetworks_FullMethodName      = "/daemon.NetworkService/ListNetworks"
+	NetworkService_GetNetwork_FullMethodName        = "/daemon.NetworkService/GetNetwork"
+	NetworkService_UpdateNetwork_FullMethodName     = "/daemon.NetworkService/UpdateNetwork"
+	NetworkService_DeleteNetwork_FullMethodName     = "/daemon.NetworkService/DeleteNetwork"
+	NetworkService_GenerateInvite_FullMethodName    = "/daemon.NetworkService/GenerateInvite"
+	NetworkService_PreviewInvite_FullMethodName     = "/daemon.NetworkService/PreviewInvite"
+	NetworkService_TransferOwnership_FullMethodName = "/daemon.NetworkService/TransferOwnership"
+	NetworkService_ConnectNetwork_FullMethodName    = "/daemon.NetworkService/ConnectNetwork"
+	NetworkService_DisconnectNetwork_FullMethodName = "/daemon.NetworkService/DisconnectNetwork"
+	NetworkService_ListJoinRequests_FullMethodName  = "/daemon.NetworkService/ListJoinRequests"
 )
 
 // NetworkServiceClient is the client API for NetworkService service.
@@ -333,6 +378,16 @@ type NetworkServiceClient interface {
 	DeleteNetwork(ctx context.Context, in *DeleteNetworkRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 	// GenerateInvite creates an invite code for a network.
 	GenerateInvite(ctx context.Context, in *GenerateInviteRequest, opts ...grpc.CallOption) (*GenerateInviteResponse, error)
+	// PreviewInvite describes the network behind an invite code without joining it.
+	PreviewInvite(ctx context.Context, in *PreviewInviteRequest, opts ...grpc.CallOption) (*InvitePreview, error)
+	// TransferOwnership makes another member the owner; the caller becomes an admin (owner only).
+	TransferOwnership(ctx context.Context, in *TransferOwnershipRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	// ConnectNetwork brings up the tunnel, which carries every joined network.
+	ConnectNetwork(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	// DisconnectNetwork tears down the active tunnel without leaving the network.
+	DisconnectNetwork(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	// ListJoinRequests returns requests waiting for approval to join a network (admin only).
+	ListJoinRequests(ctx context.Context, in *ListJoinRequestsRequest, opts ...grpc.CallOption) (*ListJoinRequestsResponse, error)
 }
 
 type networkServiceClient struct {
@@ -423,6 +478,56 @@ func (c *networkServiceClient) GenerateInvite(ctx context.Context, in *GenerateI
 	return out, nil
 }
 
+func (c *networkServiceClient) PreviewInvite(ctx context.Context, in *PreviewInviteRequest, opts ...grpc.CallOption) (*InvitePreview, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(InvitePreview)
+	err := c.cc.Invoke(ctx, NetworkService_PreviewInvite_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *networkServiceClient) TransferOwnership(ctx context.Context, in *TransferOwnershipRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, NetworkService_TransferOwnership_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *networkServiceClient) ConnectNetwork(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, NetworkService_ConnectNetwork_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *networkServiceClient) DisconnectNetwork(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, NetworkService_DisconnectNetwork_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *networkServiceClient) ListJoinRequests(ctx context.Context, in *ListJoinRequestsRequest, opts ...grpc.CallOption) (*ListJoinRequestsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListJoinRequestsResponse)
+	err := c.cc.Invoke(ctx, NetworkService_ListJoinRequests_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // NetworkServiceServer is the server API for NetworkService service.
 // All implementations must embed UnimplementedNetworkServiceServer
 // for forward compatibility.
@@ -447,6 +552,16 @@ type NetworkServiceServer interface {
 	DeleteNetwork(context.Context, *DeleteNetworkRequest) (*emptypb.Empty, error)
 	// GenerateInvite creates an invite code for a network.
 	GenerateInvite(context.Context, *GenerateInviteRequest) (*GenerateInviteResponse, error)
+	// PreviewInvite describes the network behind an invite code without joining it.
+	PreviewInvite(context.Context, *PreviewInviteRequest) (*InvitePreview, error)
+	// TransferOwnership makes another member the owner; the caller becomes an admin (owner only).
+	TransferOwnership(context.Context, *TransferOwnershipRequest) (*emptypb.Empty, error)
+	// ConnectNetwork brings up the tunnel, which carries every joined network.
+	ConnectNetwork(context.Context, *emptypb.Empty) (*emptypb.Empty, error)
+	// DisconnectNetwork tears down the active tunnel without leaving the network.
+	DisconnectNetwork(context.Context, *emptypb.Empty) (*emptypb.Empty, error)
+	// ListJoinRequests returns requests waiting for approval to join a network (admin only).
+	ListJoinRequests(context.Context, *ListJoinRequestsRequest) (*ListJoinRequestsResponse, error)
 	mustEmbedUnimplementedNetworkServiceServer()
 }
 
@@ -481,6 +596,21 @@ func (UnimplementedNetworkServiceServer) DeleteNetwork(context.Context, *DeleteN
 func (UnimplementedNetworkServiceServer) GenerateInvite(context.Context, *GenerateInviteRequest) (*GenerateInviteResponse, error) {
 	return nil, status.Error(codes.Unimplemented, "method GenerateInvite not implemented")
 }
+func (UnimplementedNetworkServiceServer) PreviewInvite(context.Context, *PreviewInviteRequest) (*InvitePreview, error) {
+	return nil, status.Error(codes.Unimplemented, "method PreviewInvite not implemented")
+}
+func (UnimplementedNetworkServiceServer) TransferOwnership(context.Context, *TransferOwnershipRequest) (*emptypb.Empty, error) {
+	return nil, status.Error(codes.Unimplemented, "method TransferOwnership not implemented")
+}
+func (UnimplementedNetworkServiceServer) ConnectNetwork(context.Context, *emptypb.Empty) (*emptypb.Empty, error) {
+	return nil, status.Error(codes.Unimplemented, "method ConnectNetwork not implemented")
+}
+func (UnimplementedNetworkServiceServer) DisconnectNetwork(context.Context, *emptypb.Empty) (*emptypb.Empty, error) {
+	return nil, status.Error(codes.Unimplemented, "method DisconnectNetwork not implemented")
+}
+func (UnimplementedNetworkServiceServer) ListJoinRequests(context.Context, *ListJoinRequestsRequest) (*ListJoinRequestsResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListJoinRequests not implemented")
+}
 func (UnimplementedNetworkServiceServer) mustEmbedUnimplementedNetworkServiceServer() {}
 func (UnimplementedNetworkServiceServer) testEmbeddedByValue()                        {}
 
@@ -646,6 +776,96 @@ func _NetworkService_GenerateInvite_Handler(srv interface{}, ctx context.Context
 	return interceptor(ctx, in, info, handler)
 }
 
+func _NetworkService_PreviewInvite_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PreviewInviteRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(NetworkServiceServer).PreviewInvite(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: NetworkService_PreviewInvite_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(NetworkServiceServer).PreviewInvite(ctx, req.(*PreviewInviteRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _NetworkService_TransferOwnership_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TransferOwnershipRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(NetworkServiceServer).TransferOwnership(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: NetworkService_TransferOwnership_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(NetworkServiceServer).TransferOwnership(ctx, req.(*TransferOwnershipRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _NetworkService_ConnectNetwork_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(emptypb.Empty)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(NetworkServiceServer).ConnectNetwork(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: NetworkService_ConnectNetwork_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(NetworkServiceServer).ConnectNetwork(ctx, req.(*emptypb.Empty))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _NetworkService_DisconnectNetwork_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(emptypb.Empty)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(NetworkServiceServer).DisconnectNetwork(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: NetworkService_DisconnectNetwork_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(NetworkServiceServer).DisconnectNetwork(ctx, req.(*emptypb.Empty))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _NetworkService_ListJoinRequests_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListJoinRequestsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(NetworkServiceServer).ListJoinRequests(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: NetworkService_ListJoinRequests_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(NetworkServiceServer).ListJoinRequests(ctx, req.(*ListJoinRequestsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // NetworkService_ServiceDesc is the grpc.ServiceDesc for NetworkService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -685,17 +905,38 @@ var NetworkService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "GenerateInvite",
 			Handler:    _NetworkService_GenerateInvite_Handler,
 		},
+		{
+			MethodName: "PreviewInvite",
+			Handler:    _NetworkService_PreviewInvite_Handler,
+		},
+		{
+			MethodName: "TransferOwnership",
+			Handler:    _NetworkService_TransferOwnership_Handler,
+		},
+		{
+			MethodName: "ConnectNetwork",
+			Handler:    _NetworkService_ConnectNetwork_Handler,
+		},
+		{
+			MethodName: "DisconnectNetwork",
+			Handler:    _NetworkService_DisconnectNetwork_Handler,
+		},
+		{
+			MethodName: "ListJoinRequests",
+			Handler:    _NetworkService_ListJoinRequests_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "daemon.proto",
 }
 
 const (
-	PeerService_GetPeers_FullMethodName  = "/daemon.PeerService/GetPeers"
-	PeerService_GetPeer_FullMethodName   = "/daemon.PeerService/GetPeer"
-	PeerService_KickPeer_FullMethodName  = "/daemon.PeerService/KickPeer"
-	PeerService_BanPeer_FullMethodName   = "/daemon.PeerService/BanPeer"
-	PeerService_UnbanPeer_FullMethodName = "/daemon.PeerService/UnbanPeer"
+	PeerService_GetPeers_FullMethodName   = "/daemon.PeerService/GetPeers"
+	PeerService_GetPeer_FullMethodName    = "/daemon.PeerService/GetPeer"
+	PeerService_KickPeer_FullMethodName   = "/daemon.PeerService/KickPeer"
+	PeerService_BanPeer_FullMethodName    = "/daemon.PeerService/BanPeer"
+	PeerService_UnbanPeer_FullMethodName  = "/daemon.PeerService/UnbanPeer"
+	PeerService_ReportPeer_FullMethodName = "/daemon.PeerService/ReportPeer"
 )
 
 // PeerServiceClient is the client API for PeerService service.
@@ -716,6 +957,8 @@ type PeerServiceClient interface {
 	BanPeer(ctx context.Context, in *BanPeerRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 	// UnbanPeer removes a ban (host only).
 	UnbanPeer(ctx context.Context, in *UnbanPeerRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	// ReportPeer forwards a report with the offending messages to the network's admins.
+	ReportPeer(ctx context.Context, in *ReportPeerRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 }
 
 type peerServiceClient struct {
@@ -776,6 +1019,16 @@ func (c *peerServiceClient) UnbanPeer(ctx context.Context, in *UnbanPeerRequest,
 	return out, nil
 }
 
+func (c *peerServiceClient) ReportPeer(ctx context.Context, in *ReportPeerRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, PeerService_ReportPeer_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // PeerServiceServer is the server API for PeerService service.
 // All implementations must embed UnimplementedPeerServiceServer
 // for forward compatibility.
@@ -794,6 +1047,8 @@ type PeerServiceServer interface {
 	BanPeer(context.Context, *BanPeerRequest) (*emptypb.Empty, error)
 	// UnbanPeer removes a ban (host only).
 	UnbanPeer(context.Context, *UnbanPeerRequest) (*emptypb.Empty, error)
+	// ReportPeer forwards a report with the offending messages to the network's admins.
+	ReportPeer(context.Context, *ReportPeerRequest) (*emptypb.Empty, error)
 	mustEmbedUnimplementedPeerServiceServer()
 }
 
@@ -819,6 +1074,9 @@ func (UnimplementedPeerServiceServer) BanPeer(context.Context, *BanPeerRequest)
 func (UnimplementedPeerServiceServer) UnbanPeer(context.Context, *UnbanPeerRequest) (*emptypb.Empty, error) {
 	return nil, status.Error(codes.Unimplemented, "method UnbanPeer not implemented")
 }
+func (UnimplementedPeerServiceServer) ReportPeer(context.Context, *ReportPeerRequest) (*emptypb.Empty, error) {
+	return nil, status.Error(codes.Unimplemented, "method ReportPeer not implemented")
+}
 func (UnimplementedPeerServiceServer) mustEmbedUnimplementedPeerServiceServer() {}
 func (UnimplementedPeerServiceServer) testEmbeddedByValue()                     {}
 
@@ -930,6 +1188,24 @@ func _PeerService_UnbanPeer_Handler(srv interface{}, ctx context.Context, dec fu
 	return interceptor(ctx, in, info, handler)
 }
 
+func _PeerService_ReportPeer_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ReportPeerRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(PeerServiceServer).ReportPeer(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: PeerService_ReportPeer_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(PeerServiceServer).ReportPeer(ctx, req.(*ReportPeerRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // PeerService_ServiceDesc is the grpc.ServiceDesc for PeerService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -957,6 +1233,10 @@ var PeerService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "UnbanPeer",
 			Handler:    _PeerService_UnbanPeer_Handler,
 		},
+		{
+			MethodName: "ReportPeer",
+			Handler:    _PeerService_ReportPeer_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "daemon.proto",
@@ -1159,12 +1439,15 @@ var ChatService_ServiceDesc = grpc.ServiceDesc{
 }
 
 const (
-	TransferService_SendFile_FullMethodName           = "/daemon.TransferService/SendFile"
-	TransferService_AcceptTransfer_FullMethodName     = "/daemon.TransferService/AcceptTransfer"
-	TransferService_RejectTransfer_FullMethodName     = "/daemon.TransferService/RejectTransfer"
-	TransferService_CancelTransfer_FullMethodName     = "/daemon.TransferService/CancelTransfer"
-	TransferService_ListTransfers_FullMethodName      = "/daemon.TransferService/ListTransfers"
-	TransferService_SubscribeTransfers_FullMethodName = "/daemon.TransferService/SubscribeTransfers"
+	TransferService_SendFile_FullMethodName             = "/daemon.TransferService/SendFile"
+	TransferService_AcceptTransfer_FullMethodName       = "/daemon.TransferService/AcceptTransfer"
+	TransferService_RejectTransfer_FullMethodName       = "/daemon.TransferService/RejectTransfer"
+	TransferService_CancelTransfer_FullMethodName       = "/daemon.TransferService/CancelTransfer"
+	TransferService_ListTransfers_FullMethodName        = "/daemon.TransferService/ListTransfers"
+	TransferService_SubscribeTransfers_FullMethodName   = "/daemon.TransferService/SubscribeTransfers"
+	TransferService_SetTransferRateLimit_FullMethodName = "/daemon.TransferService/SetTransferRateLimit"
+	TransferService_ListDirectory_FullMethodName        = "/daemon.TransferService/ListDirectory"
+	TransferService_StatPath_FullMethodName             = "/daemon.TransferService/StatPath"
 )
 
 // TransferServiceClient is the client API for TransferService service.
@@ -1187,6 +1470,12 @@ type TransferServiceClient interface {
 	ListTransfers(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*ListTransfersResponse, error)
 	// SubscribeTransfers streams transfer progress updates.
 	SubscribeTransfers(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (grpc.ServerStreamingClient[TransferEvent], error)
+	// SetTransferRateLimit caps one transfer's speed, overriding the settings.
+	SetTransferRateLimit(ctx context.Context, in *SetTransferRateLimitRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	// ListDirectory lists a directory on the daemon's host, for picking files to send.
+	ListDirectory(ctx context.Context, in *ListDirectoryRequest, opts ...grpc.CallOption) (*ListDirectoryResponse, error)
+	// StatPath describes one path on the daemon's host.
+	StatPath(ctx context.Context, in *StatPathRequest, opts ...grpc.CallOption) (*RemoteFileEntry, error)
 }
 
 type transferServiceClient struct {
@@ -1266,6 +1555,36 @@ func (c *transferServiceClient) SubscribeTransfers(ctx context.Context, in *empt
 // This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
 type TransferService_SubscribeTransfersClient = grpc.ServerStreamingClient[TransferEvent]
 
+func (c *transferServiceClient) SetTransferRateLimit(ctx context.Context, in *SetTransferRateLimitRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, TransferService_SetTransferRateLimit_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *transferServiceClient) ListDirectory(ctx context.Context, in *ListDirectoryRequest, opts ...grpc.CallOption) (*ListDirectoryResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListDirectoryResponse)
+	err := c.cc.Invoke(ctx, TransferService_ListDirectory_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *transferServiceClient) StatPath(ctx context.Context, in *StatPathRequest, opts ...grpc.CallOption) (*RemoteFileEntry, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(RemoteFileEntry)
+	err := c.cc.Invoke(ctx, TransferService_StatPath_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // TransferServiceServer is the server API for TransferService service.
 // All implementations must embed UnimplementedTransferServiceServer
 // for forward compatibility.
@@ -1286,6 +1605,12 @@ type TransferServiceServer interface {
 	ListTransfers(context.Context, *emptypb.Empty) (*ListTransfersResponse, error)
 	// SubscribeTransfers streams transfer progress updates.
 	SubscribeTransfers(*emptypb.Empty, grpc.ServerStreamingServer[TransferEvent]) error
+	// SetTransferRateLimit caps one transfer's speed, overriding the settings.
+	SetTransferRateLimit(context.Context, *SetTransferRateLimitRequest) (*emptypb.Empty, error)
+	// ListDirectory lists a directory on the daemon's host, for picking files to send.
+	ListDirectory(context.Context, *ListDirectoryRequest) (*ListDirectoryResponse, error)
+	// StatPath describes one path on the daemon's host.
+	StatPath(context.Context, *StatPathRequest) (*RemoteFileEntry, error)
 	mustEmbedUnimplementedTransferServiceServer()
 }
 
@@ -1314,6 +1639,15 @@ func (UnimplementedTransferServiceServer) ListTransfers(context.Context, *emptyp
 func (UnimplementedTransferServiceServer) SubscribeTransfers(*emptypb.Empty, grpc.ServerStreamingServer[TransferEvent]) error {
 	return status.Error(codes.Unimplemented, "method SubscribeTransfers not implemented")
 }
+func (UnimplementedTransferServiceServer) SetTransferRateLimit(context.Context, *SetTransferRateLimitRequest) (*emptypb.Empty, error) {
+	return nil, status.Error(codes.Unimplemented, "method SetTransferRateLimit not implemented")
+}
+func (UnimplementedTransferServiceServer) ListDirectory(context.Context, *ListDirectoryRequest) (*ListDirectoryResponse, error) {
+	return nil, status.Error(codes.Unimplemented, "method ListDirectory not implemented")
+}
+func (UnimplementedTransferServiceServer) StatPath(context.Context, *StatPathRequest) (*RemoteFileEntry, error) {
+	return nil, status.Error(codes.Unimplemented, "method StatPath not implemented")
+}
 func (UnimplementedTransferServiceServer) mustEmbedUnimplementedTransferServiceServer() {}
 func (UnimplementedTransferServiceServer) testEmbeddedByValue()                         {}
 
@@ -1436,6 +1770,60 @@ func _TransferService_SubscribeTransfers_Handler(srv interface{}, stream grpc.Se
 // This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
 type TransferService_SubscribeTransfersServer = grpc.ServerStreamingServer[TransferEvent]
 
+func _TransferService_SetTransferRateLimit_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetTransferRateLimitRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TransferServiceServer).SetTransferRateLimit(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: TransferService_SetTransferRateLimit_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TransferServiceServer).SetTransferRateLimit(ctx, req.(*SetTransferRateLimitRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TransferService_ListDirectory_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListDirectoryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TransferServiceServer).ListDirectory(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: TransferService_ListDirectory_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TransferServiceServer).ListDirectory(ctx, req.(*ListDirectoryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TransferService_StatPath_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(StatPathRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TransferServiceServer).StatPath(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: TransferService_StatPath_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TransferServiceServer).StatPath(ctx, req.(*StatPathRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // TransferService_ServiceDesc is the grpc.ServiceDesc for TransferService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -1463,6 +1851,18 @@ var TransferService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "ListTransfers",
 			Handler:    _TransferService_ListTransfers_Handler,
 		},
+		{
+			MethodName: "SetTransferRateLimit",
+			Handler:    _TransferService_SetTransferRateLimit_Handler,
+		},
+		{
+			MethodName: "ListDirectory",
+			Handler:    _TransferService_ListDirectory_Handler,
+		},
+		{
+			MethodName: "StatPath",
+			Handler:    _TransferService_StatPath_Handler,
+		},
 	},
 	Streams: []grpc.StreamDesc{
 		{
@@ -1631,9 +2031,10 @@ var VoiceService_ServiceDesc = grpc.ServiceDesc{
 }
 
 const (
-	SettingsService_GetSettings_FullMethodName    = "/daemon.SettingsService/GetSettings"
-	SettingsService_UpdateSettings_FullMethodName = "/daemon.SettingsService/UpdateSettings"
-	SettingsService_ResetSettings_FullMethodName  = "/daemon.SettingsService/ResetSettings"
+	SettingsService_GetSettings_FullMethodName       = "/daemon.SettingsService/GetSettings"
+	SettingsService_UpdateSettings_FullMethodName    = "/daemon.SettingsService/UpdateSettings"
+	SettingsService_ResetSettings_FullMethodName     = "/daemon.SettingsService/ResetSettings"
+	SettingsService_SubscribeSettings_FullMethodName = "/daemon.SettingsService/SubscribeSettings"
 )
 
 // SettingsServiceClient is the client API for SettingsService service.
@@ -1650,6 +2051,9 @@ type SettingsServiceClient interface {
 	UpdateSettings(ctx context.Context, in *UpdateSettingsRequest, opts ...grpc.CallOption) (*Settings, error)
 	// ResetSettings resets settings to defaults.
 	ResetSettings(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (*Settings, error)
+	// SubscribeSettings streams the settings: the current ones first, then each
+	// change, whoever made it.
+	SubscribeSettings(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (grpc.ServerStreamingClient[Settings], error)
 }
 
 type settingsServiceClient struct {
@@ -1690,6 +2094,25 @@ func (c *settingsServiceClient) ResetSettings(ctx context.Context, in *emptypb.E
 	return out, nil
 }
 
+func (c *settingsServiceClient) SubscribeSettings(ctx context.Context, in *emptypb.Empty, opts ...grpc.CallOption) (grpc.ServerStreamingClient[Settings], error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	stream, err := c.cc.NewStream(ctx, &SettingsService_ServiceDesc.Streams[0], SettingsService_SubscribeSettings_FullMethodName, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &grpc.GenericClientStream[emptypb.Empty, Settings]{ClientStream: stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+// This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
+type SettingsService_SubscribeSettingsClient = grpc.ServerStreamingClient[Settings]
+
 // SettingsServiceServer is the server API for SettingsService service.
 // All implementations must embed UnimplementedSettingsServiceServer
 // for forward compatibility.
@@ -1704,6 +2127,9 @@ type SettingsServiceServer interface {
 	UpdateSettings(context.Context, *UpdateSettingsRequest) (*Settings, error)
 	// ResetSettings resets settings to defaults.
 	ResetSettings(context.Context, *emptypb.Empty) (*Settings, error)
+	// SubscribeSettings streams the settings: the current ones first, then each
+	// change, whoever made it.
+	SubscribeSettings(*emptypb.Empty, grpc.ServerStreamingServer[Settings]) error
 	mustEmbedUnimplementedSettingsServiceServer()
 }
 
@@ -1723,6 +2149,9 @@ func (UnimplementedSettingsServiceServer) UpdateSettings(context.Context, *Updat
 func (UnimplementedSettingsServiceServer) ResetSettings(context.Context, *emptypb.Empty) (*Settings, error) {
 	return nil, status.Error(codes.Unimplemented, "method ResetSettings not implemented")
 }
+func (UnimplementedSettingsServiceServer) SubscribeSettings(*emptypb.Empty, grpc.ServerStreamingServer[Settings]) error {
+	return status.Error(codes.Unimplemented, "method SubscribeSettings not implemented")
+}
 func (UnimplementedSettingsServiceServer) mustEmbedUnimplementedSettingsServiceServer() {}
 func (UnimplementedSettingsServiceServer) testEmbeddedByValue()                         {}
 
@@ -1798,6 +2227,17 @@ func _SettingsService_ResetSettings_Handler(srv interface{}, ctx context.Context
 	return interceptor(ctx, in, info, handler)
 }
 
+func _SettingsService_SubscribeSettings_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(emptypb.Empty)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(SettingsServiceServer).SubscribeSettings(m, &grpc.GenericServerStream[emptypb.Empty, Settings]{ServerStream: stream})
+}
+
+// This type alias is provided for backwards compatibility with existing code that references the prior non-generic stream type by name.
+type SettingsService_SubscribeSettingsServer = grpc.ServerStreamingServer[Settings]
+
 // SettingsService_ServiceDesc is the grpc.ServiceDesc for SettingsService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -1818,6 +2258,12 @@ var SettingsService_ServiceDesc = grpc.ServiceDesc{
 			Handler:    _SettingsService_ResetSettings_Handler,
 		},
 	},
-	Streams:  []grpc.StreamDesc{},
+	Streams: []grpc.StreamDesc{
+		{
+			StreamName:    "SubscribeSettings",
+			Handler:       _SettingsService_SubscribeSettings_Handler,
+			ServerStreams: true,
+		},
+	},
 	Metadata: "daemon.proto",
 }
@@ -0,0 +1,51 @@
+package repository
+
+import (
+	"context"
+	"sort"
+	"sync"
+
+	"github.com/orhaniscoding/goconnect/server/internal/domain"
+)
+
+// PeerReportRepository stores members' reports about each other
+type PeerReportRepository interface {
+	Create(ctx context.Context, report *domain.PeerReport) error
+	// ListByNetwork returns a network's reports, newest first
+	ListByNetwork(ctx context.Context, networkID string, limit int) ([]*domain.PeerReport, error)
+}
+
+// InMemoryPeerReportRepository is an in-memory implementation
+type InMemoryPeerReportRepository struct {
+	mu        sync.RWMutex
+	byNetwork map[string][]*domain.PeerReport
+}
+
+// NewInMemoryPeerReportRepository creates a new in-memory peer report repository
+func NewInMemoryPeerReportRepository() *InMemoryPeerReportRepository {
+	return &InMemoryPeerReportRepository{byNetwork: make(map[string][]*domain.PeerReport)}
+}
+
+// Create stores a new peer report
+func (r *InMemoryPeerReportRepository) Create(ctx context.Context, report *domain.PeerReport) error {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	r.byNetwork[report.NetworkID] = append(r.byNetwork[report.NetworkID], report)
+	return nil
+}
+
+// ListByNetwork returns a network's reports, newest first
+func (r *InMemoryPeerReportRepository) ListByNetwork(ctx context.Context, networkID string, limit int) ([]*domain.PeerReport, error) {
+	r.mu.RLock()
+	defer r.mu.RUnlock()
+
+	reports := append([]*domain.PeerReport(nil), r.byNetwork[networkID]...)
+	sort.SliceStable(reports, func(i, j int) bool {
+		return reports[i].CreatedAt.After(reports[j].CreatedAt)
+	})
+	if limit > 0 && len(reports) > limit {
+		reports = reports[:limit]
+	}
+	return reports, nil
+}
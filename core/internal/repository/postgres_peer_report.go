@@ -0,0 +1,89 @@
+package repository
+
+import (
+	"context"
+	"database/sql"
+	"encoding/json"
+	"fmt"
+
+	"github.com/orhaniscoding/goconnect/server/internal/domain"
+)
+
+// PostgresPeerReportRepository implements PeerReportRepository using PostgreSQL
+type PostgresPeerReportRepository struct {
+	db *sql.DB
+}
+
+// NewPostgresPeerReportRepository creates a new PostgreSQL-backed peer report repository
+func NewPostgresPeerReportRepository(db *sql.DB) *PostgresPeerReportRepository {
+	return &PostgresPeerReportRepository{db: db}
+}
+
+// Create stores a new peer report
+func (r *PostgresPeerReportRepository) Create(ctx context.Context, report *domain.PeerReport) error {
+	messagesJSON, err := json.Marshal(report.UnverifiedMessages)
+	if err != nil {
+		return fmt.Errorf("failed to marshal reported messages: %w", err)
+	}
+
+	query := `
+		INSERT INTO peer_reports (id, network_id, reporter_id, target_id, reason, messages, created_at)
+		VALUES ($1, $2, $3, $4, $5, $6, $7)
+	`
+	_, err = r.db.ExecContext(ctx, query,
+		report.ID,
+		report.NetworkID,
+		report.ReporterID,
+		report.TargetID,
+		report.Reason,
+		messagesJSON,
+		report.CreatedAt,
+	)
+	if err != nil {
+		return fmt.Errorf("failed to create peer report: %w", err)
+	}
+	return nil
+}
+
+// ListByNetwork returns a network's reports, newest first
+func (r *PostgresPeerReportRepository) ListByNetwork(ctx context.Context, networkID string, limit int) ([]*domain.PeerReport, error) {
+	query := `
+		SELECT id, network_id, reporter_id, target_id, reason, messages, created_at
+		FROM peer_reports
+		WHERE network_id = $1
+		ORDER BY created_at DESC
+		LIMIT $2
+	`
+	rows, err := r.db.QueryContext(ctx, query, networkID, limit)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list peer reports: %w", err)
+	}
+	defer rows.Close()
+
+	var reports []*domain.PeerReport
+	for rows.Next() {
+		report := &domain.PeerReport{}
+		var messagesJSON []byte
+		if err := rows.Scan(
+			&report.ID,
+			&report.NetworkID,
+			&report.ReporterID,
+			&report.TargetID,
+			&report.Reason,
+			&messagesJSON,
+			&report.CreatedAt,
+		); err != nil {
+			return nil, fmt.Errorf("failed to scan peer report: %w", err)
+		}
+		if err := json.Unmarshal(messagesJSON, &report.UnverifiedMessages); err != nil {
+			return nil, fmt.Errorf("failed to unmarshal reported messages: %w", err)
+		}
+		reports = append(reports, report)
+	}
+
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("error iterating peer reports: %w", err)
+	}
+
+	return reports, nil
+}
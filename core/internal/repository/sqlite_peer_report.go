@@ -0,0 +1,62 @@
+package repository
+
+import (
+	"context"
+	"database/sql"
+	"encoding/json"
+	"fmt"
+
+	"github.com/orhaniscoding/goconnect/server/internal/domain"
+)
+
+// SQLitePeerReportRepository implements PeerReportRepository using SQLite.
+type SQLitePeerReportRepository struct {
+	db *sql.DB
+}
+
+func NewSQLitePeerReportRepository(db *sql.DB) *SQLitePeerReportRepository {
+	return &SQLitePeerReportRepository{db: db}
+}
+
+func (r *SQLitePeerReportRepository) Create(ctx context.Context, report *domain.PeerReport) error {
+	messagesJSON, err := json.Marshal(report.UnverifiedMessages)
+	if err != nil {
+		return fmt.Errorf("failed to marshal reported messages: %w", err)
+	}
+	_, err = r.db.ExecContext(ctx, `
+		INSERT INTO peer_reports (id, network_id, reporter_id, target_id, reason, messages, created_at)
+		VALUES (?, ?, ?, ?, ?, ?, ?)
+	`, report.ID, report.NetworkID, report.ReporterID, report.TargetID, report.Reason, string(messagesJSON), report.CreatedAt)
+	if err != nil {
+		return fmt.Errorf("failed to create peer report: %w", err)
+	}
+	return nil
+}
+
+func (r *SQLitePeerReportRepository) ListByNetwork(ctx context.Context, networkID string, limit int) ([]*domain.PeerReport, error) {
+	rows, err := r.db.QueryContext(ctx, `
+		SELECT id, network_id, reporter_id, target_id, reason, messages, created_at
+		FROM peer_reports
+		WHERE network_id = ?
+		ORDER BY created_at DESC
+		LIMIT ?
+	`, networkID, limit)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list peer reports: %w", err)
+	}
+	defer rows.Close()
+
+	var reports []*domain.PeerReport
+	for rows.Next() {
+		report := &domain.PeerReport{}
+		var messagesJSON string
+		if err := rows.Scan(&report.ID, &report.NetworkID, &report.ReporterID, &report.TargetID, &report.Reason, &messagesJSON, &report.CreatedAt); err != nil {
+			return nil, fmt.Errorf("failed to scan peer report: %w", err)
+		}
+		if err := json.Unmarshal([]byte(messagesJSON), &report.UnverifiedMessages); err != nil {
+			return nil, fmt.Errorf("failed to unmarshal reported messages: %w", err)
+		}
+		reports = append(reports, report)
+	}
+	return reports, rows.Err()
+}
@@ -10,6 +10,7 @@ import (
 	"github.com/orhaniscoding/goconnect/server/internal/logger"
 	"github.com/orhaniscoding/goconnect/server/internal/proto"
 	"google.golang.org/protobuf/types/known/emptypb"
+	"google.golang.org/protobuf/types/known/timestamppb"
 )
 
 // DaemonHandler implements the DaemonServiceServer gRPC interface.
@@ -50,9 +51,10 @@ func (h *DaemonHandler) GetStatus(ctx context.Context, in *proto.GetStatusReques
 
 func (h *DaemonHandler) GetVersion(ctx context.Context, in *emptypb.Empty) (*proto.VersionResponse, error) {
 	return &proto.VersionResponse{
-		Version: h.version,
-		Os:      runtime.GOOS,
-		Arch:    runtime.GOARCH,
+		Version:    h.version,
+		Os:         runtime.GOOS,
+		Arch:       runtime.GOARCH,
+		DaemonTime: timestamppb.Now(),
 	}, nil
 }
 
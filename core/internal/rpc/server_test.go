@@ -95,6 +95,7 @@ func TestServer_StartStop(t *testing.T) {
 	resp, err := client.GetVersion(ctx, &emptypb.Empty{})
 	require.NoError(t, err)
 	assert.Equal(t, "test-version", resp.Version)
+	assert.NotNil(t, resp.DaemonTime)
 
 	// Test Shutdown call
 	_, err = client.Shutdown(ctx, &emptypb.Empty{})
@@ -15,6 +15,8 @@ type InviteService struct {
 	inviteRepo  repository.InviteTokenRepository
 	networkRepo repository.NetworkRepository
 	memberRepo  repository.MembershipRepository
+	peerRepo    repository.PeerRepository
+	userRepo    repository.UserRepository
 	auditor     audit.Auditor
 	baseURL     string // For generating invite URLs
 }
@@ -39,6 +41,12 @@ func (s *InviteService) SetAuditor(a audit.Auditor) {
 	s.auditor = a
 }
 
+// SetPreviewRepositories sets the repositories PreviewInvite reads online peers and the owner's name from
+func (s *InviteService) SetPreviewRepositories(peerRepo repository.PeerRepository, userRepo repository.UserRepository) {
+	s.peerRepo = peerRepo
+	s.userRepo = userRepo
+}
+
 // CreateInviteOptions contains options for creating an invite
 type CreateInviteOptions struct {
 	ExpiresIn int // seconds, default 24 hours
@@ -125,6 +133,57 @@ func (s *InviteService) ValidateInvite(ctx context.Context, tokenStr string) (*d
 	return token, nil
 }
 
+// PreviewInvite validates an invite token and describes the network it leads to
+func (s *InviteService) PreviewInvite(ctx context.Context, tokenStr string) (*domain.InvitePreview, error) {
+	token, err := s.ValidateInvite(ctx, tokenStr)
+	if err != nil {
+		return nil, err
+	}
+
+	network, err := s.networkRepo.GetByID(ctx, token.NetworkID)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get network for invite preview: %w", err)
+	}
+
+	preview := &domain.InvitePreview{
+		Token:       token,
+		NetworkName: network.Name,
+		OwnerID:     network.CreatedBy,
+	}
+
+	cursor := ""
+	for {
+		members, next, err := s.memberRepo.List(ctx, token.NetworkID, string(domain.StatusApproved), 100, cursor)
+		if err != nil {
+			return nil, fmt.Errorf("failed to list members for invite preview: %w", err)
+		}
+		preview.MemberCount += len(members)
+		for _, m := range members {
+			// Ownership can have been transferred since the network was created
+			if m.Role == domain.RoleOwner {
+				preview.OwnerID = m.UserID
+			}
+		}
+		if next == "" {
+			break
+		}
+		cursor = next
+	}
+
+	if s.peerRepo != nil {
+		if peers, err := s.peerRepo.GetActivePeers(ctx, token.NetworkID); err == nil {
+			preview.OnlineCount = len(peers)
+		}
+	}
+	if s.userRepo != nil && preview.OwnerID != "" {
+		if owner, err := s.userRepo.GetByID(ctx, preview.OwnerID); err == nil {
+			preview.OwnerName = owner.DisplayName()
+		}
+	}
+
+	return preview, nil
+}
+
 // UseInvite uses an invite token to join a network
 func (s *InviteService) UseInvite(ctx context.Context, tokenStr, userID string) (*domain.InviteToken, error) {
 	token, err := s.inviteRepo.UseToken(ctx, tokenStr)
@@ -189,6 +189,69 @@ func TestInviteService_ValidateAndUseInvite(t *testing.T) {
 	})
 }
 
+func TestInviteService_PreviewInvite(t *testing.T) {
+	inviteRepo := repository.NewInMemoryInviteTokenRepository()
+	networkRepo := repository.NewInMemoryNetworkRepository()
+	membershipRepo := repository.NewInMemoryMembershipRepository()
+	peerRepo := repository.NewInMemoryPeerRepository()
+	userRepo := repository.NewInMemoryUserRepository()
+
+	svc := NewInviteService(inviteRepo, networkRepo, membershipRepo, "https://app.example.com")
+	svc.SetPreviewRepositories(peerRepo, userRepo)
+	ctx := context.Background()
+
+	networkRepo.Create(ctx, &domain.Network{
+		ID:         "net-preview-1",
+		TenantID:   "tenant-1",
+		Name:       "Preview Test Network",
+		CIDR:       "10.40.0.0/24",
+		Visibility: domain.NetworkVisibilityPrivate,
+		JoinPolicy: domain.JoinPolicyInvite,
+		CreatedBy:  "user-creator-1",
+	})
+	// Ownership moved away from the creator
+	membershipRepo.UpsertApproved(ctx, "net-preview-1", "user-creator-1", domain.RoleAdmin, time.Now())
+	membershipRepo.UpsertApproved(ctx, "net-preview-1", "user-owner-1", domain.RoleOwner, time.Now())
+	membershipRepo.UpsertApproved(ctx, "net-preview-1", "user-member-1", domain.RoleMember, time.Now())
+
+	fullName := "Ada Owner"
+	userRepo.Create(ctx, &domain.User{ID: "user-owner-1", Email: "owner@example.com", FullName: &fullName})
+
+	peerRepo.Create(ctx, &domain.Peer{NetworkID: "net-preview-1", DeviceID: "dev-1", Active: true})
+	peerRepo.Create(ctx, &domain.Peer{NetworkID: "net-preview-1", DeviceID: "dev-2", Active: false})
+
+	response, err := svc.CreateInvite(ctx, "net-preview-1", "tenant-1", "user-owner-1", CreateInviteOptions{ExpiresIn: 3600})
+	if err != nil {
+		t.Fatalf("CreateInvite failed: %v", err)
+	}
+
+	t.Run("describes the network", func(t *testing.T) {
+		preview, err := svc.PreviewInvite(ctx, response.Token)
+		if err != nil {
+			t.Fatalf("PreviewInvite failed: %v", err)
+		}
+
+		if preview.NetworkName != "Preview Test Network" {
+			t.Errorf("expected network name, got %q", preview.NetworkName)
+		}
+		if preview.MemberCount != 3 {
+			t.Errorf("expected 3 members, got %d", preview.MemberCount)
+		}
+		if preview.OnlineCount != 1 {
+			t.Errorf("expected 1 online peer, got %d", preview.OnlineCount)
+		}
+		if preview.OwnerID != "user-owner-1" || preview.OwnerName != "Ada Owner" {
+			t.Errorf("expected owner user-owner-1 (Ada Owner), got %s (%s)", preview.OwnerID, preview.OwnerName)
+		}
+	})
+
+	t.Run("fails for invalid token", func(t *testing.T) {
+		if _, err := svc.PreviewInvite(ctx, "invalid-token"); err == nil {
+			t.Error("expected error for invalid token")
+		}
+	})
+}
+
 func TestInviteService_RevokeInvite(t *testing.T) {
 	// Setup repositories
 	inviteRepo := repository.NewInMemoryInviteTokenRepository()
@@ -19,6 +19,8 @@ type MembershipService struct {
 	joins            repository.JoinRequestRepository
 	idempotency      repository.IdempotencyRepository
 	invites          repository.InviteTokenRepository
+	reports          repository.PeerReportRepository
+	users            repository.UserRepository
 	peerProvisioning *PeerProvisioningService
 	aud              Auditor
 	notifier         MembershipNotifier
@@ -64,6 +66,16 @@ func (s *MembershipService) SetInviteTokenRepository(r repository.InviteTokenRep
 	s.invites = r
 }
 
+// SetPeerReportRepository sets the repository peer reports are stored in
+func (s *MembershipService) SetPeerReportRepository(r repository.PeerReportRepository) {
+	s.reports = r
+}
+
+// SetUserRepository sets the repository ListJoinRequests reads requester names from
+func (s *MembershipService) SetUserRepository(r repository.UserRepository) {
+	s.users = r
+}
+
 // SetPeerProvisioning sets the peer provisioning service
 func (s *MembershipService) SetPeerProvisioning(pp *PeerProvisioningService) {
 	s.peerProvisioning = pp
@@ -270,6 +282,115 @@ func (s *MembershipService) Ban(ctx context.Context, networkID, targetUserID, ac
 	return nil
 }
 
+// TransferOwnership makes an approved member the owner and demotes the current owner to admin
+func (s *MembershipService) TransferOwnership(ctx context.Context, networkID, newOwnerID, actorID, tenantID string) error {
+	// Verify network tenant
+	net, err := s.networks.GetByID(ctx, networkID)
+	if err != nil {
+		return fmt.Errorf("failed to get network for ownership transfer: %w", err)
+	}
+	if net.TenantID != tenantID {
+		return domain.NewError(domain.ErrNotFound, "Network not found", nil)
+	}
+
+	actor, err := s.members.Get(ctx, networkID, actorID)
+	if err != nil || actor.Role != domain.RoleOwner {
+		return domain.NewError(domain.ErrNotAuthorized, "Only the network owner can transfer ownership", nil)
+	}
+	if newOwnerID == actorID {
+		return domain.NewError(domain.ErrInvalidRequest, "You already own this network", nil)
+	}
+	target, err := s.members.Get(ctx, networkID, newOwnerID)
+	if err != nil || target.Status != domain.StatusApproved {
+		return domain.NewError(domain.ErrInvalidRequest, "The new owner must be an approved member", nil)
+	}
+
+	// Promote first so the network is never left without an owner
+	if _, err := s.members.UpsertApproved(ctx, networkID, newOwnerID, domain.RoleOwner, joinedAt(target)); err != nil {
+		return fmt.Errorf("failed to promote new owner: %w", err)
+	}
+	if _, err := s.members.UpsertApproved(ctx, networkID, actorID, domain.RoleAdmin, joinedAt(actor)); err != nil {
+		return fmt.Errorf("failed to demote previous owner: %w", err)
+	}
+
+	s.audit(ctx, tenantID, audit.ActionNetworkOwnerTransfer, actorID, networkID, map[string]any{"new_owner": newOwnerID})
+	return nil
+}
+
+// ReportPeer records a member's report about another member for the network's admins to review
+func (s *MembershipService) ReportPeer(ctx context.Context, networkID, targetUserID, reporterID, tenantID, reason string, messages []domain.ReportedMessage) error {
+	// Verify network tenant
+	net, err := s.networks.GetByID(ctx, networkID)
+	if err != nil {
+		return fmt.Errorf("failed to get network for peer report: %w", err)
+	}
+	if net.TenantID != tenantID {
+		return domain.NewError(domain.ErrNotFound, "Network not found", nil)
+	}
+
+	reporter, err := s.members.Get(ctx, networkID, reporterID)
+	if err != nil || reporter.Status != domain.StatusApproved {
+		return domain.NewError(domain.ErrNotAuthorized, "Only network members can report peers", nil)
+	}
+	if targetUserID == reporterID {
+		return domain.NewError(domain.ErrInvalidRequest, "You cannot report yourself", nil)
+	}
+	if _, err := s.members.Get(ctx, networkID, targetUserID); err != nil {
+		return domain.NewError(domain.ErrNotFound, "Member not found", nil)
+	}
+
+	if s.reports == nil {
+		return fmt.Errorf("peer report repository not configured")
+	}
+	report := &domain.PeerReport{
+		ID:                 domain.GeneratePeerReportID(),
+		NetworkID:          networkID,
+		ReporterID:         reporterID,
+		TargetID:           targetUserID,
+		Reason:             reason,
+		CreatedAt:          time.Now(),
+		UnverifiedMessages: messages,
+	}
+	if err := s.reports.Create(ctx, report); err != nil {
+		return fmt.Errorf("failed to store peer report: %w", err)
+	}
+
+	// The reason and quoted messages stay in the report; the audit log only gets IDs and counts
+	s.audit(ctx, tenantID, audit.ActionNetworkPeerReport, reporterID, networkID, map[string]any{"report_id": report.ID, "target": targetUserID, "messages": len(messages)})
+	return nil
+}
+
+// ListPeerReports lists a network's peer reports, newest first (admin/owner only)
+func (s *MembershipService) ListPeerReports(ctx context.Context, networkID, tenantID string, limit int) ([]*domain.PeerReport, error) {
+	// Verify network tenant
+	net, err := s.networks.GetByID(ctx, networkID)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get network: %w", err)
+	}
+	if net.TenantID != tenantID {
+		return nil, domain.NewError(domain.ErrNotFound, "Network not found", nil)
+	}
+	if s.reports == nil {
+		return nil, fmt.Errorf("peer report repository not configured")
+	}
+	if limit <= 0 || limit > 100 {
+		limit = 50
+	}
+	reports, err := s.reports.ListByNetwork(ctx, networkID, limit)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list peer reports: %w", err)
+	}
+	return reports, nil
+}
+
+// joinedAt keeps a member's original join time when their role changes
+func joinedAt(m *domain.Membership) time.Time {
+	if m.JoinedAt != nil {
+		return *m.JoinedAt
+	}
+	return time.Now()
+}
+
 func (s *MembershipService) ListMembers(ctx context.Context, networkID, status, tenantID string, limit int, cursor string) ([]*domain.Membership, string, error) {
 	// Verify network tenant
 	net, err := s.networks.GetByID(ctx, networkID)
@@ -300,6 +421,13 @@ func (s *MembershipService) ListJoinRequests(ctx context.Context, networkID, ten
 	if err != nil {
 		return nil, fmt.Errorf("failed to list pending join requests: %w", err)
 	}
+	if s.users != nil {
+		for _, r := range requests {
+			if u, err := s.users.GetByID(ctx, r.UserID); err == nil {
+				r.UserName = u.DisplayName()
+			}
+		}
+	}
 	return requests, nil
 }
 
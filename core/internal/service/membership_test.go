@@ -6,6 +6,7 @@ import (
 	"testing"
 	"time"
 
+	"github.com/orhaniscoding/goconnect/server/internal/audit"
 	"github.com/orhaniscoding/goconnect/server/internal/domain"
 	"github.com/orhaniscoding/goconnect/server/internal/repository"
 	"github.com/stretchr/testify/assert"
@@ -474,6 +475,139 @@ func TestBan_Unauthorized(t *testing.T) {
 	}
 }
 
+// Test TransferOwnership: Owner hands the network to a member
+func TestTransferOwnership_Success(t *testing.T) {
+	nrepo := repository.NewInMemoryNetworkRepository()
+	mrepo := repository.NewInMemoryMembershipRepository()
+	jrepo := repository.NewInMemoryJoinRequestRepository()
+	irepo := repository.NewInMemoryIdempotencyRepository()
+	svc := NewMembershipService(nrepo, mrepo, jrepo, irepo)
+
+	net := &domain.Network{
+		ID:         "net-transfer",
+		TenantID:   "t1",
+		Name:       "TransferNet",
+		Visibility: domain.NetworkVisibilityPublic,
+		JoinPolicy: domain.JoinPolicyOpen,
+		CIDR:       "10.9.0.0/24",
+		CreatedBy:  "owner",
+	}
+	_ = nrepo.Create(context.Background(), net)
+	_, _ = mrepo.UpsertApproved(context.Background(), net.ID, "owner", domain.RoleOwner, time.Now())
+	_, _ = mrepo.UpsertApproved(context.Background(), net.ID, "heir", domain.RoleMember, time.Now())
+
+	if err := svc.TransferOwnership(context.Background(), net.ID, "heir", "owner", "t1"); err != nil {
+		t.Fatalf("expected transfer to succeed, got error: %v", err)
+	}
+
+	heir, _ := mrepo.Get(context.Background(), net.ID, "heir")
+	if heir.Role != domain.RoleOwner {
+		t.Errorf("expected new owner role %s, got %s", domain.RoleOwner, heir.Role)
+	}
+	previous, _ := mrepo.Get(context.Background(), net.ID, "owner")
+	if previous.Role != domain.RoleAdmin {
+		t.Errorf("expected previous owner role %s, got %s", domain.RoleAdmin, previous.Role)
+	}
+}
+
+// Test TransferOwnership: Admins cannot hand over a network they do not own
+func TestTransferOwnership_NotOwner(t *testing.T) {
+	nrepo := repository.NewInMemoryNetworkRepository()
+	mrepo := repository.NewInMemoryMembershipRepository()
+	jrepo := repository.NewInMemoryJoinRequestRepository()
+	irepo := repository.NewInMemoryIdempotencyRepository()
+	svc := NewMembershipService(nrepo, mrepo, jrepo, irepo)
+
+	net := &domain.Network{
+		ID:         "net-transfer-unauth",
+		TenantID:   "t1",
+		Name:       "Net",
+		Visibility: domain.NetworkVisibilityPublic,
+		JoinPolicy: domain.JoinPolicyOpen,
+		CIDR:       "10.10.0.0/24",
+		CreatedBy:  "owner",
+	}
+	_ = nrepo.Create(context.Background(), net)
+	_, _ = mrepo.UpsertApproved(context.Background(), net.ID, "owner", domain.RoleOwner, time.Now())
+	_, _ = mrepo.UpsertApproved(context.Background(), net.ID, "admin", domain.RoleAdmin, time.Now())
+
+	err := svc.TransferOwnership(context.Background(), net.ID, "admin", "admin", "t1")
+	var derr *domain.Error
+	if !errors.As(err, &derr) {
+		t.Fatalf("expected domain.Error, got %T", err)
+	}
+	if derr.Code != domain.ErrNotAuthorized {
+		t.Errorf("expected error code %s, got %s", domain.ErrNotAuthorized, derr.Code)
+	}
+}
+
+// Test ReportPeer: Members can report each other, but not themselves
+func TestReportPeer(t *testing.T) {
+	nrepo := repository.NewInMemoryNetworkRepository()
+	mrepo := repository.NewInMemoryMembershipRepository()
+	jrepo := repository.NewInMemoryJoinRequestRepository()
+	irepo := repository.NewInMemoryIdempotencyRepository()
+	svc := NewMembershipService(nrepo, mrepo, jrepo, irepo)
+	rrepo := repository.NewInMemoryPeerReportRepository()
+	svc.SetPeerReportRepository(rrepo)
+
+	var actions []string
+	var auditDetails map[string]any
+	svc.SetAuditor(auditorFunc(func(ctx context.Context, tenantID, action, actor, object string, details map[string]any) {
+		actions = append(actions, action)
+		auditDetails = details
+	}))
+
+	net := &domain.Network{
+		ID:         "net-report",
+		TenantID:   "t1",
+		Name:       "ReportNet",
+		Visibility: domain.NetworkVisibilityPublic,
+		JoinPolicy: domain.JoinPolicyOpen,
+		CIDR:       "10.11.0.0/24",
+		CreatedBy:  "owner",
+	}
+	_ = nrepo.Create(context.Background(), net)
+	_, _ = mrepo.UpsertApproved(context.Background(), net.ID, "alice", domain.RoleMember, time.Now())
+	_, _ = mrepo.UpsertApproved(context.Background(), net.ID, "spammer", domain.RoleMember, time.Now())
+
+	messages := []domain.ReportedMessage{{ID: "m1", From: "spammer", Content: "buy now", SentAt: time.Now()}}
+	if err := svc.ReportPeer(context.Background(), net.ID, "spammer", "alice", "t1", "spam", messages); err != nil {
+		t.Fatalf("expected report to succeed, got error: %v", err)
+	}
+	if len(actions) != 1 || actions[0] != audit.ActionNetworkPeerReport {
+		t.Errorf("expected one %s audit event, got %v", audit.ActionNetworkPeerReport, actions)
+	}
+	if _, ok := auditDetails["reason"]; ok {
+		t.Errorf("expected the reason to stay out of the audit log, got %v", auditDetails)
+	}
+	if auditDetails["messages"] != 1 {
+		t.Errorf("expected the audit log to count the quoted messages, got %v", auditDetails["messages"])
+	}
+
+	reports, err := svc.ListPeerReports(context.Background(), net.ID, "t1", 0)
+	if err != nil {
+		t.Fatalf("expected reports to be listed, got error: %v", err)
+	}
+	if len(reports) != 1 || reports[0].TargetID != "spammer" || reports[0].Reason != "spam" || len(reports[0].UnverifiedMessages) != 1 {
+		t.Errorf("expected the stored report, got %+v", reports)
+	}
+	if auditDetails["report_id"] != reports[0].ID {
+		t.Errorf("expected the audit log to reference report %s, got %v", reports[0].ID, auditDetails["report_id"])
+	}
+
+	err = svc.ReportPeer(context.Background(), net.ID, "alice", "alice", "t1", "", nil)
+	var derr *domain.Error
+	if !errors.As(err, &derr) || derr.Code != domain.ErrInvalidRequest {
+		t.Errorf("expected %s for a self-report, got %v", domain.ErrInvalidRequest, err)
+	}
+
+	err = svc.ReportPeer(context.Background(), net.ID, "spammer", "stranger", "t1", "", nil)
+	if !errors.As(err, &derr) || derr.Code != domain.ErrNotAuthorized {
+		t.Errorf("expected %s for a non-member, got %v", domain.ErrNotAuthorized, err)
+	}
+}
+
 // Test ListMembers: List all approved members
 func TestListMembers_Success(t *testing.T) {
 	nrepo := repository.NewInMemoryNetworkRepository()
@@ -649,6 +783,32 @@ func TestMembershipService_ListJoinRequests(t *testing.T) {
 		_ = requests
 	})
 
+	t.Run("List Join Requests Includes Names", func(t *testing.T) {
+		urepo := repository.NewInMemoryUserRepository()
+		fullName := "Grace Requester"
+		_ = urepo.Create(ctx, &domain.User{ID: "user-jr-1", Email: "grace@example.com", FullName: &fullName})
+		service.SetUserRepository(urepo)
+		defer service.SetUserRepository(nil)
+
+		_, _ = jrepo.CreatePending(ctx, "net-jr-1", "user-jr-1")
+		_, _ = jrepo.CreatePending(ctx, "net-jr-1", "user-jr-unknown")
+
+		requests, err := service.ListJoinRequests(ctx, "net-jr-1", "tenant-1")
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		names := map[string]string{}
+		for _, r := range requests {
+			names[r.UserID] = r.UserName
+		}
+		if names["user-jr-1"] != "Grace Requester" {
+			t.Errorf("expected requester name, got %q", names["user-jr-1"])
+		}
+		if names["user-jr-unknown"] != "" {
+			t.Errorf("expected empty name for unknown user, got %q", names["user-jr-unknown"])
+		}
+	})
+
 	t.Run("List Join Requests Wrong Tenant", func(t *testing.T) {
 		// Should fail with wrong tenantID
 		_, err := service.ListJoinRequests(ctx, "net-jr-1", "wrong-tenant")
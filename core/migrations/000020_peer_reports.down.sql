@@ -0,0 +1,5 @@
+-- GoConnect Migration: Remove peer reports
+-- Version: 20 (down)
+
+DROP INDEX IF EXISTS idx_peer_reports_network_created;
+DROP TABLE IF EXISTS peer_reports;
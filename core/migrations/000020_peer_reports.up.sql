@@ -0,0 +1,19 @@
+-- GoConnect Migration: Peer reports
+-- Version: 20
+-- Date: 2026-10-16
+-- Description: Members' reports about each other, for network admins to review
+
+CREATE TABLE IF NOT EXISTS peer_reports (
+    id VARCHAR(64) PRIMARY KEY,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    reporter_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    target_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason TEXT NOT NULL DEFAULT '',
+    messages JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_peer_reports_network_created ON peer_reports(network_id, created_at DESC);
+
+COMMENT ON TABLE peer_reports IS 'Reports by network members about other members';
+COMMENT ON COLUMN peer_reports.messages IS 'Chat messages quoted by the reporter''s client; not verified by the server';
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_peer_reports_network_created;
+DROP TABLE IF EXISTS peer_reports;
@@ -0,0 +1,13 @@
+-- Members' reports about each other, for network admins to review
+-- This matches the Postgres migration 000020_peer_reports
+
+CREATE TABLE IF NOT EXISTS peer_reports (
+    id TEXT PRIMARY KEY,
+    network_id TEXT NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    reporter_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    target_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason TEXT NOT NULL DEFAULT '',
+    messages TEXT NOT NULL DEFAULT '[]', -- JSON; quoted by the reporter's client, not verified
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_peer_reports_network_created ON peer_reports(network_id, created_at);
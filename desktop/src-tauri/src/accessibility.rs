@@ -0,0 +1,153 @@
+// Accessibility Announcements
+// Speaks important state changes (connected/disconnected, transfer complete) to
+// screen readers in addition to notifications.
+//
+// While the window is visible, announcements go to an ARIA live region in the
+// webview, which the webview exposes through UIA / NSAccessibility / AT-SPI.
+// While it is hidden in the tray, a native fallback speaks through VoiceOver
+// (macOS) or speech-dispatcher, the speech backend Orca uses (Linux).
+
+use crate::commands::DaemonState;
+use crate::i18n::{self, Text};
+use crate::store::JsonStore;
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Tauri event carrying an `Announcement` for the frontend live region
+pub const ANNOUNCE_EVENT: &str = "a11y://announce";
+
+const SETTINGS_FILE: &str = "accessibility.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum Politeness {
+    /// Read after the current utterance (aria-live="polite")
+    Polite,
+    /// Interrupt the screen reader (aria-live="assertive")
+    Assertive,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct Announcement {
+    pub message: String,
+    pub politeness: Politeness,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct AccessibilitySettings {
+    pub announcements_enabled: bool,
+    /// Speak through the OS screen reader while the window is hidden
+    pub native_fallback: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self { announcements_enabled: true, native_fallback: true }
+    }
+}
+
+/// Managed state holding the persisted accessibility settings
+pub struct AccessibilityState {
+    settings: JsonStore<AccessibilitySettings>,
+}
+
+impl AccessibilityState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE) }
+    }
+}
+
+/// Announce a message to assistive technology
+pub fn announce(app: &AppHandle, message: &str, politeness: Politeness) {
+    let Some(state) = app.try_state::<AccessibilityState>() else {
+        return;
+    };
+    let settings = state.settings.get();
+    if !settings.announcements_enabled {
+        return;
+    }
+
+    let window_visible = app
+        .get_webview_window("main")
+        .and_then(|w| w.is_visible().ok())
+        .unwrap_or(false);
+    if window_visible {
+        let _ = app.emit(ANNOUNCE_EVENT, Announcement { message: message.to_string(), politeness });
+    } else if settings.native_fallback {
+        let message = message.to_string();
+        std::thread::spawn(move || speak_native(&message));
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn speak_native(message: &str) {
+    // Only VoiceOver users should hear this, so never launch it
+    let script = format!(
+        "if application \"VoiceOver\" is running then tell application \"VoiceOver\" to output \"{}\"",
+        message.replace('\\', "\\\\").replace('"', "\\\"")
+    );
+    let _ = std::process::Command::new("osascript").args(["-e", &script]).status();
+}
+
+#[cfg(target_os = "linux")]
+fn speak_native(message: &str) {
+    // Only speak when a screen reader is active, not for every desktop user
+    let orca_running = std::process::Command::new("pgrep")
+        .args(["-x", "orca"])
+        .output()
+        .is_ok_and(|out| out.status.success());
+    if orca_running {
+        let _ = std::process::Command::new("spd-say").args(["--application-name", "GoConnect", "--", message]).status();
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn speak_native(_message: &str) {
+    // Narrator has no public speech API outside UIA; the notification toast is read instead
+}
+
+/// Announce connect/disconnect transitions from the status stream
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut status_rx = app.state::<DaemonState>().subscribe_status();
+        // The first status only establishes the baseline; startup is not a transition
+        let mut connected_to: Option<Option<String>> = None;
+
+        while status_rx.changed().await.is_ok() {
+            let status = status_rx.borrow_and_update().clone();
+            let now = status.filter(|s| s.connected).map(|s| s.network_name);
+            let previous = connected_to.replace(now.clone());
+            if previous.is_none() || previous == Some(now.clone()) {
+                continue;
+            }
+
+            let message = match &now {
+                Some(network) => i18n::text(Text::A11yConnected).replace("{network}", network),
+                None => i18n::text(Text::A11yDisconnected).to_string(),
+            };
+            announce(&app, &message, Politeness::Assertive);
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_accessibility_settings(state: State<'_, AccessibilityState>) -> Result<AccessibilitySettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_accessibility_settings(
+    state: State<'_, AccessibilityState>,
+    settings: AccessibilitySettings,
+) -> Result<AccessibilitySettings, String> {
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
@@ -0,0 +1,340 @@
+// Activity Log
+// Local record of connection sessions and finished transfers, the raw data behind
+// usage reports and the session history. The daemon only knows the present; this
+// remembers the past.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const LOG_FILE: &str = "activity.json";
+
+/// How often transfers are polled and the open session's heartbeat is written
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Records older than this are pruned
+const RETENTION_SECS: u64 = 180 * 86_400;
+
+/// How a session ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SessionEnd {
+    Disconnected,
+    /// Connected to another network
+    Switched,
+    /// The app quit or crashed while connected
+    Interrupted,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Session {
+    pub network: String,
+    /// Unix timestamps (seconds)
+    pub started_at: u64,
+    /// `None` while connected
+    pub ended_at: Option<u64>,
+    /// Last time the session was seen alive, used to close it after a crash
+    pub last_seen: u64,
+    /// `None` while connected, and for sessions recorded before this was tracked
+    #[serde(default)]
+    pub end: Option<SessionEnd>,
+}
+
+impl Session {
+    /// Connected seconds that fall inside `[from, to)`
+    pub fn overlap(&self, from: u64, to: u64) -> u64 {
+        let end = self.ended_at.unwrap_or(self.last_seen).min(to);
+        end.saturating_sub(self.started_at.max(from))
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct SessionEntry {
+    pub network: String,
+    #[ts(type = "number")]
+    pub started_at: u64,
+    /// `None` while connected
+    #[ts(type = "number | null")]
+    pub ended_at: Option<u64>,
+    pub end: Option<SessionEnd>,
+    /// Up to now for the open session
+    #[ts(type = "number")]
+    pub duration_secs: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct NetworkTime {
+    pub network: String,
+    pub sessions: u32,
+    #[ts(type = "number")]
+    pub connected_secs: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct SessionHistory {
+    /// Newest first
+    pub sessions: Vec<SessionEntry>,
+    /// Connected time inside the filter range across all matching sessions,
+    /// not only the returned page
+    #[ts(type = "number")]
+    pub total_secs: u64,
+    /// Sorted by connected time
+    pub networks: Vec<NetworkTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TransferRecord {
+    pub id: String,
+    pub peer_id: String,
+    pub peer_name: String,
+    /// Network the client was connected to when the transfer finished
+    pub network: String,
+    pub file_name: String,
+    pub bytes: u64,
+    /// "upload" or "download"
+    pub direction: String,
+    /// "completed", "failed" or "cancelled"
+    pub status: String,
+    pub finished_at: u64,
+    /// Local file: where a download was saved, or what was uploaded; `None` when unknown
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ActivityLog {
+    pub sessions: Vec<Session>,
+    pub transfers: Vec<TransferRecord>,
+}
+
+/// Managed state holding the persisted activity log
+pub struct ActivityState {
+    log: JsonStore<ActivityLog>,
+    /// Local paths of transfers still running, by transfer id
+    paths: Mutex<HashMap<String, String>>,
+}
+
+impl ActivityState {
+    pub fn load(app: &AppHandle) -> Self {
+        let state = Self { log: JsonStore::open(app, LOG_FILE), paths: Mutex::new(HashMap::new()) };
+
+        // A session left open by a crash ends when it was last seen
+        let _ = state.log.update(|log| {
+            for session in log.sessions.iter_mut().filter(|s| s.ended_at.is_none()) {
+                session.ended_at = Some(session.last_seen);
+                session.end = Some(SessionEnd::Interrupted);
+            }
+        });
+        state
+    }
+
+    pub fn snapshot(&self) -> ActivityLog {
+        self.log.get()
+    }
+
+    fn switch_session(&self, network: Option<&str>) {
+        let now = unix_now();
+        let result = self.log.update(|log| {
+            let end = if network.is_some() { SessionEnd::Switched } else { SessionEnd::Disconnected };
+            for session in log.sessions.iter_mut().filter(|s| s.ended_at.is_none()) {
+                session.ended_at = Some(now);
+                session.last_seen = now;
+                session.end = Some(end);
+            }
+            if let Some(network) = network {
+                log.sessions.push(Session {
+                    network: network.to_string(),
+                    started_at: now,
+                    ended_at: None,
+                    last_seen: now,
+                    end: None,
+                });
+            }
+            prune(log, now);
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record session: {}", e);
+        }
+    }
+
+    /// Note the local file of a transfer, kept with its record once it finishes
+    pub(crate) fn remember_path(&self, transfer_id: &str, path: &str) {
+        self.paths.lock().unwrap().insert(transfer_id.to_string(), path.to_string());
+    }
+
+    /// Local file of a transfer, whether still running or already recorded
+    pub(crate) fn path_of(&self, transfer_id: &str) -> Option<String> {
+        if let Some(path) = self.paths.lock().unwrap().get(transfer_id) {
+            return Some(path.clone());
+        }
+        self.log.get().transfers.into_iter().find(|t| t.id == transfer_id).and_then(|t| t.path)
+    }
+
+    fn record_transfers(&self, mut finished: Vec<TransferRecord>) {
+        let now = unix_now();
+        {
+            let mut paths = self.paths.lock().unwrap();
+            for record in &mut finished {
+                record.path = paths.remove(&record.id);
+            }
+        }
+        let result = self.log.update(|log| {
+            if let Some(session) = log.sessions.iter_mut().find(|s| s.ended_at.is_none()) {
+                session.last_seen = now;
+            }
+            let known: HashSet<String> = log.transfers.iter().map(|t| t.id.clone()).collect();
+            log.transfers.extend(finished.into_iter().filter(|t| !known.contains(&t.id)));
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record transfers: {}", e);
+        }
+    }
+}
+
+fn prune(log: &mut ActivityLog, now: u64) {
+    let cutoff = now.saturating_sub(RETENTION_SECS);
+    log.sessions.retain(|s| s.ended_at.is_none_or(|end| end >= cutoff));
+    log.transfers.retain(|t| t.finished_at >= cutoff);
+}
+
+/// Follow the status stream for sessions and poll the daemon for finished transfers
+pub fn spawn(app: AppHandle) {
+    let session_app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let activity = session_app.state::<ActivityState>();
+        let mut status_rx = session_app.state::<DaemonState>().subscribe_status();
+        let mut current: Option<String> = None;
+
+        while status_rx.changed().await.is_ok() {
+            let status = status_rx.borrow_and_update().clone();
+            // An unreachable daemon is not a disconnect; only trust real answers
+            let Some(status) = status else { continue };
+            let network = status.connected.then_some(status.network_name);
+            if network != current {
+                activity.switch_session(network.as_deref());
+                current = network;
+            }
+        }
+    });
+
+    tauri::async_runtime::spawn(async move {
+        let activity = app.state::<ActivityState>();
+        let daemon = app.state::<DaemonState>();
+        loop {
+            tokio::time::sleep(SAMPLE_INTERVAL).await;
+            if !daemon.connection.state().is_usable() {
+                continue;
+            }
+
+            let transfers = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None));
+            let peers = daemon_call!(daemon, "get_peers", |client| client.get_peers()).unwrap_or_default();
+            let network = daemon
+                .latest_status()
+                .filter(|s| s.connected)
+                .map(|s| s.network_name)
+                .unwrap_or_default();
+
+            let Ok(transfers) = transfers else { continue };
+            let now = unix_now();
+            let finished = transfers
+                .into_iter()
+                .filter(|t| matches!(t.status.as_str(), "completed" | "failed" | "cancelled"))
+                .map(|t| TransferRecord {
+                    peer_name: peers
+                        .iter()
+                        .find(|p| p.id == t.peer_id)
+                        .map(|p| if p.display_name.is_empty() { p.name.clone() } else { p.display_name.clone() })
+                        .unwrap_or_else(|| t.peer_id.clone()),
+                    id: t.id,
+                    peer_id: t.peer_id,
+                    network: network.clone(),
+                    file_name: t.file_name,
+                    bytes: t.transferred,
+                    direction: t.direction,
+                    status: t.status,
+                    finished_at: now,
+                    path: None,
+                })
+                .collect();
+            activity.record_transfers(finished);
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Sessions overlapping `[from, to)` (default: all retained), optionally for one
+/// network, with connected-time totals
+#[tauri::command]
+pub async fn get_session_history(
+    state: State<'_, ActivityState>,
+    network: Option<String>,
+    from: Option<u64>,
+    to: Option<u64>,
+    limit: Option<usize>,
+) -> Result<SessionHistory, String> {
+    let now = unix_now();
+    let (from, to) = (from.unwrap_or(0), to.unwrap_or(now).min(now));
+    if from >= to {
+        return Err("the start of the range must be before its end".to_string());
+    }
+
+    let mut sessions: Vec<Session> = state
+        .snapshot()
+        .sessions
+        .into_iter()
+        .filter(|s| network.as_ref().is_none_or(|n| &s.network == n))
+        .map(|mut s| {
+            // The open session runs until now, not its last heartbeat
+            if s.ended_at.is_none() {
+                s.last_seen = now;
+            }
+            s
+        })
+        .filter(|s| s.overlap(from, to) > 0)
+        .collect();
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.started_at));
+
+    let mut networks: HashMap<String, NetworkTime> = HashMap::new();
+    let mut total_secs = 0;
+    for session in &sessions {
+        let secs = session.overlap(from, to);
+        total_secs += secs;
+        let entry = networks.entry(session.network.clone()).or_insert_with(|| NetworkTime {
+            network: session.network.clone(),
+            sessions: 0,
+            connected_secs: 0,
+        });
+        entry.sessions += 1;
+        entry.connected_secs += secs;
+    }
+    let mut networks: Vec<NetworkTime> = networks.into_values().collect();
+    networks.sort_by_key(|n| std::cmp::Reverse(n.connected_secs));
+
+    Ok(SessionHistory {
+        sessions: sessions
+            .into_iter()
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|s| SessionEntry {
+                duration_secs: s.ended_at.unwrap_or(s.last_seen).saturating_sub(s.started_at),
+                network: s.network,
+                started_at: s.started_at,
+                ended_at: s.ended_at,
+                end: s.end,
+            })
+            .collect(),
+        total_secs,
+        networks,
+    })
+}
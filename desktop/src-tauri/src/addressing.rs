@@ -0,0 +1,213 @@
+// Virtual Addresses
+// A network can give each member an IPv4 and an IPv6 address. The daemon
+// reports them together in its single `virtual_ip` field ("100.64.0.2",
+// "fd7a::2" or "100.64.0.2, fd7a::2"); the client splits them into
+// `virtual_ipv4`/`virtual_ipv6` and keeps `virtual_ip` as the address of the
+// preferred family, so code that needs one address keeps working.
+// Formatting helpers put IPv6 literals in brackets (or the ipv6-literal.net
+// form for UNC paths) wherever an address is joined with a port or a path.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::store::JsonStore;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::RwLock;
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "address_family.json";
+
+static PREFERRED: RwLock<AddressFamily> = RwLock::new(AddressFamily::Ipv4);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum AddressFamily {
+    #[default]
+    Ipv4,
+    Ipv6,
+}
+
+/// How `format_address` writes an address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum AddressStyle {
+    /// The bare address
+    Plain,
+    /// `host:port`, bracketing IPv6
+    HostPort,
+    /// `http://host:port/`
+    Url,
+    /// `\\host` for Windows shares
+    Unc,
+}
+
+/// The addresses of one member, split by family
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VirtualAddresses {
+    pub v4: Option<Ipv4Addr>,
+    pub v6: Option<Ipv6Addr>,
+}
+
+impl VirtualAddresses {
+    /// Parse the daemon's `virtual_ip`: one or two addresses, optionally with a prefix length
+    pub fn parse(raw: &str) -> Self {
+        let mut addresses = Self::default();
+        for part in raw.split([',', ';', ' ']).map(str::trim).filter(|p| !p.is_empty()) {
+            let address = part.split('/').next().unwrap_or(part);
+            match address.parse::<IpAddr>() {
+                Ok(IpAddr::V4(ip)) if addresses.v4.is_none() => addresses.v4 = Some(ip),
+                Ok(IpAddr::V6(ip)) if addresses.v6.is_none() => addresses.v6 = Some(ip),
+                _ => {}
+            }
+        }
+        addresses
+    }
+
+    /// The address of the preferred family, falling back to the other one
+    pub fn preferred(&self) -> Option<IpAddr> {
+        self.of(preferred_family()).or_else(|| self.v4.map(IpAddr::V4)).or_else(|| self.v6.map(IpAddr::V6))
+    }
+
+    pub fn of(&self, family: AddressFamily) -> Option<IpAddr> {
+        match family {
+            AddressFamily::Ipv4 => self.v4.map(IpAddr::V4),
+            AddressFamily::Ipv6 => self.v6.map(IpAddr::V6),
+        }
+    }
+
+    /// `(virtual_ip, virtual_ipv4, virtual_ipv6)` for the client types. An
+    /// unparseable value is passed through as `virtual_ip` unchanged.
+    pub fn fields(raw: &str) -> (String, Option<String>, Option<String>) {
+        let addresses = Self::parse(raw);
+        let preferred = addresses.preferred().map(|ip| ip.to_string()).unwrap_or_else(|| raw.trim().to_string());
+        (preferred, addresses.v4.map(|ip| ip.to_string()), addresses.v6.map(|ip| ip.to_string()))
+    }
+}
+
+pub fn preferred_family() -> AddressFamily {
+    *PREFERRED.read().unwrap()
+}
+
+/// The address as a host part: IPv6 literals in brackets
+pub fn host(ip: &str) -> String {
+    match ip.parse::<IpAddr>() {
+        Ok(IpAddr::V6(_)) => format!("[{}]", ip),
+        _ => ip.to_string(),
+    }
+}
+
+pub fn host_port(ip: &str, port: u16) -> String {
+    format!("{}:{}", host(ip), port)
+}
+
+/// Host part of a UNC path; Windows cannot take an IPv6 literal there
+pub fn unc_host(ip: &str) -> String {
+    match ip.parse::<IpAddr>() {
+        Ok(IpAddr::V6(_)) => format!("{}.ipv6-literal.net", ip.replace(':', "-").replace('%', "s")),
+        _ => ip.to_string(),
+    }
+}
+
+/// Both addresses for display, preferred family first
+pub fn display(v4: Option<&str>, v6: Option<&str>) -> Option<String> {
+    let (first, second) = match preferred_family() {
+        AddressFamily::Ipv4 => (v4, v6),
+        AddressFamily::Ipv6 => (v6, v4),
+    };
+    match (first, second) {
+        (Some(a), Some(b)) => Some(format!("{} · {}", a, b)),
+        (a, b) => a.or(b).map(str::to_string),
+    }
+}
+
+/// `address` written in `style`; brackets around an IPv6 input are accepted
+pub fn format_text(address: &str, port: Option<u16>, style: AddressStyle) -> Result<String, String> {
+    let ip = address.trim().trim_start_matches('[').trim_end_matches(']');
+    ip.parse::<IpAddr>().map_err(|_| format!("'{}' is not an IP address", address))?;
+    Ok(format(ip, port, style))
+}
+
+fn format(ip: &str, port: Option<u16>, style: AddressStyle) -> String {
+    match (style, port) {
+        (AddressStyle::Plain, _) => ip.to_string(),
+        (AddressStyle::HostPort, Some(port)) => host_port(ip, port),
+        (AddressStyle::HostPort, None) => host(ip),
+        (AddressStyle::Url, Some(port)) => format!("http://{}/", host_port(ip, port)),
+        (AddressStyle::Url, None) => format!("http://{}/", host(ip)),
+        (AddressStyle::Unc, _) => format!("\\\\{}", unc_host(ip)),
+    }
+}
+
+/// Managed state holding the preferred family
+pub struct AddressingState {
+    family: JsonStore<AddressFamily>,
+}
+
+impl AddressingState {
+    pub fn load(app: &AppHandle) -> Self {
+        let family = JsonStore::open(app, SETTINGS_FILE);
+        *PREFERRED.write().unwrap() = family.get();
+        Self { family }
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_address_family() -> Result<AddressFamily, String> {
+    Ok(preferred_family())
+}
+
+/// Choose which family `virtual_ip` shows when a member has both
+#[tauri::command]
+pub async fn set_address_family(
+    state: State<'_, AddressingState>,
+    daemon: State<'_, DaemonState>,
+    family: AddressFamily,
+) -> Result<AddressFamily, String> {
+    state.family.set(family)?;
+    *PREFERRED.write().unwrap() = family;
+    // Cached statuses carry the old preference
+    let _ = daemon.probe_status().await;
+    Ok(family)
+}
+
+/// Write an address for a URL, a `host:port` pair or a share path
+#[tauri::command]
+pub async fn format_address(address: String, port: Option<u16>, style: AddressStyle) -> Result<String, String> {
+    format_text(&address, port, style)
+}
+
+/// Copy a peer's address: the requested (or preferred) family, formatted like
+/// `format_address`. Without `network_id`, the current network. Returns what was copied.
+#[tauri::command]
+pub async fn copy_peer_address(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    network_id: Option<String>,
+    peer_id: String,
+    family: Option<AddressFamily>,
+    port: Option<u16>,
+    style: Option<AddressStyle>,
+) -> Result<String, String> {
+    let network_id = network_id.unwrap_or_default();
+    let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(&network_id))?;
+    let peer = peers.into_iter().find(|p| p.id == peer_id).ok_or("peer not found")?;
+    let addresses = VirtualAddresses {
+        v4: peer.virtual_ipv4.as_deref().and_then(|ip| ip.parse().ok()),
+        v6: peer.virtual_ipv6.as_deref().and_then(|ip| ip.parse().ok()),
+    };
+    let ip = match family {
+        Some(family) => addresses.of(family).ok_or_else(|| {
+            let name = if family == AddressFamily::Ipv4 { "IPv4" } else { "IPv6" };
+            format!("{} has no {} address", peer.name, name)
+        })?,
+        None => addresses.preferred().ok_or_else(|| format!("{} has no virtual address", peer.name))?,
+    };
+    let text = format(&ip.to_string(), port, style.unwrap_or(AddressStyle::Plain));
+    crate::clipboard::write(&app, &text)?;
+    Ok(text)
+}
@@ -0,0 +1,236 @@
+// Admin Mode
+// Kicking, banning and deleting a network are locked until admin mode is
+// switched on, and it switches itself off again after a few minutes, so a
+// shared desktop left on the network view cannot kick anyone by accident.
+// Turning it on can require a PIN, stored only as a salted SHA-256 hash.
+// Repeated wrong PINs lock it for a while.
+
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Tauri event emitted with an `AdminModeStatus` when admin mode turns on or off
+pub const CHANGED_EVENT: &str = "admin://changed";
+
+const SETTINGS_FILE: &str = "admin_mode.json";
+
+const MAX_DURATION_MINS: u32 = 120;
+const MIN_PIN_LEN: usize = 4;
+
+/// Wrong PINs in a row before turning on is refused for LOCKOUT
+const MAX_PIN_FAILURES: u32 = 5;
+const LOCKOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct AdminModeSettings {
+    /// Off leaves moderation commands unlocked at all times
+    pub required: bool,
+    /// How long admin mode stays on
+    pub duration_mins: u32,
+}
+
+impl Default for AdminModeSettings {
+    fn default() -> Self {
+        Self { required: true, duration_mins: 10 }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+struct Stored {
+    settings: AdminModeSettings,
+    /// hex(sha256(salt || pin))
+    pin_hash: Option<String>,
+    pin_salt: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct AdminModeStatus {
+    /// Moderation commands are allowed right now
+    pub active: bool,
+    pub required: bool,
+    pub pin_set: bool,
+    /// Unix timestamp (seconds) admin mode turns off at
+    #[ts(type = "number | null")]
+    pub expires_at: Option<u64>,
+}
+
+/// Managed state holding the admin mode settings and the current session
+pub struct AdminModeState {
+    stored: JsonStore<Stored>,
+    /// When the current session ends
+    until: Mutex<Option<Instant>>,
+    failures: Mutex<(u32, Option<Instant>)>,
+    changed: tokio::sync::Notify,
+}
+
+fn hash_pin(salt: &str, pin: &str) -> String {
+    hex::encode(Sha256::digest(format!("{}{}", salt, pin).as_bytes()))
+}
+
+impl AdminModeState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            stored: JsonStore::open(app, SETTINGS_FILE),
+            until: Mutex::new(None),
+            failures: Mutex::new((0, None)),
+            changed: tokio::sync::Notify::new(),
+        }
+    }
+
+    fn active_until(&self) -> Option<Instant> {
+        let mut until = self.until.lock().unwrap();
+        if until.is_some_and(|at| at <= Instant::now()) {
+            *until = None;
+        }
+        *until
+    }
+
+    pub fn status(&self) -> AdminModeStatus {
+        let stored = self.stored.get();
+        let until = self.active_until();
+        AdminModeStatus {
+            active: !stored.settings.required || until.is_some(),
+            required: stored.settings.required,
+            pin_set: stored.pin_hash.is_some(),
+            expires_at: until.map(|at| unix_now() + at.saturating_duration_since(Instant::now()).as_secs()),
+        }
+    }
+
+    /// Gate for moderation and destructive commands
+    pub fn require(&self) -> Result<(), String> {
+        if self.status().active {
+            Ok(())
+        } else {
+            Err("this needs admin mode; turn it on first".to_string())
+        }
+    }
+
+    fn check_pin(&self, pin: Option<&str>) -> Result<(), String> {
+        let stored = self.stored.get();
+        let Some(expected) = stored.pin_hash else { return Ok(()) };
+        let mut failures = self.failures.lock().unwrap();
+        if let Some(locked) = failures.1.filter(|at| at.elapsed() < LOCKOUT) {
+            let wait = LOCKOUT.saturating_sub(locked.elapsed()).as_secs().max(1);
+            return Err(format!("too many wrong PINs; try again in {}s", wait));
+        }
+        if pin.is_some_and(|pin| hash_pin(&stored.pin_salt, pin.trim()) == expected) {
+            *failures = (0, None);
+            return Ok(());
+        }
+        failures.0 += 1;
+        if failures.0 >= MAX_PIN_FAILURES {
+            *failures = (0, Some(Instant::now()));
+        }
+        Err("wrong PIN".to_string())
+    }
+}
+
+/// Announce admin mode turning off by itself
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<AdminModeState>();
+        loop {
+            let until = state.active_until();
+            match until {
+                Some(at) => {
+                    tokio::select! {
+                        _ = tokio::time::sleep_until(at.into()) => {
+                            log::info!("Admin mode timed out");
+                            let _ = app.emit(CHANGED_EVENT, state.status());
+                        }
+                        _ = state.changed.notified() => {}
+                    }
+                }
+                None => state.changed.notified().await,
+            }
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_admin_mode_status(state: State<'_, AdminModeState>) -> Result<AdminModeStatus, String> {
+    Ok(state.status())
+}
+
+/// Unlock moderation commands for `minutes` (the configured duration by default)
+#[tauri::command]
+pub async fn enable_admin_mode(
+    app: AppHandle,
+    state: State<'_, AdminModeState>,
+    pin: Option<String>,
+    minutes: Option<u32>,
+) -> Result<AdminModeStatus, String> {
+    state.check_pin(pin.as_deref())?;
+    let minutes = minutes.unwrap_or(state.stored.get().settings.duration_mins).clamp(1, MAX_DURATION_MINS);
+    *state.until.lock().unwrap() = Some(Instant::now() + Duration::from_secs(u64::from(minutes) * 60));
+    state.changed.notify_one();
+    log::info!("Admin mode on for {} minutes", minutes);
+    let status = state.status();
+    let _ = app.emit(CHANGED_EVENT, &status);
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn disable_admin_mode(app: AppHandle, state: State<'_, AdminModeState>) -> Result<AdminModeStatus, String> {
+    *state.until.lock().unwrap() = None;
+    state.changed.notify_one();
+    let status = state.status();
+    let _ = app.emit(CHANGED_EVENT, &status);
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn get_admin_mode_settings(state: State<'_, AdminModeState>) -> Result<AdminModeSettings, String> {
+    Ok(state.stored.get().settings)
+}
+
+/// Changing the settings needs admin mode itself, so it cannot be switched off behind its back
+#[tauri::command]
+pub async fn set_admin_mode_settings(
+    state: State<'_, AdminModeState>,
+    settings: AdminModeSettings,
+) -> Result<AdminModeSettings, String> {
+    state.require()?;
+    if !(1..=MAX_DURATION_MINS).contains(&settings.duration_mins) {
+        return Err(format!("the duration must be between 1 and {} minutes", MAX_DURATION_MINS));
+    }
+    state.stored.update(|stored| stored.settings = settings.clone())?;
+    Ok(settings)
+}
+
+/// Set, change or (with `new_pin` omitted) remove the PIN; the current one is needed if set
+#[tauri::command]
+pub async fn set_admin_pin(
+    state: State<'_, AdminModeState>,
+    current_pin: Option<String>,
+    new_pin: Option<String>,
+) -> Result<AdminModeStatus, String> {
+    state.check_pin(current_pin.as_deref())?;
+    let new_pin = new_pin.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+    if new_pin.as_ref().is_some_and(|p| p.chars().count() < MIN_PIN_LEN) {
+        return Err(format!("the PIN must have at least {} characters", MIN_PIN_LEN));
+    }
+    state.stored.update(|stored| match new_pin {
+        Some(pin) => {
+            stored.pin_salt = hex::encode(rand::random::<[u8; 16]>());
+            stored.pin_hash = Some(hash_pin(&stored.pin_salt, &pin));
+        }
+        None => {
+            stored.pin_hash = None;
+            stored.pin_salt.clear();
+        }
+    })?;
+    Ok(state.status())
+}
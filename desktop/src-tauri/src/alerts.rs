@@ -0,0 +1,391 @@
+// Alert Router
+// Forwards critical events to email (SMTP) and/or a push service (ntfy, Gotify)
+// so they reach the user when the desktop is unattended. Each kind is rate
+// limited, plus a global hourly cap, so a flapping daemon cannot flood an inbox.
+
+use crate::commands::DaemonState;
+use crate::connection::ConnectionState;
+use crate::store::JsonStore;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "alerts.json";
+
+/// How often sustained conditions are re-evaluated
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const PUSH_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum AlertKind {
+    /// Daemon unreachable for longer than `daemon_down_minutes`
+    DaemonDown,
+    /// Tunnel disconnected for longer than `tunnel_down_minutes` while the daemon is up
+    TunnelDown,
+    /// Daemon rejected the IPC token
+    AuthFailed,
+    /// Sent by `send_test_alert`; never rate limited
+    Test,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SmtpSecurity {
+    /// Implicit TLS, usually port 465
+    Tls,
+    /// STARTTLS upgrade, usually port 587
+    StartTls,
+    /// Plain text; only for relays on a trusted network
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct EmailTarget {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub username: String,
+    pub password: String,
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PushProvider {
+    /// `url` is the topic URL, e.g. https://ntfy.sh/my-topic
+    Ntfy,
+    /// `url` is the server root; `token` is an application token
+    Gotify,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct PushTarget {
+    pub provider: PushProvider,
+    pub url: String,
+    #[serde(default)]
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct AlertSettings {
+    pub enabled: bool,
+    pub kinds: Vec<AlertKind>,
+    pub daemon_down_minutes: u32,
+    pub tunnel_down_minutes: u32,
+    /// Minimum gap between two alerts of the same kind
+    pub cooldown_minutes: u32,
+    /// Cap across all kinds
+    pub max_per_hour: u32,
+    pub email: Option<EmailTarget>,
+    pub push: Option<PushTarget>,
+}
+
+impl Default for AlertSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            kinds: vec![AlertKind::DaemonDown, AlertKind::AuthFailed],
+            daemon_down_minutes: 5,
+            tunnel_down_minutes: 10,
+            cooldown_minutes: 30,
+            max_per_hour: 6,
+            email: None,
+            push: None,
+        }
+    }
+}
+
+impl AlertSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.enabled && self.email.is_none() && self.push.is_none() {
+            return Err("configure an email or push target to enable alerts".to_string());
+        }
+        if self.daemon_down_minutes == 0 || self.tunnel_down_minutes == 0 {
+            return Err("alert thresholds must be at least one minute".to_string());
+        }
+        if self.max_per_hour == 0 {
+            return Err("max alerts per hour must be at least 1".to_string());
+        }
+        if let Some(email) = &self.email {
+            if email.host.trim().is_empty() {
+                return Err("SMTP host is required".to_string());
+            }
+            email.from.parse::<Mailbox>().map_err(|e| format!("invalid sender '{}': {}", email.from, e))?;
+            if email.to.is_empty() {
+                return Err("add at least one recipient".to_string());
+            }
+            for to in &email.to {
+                to.parse::<Mailbox>().map_err(|e| format!("invalid recipient '{}': {}", to, e))?;
+            }
+        }
+        if let Some(push) = &self.push {
+            let url = reqwest::Url::parse(&push.url).map_err(|e| format!("invalid push URL: {}", e))?;
+            if !matches!(url.scheme(), "http" | "https") {
+                return Err("push URL must use http or https".to_string());
+            }
+            if push.provider == PushProvider::Gotify && push.token.is_empty() {
+                return Err("Gotify requires an application token".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Managed state holding the alert settings and rate limiter
+pub struct AlertState {
+    settings: JsonStore<AlertSettings>,
+    /// Last send per kind, and send times within the past hour
+    sent: Mutex<(HashMap<AlertKind, Instant>, VecDeque<Instant>)>,
+    http: reqwest::Client,
+}
+
+impl AlertState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            sent: Mutex::new((HashMap::new(), VecDeque::new())),
+            http: reqwest::Client::builder().timeout(PUSH_TIMEOUT).build().unwrap_or_default(),
+        }
+    }
+
+    /// Reserve a send slot, or explain why the alert is suppressed
+    fn admit(&self, kind: AlertKind, settings: &AlertSettings) -> Result<(), String> {
+        if kind == AlertKind::Test {
+            return Ok(());
+        }
+        let mut sent = self.sent.lock().unwrap();
+        let (per_kind, window) = &mut *sent;
+        let now = Instant::now();
+        while window.front().is_some_and(|t| now.duration_since(*t) >= Duration::from_secs(3600)) {
+            window.pop_front();
+        }
+
+        let cooldown = Duration::from_secs(u64::from(settings.cooldown_minutes) * 60);
+        if per_kind.get(&kind).is_some_and(|t| now.duration_since(*t) < cooldown) {
+            return Err(format!("{:?} alert is cooling down", kind));
+        }
+        if window.len() >= settings.max_per_hour as usize {
+            return Err("hourly alert limit reached".to_string());
+        }
+        per_kind.insert(kind, now);
+        window.push_back(now);
+        Ok(())
+    }
+}
+
+async fn send_email(target: &EmailTarget, subject: &str, body: &str) -> Result<(), String> {
+    let mut message = Message::builder()
+        .from(target.from.parse::<Mailbox>().map_err(|e| e.to_string())?)
+        .subject(subject);
+    for to in &target.to {
+        message = message.to(to.parse::<Mailbox>().map_err(|e| e.to_string())?);
+    }
+    let message = message.body(body.to_string()).map_err(|e| e.to_string())?;
+
+    let builder = match target.security {
+        SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&target.host),
+        SmtpSecurity::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&target.host),
+        SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&target.host)),
+    }
+    .map_err(|e| e.to_string())?;
+    let mut builder = builder.port(target.port);
+    if !target.username.is_empty() {
+        builder = builder.credentials(Credentials::new(target.username.clone(), target.password.clone()));
+    }
+    builder.build().send(message).await.map(|_| ()).map_err(|e| e.to_string())
+}
+
+async fn send_push(http: &reqwest::Client, target: &PushTarget, title: &str, body: &str) -> Result<(), String> {
+    let request = match target.provider {
+        PushProvider::Ntfy => {
+            let request = http
+                .post(&target.url)
+                .header("Title", title)
+                .header("Priority", "urgent")
+                .header("Tags", "warning")
+                .body(body.to_string());
+            if target.token.is_empty() {
+                request
+            } else {
+                request.bearer_auth(&target.token)
+            }
+        }
+        PushProvider::Gotify => http
+            .post(format!("{}/message", target.url.trim_end_matches('/')))
+            .header("X-Gotify-Key", &target.token)
+            .json(&json!({ "title": title, "message": body, "priority": 8 })),
+    };
+    request
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Forward an alert to every configured target, subject to settings and rate limits
+pub async fn raise(app: &AppHandle, kind: AlertKind, title: &str, body: &str) -> Result<(), String> {
+    let state = app.state::<AlertState>();
+    let settings = state.settings.get();
+    if kind != AlertKind::Test && !(settings.enabled && settings.kinds.contains(&kind)) {
+        return Ok(());
+    }
+    state.admit(kind, &settings)?;
+
+    let subject = format!("[GoConnect] {}", title);
+    let (email, push) = tokio::join!(
+        async {
+            match &settings.email {
+                Some(target) => send_email(target, &subject, body).await.map_err(|e| format!("email: {}", e)),
+                None => Ok(()),
+            }
+        },
+        async {
+            match &settings.push {
+                Some(target) => send_push(&state.http, target, &subject, body).await.map_err(|e| format!("push: {}", e)),
+                None => Ok(()),
+            }
+        },
+    );
+    let errors: Vec<String> = [email, push].into_iter().filter_map(Result::err).collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+/// Fire `kind` once per episode after `condition` has held for `threshold`
+struct Sustained {
+    since: Option<Instant>,
+    fired: bool,
+}
+
+impl Sustained {
+    fn new() -> Self {
+        Self { since: None, fired: false }
+    }
+
+    fn update(&mut self, condition: bool, threshold: Duration) -> bool {
+        if !condition {
+            *self = Self::new();
+            return false;
+        }
+        let since = *self.since.get_or_insert_with(Instant::now);
+        if !self.fired && since.elapsed() >= threshold {
+            self.fired = true;
+            return true;
+        }
+        false
+    }
+}
+
+/// Watch the daemon connection and tunnel state for alert conditions
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        let mut daemon_down = Sustained::new();
+        let mut tunnel_down = Sustained::new();
+        let mut auth_failed = false;
+        let mut was_connected = false;
+        let mut ticker = tokio::time::interval(CHECK_INTERVAL);
+
+        loop {
+            ticker.tick().await;
+            let settings = app.state::<AlertState>().settings.get();
+            let state = daemon.connection.state();
+            let minutes = |m: u32| Duration::from_secs(u64::from(m) * 60);
+
+            let mut pending = Vec::new();
+            if daemon_down.update(!state.is_daemon_running(), minutes(settings.daemon_down_minutes)) {
+                pending.push((
+                    AlertKind::DaemonDown,
+                    "Daemon is down".to_string(),
+                    format!(
+                        "The GoConnect daemon has been unreachable for over {} minutes (state: {}).",
+                        settings.daemon_down_minutes,
+                        state.label()
+                    ),
+                ));
+            }
+
+            let now_auth_failed = state == ConnectionState::AuthFailed;
+            if now_auth_failed && !auth_failed {
+                pending.push((
+                    AlertKind::AuthFailed,
+                    "Daemon rejected the desktop client".to_string(),
+                    "The daemon is running but rejected the IPC token. Restarting the daemon usually fixes this.".to_string(),
+                ));
+            }
+            auth_failed = now_auth_failed;
+
+            // Only a tunnel that was up and dropped counts, not one the user never connected
+            let status = daemon.latest_status();
+            let connected = status.as_ref().is_some_and(|s| s.connected);
+            was_connected |= connected;
+            let dropped = state.is_usable() && was_connected && !connected;
+            if dropped && tunnel_down.update(true, minutes(settings.tunnel_down_minutes)) {
+                pending.push((
+                    AlertKind::TunnelDown,
+                    "VPN tunnel is down".to_string(),
+                    format!(
+                        "The tunnel has been disconnected for over {} minutes while the daemon is running.",
+                        settings.tunnel_down_minutes
+                    ),
+                ));
+            } else if !dropped {
+                tunnel_down.update(false, Duration::ZERO);
+            }
+
+            for (kind, title, body) in pending {
+                let body = format!("{}\n\nDetected at {}.", body, chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                if let Err(e) = raise(&app, kind, &title, &body).await {
+                    log::warn!("Alert '{}' not delivered: {}", title, e);
+                }
+            }
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_alert_settings(state: State<'_, AlertState>) -> Result<AlertSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_alert_settings(state: State<'_, AlertState>, settings: AlertSettings) -> Result<AlertSettings, String> {
+    settings.validate()?;
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
+
+/// Send a test alert through every configured target, bypassing rate limits
+#[tauri::command]
+pub async fn send_test_alert(app: AppHandle) -> Result<(), String> {
+    raise(
+        &app,
+        AlertKind::Test,
+        "Test alert",
+        "This is a test alert from GoConnect. Critical events will be delivered the same way.",
+    )
+    .await
+}
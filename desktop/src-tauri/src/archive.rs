@@ -0,0 +1,123 @@
+// Archived Networks
+// Networks the user leaves are kept in a local archive with their name, the
+// last invite code we saw and free-form notes, so seasonal groups can be found
+// again and re-joined in one step while that invite is still valid. Leaving
+// again after a re-join refreshes the entry.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::NetworkInfo;
+use crate::store::JsonStore;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const ARCHIVE_FILE: &str = "archived_networks.json";
+
+/// Oldest entries are dropped beyond this many
+const MAX_ENTRIES: usize = 100;
+
+const MAX_NOTES_LEN: usize = 2000;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ArchivedNetwork {
+    pub network_id: String,
+    pub name: String,
+    /// Last invite code seen for the network; `None` when we never had one
+    pub invite_code: Option<String>,
+    pub notes: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub left_at: u64,
+}
+
+/// Managed state holding the persisted archive
+pub struct ArchiveState {
+    entries: JsonStore<Vec<ArchivedNetwork>>,
+}
+
+impl ArchiveState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { entries: JsonStore::open(app, ARCHIVE_FILE) }
+    }
+}
+
+/// Archive a network that was just left, keeping the notes of an earlier entry
+pub(crate) fn record(app: &AppHandle, network_id: &str, name: &str, invite_code: &str) {
+    let result = app.state::<ArchiveState>().entries.update(|entries| {
+        let previous = entries.iter().position(|e| e.network_id == network_id).map(|i| entries.remove(i));
+        let invite_code = Some(invite_code.to_string()).filter(|c| !c.is_empty());
+        entries.push(ArchivedNetwork {
+            network_id: network_id.to_string(),
+            name: name.to_string(),
+            invite_code: invite_code.or_else(|| previous.as_ref().and_then(|p| p.invite_code.clone())),
+            notes: previous.map(|p| p.notes).unwrap_or_default(),
+            left_at: unix_now(),
+        });
+        let excess = entries.len().saturating_sub(MAX_ENTRIES);
+        entries.drain(..excess);
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to archive network {}: {}", name, e);
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Networks left on this device, most recently left first
+#[tauri::command]
+pub async fn list_archived_networks(state: State<'_, ArchiveState>) -> Result<Vec<ArchivedNetwork>, String> {
+    Ok(state.entries.get().into_iter().rev().collect())
+}
+
+#[tauri::command]
+pub async fn set_archived_network_notes(
+    state: State<'_, ArchiveState>,
+    network_id: String,
+    notes: String,
+) -> Result<ArchivedNetwork, String> {
+    if notes.chars().count() > MAX_NOTES_LEN {
+        return Err(format!("notes can be at most {} characters", MAX_NOTES_LEN));
+    }
+    state.entries.update(|entries| {
+        let entry = entries.iter_mut().find(|e| e.network_id == network_id);
+        let entry = entry.ok_or_else(|| "no archived network with that id".to_string())?;
+        entry.notes = notes;
+        Ok(entry.clone())
+    })?
+}
+
+#[tauri::command]
+pub async fn forget_archived_network(state: State<'_, ArchiveState>, network_id: String) -> Result<(), String> {
+    state.entries.update(|entries| entries.retain(|e| e.network_id != network_id))
+}
+
+/// Join again with the stored invite, or `invite_code` when given; the entry is
+/// removed once joined
+#[tauri::command]
+pub async fn rejoin_archived_network(
+    state: State<'_, ArchiveState>,
+    daemon: State<'_, DaemonState>,
+    network_id: String,
+    invite_code: Option<String>,
+) -> Result<NetworkInfo, String> {
+    let entry =
+        state.entries.get().into_iter().find(|e| e.network_id == network_id).ok_or("no archived network with that id")?;
+    let code = invite_code.or(entry.invite_code).ok_or("no invite is stored for this network; ask for a new one")?;
+    let code = crate::clipboard::normalize_invite(&code)?;
+
+    let preview = daemon_call!(daemon, "preview_invite", |client| client.preview_invite(&code))?;
+    if !preview.valid {
+        let reason = preview.invalid_reason.unwrap_or_else(|| "it is no longer valid".to_string());
+        return Err(format!("the stored invite cannot be used ({}); ask for a new one", reason));
+    }
+    if preview.network_id != entry.network_id {
+        return Err(format!("that invite is for {}, not {}", preview.network_name, entry.name));
+    }
+    let network = daemon_call!(daemon, "join_network", |client| client.join_network(&code))?;
+    log::info!("Re-joined archived network {}", entry.name);
+    state.entries.update(|entries| entries.retain(|e| e.network_id != network_id))?;
+    Ok(network)
+}
@@ -0,0 +1,174 @@
+// Attention Requests
+// Important events that arrive while the main window is hidden or in the
+// background ask the desktop for the user's attention, each at a level chosen
+// per event type. Informational requests flash the taskbar button once on
+// Windows and bounce the dock icon once on macOS; critical ones keep flashing
+// or bouncing until the app is focused. Linux sets the window's urgency hint
+// for either. Events also count towards a badge on the dock or launcher icon
+// (not supported on Windows), cleared when the window is focused again.
+
+use crate::commands::DaemonState;
+use crate::daemon::ChatMessage;
+use crate::peer_index::PeerIndexState;
+use crate::store::JsonStore;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tauri::{AppHandle, Manager, State, UserAttentionType};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "attention.json";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum AttentionLevel {
+    Off,
+    #[default]
+    Informational,
+    Critical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum AttentionEvent {
+    /// A chat message naming us with `@`
+    Mention,
+    /// Any other chat message
+    ChatMessage,
+    IncomingTransfer,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct AttentionSettings {
+    pub mention: AttentionLevel,
+    pub chat_message: AttentionLevel,
+    pub incoming_transfer: AttentionLevel,
+    /// Count missed events on the dock or launcher icon
+    pub badge: bool,
+}
+
+impl Default for AttentionSettings {
+    fn default() -> Self {
+        Self {
+            mention: AttentionLevel::Informational,
+            chat_message: AttentionLevel::Off,
+            incoming_transfer: AttentionLevel::Informational,
+            badge: true,
+        }
+    }
+}
+
+impl AttentionSettings {
+    fn level(&self, event: AttentionEvent) -> AttentionLevel {
+        match event {
+            AttentionEvent::Mention => self.mention,
+            AttentionEvent::ChatMessage => self.chat_message,
+            AttentionEvent::IncomingTransfer => self.incoming_transfer,
+        }
+    }
+}
+
+/// Managed state holding the settings and the badge count
+pub struct AttentionState {
+    settings: JsonStore<AttentionSettings>,
+    missed: AtomicU32,
+}
+
+impl AttentionState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), missed: AtomicU32::new(0) }
+    }
+}
+
+/// Ask for attention if the main window is out of sight and `event` is turned on
+pub(crate) fn request(app: &AppHandle, event: AttentionEvent) {
+    let (Some(state), Some(window)) = (app.try_state::<AttentionState>(), app.get_webview_window("main")) else {
+        return;
+    };
+    let in_sight = window.is_visible().unwrap_or(false)
+        && !window.is_minimized().unwrap_or(false)
+        && window.is_focused().unwrap_or(false);
+    if in_sight {
+        return;
+    }
+    let settings = state.settings.get();
+    let kind = match settings.level(event) {
+        AttentionLevel::Off => return,
+        AttentionLevel::Informational => UserAttentionType::Informational,
+        AttentionLevel::Critical => UserAttentionType::Critical,
+    };
+    if let Err(e) = window.request_user_attention(Some(kind)) {
+        log::debug!("Failed to request attention: {}", e);
+    }
+    if settings.badge {
+        let missed = state.missed.fetch_add(1, Ordering::Relaxed) + 1;
+        let _ = window.set_badge_count(Some(i64::from(missed)));
+    }
+}
+
+/// Request attention for an incoming chat message, as a mention when it names us
+pub(crate) async fn chat_message(app: &AppHandle, network_id: &str, message: &ChatMessage) {
+    if message.is_self {
+        return;
+    }
+    let daemon = app.state::<DaemonState>();
+    let names = app
+        .state::<PeerIndexState>()
+        .with_snapshot(&daemon, Some(network_id.to_string()), |s| {
+            s.peers.values().find(|p| p.is_self).map(|p| [p.name.clone(), p.display_name.clone()])
+        })
+        .await
+        .ok()
+        .and_then(|(_, names)| names)
+        .unwrap_or_default();
+    let content = message.content.to_lowercase();
+    let mentioned = names.iter().any(|name| !name.is_empty() && content.contains(&format!("@{}", name.to_lowercase())));
+    request(app, if mentioned { AttentionEvent::Mention } else { AttentionEvent::ChatMessage });
+}
+
+/// The user is back: stop flashing and clear the badge
+pub fn on_focus(app: &AppHandle) {
+    let Some(state) = app.try_state::<AttentionState>() else { return };
+    if state.missed.swap(0, Ordering::Relaxed) == 0 {
+        return;
+    }
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.request_user_attention(None);
+        let _ = window.set_badge_count(None);
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_attention_settings(state: State<'_, AttentionState>) -> Result<AttentionSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_attention_settings(
+    app: AppHandle,
+    state: State<'_, AttentionState>,
+    settings: AttentionSettings,
+) -> Result<AttentionSettings, String> {
+    state.settings.set(settings.clone())?;
+    if !settings.badge {
+        state.missed.store(0, Ordering::Relaxed);
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.set_badge_count(None);
+        }
+    }
+    Ok(settings)
+}
+
+/// Try a level out the way an event would use it, after `delay_secs` to allow hiding the window
+#[tauri::command]
+pub async fn test_attention(app: AppHandle, event: AttentionEvent, delay_secs: Option<u64>) -> Result<(), String> {
+    tokio::time::sleep(std::time::Duration::from_secs(delay_secs.unwrap_or(3).min(30))).await;
+    request(&app, event);
+    Ok(())
+}
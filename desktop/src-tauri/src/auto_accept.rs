@@ -0,0 +1,171 @@
+// Auto-Accept
+// Incoming transfers from trusted peers are accepted without asking, as long as
+// the file is within the size limit. Files go to the sender's save folder (see
+// `save_folders`), else the configured download folder (the system's Downloads
+// folder by default), under their own name, checked and numbered on collision
+// like any accepted file (see `save_paths`). Transfers
+// that a sync pair will take are left to sync. Everything else still raises the
+// usual incoming prompt.
+
+use crate::commands::DaemonState;
+use crate::connection::unix_now;
+use crate::daemon::{PeerInfo, TransferInfo};
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::peer_index::PeerIndexState;
+use crate::save_folders;
+use crate::save_paths::{self, AcceptOutcome};
+use crate::store::JsonStore;
+use crate::sync;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Emitted with the `TransferInfo` of each transfer accepted on the user's behalf
+pub const AUTO_ACCEPTED_EVENT: &str = "transfer://auto-accepted";
+
+const SETTINGS_FILE: &str = "auto_accept.json";
+
+const DEFAULT_MAX_SIZE: u64 = 100 * 1024 * 1024;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct TrustedPeer {
+    pub peer_id: String,
+    /// Name when the peer was trusted, for display while it is offline
+    pub name: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub added_at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct AutoAcceptSettings {
+    pub enabled: bool,
+    pub trusted_peers: Vec<TrustedPeer>,
+    /// Larger files are still prompted for; 0 for no limit
+    #[ts(type = "number")]
+    pub max_size_bytes: u64,
+    /// Where accepted files are saved; empty for the system's Downloads folder
+    pub download_dir: String,
+}
+
+impl Default for AutoAcceptSettings {
+    fn default() -> Self {
+        Self { enabled: true, trusted_peers: Vec::new(), max_size_bytes: DEFAULT_MAX_SIZE, download_dir: String::new() }
+    }
+}
+
+impl AutoAcceptSettings {
+    fn applies_to(&self, transfer: &TransferInfo) -> bool {
+        self.enabled
+            && (self.max_size_bytes == 0 || transfer.file_size <= self.max_size_bytes)
+            && self.trusted_peers.iter().any(|p| p.peer_id == transfer.peer_id)
+    }
+}
+
+/// Managed state holding the settings
+pub struct AutoAcceptState {
+    settings: JsonStore<AutoAcceptSettings>,
+}
+
+impl AutoAcceptState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE) }
+    }
+}
+
+/// The configured download folder, or the system's Downloads folder
+pub(crate) fn download_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let settings = app.state::<AutoAcceptState>().settings.get();
+    if !settings.download_dir.trim().is_empty() {
+        return Ok(PathBuf::from(settings.download_dir.trim()));
+    }
+    app.path().download_dir().map_err(|e| format!("no Downloads folder to save to: {}", e))
+}
+
+/// Accept a transfer into its sender's save folder, whoever sent it
+pub(crate) async fn accept(app: &AppHandle, transfer: &TransferInfo) -> Result<AcceptOutcome, String> {
+    // Only the last component, so a sender cannot pick the folder
+    let name = Path::new(&transfer.file_name)
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .filter(|n| !n.is_empty())
+        .ok_or("the file has no usable name")?;
+    let save_path = save_folders::folder_for(app, &transfer.peer_id).await?.join(name);
+    save_paths::accept(app, transfer, &save_path.to_string_lossy(), None).await
+}
+
+/// Accept a pending incoming transfer if its sender is trusted; false leaves it to the user
+pub(crate) async fn handle_incoming(app: &AppHandle, transfer: &TransferInfo) -> bool {
+    let Some(state) = app.try_state::<AutoAcceptState>() else { return false };
+    let settings = state.settings.get();
+    if !settings.applies_to(transfer) || sync::receives(app, &transfer.peer_id, &transfer.file_name) {
+        return false;
+    }
+    match accept(app, transfer).await {
+        Ok(AcceptOutcome::Accepted { path, .. }) => {
+            log::info!("Auto-accepted {} from {} into {}", transfer.file_name, transfer.peer_id, path);
+            let _ = app.emit(AUTO_ACCEPTED_EVENT, transfer);
+            let body = i18n::text(Text::AutoAcceptedBody).replace("{file}", &transfer.file_name);
+            notifications::notify(app, NotificationKind::Transfer, i18n::text(Text::AutoAcceptedTitle), &body);
+            true
+        }
+        Ok(AcceptOutcome::Skipped { .. }) => {
+            let body = i18n::text(Text::AutoSkippedBody).replace("{file}", &transfer.file_name);
+            notifications::notify(app, NotificationKind::Transfer, i18n::text(Text::AutoSkippedTitle), &body);
+            true
+        }
+        // The collision policy wants the user to decide, so it is prompted for as usual
+        Ok(AcceptOutcome::NeedsDecision { .. }) => false,
+        Err(e) => {
+            log::warn!("Failed to auto-accept transfer {}: {}", transfer.id, e);
+            false
+        }
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_auto_accept_settings(state: State<'_, AutoAcceptState>) -> Result<AutoAcceptSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_auto_accept_settings(
+    state: State<'_, AutoAcceptState>,
+    settings: AutoAcceptSettings,
+) -> Result<AutoAcceptSettings, String> {
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
+
+/// Add a peer of the current network to the trusted list
+#[tauri::command]
+pub async fn trust_peer(
+    daemon: State<'_, DaemonState>,
+    index: State<'_, PeerIndexState>,
+    state: State<'_, AutoAcceptState>,
+    peer_id: String,
+) -> Result<AutoAcceptSettings, String> {
+    let name = |p: &PeerInfo| if p.display_name.is_empty() { p.name.clone() } else { p.display_name.clone() };
+    let (_, name) = index.with_snapshot(&daemon, None, |s| s.peers.get(&peer_id).map(name)).await?;
+    let name = name.ok_or("peer not found")?;
+    state.settings.update(|settings| {
+        if !settings.trusted_peers.iter().any(|p| p.peer_id == peer_id) {
+            settings.trusted_peers.push(TrustedPeer { peer_id: peer_id.clone(), name, added_at: unix_now() });
+        }
+    })?;
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn untrust_peer(state: State<'_, AutoAcceptState>, peer_id: String) -> Result<AutoAcceptSettings, String> {
+    state.settings.update(|settings| settings.trusted_peers.retain(|p| p.peer_id != peer_id))?;
+    Ok(state.settings.get())
+}
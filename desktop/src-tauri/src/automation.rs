@@ -0,0 +1,240 @@
+// Automation Socket (feature "automation")
+// Tiny line-based API for Stream Deck plugins and AutoHotkey scripts.
+//
+// Transport: Unix domain socket (`goconnect/goconnect-automation.sock` in the
+// runtime dir, in a folder only the user can enter) or the named pipe
+// `\\.\pipe\goconnect-automation` on Windows.
+//
+// Protocol: one command per line, one reply per line.
+//   AUTH <token>            token from `automation.token` in the app data dir
+//   STATUS
+//   CONNECT                 brings up the tunnel for every joined network
+//   DISCONNECT
+//   MUTE [on|off|toggle]    forwarded to the UI, which owns the microphone
+//   FORMAT [text|json]      reply format for the rest of the connection
+// Replies are `OK <json>` or `ERR <message>`; after `FORMAT json` each reply is
+// one `models::Envelope` line instead.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::tokens_match;
+use crate::models::{Envelope, StatusReport};
+use serde_json::{json, Value};
+use std::path::Path;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+
+/// Tauri event asking the UI to change the microphone mute state
+pub const MUTE_EVENT: &str = "automation://mute";
+
+const TOKEN_FILE: &str = "automation.token";
+const TOKEN_BYTES: usize = 32;
+const MAX_LINE_BYTES: u64 = 4096;
+
+#[cfg(unix)]
+const SOCKET_DIR: &str = "goconnect";
+#[cfg(unix)]
+const SOCKET_NAME: &str = "goconnect-automation.sock";
+#[cfg(windows)]
+const PIPE_NAME: &str = r"\\.\pipe\goconnect-automation";
+
+/// Start the automation listener
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = serve(app).await {
+            log::error!("Automation socket stopped: {}", e);
+        }
+    });
+}
+
+/// Load the automation token, creating it on first use.
+/// Scripts get their own token so they never need the daemon's IPC token.
+async fn load_or_create_token(app: &AppHandle) -> Result<String, String> {
+    let dir = crate::store::data_dir(app);
+    let path = dir.join(TOKEN_FILE);
+
+    if let Ok(token) = tokio::fs::read_to_string(&path).await {
+        let token = token.trim().to_string();
+        if !token.is_empty() {
+            return Ok(token);
+        }
+    }
+
+    let token = hex::encode(rand::random::<[u8; TOKEN_BYTES]>());
+    tokio::fs::create_dir_all(&dir).await.map_err(|e| e.to_string())?;
+    // An empty token file is replaced rather than reused
+    let _ = tokio::fs::remove_file(&path).await;
+    write_private(&path, &token)?;
+    log::info!("Created automation token at {:?}", path);
+    Ok(token)
+}
+
+/// Create `path` readable by the user only, from the start rather than after writing.
+/// On Windows files under %APPDATA% are already private to the user profile.
+fn write_private(path: &Path, contents: &str) -> Result<(), String> {
+    use std::io::Write;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| format!("failed to create {:?}: {}", path, e))?;
+    file.write_all(contents.as_bytes()).map_err(|e| format!("failed to write {:?}: {}", path, e))
+}
+
+/// The socket goes in a folder only the user can enter, so it is never reachable
+/// by others, not even in the moment between binding and changing its mode
+#[cfg(unix)]
+fn socket_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+    let base = dirs::runtime_dir().unwrap_or_else(|| crate::store::data_dir(app));
+    let dir = base.join(SOCKET_DIR);
+    match std::fs::DirBuilder::new().mode(0o700).create(&dir) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+        Err(e) => return Err(format!("failed to create {:?}: {}", dir, e)),
+    }
+    // A folder that was already there must be ours (like the app's data folder)
+    // and not a link elsewhere
+    let meta = std::fs::symlink_metadata(&dir).map_err(|e| e.to_string())?;
+    let own_uid = std::fs::metadata(crate::store::data_dir(app)).map_err(|e| e.to_string())?.uid();
+    if !meta.is_dir() || meta.uid() != own_uid {
+        return Err(format!("{:?} is not a folder owned by this user", dir));
+    }
+    std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700)).map_err(|e| e.to_string())?;
+    Ok(dir.join(SOCKET_NAME))
+}
+
+#[cfg(unix)]
+async fn serve(app: AppHandle) -> Result<(), String> {
+    let token = load_or_create_token(&app).await?;
+    let path = socket_path(&app)?;
+
+    // A previous instance may have left its socket behind
+    let _ = std::fs::remove_file(&path);
+    let listener = tokio::net::UnixListener::bind(&path).map_err(|e| e.to_string())?;
+    log::info!("Automation socket listening on {:?}", path);
+
+    loop {
+        let (stream, _) = listener.accept().await.map_err(|e| e.to_string())?;
+        let (app, token) = (app.clone(), token.clone());
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = handle_connection(&app, &token, stream).await {
+                log::warn!("Automation client disconnected: {}", e);
+            }
+        });
+    }
+}
+
+#[cfg(windows)]
+async fn serve(app: AppHandle) -> Result<(), String> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let token = load_or_create_token(&app).await?;
+    let mut server = ServerOptions::new()
+        .first_pipe_instance(true)
+        .create(PIPE_NAME)
+        .map_err(|e| e.to_string())?;
+    log::info!("Automation pipe listening on {}", PIPE_NAME);
+
+    loop {
+        server.connect().await.map_err(|e| e.to_string())?;
+        let connected = server;
+        server = ServerOptions::new().create(PIPE_NAME).map_err(|e| e.to_string())?;
+
+        let (app, token) = (app.clone(), token.clone());
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = handle_connection(&app, &token, connected).await {
+                log::warn!("Automation client disconnected: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection<S>(app: &AppHandle, token: &str, stream: S) -> Result<(), String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+    let mut authenticated = false;
+    let mut envelopes = false;
+
+    loop {
+        let mut line = String::new();
+        let read = (&mut reader)
+            .take(MAX_LINE_BYTES)
+            .read_line(&mut line)
+            .await
+            .map_err(|e| e.to_string())?;
+        if read == 0 {
+            return Ok(());
+        }
+        if !line.ends_with('\n') && read as u64 == MAX_LINE_BYTES {
+            let _ = writer.write_all(b"ERR line too long\n").await;
+            return Err("line too long".to_string());
+        }
+
+        let mut parts = line.split_whitespace();
+        let verb = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let arg = parts.next();
+
+        let result = match (verb.as_str(), authenticated) {
+            ("", _) => continue,
+            ("format", _) => match arg.map(|a| a.to_ascii_lowercase()).as_deref() {
+                Some("json") => {
+                    envelopes = true;
+                    Ok(Value::Null)
+                }
+                Some("text") | None => {
+                    envelopes = false;
+                    Ok(Value::Null)
+                }
+                Some(other) => Err(format!("invalid format '{}', expected text|json", other)),
+            },
+            ("auth", _) => {
+                authenticated = arg.is_some_and(|t| tokens_match(t, token));
+                if authenticated {
+                    Ok(Value::Null)
+                } else {
+                    Err("invalid token".to_string())
+                }
+            }
+            (_, false) => Err("authenticate with AUTH <token> first".to_string()),
+            (verb, true) => execute(app, verb, arg).await,
+        };
+
+        let reply = match result {
+            _ if envelopes => format!("{}\n", Envelope::from_result(result).to_line()),
+            Ok(value) => format!("OK {}\n", value),
+            Err(e) => format!("ERR {}\n", e.replace('\n', " ")),
+        };
+        writer.write_all(reply.as_bytes()).await.map_err(|e| e.to_string())?;
+    }
+}
+
+async fn execute(app: &AppHandle, verb: &str, arg: Option<&str>) -> Result<Value, String> {
+    let state = app.state::<DaemonState>();
+
+    match verb {
+        "status" => Ok(json!(StatusReport::probe(&state).await)),
+        "connect" => {
+            daemon_call!(state, "connect_network", |client| client.connect_network())?;
+            Ok(Value::Null)
+        }
+        "disconnect" => {
+            daemon_call!(state, "disconnect_network", |client| client.disconnect_network())?;
+            Ok(Value::Null)
+        }
+        "mute" => {
+            let mode = arg.unwrap_or("toggle").to_ascii_lowercase();
+            if !matches!(mode.as_str(), "on" | "off" | "toggle") {
+                return Err(format!("invalid mute mode '{}', expected on|off|toggle", mode));
+            }
+            app.emit(MUTE_EVENT, &mode).map_err(|e| e.to_string())?;
+            Ok(json!(mode))
+        }
+        other => Err(format!("unknown command '{}'", other)),
+    }
+}
@@ -0,0 +1,230 @@
+// Autostart
+// Starting the app when the user logs in, with the platform's own mechanism so
+// it shows up (and can be turned off) where users expect: a value under the
+// registry Run key on Windows, a LaunchAgent on macOS and a `.desktop` file in
+// ~/.config/autostart on Linux. Everything is per user; nothing needs
+// administrator rights. The entry passes `--minimized` while the
+// `start_minimized` setting is on, so a login start goes straight to the tray;
+// the entry is rewritten whenever that setting changes (see `settings_watch`).
+// Portable runs never register themselves on the host.
+
+use crate::commands::DaemonState;
+use crate::daemon::Settings;
+use crate::lifecycle::command;
+use crate::optimistic::{self, OptimisticState};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+/// Passed by the autostart entry to start hidden in the tray
+const MINIMIZED_ARG: &str = "--minimized";
+
+/// Name of the Run key value on Windows
+const APP_NAME: &str = "GoConnect";
+const RUN_KEY: &str = r"HKCU\Software\Microsoft\Windows\CurrentVersion\Run";
+
+/// launchd label on macOS, matching the bundle identifier
+const LAUNCH_AGENT_LABEL: &str = "com.goconnect.desktop";
+
+/// File name in ~/.config/autostart on Linux
+const DESKTOP_FILE: &str = "goconnect-desktop.desktop";
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct AutostartStatus {
+    pub enabled: bool,
+    /// The entry starts the app in the tray
+    pub minimized: bool,
+}
+
+/// The app was started by the autostart entry with `start_minimized` on
+fn launched_minimized() -> bool {
+    std::env::args().skip(1).any(|arg| arg == MINIMIZED_ARG)
+}
+
+/// The program to start; an AppImage's own path rather than where it is mounted
+fn executable() -> Result<PathBuf, String> {
+    if let Some(appimage) = std::env::var_os("APPIMAGE").filter(|_| cfg!(target_os = "linux")) {
+        return Ok(PathBuf::from(appimage));
+    }
+    std::env::current_exe().map_err(|e| format!("failed to find the app's executable: {}", e))
+}
+
+fn escape_xml(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Quote an argument for a `.desktop` Exec line
+fn quote_exec(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Where the entry lives: the LaunchAgent plist or the `.desktop` file
+fn entry_file() -> Option<PathBuf> {
+    if cfg!(target_os = "macos") {
+        Some(dirs::home_dir()?.join("Library/LaunchAgents").join(format!("{}.plist", LAUNCH_AGENT_LABEL)))
+    } else {
+        Some(dirs::config_dir()?.join("autostart").join(DESKTOP_FILE))
+    }
+}
+
+/// The entry as it should read for this executable
+fn render(minimized: bool) -> Result<String, String> {
+    let exe = executable()?.to_string_lossy().into_owned();
+    let args: Vec<&str> = std::iter::once(exe.as_str()).chain(minimized.then_some(MINIMIZED_ARG)).collect();
+    if cfg!(windows) {
+        let mut line = format!("\"{}\"", exe);
+        if minimized {
+            line = format!("{} {}", line, MINIMIZED_ARG);
+        }
+        return Ok(line);
+    }
+    if cfg!(target_os = "macos") {
+        let arguments: String =
+            args.iter().map(|arg| format!("        <string>{}</string>\n", escape_xml(arg))).collect();
+        return Ok([
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+            "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+            "<plist version=\"1.0\">\n<dict>\n",
+            &format!("    <key>Label</key>\n    <string>{}</string>\n", LAUNCH_AGENT_LABEL),
+            &format!("    <key>ProgramArguments</key>\n    <array>\n{}    </array>\n", arguments),
+            "    <key>RunAtLoad</key>\n    <true/>\n",
+            "</dict>\n</plist>\n",
+        ]
+        .concat());
+    }
+    let exec = args.iter().map(|arg| quote_exec(arg)).collect::<Vec<_>>().join(" ");
+    Ok(format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec={}\nTerminal=false\nX-GNOME-Autostart-enabled=true\n",
+        APP_NAME, exec
+    ))
+}
+
+/// The entry as it is now, or `None` when the app does not start at login
+fn read() -> Option<String> {
+    if cfg!(windows) {
+        let output = command("reg", &["query", RUN_KEY, "/v", APP_NAME]).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        let value = stdout.lines().find_map(|line| line.split_once("REG_SZ").map(|(_, v)| v.trim().to_string()));
+        return value;
+    }
+    let contents = std::fs::read_to_string(entry_file()?).ok()?;
+    // A desktop environment may hide the entry instead of deleting it
+    (!contents.lines().any(|line| line.trim() == "Hidden=true")).then_some(contents)
+}
+
+fn write(minimized: bool) -> Result<(), String> {
+    let entry = render(minimized)?;
+    if cfg!(windows) {
+        let output = command("reg", &["add", RUN_KEY, "/v", APP_NAME, "/t", "REG_SZ", "/d", &entry, "/f"])
+            .output()
+            .map_err(|e| format!("failed to run reg: {}", e))?;
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("failed to add the Run key value: {}", error.trim()));
+        }
+        return Ok(());
+    }
+    let file = entry_file().ok_or("no home folder to put the autostart entry in")?;
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(&file, entry).map_err(|e| format!("failed to write {}: {}", file.display(), e))
+}
+
+fn remove() -> Result<(), String> {
+    if cfg!(windows) {
+        if read().is_some() {
+            let output = command("reg", &["delete", RUN_KEY, "/v", APP_NAME, "/f"])
+                .output()
+                .map_err(|e| format!("failed to run reg: {}", e))?;
+            if !output.status.success() {
+                return Err("failed to remove the Run key value".to_string());
+            }
+        }
+        return Ok(());
+    }
+    let Some(file) = entry_file() else { return Ok(()) };
+    match std::fs::remove_file(&file) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("failed to remove {}: {}", file.display(), e)),
+    }
+}
+
+fn status() -> AutostartStatus {
+    let entry = read();
+    AutostartStatus { enabled: entry.is_some(), minimized: entry.is_some_and(|e| e.contains(MINIMIZED_ARG)) }
+}
+
+/// Keep an existing entry in line with `start_minimized` and where the app now lives
+pub(crate) fn adopt(settings: &Settings) {
+    if crate::portable::enabled() {
+        return;
+    }
+    let Some(current) = read() else { return };
+    match render(settings.start_minimized) {
+        Ok(expected) if expected.trim() != current.trim() => {
+            if let Err(e) = write(settings.start_minimized) {
+                log::warn!("Failed to update the autostart entry: {}", e);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("Failed to update the autostart entry: {}", e),
+    }
+}
+
+/// Hide the main window when started minimized by the autostart entry
+pub fn init(app: &AppHandle) {
+    if launched_minimized() {
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.hide();
+        }
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_autostart() -> Result<AutostartStatus, String> {
+    if crate::portable::enabled() {
+        return Ok(AutostartStatus { enabled: false, minimized: false });
+    }
+    Ok(status())
+}
+
+/// Start the app at login, or stop doing so; the entry follows `start_minimized`
+#[tauri::command]
+pub async fn set_autostart(
+    state: State<'_, OptimisticState>,
+    daemon: State<'_, DaemonState>,
+    enabled: bool,
+) -> Result<AutostartStatus, String> {
+    if crate::portable::enabled() {
+        return Err("portable runs do not start at login".to_string());
+    }
+    if enabled {
+        // Without the daemon the entry starts with the window shown; the
+        // settings watch brings it in line once the daemon answers
+        let minimized = optimistic::current_settings(&state, &daemon).await.is_ok_and(|s| s.start_minimized);
+        write(minimized)?;
+    } else {
+        remove()?;
+    }
+    log::info!("Autostart at login {}", if enabled { "enabled" } else { "disabled" });
+    Ok(status())
+}
@@ -0,0 +1,199 @@
+// Peer Availability
+// Samples which peers are online while this client is connected and keeps the
+// result as per-peer online/offline spans, so the user can see which machine is
+// reliably up before hosting something on it. Time this client was not watching
+// (app closed, tunnel down) is reported as unknown rather than as downtime.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const HISTORY_FILE: &str = "peer_availability.json";
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Samples further apart than this leave an unknown gap between them
+const MAX_SAMPLE_GAP_SECS: u64 = 3 * 60;
+
+/// Spans older than this are pruned; also the longest window offered
+const RETENTION_SECS: u64 = 30 * 86_400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PeerPresence {
+    Online,
+    Offline,
+    /// Not observed
+    Unknown,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct AvailabilitySpan {
+    pub state: PeerPresence,
+    #[ts(type = "number")]
+    pub start: u64,
+    #[ts(type = "number")]
+    pub end: u64,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum AvailabilityWindow {
+    Day,
+    Week,
+    Month,
+}
+
+impl AvailabilityWindow {
+    fn secs(self) -> u64 {
+        match self {
+            AvailabilityWindow::Day => 86_400,
+            AvailabilityWindow::Week => 7 * 86_400,
+            AvailabilityWindow::Month => RETENTION_SECS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerAvailability {
+    pub peer_id: String,
+    #[ts(type = "number")]
+    pub from: u64,
+    #[ts(type = "number")]
+    pub to: u64,
+    /// Share of the observed time the peer was online; `None` if never observed
+    pub uptime_percent: Option<f64>,
+    #[ts(type = "number")]
+    pub observed_secs: u64,
+    #[ts(type = "number")]
+    pub online_secs: u64,
+    /// Times the peer went offline after being seen online
+    pub outages: u32,
+    /// Covers the whole window in order, gaps filled with `unknown`
+    pub timeline: Vec<AvailabilitySpan>,
+}
+
+/// Managed state holding the observed spans per peer id
+pub struct AvailabilityState {
+    history: JsonStore<HashMap<String, Vec<AvailabilitySpan>>>,
+}
+
+impl AvailabilityState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { history: JsonStore::open(app, HISTORY_FILE) }
+    }
+
+    /// Extend each peer's last span when the state and timing continue it,
+    /// otherwise start a new one
+    fn record(&self, samples: Vec<(String, bool)>, now: u64) {
+        let cutoff = now.saturating_sub(RETENTION_SECS);
+        let result = self.history.update(|history| {
+            for (peer_id, online) in samples {
+                let state = if online { PeerPresence::Online } else { PeerPresence::Offline };
+                let spans = history.entry(peer_id).or_default();
+                match spans.last_mut() {
+                    Some(last) if now.saturating_sub(last.end) <= MAX_SAMPLE_GAP_SECS => {
+                        if last.state == state {
+                            last.end = now;
+                        } else {
+                            // The change happened somewhere since the last sample; split the difference
+                            let at = last.end + (now - last.end) / 2;
+                            last.end = at;
+                            spans.push(AvailabilitySpan { state, start: at, end: now });
+                        }
+                    }
+                    _ => spans.push(AvailabilitySpan { state, start: now, end: now }),
+                }
+            }
+            for spans in history.values_mut() {
+                spans.retain(|s| s.end >= cutoff);
+            }
+            history.retain(|_, spans| !spans.is_empty());
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record peer availability: {}", e);
+        }
+    }
+}
+
+/// Sample peer presence while the tunnel is up
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<AvailabilityState>();
+        let daemon = app.state::<DaemonState>();
+        loop {
+            let connected = daemon.connection.state().is_usable() && daemon.latest_status().is_some_and(|s| s.connected);
+            if connected {
+                if let Ok(peers) = daemon_call!(daemon, "get_peers", |client| client.get_peers()) {
+                    state.record(peers.into_iter().map(|p| (p.id, p.connected)).collect(), unix_now());
+                }
+            }
+            tokio::time::sleep(SAMPLE_INTERVAL).await;
+        }
+    });
+}
+
+/// Clip `spans` to `[from, to)` and fill the gaps with unknown
+fn timeline(spans: &[AvailabilitySpan], from: u64, to: u64) -> Vec<AvailabilitySpan> {
+    let mut out = Vec::new();
+    let mut cursor = from;
+    for span in spans.iter().filter(|s| s.end > from && s.start < to) {
+        let (start, end) = (span.start.max(from), span.end.min(to));
+        if start > cursor {
+            out.push(AvailabilitySpan { state: PeerPresence::Unknown, start: cursor, end: start });
+        }
+        if end > start {
+            out.push(AvailabilitySpan { state: span.state, start, end });
+        }
+        cursor = cursor.max(end);
+    }
+    if cursor < to {
+        out.push(AvailabilitySpan { state: PeerPresence::Unknown, start: cursor, end: to });
+    }
+    out
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_peer_availability(
+    state: State<'_, AvailabilityState>,
+    peer_id: String,
+    window: AvailabilityWindow,
+) -> Result<PeerAvailability, String> {
+    let to = unix_now();
+    let from = to.saturating_sub(window.secs());
+    let spans = state.history.get().remove(&peer_id).unwrap_or_default();
+    let timeline = timeline(&spans, from, to);
+
+    let secs = |presence: PeerPresence| -> u64 {
+        timeline.iter().filter(|s| s.state == presence).map(|s| s.end - s.start).sum()
+    };
+    let online_secs = secs(PeerPresence::Online);
+    let observed_secs = online_secs + secs(PeerPresence::Offline);
+    let outages = timeline
+        .windows(2)
+        .filter(|pair| pair[0].state == PeerPresence::Online && pair[1].state == PeerPresence::Offline)
+        .count() as u32;
+
+    Ok(PeerAvailability {
+        peer_id,
+        from,
+        to,
+        uptime_percent: (observed_secs > 0).then(|| online_secs as f64 * 100.0 / observed_secs as f64),
+        observed_secs,
+        online_secs,
+        outages,
+        timeline,
+    })
+}
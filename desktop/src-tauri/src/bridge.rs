@@ -0,0 +1,223 @@
+// WebSocket JSON-RPC Bridge (feature "ws-bridge")
+// Exposes the Tauri command set on a loopback WebSocket so alternative frontends
+// (web dashboard, Stream Deck plugin) can drive GoConnect alongside the webview.
+//
+// Protocol: JSON-RPC 2.0 text frames. The first call on every connection must be
+//   {"jsonrpc":"2.0","id":1,"method":"auth","params":{"token":"<daemon IPC token>"}}
+// Method names and parameter names match the Tauri commands exactly.
+
+use crate::commands;
+use crate::daemon::{tokens_match, DaemonClient};
+use crate::remote_files;
+use futures_util::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use tauri::{AppHandle, Manager};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::Message;
+
+const BRIDGE_ADDR_ENV: &str = "GOCONNECT_WS_BRIDGE_ADDR";
+const DEFAULT_BRIDGE_ADDR: &str = "127.0.0.1:34102";
+
+// JSON-RPC 2.0 error codes
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SERVER_ERROR: i64 = -32000;
+const UNAUTHORIZED: i64 = -32001;
+
+#[derive(serde::Deserialize)]
+struct RpcRequest {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl From<String> for RpcError {
+    fn from(message: String) -> Self {
+        Self { code: SERVER_ERROR, message }
+    }
+}
+
+/// Start the bridge on the configured loopback address
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = serve(app).await {
+            log::error!("WebSocket bridge stopped: {}", e);
+        }
+    });
+}
+
+async fn serve(app: AppHandle) -> Result<(), String> {
+    let addr: SocketAddr = std::env::var(BRIDGE_ADDR_ENV)
+        .unwrap_or_else(|_| DEFAULT_BRIDGE_ADDR.to_string())
+        .parse()
+        .map_err(|e| format!("invalid {}: {}", BRIDGE_ADDR_ENV, e))?;
+    if !addr.ip().is_loopback() {
+        return Err(format!("refusing to expose the bridge on non-loopback address {}", addr));
+    }
+
+    let listener = TcpListener::bind(addr).await.map_err(|e| e.to_string())?;
+    log::info!("WebSocket bridge listening on ws://{}", addr);
+
+    loop {
+        let (stream, peer) = listener.accept().await.map_err(|e| e.to_string())?;
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = handle_connection(app, stream).await {
+                log::warn!("WebSocket bridge client {} disconnected: {}", peer, e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(app: AppHandle, stream: TcpStream) -> Result<(), String> {
+    let mut socket = tokio_tungstenite::accept_async(stream)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut authenticated = false;
+
+    while let Some(message) = socket.next().await {
+        let text = match message.map_err(|e| e.to_string())? {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let response = match serde_json::from_str::<RpcRequest>(&text) {
+            Ok(request) => {
+                let result = if request.method == "auth" {
+                    authenticate(&request.params).await.map(|_| {
+                        authenticated = true;
+                        Value::Bool(true)
+                    })
+                } else if !authenticated {
+                    Err(RpcError { code: UNAUTHORIZED, message: "call auth first".to_string() })
+                } else {
+                    dispatch(&app, &request.method, &request.params).await
+                };
+                encode(request.id, result)
+            }
+            Err(e) => encode(Value::Null, Err(RpcError { code: PARSE_ERROR, message: e.to_string() })),
+        };
+
+        socket
+            .send(Message::Text(response.to_string()))
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Clients authenticate with the same IPC token the daemon issues to local tools
+async fn authenticate(params: &Value) -> Result<(), RpcError> {
+    let provided: String = arg(params, "token")?;
+    let expected = DaemonClient::load_ipc_token().await.map_err(|e| e.to_string())?;
+    if !tokens_match(&provided, &expected) {
+        return Err(RpcError { code: UNAUTHORIZED, message: "invalid token".to_string() });
+    }
+    Ok(())
+}
+
+fn encode(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => json!({ "jsonrpc": "2.0", "id": id, "error": { "code": e.code, "message": e.message } }),
+    }
+}
+
+/// Extract a named parameter; missing parameters deserialize from null so `Option` args work
+fn arg<T: DeserializeOwned>(params: &Value, name: &str) -> Result<T, RpcError> {
+    let value = params.get(name).cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value).map_err(|e| RpcError {
+        code: INVALID_PARAMS,
+        message: format!("parameter '{}': {}", name, e),
+    })
+}
+
+fn reply<T: serde::Serialize>(result: Result<T, String>) -> Result<Value, RpcError> {
+    let value = result?;
+    serde_json::to_value(value).map_err(|e| RpcError::from(e.to_string()))
+}
+
+/// One argument of a routed command: the app handle, a managed state, or a named parameter
+macro_rules! route_arg {
+    ($app:expr, $params:expr, app) => {
+        $app.clone()
+    };
+    ($app:expr, $params:expr, state) => {
+        $app.state()
+    };
+    ($app:expr, $params:expr, $name:literal) => {
+        arg($params, $name)?
+    };
+}
+
+/// Route a method to its Tauri command: `"name" => module::command(arg, ...)`, where each
+/// argument is `app`, `state` (any managed state) or the name of a parameter
+macro_rules! routes {
+    ($method:expr, $params:expr, $app:expr, { $($name:literal => $($command:ident)::+($($arg:tt),*)),* $(,)? }) => {
+        match $method {
+            $( $name => reply($($command)::+($(route_arg!($app, $params, $arg)),*).await), )*
+            other => Err(RpcError { code: METHOD_NOT_FOUND, message: format!("unknown method '{}'", other) }),
+        }
+    };
+}
+
+async fn dispatch(app: &AppHandle, method: &str, params: &Value) -> Result<Value, RpcError> {
+    routes!(method, params, app, {
+        // Daemon
+        "daemon_get_status" => commands::daemon_get_status(state),
+        "daemon_get_network_statuses" => commands::daemon_get_network_statuses(state),
+        "daemon_get_version" => commands::daemon_get_version(state),
+        "daemon_is_running" => commands::daemon_is_running(state),
+        "daemon_get_connection_state" => commands::daemon_get_connection_state(state),
+        "daemon_get_rpc_metrics" => commands::daemon_get_rpc_metrics(state),
+        // Networks
+        "daemon_connect" => commands::daemon_connect(state),
+        "daemon_disconnect" => commands::daemon_disconnect(state),
+        "daemon_list_networks" => commands::daemon_list_networks(state, state),
+        "daemon_create_network" => commands::daemon_create_network(state, "name"),
+        "daemon_preview_invite" => commands::daemon_preview_invite(state, "invite_code"),
+        "daemon_join_network" => commands::daemon_join_network(state, state, "invite_code", "options"),
+        "daemon_leave_network" => commands::daemon_leave_network(app, "network_id", "options"),
+        "daemon_generate_invite" => commands::daemon_generate_invite(state, "network_id"),
+        "daemon_update_network" => commands::daemon_update_network(state, "network_id", "name"),
+        "daemon_delete_network" => commands::daemon_delete_network(state, state, "network_id"),
+        // Peers
+        "daemon_get_peers" => commands::daemon_get_peers(state),
+        "daemon_kick_peer" => commands::daemon_kick_peer(state, state, state, "network_id", "peer_id"),
+        "daemon_ban_peer" => commands::daemon_ban_peer(state, state, state, "network_id", "peer_id", "reason"),
+        "daemon_unban_peer" => commands::daemon_unban_peer(state, state, state, "network_id", "peer_id"),
+        // Settings
+        "daemon_get_settings" => commands::daemon_get_settings(state),
+        "daemon_update_settings" => commands::daemon_update_settings(state, "settings"),
+        "daemon_reset_settings" => commands::daemon_reset_settings(state),
+        // Chat
+        "daemon_get_messages" => commands::daemon_get_messages(state, "network_id", "limit", "before"),
+        "daemon_send_message" => commands::daemon_send_message(state, "network_id", "content"),
+        // Transfers
+        "daemon_list_transfers" => commands::daemon_list_transfers(state, state, state, state, "status", "peer_id"),
+        "daemon_get_transfer_stats" => commands::daemon_get_transfer_stats(state),
+        "daemon_send_file" => commands::daemon_send_file(app, "peer_id", "file_path"),
+        "daemon_accept_transfer" =>
+            commands::daemon_accept_transfer(app, state, "transfer_id", "save_path", "collision"),
+        "daemon_cancel_transfer" => commands::daemon_cancel_transfer(state, "transfer_id"),
+        "daemon_reject_transfer" => commands::daemon_reject_transfer(state, "transfer_id"),
+        "daemon_set_transfer_rate_limit" =>
+            commands::daemon_set_transfer_rate_limit(state, "transfer_id", "max_speed_kbps"),
+        "daemon_set_transfer_rate_limits" =>
+            commands::daemon_set_transfer_rate_limits(state, state, "upload_kbps", "download_kbps"),
+        // Files on the daemon's host
+        "list_remote_directory" => remote_files::list_remote_directory(state, "path", "include_hidden"),
+        "stat_remote_path" => remote_files::stat_remote_path(state, "path"),
+    })
+}
@@ -0,0 +1,165 @@
+// Fault Injection (Chaos Mode)
+// Debug-only layer that perturbs daemon RPCs to exercise reconnection, caching and retry paths.
+//
+// Enable at startup with an env var, e.g.
+//   GOCONNECT_CHAOS="delay=800,drop=0.1,unavailable=0.05,burst=4"
+// or at runtime through the hidden `debug_set_chaos` command.
+
+use rand::Rng;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tonic::Status;
+use ts_rs::TS;
+
+const CHAOS_ENV: &str = "GOCONNECT_CHAOS";
+
+/// Fault injection knobs. All probabilities are in the range 0.0..=1.0.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ChaosConfig {
+    pub enabled: bool,
+    /// Upper bound of the random delay added before each call
+    #[ts(type = "number")]
+    pub max_delay_ms: u64,
+    /// Probability that a completed call's response is discarded
+    pub drop_rate: f64,
+    /// Probability that a call starts an UNAVAILABLE burst
+    pub unavailable_rate: f64,
+    /// Number of consecutive calls that fail once a burst starts
+    pub burst_length: u32,
+}
+
+impl ChaosConfig {
+    /// Parse the `key=value,key=value` format used by the env var.
+    /// Unknown keys and malformed values are reported rather than ignored.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut config = ChaosConfig {
+            enabled: true,
+            burst_length: 1,
+            ..Default::default()
+        };
+
+        for pair in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected key=value, got '{}'", pair))?;
+            let invalid = |_| format!("invalid value for '{}': '{}'", key, value);
+
+            match key {
+                "delay" => config.max_delay_ms = value.parse().map_err(invalid)?,
+                "drop" => config.drop_rate = parse_rate(key, value)?,
+                "unavailable" => config.unavailable_rate = parse_rate(key, value)?,
+                "burst" => config.burst_length = value.parse().map_err(invalid)?,
+                _ => return Err(format!("unknown chaos option '{}'", key)),
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        check_rate("drop", self.drop_rate)?;
+        check_rate("unavailable", self.unavailable_rate)?;
+        Ok(())
+    }
+}
+
+fn parse_rate(key: &str, value: &str) -> Result<f64, String> {
+    let rate: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid value for '{}': '{}'", key, value))?;
+    check_rate(key, rate)
+}
+
+fn check_rate(key: &str, rate: f64) -> Result<f64, String> {
+    if !(0.0..=1.0).contains(&rate) {
+        return Err(format!("'{}' must be between 0.0 and 1.0, got {}", key, rate));
+    }
+    Ok(rate)
+}
+
+struct ChaosState {
+    config: ChaosConfig,
+    burst_remaining: u32,
+}
+
+/// What to do with a single call, decided up front so the lock is never held across an await
+struct FaultPlan {
+    delay: Option<Duration>,
+    unavailable: bool,
+    drop_response: bool,
+}
+
+fn state() -> &'static Mutex<ChaosState> {
+    static STATE: OnceLock<Mutex<ChaosState>> = OnceLock::new();
+    STATE.get_or_init(|| {
+        let config = match std::env::var(CHAOS_ENV) {
+            Ok(spec) => ChaosConfig::parse(&spec).unwrap_or_else(|e| {
+                log::warn!("Ignoring {}: {}", CHAOS_ENV, e);
+                ChaosConfig::default()
+            }),
+            Err(_) => ChaosConfig::default(),
+        };
+        Mutex::new(ChaosState { config, burst_remaining: 0 })
+    })
+}
+
+/// Current fault injection configuration
+pub fn config() -> ChaosConfig {
+    state().lock().unwrap().config.clone()
+}
+
+/// Replace the fault injection configuration, ending any burst in progress
+pub fn set_config(config: ChaosConfig) -> Result<(), String> {
+    config.validate()?;
+    let mut guard = state().lock().unwrap();
+    guard.config = config;
+    guard.burst_remaining = 0;
+    Ok(())
+}
+
+fn next_plan() -> FaultPlan {
+    let mut guard = state().lock().unwrap();
+    if !guard.config.enabled {
+        return FaultPlan { delay: None, unavailable: false, drop_response: false };
+    }
+
+    let mut rng = rand::thread_rng();
+    let config = guard.config.clone();
+
+    let delay = (config.max_delay_ms > 0)
+        .then(|| Duration::from_millis(rng.gen_range(0..=config.max_delay_ms)));
+
+    if guard.burst_remaining == 0 && rng.gen_bool(config.unavailable_rate) {
+        guard.burst_remaining = config.burst_length.max(1);
+    }
+    let unavailable = guard.burst_remaining > 0;
+    if unavailable {
+        guard.burst_remaining -= 1;
+    }
+
+    FaultPlan {
+        delay,
+        unavailable,
+        drop_response: !unavailable && rng.gen_bool(config.drop_rate),
+    }
+}
+
+/// Run a daemon call under the active fault plan
+pub async fn intercept<T>(call: impl Future<Output = Result<T, Status>>) -> Result<T, Status> {
+    let plan = next_plan();
+
+    if let Some(delay) = plan.delay {
+        tokio::time::sleep(delay).await;
+    }
+    if plan.unavailable {
+        return Err(Status::unavailable("chaos: injected daemon outage"));
+    }
+
+    let result = call.await;
+    if plan.drop_response {
+        return Err(Status::deadline_exceeded("chaos: response dropped"));
+    }
+    result
+}
@@ -0,0 +1,371 @@
+// Conversation Export
+// Writes the chat history of a network, or everything one peer said across
+// the networks we share, to a folder the user picks: a self-contained HTML
+// page or a JSON file, plus copies of the files exchanged with the people in
+// the conversation when asked to. The daemon hands out history a page at a
+// time; progress is reported after every page and every copied file so long
+// histories do not look stuck.
+
+use crate::activity::ActivityState;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::ChatMessage;
+use chrono::{Local, TimeZone};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
+use ts_rs::TS;
+
+/// Tauri event emitted with an `ExportProgress` while an export runs
+pub const PROGRESS_EVENT: &str = "export://progress";
+
+const PAGE_SIZE: i32 = 200;
+
+/// Exports stop paging beyond this many messages per network
+const MAX_MESSAGES: usize = 100_000;
+
+const ATTACHMENTS_DIR: &str = "attachments";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ExportFormat {
+    Html,
+    Json,
+}
+
+/// Unix timestamps (seconds); open ends when omitted
+#[derive(Debug, Clone, Default, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct ExportRange {
+    #[ts(type = "number | null")]
+    pub from: Option<u64>,
+    #[ts(type = "number | null")]
+    pub to: Option<u64>,
+}
+
+impl ExportRange {
+    fn contains(&self, at: u64) -> bool {
+        self.from.is_none_or(|from| at >= from) && self.to.is_none_or(|to| at < to)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ExportStage {
+    Messages,
+    Attachments,
+    Writing,
+    Done,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ExportProgress {
+    pub export_id: String,
+    pub stage: ExportStage,
+    pub done: u32,
+    /// `None` while not known yet (messages are counted as they arrive)
+    pub total: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ExportResult {
+    pub export_id: String,
+    /// Folder created inside the chosen one
+    pub folder: String,
+    /// The HTML or JSON file
+    pub file: String,
+    pub messages: u32,
+    pub attachments: u32,
+    /// Files exchanged in the range that no longer exist where they were
+    pub missing_attachments: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct ExportedMessage {
+    network: String,
+    sender_id: String,
+    sender: String,
+    is_self: bool,
+    /// Unix timestamp (seconds)
+    sent_at: u64,
+    content: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct ExportedAttachment {
+    file_name: String,
+    /// Relative to the export folder
+    path: String,
+    peer: String,
+    direction: String,
+    finished_at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct Archive {
+    title: String,
+    exported_at: u64,
+    messages: Vec<ExportedMessage>,
+    attachments: Vec<ExportedAttachment>,
+}
+
+fn progress(app: &AppHandle, export_id: &str, stage: ExportStage, done: usize, total: Option<usize>) {
+    let progress = ExportProgress {
+        export_id: export_id.to_string(),
+        stage,
+        done: done as u32,
+        total: total.map(|t| t as u32),
+    };
+    let _ = app.emit(PROGRESS_EVENT, progress);
+}
+
+/// All messages of `network_id` inside `range`, oldest first
+async fn fetch_history(
+    app: &AppHandle,
+    export_id: &str,
+    network_id: &str,
+    range: &ExportRange,
+    fetched: &mut usize,
+) -> Result<Vec<ChatMessage>, String> {
+    let daemon = app.state::<DaemonState>();
+    let mut messages: Vec<ChatMessage> = Vec::new();
+    let mut before: Option<String> = None;
+    loop {
+        let page = daemon_call!(daemon, "get_messages", |client| {
+            client.get_messages(network_id, PAGE_SIZE, before.as_deref())
+        })?;
+        let full = page.len() as i32 == PAGE_SIZE;
+        let oldest = page.iter().min_by_key(|m| sent_at(m)).cloned();
+        *fetched += page.len();
+        messages.extend(page.into_iter().filter(|m| range.contains(sent_at(m))));
+        progress(app, export_id, ExportStage::Messages, *fetched, None);
+
+        let Some(oldest) = oldest else { break };
+        let past_range = range.from.is_some_and(|from| sent_at(&oldest) < from);
+        if !full || past_range || messages.len() >= MAX_MESSAGES || before.as_ref() == Some(&oldest.id) {
+            break;
+        }
+        before = Some(oldest.id);
+    }
+    messages.sort_by_key(sent_at);
+    Ok(messages)
+}
+
+fn sent_at(message: &ChatMessage) -> u64 {
+    message.timestamp.parse().unwrap_or(0)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn format_time(at: u64) -> String {
+    Local.timestamp_opt(at as i64, 0).single().map(|t| t.format("%Y-%m-%d %H:%M").to_string()).unwrap_or_default()
+}
+
+fn render_html(archive: &Archive) -> String {
+    // Name the network on each message only when there is more than one
+    let networks: HashSet<&str> = archive.messages.iter().map(|m| m.network.as_str()).collect();
+    let messages: String = archive
+        .messages
+        .iter()
+        .map(|m| {
+            format!(
+                r#"<div class="msg{}"><span class="meta">{} · {}{}</span><p>{}</p></div>"#,
+                if m.is_self { " self" } else { "" },
+                escape(&m.sender),
+                format_time(m.sent_at),
+                if networks.len() > 1 { format!(" · {}", escape(&m.network)) } else { String::new() },
+                escape(&m.content).replace('\n', "<br>")
+            )
+        })
+        .collect();
+    let attachments: String = archive
+        .attachments
+        .iter()
+        .map(|a| {
+            format!(
+                r#"<li><a href="{}">{}</a> <span class="meta">{} {} · {}</span></li>"#,
+                escape(&a.path),
+                escape(&a.file_name),
+                if a.direction == "upload" { "sent to" } else { "from" },
+                escape(&a.peer),
+                format_time(a.finished_at)
+            )
+        })
+        .collect();
+    let attachments =
+        if attachments.is_empty() { String::new() } else { format!("<h2>Attachments</h2><ul>{}</ul>", attachments) };
+    format!(
+        r#"<!DOCTYPE html><html><head><meta charset="utf-8"><title>{title}</title>
+<style>body{{font-family:system-ui,sans-serif;max-width:48rem;margin:2rem auto;padding:0 1rem;color:#1f2937}}.msg{{margin:.6rem 0;padding:.4rem .7rem;border-radius:.5rem;background:#f3f4f6}}.msg.self{{background:#dbeafe}}.msg p{{margin:.2rem 0 0}}.meta{{color:#6b7280;font-size:.85rem}}</style>
+</head><body><h1>{title}</h1><p class="meta">Exported {exported} · {count} messages</p>{messages}{attachments}</body></html>"#,
+        title = escape(&archive.title),
+        exported = format_time(archive.exported_at),
+        count = archive.messages.len(),
+    )
+}
+
+/// A file name not used yet in `taken`
+fn unique_name(taken: &mut HashSet<String>, name: &str) -> String {
+    let path = Path::new(name);
+    let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_else(|| "file".to_string());
+    let ext = path.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+    let mut candidate = format!("{}{}", stem, ext);
+    let mut n = 1;
+    while !taken.insert(candidate.to_lowercase()) {
+        n += 1;
+        candidate = format!("{} ({}){}", stem, n, ext);
+    }
+    candidate
+}
+
+fn slug(text: &str) -> String {
+    let slug: String = text.chars().map(|c| if c.is_alphanumeric() { c } else { '-' }).collect();
+    let slug = slug.trim_matches('-').to_lowercase();
+    if slug.is_empty() { "chat".to_string() } else { slug.chars().take(40).collect() }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Export the chat of `network_id`, or what `peer_id` said in every network we
+/// share (both: only that peer in that network), into a new folder inside `folder`
+#[tauri::command]
+pub async fn export_conversation(
+    app: AppHandle,
+    network_id: Option<String>,
+    peer_id: Option<String>,
+    range: Option<ExportRange>,
+    format: ExportFormat,
+    include_attachments: bool,
+    folder: String,
+) -> Result<ExportResult, String> {
+    if network_id.is_none() && peer_id.is_none() {
+        return Err("pick a network or a peer to export".to_string());
+    }
+    let root = PathBuf::from(&folder);
+    if !root.is_dir() {
+        return Err(format!("{} is not a folder", folder));
+    }
+    let range = range.unwrap_or_default();
+    let export_id = hex::encode(rand::random::<[u8; 8]>());
+    let daemon = app.state::<DaemonState>();
+
+    let networks = daemon_call!(daemon, "list_networks", |client| client.list_networks())?;
+    let networks: Vec<_> = match &network_id {
+        Some(id) => vec![networks.into_iter().find(|n| n.id == *id).ok_or("you are not a member of that network")?],
+        None => networks,
+    };
+
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut self_ids: HashSet<String> = HashSet::new();
+    let mut participants: HashSet<String> = HashSet::new();
+    let mut messages = Vec::new();
+    let mut fetched = 0;
+    let mut title = None;
+    for network in &networks {
+        let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(&network.id))?;
+        if peer_id.as_ref().is_some_and(|id| !peers.iter().any(|p| p.id == *id)) {
+            continue;
+        }
+        for peer in &peers {
+            let name = if peer.display_name.is_empty() { &peer.name } else { &peer.display_name };
+            names.insert(peer.id.clone(), name.clone());
+            if peer.is_self {
+                self_ids.insert(peer.id.clone());
+            } else if peer_id.is_none() {
+                participants.insert(peer.id.clone());
+            }
+        }
+        let history = fetch_history(&app, &export_id, &network.id, &range, &mut fetched).await?;
+        for message in history {
+            if peer_id.as_ref().is_some_and(|id| message.peer_id != *id) {
+                continue;
+            }
+            let is_self = message.is_self || self_ids.contains(&message.peer_id);
+            messages.push(ExportedMessage {
+                network: network.name.clone(),
+                sender: names.get(&message.peer_id).cloned().unwrap_or_else(|| message.peer_id.clone()),
+                sender_id: message.peer_id.clone(),
+                is_self,
+                sent_at: sent_at(&message),
+                content: message.content,
+            });
+        }
+        title.get_or_insert_with(|| network.name.clone());
+    }
+    if let Some(id) = &peer_id {
+        participants.insert(id.clone());
+        title = Some(names.get(id).cloned().ok_or("you share no network with that peer")?);
+    }
+    messages.sort_by_key(|m| m.sent_at);
+    let title = title.unwrap_or_else(|| "Conversation".to_string());
+
+    let stamp = Local::now().format("%Y%m%d-%H%M%S");
+    let target = root.join(format!("goconnect-{}-{}", slug(&title), stamp));
+    std::fs::create_dir_all(&target).map_err(|e| format!("could not create {}: {}", target.display(), e))?;
+
+    let mut attachments = Vec::new();
+    let mut missing = 0;
+    if include_attachments {
+        let log = app.state::<ActivityState>().snapshot();
+        let exchanged: Vec<_> = log
+            .transfers
+            .into_iter()
+            .filter(|t| t.status == "completed" && participants.contains(&t.peer_id) && range.contains(t.finished_at))
+            .collect();
+        let dir = target.join(ATTACHMENTS_DIR);
+        let mut taken = HashSet::new();
+        for (i, transfer) in exchanged.iter().enumerate() {
+            let source = transfer.path.as_deref().map(Path::new).filter(|p| p.is_file());
+            let Some(source) = source else {
+                missing += 1;
+                continue;
+            };
+            std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+            let name = unique_name(&mut taken, &transfer.file_name);
+            let copy = tokio::fs::copy(source, dir.join(&name)).await;
+            match copy {
+                Ok(_) => attachments.push(ExportedAttachment {
+                    file_name: transfer.file_name.clone(),
+                    path: format!("{}/{}", ATTACHMENTS_DIR, name),
+                    peer: transfer.peer_name.clone(),
+                    direction: transfer.direction.clone(),
+                    finished_at: transfer.finished_at,
+                }),
+                Err(e) => {
+                    log::warn!("Skipping attachment {:?} in export: {}", source, e);
+                    missing += 1;
+                }
+            }
+            progress(&app, &export_id, ExportStage::Attachments, i + 1, Some(exchanged.len()));
+        }
+    }
+
+    progress(&app, &export_id, ExportStage::Writing, messages.len(), Some(messages.len()));
+    let archive = Archive { title, exported_at: crate::connection::unix_now(), messages, attachments };
+    let (file, contents) = match format {
+        ExportFormat::Html => (target.join("conversation.html"), render_html(&archive)),
+        ExportFormat::Json => {
+            (target.join("conversation.json"), serde_json::to_string_pretty(&archive).map_err(|e| e.to_string())?)
+        }
+    };
+    std::fs::write(&file, contents).map_err(|e| format!("could not write {}: {}", file.display(), e))?;
+    progress(&app, &export_id, ExportStage::Done, archive.messages.len(), Some(archive.messages.len()));
+    log::info!("Exported {} messages to {:?}", archive.messages.len(), target);
+
+    Ok(ExportResult {
+        export_id,
+        folder: target.to_string_lossy().into_owned(),
+        file: file.to_string_lossy().into_owned(),
+        messages: archive.messages.len() as u32,
+        attachments: archive.attachments.len() as u32,
+        missing_attachments: missing,
+    })
+}
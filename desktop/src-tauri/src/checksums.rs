@@ -0,0 +1,163 @@
+// Transfer Checksums
+// Completed downloads are hashed with SHA-256 on a blocking worker thread and
+// compared with the hash the daemon reports for the sender's file. Results are
+// kept so `TransferInfo.verified` can be filled in on later listings; a
+// mismatch raises a notification since the file on disk is not what was sent.
+// Daemons that report no hash leave downloads unverified.
+
+use crate::activity::ActivityState;
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::TransferInfo;
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::part_files;
+use crate::store::JsonStore;
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Emitted with each `TransferVerification`
+pub const VERIFIED_EVENT: &str = "transfer://verified";
+
+const RESULTS_FILE: &str = "transfer_checksums.json";
+
+/// Oldest results are dropped beyond this many
+const MAX_RESULTS: usize = 1000;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct TransferVerification {
+    pub transfer_id: String,
+    pub path: String,
+    /// Hash reported by the daemon; `None` when it reports none
+    pub expected: Option<String>,
+    pub actual: String,
+    pub verified: bool,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub checked_at: u64,
+}
+
+/// Managed state holding verification results
+pub struct ChecksumState {
+    results: JsonStore<Vec<TransferVerification>>,
+}
+
+impl ChecksumState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { results: JsonStore::open(app, RESULTS_FILE) }
+    }
+
+    /// Set `verified` on transfers whose download matched its hash
+    pub(crate) fn annotate(&self, transfers: &mut [TransferInfo]) {
+        let results = self.results.get();
+        for transfer in transfers {
+            transfer.verified = results.iter().any(|r| r.transfer_id == transfer.id && r.verified);
+        }
+    }
+
+    fn checked(&self, transfer_id: &str) -> bool {
+        self.results.get().iter().any(|r| r.transfer_id == transfer_id)
+    }
+
+    fn store(&self, result: &TransferVerification) -> Result<(), String> {
+        self.results.update(|results| {
+            results.retain(|r| r.transfer_id != result.transfer_id);
+            results.push(result.clone());
+            let excess = results.len().saturating_sub(MAX_RESULTS);
+            results.drain(..excess);
+        })
+    }
+}
+
+pub(crate) fn sha256_file(path: &str) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1024 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Hash a finished download and compare it with what the daemon reported
+async fn verify(app: &AppHandle, transfer: &TransferInfo) -> Result<TransferVerification, String> {
+    if transfer.direction != "download" || transfer.status != "completed" {
+        return Err("only completed downloads can be verified".to_string());
+    }
+    let path = app
+        .state::<ActivityState>()
+        .path_of(&transfer.id)
+        .ok_or("the downloaded file's location is not known")?;
+    verify_file(app, transfer, &path, path.clone()).await
+}
+
+/// Hash `hashed` as the download of `transfer` and record the result under `path`,
+/// for a download still in its temporary file
+pub(crate) async fn verify_file(
+    app: &AppHandle,
+    transfer: &TransferInfo,
+    hashed: &str,
+    path: String,
+) -> Result<TransferVerification, String> {
+    let file = hashed.to_string();
+    let actual = tokio::task::spawn_blocking(move || sha256_file(&file))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("failed to read {}: {}", hashed, e))?;
+
+    let result = TransferVerification {
+        transfer_id: transfer.id.clone(),
+        path,
+        verified: transfer.sha256.as_deref() == Some(actual.as_str()),
+        expected: transfer.sha256.clone(),
+        actual,
+        checked_at: unix_now(),
+    };
+    app.state::<ChecksumState>().store(&result)?;
+    let _ = app.emit(VERIFIED_EVENT, &result);
+    if result.expected.is_some() && !result.verified {
+        let body = i18n::text(Text::ChecksumMismatchBody).replace("{file}", &transfer.file_name);
+        notifications::notify(app, NotificationKind::Transfer, i18n::text(Text::ChecksumMismatchTitle), &body);
+    }
+    Ok(result)
+}
+
+/// Verify a download in the background once it completes, unless it was already checked
+pub(crate) fn on_transfer_update(app: &AppHandle, transfer: &TransferInfo) {
+    if transfer.direction != "download" || transfer.status != "completed" || transfer.sha256.is_none() {
+        return;
+    }
+    // Downloads still in a `.part` file are verified before they are put in place
+    if app.state::<ChecksumState>().checked(&transfer.id) || part_files::is_partial(app, &transfer.id) {
+        return;
+    }
+    let (app, transfer) = (app.clone(), transfer.clone());
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = verify(&app, &transfer).await {
+            log::warn!("Failed to verify transfer {}: {}", transfer.id, e);
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Hash a completed download and compare it with the daemon's hash
+#[tauri::command]
+pub async fn daemon_verify_transfer(
+    app: AppHandle,
+    state: State<'_, DaemonState>,
+    transfer_id: String,
+) -> Result<TransferVerification, String> {
+    let transfers = daemon_call!(state, "list_transfers", |client| client.list_transfers(None, None))?;
+    let transfer = transfers.into_iter().find(|t| t.id == transfer_id).ok_or("transfer not found")?;
+    verify(&app, &transfer).await
+}
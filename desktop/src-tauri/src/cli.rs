@@ -0,0 +1,192 @@
+// Command Line
+// Headless actions for scripts, handled before any window or tray exists:
+//
+//   goconnect-desktop --join <invite>
+//   goconnect-desktop --send <file> --to <peer id or name>
+//   goconnect-desktop --status [--json]
+//
+// Each is a one-shot call to the daemon; a running desktop instance picks the
+// change up from the daemon like any other. Results go to stdout, errors to
+// stderr, and the process exits 0 on success, 1 on failure and 2 on bad usage.
+// With `--json` the outcome is printed as a `models::Envelope` instead.
+// Arguments that are not actions (such as `--portable`) start the app normally.
+
+use crate::daemon::{DaemonClient, DaemonError};
+use crate::endpoint::EndpointSettings;
+use crate::models::{Envelope, JoinReport, SendReport, StatusReport};
+use crate::remote_files;
+
+const USAGE: &str = "\
+Usage:
+  goconnect-desktop --join <invite>
+  goconnect-desktop --send <file> --to <peer>
+  goconnect-desktop --status [--json]
+Add --json to any action for machine-readable output, and --endpoint <url>
+to reach a daemon that is not on the default port.";
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    Join(String),
+    Send { file: String, peer: String },
+    Status,
+    Help,
+}
+
+struct Invocation {
+    action: Action,
+    json: bool,
+    /// Daemon endpoint instead of the default or `GOCONNECT_DAEMON_ENDPOINT`
+    endpoint: Option<String>,
+}
+
+/// `Ok(None)` when the arguments hold no headless action
+fn parse(args: &[String]) -> Result<Option<Invocation>, String> {
+    let mut action = None;
+    let (mut file, mut peer, mut endpoint, mut json) = (None, None, None, false);
+    let mut args = args.iter();
+
+    let value = |args: &mut std::slice::Iter<String>, flag: &str| {
+        args.next()
+            .filter(|v| !v.starts_with("--"))
+            .cloned()
+            .ok_or_else(|| format!("{} needs a value", flag))
+    };
+    while let Some(arg) = args.next() {
+        let next = match arg.as_str() {
+            "--join" => Action::Join(value(&mut args, "--join")?),
+            "--send" => {
+                file = Some(value(&mut args, "--send")?);
+                continue;
+            }
+            "--to" => {
+                peer = Some(value(&mut args, "--to")?);
+                continue;
+            }
+            "--endpoint" => {
+                endpoint = Some(crate::endpoint::normalize(&value(&mut args, "--endpoint")?)?);
+                continue;
+            }
+            "--status" => Action::Status,
+            "--json" => {
+                json = true;
+                continue;
+            }
+            "--help" | "-h" => Action::Help,
+            _ => continue,
+        };
+        if action.replace(next).is_some() {
+            return Err("only one action can be given at a time".to_string());
+        }
+    }
+
+    let action = match (action, file, peer) {
+        (None, Some(file), Some(peer)) => Action::Send { file, peer },
+        (None, Some(_), None) => return Err("--send needs --to <peer>".to_string()),
+        (_, None, Some(_)) => return Err("--to is only used with --send".to_string()),
+        (Some(_), Some(_), _) => return Err("only one action can be given at a time".to_string()),
+        (Some(action), None, None) => action,
+        (None, None, None) => return Ok(None),
+    };
+    Ok(Some(Invocation { action, json, endpoint }))
+}
+
+fn daemon_error(e: DaemonError) -> String {
+    e.to_string()
+}
+
+/// What an action produced; serialized as the envelope's `data`
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+enum Report {
+    Join(JoinReport),
+    Send(SendReport),
+    Status(StatusReport),
+}
+
+impl Report {
+    fn human(&self) -> String {
+        match self {
+            Report::Join(r) => format!("Joined {} ({})", r.network_name, r.network_id),
+            Report::Send(r) => format!("Sending {} (transfer {})", r.file, r.transfer_id),
+            Report::Status(r) if r.connected => format!(
+                "Connected to {} as {} with {} active peers",
+                r.network_name.as_deref().unwrap_or_default(),
+                r.virtual_ip.as_deref().unwrap_or_default(),
+                r.active_peers.unwrap_or_default()
+            ),
+            Report::Status(_) => "Not connected".to_string(),
+        }
+    }
+}
+
+async fn execute(action: &Action) -> Result<Report, String> {
+    let client = DaemonClient::connect().await.map_err(daemon_error)?;
+    match action {
+        Action::Join(invite) => {
+            let invite = crate::clipboard::normalize_invite(invite)?;
+            let network = client.join_network(&invite).await.map_err(daemon_error)?;
+            Ok(Report::Join(JoinReport { network_id: network.id, network_name: network.name }))
+        }
+        Action::Send { file, peer } => {
+            // A remote daemon resolves the path on its own host
+            let file = if crate::endpoint::active().is_remote() {
+                file.clone()
+            } else {
+                let path = std::fs::canonicalize(file).map_err(|e| format!("{}: {}", file, e))?;
+                path.to_string_lossy().into_owned()
+            };
+            remote_files::check_send_path_with(&client, &file).await?;
+            let peers = client.get_peers().await.map_err(daemon_error)?;
+            let target = peers
+                .iter()
+                .find(|p| &p.id == peer)
+                .or_else(|| {
+                    peers
+                        .iter()
+                        .find(|p| p.display_name.eq_ignore_ascii_case(peer) || p.name.eq_ignore_ascii_case(peer))
+                })
+                .ok_or_else(|| format!("no peer named {}", peer))?;
+            let transfer_id = client.send_file(&target.id, &file).await.map_err(daemon_error)?;
+            Ok(Report::Send(SendReport { transfer_id, peer_id: target.id.clone(), file }))
+        }
+        Action::Status => {
+            let status = client.get_status().await.map_err(daemon_error)?;
+            Ok(Report::Status(StatusReport::new(None, Some(&status))))
+        }
+        Action::Help => Err("help is not a daemon action".to_string()),
+    }
+}
+
+/// Run a headless action from the process arguments. Returns the exit code, or
+/// `None` when the app should start normally.
+pub fn run_headless() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let invocation = match parse(&args) {
+        Ok(invocation) => invocation?,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return Some(2);
+        }
+    };
+
+    if invocation.action == Action::Help {
+        println!("{}", USAGE);
+        return Some(0);
+    }
+
+    if let Some(url) = invocation.endpoint {
+        crate::endpoint::configure(EndpointSettings { url: Some(url), token_path: None });
+    }
+    let result = tauri::async_runtime::block_on(execute(&invocation.action));
+    let code = if result.is_ok() { 0 } else { 1 };
+    if invocation.json {
+        // Failures are enveloped on stdout too, so tooling reads one stream
+        println!("{}", Envelope::from_result(result).to_line());
+    } else {
+        match result {
+            Ok(report) => println!("{}", report.human()),
+            Err(e) => eprintln!("Error: {}", e),
+        }
+    }
+    Some(code)
+}
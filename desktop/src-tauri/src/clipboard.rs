@@ -0,0 +1,105 @@
+// Clipboard
+// Every copy path goes through here so what lands on the clipboard is the same
+// everywhere and pastes cleanly: addresses are written by `addressing` (IPv6
+// bracketed next to a port), invite codes are normalized (links unwrapped,
+// separators dropped, upper case) and can be grouped for reading aloud or
+// wrapped in a join link. Grouped codes and links are accepted back by join.
+
+use crate::addressing::{self, AddressStyle};
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use ts_rs::TS;
+
+/// Schemes registered for deep links (tauri.conf.json)
+const LINK_SCHEMES: &[&str] = &["goconnect://", "gc://"];
+
+/// Characters per group in a grouped invite code
+const INVITE_GROUP: usize = 4;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum InviteStyle {
+    /// `ABCD1234`
+    #[default]
+    Plain,
+    /// `ABCD-1234`
+    Grouped,
+    /// `goconnect://join?code=ABCD1234`
+    Link,
+}
+
+/// The bare code from whatever was pasted: a code with spaces or dashes, or a
+/// `goconnect://join?code=` / `gc://join/` link
+pub fn normalize_invite(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    let code = match LINK_SCHEMES.iter().find_map(|scheme| strip_prefix_ignore_case(input, scheme)) {
+        Some(rest) => {
+            let rest = rest.strip_prefix("join").unwrap_or(rest);
+            match rest.split_once("code=") {
+                Some((_, query)) => query.split('&').next().unwrap_or_default(),
+                None => rest.trim_start_matches(['/', '?']),
+            }
+        }
+        None => input,
+    };
+    let code: String = code.chars().filter(char::is_ascii_alphanumeric).collect::<String>().to_ascii_uppercase();
+    if code.is_empty() {
+        return Err("no invite code found".to_string());
+    }
+    Ok(code)
+}
+
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = input.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix).then(|| &input[prefix.len()..])
+}
+
+pub fn format_invite_code(code: &str, style: InviteStyle) -> Result<String, String> {
+    let code = normalize_invite(code)?;
+    Ok(match style {
+        InviteStyle::Plain => code,
+        InviteStyle::Grouped => code
+            .as_bytes()
+            .chunks(INVITE_GROUP)
+            .map(|group| String::from_utf8_lossy(group).into_owned())
+            .collect::<Vec<_>>()
+            .join("-"),
+        InviteStyle::Link => format!("{}join?code={}", LINK_SCHEMES[0], code),
+    })
+}
+
+/// Put `text` on the system clipboard
+pub fn write(app: &AppHandle, text: &str) -> Result<(), String> {
+    app.clipboard().write_text(text).map_err(|e| format!("failed to copy: {}", e))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn format_invite(code: String, style: Option<InviteStyle>) -> Result<String, String> {
+    format_invite_code(&code, style.unwrap_or_default())
+}
+
+/// Copy an invite code; returns what was copied
+#[tauri::command]
+pub async fn copy_invite(app: AppHandle, code: String, style: Option<InviteStyle>) -> Result<String, String> {
+    let text = format_invite_code(&code, style.unwrap_or_default())?;
+    write(&app, &text)?;
+    Ok(text)
+}
+
+/// Copy an address formatted like `format_address`; returns what was copied
+#[tauri::command]
+pub async fn copy_address(
+    app: AppHandle,
+    address: String,
+    port: Option<u16>,
+    style: Option<AddressStyle>,
+) -> Result<String, String> {
+    let text = addressing::format_text(&address, port, style.unwrap_or(AddressStyle::Plain))?;
+    write(&app, &text)?;
+    Ok(text)
+}
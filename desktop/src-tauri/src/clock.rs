@@ -0,0 +1,169 @@
+// Clock Skew Detection
+// Tokens and peer handshakes carry timestamps, so a system clock that is far
+// off makes them fail with errors that say nothing about time. The local clock
+// is compared now and then with the coordination server's, as relayed by the
+// daemon in `get_version` (or the daemon's own clock when it has not reached the
+// server yet). A significant skew raises `SKEW_EVENT` and a notification once,
+// adds a hint to authentication errors, and `fix_clock_skew` asks the OS to
+// resynchronize its clock.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::i18n::{self, Text};
+use crate::lifecycle::command;
+use crate::notifications::{self, NotificationKind};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager};
+use ts_rs::TS;
+
+/// Emitted with the `ClockStatus` when a skew is found or goes away
+pub const SKEW_EVENT: &str = "clock://skew";
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Most validation allows a minute of leeway
+const SIGNIFICANT_SKEW_SECS: i64 = 60;
+
+/// Skew of the last check when significant, otherwise 0; read when describing errors
+static SIGNIFICANT_SKEW: AtomicI64 = AtomicI64::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ClockReference {
+    /// The coordination server, as relayed by the daemon
+    Server,
+    /// The daemon's machine, before it has reached the server
+    Daemon,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ClockStatus {
+    pub reference: ClockReference,
+    /// Seconds the local clock is ahead of the reference; negative when behind
+    #[ts(type = "number")]
+    pub skew_secs: i64,
+    pub significant: bool,
+    /// What to tell the user, when significant
+    pub warning: Option<String>,
+}
+
+/// Managed state holding the result of the last check
+#[derive(Default)]
+pub struct ClockState {
+    last: Mutex<Option<ClockStatus>>,
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or(0)
+}
+
+fn describe_skew(skew_secs: i64) -> String {
+    let secs = skew_secs.unsigned_abs();
+    let amount = match secs {
+        0..120 => format!("{} seconds", secs),
+        120..7200 => format!("{} minutes", secs / 60),
+        _ => format!("{} hours", secs / 3600),
+    };
+    let direction = if skew_secs > 0 { "ahead" } else { "behind" };
+    format!("This computer's clock is {} {}", amount, direction)
+}
+
+/// Hint appended to authentication errors while the clock is known to be off
+pub(crate) fn auth_hint() -> Option<String> {
+    let skew = SIGNIFICANT_SKEW.load(Ordering::Relaxed);
+    (skew != 0).then(|| format!("{}, which breaks token checks; correct the system time", describe_skew(skew)))
+}
+
+/// Compare the local clock with the daemon's reference time
+async fn measure(daemon: &DaemonState) -> Result<ClockStatus, String> {
+    let sent = now_ms();
+    let version = daemon_call!(daemon, "get_version", |client| client.get_version())?;
+    // The reference time was read about halfway through the round trip
+    let local_secs = (sent + now_ms()) / 2 / 1000;
+    let (reference, time) = match (version.server_time, version.daemon_time) {
+        (Some(time), _) => (ClockReference::Server, time),
+        (None, Some(time)) => (ClockReference::Daemon, time),
+        (None, None) => return Err("this daemon version does not report its time".to_string()),
+    };
+    let skew_secs = local_secs - time as i64;
+    let significant = skew_secs.abs() >= SIGNIFICANT_SKEW_SECS;
+    Ok(ClockStatus {
+        reference,
+        skew_secs,
+        significant,
+        warning: significant.then(|| format!("{}. Sign-in and peer connections may fail.", describe_skew(skew_secs))),
+    })
+}
+
+/// Check the clock and announce it when a skew appears or clears
+async fn check(app: &AppHandle) -> Result<ClockStatus, String> {
+    let status = measure(&app.state::<DaemonState>()).await?;
+    SIGNIFICANT_SKEW.store(if status.significant { status.skew_secs } else { 0 }, Ordering::Relaxed);
+
+    let state = app.state::<ClockState>();
+    let was_significant = state.last.lock().unwrap().replace(status.clone()).is_some_and(|s| s.significant);
+    if status.significant != was_significant {
+        let _ = app.emit(SKEW_EVENT, &status);
+        if let Some(warning) = &status.warning {
+            log::warn!("Clock skew of {}s against the {:?} clock", status.skew_secs, status.reference);
+            notifications::notify(app, NotificationKind::System, i18n::text(Text::ClockSkewTitle), warning);
+        }
+    }
+    Ok(status)
+}
+
+/// Check the clock periodically while the daemon is reachable
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        loop {
+            if daemon.connection.state().is_usable() {
+                if let Err(e) = check(&app).await {
+                    log::debug!("Clock check skipped: {}", e);
+                }
+            }
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+/// Ask the OS to synchronize its clock with network time; needs elevation
+fn resync() -> Result<(), String> {
+    let status = if cfg!(windows) {
+        let command_line = "Start-Process -FilePath powershell -ArgumentList \
+            '-NoProfile -Command Start-Service w32time; w32tm /resync /force' -Verb RunAs -Wait -WindowStyle Hidden";
+        command("powershell", &["-NoProfile", "-Command", command_line]).status()
+    } else if cfg!(target_os = "macos") {
+        let script = "do shell script \"sntp -sS time.apple.com\" with administrator privileges";
+        command("osascript", &["-e", script]).status()
+    } else {
+        command("pkexec", &["timedatectl", "set-ntp", "true"]).status()
+    };
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        Ok(_) => Err("the clock could not be synchronized, or elevation was denied".to_string()),
+        Err(e) => Err(format!("failed to run the time service: {}", e)),
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Measure the skew now
+#[tauri::command]
+pub async fn get_clock_status(app: AppHandle) -> Result<ClockStatus, String> {
+    check(&app).await
+}
+
+/// Resynchronize the system clock, then measure again
+#[tauri::command]
+pub async fn fix_clock_skew(app: AppHandle) -> Result<ClockStatus, String> {
+    tauri::async_runtime::spawn_blocking(resync).await.map_err(|e| e.to_string())??;
+    // Time daemons may take a moment to step the clock
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    check(&app).await
+}
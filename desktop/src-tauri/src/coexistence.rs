@@ -0,0 +1,591 @@
+// VPN Co-existence
+// Running GoConnect next to another VPN. Known VPN adapters are detected from
+// their interface names; the user then picks which side wins when both route
+// the same range (a metric preference) and which ranges stay off GoConnect
+// (exclusions, routed where they would go without it). The daemon has no say
+// in the OS routing table, so the changes are applied here with one elevation
+// prompt, and again after each connect when `apply_on_connect` is set.
+// A guided plan proposes settings from the current route conflicts and shows
+// the exact commands before anything is run.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::interfaces::{self, SystemRoute};
+use crate::routes::{self, ConflictSeverity, EffectiveRoutes, RouteConflict};
+use crate::store::JsonStore;
+use ipnet::IpNet;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "coexistence.json";
+
+/// Metric given to GoConnect routes when they should win
+const PREFERRED_METRIC: u32 = 5;
+/// Metric given to GoConnect routes when the other VPN should win
+const DEFERRED_METRIC: u32 = 5000;
+
+/// Time for the daemon to install its routes after connecting
+const APPLY_DELAY: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum RoutePriority {
+    /// Leave the metrics the daemon installed
+    #[default]
+    Automatic,
+    PreferGoconnect,
+    PreferOther,
+    /// Use `custom_metric`
+    Custom,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct RouteExclusion {
+    /// Range kept off GoConnect
+    pub cidr: String,
+    /// Interface to send it through; `None` for wherever it goes without GoConnect
+    #[serde(default)]
+    pub via_interface: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct CoexistenceSettings {
+    pub priority: RoutePriority,
+    pub custom_metric: Option<u32>,
+    pub exclusions: Vec<RouteExclusion>,
+    /// Re-apply after every connect (asks for elevation each time)
+    pub apply_on_connect: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct VpnAdapter {
+    pub interface: String,
+    pub product: String,
+    pub addresses: Vec<String>,
+    /// Number of routes through the adapter
+    pub route_count: u32,
+    /// The adapter carries a default route (full tunnel)
+    pub full_tunnel: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct RouteAction {
+    pub description: String,
+    /// The command as run, in the platform's shell
+    pub command: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CoexistencePlan {
+    pub adapters: Vec<VpnAdapter>,
+    pub conflicts: Vec<RouteConflict>,
+    /// Settings proposed from the conflicts, or the ones given
+    pub settings: CoexistenceSettings,
+    /// What applying `settings` would run
+    pub actions: Vec<RouteAction>,
+    /// Guidance for the user, in order
+    pub steps: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CoexistenceResult {
+    pub actions: Vec<RouteAction>,
+    /// Parts that were skipped and why
+    pub notes: Vec<String>,
+}
+
+/// An exclusion route added by us, for reverting
+#[derive(Debug, Clone)]
+struct AddedRoute {
+    cidr: IpNet,
+    interface: String,
+}
+
+/// Managed state holding the co-existence settings
+pub struct CoexistenceState {
+    settings: JsonStore<CoexistenceSettings>,
+    added: Mutex<Vec<AddedRoute>>,
+}
+
+impl CoexistenceState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), added: Mutex::new(Vec::new()) }
+    }
+}
+
+fn validate(settings: &CoexistenceSettings) -> Result<CoexistenceSettings, String> {
+    if settings.priority == RoutePriority::Custom && settings.custom_metric.is_none() {
+        return Err("a custom priority needs a metric".to_string());
+    }
+    let mut exclusions: Vec<RouteExclusion> = Vec::new();
+    for exclusion in &settings.exclusions {
+        let cidr = exclusion
+            .cidr
+            .trim()
+            .parse::<IpNet>()
+            .or_else(|_| exclusion.cidr.trim().parse::<IpAddr>().map(IpNet::from))
+            .map_err(|_| format!("'{}' is not a CIDR range", exclusion.cidr))?
+            .trunc();
+        if cidr.prefix_len() == 0 {
+            return Err("excluding the default route would take all traffic off the network".to_string());
+        }
+        let cidr = cidr.to_string();
+        if exclusions.iter().any(|e| e.cidr == cidr) {
+            continue;
+        }
+        let via_interface = exclusion.via_interface.as_deref().map(str::trim).filter(|i| !i.is_empty());
+        exclusions.push(RouteExclusion { cidr, via_interface: via_interface.map(str::to_string) });
+    }
+    Ok(CoexistenceSettings { exclusions, ..settings.clone() })
+}
+
+/// Virtual IPs of the connected networks; none when the daemon is unreachable
+async fn virtual_ips(daemon: &DaemonState) -> Vec<IpAddr> {
+    daemon_call!(daemon, "get_network_statuses", |client| client.get_network_statuses())
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|n| n.addresses())
+        .collect()
+}
+
+fn adapters(tunnels: &[String], table: &[SystemRoute]) -> Result<Vec<VpnAdapter>, String> {
+    let all = if_addrs::get_if_addrs().map_err(|e| format!("failed to list interfaces: {}", e))?;
+    let mut adapters: Vec<VpnAdapter> = Vec::new();
+    for iface in &all {
+        if tunnels.contains(&iface.name) || iface.is_loopback() {
+            continue;
+        }
+        let Some(product) = routes::vpn_name(&iface.name) else { continue };
+        let address = iface.ip().to_string();
+        if let Some(existing) = adapters.iter_mut().find(|a| a.interface == iface.name) {
+            existing.addresses.push(address);
+            continue;
+        }
+        let own: Vec<&SystemRoute> = table.iter().filter(|r| r.interface == iface.name).collect();
+        adapters.push(VpnAdapter {
+            interface: iface.name.clone(),
+            product,
+            addresses: vec![address],
+            route_count: own.len() as u32,
+            full_tunnel: own.iter().any(|r| routes::parse_destination(&r.route).is_some_and(|n| n.prefix_len() == 0)),
+        });
+    }
+    adapters.sort_by(|a, b| a.interface.cmp(&b.interface));
+    Ok(adapters)
+}
+
+/// Propose settings from the route conflicts, with the reasoning as steps
+fn suggest(adapters: &[VpnAdapter], conflicts: &[RouteConflict]) -> (CoexistenceSettings, Vec<String>) {
+    let mut settings = CoexistenceSettings::default();
+    let mut steps = Vec::new();
+    if adapters.is_empty() {
+        steps.push("No other VPN is active; nothing needs to change.".to_string());
+        return (settings, steps);
+    }
+    for adapter in adapters {
+        steps.push(format!("{} is active on {}.", adapter.product, adapter.interface));
+    }
+
+    for conflict in conflicts.iter().filter(|c| c.severity == ConflictSeverity::High) {
+        let vpn = conflict.other_vpn.clone().unwrap_or_else(|| conflict.other_interface.clone());
+        let other = routes::parse_destination(&conflict.other_route);
+        let ours = conflict.route.parse::<IpNet>().ok();
+        match (other, ours) {
+            (Some(other), Some(ours)) if other.prefix_len() > ours.prefix_len() => {
+                let cidr = other.to_string();
+                if !settings.exclusions.iter().any(|e| e.cidr == cidr) {
+                    steps.push(format!(
+                        "{} needs {}, inside GoConnect's {}; keep it on {} with an exclusion.",
+                        vpn, cidr, ours, vpn
+                    ));
+                    settings.exclusions.push(RouteExclusion {
+                        cidr,
+                        via_interface: Some(conflict.other_interface.clone()),
+                    });
+                }
+            }
+            (Some(other), _) if other.prefix_len() == 0 => steps.push(format!(
+                "{} sends all traffic through a policy-routing table. Exclude {} in {}'s own settings \
+                 (split tunneling or allowed LAN ranges).",
+                vpn, conflict.route, vpn
+            )),
+            _ if settings.priority == RoutePriority::Automatic => {
+                steps.push(format!(
+                    "{} routes the same range as GoConnect ({}); give GoConnect the better metric. \
+                     Choose \"prefer other\" instead if {} should win.",
+                    vpn, conflict.route, vpn
+                ));
+                settings.priority = RoutePriority::PreferGoconnect;
+            }
+            _ => {}
+        }
+    }
+    if settings == CoexistenceSettings::default() {
+        steps.push("No route competes with GoConnect; both VPNs can run as they are.".to_string());
+    } else {
+        steps.push("Review the commands below, then apply them. Your system asks for administrator rights.".to_string());
+    }
+    (settings, steps)
+}
+
+#[cfg(not(windows))]
+fn quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+#[cfg(windows)]
+fn quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "''"))
+}
+
+fn metric_actions(
+    settings: &CoexistenceSettings,
+    effective: &EffectiveRoutes,
+    notes: &mut Vec<String>,
+) -> Vec<RouteAction> {
+    let metric = match settings.priority {
+        RoutePriority::Automatic => return Vec::new(),
+        RoutePriority::PreferGoconnect => PREFERRED_METRIC,
+        RoutePriority::PreferOther => DEFERRED_METRIC,
+        RoutePriority::Custom => settings.custom_metric.unwrap_or(PREFERRED_METRIC),
+    };
+    if effective.tunnel_interfaces.is_empty() {
+        notes.push("route priority: no network is connected".to_string());
+        return Vec::new();
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // Add at the new metric before deleting the old route so traffic never loses its route
+        effective
+            .routes
+            .iter()
+            .filter(|r| r.table.is_none() && r.route.metric.unwrap_or(0) != metric)
+            .flat_map(|r| {
+                let dst = quote(&r.route.destination);
+                let dev = quote(&r.interface);
+                let via = r.route.gateway.as_deref().map(|g| format!(" via {}", quote(g))).unwrap_or_default();
+                [
+                    RouteAction {
+                        description: format!("Route {} through {} at metric {}", r.route.destination, r.interface, metric),
+                        command: format!("ip route add {} dev {}{} metric {}", dst, dev, via, metric),
+                    },
+                    RouteAction {
+                        description: format!("Remove the metric {} route", r.route.metric.unwrap_or(0)),
+                        command: format!("ip route del {} dev {} metric {}", dst, dev, r.route.metric.unwrap_or(0)),
+                    },
+                ]
+            })
+            .collect()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let _ = metric;
+        notes.push("route priority: macOS has no route metrics; order the services in Network settings".to_string());
+        Vec::new()
+    }
+
+    #[cfg(windows)]
+    {
+        effective
+            .tunnel_interfaces
+            .iter()
+            .map(|alias| RouteAction {
+                description: format!("Set the interface metric of {} to {}", alias, metric),
+                command: format!("Set-NetIPInterface -InterfaceAlias {} -InterfaceMetric {}", quote(alias), metric),
+            })
+            .collect()
+    }
+}
+
+/// Where `cidr` would be routed without GoConnect: the most specific other route covering it
+fn fallback_route<'a>(cidr: &IpNet, tunnels: &[String], table: &'a [SystemRoute]) -> Option<&'a SystemRoute> {
+    table
+        .iter()
+        .filter(|r| r.table.is_none() && !tunnels.contains(&r.interface))
+        .filter_map(|r| Some((routes::parse_destination(&r.route)?, r)))
+        .filter(|(net, _)| net.contains(cidr))
+        .max_by_key(|(net, r)| (net.prefix_len(), std::cmp::Reverse(r.route.metric.unwrap_or(0))))
+        .map(|(_, r)| r)
+}
+
+fn exclusion_actions(
+    settings: &CoexistenceSettings,
+    tunnels: &[String],
+    table: &[SystemRoute],
+    notes: &mut Vec<String>,
+) -> Vec<(RouteAction, AddedRoute)> {
+    let mut actions = Vec::new();
+    for exclusion in &settings.exclusions {
+        let Ok(cidr) = exclusion.cidr.parse::<IpNet>() else { continue };
+        let fallback = fallback_route(&cidr, tunnels, table);
+        let interface = match (&exclusion.via_interface, fallback) {
+            (Some(via), _) => via.clone(),
+            (None, Some(route)) => route.interface.clone(),
+            (None, None) => {
+                notes.push(format!("exclusion {}: no other route covers it", cidr));
+                continue;
+            }
+        };
+        if tunnels.contains(&interface) {
+            notes.push(format!("exclusion {}: {} is a GoConnect interface", cidr, interface));
+            continue;
+        }
+        // Keep the fallback's gateway only when it is the same interface
+        let gateway = fallback.filter(|r| r.interface == interface).and_then(|r| r.route.gateway.clone());
+
+        #[cfg(target_os = "linux")]
+        let command = format!(
+            "ip route replace {} dev {}{}",
+            quote(&cidr.to_string()),
+            quote(&interface),
+            gateway.as_deref().map(|g| format!(" via {}", quote(g))).unwrap_or_default()
+        );
+        #[cfg(target_os = "macos")]
+        let command = match gateway.as_deref() {
+            Some(g) => format!("route -n add -net {} {}", quote(&cidr.to_string()), quote(g)),
+            None => format!("route -n add -net {} -interface {}", quote(&cidr.to_string()), quote(&interface)),
+        };
+        #[cfg(windows)]
+        let command = format!(
+            "New-NetRoute -DestinationPrefix {} -InterfaceAlias {}{} -RouteMetric 1 -PolicyStore ActiveStore",
+            quote(&cidr.to_string()),
+            quote(&interface),
+            gateway.as_deref().map(|g| format!(" -NextHop {}", quote(g))).unwrap_or_default()
+        );
+
+        actions.push((
+            RouteAction { description: format!("Send {} through {} instead of GoConnect", cidr, interface), command },
+            AddedRoute { cidr, interface },
+        ));
+    }
+    actions
+}
+
+fn removal_command(route: &AddedRoute) -> String {
+    #[cfg(target_os = "linux")]
+    return format!("ip route del {} dev {}", quote(&route.cidr.to_string()), quote(&route.interface));
+    #[cfg(target_os = "macos")]
+    return format!("route -n delete -net {}", quote(&route.cidr.to_string()));
+    #[cfg(windows)]
+    return format!(
+        "Remove-NetRoute -DestinationPrefix {} -InterfaceAlias {} -Confirm:$false",
+        quote(&route.cidr.to_string()),
+        quote(&route.interface)
+    );
+}
+
+struct Snapshot {
+    effective: EffectiveRoutes,
+    table: Vec<SystemRoute>,
+    adapters: Vec<VpnAdapter>,
+}
+
+fn snapshot(virtual_ips: &[IpAddr]) -> Result<Snapshot, String> {
+    let effective = routes::inspect(virtual_ips)?;
+    let table = interfaces::route_table()?;
+    let adapters = adapters(&effective.tunnel_interfaces, &table)?;
+    Ok(Snapshot { effective, table, adapters })
+}
+
+/// Run `commands` in one elevated script, so the user is asked once
+fn run_elevated(commands: &[String]) -> Result<(), String> {
+    #[cfg(windows)]
+    let (extension, body) = ("ps1", format!("$ErrorActionPreference = 'Stop'\r\n{}\r\n", commands.join("\r\n")));
+    #[cfg(not(windows))]
+    let (extension, body) = ("sh", format!("set -e\n{}\n", commands.join("\n")));
+
+    let path = std::env::temp_dir().join(format!("goconnect-routes-{}.{}", rand::random::<u64>(), extension));
+    std::fs::write(&path, body).map_err(|e| format!("failed to write {:?}: {}", path, e))?;
+
+    #[cfg(target_os = "linux")]
+    let status = std::process::Command::new("pkexec").arg("sh").arg(&path).status();
+
+    #[cfg(target_os = "macos")]
+    let status = {
+        let script = format!(
+            "do shell script \"sh '{}'\" with administrator privileges",
+            path.to_string_lossy().replace('\'', r"'\''")
+        );
+        std::process::Command::new("osascript").args(["-e", &script]).status()
+    };
+
+    #[cfg(windows)]
+    let status = {
+        let command = format!(
+            "$p = Start-Process -FilePath powershell -ArgumentList '-NoProfile','-ExecutionPolicy','Bypass','-File','\"{}\"' \
+             -Verb RunAs -Wait -WindowStyle Hidden -PassThru; exit $p.ExitCode",
+            path.display()
+        );
+        std::process::Command::new("powershell").args(["-NoProfile", "-Command", &command]).status()
+    };
+
+    let _ = std::fs::remove_file(&path);
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        Ok(_) => Err("elevation was denied or a route command failed".to_string()),
+        Err(e) => Err(format!("failed to request elevation: {}", e)),
+    }
+}
+
+/// Apply the saved settings to the current routes
+async fn apply(state: &CoexistenceState, daemon: &DaemonState) -> Result<CoexistenceResult, String> {
+    let settings = state.settings.get();
+    let ips = virtual_ips(daemon).await;
+    let (actions, added, notes) = tauri::async_runtime::spawn_blocking(move || {
+        let snapshot = snapshot(&ips)?;
+        let mut notes = Vec::new();
+        let mut actions = metric_actions(&settings, &snapshot.effective, &mut notes);
+        let (exclusions, added): (Vec<_>, Vec<_>) =
+            exclusion_actions(&settings, &snapshot.effective.tunnel_interfaces, &snapshot.table, &mut notes)
+                .into_iter()
+                .unzip();
+        actions.extend(exclusions);
+        if !actions.is_empty() {
+            run_elevated(&actions.iter().map(|a| a.command.clone()).collect::<Vec<_>>())?;
+        }
+        Ok::<_, String>((actions, added, notes))
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    let mut recorded = state.added.lock().unwrap();
+    for route in added {
+        if !recorded.iter().any(|r| r.cidr == route.cidr && r.interface == route.interface) {
+            recorded.push(route);
+        }
+    }
+    Ok(CoexistenceResult { actions, notes })
+}
+
+/// Re-apply after each connect when the user asked for it
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut status_rx = app.state::<DaemonState>().subscribe_status();
+        let mut was_connected = false;
+        while status_rx.changed().await.is_ok() {
+            let connected = status_rx.borrow_and_update().as_ref().is_some_and(|s| s.connected);
+            let rising = connected && !was_connected;
+            was_connected = connected;
+            let state = app.state::<CoexistenceState>();
+            let settings = state.settings.get();
+            if !rising || !settings.apply_on_connect || settings == CoexistenceSettings::default() {
+                continue;
+            }
+            tokio::time::sleep(APPLY_DELAY).await;
+            match apply(&state, &app.state::<DaemonState>()).await {
+                Ok(result) => log::info!("Applied {} co-existence route changes", result.actions.len()),
+                Err(e) => log::warn!("Failed to apply VPN co-existence settings: {}", e),
+            }
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Other VPN adapters that are up, excluding GoConnect's own tunnel
+#[tauri::command]
+pub async fn detect_vpn_adapters(daemon: State<'_, DaemonState>) -> Result<Vec<VpnAdapter>, String> {
+    let ips = virtual_ips(&daemon).await;
+    tauri::async_runtime::spawn_blocking(move || snapshot(&ips).map(|s| s.adapters))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn get_coexistence_settings(state: State<'_, CoexistenceState>) -> Result<CoexistenceSettings, String> {
+    Ok(state.settings.get())
+}
+
+/// Validate and save; nothing changes in the routing table until applied
+#[tauri::command]
+pub async fn set_coexistence_settings(
+    state: State<'_, CoexistenceState>,
+    settings: CoexistenceSettings,
+) -> Result<CoexistenceSettings, String> {
+    let settings = validate(&settings)?;
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
+
+/// The guided "both VPNs" flow: detected adapters, conflicts, proposed settings
+/// (or `settings` when given) and the commands applying them would run
+#[tauri::command]
+pub async fn plan_vpn_coexistence(
+    daemon: State<'_, DaemonState>,
+    settings: Option<CoexistenceSettings>,
+) -> Result<CoexistencePlan, String> {
+    let given = settings.as_ref().map(validate).transpose()?;
+    let ips = virtual_ips(&daemon).await;
+    tauri::async_runtime::spawn_blocking(move || {
+        let snapshot = snapshot(&ips)?;
+        let (suggested, mut steps) = suggest(&snapshot.adapters, &snapshot.effective.conflicts);
+        let settings = given.unwrap_or(suggested);
+        let mut notes = Vec::new();
+        let mut actions = metric_actions(&settings, &snapshot.effective, &mut notes);
+        actions.extend(
+            exclusion_actions(&settings, &snapshot.effective.tunnel_interfaces, &snapshot.table, &mut notes)
+                .into_iter()
+                .map(|(action, _)| action),
+        );
+        steps.extend(notes);
+        Ok(CoexistencePlan {
+            adapters: snapshot.adapters,
+            conflicts: snapshot.effective.conflicts,
+            settings,
+            actions,
+            steps,
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Apply the saved settings now (asks for administrator rights)
+#[tauri::command]
+pub async fn apply_vpn_coexistence(
+    state: State<'_, CoexistenceState>,
+    daemon: State<'_, DaemonState>,
+) -> Result<CoexistenceResult, String> {
+    apply(&state, &daemon).await
+}
+
+/// Remove the exclusion routes added this session. Metrics return to the
+/// daemon's on the next connect.
+#[tauri::command]
+pub async fn revert_vpn_coexistence(state: State<'_, CoexistenceState>) -> Result<CoexistenceResult, String> {
+    let added = std::mem::take(&mut *state.added.lock().unwrap());
+    let actions: Vec<RouteAction> = added
+        .iter()
+        .map(|r| RouteAction {
+            description: format!("Remove the exclusion route for {}", r.cidr),
+            command: removal_command(r),
+        })
+        .collect();
+    if actions.is_empty() {
+        return Ok(CoexistenceResult { actions, notes: vec!["no exclusion routes were added".to_string()] });
+    }
+    let commands: Vec<String> = actions.iter().map(|a| a.command.clone()).collect();
+    let result = tauri::async_runtime::spawn_blocking(move || run_elevated(&commands))
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Err(e) = result {
+        // Still ours to remove
+        state.added.lock().unwrap().extend(added);
+        return Err(e);
+    }
+    Ok(CoexistenceResult { actions, notes: Vec::new() })
+}
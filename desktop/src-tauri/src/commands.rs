@@ -1,35 +1,188 @@
 // Tauri Commands - Bridge between frontend and daemon gRPC client
 
+use crate::admin_mode::AdminModeState;
+use crate::checksums::ChecksumState;
+use crate::clock;
+use crate::connection::{ConnectionEvent, ConnectionMonitor, ConnectionSnapshot};
 use crate::daemon::{
-    ChatMessage, DaemonClient, DaemonStatus, NetworkInfo, PeerInfo, Settings, 
+    ChatMessage, DaemonClient, DaemonError, DaemonStatus, InvitePreview, NetworkInfo, NetworkStatus, PeerInfo, Settings, 
     TransferInfo, TransferStats, VersionInfo
 };
-use tauri::State;
-use tokio::sync::Mutex;
+use crate::guests::{self, GuestState, JoinOptions};
+use crate::leave::{self, LeaveOptions, LeaveOutcome};
+use crate::metrics::{CommandMetrics, RpcMetrics};
+use crate::moderation::{ModerationAction, ModerationState};
+use crate::optimistic::{self, OptimisticState};
+use crate::part_files::PartFilesState;
+use crate::remote_files;
+use crate::reputation::ReputationState;
+use crate::save_paths::{self, AcceptOutcome, CollisionPolicy};
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::{AppHandle, State};
+use tokio::sync::watch;
+use tonic::Code;
+
+/// Emitted with the per-network breakdown whenever it changes
+pub const NETWORKS_CHANGED_EVENT: &str = "daemon://networks-changed";
+
+/// Command pipeline shared by every daemon-backed command:
+/// client acquisition, one retry with a freshly loaded IPC token on UNAUTHENTICATED,
+/// then logging, metrics, connection-state bookkeeping and error mapping.
+///
+/// `daemon_call!(state, "name", |client| client.some_rpc(&arg))`
+macro_rules! daemon_call {
+    ($state:expr, $command:literal, |$client:ident| $call:expr) => {{
+        let state: &$crate::commands::DaemonState = &$state;
+        let started = std::time::Instant::now();
+        let mut result = match state.client().await {
+            Ok($client) => $call.await,
+            Err(e) => Err(e),
+        };
+        let retried = state.refresh_token_if_rejected(&result);
+        if retried {
+            result = match state.client().await {
+                Ok($client) => $call.await,
+                Err(e) => Err(e),
+            };
+        }
+        state.finish($command, started, retried, result)
+    }};
+}
+pub(crate) use daemon_call;
+
+/// Managed state holding the daemon client connection and its lifecycle state
+#[derive(Default)]
+pub struct DaemonState {
+    client: Mutex<Option<DaemonClient>>,
+    pub connection: ConnectionMonitor,
+    pub metrics: RpcMetrics,
+    /// Latest status from any probe; `None` while the daemon cannot be queried
+    status: watch::Sender<Option<DaemonStatus>>,
+    /// Per-network breakdown from the latest network probe
+    networks: watch::Sender<Vec<NetworkStatus>>,
+}
 
-/// Managed state holding the daemon client connection
-pub struct DaemonState(pub Mutex<Option<DaemonClient>>);
+impl DaemonState {
+    /// Return the cached client, connecting on first use
+    pub async fn client(&self) -> Result<DaemonClient, DaemonError> {
+        if let Some(client) = self.client.lock().unwrap().as_ref() {
+            return Ok(client.clone());
+        }
+
+        // Connection success alone does not prove the token is valid,
+        // so only failures are fed to the state machine here
+        self.connection.apply(ConnectionEvent::Probe);
+        let client = DaemonClient::connect().await.inspect_err(|e| {
+            self.connection.apply(ConnectionEvent::from_error(e));
+        })?;
 
-impl Default for DaemonState {
-    fn default() -> Self {
-        Self(Mutex::new(None))
+        *self.client.lock().unwrap() = Some(client.clone());
+        Ok(client)
+    }
+
+    /// Drop the cached client so the next call connects afresh, e.g. to a new endpoint
+    pub fn reset_client(&self) {
+        self.client.lock().unwrap().take();
+    }
+
+    /// The daemon rotates its IPC token on restart. On UNAUTHENTICATED, drop the
+    /// cached client so the next `client()` call re-reads the token file.
+    pub(crate) fn refresh_token_if_rejected<T>(&self, result: &Result<T, DaemonError>) -> bool {
+        let rejected = matches!(result, Err(DaemonError::Rpc(status)) if status.code() == Code::Unauthenticated);
+        if rejected {
+            self.client.lock().unwrap().take();
+        }
+        rejected
+    }
+
+    /// Final pipeline stage: log, record metrics, update the connection state and map errors
+    pub(crate) fn finish<T>(
+        &self,
+        command: &'static str,
+        started: Instant,
+        retried: bool,
+        result: Result<T, DaemonError>,
+    ) -> Result<T, String> {
+        let elapsed = started.elapsed();
+        let message = result.as_ref().err().map(describe_error);
+
+        match &message {
+            None => log::debug!("{} ok in {:?}", command, elapsed),
+            Some(error) => log::warn!("{} failed in {:?}: {}", command, elapsed, error),
+        }
+        self.metrics.record(command, elapsed, message.as_deref(), retried);
+
+        // The cached client is dropped once the daemon is gone or rejects our token,
+        // so the next call reconnects and re-reads the token file
+        if !self.connection.record(&result).is_usable() {
+            self.client.lock().unwrap().take();
+        }
+
+        result.map_err(|_| message.unwrap_or_default())
+    }
+
+    /// Query daemon status, driving the state machine and the status stream either way
+    pub async fn probe_status(&self) -> Result<DaemonStatus, String> {
+        let result = daemon_call!(self, "get_status", |client| client.get_status());
+        let latest = result.as_ref().ok().cloned();
+        self.status.send_if_modified(|current| {
+            let changed = *current != latest;
+            *current = latest;
+            changed
+        });
+        result
+    }
+
+    /// Query every joined network's state; the breakdown is cleared when the daemon cannot answer
+    pub async fn probe_networks(&self) -> Result<Vec<NetworkStatus>, String> {
+        let result = daemon_call!(self, "get_network_statuses", |client| client.get_network_statuses());
+        let latest = result.as_ref().cloned().unwrap_or_default();
+        self.networks.send_if_modified(|current| {
+            let changed = *current != latest;
+            *current = latest;
+            changed
+        });
+        result
     }
-}
 
-/// Ensure daemon client is connected
-async fn get_client(state: &State<'_, DaemonState>) -> Result<DaemonClient, String> {
-    let mut guard = state.0.lock().await;
+    /// Per-network states from the most recent network probe
+    pub fn latest_networks(&self) -> Vec<NetworkStatus> {
+        self.networks.borrow().clone()
+    }
+
+    /// Receive the per-network states whenever they change
+    pub fn subscribe_networks(&self) -> watch::Receiver<Vec<NetworkStatus>> {
+        self.networks.subscribe()
+    }
 
-    // Use existing connection if available
-    if let Some(client) = guard.as_ref() {
-        return Ok(client.clone());
+    /// Status from the most recent probe, without querying the daemon
+    pub fn latest_status(&self) -> Option<DaemonStatus> {
+        self.status.borrow().clone()
     }
 
-    // Otherwise create new connection
-    let client = DaemonClient::connect().await.map_err(|e| e.to_string())?;
-    *guard = Some(client.clone());
-    
-    Ok(client)
+    /// Receive the latest status whenever it changes
+    pub fn subscribe_status(&self) -> watch::Receiver<Option<DaemonStatus>> {
+        self.status.subscribe()
+    }
+}
+
+/// Map a client error to a message fit for the UI
+fn describe_error(error: &DaemonError) -> String {
+    match error {
+        DaemonError::Rpc(status) => match status.code() {
+            Code::Unavailable => "GoConnect daemon is unavailable".to_string(),
+            Code::Unauthenticated | Code::PermissionDenied => match clock::auth_hint() {
+                Some(hint) => format!("Daemon rejected the request. {}", hint),
+                None => "Daemon rejected the IPC token; restart the GoConnect daemon".to_string(),
+            },
+            Code::DeadlineExceeded => "Daemon did not respond in time".to_string(),
+            Code::Unimplemented => "This daemon version does not support this operation".to_string(),
+            _ if !status.message().is_empty() => status.message().to_string(),
+            _ => error.to_string(),
+        },
+        _ => error.to_string(),
+    }
 }
 
 // =============================================================================
@@ -38,27 +191,41 @@ async fn get_client(state: &State<'_, DaemonState>) -> Result<DaemonClient, Stri
 
 #[tauri::command]
 pub async fn daemon_get_status(state: State<'_, DaemonState>) -> Result<DaemonStatus, String> {
-    let client = get_client(&state).await?;
-    client.get_status().await.map_err(|e| e.to_string())
+    state.probe_status().await
+}
+
+/// Every joined network with its own connection state, virtual IP and peer counts
+#[tauri::command]
+pub async fn daemon_get_network_statuses(state: State<'_, DaemonState>) -> Result<Vec<NetworkStatus>, String> {
+    state.probe_networks().await
 }
 
 #[tauri::command]
 pub async fn daemon_get_version(state: State<'_, DaemonState>) -> Result<VersionInfo, String> {
-    let client = get_client(&state).await?;
-    client.get_version().await.map_err(|e| e.to_string())
+    daemon_call!(state, "get_version", |client| client.get_version())
 }
 
 #[tauri::command]
-pub async fn daemon_is_running(_state: State<'_, DaemonState>) -> Result<bool, String> {
-    match DaemonClient::connect().await {
-        Ok(client) => {
-            match client.get_status().await {
-                Ok(_) => Ok(true),
-                Err(_) => Ok(false),
-            }
-        }
-        Err(_) => Ok(false),
-    }
+pub async fn daemon_is_running(state: State<'_, DaemonState>) -> Result<bool, String> {
+    // The probe outcome is reflected in the connection state; the error itself is not needed
+    let _ = state.probe_status().await;
+    Ok(state.connection.state().is_daemon_running())
+}
+
+#[tauri::command]
+pub async fn daemon_get_connection_state(state: State<'_, DaemonState>) -> Result<ConnectionSnapshot, String> {
+    Ok(state.connection.snapshot())
+}
+
+#[tauri::command]
+pub async fn daemon_get_rpc_metrics(state: State<'_, DaemonState>) -> Result<Vec<CommandMetrics>, String> {
+    Ok(state.metrics.snapshot())
+}
+
+#[tauri::command]
+pub async fn daemon_reset_rpc_metrics(state: State<'_, DaemonState>) -> Result<(), String> {
+    state.metrics.reset();
+    Ok(())
 }
 
 // =============================================================================
@@ -70,32 +237,76 @@ pub async fn daemon_create_network(
     state: State<'_, DaemonState>,
     name: String,
 ) -> Result<NetworkInfo, String> {
-    let client = get_client(&state).await?;
-    client.create_network(&name).await.map_err(|e| e.to_string())
+    daemon_call!(state, "create_network", |client| client.create_network(&name))
 }
 
+/// Bring up the tunnel; every joined network shares it
+#[tauri::command]
+pub async fn daemon_connect(state: State<'_, DaemonState>) -> Result<DaemonStatus, String> {
+    daemon_call!(state, "connect_network", |client| client.connect_network())?;
+    state.probe_status().await
+}
+
+/// Tear down the active tunnel without leaving the network
+#[tauri::command]
+pub async fn daemon_disconnect(state: State<'_, DaemonState>) -> Result<DaemonStatus, String> {
+    daemon_call!(state, "disconnect_network", |client| client.disconnect_network())?;
+    state.probe_status().await
+}
+
+/// With `options.guest_hours` the network is left again after that long; see `guests`
 #[tauri::command]
 pub async fn daemon_join_network(
     state: State<'_, DaemonState>,
+    guests: State<'_, GuestState>,
     invite_code: String,
+    options: Option<JoinOptions>,
 ) -> Result<NetworkInfo, String> {
-    let client = get_client(&state).await?;
-    client.join_network(&invite_code).await.map_err(|e| e.to_string())
+    // Accept grouped codes and join links as copied by `clipboard`
+    let invite_code = crate::clipboard::normalize_invite(&invite_code)?;
+    let guest_hours = options.unwrap_or_default().guest_hours;
+    if let Some(hours) = guest_hours {
+        guests::validate_hours(hours)?;
+    }
+    let mut network = daemon_call!(state, "join_network", |client| client.join_network(&invite_code))?;
+    if let Some(hours) = guest_hours {
+        network.guest_until = Some(guests.register(&network, hours)?);
+    }
+    Ok(network)
 }
 
+/// Show where an invite leads before joining
 #[tauri::command]
-pub async fn daemon_list_networks(state: State<'_, DaemonState>) -> Result<Vec<NetworkInfo>, String> {
-    let client = get_client(&state).await?;
-    client.list_networks().await.map_err(|e| e.to_string())
+pub async fn daemon_preview_invite(
+    state: State<'_, DaemonState>,
+    invite_code: String,
+) -> Result<InvitePreview, String> {
+    let invite_code = crate::clipboard::normalize_invite(&invite_code)?;
+    let mut preview = daemon_call!(state, "preview_invite", |client| client.preview_invite(&invite_code))?;
+    if let Ok(networks) = daemon_call!(state, "list_networks", |client| client.list_networks()) {
+        preview.already_joined = networks.iter().any(|n| n.id == preview.network_id);
+    }
+    Ok(preview)
 }
 
 #[tauri::command]
-pub async fn daemon_leave_network(
+pub async fn daemon_list_networks(
     state: State<'_, DaemonState>,
+    guests: State<'_, GuestState>,
+) -> Result<Vec<NetworkInfo>, String> {
+    let mut networks = daemon_call!(state, "list_networks", |client| client.list_networks())?;
+    guests.annotate(&mut networks);
+    Ok(networks)
+}
+
+/// Leaves after a safety check; see `leave` for the confirmation flow and cleanup options
+#[tauri::command]
+pub async fn daemon_leave_network(
+    app: AppHandle,
     network_id: String,
-) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.leave_network(&network_id).await.map_err(|e| e.to_string())
+    options: Option<LeaveOptions>,
+) -> Result<LeaveOutcome, String> {
+    leave::leave(&app, &network_id, options.unwrap_or_default()).await
 }
 
 #[tauri::command]
@@ -103,17 +314,17 @@ pub async fn daemon_generate_invite(
     state: State<'_, DaemonState>,
     network_id: String,
 ) -> Result<String, String> {
-    let client = get_client(&state).await?;
-    client.generate_invite(&network_id).await.map_err(|e| e.to_string())
+    daemon_call!(state, "generate_invite", |client| client.generate_invite(&network_id))
 }
 
 #[tauri::command]
 pub async fn daemon_delete_network(
     state: State<'_, DaemonState>,
+    admin: State<'_, AdminModeState>,
     network_id: String,
 ) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.delete_network(&network_id).await.map_err(|e| e.to_string())
+    admin.require()?;
+    daemon_call!(state, "delete_network", |client| client.delete_network(&network_id))
 }
 
 #[tauri::command]
@@ -122,8 +333,7 @@ pub async fn daemon_update_network(
     network_id: String,
     name: String,
 ) -> Result<NetworkInfo, String> {
-    let client = get_client(&state).await?;
-    client.update_network(&network_id, &name).await.map_err(|e| e.to_string())
+    daemon_call!(state, "update_network", |client| client.update_network(&network_id, &name))
 }
 
 // =============================================================================
@@ -132,39 +342,50 @@ pub async fn daemon_update_network(
 
 #[tauri::command]
 pub async fn daemon_get_peers(state: State<'_, DaemonState>) -> Result<Vec<PeerInfo>, String> {
-    let client = get_client(&state).await?;
-    client.get_peers().await.map_err(|e| e.to_string())
+    daemon_call!(state, "get_peers", |client| client.get_peers())
 }
 
 #[tauri::command]
 pub async fn daemon_kick_peer(
     state: State<'_, DaemonState>,
+    admin: State<'_, AdminModeState>,
+    moderation: State<'_, ModerationState>,
     network_id: String,
     peer_id: String,
 ) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.kick_peer(&network_id, &peer_id).await.map_err(|e| e.to_string())
+    admin.require()?;
+    daemon_call!(state, "kick_peer", |client| client.kick_peer(&network_id, &peer_id))?;
+    moderation.record(&network_id, &peer_id, ModerationAction::Kick, None);
+    Ok(())
 }
 
 #[tauri::command]
 pub async fn daemon_ban_peer(
     state: State<'_, DaemonState>,
+    admin: State<'_, AdminModeState>,
+    moderation: State<'_, ModerationState>,
     network_id: String,
     peer_id: String,
     reason: String,
 ) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.ban_peer(&network_id, &peer_id, &reason).await.map_err(|e| e.to_string())
+    admin.require()?;
+    daemon_call!(state, "ban_peer", |client| client.ban_peer(&network_id, &peer_id, &reason))?;
+    moderation.record(&network_id, &peer_id, ModerationAction::Ban, Some(&reason));
+    Ok(())
 }
 
 #[tauri::command]
 pub async fn daemon_unban_peer(
     state: State<'_, DaemonState>,
+    admin: State<'_, AdminModeState>,
+    moderation: State<'_, ModerationState>,
     network_id: String,
     peer_id: String,
 ) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.unban_peer(&network_id, &peer_id).await.map_err(|e| e.to_string())
+    admin.require()?;
+    daemon_call!(state, "unban_peer", |client| client.unban_peer(&network_id, &peer_id))?;
+    moderation.record(&network_id, &peer_id, ModerationAction::Unban, None);
+    Ok(())
 }
 
 // =============================================================================
@@ -173,8 +394,7 @@ pub async fn daemon_unban_peer(
 
 #[tauri::command]
 pub async fn daemon_get_settings(state: State<'_, DaemonState>) -> Result<Settings, String> {
-    let client = get_client(&state).await?;
-    client.get_settings().await.map_err(|e| e.to_string())
+    daemon_call!(state, "get_settings", |client| client.get_settings())
 }
 
 #[tauri::command]
@@ -182,14 +402,38 @@ pub async fn daemon_update_settings(
     state: State<'_, DaemonState>,
     settings: Settings,
 ) -> Result<Settings, String> {
-    let client = get_client(&state).await?;
-    client.update_settings(&settings).await.map_err(|e| e.to_string())
+    daemon_call!(state, "update_settings", |client| client.update_settings(&settings))
+}
+
+/// Cap transfer speeds for all transfers, e.g. on metered connections; 0 for no limit
+#[tauri::command]
+pub async fn daemon_set_transfer_rate_limits(
+    state: State<'_, DaemonState>,
+    local: State<'_, OptimisticState>,
+    upload_kbps: u32,
+    download_kbps: u32,
+) -> Result<Settings, String> {
+    optimistic::update_settings(&local, &state, |settings| {
+        settings.max_upload_speed_kbps = upload_kbps;
+        settings.max_download_speed_kbps = download_kbps;
+    })
+    .await
+}
+
+/// Cap one transfer's speed regardless of the limits for all; `None` lifts the override
+#[tauri::command]
+pub async fn daemon_set_transfer_rate_limit(
+    state: State<'_, DaemonState>,
+    transfer_id: String,
+    max_speed_kbps: Option<u32>,
+) -> Result<(), String> {
+    let kbps = max_speed_kbps.unwrap_or(0);
+    daemon_call!(state, "set_transfer_rate_limit", |client| client.set_transfer_rate_limit(&transfer_id, kbps))
 }
 
 #[tauri::command]
 pub async fn daemon_reset_settings(state: State<'_, DaemonState>) -> Result<Settings, String> {
-    let client = get_client(&state).await?;
-    client.reset_settings().await.map_err(|e| e.to_string())
+    daemon_call!(state, "reset_settings", |client| client.reset_settings())
 }
 
 // =============================================================================
@@ -203,10 +447,7 @@ pub async fn daemon_get_messages(
     limit: Option<i32>,
     before: Option<String>,
 ) -> Result<Vec<ChatMessage>, String> {
-    let client = get_client(&state).await?;
-    client.get_messages(&network_id, limit.unwrap_or(50), before.as_deref())
-        .await
-        .map_err(|e| e.to_string())
+    daemon_call!(state, "get_messages", |client| client.get_messages(&network_id, limit.unwrap_or(50), before.as_deref()))
 }
 
 #[tauri::command]
@@ -215,8 +456,7 @@ pub async fn daemon_send_message(
     network_id: String,
     content: String,
 ) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.send_message(&network_id, &content).await.map_err(|e| e.to_string())
+    daemon_call!(state, "send_message", |client| client.send_message(&network_id, &content)).map(|_| ())
 }
 
 // =============================================================================
@@ -226,19 +466,23 @@ pub async fn daemon_send_message(
 #[tauri::command]
 pub async fn daemon_list_transfers(
     state: State<'_, DaemonState>,
+    checksums: State<'_, ChecksumState>,
+    parts: State<'_, PartFilesState>,
+    reputation: State<'_, ReputationState>,
     status: Option<String>,
     peer_id: Option<String>,
 ) -> Result<Vec<TransferInfo>, String> {
-    let client = get_client(&state).await?;
-    client.list_transfers(status.as_deref(), peer_id.as_deref())
-        .await
-        .map_err(|e| e.to_string())
+    let mut transfers =
+        daemon_call!(state, "list_transfers", |client| client.list_transfers(status.as_deref(), peer_id.as_deref()))?;
+    checksums.annotate(&mut transfers);
+    parts.annotate(&mut transfers);
+    reputation.annotate(&mut transfers);
+    Ok(transfers)
 }
 
 #[tauri::command]
 pub async fn daemon_get_transfer_stats(state: State<'_, DaemonState>) -> Result<TransferStats, String> {
-    let client = get_client(&state).await?;
-    client.get_transfer_stats().await.map_err(|e| e.to_string())
+    daemon_call!(state, "get_transfer_stats", |client| client.get_transfer_stats())
 }
 
 #[tauri::command]
@@ -246,8 +490,7 @@ pub async fn daemon_cancel_transfer(
     state: State<'_, DaemonState>,
     transfer_id: String,
 ) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.cancel_transfer(&transfer_id).await.map_err(|e| e.to_string())
+    daemon_call!(state, "cancel_transfer", |client| client.cancel_transfer(&transfer_id))
 }
 
 #[tauri::command]
@@ -255,26 +498,46 @@ pub async fn daemon_reject_transfer(
     state: State<'_, DaemonState>,
     transfer_id: String,
 ) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.reject_transfer(&transfer_id).await.map_err(|e| e.to_string())
+    daemon_call!(state, "reject_transfer", |client| client.reject_transfer(&transfer_id))
 }
 
 #[tauri::command]
-pub async fn daemon_send_file(
-    state: State<'_, DaemonState>,
-    peer_id: String,
-    file_path: String,
-) -> Result<String, String> {
-    let client = get_client(&state).await?;
-    client.send_file(&peer_id, &file_path).await.map_err(|e| e.to_string())
+pub async fn daemon_send_file(app: AppHandle, peer_id: String, file_path: String) -> Result<String, String> {
+    remote_files::send_file(&app, &peer_id, &file_path).await
 }
 
 #[tauri::command]
 pub async fn daemon_accept_transfer(
+    app: AppHandle,
     state: State<'_, DaemonState>,
     transfer_id: String,
     save_path: String,
-) -> Result<(), String> {
-    let client = get_client(&state).await?;
-    client.accept_transfer(&transfer_id, &save_path).await.map_err(|e| e.to_string())
+    collision: Option<CollisionPolicy>,
+) -> Result<AcceptOutcome, String> {
+    let transfers = daemon_call!(state, "list_transfers", |client| client.list_transfers(None, None))?;
+    let transfer = transfers.into_iter().find(|t| t.id == transfer_id).ok_or("transfer not found")?;
+    save_paths::accept(&app, &transfer, &save_path, collision).await
+}
+
+// =============================================================================
+// DEBUG COMMANDS (hidden, debug builds only)
+// =============================================================================
+
+#[cfg(debug_assertions)]
+#[tauri::command]
+pub async fn debug_get_chaos() -> Result<crate::chaos::ChaosConfig, String> {
+    Ok(crate::chaos::config())
+}
+
+/// Accepts the same `key=value` spec as GOCONNECT_CHAOS; an empty spec disables injection
+#[cfg(debug_assertions)]
+#[tauri::command]
+pub async fn debug_set_chaos(spec: String) -> Result<crate::chaos::ChaosConfig, String> {
+    let config = if spec.trim().is_empty() {
+        crate::chaos::ChaosConfig::default()
+    } else {
+        crate::chaos::ChaosConfig::parse(&spec)?
+    };
+    crate::chaos::set_config(config.clone())?;
+    Ok(config)
 }
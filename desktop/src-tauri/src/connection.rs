@@ -0,0 +1,239 @@
+// Connection Lifecycle State Machine
+// Single source of truth for "can we talk to the daemon?" shared by tray, commands and UI
+
+use crate::daemon::DaemonError;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::watch;
+use tonic::Code;
+use ts_rs::TS;
+
+/// Tauri event emitted on every state transition
+pub const STATE_CHANGED_EVENT: &str = "connection://state-changed";
+
+/// Failed reconnect attempts before we consider the daemon gone
+const RECONNECT_ATTEMPTS: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ConnectionState {
+    /// Daemon not running (or its token file is missing)
+    NoDaemon,
+    /// First contact in progress
+    Connecting,
+    /// Daemon is up but rejected our IPC token
+    AuthFailed,
+    /// Daemon reachable and answering
+    Ready,
+    /// Daemon reachable but some calls are failing
+    Degraded,
+    /// Lost a previously working connection, retrying
+    Reconnecting,
+}
+
+impl ConnectionState {
+    /// Whether the daemon process is known to be up, regardless of call health
+    pub fn is_daemon_running(self) -> bool {
+        matches!(self, Self::Ready | Self::Degraded | Self::AuthFailed)
+    }
+
+    /// Whether RPCs are expected to succeed
+    pub fn is_usable(self) -> bool {
+        matches!(self, Self::Ready | Self::Degraded)
+    }
+
+    /// Short human-readable label used by the tray
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::NoDaemon => "Daemon Stopped",
+            Self::Connecting => "Connecting...",
+            Self::AuthFailed => "Authentication Failed",
+            Self::Ready => "Ready",
+            Self::Degraded => "Degraded",
+            Self::Reconnecting => "Reconnecting...",
+        }
+    }
+}
+
+/// Inputs that drive the state machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// A connection attempt is starting
+    Probe,
+    /// A call to the daemon succeeded
+    Succeeded,
+    /// The daemon could not be reached at all
+    Unreachable,
+    /// The daemon rejected our credentials
+    AuthRejected,
+    /// The daemon answered with an error
+    CallFailed,
+}
+
+impl ConnectionEvent {
+    /// Classify a client error into a lifecycle event
+    pub fn from_error(error: &DaemonError) -> Self {
+        match error {
+            DaemonError::TokenNotFound(_) | DaemonError::Connection(_) => Self::Unreachable,
+            DaemonError::Rpc(status) => match status.code() {
+                Code::Unavailable => Self::Unreachable,
+                Code::Unauthenticated | Code::PermissionDenied => Self::AuthRejected,
+                Code::Internal
+                | Code::Unknown
+                | Code::DeadlineExceeded
+                | Code::ResourceExhausted
+                | Code::Aborted
+                | Code::DataLoss => Self::CallFailed,
+                // The daemon answered; only the request itself was rejected
+                _ => Self::Succeeded,
+            },
+            DaemonError::InvalidResponse(_) => Self::CallFailed,
+        }
+    }
+
+    fn reason(self) -> &'static str {
+        match self {
+            Self::Probe => "connection attempt started",
+            Self::Succeeded => "daemon responded",
+            Self::Unreachable => "daemon unreachable",
+            Self::AuthRejected => "IPC token rejected",
+            Self::CallFailed => "daemon call failed",
+        }
+    }
+}
+
+/// Snapshot broadcast to subscribers after each transition
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ConnectionSnapshot {
+    pub state: ConnectionState,
+    pub previous: Option<ConnectionState>,
+    pub reason: String,
+    /// Unix timestamp (seconds) of the last transition
+    #[ts(type = "number")]
+    pub since: u64,
+}
+
+struct Machine {
+    state: ConnectionState,
+    failed_reconnects: u32,
+}
+
+impl Machine {
+    fn next(&mut self, event: ConnectionEvent) -> ConnectionState {
+        use ConnectionEvent as E;
+        use ConnectionState as S;
+
+        let next = match (self.state, event) {
+            (_, E::Succeeded) => S::Ready,
+            (_, E::AuthRejected) => S::AuthFailed,
+
+            (S::NoDaemon | S::AuthFailed, E::Probe) => S::Connecting,
+            (state, E::Probe) => state,
+
+            (S::Ready | S::Degraded, E::Unreachable) => S::Reconnecting,
+            (S::Reconnecting, E::Unreachable) => {
+                self.failed_reconnects += 1;
+                if self.failed_reconnects < RECONNECT_ATTEMPTS {
+                    S::Reconnecting
+                } else {
+                    S::NoDaemon
+                }
+            }
+            (_, E::Unreachable) => S::NoDaemon,
+
+            (S::AuthFailed, E::CallFailed) => S::AuthFailed,
+            (_, E::CallFailed) => S::Degraded,
+        };
+
+        if next != S::Reconnecting {
+            self.failed_reconnects = 0;
+        }
+        next
+    }
+}
+
+/// Owns the state machine and broadcasts transitions via a watch channel
+pub struct ConnectionMonitor {
+    machine: Mutex<Machine>,
+    tx: watch::Sender<ConnectionSnapshot>,
+    /// Times a working connection was lost and reconnecting began
+    reconnects: AtomicU64,
+}
+
+impl Default for ConnectionMonitor {
+    fn default() -> Self {
+        let (tx, _) = watch::channel(ConnectionSnapshot {
+            state: ConnectionState::NoDaemon,
+            previous: None,
+            reason: "not yet probed".to_string(),
+            since: unix_now(),
+        });
+        Self {
+            machine: Mutex::new(Machine { state: ConnectionState::NoDaemon, failed_reconnects: 0 }),
+            tx,
+            reconnects: AtomicU64::new(0),
+        }
+    }
+}
+
+impl ConnectionMonitor {
+    /// Current state
+    pub fn state(&self) -> ConnectionState {
+        self.machine.lock().unwrap().state
+    }
+
+    /// Latest snapshot, including the reason for the last transition
+    pub fn snapshot(&self) -> ConnectionSnapshot {
+        self.tx.borrow().clone()
+    }
+
+    /// Receive a snapshot on every transition
+    pub fn subscribe(&self) -> watch::Receiver<ConnectionSnapshot> {
+        self.tx.subscribe()
+    }
+
+    /// Reconnect episodes since startup
+    pub fn reconnect_count(&self) -> u64 {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+
+    /// Feed an event into the machine. Returns the resulting state.
+    pub fn apply(&self, event: ConnectionEvent) -> ConnectionState {
+        let mut machine = self.machine.lock().unwrap();
+        let previous = machine.state;
+        let next = machine.next(event);
+        machine.state = next;
+
+        if next != previous {
+            if next == ConnectionState::Reconnecting {
+                self.reconnects.fetch_add(1, Ordering::Relaxed);
+            }
+            // send_replace never fails, even with no subscribers
+            self.tx.send_replace(ConnectionSnapshot {
+                state: next,
+                previous: Some(previous),
+                reason: event.reason().to_string(),
+                since: unix_now(),
+            });
+        }
+        next
+    }
+
+    /// Feed the outcome of a daemon call into the machine
+    pub fn record<T>(&self, result: &Result<T, DaemonError>) -> ConnectionState {
+        match result {
+            Ok(_) => self.apply(ConnectionEvent::Succeeded),
+            Err(e) => self.apply(ConnectionEvent::from_error(e)),
+        }
+    }
+}
+
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
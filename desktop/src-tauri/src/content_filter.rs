@@ -0,0 +1,178 @@
+// Content Filter
+// User-maintained lists of terms masked with asterisks in chat notification
+// previews (desktop toasts and the inbox) and, when enabled, in the prefetched
+// message cache. One list applies everywhere and each network can add its own.
+// Terms match case-insensitively as whole words or phrases, so "ass" does not
+// mask "class". Messages themselves are never changed on the daemon.
+
+use crate::notifications::NotificationKind;
+use crate::store::JsonStore;
+use std::collections::BTreeMap;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const FILTER_FILE: &str = "content_filter.json";
+
+const MAX_TERMS: usize = 500;
+const MAX_TERM_LEN: usize = 60;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct ContentFilterSettings {
+    pub enabled: bool,
+    /// Also mask message bodies kept in the prefetch cache
+    pub filter_cached_messages: bool,
+    /// Terms masked in every network
+    pub global_terms: Vec<String>,
+    /// Extra terms per network id
+    pub network_terms: BTreeMap<String, Vec<String>>,
+}
+
+/// Managed state holding the persisted filter lists
+pub struct ContentFilterState {
+    settings: JsonStore<ContentFilterSettings>,
+}
+
+impl ContentFilterState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, FILTER_FILE) }
+    }
+
+    /// `text` with every listed term for `network_id` masked
+    pub fn mask(&self, network_id: Option<&str>, text: &str) -> String {
+        let settings = self.settings.get();
+        if !settings.enabled {
+            return text.to_string();
+        }
+        let network_terms = network_id.and_then(|id| settings.network_terms.get(id));
+        let terms = settings.global_terms.iter().chain(network_terms.into_iter().flatten());
+        mask_terms(text, terms)
+    }
+
+    /// Whether `text` contains a listed term for `network_id`
+    pub fn flags(&self, network_id: &str, text: &str) -> bool {
+        self.mask(Some(network_id), text) != text
+    }
+}
+
+/// Lowercase one char to one char, so positions stay aligned with the original
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn mask_terms<'a>(text: &str, terms: impl Iterator<Item = &'a String>) -> String {
+    let original: Vec<char> = text.chars().collect();
+    let folded: Vec<char> = original.iter().copied().map(fold).collect();
+    let mut masked = vec![false; original.len()];
+    let is_word = |i: usize| folded.get(i).is_some_and(|c| c.is_alphanumeric());
+
+    for term in terms {
+        let term: Vec<char> = term.chars().map(fold).collect();
+        if term.is_empty() || term.len() > folded.len() {
+            continue;
+        }
+        for start in 0..=folded.len() - term.len() {
+            let end = start + term.len();
+            let boundary = (start == 0 || !is_word(start - 1)) && !is_word(end);
+            if boundary && folded[start..end] == term[..] {
+                masked[start..end].iter_mut().for_each(|m| *m = true);
+            }
+        }
+    }
+    original
+        .iter()
+        .zip(masked)
+        .map(|(&c, masked)| if masked && !c.is_whitespace() { '*' } else { c })
+        .collect()
+}
+
+/// Mask a notification body if it previews a chat message
+pub(crate) fn mask_preview(app: &AppHandle, kind: NotificationKind, network_id: Option<&str>, body: &str) -> String {
+    match (kind, app.try_state::<ContentFilterState>()) {
+        (NotificationKind::Message, Some(state)) => state.mask(network_id, body),
+        _ => body.to_string(),
+    }
+}
+
+/// Mask a message body on its way into the local cache, if the user asked for that
+pub(crate) fn mask_cached(app: &AppHandle, network_id: &str, body: &str) -> Option<String> {
+    let state = app.try_state::<ContentFilterState>()?;
+    if !state.settings.get().filter_cached_messages {
+        return None;
+    }
+    Some(state.mask(Some(network_id), body)).filter(|masked| masked != body)
+}
+
+/// Trimmed, de-duplicated terms; errors on ones too long to be sensible
+fn normalize_terms(terms: Vec<String>) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for term in terms {
+        let term = term.trim().to_string();
+        if term.chars().count() > MAX_TERM_LEN {
+            return Err(format!("terms can be at most {} characters", MAX_TERM_LEN));
+        }
+        if !term.is_empty() && !normalized.iter().any(|t| t.to_lowercase() == term.to_lowercase()) {
+            normalized.push(term);
+        }
+    }
+    if normalized.len() > MAX_TERMS {
+        return Err(format!("a list can hold at most {} terms", MAX_TERMS));
+    }
+    Ok(normalized)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_content_filter(state: State<'_, ContentFilterState>) -> Result<ContentFilterSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_content_filter_options(
+    state: State<'_, ContentFilterState>,
+    enabled: bool,
+    filter_cached_messages: bool,
+) -> Result<ContentFilterSettings, String> {
+    state.settings.update(|settings| {
+        settings.enabled = enabled;
+        settings.filter_cached_messages = filter_cached_messages;
+        settings.clone()
+    })
+}
+
+/// Replace the list for `network_id`, or the global list when it is omitted.
+/// An empty list removes a network's own list.
+#[tauri::command]
+pub async fn set_content_filter_terms(
+    state: State<'_, ContentFilterState>,
+    network_id: Option<String>,
+    terms: Vec<String>,
+) -> Result<ContentFilterSettings, String> {
+    let terms = normalize_terms(terms)?;
+    state.settings.update(|settings| {
+        match network_id {
+            None => settings.global_terms = terms,
+            Some(id) if terms.is_empty() => {
+                settings.network_terms.remove(&id);
+            }
+            Some(id) => {
+                settings.network_terms.insert(id, terms);
+            }
+        }
+        settings.clone()
+    })
+}
+
+/// How `text` would look in a notification from `network_id`, for trying out the lists
+#[tauri::command]
+pub async fn preview_content_filter(
+    state: State<'_, ContentFilterState>,
+    network_id: Option<String>,
+    text: String,
+) -> Result<String, String> {
+    Ok(state.mask(network_id.as_deref(), &text))
+}
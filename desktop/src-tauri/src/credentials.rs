@@ -0,0 +1,209 @@
+// Credentials Monitor
+// Keeps an eye on the credentials the desktop depends on: the IPC token it reads
+// from the daemon's token file, which the daemon rotates well before it expires,
+// and the account tokens the daemon holds (listed by `get_credentials`; older
+// daemons list none). Each is classed as valid, expiring soon, expired or
+// rejected. The check runs every hour; a credential that becomes expiring or
+// expired raises `STATUS_EVENT` and a notification once, ahead of sign-in or
+// connections starting to fail.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::{unix_now, ConnectionState};
+use crate::daemon::{CredentialInfo, DaemonClient};
+use crate::endpoint;
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager};
+use ts_rs::TS;
+
+/// Emitted with the `CredentialsStatus` when any credential changes state
+pub const STATUS_EVENT: &str = "credentials://status";
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Warn this long before expiry at most...
+const MAX_WARNING_SECS: u64 = 7 * 24 * 60 * 60;
+/// ...or for the last fifth of a shorter lifetime, so hourly tokens do not warn all the time
+const WARNING_FRACTION: u64 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum CredentialState {
+    Valid,
+    ExpiringSoon,
+    Expired,
+    /// Refused by the daemon, or missing
+    Rejected,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CredentialStatus {
+    /// "ipc_token", "account_token" or "refresh_token"
+    pub kind: String,
+    pub name: String,
+    pub state: CredentialState,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number | null")]
+    pub issued_at: Option<u64>,
+    /// Unix timestamp (seconds); `None` when it does not expire
+    #[ts(type = "number | null")]
+    pub expires_at: Option<u64>,
+    /// What to tell the user, unless valid
+    pub warning: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CredentialsStatus {
+    pub credentials: Vec<CredentialStatus>,
+    /// Why the daemon's own credentials are missing from the list, if they are
+    pub daemon_error: Option<String>,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub checked_at: u64,
+}
+
+/// Managed state holding the state each credential had at the last check
+#[derive(Default)]
+pub struct CredentialsState {
+    last: Mutex<HashMap<(String, String), CredentialState>>,
+}
+
+fn describe_kind(kind: &str) -> &str {
+    match kind {
+        "ipc_token" => "The daemon access token",
+        "account_token" => "The account sign-in",
+        "refresh_token" => "The saved account session",
+        _ => "A credential",
+    }
+}
+
+fn classify(credential: CredentialInfo, now: u64) -> CredentialStatus {
+    let what = describe_kind(&credential.kind);
+    let (state, warning) = match credential.expires_at {
+        Some(expires) if expires <= now => (CredentialState::Expired, Some(format!("{} has expired", what))),
+        Some(expires) => {
+            let lifetime = credential.issued_at.map(|issued| expires.saturating_sub(issued));
+            let window = lifetime.map_or(MAX_WARNING_SECS, |l| (l / WARNING_FRACTION).min(MAX_WARNING_SECS));
+            let left = expires - now;
+            if left <= window {
+                let hours = left.div_ceil(3600);
+                (CredentialState::ExpiringSoon, Some(format!("{} expires in {} hour(s)", what, hours)))
+            } else {
+                (CredentialState::Valid, None)
+            }
+        }
+        None => (CredentialState::Valid, None),
+    };
+    CredentialStatus {
+        kind: credential.kind,
+        name: credential.name,
+        state,
+        issued_at: credential.issued_at,
+        expires_at: credential.expires_at,
+        warning,
+    }
+}
+
+/// The IPC token as seen from here: the token file, and whether the daemon took it
+fn ipc_token(daemon: &DaemonState, reported: Option<CredentialInfo>, now: u64) -> CredentialStatus {
+    let path = endpoint::active().token_path.map(PathBuf::from).or_else(|| DaemonClient::default_token_path().ok());
+    let modified = path
+        .as_ref()
+        .and_then(|p| std::fs::metadata(p).and_then(|m| m.modified()).ok())
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs());
+    let name = path.map(|p| p.to_string_lossy().into_owned()).unwrap_or_default();
+    let credential = reported.unwrap_or(CredentialInfo {
+        kind: "ipc_token".to_string(),
+        name: name.clone(),
+        issued_at: modified,
+        expires_at: None,
+    });
+    let rejected = match modified {
+        None => Some("The daemon access token file is missing; is the daemon running?"),
+        Some(_) if daemon.connection.state() == ConnectionState::AuthFailed => {
+            Some("The daemon refused its access token; restart the daemon or check the token file")
+        }
+        Some(_) => None,
+    };
+    let mut status = classify(credential, now);
+    status.name = name;
+    if let Some(warning) = rejected {
+        status.state = CredentialState::Rejected;
+        status.warning = Some(warning.to_string());
+    }
+    status
+}
+
+async fn collect(daemon: &DaemonState) -> CredentialsStatus {
+    let now = unix_now();
+    let (mut reported, daemon_error) =
+        match daemon_call!(daemon, "get_credentials", |client| client.get_credentials()) {
+            Ok(list) => (list, None),
+            Err(e) => (Vec::new(), Some(e)),
+        };
+    let reported_ipc = reported.iter().position(|c| c.kind == "ipc_token").map(|i| reported.remove(i));
+
+    let mut credentials = vec![ipc_token(daemon, reported_ipc, now)];
+    credentials.extend(reported.into_iter().map(|c| classify(c, now)));
+    CredentialsStatus { credentials, daemon_error, checked_at: now }
+}
+
+/// Check every credential and warn about those that just started expiring or expired
+async fn check(app: &AppHandle) -> CredentialsStatus {
+    let status = collect(&app.state::<DaemonState>()).await;
+
+    let state = app.state::<CredentialsState>();
+    let mut changed = false;
+    {
+        let mut last = state.last.lock().unwrap();
+        let previous = std::mem::take(&mut *last);
+        for credential in &status.credentials {
+            let key = (credential.kind.clone(), credential.name.clone());
+            let before = previous.get(&key).copied();
+            last.insert(key, credential.state);
+            if before == Some(credential.state) {
+                continue;
+            }
+            changed = true;
+            let Some(warning) = credential.warning.as_deref() else { continue };
+            log::warn!("{} ({})", warning, credential.name);
+            notifications::notify(app, NotificationKind::System, i18n::text(Text::CredentialAttentionTitle), warning);
+        }
+        changed |= previous.keys().any(|key| !last.contains_key(key));
+    }
+    if changed {
+        let _ = app.emit(STATUS_EVENT, &status);
+    }
+    status
+}
+
+/// Check credentials periodically while the daemon is reachable
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        loop {
+            if daemon.connection.state().is_usable() || daemon.connection.state() == ConnectionState::AuthFailed {
+                check(&app).await;
+            }
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Check every credential now
+#[tauri::command]
+pub async fn get_credentials_status(app: AppHandle) -> Result<CredentialsStatus, String> {
+    Ok(check(&app).await)
+}
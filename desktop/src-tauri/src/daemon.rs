@@ -1,10 +1,17 @@
 // GoConnect Daemon gRPC Client
 // Communicates with the local daemon via gRPC with IPC token authentication
 
+use crate::addressing::VirtualAddresses;
+use crate::endpoint::{self, Endpoint};
+use crate::reputation::ReputationVerdict;
+#[cfg(unix)]
+use hyper_util::rt::TokioIo;
+use std::future::Future;
 use std::path::PathBuf;
 use tonic::transport::Channel;
 use tonic::metadata::MetadataValue;
-use tonic::{Request, Status};
+use tonic::{Request, Response, Status};
+use ts_rs::TS;
 
 // Include generated protobuf code
 pub mod proto {
@@ -21,6 +28,24 @@ use proto::voice_service_client::VoiceServiceClient;
 
 const IPC_TOKEN_HEADER: &str = "x-goconnect-ipc-token";
 
+/// Await a gRPC call and map its failure into a DaemonError.
+/// Debug builds route every call through the fault injector (see `chaos`).
+async fn rpc<T>(call: impl Future<Output = Result<Response<T>, Status>>) -> Result<Response<T>, DaemonError> {
+    #[cfg(debug_assertions)]
+    let result = crate::chaos::intercept(call).await;
+    #[cfg(not(debug_assertions))]
+    let result = call.await;
+
+    result.map_err(DaemonError::Rpc)
+}
+
+/// Compare secrets without short-circuiting on the first differing byte
+#[cfg(any(feature = "ws-bridge", feature = "automation"))]
+pub fn tokens_match(provided: &str, expected: &str) -> bool {
+    let (a, b) = (provided.as_bytes(), expected.as_bytes());
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// DaemonClient wraps gRPC connections to the local GoConnect daemon
 #[derive(Clone)]
 pub struct DaemonClient {
@@ -29,12 +54,22 @@ pub struct DaemonClient {
 }
 
 impl DaemonClient {
-    /// Connect to the daemon with IPC token authentication
+    /// Connect to the configured daemon endpoint with IPC token authentication
     pub async fn connect() -> Result<Self, DaemonError> {
-        let token = Self::load_ipc_token().await?;
-        let endpoint = Self::get_daemon_endpoint();
-        
-        let channel = Channel::from_static(endpoint)
+        Self::connect_to(&endpoint::active()).await
+    }
+
+    /// Connect to a specific endpoint.
+    /// NOTE: Daemon runs BOTH Unix socket and TCP on Linux/macOS. The socket is
+    /// preferred when the endpoint has one; the TCP port is the fallback.
+    pub async fn connect_to(endpoint: &Endpoint) -> Result<Self, DaemonError> {
+        let token = Self::read_token(endpoint).await?;
+
+        if let Some(channel) = Self::connect_socket(endpoint).await {
+            return Ok(Self { channel, token });
+        }
+        let channel = Channel::from_shared(endpoint.url.clone())
+            .map_err(|e| DaemonError::Connection(e.to_string()))?
             .connect()
             .await
             .map_err(|e| DaemonError::Connection(e.to_string()))?;
@@ -42,23 +77,45 @@ impl DaemonClient {
         Ok(Self { channel, token })
     }
 
-    /// Get the platform-specific daemon endpoint
-    /// NOTE: Daemon runs BOTH Unix socket (for CLI) and TCP (for Desktop) on Linux/macOS.
-    /// This uses the TCP endpoint which the daemon starts specifically for Desktop compatibility.
-    fn get_daemon_endpoint() -> &'static str {
-        #[cfg(target_os = "windows")]
-        {
-            "http://127.0.0.1:34101"
+    /// Open the endpoint's Unix socket; `None` when it has none, or it is
+    /// missing or refuses, so the caller falls back to TCP
+    #[cfg(unix)]
+    async fn connect_socket(endpoint: &Endpoint) -> Option<Channel> {
+        let path = PathBuf::from(endpoint.socket_path.as_ref()?);
+        if !path.exists() {
+            return None;
         }
-        #[cfg(not(target_os = "windows"))]
-        {
-            "http://127.0.0.1:34101" // Daemon provides TCP fallback for Desktop on all platforms
+        let target = path.clone();
+        let connector = tower::service_fn(move |_: tonic::transport::Uri| {
+            let path = target.clone();
+            async move { Ok::<_, std::io::Error>(TokioIo::new(tokio::net::UnixStream::connect(path).await?)) }
+        });
+        // tonic wants a URI, but the connector alone decides where to connect
+        match tonic::transport::Endpoint::from_static("http://localhost").connect_with_connector(connector).await {
+            Ok(channel) => Some(channel),
+            Err(e) => {
+                log::debug!("Daemon socket {:?} unusable, falling back to TCP: {}", path, e);
+                None
+            }
         }
     }
 
-    /// Load IPC auth token from the token file
-    async fn load_ipc_token() -> Result<String, DaemonError> {
-        let token_path = Self::get_token_path()?;
+    #[cfg(not(unix))]
+    async fn connect_socket(_endpoint: &Endpoint) -> Option<Channel> {
+        None
+    }
+
+    /// Load IPC auth token for the active endpoint
+    #[cfg_attr(not(feature = "ws-bridge"), allow(dead_code))]
+    pub(crate) async fn load_ipc_token() -> Result<String, DaemonError> {
+        Self::read_token(&endpoint::active()).await
+    }
+
+    async fn read_token(endpoint: &Endpoint) -> Result<String, DaemonError> {
+        let token_path = match &endpoint.token_path {
+            Some(path) => PathBuf::from(path),
+            None => Self::default_token_path()?,
+        };
         
         let token = tokio::fs::read_to_string(&token_path)
             .await
@@ -70,7 +127,11 @@ impl DaemonClient {
     }
 
     /// Get platform-specific token path
-    fn get_token_path() -> Result<PathBuf, DaemonError> {
+    pub(crate) fn default_token_path() -> Result<PathBuf, DaemonError> {
+        // A portable daemon keeps its token in the shared portable data folder
+        if let Some(token) = crate::portable::data_dir().map(|dir| dir.join("ipc.token")).filter(|p| p.is_file()) {
+            return Ok(token);
+        }
         #[cfg(target_os = "windows")]
         {
             let local_app_data = dirs::data_local_dir()
@@ -108,14 +169,15 @@ impl DaemonClient {
         let mut client = DaemonServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(proto::GetStatusRequest {}));
         
-        let response = client.get_status(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.get_status(request)).await?;
         
         let status = response.into_inner();
+        let (virtual_ip, virtual_ipv4, virtual_ipv6) = VirtualAddresses::fields(&status.virtual_ip);
         Ok(DaemonStatus {
             connected: status.status == proto::ConnectionStatus::Connected as i32,
-            virtual_ip: status.virtual_ip,
+            virtual_ip,
+            virtual_ipv4,
+            virtual_ipv6,
             active_peers: status.active_peers as u32,
             network_name: status.current_network_name,
         })
@@ -126,9 +188,7 @@ impl DaemonClient {
         let mut client = DaemonServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(()));
         
-        let response = client.get_version(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.get_version(request)).await?;
         
         let v = response.into_inner();
         Ok(VersionInfo {
@@ -138,9 +198,31 @@ impl DaemonClient {
             go_version: v.go_version,
             os: v.os,
             arch: v.arch,
+            daemon_time: v.daemon_time.map(|t| t.seconds.max(0) as u64),
+            server_time: v.server_time.map(|t| t.seconds.max(0) as u64),
         })
     }
 
+    /// List the credentials the daemon holds
+    pub async fn get_credentials(&self) -> Result<Vec<CredentialInfo>, DaemonError> {
+        let mut client = DaemonServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(()));
+
+        let response = rpc(client.get_credentials(request)).await?;
+
+        Ok(response
+            .into_inner()
+            .credentials
+            .into_iter()
+            .map(|c| CredentialInfo {
+                kind: c.kind,
+                name: c.name,
+                issued_at: c.issued_at.map(|t| t.seconds.max(0) as u64),
+                expires_at: c.expires_at.map(|t| t.seconds.max(0) as u64),
+            })
+            .collect())
+    }
+
     // =========================================================================
     // NETWORK SERVICE
     // =========================================================================
@@ -153,9 +235,7 @@ impl DaemonClient {
             description: String::new(),
         }));
         
-        let response = client.create_network(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.create_network(request)).await?;
         
         let resp = response.into_inner();
         let network = resp.network.ok_or_else(|| DaemonError::InvalidResponse("missing network".into()))?;
@@ -164,6 +244,7 @@ impl DaemonClient {
             id: network.id,
             name: network.name,
             invite_code: resp.invite_code,
+            guest_until: None,
         })
     }
 
@@ -174,9 +255,7 @@ impl DaemonClient {
             invite_code: invite_code.to_string(),
         }));
         
-        let response = client.join_network(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.join_network(request)).await?;
         
         let resp = response.into_inner();
         let network = resp.network.ok_or_else(|| DaemonError::InvalidResponse("missing network".into()))?;
@@ -185,6 +264,7 @@ impl DaemonClient {
             id: network.id,
             name: network.name,
             invite_code: String::new(),
+            guest_until: None,
         })
     }
 
@@ -193,9 +273,7 @@ impl DaemonClient {
         let mut client = NetworkServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(()));
         
-        let response = client.list_networks(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.list_networks(request)).await?;
         
         let networks = response.into_inner().networks
             .into_iter()
@@ -203,12 +281,56 @@ impl DaemonClient {
                 id: n.id,
                 name: n.name,
                 invite_code: n.invite_code,
+                guest_until: None,
             })
             .collect();
         
         Ok(networks)
     }
 
+    /// Connection state of every joined network. Only connected networks are
+    /// asked for their peers, to learn this machine's virtual IP there.
+    pub async fn get_network_statuses(&self) -> Result<Vec<NetworkStatus>, DaemonError> {
+        let mut client = NetworkServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(()));
+        let networks = rpc(client.list_networks(request)).await?.into_inner().networks;
+
+        let mut client = DaemonServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::GetStatusRequest {}));
+        let current = rpc(client.get_status(request)).await?.into_inner();
+
+        let mut statuses = Vec::with_capacity(networks.len());
+        for n in networks {
+            let is_current = n.id == current.current_network_id;
+            let raw = if is_current && !current.virtual_ip.is_empty() {
+                Some(current.virtual_ip.clone())
+            } else if n.is_connected {
+                // One network's peers failing to load leaves just its address unknown
+                self.get_network_peers(&n.id)
+                    .await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|p| p.is_self && !p.virtual_ip.is_empty())
+                    .map(|p| [p.virtual_ipv4, p.virtual_ipv6].into_iter().flatten().collect::<Vec<_>>().join(","))
+            } else {
+                None
+            };
+            let addresses = raw.as_deref().map(VirtualAddresses::parse).unwrap_or_default();
+            statuses.push(NetworkStatus {
+                network_id: n.id,
+                name: n.name,
+                connected: n.is_connected,
+                is_current,
+                virtual_ip: addresses.preferred().map(|ip| ip.to_string()).or(raw),
+                virtual_ipv4: addresses.v4.map(|ip| ip.to_string()),
+                virtual_ipv6: addresses.v6.map(|ip| ip.to_string()),
+                peer_count: n.peer_count.max(0) as u32,
+                online_count: n.online_count.max(0) as u32,
+            });
+        }
+        Ok(statuses)
+    }
+
     /// Leave a network
     pub async fn leave_network(&self, network_id: &str) -> Result<(), DaemonError> {
         let mut client = NetworkServiceClient::new(self.channel.clone());
@@ -216,27 +338,88 @@ impl DaemonClient {
             network_id: network_id.to_string(),
         }));
         
-        client.leave_network(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.leave_network(request)).await?;
         
         Ok(())
     }
 
     /// Generate an invite code for a network
     pub async fn generate_invite(&self, network_id: &str) -> Result<String, DaemonError> {
+        // Unlimited uses, no expiry
+        Ok(self.create_invite(network_id, 0, 0).await?.code)
+    }
+
+    /// Generate an invite limited to `max_uses` joins and `expires_hours` (0 = no limit)
+    pub async fn create_invite(
+        &self,
+        network_id: &str,
+        max_uses: u32,
+        expires_hours: u32,
+    ) -> Result<GeneratedInvite, DaemonError> {
         let mut client = NetworkServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(proto::GenerateInviteRequest {
             network_id: network_id.to_string(),
-            max_uses: 0, // Unlimited
-            expires_hours: 0, // No expiry
+            max_uses: max_uses.min(i32::MAX as u32) as i32,
+            expires_hours: expires_hours.min(i32::MAX as u32) as i32,
         }));
 
-        let response = client.generate_invite(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.generate_invite(request)).await?.into_inner();
 
-        Ok(response.into_inner().invite_code)
+        Ok(GeneratedInvite {
+            code: response.invite_code,
+            url: response.invite_url,
+            max_uses,
+            expires_at: response.expires_at.map(|t| t.seconds.max(0) as u64),
+        })
+    }
+
+    /// Describe the network behind an invite code without joining
+    pub async fn preview_invite(&self, invite_code: &str) -> Result<InvitePreview, DaemonError> {
+        let mut client = NetworkServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::PreviewInviteRequest {
+            invite_code: invite_code.to_string(),
+        }));
+
+        let p = rpc(client.preview_invite(request)).await?.into_inner();
+
+        Ok(InvitePreview {
+            valid: p.valid,
+            invalid_reason: if p.invalid_reason.is_empty() { None } else { Some(p.invalid_reason) },
+            network_id: p.network_id,
+            network_name: p.network_name,
+            description: p.description,
+            member_count: p.member_count.max(0) as u32,
+            online_count: p.online_count.max(0) as u32,
+            owner_id: p.owner_id,
+            owner_name: p.owner_name,
+            requires_approval: p.requires_approval,
+            min_trust_level: if p.min_trust_level.is_empty() { None } else { Some(p.min_trust_level) },
+            permissions: p.permissions,
+            expires_at: p.expires_at.map(|t| t.seconds.max(0) as u64),
+            uses_remaining: (p.uses_remaining >= 0).then_some(p.uses_remaining as u32),
+            already_joined: false,
+        })
+    }
+
+    /// Requests waiting for an admin to let them join (admin only)
+    pub async fn list_join_requests(&self, network_id: &str) -> Result<Vec<JoinRequest>, DaemonError> {
+        let mut client = NetworkServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::ListJoinRequestsRequest {
+            network_id: network_id.to_string(),
+        }));
+
+        let response = rpc(client.list_join_requests(request)).await?.into_inner();
+
+        Ok(response
+            .requests
+            .into_iter()
+            .map(|r| JoinRequest {
+                peer_id: r.peer_id,
+                name: r.name,
+                message: if r.message.is_empty() { None } else { Some(r.message) },
+                requested_at: r.requested_at.map(|t| t.seconds.max(0) as u64).unwrap_or_default(),
+            })
+            .collect())
     }
 
     /// Update network properties (owner only)
@@ -247,9 +430,7 @@ impl DaemonClient {
             name: name.to_string(),
         }));
 
-        let response = client.update_network(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.update_network(request)).await?;
 
         let network = response.into_inner();
         Ok(NetworkInfo::from_proto(&network))
@@ -262,9 +443,40 @@ impl DaemonClient {
             network_id: network_id.to_string(),
         }));
 
-        client.delete_network(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.delete_network(request)).await?;
+
+        Ok(())
+    }
+
+    /// Make another member the owner of a network (owner only)
+    pub async fn transfer_ownership(&self, network_id: &str, new_owner_id: &str) -> Result<(), DaemonError> {
+        let mut client = NetworkServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::TransferOwnershipRequest {
+            network_id: network_id.to_string(),
+            new_owner_id: new_owner_id.to_string(),
+        }));
+
+        rpc(client.transfer_ownership(request)).await?;
+
+        Ok(())
+    }
+
+    /// Bring up the tunnel, which carries every joined network
+    pub async fn connect_network(&self) -> Result<(), DaemonError> {
+        let mut client = NetworkServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(()));
+
+        rpc(client.connect_network(request)).await?;
+
+        Ok(())
+    }
+
+    /// Tear down the active tunnel without leaving the network
+    pub async fn disconnect_network(&self) -> Result<(), DaemonError> {
+        let mut client = NetworkServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(()));
+
+        rpc(client.disconnect_network(request)).await?;
 
         Ok(())
     }
@@ -275,32 +487,61 @@ impl DaemonClient {
 
     /// Get list of peers
     pub async fn get_peers(&self) -> Result<Vec<PeerInfo>, DaemonError> {
+        self.get_network_peers("").await // Empty = current network
+    }
+
+    /// Get list of peers of a specific network
+    pub async fn get_network_peers(&self, network_id: &str) -> Result<Vec<PeerInfo>, DaemonError> {
         let mut client = PeerServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(proto::GetPeersRequest {
-            network_id: String::new(), // Empty = current network
+            network_id: network_id.to_string(),
         }));
         
-        let response = client.get_peers(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.get_peers(request)).await?;
         
         let peers = response.into_inner().peers
             .into_iter()
-            .map(|p| PeerInfo {
-                id: p.id,
-                name: p.name,
-                display_name: p.display_name,
-                virtual_ip: p.virtual_ip,
-                connected: p.status == proto::ConnectionStatus::Connected as i32,
-                is_relay: p.connection_type == proto::ConnectionType::Relay as i32,
-                latency_ms: p.latency_ms,
-                is_self: p.is_self,
-            })
+            .map(PeerInfo::from_proto)
             .collect();
         
         Ok(peers)
     }
 
+    /// Stream peer joins and leaves
+    pub async fn subscribe_peer_events(&self) -> Result<PeerEvents, DaemonError> {
+        let mut client = DaemonServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::SubscribeRequest {
+            event_types: vec![proto::EventType::PeerJoined as i32, proto::EventType::PeerLeft as i32],
+        }));
+        let stream = rpc(client.subscribe(request)).await?.into_inner();
+        Ok(PeerEvents { stream })
+    }
+
+    /// Subscribe to peer, chat and transfer-request events in one stream
+    pub async fn subscribe_events(&self) -> Result<DaemonEvents, DaemonError> {
+        let mut client = DaemonServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::SubscribeRequest {
+            event_types: vec![
+                proto::EventType::PeerJoined as i32,
+                proto::EventType::PeerLeft as i32,
+                proto::EventType::ChatMessage as i32,
+                proto::EventType::TransferRequest as i32,
+            ],
+        }));
+        let stream = rpc(client.subscribe(request)).await?.into_inner();
+        Ok(DaemonEvents { stream })
+    }
+
+    /// Subscribe to security events on their own stream
+    pub async fn subscribe_security_events(&self) -> Result<SecurityEvents, DaemonError> {
+        let mut client = DaemonServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::SubscribeRequest {
+            event_types: vec![proto::EventType::Security as i32],
+        }));
+        let stream = rpc(client.subscribe(request)).await?.into_inner();
+        Ok(SecurityEvents { stream })
+    }
+
     /// Kick a peer from a network
     pub async fn kick_peer(&self, network_id: &str, peer_id: &str) -> Result<(), DaemonError> {
         let mut client = PeerServiceClient::new(self.channel.clone());
@@ -310,9 +551,7 @@ impl DaemonClient {
             reason: String::new(),
         }));
         
-        client.kick_peer(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.kick_peer(request)).await?;
         
         Ok(())
     }
@@ -326,13 +565,32 @@ impl DaemonClient {
             reason: reason.to_string(),
         }));
         
-        client.ban_peer(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.ban_peer(request)).await?;
         
         Ok(())
     }
 
+    /// Report a peer to the network's admins, quoting the offending messages
+    pub async fn report_peer(
+        &self,
+        network_id: &str,
+        peer_id: &str,
+        reason: &str,
+        message_ids: &[String],
+    ) -> Result<(), DaemonError> {
+        let mut client = PeerServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::ReportPeerRequest {
+            network_id: network_id.to_string(),
+            peer_id: peer_id.to_string(),
+            reason: reason.to_string(),
+            message_ids: message_ids.to_vec(),
+        }));
+
+        rpc(client.report_peer(request)).await?;
+
+        Ok(())
+    }
+
     /// Unban a peer from a network
     pub async fn unban_peer(&self, network_id: &str, peer_id: &str) -> Result<(), DaemonError> {
         let mut client = PeerServiceClient::new(self.channel.clone());
@@ -341,9 +599,7 @@ impl DaemonClient {
             peer_id: peer_id.to_string(),
         }));
         
-        client.unban_peer(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.unban_peer(request)).await?;
         
         Ok(())
     }
@@ -357,17 +613,9 @@ impl DaemonClient {
         let mut client = SettingsServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(()));
         
-        let response = client.get_settings(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.get_settings(request)).await?;
         
-        let s = response.into_inner();
-        Ok(Settings {
-            auto_connect: s.auto_connect,
-            start_minimized: s.start_minimized,
-            notifications_enabled: s.notifications_enabled,
-            log_level: String::new(), // Not in proto, use default
-        })
+        Ok(Settings::from_proto(response.into_inner()))
     }
 
     /// Update daemon settings
@@ -378,26 +626,18 @@ impl DaemonClient {
                 auto_connect: settings.auto_connect,
                 start_minimized: settings.start_minimized,
                 notifications_enabled: settings.notifications_enabled,
-                auto_accept_files: false,
-                download_path: String::new(),
-                max_upload_speed_kbps: 0,
-                max_download_speed_kbps: 0,
-                theme: String::new(),
-                language: String::new(),
+                auto_accept_files: settings.auto_accept_files,
+                download_path: settings.download_path.clone(),
+                max_upload_speed_kbps: settings.max_upload_speed_kbps.min(i32::MAX as u32) as i32,
+                max_download_speed_kbps: settings.max_download_speed_kbps.min(i32::MAX as u32) as i32,
+                theme: settings.theme.clone(),
+                language: settings.language.clone(),
             }),
         }));
         
-        let response = client.update_settings(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.update_settings(request)).await?;
         
-        let s = response.into_inner();
-        Ok(Settings {
-            auto_connect: s.auto_connect,
-            start_minimized: s.start_minimized,
-            notifications_enabled: s.notifications_enabled,
-            log_level: String::new(),
-        })
+        Ok(Settings::from_proto(response.into_inner()))
     }
 
     /// Reset settings to defaults
@@ -405,17 +645,17 @@ impl DaemonClient {
         let mut client = SettingsServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(()));
         
-        let response = client.reset_settings(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.reset_settings(request)).await?;
         
-        let s = response.into_inner();
-        Ok(Settings {
-            auto_connect: s.auto_connect,
-            start_minimized: s.start_minimized,
-            notifications_enabled: s.notifications_enabled,
-            log_level: String::new(),
-        })
+        Ok(Settings::from_proto(response.into_inner()))
+    }
+
+    /// Follow the settings: the current ones, then every change
+    pub async fn subscribe_settings(&self) -> Result<SettingsEvents, DaemonError> {
+        let mut client = SettingsServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(()));
+        let stream = rpc(client.subscribe_settings(request)).await?.into_inner();
+        Ok(SettingsEvents { stream })
     }
 
     // =========================================================================
@@ -431,26 +671,18 @@ impl DaemonClient {
             before_id: before.unwrap_or_default().to_string(),
         }));
         
-        let response = client.get_messages(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.get_messages(request)).await?;
         
         let messages = response.into_inner().messages
             .into_iter()
-            .map(|m| ChatMessage {
-                id: m.id,
-                peer_id: m.sender_id.clone(),
-                content: m.content,
-                timestamp: m.sent_at.map(|t| t.seconds.to_string()).unwrap_or_default(),
-                is_self: false, // Determine from sender_id comparison if needed
-            })
+            .map(ChatMessage::from_proto)
             .collect();
         
         Ok(messages)
     }
 
-    /// Send a chat message
-    pub async fn send_message(&self, network_id: &str, content: &str) -> Result<(), DaemonError> {
+    /// Send a chat message; returns the message as the daemon stored it
+    pub async fn send_message(&self, network_id: &str, content: &str) -> Result<Option<ChatMessage>, DaemonError> {
         let mut client = ChatServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(proto::SendMessageRequest {
             network_id: network_id.to_string(),
@@ -458,11 +690,9 @@ impl DaemonClient {
             recipient_id: String::new(), // Empty = broadcast to network
         }));
         
-        client.send_message(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.send_message(request)).await?;
         
-        Ok(())
+        Ok(response.into_inner().message.map(ChatMessage::from_proto))
     }
 
     // =========================================================================
@@ -474,35 +704,24 @@ impl DaemonClient {
         let mut client = TransferServiceClient::new(self.channel.clone());
         let request = self.add_auth(Request::new(()));
         
-        let response = client.list_transfers(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.list_transfers(request)).await?;
         
         let transfers = response.into_inner().transfers
             .into_iter()
-            .map(|t| TransferInfo {
-                id: t.id,
-                peer_id: t.peer_id,
-                file_name: t.filename,
-                file_size: t.size_bytes as u64,
-                transferred: t.transferred_bytes as u64,
-                status: match t.status {
-                    0 => "pending".to_string(),
-                    1 => "pending".to_string(),
-                    2 => "active".to_string(),
-                    3 => "completed".to_string(),
-                    4 => "failed".to_string(),
-                    5 => "cancelled".to_string(),
-                    _ => "unknown".to_string(),
-                },
-                direction: if t.is_incoming { "download".to_string() } else { "upload".to_string() },
-                error: if t.error_message.is_empty() { None } else { Some(t.error_message) },
-            })
+            .map(TransferInfo::from_proto)
             .collect();
         
         Ok(transfers)
     }
 
+    /// Stream progress and state changes of every transfer
+    pub async fn subscribe_transfers(&self) -> Result<TransferEvents, DaemonError> {
+        let mut client = TransferServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(()));
+        let stream = rpc(client.subscribe_transfers(request)).await?.into_inner();
+        Ok(TransferEvents { stream })
+    }
+
     /// Get transfer statistics
     pub async fn get_transfer_stats(&self) -> Result<TransferStats, DaemonError> {
         // Note: This would require a new gRPC method. For now, aggregate from list_transfers
@@ -545,13 +764,24 @@ impl DaemonClient {
             transfer_id: transfer_id.to_string(),
         }));
         
-        client.cancel_transfer(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.cancel_transfer(request)).await?;
         
         Ok(())
     }
 
+    /// Cap one transfer's speed; `0` goes back to the limits from the settings
+    pub async fn set_transfer_rate_limit(&self, transfer_id: &str, max_speed_kbps: u32) -> Result<(), DaemonError> {
+        let mut client = TransferServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::SetTransferRateLimitRequest {
+            transfer_id: transfer_id.to_string(),
+            max_speed_kbps: max_speed_kbps.min(i32::MAX as u32) as i32,
+        }));
+
+        rpc(client.set_transfer_rate_limit(request)).await?;
+
+        Ok(())
+    }
+
     /// Reject an incoming transfer
     pub async fn reject_transfer(&self, transfer_id: &str) -> Result<(), DaemonError> {
         let mut client = TransferServiceClient::new(self.channel.clone());
@@ -559,9 +789,7 @@ impl DaemonClient {
             transfer_id: transfer_id.to_string(),
         }));
         
-        client.reject_transfer(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.reject_transfer(request)).await?;
         
         Ok(())
     }
@@ -574,13 +802,44 @@ impl DaemonClient {
             file_path: file_path.to_string(),
         }));
         
-        let response = client.send_file(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        let response = rpc(client.send_file(request)).await?;
         
         Ok(response.into_inner().transfer_id)
     }
 
+    /// List a directory on the daemon's host; an empty path is the daemon user's home
+    pub async fn list_remote_directory(
+        &self,
+        path: &str,
+        include_hidden: bool,
+    ) -> Result<RemoteDirectory, DaemonError> {
+        let mut client = TransferServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::ListDirectoryRequest {
+            path: path.to_string(),
+            include_hidden,
+        }));
+
+        let response = rpc(client.list_directory(request)).await?.into_inner();
+
+        Ok(RemoteDirectory {
+            path: response.path,
+            parent: Some(response.parent).filter(|p| !p.is_empty()),
+            entries: response.entries.into_iter().map(RemoteFileEntry::from_proto).collect(),
+        })
+    }
+
+    /// Describe a path on the daemon's host
+    pub async fn stat_remote_path(&self, path: &str) -> Result<RemoteFileEntry, DaemonError> {
+        let mut client = TransferServiceClient::new(self.channel.clone());
+        let request = self.add_auth(Request::new(proto::StatPathRequest {
+            path: path.to_string(),
+        }));
+
+        let response = rpc(client.stat_path(request)).await?;
+
+        Ok(RemoteFileEntry::from_proto(response.into_inner()))
+    }
+
     /// Accept an incoming transfer
     pub async fn accept_transfer(&self, transfer_id: &str, save_path: &str) -> Result<(), DaemonError> {
         let mut client = TransferServiceClient::new(self.channel.clone());
@@ -589,9 +848,7 @@ impl DaemonClient {
             save_path: save_path.to_string(),
         }));
         
-        client.accept_transfer(request)
-            .await
-            .map_err(|e| DaemonError::Rpc(e))?;
+        rpc(client.accept_transfer(request)).await?;
         
         Ok(())
     }
@@ -601,15 +858,20 @@ impl DaemonClient {
 // DATA TYPES (Rust-friendly versions of proto messages)
 // =============================================================================
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, TS)]
+#[ts(export)]
 pub struct DaemonStatus {
     pub connected: bool,
+    /// Address of the preferred family; see `addressing`
     pub virtual_ip: String,
+    pub virtual_ipv4: Option<String>,
+    pub virtual_ipv6: Option<String>,
     pub active_peers: u32,
     pub network_name: String,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
 pub struct VersionInfo {
     pub version: String,
     pub build_date: String,
@@ -617,36 +879,344 @@ pub struct VersionInfo {
     pub go_version: String,
     pub os: String,
     pub arch: String,
+    /// Unix timestamp (seconds) of the daemon's clock; `None` from older daemons
+    #[ts(type = "number | null")]
+    pub daemon_time: Option<u64>,
+    /// Unix timestamp (seconds) of the coordination server's clock, as known to the daemon
+    #[ts(type = "number | null")]
+    pub server_time: Option<u64>,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CredentialInfo {
+    /// "ipc_token", "account_token" or "refresh_token"
+    pub kind: String,
+    pub name: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number | null")]
+    pub issued_at: Option<u64>,
+    /// Unix timestamp (seconds); `None` when it does not expire
+    #[ts(type = "number | null")]
+    pub expires_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
 pub struct NetworkInfo {
     pub id: String,
     pub name: String,
     pub invite_code: String,
+    /// Joined as a guest: the network is left at this Unix timestamp (seconds); see `guests`
+    #[ts(type = "number | null")]
+    pub guest_until: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct GeneratedInvite {
+    pub code: String,
+    /// Join link; empty when the daemon has none
+    pub url: String,
+    /// 0 = unlimited
+    pub max_uses: u32,
+    /// Unix timestamp (seconds); `None` when it never expires
+    #[ts(type = "number | null")]
+    pub expires_at: Option<u64>,
+}
+
+/// Someone waiting for approval to join a network
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct JoinRequest {
+    pub peer_id: String,
+    pub name: String,
+    pub message: Option<String>,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub requested_at: u64,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+/// What joining an invite code would lead to
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct InvitePreview {
+    pub valid: bool,
+    /// Why the code cannot be used: expired, used up, revoked, unknown
+    pub invalid_reason: Option<String>,
+    pub network_id: String,
+    pub network_name: String,
+    pub description: String,
+    pub member_count: u32,
+    pub online_count: u32,
+    pub owner_id: String,
+    pub owner_name: String,
+    /// Joining waits for an admin to accept
+    pub requires_approval: bool,
+    pub min_trust_level: Option<String>,
+    /// What members may do, e.g. "chat", "file_transfer"
+    pub permissions: Vec<String>,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number | null")]
+    pub expires_at: Option<u64>,
+    /// `None` when unlimited
+    pub uses_remaining: Option<u32>,
+    /// This client is a member already
+    pub already_joined: bool,
+}
+
+/// One joined network's state; several can be connected at once
+#[derive(Debug, Clone, PartialEq, serde::Serialize, TS)]
+#[ts(export)]
+pub struct NetworkStatus {
+    pub network_id: String,
+    pub name: String,
+    pub connected: bool,
+    /// The network `DaemonStatus` reports on
+    pub is_current: bool,
+    /// This machine's address in the network while connected, of the preferred family
+    pub virtual_ip: Option<String>,
+    pub virtual_ipv4: Option<String>,
+    pub virtual_ipv6: Option<String>,
+    pub peer_count: u32,
+    pub online_count: u32,
+}
+
+impl NetworkStatus {
+    /// This machine's addresses in the network, both families
+    pub fn addresses(&self) -> Vec<std::net::IpAddr> {
+        [&self.virtual_ipv4, &self.virtual_ipv6]
+            .into_iter()
+            .filter_map(|ip| ip.as_deref()?.parse().ok())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
 pub struct PeerInfo {
     pub id: String,
     pub name: String,
     pub display_name: String,
+    /// Address of the preferred family; see `addressing`
     pub virtual_ip: String,
+    pub virtual_ipv4: Option<String>,
+    pub virtual_ipv6: Option<String>,
     pub connected: bool,
     pub is_relay: bool,
+    #[ts(type = "number")]
     pub latency_ms: i64,
     pub is_self: bool,
+    /// "owner", "admin", "member", or empty when unknown
+    pub role: String,
+}
+
+impl PeerInfo {
+    fn from_proto(p: proto::Peer) -> Self {
+        let (virtual_ip, virtual_ipv4, virtual_ipv6) = VirtualAddresses::fields(&p.virtual_ip);
+        PeerInfo {
+            id: p.id,
+            name: p.name,
+            display_name: p.display_name,
+            virtual_ip,
+            virtual_ipv4,
+            virtual_ipv6,
+            connected: p.status == proto::ConnectionStatus::Connected as i32,
+            is_relay: p.connection_type == proto::ConnectionType::Relay as i32,
+            latency_ms: p.latency_ms,
+            is_self: p.is_self,
+            role: match proto::NetworkRole::try_from(p.role) {
+                Ok(proto::NetworkRole::Owner) => "owner",
+                Ok(proto::NetworkRole::Admin) => "admin",
+                Ok(proto::NetworkRole::Member) => "member",
+                _ => "",
+            }
+            .to_string(),
+        }
+    }
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// A peer joining or leaving, as pushed by the daemon
+#[derive(Debug, Clone)]
+pub struct PeerChange {
+    pub peer: PeerInfo,
+    pub joined: bool,
+}
+
+/// Peer events from the daemon's event stream
+pub struct PeerEvents {
+    stream: tonic::Streaming<proto::DaemonEvent>,
+}
+
+impl PeerEvents {
+    /// The next peer event; `None` once the daemon closes the stream
+    pub async fn next(&mut self) -> Result<Option<PeerChange>, DaemonError> {
+        while let Some(event) = self.stream.message().await? {
+            if let Some(proto::daemon_event::Payload::PeerEvent(proto::PeerEvent { peer: Some(peer), joined })) =
+                event.payload
+            {
+                return Ok(Some(PeerChange { peer: PeerInfo::from_proto(peer), joined }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// An event pushed by the daemon
+#[derive(Debug, Clone)]
+pub enum DaemonEvent {
+    Peer(PeerChange),
+    Chat { network_id: String, message: ChatMessage },
+    Transfer(TransferInfo),
+}
+
+/// Events from the daemon's event stream, see `subscribe_events`
+pub struct DaemonEvents {
+    stream: tonic::Streaming<proto::DaemonEvent>,
+}
+
+impl DaemonEvents {
+    /// The next event; `None` once the daemon closes the stream
+    pub async fn next(&mut self) -> Result<Option<DaemonEvent>, DaemonError> {
+        use proto::daemon_event::Payload;
+        while let Some(event) = self.stream.message().await? {
+            let event = match event.payload {
+                Some(Payload::PeerEvent(proto::PeerEvent { peer: Some(peer), joined })) => {
+                    DaemonEvent::Peer(PeerChange { peer: PeerInfo::from_proto(peer), joined })
+                }
+                Some(Payload::ChatMessage(message)) => DaemonEvent::Chat {
+                    network_id: message.network_id.clone(),
+                    message: ChatMessage::from_proto(message),
+                },
+                Some(Payload::TransferEvent(proto::TransferEvent { transfer: Some(transfer) })) => {
+                    DaemonEvent::Transfer(TransferInfo::from_proto(transfer))
+                }
+                _ => continue,
+            };
+            return Ok(Some(event));
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SecurityEventKind {
+    DeviceAdded,
+    PeerKeyChanged,
+    IpcAuthFailed,
+    /// A kind this client does not know yet
+    Other,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct SecurityEvent {
+    pub id: String,
+    pub kind: SecurityEventKind,
+    pub message: String,
+    pub peer_id: Option<String>,
+    pub network_id: Option<String>,
+    pub device_name: Option<String>,
+    /// Where a failed IPC call came from, when known
+    pub source: Option<String>,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub timestamp: u64,
+}
+
+/// Settings from the daemon's settings stream
+pub struct SettingsEvents {
+    stream: tonic::Streaming<proto::Settings>,
+}
+
+impl SettingsEvents {
+    /// The next settings; `None` once the daemon closes the stream
+    pub async fn next(&mut self) -> Result<Option<Settings>, DaemonError> {
+        Ok(self.stream.message().await?.map(Settings::from_proto))
+    }
+}
+
+/// Events from a security event subscription, see `subscribe_security_events`
+pub struct SecurityEvents {
+    stream: tonic::Streaming<proto::DaemonEvent>,
+}
+
+impl SecurityEvents {
+    /// The next event; `None` once the daemon closes the stream
+    pub async fn next(&mut self) -> Result<Option<SecurityEvent>, DaemonError> {
+        use proto::daemon_event::Payload;
+        while let Some(event) = self.stream.message().await? {
+            let Some(Payload::SecurityEvent(e)) = event.payload else { continue };
+            let kind = match proto::SecurityEventKind::try_from(e.kind) {
+                Ok(proto::SecurityEventKind::DeviceAdded) => SecurityEventKind::DeviceAdded,
+                Ok(proto::SecurityEventKind::PeerKeyChanged) => SecurityEventKind::PeerKeyChanged,
+                Ok(proto::SecurityEventKind::IpcAuthFailed) => SecurityEventKind::IpcAuthFailed,
+                _ => SecurityEventKind::Other,
+            };
+            let non_empty = |s: String| (!s.is_empty()).then_some(s);
+            return Ok(Some(SecurityEvent {
+                id: e.id,
+                kind,
+                message: e.message,
+                peer_id: non_empty(e.peer_id),
+                network_id: non_empty(e.network_id),
+                device_name: non_empty(e.device_name),
+                source: non_empty(e.source),
+                timestamp: event.timestamp.map(|t| t.seconds.max(0) as u64).unwrap_or_default(),
+            }));
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
 pub struct Settings {
     pub auto_connect: bool,
     pub start_minimized: bool,
     pub notifications_enabled: bool,
     pub log_level: String,
+    /// "system", "light" or "dark"
+    #[serde(default)]
+    pub theme: String,
+    /// Locale code, e.g. "en" or "tr"
+    #[serde(default)]
+    pub language: String,
+    /// Upload speed cap for transfers; 0 for none
+    #[serde(default)]
+    pub max_upload_speed_kbps: u32,
+    /// Download speed cap for transfers; 0 for none
+    #[serde(default)]
+    pub max_download_speed_kbps: u32,
+    /// The daemon accepts incoming files without asking
+    #[serde(default)]
+    pub auto_accept_files: bool,
+    /// Where the daemon saves incoming files; empty for its default
+    #[serde(default)]
+    pub download_path: String,
+}
+
+impl Settings {
+    fn from_proto(s: proto::Settings) -> Self {
+        Self {
+            auto_connect: s.auto_connect,
+            start_minimized: s.start_minimized,
+            notifications_enabled: s.notifications_enabled,
+            log_level: String::new(), // Not in proto, use default
+            theme: s.theme,
+            language: s.language,
+            max_upload_speed_kbps: s.max_upload_speed_kbps.max(0) as u32,
+            max_download_speed_kbps: s.max_download_speed_kbps.max(0) as u32,
+            auto_accept_files: s.auto_accept_files,
+            download_path: s.download_path,
+        }
+    }
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
 pub struct ChatMessage {
     pub id: String,
     pub peer_id: String,
@@ -655,26 +1225,140 @@ pub struct ChatMessage {
     pub is_self: bool,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+impl ChatMessage {
+    fn from_proto(m: proto::ChatMessage) -> Self {
+        Self {
+            id: m.id,
+            peer_id: m.sender_id,
+            content: m.content,
+            timestamp: m.sent_at.map(|t| t.seconds.to_string()).unwrap_or_default(),
+            is_self: false, // Determine from sender_id comparison if needed
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
 pub struct TransferInfo {
     pub id: String,
     pub peer_id: String,
     pub file_name: String,
+    #[ts(type = "number")]
     pub file_size: u64,
+    #[ts(type = "number")]
     pub transferred: u64,
     pub status: String,
     pub direction: String,
     pub error: Option<String>,
+    /// Hex SHA-256 of the sender's file, when the daemon reports one
+    pub sha256: Option<String>,
+    /// The downloaded file was hashed locally and matched `sha256`
+    pub verified: bool,
+    /// Speed cap for this transfer alone; `None` when the settings' limits apply
+    pub max_speed_kbps: Option<u32>,
+    /// Temporary file a download is written to until it is complete and verified
+    pub part_path: Option<String>,
+    /// What the reputation service said about the received file, when it was asked
+    pub reputation: Option<ReputationVerdict>,
+}
+
+impl TransferInfo {
+    fn from_proto(t: proto::FileTransfer) -> Self {
+        TransferInfo {
+            id: t.id,
+            peer_id: t.peer_id,
+            file_name: t.filename,
+            file_size: t.size_bytes as u64,
+            transferred: t.transferred_bytes as u64,
+            status: match t.status {
+                0 => "pending".to_string(),
+                1 => "pending".to_string(),
+                2 => "active".to_string(),
+                3 => "completed".to_string(),
+                4 => "failed".to_string(),
+                5 => "cancelled".to_string(),
+                _ => "unknown".to_string(),
+            },
+            direction: if t.is_incoming { "download".to_string() } else { "upload".to_string() },
+            error: if t.error_message.is_empty() { None } else { Some(t.error_message) },
+            sha256: Some(t.sha256.to_lowercase()).filter(|h| !h.is_empty()),
+            verified: false,
+            max_speed_kbps: (t.max_speed_kbps > 0).then_some(t.max_speed_kbps as u32),
+            part_path: None,
+            reputation: None,
+        }
+    }
+}
+
+/// A file or directory on the daemon's host
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct RemoteFileEntry {
+    pub name: String,
+    /// Absolute path on the daemon's host
+    pub path: String,
+    pub is_dir: bool,
+    #[ts(type = "number")]
+    pub size_bytes: u64,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number | null")]
+    pub modified_at: Option<u64>,
+    /// The daemon can open it
+    pub readable: bool,
+}
+
+impl RemoteFileEntry {
+    fn from_proto(e: proto::RemoteFileEntry) -> Self {
+        RemoteFileEntry {
+            name: e.name,
+            path: e.path,
+            is_dir: e.is_dir,
+            size_bytes: e.size_bytes.max(0) as u64,
+            modified_at: e.modified_at.map(|t| t.seconds.max(0) as u64),
+            readable: e.readable,
+        }
+    }
+}
+
+/// A directory listing from the daemon's host
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct RemoteDirectory {
+    /// Absolute path, as resolved by the daemon
+    pub path: String,
+    /// `None` at the root
+    pub parent: Option<String>,
+    pub entries: Vec<RemoteFileEntry>,
+}
+
+/// Transfer updates from the daemon's transfer stream
+pub struct TransferEvents {
+    stream: tonic::Streaming<proto::TransferEvent>,
+}
+
+impl TransferEvents {
+    /// The next transfer update; `None` once the daemon closes the stream
+    pub async fn next(&mut self) -> Result<Option<TransferInfo>, DaemonError> {
+        while let Some(event) = self.stream.message().await? {
+            if let Some(transfer) = event.transfer {
+                return Ok(Some(TransferInfo::from_proto(transfer)));
+            }
+        }
+        Ok(None)
+    }
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
 pub struct TransferStats {
     pub total_uploads: u32,
     pub total_downloads: u32,
     pub active_transfers: u32,
     pub completed_transfers: u32,
     pub failed_transfers: u32,
+    #[ts(type = "number")]
     pub total_bytes_sent: u64,
+    #[ts(type = "number")]
     pub total_bytes_received: u64,
 }
 
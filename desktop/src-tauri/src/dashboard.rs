@@ -0,0 +1,105 @@
+// Dashboard Summary
+// One round trip for everything the dashboard shows on mount, gathered concurrently
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::ConnectionSnapshot;
+use crate::daemon::{ChatMessage, DaemonStatus, TransferInfo};
+use crate::notifications::{NotificationCenter, NotificationRecord};
+use std::collections::HashMap;
+use tauri::State;
+use ts_rs::TS;
+
+const TOP_PEERS: usize = 5;
+const RECENT_MESSAGES: i32 = 5;
+const RECENT_EVENTS: usize = 10;
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerTraffic {
+    pub peer_id: String,
+    pub name: String,
+    /// Bytes moved in either direction across all known transfers
+    #[ts(type = "number")]
+    pub bytes: u64,
+    pub transfers: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct DashboardSummary {
+    /// `None` while the daemon cannot be queried
+    pub status: Option<DaemonStatus>,
+    pub connection: ConnectionSnapshot,
+    pub top_peers: Vec<PeerTraffic>,
+    /// Latest messages of the connected network, oldest first
+    pub recent_messages: Vec<ChatMessage>,
+    pub active_transfers: Vec<TransferInfo>,
+    /// Newest first
+    pub recent_events: Vec<NotificationRecord>,
+}
+
+/// Sections fail independently so one unsupported RPC does not blank the dashboard
+#[tauri::command]
+pub async fn get_dashboard_summary(
+    daemon: State<'_, DaemonState>,
+    notifications: State<'_, NotificationCenter>,
+) -> Result<DashboardSummary, String> {
+    let (status, networks, peers, transfers) = tokio::join!(
+        daemon.probe_status(),
+        async { daemon_call!(daemon, "list_networks", |client| client.list_networks()) },
+        async { daemon_call!(daemon, "get_peers", |client| client.get_peers()) },
+        async { daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None)) },
+    );
+    let status = status.ok();
+    let transfers = transfers.unwrap_or_default();
+
+    // Status only carries the network name, so resolve the id through the network list
+    let current_network = status
+        .as_ref()
+        .filter(|s| s.connected)
+        .and_then(|s| networks.ok()?.into_iter().find(|n| n.name == s.network_name));
+    let recent_messages = match current_network {
+        Some(network) => daemon_call!(daemon, "get_messages", |client| client.get_messages(
+            &network.id,
+            RECENT_MESSAGES,
+            None
+        ))
+        .unwrap_or_default(),
+        None => Vec::new(),
+    };
+
+    let names: HashMap<String, String> = peers
+        .unwrap_or_default()
+        .into_iter()
+        .map(|p| (p.id, if p.display_name.is_empty() { p.name } else { p.display_name }))
+        .collect();
+    let mut traffic: HashMap<&str, PeerTraffic> = HashMap::new();
+    for t in &transfers {
+        let entry = traffic.entry(&t.peer_id).or_insert_with(|| PeerTraffic {
+            peer_id: t.peer_id.clone(),
+            name: names.get(&t.peer_id).cloned().unwrap_or_else(|| t.peer_id.clone()),
+            bytes: 0,
+            transfers: 0,
+        });
+        entry.bytes += t.transferred;
+        entry.transfers += 1;
+    }
+    let mut top_peers: Vec<PeerTraffic> = traffic.into_values().collect();
+    top_peers.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+    top_peers.truncate(TOP_PEERS);
+
+    let active_transfers = transfers
+        .iter()
+        .filter(|t| matches!(t.status.as_str(), "pending" | "active"))
+        .cloned()
+        .collect();
+
+    Ok(DashboardSummary {
+        status,
+        connection: daemon.connection.snapshot(),
+        top_peers,
+        recent_messages,
+        active_transfers,
+        recent_events: notifications.recent(RECENT_EVENTS),
+    })
+}
@@ -0,0 +1,673 @@
+// Delta Transfers
+// rsync-style re-sends for large synced files: the receiving client hashes the
+// blocks of its current copy, and only blocks the sender cannot match cross the
+// wire. Both sides talk over the tunnel on DELTA_PORT:
+//   sender   -> {"version","file_name","size","block_size"}\n
+//   receiver -> {"ok":true}\n <u32 count> (<u32 weak> <32-byte sha256>)*   or {"ok":false,"reason"}\n
+//   sender   -> ('C' <u32 block> | 'D' <u32 len> <bytes> | 'Z' <u32 raw len> <u32 len> <zstd>)*
+//               'E' <32-byte sha256 of the file>
+//   receiver -> {"ok":true}\n   or {"ok":false,"reason"}\n
+// The sender offers "compression" in the header for compressible file types; 'Z'
+// frames are only used when the receiver's first reply echoes it back.
+// Whenever a delta is not possible (peer without the listener, no basis copy,
+// local edits on the receiver, any error) the caller falls back to a full transfer.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use crate::sync;
+use crate::tunnel;
+use crate::versions::{self, VersionSource};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "delta.json";
+const PROTOCOL_VERSION: u32 = 1;
+
+pub const DELTA_PORT: u16 = 34130;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Applying a delta and versioning the old copy can take a while for big files
+const IO_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Literal runs are sent in frames of at most this size
+const MAX_LITERAL: usize = 64 * 1024;
+/// Read-ahead used while scanning the new file
+const READ_CHUNK: usize = 256 * 1024;
+const MAX_HEADER_BYTES: u64 = 4096;
+
+const ZSTD: &str = "zstd";
+
+/// Formats that are already compressed; zstd would only cost CPU on these
+const INCOMPRESSIBLE_EXTENSIONS: &[&str] = &[
+    "zip", "gz", "tgz", "bz2", "xz", "zst", "7z", "rar", "jpg", "jpeg", "png", "gif", "webp", "heic", "avif",
+    "mp3", "aac", "ogg", "opus", "flac", "m4a", "mp4", "mkv", "mov", "webm", "avi", "docx", "xlsx", "pptx",
+    "odt", "epub", "jar", "apk", "dmg", "pdf",
+];
+
+const MAX_RECORDS: usize = 100;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct DeltaSettings {
+    /// Try deltas when re-sending, and accept them from peers
+    pub enabled: bool,
+    /// Smaller files are always sent whole
+    #[ts(type = "number")]
+    pub min_size_mb: u64,
+    pub block_size_kib: u32,
+    /// zstd level for changed data of compressible files; 0 sends it raw
+    pub compression_level: i32,
+}
+
+impl Default for DeltaSettings {
+    fn default() -> Self {
+        Self { enabled: true, min_size_mb: 8, block_size_kib: 64, compression_level: 3 }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct DeltaRecord {
+    pub peer_id: String,
+    pub file_name: String,
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Bytes that crossed the wire; `None` when it fell back to a full transfer
+    #[ts(type = "number | null")]
+    pub sent_bytes: Option<u64>,
+    /// Changed data before compression
+    #[ts(type = "number | null")]
+    pub raw_bytes: Option<u64>,
+    /// Changed data as sent, after compression
+    #[ts(type = "number | null")]
+    pub compressed_bytes: Option<u64>,
+    pub fallback_reason: Option<String>,
+    #[ts(type = "number")]
+    pub finished_at: u64,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+struct DeltaData {
+    settings: DeltaSettings,
+    records: Vec<DeltaRecord>,
+}
+
+/// Managed state holding delta settings and recent outcomes
+pub struct DeltaState {
+    data: JsonStore<DeltaData>,
+    changed: tokio::sync::Notify,
+}
+
+impl DeltaState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { data: JsonStore::open(app, SETTINGS_FILE), changed: tokio::sync::Notify::new() }
+    }
+
+    fn record(&self, record: DeltaRecord) {
+        let result = self.data.update(|data| {
+            data.records.push(record);
+            let excess = data.records.len().saturating_sub(MAX_RECORDS);
+            data.records.drain(..excess);
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record delta transfer: {}", e);
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Header {
+    version: u32,
+    file_name: String,
+    size: u64,
+    block_size: u32,
+    /// Offered by the sender; older peers ignore it
+    #[serde(default)]
+    compression: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Reply {
+    ok: bool,
+    #[serde(default)]
+    reason: Option<String>,
+    /// Compression the receiver accepted from the offer
+    #[serde(default)]
+    compression: Option<String>,
+}
+
+impl Reply {
+    fn accept(compression: Option<String>) -> Self {
+        Self { ok: true, reason: None, compression }
+    }
+
+    fn refuse(reason: impl Into<String>) -> Self {
+        Self { ok: false, reason: Some(reason.into()), compression: None }
+    }
+}
+
+/// Block signature of the receiver's copy
+struct Block {
+    weak: u32,
+    strong: [u8; 32],
+}
+
+/// rsync's rolling checksum over a fixed-size window
+struct Rolling {
+    a: u32,
+    b: u32,
+    len: u32,
+}
+
+impl Rolling {
+    fn new(window: &[u8]) -> Self {
+        let len = window.len() as u32;
+        let (mut a, mut b) = (0u32, 0u32);
+        for (i, &x) in window.iter().enumerate() {
+            a = a.wrapping_add(u32::from(x));
+            b = b.wrapping_add((len - i as u32).wrapping_mul(u32::from(x)));
+        }
+        Self { a: a & 0xffff, b: b & 0xffff, len }
+    }
+
+    fn digest(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+
+    /// Slide the window one byte: `out` leaves at the front, `next` enters at the back
+    fn roll(&mut self, out: u8, next: u8) {
+        let (out, next) = (u32::from(out), u32::from(next));
+        self.a = self.a.wrapping_sub(out).wrapping_add(next) & 0xffff;
+        self.b = self.b.wrapping_sub(self.len.wrapping_mul(out)).wrapping_add(self.a) & 0xffff;
+    }
+}
+
+fn io_error(message: impl Into<String>) -> std::io::Error {
+    std::io::Error::other(message.into())
+}
+
+fn write_line<T: serde::Serialize>(stream: &mut impl Write, value: &T) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(value).map_err(|e| io_error(e.to_string()))?;
+    line.push(b'\n');
+    stream.write_all(&line)
+}
+
+fn read_line<T: serde::de::DeserializeOwned>(reader: &mut impl BufRead) -> std::io::Result<T> {
+    let mut line = String::new();
+    reader.by_ref().take(MAX_HEADER_BYTES).read_line(&mut line)?;
+    serde_json::from_str(&line).map_err(|e| io_error(format!("bad message: {}", e)))
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_be_bytes(bytes))
+}
+
+/// Signatures of every full block of `path`; the short tail is always resent
+fn signatures(path: &Path, block_size: usize) -> std::io::Result<Vec<Block>> {
+    let mut file = BufReader::new(std::fs::File::open(path)?);
+    let mut buf = vec![0u8; block_size];
+    let mut blocks = Vec::new();
+    loop {
+        match file.read_exact(&mut buf) {
+            Ok(()) => blocks.push(Block { weak: Rolling::new(&buf).digest(), strong: Sha256::digest(&buf).into() }),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(blocks),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// =============================================================================
+// SENDING
+// =============================================================================
+
+/// Scanner over the new file: a window of `block` bytes that slides through a
+/// buffer refilled from disk, hashing everything read for the final check
+struct Scanner {
+    reader: std::fs::File,
+    buf: Vec<u8>,
+    start: usize,
+    block: usize,
+    hasher: Sha256,
+    eof: bool,
+}
+
+impl Scanner {
+    /// Make sure a full window is buffered if the file has one left
+    fn fill(&mut self) -> std::io::Result<()> {
+        if self.eof || self.buf.len() - self.start >= self.block {
+            return Ok(());
+        }
+        if self.start >= READ_CHUNK {
+            self.buf.drain(..self.start);
+            self.start = 0;
+        }
+        let mut chunk = vec![0u8; READ_CHUNK];
+        while self.buf.len() - self.start < self.block {
+            let n = self.reader.read(&mut chunk)?;
+            if n == 0 {
+                self.eof = true;
+                break;
+            }
+            self.hasher.update(&chunk[..n]);
+            self.buf.extend_from_slice(&chunk[..n]);
+        }
+        Ok(())
+    }
+
+    fn window(&self) -> Option<&[u8]> {
+        self.buf.get(self.start..self.start + self.block)
+    }
+}
+
+fn is_compressible(path: &Path) -> bool {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or_default().to_ascii_lowercase();
+    !INCOMPRESSIBLE_EXTENSIONS.contains(&ext.as_str())
+}
+
+/// Byte counts of one delta as sent
+#[derive(Debug, Default)]
+struct WireStats {
+    sent: u64,
+    raw: u64,
+    compressed: u64,
+}
+
+impl WireStats {
+    /// Send the pending literal run, compressed when negotiated and worth it
+    fn flush_literal(
+        &mut self,
+        stream: &mut impl Write,
+        literal: &mut Vec<u8>,
+        level: Option<i32>,
+    ) -> std::io::Result<()> {
+        if literal.is_empty() {
+            return Ok(());
+        }
+        let packed = level
+            .and_then(|level| zstd::bulk::compress(literal, level).ok())
+            .filter(|packed| packed.len() < literal.len());
+        match packed {
+            Some(packed) => {
+                stream.write_all(b"Z")?;
+                stream.write_all(&(literal.len() as u32).to_be_bytes())?;
+                stream.write_all(&(packed.len() as u32).to_be_bytes())?;
+                stream.write_all(&packed)?;
+                self.sent += 9 + packed.len() as u64;
+                self.compressed += packed.len() as u64;
+            }
+            None => {
+                stream.write_all(b"D")?;
+                stream.write_all(&(literal.len() as u32).to_be_bytes())?;
+                stream.write_all(literal)?;
+                self.sent += 5 + literal.len() as u64;
+                self.compressed += literal.len() as u64;
+            }
+        }
+        self.raw += literal.len() as u64;
+        literal.clear();
+        Ok(())
+    }
+}
+
+/// Run the sender side against `addr`, offering zstd at `level` when given
+fn send_blocking(addr: SocketAddr, path: &Path, block_size: u32, level: Option<i32>) -> Result<WireStats, String> {
+    let file_name = path.file_name().and_then(|n| n.to_str()).ok_or("file has no usable name")?.to_string();
+    let reader = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let size = reader.metadata().map_err(|e| e.to_string())?.len();
+
+    let stream = std::net::TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)
+        .map_err(|e| format!("peer has no delta listener ({})", e))?;
+    stream.set_read_timeout(Some(IO_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(IO_TIMEOUT)).map_err(|e| e.to_string())?;
+    let mut input = BufReader::new(stream.try_clone().map_err(|e| e.to_string())?);
+    let mut output = std::io::BufWriter::new(stream);
+
+    let run = || -> std::io::Result<Result<WireStats, String>> {
+        let compression = level.map(|_| ZSTD.to_string());
+        write_line(&mut output, &Header { version: PROTOCOL_VERSION, file_name, size, block_size, compression })?;
+        output.flush()?;
+        let reply: Reply = read_line(&mut input)?;
+        if !reply.ok {
+            return Ok(Err(reply.reason.unwrap_or_else(|| "refused by peer".to_string())));
+        }
+        let level = level.filter(|_| reply.compression.as_deref() == Some(ZSTD));
+
+        let count = read_u32(&mut input)?;
+        let mut table: HashMap<u32, Vec<(u32, [u8; 32])>> = HashMap::new();
+        for index in 0..count {
+            let weak = read_u32(&mut input)?;
+            let mut strong = [0u8; 32];
+            input.read_exact(&mut strong)?;
+            table.entry(weak).or_default().push((index, strong));
+        }
+
+        let mut scan = Scanner {
+            reader,
+            buf: Vec::with_capacity(READ_CHUNK * 2),
+            start: 0,
+            block: block_size as usize,
+            hasher: Sha256::new(),
+            eof: false,
+        };
+        let mut literal = Vec::with_capacity(MAX_LITERAL);
+        let mut stats = WireStats::default();
+        let mut rolling: Option<Rolling> = None;
+
+        loop {
+            scan.fill()?;
+            let Some(window) = scan.window() else { break };
+            let sum = rolling.get_or_insert_with(|| Rolling::new(window));
+
+            let matched = table.get(&sum.digest()).and_then(|candidates| {
+                let strong: [u8; 32] = Sha256::digest(window).into();
+                candidates.iter().find(|(_, s)| *s == strong).map(|(index, _)| *index)
+            });
+            if let Some(index) = matched {
+                stats.flush_literal(&mut output, &mut literal, level)?;
+                output.write_all(b"C")?;
+                output.write_all(&index.to_be_bytes())?;
+                stats.sent += 5;
+                scan.start += scan.block;
+                rolling = None;
+                continue;
+            }
+
+            let out = scan.buf[scan.start];
+            literal.push(out);
+            if literal.len() >= MAX_LITERAL {
+                stats.flush_literal(&mut output, &mut literal, level)?;
+            }
+            scan.start += 1;
+            scan.fill()?;
+            match (scan.window().map(|w| w[w.len() - 1]), rolling.as_mut()) {
+                (Some(next), Some(sum)) => sum.roll(out, next),
+                _ => rolling = None,
+            }
+        }
+
+        // Whatever is left is shorter than a block
+        literal.extend_from_slice(&scan.buf[scan.start..]);
+        while !literal.is_empty() {
+            let rest = literal.split_off(literal.len().min(MAX_LITERAL));
+            stats.flush_literal(&mut output, &mut literal, level)?;
+            literal = rest;
+        }
+        output.write_all(b"E")?;
+        output.write_all(&scan.hasher.finalize())?;
+        output.flush()?;
+        stats.sent += 33;
+
+        let reply: Reply = read_line(&mut input)?;
+        Ok(match reply.ok {
+            true => Ok(stats),
+            false => Err(reply.reason.unwrap_or_else(|| "peer rejected the delta".to_string())),
+        })
+    };
+    run().map_err(|e| format!("delta transfer failed: {}", e))?
+}
+
+/// Send `path` to `peer_id` as a delta against the peer's current copy.
+/// Returns false when a full transfer is needed instead; the reason is recorded.
+pub async fn try_send(app: &AppHandle, peer_id: &str, path: &Path) -> bool {
+    let state = app.state::<DeltaState>();
+    let settings = state.data.get().settings;
+    let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    if !settings.enabled || size < settings.min_size_mb.saturating_mul(1024 * 1024) {
+        return false;
+    }
+
+    let daemon = app.state::<DaemonState>();
+    let ip = daemon_call!(daemon, "get_peers", |client| client.get_peers())
+        .ok()
+        .and_then(|peers| peers.into_iter().find(|p| p.id == peer_id && p.connected))
+        .and_then(|p| p.virtual_ip.parse::<IpAddr>().ok());
+    let result = match ip {
+        Some(ip) => {
+            let (path, block_size) = (path.to_path_buf(), settings.block_size_kib.clamp(4, 4096) * 1024);
+            let level = Some(settings.compression_level).filter(|l| *l > 0 && is_compressible(&path));
+            tauri::async_runtime::spawn_blocking(move || {
+                send_blocking(SocketAddr::new(ip, DELTA_PORT), &path, block_size, level)
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()))
+        }
+        None => Err("peer is offline".to_string()),
+    };
+
+    let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    match &result {
+        Ok(stats) => log::info!("Delta sent {} ({} of {} bytes)", file_name, stats.sent, size),
+        Err(reason) => log::debug!("Delta for {} not possible, sending in full: {}", file_name, reason),
+    }
+    state.record(DeltaRecord {
+        peer_id: peer_id.to_string(),
+        file_name,
+        size,
+        sent_bytes: result.as_ref().ok().map(|s| s.sent),
+        raw_bytes: result.as_ref().ok().map(|s| s.raw),
+        compressed_bytes: result.as_ref().ok().map(|s| s.compressed),
+        fallback_reason: result.as_ref().err().cloned(),
+        finished_at: unix_now(),
+    });
+    result.is_ok()
+}
+
+// =============================================================================
+// RECEIVING
+// =============================================================================
+
+/// Rebuild the new file into `partial` from `basis` and the sender's ops
+fn apply_blocking(
+    stream: &mut BufReader<std::net::TcpStream>,
+    basis: &Path,
+    partial: &Path,
+    header: &Header,
+    blocks: usize,
+) -> std::io::Result<()> {
+    let block_size = header.block_size as usize;
+    let compressed = header.compression.as_deref() == Some(ZSTD);
+    let mut basis = std::fs::File::open(basis)?;
+    let mut out = std::io::BufWriter::new(std::fs::File::create(partial)?);
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; block_size.max(MAX_LITERAL)];
+    let mut written = 0u64;
+
+    loop {
+        let mut op = [0u8; 1];
+        stream.read_exact(&mut op)?;
+        let chunk = match op[0] {
+            b'C' => {
+                let index = read_u32(stream)? as usize;
+                if index >= blocks {
+                    return Err(io_error("block index out of range"));
+                }
+                basis.seek(SeekFrom::Start((index * block_size) as u64))?;
+                basis.read_exact(&mut buf[..block_size])?;
+                &buf[..block_size]
+            }
+            b'D' => {
+                let len = read_u32(stream)? as usize;
+                if len > MAX_LITERAL {
+                    return Err(io_error("literal frame too large"));
+                }
+                stream.read_exact(&mut buf[..len])?;
+                &buf[..len]
+            }
+            b'Z' if compressed => {
+                let (raw_len, len) = (read_u32(stream)? as usize, read_u32(stream)? as usize);
+                if raw_len > MAX_LITERAL || len > raw_len {
+                    return Err(io_error("compressed frame too large"));
+                }
+                let mut packed = vec![0u8; len];
+                stream.read_exact(&mut packed)?;
+                let unpacked = zstd::bulk::decompress(&packed, raw_len)?;
+                if unpacked.len() != raw_len {
+                    return Err(io_error("compressed frame has the wrong length"));
+                }
+                buf[..raw_len].copy_from_slice(&unpacked);
+                &buf[..raw_len]
+            }
+            b'E' => {
+                let mut expected = [0u8; 32];
+                stream.read_exact(&mut expected)?;
+                out.flush()?;
+                let actual: [u8; 32] = hasher.finalize().into();
+                if actual != expected || written != header.size {
+                    return Err(io_error("rebuilt file does not match the sender's"));
+                }
+                return out.get_ref().sync_all();
+            }
+            _ => return Err(io_error("unknown delta op")),
+        };
+        written += chunk.len() as u64;
+        if written > header.size {
+            return Err(io_error("delta is longer than the announced size"));
+        }
+        hasher.update(chunk);
+        out.write_all(chunk)?;
+    }
+}
+
+/// Serve one sender: find the local copy, share its signatures and apply the delta
+async fn receive(app: &AppHandle, stream: tokio::net::TcpStream, from: IpAddr) -> Result<(), String> {
+    let mut reader = tokio::io::BufReader::new(stream);
+    let mut line = String::new();
+    tokio::time::timeout(IO_TIMEOUT, (&mut reader).take(MAX_HEADER_BYTES).read_line(&mut line))
+        .await
+        .map_err(|_| "header timed out".to_string())?
+        .map_err(|e| e.to_string())?;
+    let header: Header = serde_json::from_str(&line).map_err(|e| format!("bad header: {}", e))?;
+
+    let daemon = app.state::<DaemonState>();
+    let peer_id = daemon_call!(daemon, "get_peers", |client| client.get_peers())
+        .ok()
+        .and_then(|peers| peers.into_iter().find(|p| p.virtual_ip.parse::<IpAddr>().ok() == Some(from)))
+        .map(|p| p.id);
+    let block_size = header.block_size as usize;
+    let target = match peer_id {
+        _ if header.version != PROTOCOL_VERSION => Err("unsupported delta version".to_string()),
+        _ if !(4 * 1024..=4096 * 1024).contains(&block_size) => Err("unsupported block size".to_string()),
+        Some(peer_id) => sync::delta_basis(app, &peer_id, &header.file_name),
+        None => Err("unknown peer".to_string()),
+    };
+    let (pair_id, target) = match target {
+        Ok(found) => found,
+        Err(reason) => {
+            let mut reply = serde_json::to_vec(&Reply::refuse(reason.clone())).map_err(|e| e.to_string())?;
+            reply.push(b'\n');
+            let _ = reader.get_mut().write_all(&reply).await;
+            return Err(reason);
+        }
+    };
+
+    // Hand the connection to a blocking worker for the bulk of the exchange
+    let stream = reader.into_inner().into_std().map_err(|e| e.to_string())?;
+    let mut partial = target.clone().into_os_string();
+    partial.push(".part");
+    let partial = PathBuf::from(partial);
+    let (basis, staged) = (target.clone(), partial.clone());
+    let (stream, result) = tauri::async_runtime::spawn_blocking(move || {
+        let mut stream = BufReader::new(stream);
+        let run = |stream: &mut BufReader<std::net::TcpStream>| -> std::io::Result<()> {
+            stream.get_ref().set_nonblocking(false)?;
+            stream.get_ref().set_read_timeout(Some(IO_TIMEOUT))?;
+            let blocks = signatures(&basis, block_size)?;
+            let mut out = std::io::BufWriter::new(stream.get_ref());
+            write_line(&mut out, &Reply::accept(header.compression.clone().filter(|c| c == ZSTD)))?;
+            out.write_all(&(blocks.len() as u32).to_be_bytes())?;
+            for block in &blocks {
+                out.write_all(&block.weak.to_be_bytes())?;
+                out.write_all(&block.strong)?;
+            }
+            out.flush()?;
+            drop(out);
+            apply_blocking(stream, &basis, &staged, &header, blocks.len())
+        };
+        let result = run(&mut stream);
+        (stream.into_inner(), result)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let result = match result {
+        Ok(()) => {
+            if let Err(e) = versions::snapshot_before_overwrite(app, &target, VersionSource::Sync).await {
+                log::warn!("Failed to keep previous version of {:?}: {}", target, e);
+            }
+            std::fs::rename(&partial, &target).map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    };
+    if result.is_err() {
+        let _ = std::fs::remove_file(&partial);
+    } else {
+        sync::note_received(app, &pair_id, &target);
+    }
+
+    let reply = match &result {
+        Ok(()) => Reply::accept(None),
+        Err(e) => Reply::refuse(e.clone()),
+    };
+    let mut stream = stream;
+    write_line(&mut stream, &reply).map_err(|e| e.to_string())?;
+    result
+}
+
+/// Listen for deltas on the virtual IP while connected and enabled
+pub fn spawn(app: AppHandle) {
+    tunnel::serve(
+        app,
+        "Delta listener",
+        DELTA_PORT,
+        |app| app.state::<DeltaState>().data.get().settings.enabled,
+        Some(|app| &app.state::<DeltaState>().inner().changed),
+        |app, stream, from| async move {
+            if let Err(e) = receive(&app, stream, from.ip()).await {
+                log::debug!("Delta from {} not applied: {}", from, e);
+            }
+        },
+    );
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_delta_settings(state: State<'_, DeltaState>) -> Result<DeltaSettings, String> {
+    Ok(state.data.get().settings)
+}
+
+#[tauri::command]
+pub async fn set_delta_settings(
+    state: State<'_, DeltaState>,
+    settings: DeltaSettings,
+) -> Result<DeltaSettings, String> {
+    if !(4..=4096).contains(&settings.block_size_kib) {
+        return Err("block size must be between 4 and 4096 KiB".to_string());
+    }
+    if !(0..=19).contains(&settings.compression_level) {
+        return Err("compression level must be between 0 (off) and 19".to_string());
+    }
+    state.data.update(|data| data.settings = settings.clone())?;
+    state.changed.notify_one();
+    Ok(settings)
+}
+
+/// Recent delta attempts, newest first, with bytes saved or why it fell back
+#[tauri::command]
+pub async fn list_delta_transfers(state: State<'_, DeltaState>) -> Result<Vec<DeltaRecord>, String> {
+    Ok(state.data.get().records.into_iter().rev().collect())
+}
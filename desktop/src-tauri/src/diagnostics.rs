@@ -0,0 +1,214 @@
+// Diagnostics Bundle
+// One zip for support: the desktop logs, a status summary, and an OpenMetrics
+// snapshot (RPC call stats, reconnect counts, transfer totals and rates) so
+// triage can start from numbers instead of reading logs.
+
+use crate::activity::ActivityState;
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store;
+use chrono::Local;
+use serde_json::json;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use tauri::{AppHandle, Manager};
+use ts_rs::TS;
+use zip::write::SimpleFileOptions;
+
+const BUNDLES_DIR: &str = "diagnostics";
+
+/// Windows the transfer rate is averaged over, in seconds
+const RATE_WINDOWS: &[(&str, u64)] = &[("1h", 3600), ("24h", 86_400)];
+
+/// Log files larger than this only contribute their tail
+const MAX_LOG_BYTES: u64 = 8 * 1024 * 1024;
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct DiagnosticsBundle {
+    pub path: String,
+    /// Entries inside the zip
+    pub files: Vec<String>,
+    #[ts(type = "number")]
+    pub size: u64,
+}
+
+/// Quote an OpenMetrics label value
+fn label(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+fn family(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+}
+
+/// The metrics snapshot in OpenMetrics text format
+async fn openmetrics(app: &AppHandle) -> String {
+    let daemon = app.state::<DaemonState>();
+    let rpc = daemon.metrics.snapshot();
+    let mut out = String::new();
+
+    family(&mut out, "goconnect_rpc_calls", "counter", "Daemon RPC calls by command.");
+    for m in &rpc {
+        let _ = writeln!(out, "goconnect_rpc_calls_total{{command={}}} {}", label(&m.command), m.calls);
+    }
+    family(&mut out, "goconnect_rpc_failures", "counter", "Failed daemon RPC calls by command.");
+    for m in &rpc {
+        let _ = writeln!(out, "goconnect_rpc_failures_total{{command={}}} {}", label(&m.command), m.failures);
+    }
+    family(&mut out, "goconnect_rpc_auth_retries", "counter", "Calls retried after refreshing the IPC token.");
+    for m in &rpc {
+        let _ = writeln!(out, "goconnect_rpc_auth_retries_total{{command={}}} {}", label(&m.command), m.auth_retries);
+    }
+    family(&mut out, "goconnect_rpc_duration_seconds", "summary", "Time spent in daemon RPC calls.");
+    for m in &rpc {
+        let (command, total) = (label(&m.command), m.total_ms as f64 / 1000.0);
+        let _ = writeln!(out, "goconnect_rpc_duration_seconds_sum{{command={}}} {:.3}", command, total);
+        let _ = writeln!(out, "goconnect_rpc_duration_seconds_count{{command={}}} {}", command, m.calls);
+    }
+    family(&mut out, "goconnect_rpc_duration_max_seconds", "gauge", "Slowest daemon RPC call by command.");
+    for m in &rpc {
+        let (command, max) = (label(&m.command), m.max_ms as f64 / 1000.0);
+        let _ = writeln!(out, "goconnect_rpc_duration_max_seconds{{command={}}} {:.3}", command, max);
+    }
+
+    family(&mut out, "goconnect_daemon_reconnects", "counter", "Times the daemon connection was lost since startup.");
+    let _ = writeln!(out, "goconnect_daemon_reconnects_total {}", daemon.connection.reconnect_count());
+    family(&mut out, "goconnect_daemon_state", "stateset", "Current daemon connection state.");
+    let state = serde_json::to_value(daemon.connection.state()).unwrap_or_default();
+    for candidate in ["no_daemon", "connecting", "auth_failed", "ready", "degraded", "reconnecting"] {
+        let active = u8::from(state.as_str() == Some(candidate));
+        let _ = writeln!(out, "goconnect_daemon_state{{goconnect_daemon_state={}}} {}", label(candidate), active);
+    }
+    family(&mut out, "goconnect_tunnel_connected", "gauge", "Whether a network tunnel is up.");
+    let connected = daemon.latest_status().is_some_and(|s| s.connected);
+    let _ = writeln!(out, "goconnect_tunnel_connected {}", u8::from(connected));
+
+    // Daemon-wide transfer totals, when it answers
+    if let Ok(stats) = daemon_call!(daemon, "get_transfer_stats", |client| client.get_transfer_stats()) {
+        family(&mut out, "goconnect_transfers", "counter", "Transfers handled by the daemon by outcome.");
+        let _ = writeln!(out, "goconnect_transfers_total{{status=\"completed\"}} {}", stats.completed_transfers);
+        let _ = writeln!(out, "goconnect_transfers_total{{status=\"failed\"}} {}", stats.failed_transfers);
+        family(&mut out, "goconnect_transfers_active", "gauge", "Transfers in progress.");
+        let _ = writeln!(out, "goconnect_transfers_active {}", stats.active_transfers);
+        family(&mut out, "goconnect_transfer_bytes", "counter", "Bytes transferred by direction.");
+        let _ = writeln!(out, "goconnect_transfer_bytes_total{{direction=\"sent\"}} {}", stats.total_bytes_sent);
+        let _ = writeln!(out, "goconnect_transfer_bytes_total{{direction=\"received\"}} {}", stats.total_bytes_received);
+    }
+
+    // Rates from the local activity log of finished transfers
+    let transfers = app.state::<ActivityState>().snapshot().transfers;
+    let now = unix_now();
+    family(
+        &mut out,
+        "goconnect_transfer_rate_bytes_per_second",
+        "gauge",
+        "Completed transfer bytes averaged over a window.",
+    );
+    for (window, secs) in RATE_WINDOWS {
+        let cutoff = now.saturating_sub(*secs);
+        let bytes: u64 = transfers
+            .iter()
+            .filter(|t| t.status == "completed" && t.finished_at >= cutoff)
+            .map(|t| t.bytes)
+            .sum();
+        let _ = writeln!(
+            out,
+            "goconnect_transfer_rate_bytes_per_second{{window={}}} {:.1}",
+            label(window),
+            bytes as f64 / *secs as f64
+        );
+    }
+
+    out.push_str("# EOF\n");
+    out
+}
+
+/// Status summary for the bundle
+async fn status_json(app: &AppHandle) -> serde_json::Value {
+    let daemon = app.state::<DaemonState>();
+    let version = daemon_call!(daemon, "get_version", |client| client.get_version()).ok();
+    json!({
+        "generated_at": unix_now(),
+        "app_version": app.package_info().version.to_string(),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "connection": daemon.connection.snapshot(),
+        "daemon_status": daemon.latest_status(),
+        "networks": daemon.latest_networks(),
+        "daemon_version": version,
+    })
+}
+
+/// The last `MAX_LOG_BYTES` of a log file
+fn read_log(path: &std::path::Path) -> std::io::Result<Vec<u8>> {
+    use std::io::{Read, Seek, SeekFrom};
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(MAX_LOG_BYTES)))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn write_zip(path: &std::path::Path, entries: Vec<(String, Vec<u8>)>) -> Result<(), String> {
+    let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for (name, bytes) in entries {
+        zip.start_file(name, options).map_err(|e| e.to_string())?;
+        zip.write_all(&bytes).map_err(|e| e.to_string())?;
+    }
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Write a diagnostics zip to the app data folder and return where it is
+#[tauri::command]
+pub async fn export_diagnostics_bundle(app: AppHandle) -> Result<DiagnosticsBundle, String> {
+    let mut entries = vec![
+        ("metrics.txt".to_string(), openmetrics(&app).await.into_bytes()),
+        (
+            "status.json".to_string(),
+            serde_json::to_vec_pretty(&status_json(&app).await).map_err(|e| e.to_string())?,
+        ),
+    ];
+    if let Some(dir) = store::log_dir(&app) {
+        for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            match read_log(&path) {
+                Ok(bytes) => entries.push((format!("logs/{}", entry.file_name().to_string_lossy()), bytes)),
+                Err(e) => log::warn!("Skipping log {:?} in diagnostics: {}", path, e),
+            }
+        }
+    }
+
+    let dir = store::data_dir(&app).join(BUNDLES_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!("goconnect-diagnostics-{}.zip", Local::now().format("%Y%m%d-%H%M%S")));
+    let files: Vec<String> = entries.iter().map(|(name, _)| name.clone()).collect();
+    let target = path.clone();
+    tauri::async_runtime::spawn_blocking(move || write_zip(&target, entries))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    Ok(DiagnosticsBundle {
+        size: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+        path: path.to_string_lossy().into_owned(),
+        files,
+    })
+}
+
+/// The metrics snapshot alone, in OpenMetrics text format
+#[tauri::command]
+pub async fn get_metrics_snapshot(app: AppHandle) -> Result<String, String> {
+    Ok(openmetrics(&app).await)
+}
@@ -0,0 +1,319 @@
+// Daemon Discovery
+// Finds daemons the desktop cannot see by default: one inside a WSL2 distro
+// (Windows) or a Docker container. Each candidate gets its port probed and its
+// IPC token file mapped to a path the desktop can read: WSL homes through
+// `\\wsl.localhost\<distro>`, containers through a bind mount of the daemon's
+// data folder, or failing that a copy taken with `docker cp`. Connecting to a
+// candidate tests it and, when the daemon accepts the token, saves it as the
+// daemon endpoint. Hints explain what to change when a candidate is not usable.
+
+use crate::commands::DaemonState;
+use crate::endpoint::{self, Endpoint, EndpointSettings, EndpointSource, EndpointState, EndpointTest};
+use crate::store::data_dir;
+use std::process::Command;
+use std::time::Duration;
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The daemon's token file relative to its home directory on Linux
+#[cfg(windows)]
+const LINUX_TOKEN_PATH: &str = ".local/share/goconnect/ipc.token";
+
+/// Where the daemon keeps its token in the official container image
+const CONTAINER_TOKEN_PATH: &str = "/root/.local/share/goconnect/ipc.token";
+
+/// Tokens copied out of containers
+const TOKENS_DIR: &str = "daemon_tokens";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum CandidateKind {
+    Local,
+    Wsl,
+    Docker,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct DaemonCandidate {
+    pub kind: CandidateKind,
+    /// Distro or container name
+    pub name: String,
+    pub url: String,
+    /// Token file as seen from this machine, when one could be mapped
+    pub token_path: Option<String>,
+    /// Something accepts connections on the daemon port
+    pub reachable: bool,
+    pub token_found: bool,
+    pub container_id: Option<String>,
+    /// The WSL distro's own address, tried when localhost forwarding is off
+    pub guest_ip: Option<String>,
+    /// What to change before this candidate can work
+    pub hints: Vec<String>,
+}
+
+/// Run a tool and return its stdout, or `None` when it is missing or fails
+fn output(program: &str, args: &[&str]) -> Option<Vec<u8>> {
+    let mut command = Command::new(program);
+    command.args(args);
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = command.output().ok()?;
+    output.status.success().then_some(output.stdout)
+}
+
+async fn port_open(host: &str, port: u16) -> bool {
+    matches!(
+        tokio::time::timeout(PROBE_TIMEOUT, tokio::net::TcpStream::connect((host, port))).await,
+        Ok(Ok(_))
+    )
+}
+
+fn local_candidate() -> DaemonCandidate {
+    let token = crate::daemon::DaemonClient::default_token_path().ok();
+    DaemonCandidate {
+        kind: CandidateKind::Local,
+        name: "This computer".to_string(),
+        url: endpoint::DEFAULT_URL.to_string(),
+        token_found: token.as_ref().is_some_and(|p| p.is_file()),
+        token_path: None,
+        reachable: false,
+        container_id: None,
+        guest_ip: None,
+        hints: Vec::new(),
+    }
+}
+
+/// `wsl.exe --list` prints UTF-16; commands run inside a distro print UTF-8
+#[cfg_attr(not(windows), allow(dead_code))]
+fn decode_wsl(bytes: &[u8]) -> String {
+    if bytes.len() >= 2 && bytes.iter().skip(1).step_by(2).all(|b| *b == 0) {
+        let units: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Map a Linux path inside `distro` to its `\\wsl.localhost` UNC path
+#[cfg_attr(not(windows), allow(dead_code))]
+fn wsl_unc_path(distro: &str, linux_path: &str) -> String {
+    format!(r"\\wsl.localhost\{}{}", distro, linux_path.replace('/', "\\"))
+}
+
+#[cfg(windows)]
+fn wsl_candidates() -> Vec<DaemonCandidate> {
+    let Some(list) = output("wsl.exe", &["--list", "--quiet"]) else { return Vec::new() };
+    decode_wsl(&list)
+        .lines()
+        .map(|l| l.trim().trim_matches('\0').to_string())
+        .filter(|l| !l.is_empty() && !l.starts_with("docker-desktop"))
+        .filter_map(|distro| {
+            let probe = output("wsl.exe", &["-d", &distro, "-e", "sh", "-c", "echo $HOME; hostname -I"])?;
+            let probe = decode_wsl(&probe);
+            let mut lines = probe.lines();
+            let home = lines.next()?.trim().to_string();
+            let ip = lines.next().and_then(|l| l.split_whitespace().next()).map(str::to_string);
+            let token = wsl_unc_path(&distro, &format!("{}/{}", home.trim_end_matches('/'), LINUX_TOKEN_PATH));
+            let token_found = std::path::Path::new(&token).is_file();
+            let mut hints = Vec::new();
+            if !token_found {
+                hints.push(format!("No daemon token in {}; start the daemon inside {} first", home, distro));
+            }
+            Some(DaemonCandidate {
+                kind: CandidateKind::Wsl,
+                // WSL2 forwards localhost; the distro address is tried when it does not
+                url: endpoint::DEFAULT_URL.to_string(),
+                token_path: Some(token),
+                token_found,
+                reachable: false,
+                container_id: None,
+                guest_ip: ip,
+                hints,
+                name: distro,
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn wsl_candidates() -> Vec<DaemonCandidate> {
+    Vec::new()
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DockerMount {
+    source: String,
+    destination: String,
+}
+
+/// Host port publishing the container's daemon port, from `docker ps` Ports
+/// such as `0.0.0.0:34101->34101/tcp, [::]:34101->34101/tcp`
+fn published_port(ports: &str) -> Option<u16> {
+    let container_port = format!("->{}/tcp", endpoint::DEFAULT_PORT);
+    ports.split(',').map(str::trim).find_map(|mapping| {
+        let host = mapping.strip_suffix(&container_port)?;
+        host.rsplit(':').next()?.parse().ok()
+    })
+}
+
+fn docker_candidates() -> Vec<DaemonCandidate> {
+    let format = "{{.ID}}\t{{.Names}}\t{{.Image}}\t{{.Ports}}";
+    let Some(list) = output("docker", &["ps", "--format", format]) else { return Vec::new() };
+    String::from_utf8_lossy(&list)
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let (id, name, image) = (fields.next()?, fields.next()?, fields.next()?);
+            let ports = fields.next().unwrap_or_default();
+            let published = published_port(ports);
+            let looks_like_daemon = image.contains("goconnect") || name.contains("goconnect");
+            if published.is_none() && !looks_like_daemon {
+                return None;
+            }
+
+            let host_network = output("docker", &["inspect", "-f", "{{.HostConfig.NetworkMode}}", id])
+                .is_some_and(|mode| String::from_utf8_lossy(&mode).trim() == "host");
+            let mut hints = Vec::new();
+            let port = match (published, host_network) {
+                (Some(port), _) => port,
+                (None, true) => endpoint::DEFAULT_PORT,
+                (None, false) => {
+                    hints.push(format!(
+                        "Publish the daemon port: docker run -p 127.0.0.1:{0}:{0} ...",
+                        endpoint::DEFAULT_PORT
+                    ));
+                    endpoint::DEFAULT_PORT
+                }
+            };
+
+            // A bind mount over the daemon's data folder exposes the token directly
+            let mounts: Vec<DockerMount> = output("docker", &["inspect", "-f", "{{json .Mounts}}", id])
+                .and_then(|json| serde_json::from_slice(&json).ok())
+                .unwrap_or_default();
+            let token_path = mounts.iter().find_map(|m| {
+                let rest = CONTAINER_TOKEN_PATH.strip_prefix(m.destination.trim_end_matches('/'))?;
+                Some(format!("{}{}", m.source.trim_end_matches('/'), rest))
+            });
+            let token_found = token_path.as_ref().is_some_and(|p| std::path::Path::new(p).is_file());
+            if !token_found {
+                hints.push(
+                    "The token is copied out of the container on connect; mount the daemon's data folder \
+                     to keep it in sync across daemon restarts"
+                        .to_string(),
+                );
+            }
+
+            Some(DaemonCandidate {
+                kind: CandidateKind::Docker,
+                name: name.to_string(),
+                url: format!("http://127.0.0.1:{}", port),
+                token_path,
+                reachable: false,
+                token_found,
+                container_id: Some(id.to_string()),
+                guest_ip: None,
+                hints,
+            })
+        })
+        .collect()
+}
+
+/// Copy a container's token next to the other desktop state
+async fn copy_container_token(app: &AppHandle, candidate: &DaemonCandidate) -> Result<String, String> {
+    let id = candidate.container_id.clone().ok_or("the candidate has no container")?;
+    let dir = data_dir(app).join(TOKENS_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let target = dir.join(format!("{}.token", candidate.name.replace(['/', '\\', ':'], "_")));
+    let destination = target.to_string_lossy().into_owned();
+    let source = format!("{}:{}", id, CONTAINER_TOKEN_PATH);
+    let copied = tauri::async_runtime::spawn_blocking(move || output("docker", &["cp", &source, &destination]))
+        .await
+        .map_err(|e| e.to_string())?;
+    copied.ok_or_else(|| format!("could not copy the token out of {}; is the daemon running?", candidate.name))?;
+    Ok(target.to_string_lossy().into_owned())
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Daemons on this machine, in WSL and in Docker, with their ports probed
+#[tauri::command]
+pub async fn detect_daemon_candidates() -> Result<Vec<DaemonCandidate>, String> {
+    let mut candidates = vec![local_candidate()];
+    let found = tauri::async_runtime::spawn_blocking(|| {
+        let mut found = wsl_candidates();
+        found.extend(docker_candidates());
+        found
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    candidates.extend(found);
+
+    for candidate in &mut candidates {
+        candidate.reachable = port_open("127.0.0.1", port_of(&candidate.url)).await;
+        // WSL without localhost forwarding: try the distro's own address
+        if !candidate.reachable {
+            if let Some(ip) = candidate.guest_ip.clone() {
+                if port_open(&ip, endpoint::DEFAULT_PORT).await {
+                    candidate.url = format!("http://{}:{}", ip, endpoint::DEFAULT_PORT);
+                    candidate.reachable = true;
+                } else {
+                    candidate.hints.push(format!(
+                        "Nothing answers on port {} in this distro; the daemon must listen on 0.0.0.0 \
+                         when localhost forwarding is off",
+                        endpoint::DEFAULT_PORT
+                    ));
+                }
+            }
+        }
+    }
+    Ok(candidates)
+}
+
+fn port_of(url: &str) -> u16 {
+    url.rsplit(':').next().and_then(|p| p.parse().ok()).unwrap_or(endpoint::DEFAULT_PORT)
+}
+
+/// Test a candidate and save it as the daemon endpoint if the daemon accepts
+/// its token. The test result is returned either way.
+#[tauri::command]
+pub async fn connect_daemon_candidate(
+    app: AppHandle,
+    endpoint_state: State<'_, EndpointState>,
+    daemon: State<'_, DaemonState>,
+    candidate: DaemonCandidate,
+) -> Result<EndpointTest, String> {
+    let token_path = match candidate.kind {
+        CandidateKind::Local => None,
+        CandidateKind::Docker if !candidate.token_found => Some(copy_container_token(&app, &candidate).await?),
+        CandidateKind::Wsl | CandidateKind::Docker => candidate.token_path.clone(),
+    };
+    let url = endpoint::normalize(&candidate.url)?;
+    let result = endpoint::test(Endpoint {
+        url: url.clone(),
+        token_path: token_path.clone(),
+        source: EndpointSource::Settings,
+        socket_path: None,
+    })
+    .await;
+
+    if result.authenticated {
+        let settings = match candidate.kind {
+            // The default needs no settings, so a later change of defaults still applies
+            CandidateKind::Local => EndpointSettings::default(),
+            _ => EndpointSettings { url: Some(url), token_path },
+        };
+        endpoint_state.save(&daemon, settings).await?;
+    }
+    Ok(result)
+}
@@ -0,0 +1,272 @@
+// Daemon Endpoint
+// Where the desktop finds the daemon. The default is the loopback TCP port the
+// daemon opens for the desktop (127.0.0.1:34101) and the daemon's own token
+// file. Users running the daemon on another port or inside a container can
+// point elsewhere, in order of precedence:
+//   GOCONNECT_DAEMON_ENDPOINT / GOCONNECT_DAEMON_TOKEN_FILE environment variables
+//   the endpoint settings (daemon_endpoint.json)
+//   the defaults
+// The command line also takes `--endpoint <url>`.
+// With the default endpoint on Linux and macOS the daemon's Unix socket is
+// tried first, and the TCP port only when the socket is missing or refuses.
+
+use crate::commands::DaemonState;
+use crate::daemon::{DaemonClient, DaemonError};
+use crate::store::JsonStore;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "daemon_endpoint.json";
+
+pub const DEFAULT_URL: &str = "http://127.0.0.1:34101";
+pub const DEFAULT_PORT: u16 = 34101;
+pub const DEFAULT_SOCKET: &str = "/run/goconnect/daemon.sock";
+
+const ENDPOINT_ENV: &str = "GOCONNECT_DAEMON_ENDPOINT";
+const TOKEN_FILE_ENV: &str = "GOCONNECT_DAEMON_TOKEN_FILE";
+
+/// How long `test_daemon_endpoint` waits for the daemon
+const TEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Configured endpoint; `None` until settings are loaded (or for the command line)
+static CONFIGURED: RwLock<Option<EndpointSettings>> = RwLock::new(None);
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct EndpointSettings {
+    /// `http://host:port`; `None` for the default
+    pub url: Option<String>,
+    /// IPC token file; `None` for the daemon's default location
+    pub token_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum EndpointSource {
+    Environment,
+    Settings,
+    Default,
+}
+
+/// The endpoint in effect
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct Endpoint {
+    pub url: String,
+    /// `None` for the daemon's default token location
+    pub token_path: Option<String>,
+    pub source: EndpointSource,
+    /// Unix socket tried before `url`; only set for the default endpoint on Linux and macOS
+    pub socket_path: Option<String>,
+}
+
+impl Endpoint {
+    /// The daemon runs on another host, so paths on this machine mean nothing to it
+    pub fn is_remote(&self) -> bool {
+        let Ok(url) = tauri::Url::parse(&self.url) else { return false };
+        let host = url.host_str().unwrap_or_default().trim_start_matches('[').trim_end_matches(']');
+        match host.parse::<IpAddr>() {
+            Ok(ip) => !ip.is_loopback(),
+            Err(_) => !host.is_empty() && !host.eq_ignore_ascii_case("localhost"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct EndpointConfig {
+    /// What is saved
+    pub settings: EndpointSettings,
+    /// What is used, after environment overrides
+    pub active: Endpoint,
+    /// The active endpoint is on another host, whose files are not the desktop's
+    pub remote: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct EndpointTest {
+    /// The URL actually tried, after normalizing
+    pub url: String,
+    /// A gRPC connection could be opened
+    pub reachable: bool,
+    /// The daemon accepted the token
+    pub authenticated: bool,
+    pub daemon_version: Option<String>,
+    #[ts(type = "number | null")]
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Normalize a user-entered endpoint to `http(s)://host:port`. A bare
+/// `host:port` or `host` is taken as plain HTTP; the port defaults to 34101.
+pub fn normalize(input: &str) -> Result<String, String> {
+    let input = input.trim().trim_end_matches('/');
+    if input.is_empty() {
+        return Err("the endpoint is empty".to_string());
+    }
+    let with_scheme = if input.contains("://") { input.to_string() } else { format!("http://{}", input) };
+    let url = tauri::Url::parse(&with_scheme).map_err(|e| format!("invalid endpoint '{}': {}", input, e))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(format!("unsupported scheme '{}', expected http or https", url.scheme()));
+    }
+    let host = url.host_str().filter(|h| !h.is_empty()).ok_or("the endpoint has no host")?;
+    if url.path() != "/" || url.query().is_some() || !url.username().is_empty() {
+        return Err("the endpoint must be only a host and port".to_string());
+    }
+    let port = url.port().unwrap_or(DEFAULT_PORT);
+    Ok(format!("{}://{}:{}", url.scheme(), host, port))
+}
+
+fn validate(settings: &EndpointSettings) -> Result<EndpointSettings, String> {
+    let url = settings.url.as_deref().filter(|u| !u.trim().is_empty()).map(normalize).transpose()?;
+    let token_path = settings.token_path.as_deref().map(str::trim).filter(|p| !p.is_empty());
+    if let Some(path) = token_path {
+        if !std::path::Path::new(path).is_absolute() {
+            return Err("the token file path must be absolute".to_string());
+        }
+    }
+    Ok(EndpointSettings { url, token_path: token_path.map(str::to_string) })
+}
+
+/// Replace the configured endpoint for this process
+pub fn configure(settings: EndpointSettings) {
+    *CONFIGURED.write().unwrap() = Some(settings);
+}
+
+/// The endpoint to connect to right now
+pub fn active() -> Endpoint {
+    let env = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+    let configured = CONFIGURED.read().unwrap().clone().unwrap_or_default();
+
+    let (url, source) = match (env(ENDPOINT_ENV), configured.url) {
+        (Some(url), _) => match normalize(&url) {
+            Ok(url) => (url, EndpointSource::Environment),
+            Err(e) => {
+                log::warn!("Ignoring {}: {}", ENDPOINT_ENV, e);
+                (DEFAULT_URL.to_string(), EndpointSource::Default)
+            }
+        },
+        (None, Some(url)) => (url, EndpointSource::Settings),
+        (None, None) => (DEFAULT_URL.to_string(), EndpointSource::Default),
+    };
+    let socket_path = (cfg!(unix) && source == EndpointSource::Default).then(|| DEFAULT_SOCKET.to_string());
+    Endpoint { url, token_path: env(TOKEN_FILE_ENV).or(configured.token_path), source, socket_path }
+}
+
+/// Managed state holding the persisted endpoint settings
+pub struct EndpointState {
+    settings: JsonStore<EndpointSettings>,
+}
+
+impl EndpointState {
+    /// Load the settings and make them the process-wide endpoint
+    pub fn load(app: &AppHandle) -> Self {
+        let settings = JsonStore::open(app, SETTINGS_FILE);
+        let loaded: EndpointSettings = settings.get();
+        match validate(&loaded) {
+            Ok(valid) => configure(valid),
+            Err(e) => log::warn!("Ignoring saved daemon endpoint: {}", e),
+        }
+        Self { settings }
+    }
+
+    /// Validate, persist and switch to `settings`, dropping the current connection
+    pub async fn save(&self, daemon: &DaemonState, settings: EndpointSettings) -> Result<Endpoint, String> {
+        let settings = validate(&settings)?;
+        self.settings.set(settings.clone())?;
+        configure(settings);
+        daemon.reset_client();
+        let _ = daemon.probe_status().await;
+        Ok(active())
+    }
+}
+
+/// Connect to `endpoint` and ask for the daemon version, without touching the
+/// active connection
+pub async fn test(endpoint: Endpoint) -> EndpointTest {
+    let mut report = EndpointTest {
+        url: endpoint.url.clone(),
+        reachable: false,
+        authenticated: false,
+        daemon_version: None,
+        latency_ms: None,
+        error: None,
+    };
+    let started = Instant::now();
+    let attempt = async {
+        let client = DaemonClient::connect_to(&endpoint).await?;
+        report.reachable = true;
+        client.get_version().await
+    };
+    match tokio::time::timeout(TEST_TIMEOUT, attempt).await {
+        Ok(Ok(version)) => {
+            report.reachable = true;
+            report.authenticated = true;
+            report.daemon_version = Some(version.version);
+            report.latency_ms = Some(started.elapsed().as_millis() as u64);
+        }
+        Ok(Err(e)) => {
+            // A rejected token still proves something is listening
+            if let DaemonError::Rpc(status) = &e {
+                report.reachable = true;
+                report.latency_ms = Some(started.elapsed().as_millis() as u64);
+                report.error = Some(status.message().to_string()).filter(|m| !m.is_empty());
+            }
+            report.error.get_or_insert_with(|| e.to_string());
+        }
+        Err(_) => report.error = Some(format!("no answer within {}s", TEST_TIMEOUT.as_secs())),
+    }
+    report
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_daemon_endpoint(state: State<'_, EndpointState>) -> Result<EndpointConfig, String> {
+    let active = active();
+    Ok(EndpointConfig { settings: state.settings.get(), remote: active.is_remote(), active })
+}
+
+/// Save the endpoint and reconnect to it. Environment variables still win.
+#[tauri::command]
+pub async fn set_daemon_endpoint(
+    state: State<'_, EndpointState>,
+    daemon: State<'_, DaemonState>,
+    settings: EndpointSettings,
+) -> Result<Endpoint, String> {
+    state.save(&daemon, settings).await
+}
+
+/// Try an endpoint without saving it; omitted fields use the active endpoint
+#[tauri::command]
+pub async fn test_daemon_endpoint(url: Option<String>, token_path: Option<String>) -> Result<EndpointTest, String> {
+    let active = active();
+    // The active endpoint's socket is only tried when testing the active endpoint itself
+    let socket_path = if url.is_none() { active.socket_path.clone() } else { None };
+    let settings = EndpointSettings { url: url.or(Some(active.url)), token_path: token_path.or(active.token_path) };
+    let settings = validate(&settings)?;
+    Ok(test(Endpoint {
+        url: settings.url.unwrap_or_else(|| DEFAULT_URL.to_string()),
+        token_path: settings.token_path,
+        source: EndpointSource::Settings,
+        socket_path,
+    })
+    .await)
+}
+
+/// Default token file, for display next to the token path field
+#[tauri::command]
+pub async fn get_default_token_path() -> Result<String, String> {
+    DaemonClient::default_token_path()
+        .map(|p: PathBuf| p.to_string_lossy().into_owned())
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,98 @@
+// Daemon Event Bridge
+// One long-lived subscription to the daemon's event stream, re-emitted as
+// typed Tauri events so views can react to peers coming and going, incoming
+// transfer requests and new chat messages instead of polling for them. The
+// stream is re-opened whenever it drops; anything missed while it was down has
+// to be picked up by the next regular refresh.
+
+use crate::attention::{self, AttentionEvent};
+use crate::auto_accept;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::{ChatMessage, DaemonEvent, PeerInfo};
+use crate::peer_index;
+use crate::recent_chats;
+use crate::spam;
+use crate::transfer_prompt;
+use crate::translation;
+use crate::tray;
+use std::collections::HashSet;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use ts_rs::TS;
+
+pub const PEER_JOINED_EVENT: &str = "peer://joined";
+pub const PEER_LEFT_EVENT: &str = "peer://left";
+pub const TRANSFER_INCOMING_EVENT: &str = "transfer://incoming";
+pub const CHAT_MESSAGE_EVENT: &str = "chat://message";
+
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerEventPayload {
+    /// The network we were connected to when the event arrived, if known
+    pub network_id: Option<String>,
+    pub peer: PeerInfo,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ChatEventPayload {
+    pub network_id: String,
+    pub message: ChatMessage,
+    /// The sender is muted in this conversation; show it without notifying
+    pub muted: bool,
+}
+
+/// Follow the daemon's event stream for the life of the app
+pub fn spawn_event_bridge(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        // Transfer requests already announced; the daemon repeats pending ones
+        let mut announced: HashSet<String> = HashSet::new();
+        loop {
+            let subscribed = daemon_call!(daemon, "subscribe_events", |client| client.subscribe_events());
+            if let Ok(mut events) = subscribed {
+                while let Ok(Some(event)) = events.next().await {
+                    let emitted = match event {
+                        DaemonEvent::Peer(change) => {
+                            let name = if change.joined { PEER_JOINED_EVENT } else { PEER_LEFT_EVENT };
+                            let network_id = peer_index::current_network(&daemon).await;
+                            // Keep the tray's peer count current between status polls
+                            tray::refresh_status(&app).await;
+                            app.emit(name, PeerEventPayload { network_id, peer: change.peer })
+                        }
+                        DaemonEvent::Chat { network_id, message } => {
+                            let muted = spam::observe(&app, &network_id, &message).await;
+                            recent_chats::observe(&app, &network_id, &message, muted).await;
+                            if !muted {
+                                translation::offer_incoming(&app, &network_id, &message.id, &message.content);
+                                attention::chat_message(&app, &network_id, &message).await;
+                            }
+                            app.emit(CHAT_MESSAGE_EVENT, ChatEventPayload { network_id, message, muted })
+                        }
+                        DaemonEvent::Transfer(transfer) => {
+                            if transfer.direction != "download" || transfer.status != "pending" {
+                                announced.remove(&transfer.id);
+                                continue;
+                            }
+                            if !announced.insert(transfer.id.clone()) {
+                                continue;
+                            }
+                            if auto_accept::handle_incoming(&app, &transfer).await {
+                                continue;
+                            }
+                            transfer_prompt::offer(&app, &transfer).await;
+                            attention::request(&app, AttentionEvent::IncomingTransfer);
+                            app.emit(TRANSFER_INCOMING_EVENT, transfer)
+                        }
+                    };
+                    if let Err(e) = emitted {
+                        log::warn!("Failed to emit daemon event: {}", e);
+                    }
+                }
+            }
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    });
+}
@@ -0,0 +1,457 @@
+// HTTP File Drop
+// A small, time-limited HTTP server on the virtual IP for sharing with people who
+// only have a browser: a drop either serves selected files or accepts uploads.
+// Every URL carries a random token; the server stops once no drop is left.
+//
+// The protocol surface is tiny (GET pages/files, PUT uploads with Content-Length),
+// so requests are handled directly on the socket, one per connection.
+
+use crate::commands::DaemonState;
+use crate::connection::unix_now;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use ts_rs::TS;
+
+pub const FILE_DROP_PORT: u16 = 34120;
+
+const DEFAULT_TTL_MINUTES: u32 = 60;
+const MAX_TTL_MINUTES: u32 = 7 * 24 * 60;
+const DEFAULT_MAX_UPLOAD_MB: u64 = 2048;
+
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Expired drops are swept, and an idle server stopped, this often
+const SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum FileDropMode {
+    /// Guests download the selected files
+    Share,
+    /// Guests upload into `save_dir`
+    Receive,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct FileDropInfo {
+    pub id: String,
+    pub mode: FileDropMode,
+    /// Token-protected link to hand to the guest
+    pub url: String,
+    pub files: Vec<String>,
+    pub save_dir: Option<String>,
+    #[ts(type = "number")]
+    pub expires_at: u64,
+    /// Completed downloads (share) or uploads (receive)
+    pub transfers: u32,
+}
+
+struct ActiveDrop {
+    info: FileDropInfo,
+    token: String,
+    paths: Vec<PathBuf>,
+    max_upload_bytes: u64,
+}
+
+/// Managed state holding active drops and the server task
+#[derive(Default)]
+pub struct FileDropState {
+    drops: Mutex<HashMap<String, ActiveDrop>>,
+    /// Address the server is bound to, while running
+    server: Mutex<Option<(IpAddr, tauri::async_runtime::JoinHandle<()>)>>,
+}
+
+impl FileDropState {
+    fn with_token<R>(&self, token: &str, f: impl FnOnce(&mut ActiveDrop) -> R) -> Option<R> {
+        let mut drops = self.drops.lock().unwrap();
+        let now = unix_now();
+        drops.values_mut().find(|d| d.token == token && d.info.expires_at > now).map(f)
+    }
+
+    /// Drop expired entries; returns whether any remain
+    fn sweep(&self) -> bool {
+        let now = unix_now();
+        let mut drops = self.drops.lock().unwrap();
+        drops.retain(|_, d| d.info.expires_at > now);
+        !drops.is_empty()
+    }
+}
+
+// =============================================================================
+// HTTP HANDLING
+// =============================================================================
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    content_length: Option<u64>,
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| input.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (escaped, bytes[i]) {
+            (Some(b), _) => {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+            (None, b'+') => out.push(b' '),
+            (None, b) => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+async fn read_request(reader: &mut BufReader<TcpStream>) -> Option<Request> {
+    let mut line = String::new();
+    let mut total = 0;
+    total += reader.read_line(&mut line).await.ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?.to_string();
+
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        let n = reader.read_line(&mut header).await.ok()?;
+        total += n;
+        if n == 0 || total > MAX_HEADER_BYTES {
+            return None;
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().ok();
+            }
+        }
+    }
+
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+    let query = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (percent_decode(k), percent_decode(v)))
+        .collect();
+    Some(Request { method, path: path.to_string(), query, content_length })
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &[u8]) -> std::io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nX-Content-Type-Options: nosniff\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body).await
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn page(title: &str, content: &str) -> Vec<u8> {
+    format!(
+        r#"<!DOCTYPE html><html><head><meta charset="utf-8"><meta name="viewport" content="width=device-width,initial-scale=1"><title>{title}</title>
+<style>body{{font-family:system-ui,sans-serif;max-width:40rem;margin:2rem auto;padding:0 1rem;color:#1f2937}}li{{margin:.4rem 0}}.muted{{color:#6b7280}}</style>
+</head><body><h1>{title}</h1>{content}<p class="muted">Shared via GoConnect</p></body></html>"#
+    )
+    .into_bytes()
+}
+
+const UPLOAD_SCRIPT: &str = r#"<input type="file" id="files" multiple> <button id="send">Upload</button><ul id="log"></ul>
+<script>
+document.getElementById('send').onclick = async () => {
+  for (const file of document.getElementById('files').files) {
+    const li = document.createElement('li'); li.textContent = file.name + ' …';
+    document.getElementById('log').appendChild(li);
+    const res = await fetch('upload?name=' + encodeURIComponent(file.name), { method: 'PUT', body: file });
+    li.textContent = file.name + (res.ok ? ' ✓' : ' ✗ ' + await res.text());
+  }
+};
+</script>"#;
+
+/// A name that is safe to create inside the save folder and does not clobber anything
+fn upload_target(dir: &Path, requested: &str) -> PathBuf {
+    let base: String = requested
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_control() && !matches!(c, ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+        .collect();
+    let base = base.trim().trim_start_matches('.').to_string();
+    let base = if base.is_empty() { "upload".to_string() } else { base };
+
+    let candidate = dir.join(&base);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let (stem, ext) = match base.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => (stem.to_string(), format!(".{}", ext)),
+        _ => (base.clone(), String::new()),
+    };
+    (1..)
+        .map(|n| dir.join(format!("{} ({}){}", stem, n, ext)))
+        .find(|p| !p.exists())
+        .unwrap_or(candidate)
+}
+
+async fn handle(state: &FileDropState, stream: TcpStream) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    // Only the header phase is bounded; bodies may take as long as they need
+    let Ok(Some(request)) = tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut reader)).await else {
+        return Ok(());
+    };
+
+    // /d/<token>/[f/<index> | upload]
+    let segments: Vec<&str> = request.path.trim_start_matches('/').split('/').collect();
+    let (token, rest) = match segments.as_slice() {
+        ["d", token, rest @ ..] => (*token, rest.iter().copied().filter(|s| !s.is_empty()).collect::<Vec<_>>()),
+        _ => {
+            let stream = reader.get_mut();
+            return respond(stream, "404 Not Found", "text/plain", b"not found").await;
+        }
+    };
+    let Some((mode, files, save_dir, max_upload)) = state.with_token(token, |d| {
+        (d.info.mode.clone(), d.paths.clone(), d.info.save_dir.clone(), d.max_upload_bytes)
+    }) else {
+        let stream = reader.get_mut();
+        return respond(stream, "404 Not Found", "text/plain", b"this link has expired").await;
+    };
+
+    match (request.method.as_str(), mode, rest.as_slice()) {
+        ("GET", FileDropMode::Share, []) => {
+            let items: String = files
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    let name = p.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+                    format!(r#"<li><a href="f/{}">{}</a></li>"#, i, escape_html(&name))
+                })
+                .collect();
+            let body = page("Shared files", &format!("<ul>{}</ul>", items));
+            respond(reader.get_mut(), "200 OK", "text/html; charset=utf-8", &body).await
+        }
+        ("GET", FileDropMode::Share, ["f", index]) => {
+            let Some(path) = index.parse::<usize>().ok().and_then(|i| files.get(i)) else {
+                return respond(reader.get_mut(), "404 Not Found", "text/plain", b"not found").await;
+            };
+            let mut file = tokio::fs::File::open(path).await?;
+            let len = file.metadata().await?.len();
+            let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream\r\nContent-Length: {}\r\nContent-Disposition: attachment; filename=\"{}\"\r\nConnection: close\r\n\r\n",
+                len,
+                name.replace(['"', '\\', '\r', '\n'], "_")
+            );
+            let stream = reader.get_mut();
+            stream.write_all(head.as_bytes()).await?;
+            if tokio::io::copy(&mut file, stream).await? == len {
+                state.with_token(token, |d| d.info.transfers += 1);
+            }
+            Ok(())
+        }
+        ("GET", FileDropMode::Receive, []) => {
+            let body = page("Send files", UPLOAD_SCRIPT);
+            respond(reader.get_mut(), "200 OK", "text/html; charset=utf-8", &body).await
+        }
+        ("PUT", FileDropMode::Receive, ["upload"]) => {
+            let Some(len) = request.content_length else {
+                return respond(reader.get_mut(), "411 Length Required", "text/plain", b"length required").await;
+            };
+            if len > max_upload {
+                return respond(reader.get_mut(), "413 Payload Too Large", "text/plain", b"file too large").await;
+            }
+            let dir = PathBuf::from(save_dir.unwrap_or_default());
+            let name = request.query.get("name").map(String::as_str).unwrap_or_default();
+            let target = upload_target(&dir, name);
+            let mut partial = target.clone().into_os_string();
+            partial.push(".part");
+
+            let mut file = tokio::fs::File::create(&partial).await?;
+            let copied = tokio::io::copy(&mut (&mut reader).take(len), &mut file).await?;
+            file.sync_all().await?;
+            drop(file);
+            if copied != len {
+                let _ = tokio::fs::remove_file(&partial).await;
+                return Ok(());
+            }
+            tokio::fs::rename(&partial, &target).await?;
+            state.with_token(token, |d| d.info.transfers += 1);
+            log::info!("File drop received {:?} ({} bytes)", target, len);
+            respond(reader.get_mut(), "201 Created", "text/plain", b"ok").await
+        }
+        _ => respond(reader.get_mut(), "405 Method Not Allowed", "text/plain", b"not allowed").await,
+    }
+}
+
+/// Make sure the server is listening on `ip`, restarting it if the address changed
+async fn ensure_server(app: &AppHandle, ip: IpAddr) -> Result<(), String> {
+    let state = app.state::<FileDropState>();
+    {
+        let server = state.server.lock().unwrap();
+        if matches!(&*server, Some((bound, handle)) if *bound == ip && !handle.inner().is_finished()) {
+            return Ok(());
+        }
+    }
+
+    let listener = TcpListener::bind(SocketAddr::new(ip, FILE_DROP_PORT))
+        .await
+        .map_err(|e| format!("failed to start file drop server on {}: {}", ip, e))?;
+    let app_handle = app.clone();
+    let handle = tauri::async_runtime::spawn(async move {
+        let state = app_handle.state::<FileDropState>();
+        // The first sweep waits a full interval, so the drop that started the
+        // server is in place before an empty map can stop it
+        let start = tokio::time::Instant::now() + SWEEP_INTERVAL;
+        let mut sweep = tokio::time::interval_at(start, SWEEP_INTERVAL);
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let Ok((stream, _)) = accepted else { continue };
+                    let app = app_handle.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let state = app.state::<FileDropState>();
+                        if let Err(e) = handle(&state, stream).await {
+                            log::debug!("File drop request failed: {}", e);
+                        }
+                    });
+                }
+                _ = sweep.tick() => {
+                    if !state.sweep() {
+                        break;
+                    }
+                }
+            }
+        }
+        log::info!("File drop server stopped");
+    });
+
+    if let Some((_, previous)) = state.server.lock().unwrap().replace((ip, handle)) {
+        previous.abort();
+    }
+    Ok(())
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[derive(Debug, Clone, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct FileDropRequest {
+    pub mode: FileDropMode,
+    /// Files to serve (share mode)
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Folder to receive into (receive mode)
+    pub save_dir: Option<String>,
+    pub ttl_minutes: Option<u32>,
+    pub max_upload_mb: Option<u64>,
+}
+
+/// Create a drop and return its link
+#[tauri::command]
+pub async fn create_file_drop(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    state: State<'_, FileDropState>,
+    request: FileDropRequest,
+) -> Result<FileDropInfo, String> {
+    let FileDropRequest { mode, paths, save_dir, ttl_minutes, max_upload_mb } = request;
+    let status = daemon.probe_status().await.map_err(|e| e.to_string())?;
+    if !status.connected {
+        return Err("connect to a network before creating a file drop".to_string());
+    }
+    let ip: IpAddr = status.virtual_ip.parse().map_err(|_| "no virtual IP assigned".to_string())?;
+
+    let paths: Vec<PathBuf> = paths.into_iter().map(PathBuf::from).collect();
+    let save_dir = match mode {
+        FileDropMode::Share => {
+            if paths.is_empty() {
+                return Err("select at least one file to share".to_string());
+            }
+            if let Some(missing) = paths.iter().find(|p| !p.is_file()) {
+                return Err(format!("{:?} is not a file", missing));
+            }
+            None
+        }
+        FileDropMode::Receive => {
+            let dir = save_dir.ok_or("choose a folder to receive into")?;
+            if !Path::new(&dir).is_dir() {
+                return Err(format!("{} is not a folder", dir));
+            }
+            Some(dir)
+        }
+    };
+
+    let ttl = ttl_minutes.unwrap_or(DEFAULT_TTL_MINUTES).clamp(1, MAX_TTL_MINUTES);
+    let token = hex::encode(rand::random::<[u8; 24]>());
+    let id = hex::encode(rand::random::<[u8; 8]>());
+    let host = match ip {
+        IpAddr::V6(v6) => format!("[{}]", v6),
+        IpAddr::V4(v4) => v4.to_string(),
+    };
+    let info = FileDropInfo {
+        id: id.clone(),
+        mode,
+        url: format!("http://{}:{}/d/{}/", host, FILE_DROP_PORT, token),
+        files: paths.iter().map(|p| p.to_string_lossy().into_owned()).collect(),
+        save_dir,
+        expires_at: unix_now() + u64::from(ttl) * 60,
+        transfers: 0,
+    };
+
+    ensure_server(&app, ip).await?;
+    state.drops.lock().unwrap().insert(
+        id,
+        ActiveDrop {
+            info: info.clone(),
+            token,
+            paths,
+            max_upload_bytes: max_upload_mb.unwrap_or(DEFAULT_MAX_UPLOAD_MB).saturating_mul(1024 * 1024),
+        },
+    );
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn list_file_drops(state: State<'_, FileDropState>) -> Result<Vec<FileDropInfo>, String> {
+    state.sweep();
+    let mut drops: Vec<FileDropInfo> = state.drops.lock().unwrap().values().map(|d| d.info.clone()).collect();
+    drops.sort_by_key(|d| d.expires_at);
+    Ok(drops)
+}
+
+/// Revoke a drop immediately; its link stops working
+#[tauri::command]
+pub async fn close_file_drop(state: State<'_, FileDropState>, id: String) -> Result<(), String> {
+    state
+        .drops
+        .lock()
+        .unwrap()
+        .remove(&id)
+        .map(|_| ())
+        .ok_or_else(|| format!("file drop '{}' not found", id))
+}
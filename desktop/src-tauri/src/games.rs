@@ -0,0 +1,474 @@
+// Game Server Browser
+// Finds game servers hosted by peers so LAN-party users can join quickly. Each
+// client can advertise the servers it hosts on GAMES_PORT (a JSON list served on
+// connect); browsing queries those, and optionally the default ports of known
+// games, with each game's own status query for name, map, players and ping.
+// Browsing is opt-in since it sends probes to every online peer.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::store::JsonStore;
+use crate::tunnel;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::task::JoinSet;
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "games.json";
+
+pub const GAMES_PORT: u16 = 34150;
+
+const QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+const MAX_ADVERTISEMENT_BYTES: u64 = 16 * 1024;
+const MAX_STATUS_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum GameProtocol {
+    /// Valve A2S_INFO (Counter-Strike, TF2, Garry's Mod, Rust, ARK, ...)
+    Source,
+    MinecraftJava,
+    MinecraftBedrock,
+    /// id Tech 3 `getstatus` (Quake III, OpenArena, Urban Terror, ...)
+    Quake3,
+}
+
+impl GameProtocol {
+    const ALL: [GameProtocol; 4] = [Self::Source, Self::MinecraftJava, Self::MinecraftBedrock, Self::Quake3];
+
+    fn default_port(self) -> u16 {
+        match self {
+            Self::Source => 27015,
+            Self::MinecraftJava => 25565,
+            Self::MinecraftBedrock => 19132,
+            Self::Quake3 => 27960,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct AdvertisedServer {
+    pub protocol: GameProtocol,
+    pub port: u16,
+    /// Shown when the server does not answer the status query
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct GameSettings {
+    /// Allow list_game_servers to probe peers
+    pub browse_enabled: bool,
+    /// Also try each game's default port on peers that advertise nothing for it
+    pub probe_default_ports: bool,
+    /// Servers this machine hosts, announced to peers
+    pub advertised: Vec<AdvertisedServer>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct GameServer {
+    pub peer_id: String,
+    pub peer_name: String,
+    pub protocol: GameProtocol,
+    /// "ip:port" to connect to
+    pub address: String,
+    pub name: String,
+    pub map: Option<String>,
+    pub version: Option<String>,
+    pub players: Option<u32>,
+    pub max_players: Option<u32>,
+    pub ping_ms: Option<u32>,
+    pub advertised: bool,
+}
+
+/// Parsed status reply
+#[derive(Debug, Default)]
+struct ServerStatus {
+    name: String,
+    map: Option<String>,
+    version: Option<String>,
+    players: Option<u32>,
+    max_players: Option<u32>,
+}
+
+/// Managed state holding browse and advertise settings
+pub struct GameState {
+    settings: JsonStore<GameSettings>,
+    changed: tokio::sync::Notify,
+}
+
+impl GameState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), changed: tokio::sync::Notify::new() }
+    }
+}
+
+// =============================================================================
+// STATUS QUERIES
+// =============================================================================
+
+/// Send one datagram and wait for the reply
+async fn udp_exchange(addr: SocketAddr, request: &[u8]) -> std::io::Result<Vec<u8>> {
+    let bind: SocketAddr = if addr.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" }.parse().unwrap();
+    let socket = UdpSocket::bind(bind).await?;
+    socket.connect(addr).await?;
+    socket.send(request).await?;
+    let mut buf = vec![0u8; MAX_STATUS_BYTES];
+    let n = socket.recv(&mut buf).await?;
+    buf.truncate(n);
+    Ok(buf)
+}
+
+/// Reader over a status reply; every accessor fails softly on short input
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let slice = self.data.get(self.pos..self.pos + n)?;
+        self.pos += n;
+        Some(slice)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.bytes(1).map(|b| b[0])
+    }
+
+    fn cstring(&mut self) -> Option<String> {
+        let rest = self.data.get(self.pos..)?;
+        let end = rest.iter().position(|&b| b == 0)?;
+        self.pos += end + 1;
+        Some(String::from_utf8_lossy(&rest[..end]).into_owned())
+    }
+}
+
+const A2S_HEADER: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF];
+
+async fn query_source(addr: SocketAddr) -> std::io::Result<ServerStatus> {
+    let mut request = [A2S_HEADER, b"TSource Engine Query\0"].concat();
+    let mut reply = udp_exchange(addr, &request).await?;
+    // Newer servers answer with a challenge that must be echoed back
+    if reply.len() >= 9 && reply[4] == b'A' {
+        request.extend_from_slice(&reply[5..9]);
+        reply = udp_exchange(addr, &request).await?;
+    }
+
+    let mut c = Cursor::new(&reply);
+    let parsed = (|| {
+        (c.bytes(4)? == A2S_HEADER && c.u8()? == b'I').then_some(())?;
+        c.u8()?; // protocol
+        let name = c.cstring()?;
+        let map = c.cstring()?;
+        c.cstring()?; // folder
+        let game = c.cstring()?;
+        c.bytes(2)?; // app id
+        let players = c.u8()?;
+        let max_players = c.u8()?;
+        Some(ServerStatus {
+            name,
+            map: Some(map),
+            version: Some(game),
+            players: Some(u32::from(players)),
+            max_players: Some(u32::from(max_players)),
+        })
+    })();
+    parsed.ok_or_else(|| std::io::Error::other("not an A2S_INFO reply"))
+}
+
+fn write_varint(out: &mut Vec<u8>, value: i32) {
+    let mut value = value as u32;
+    loop {
+        if value & !0x7F == 0 {
+            out.push(value as u8);
+            return;
+        }
+        out.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+}
+
+async fn read_varint(stream: &mut TcpStream) -> std::io::Result<i32> {
+    let mut value = 0u32;
+    for shift in (0..35).step_by(7) {
+        let byte = stream.read_u8().await?;
+        value |= u32::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value as i32);
+        }
+    }
+    Err(std::io::Error::other("varint too long"))
+}
+
+/// Flatten a chat component (`"text"` or `{"text", "extra": [...]}`) to plain text
+fn chat_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Object(obj) => {
+            let mut text = obj.get("text").and_then(|t| t.as_str()).unwrap_or_default().to_string();
+            for extra in obj.get("extra").and_then(|e| e.as_array()).into_iter().flatten() {
+                text.push_str(&chat_text(extra));
+            }
+            text
+        }
+        _ => String::new(),
+    }
+}
+
+/// Minecraft: Java Edition server list ping
+async fn query_minecraft_java(addr: SocketAddr) -> std::io::Result<ServerStatus> {
+    let mut stream = TcpStream::connect(addr).await?;
+    let host = addr.ip().to_string();
+
+    let mut handshake = vec![0x00];
+    write_varint(&mut handshake, -1); // protocol version: "just tell me the status"
+    write_varint(&mut handshake, host.len() as i32);
+    handshake.extend_from_slice(host.as_bytes());
+    handshake.extend_from_slice(&addr.port().to_be_bytes());
+    write_varint(&mut handshake, 1); // next state: status
+
+    let mut packet = Vec::with_capacity(handshake.len() + 5);
+    write_varint(&mut packet, handshake.len() as i32);
+    packet.extend_from_slice(&handshake);
+    packet.extend_from_slice(&[0x01, 0x00]); // status request
+    stream.write_all(&packet).await?;
+
+    read_varint(&mut stream).await?; // packet length
+    if read_varint(&mut stream).await? != 0x00 {
+        return Err(std::io::Error::other("unexpected status packet"));
+    }
+    let len = read_varint(&mut stream).await? as usize;
+    if len > MAX_STATUS_BYTES {
+        return Err(std::io::Error::other("status reply too large"));
+    }
+    let mut json = vec![0u8; len];
+    stream.read_exact(&mut json).await?;
+
+    let status: serde_json::Value = serde_json::from_slice(&json).map_err(std::io::Error::other)?;
+    let count = |key: &str| status["players"][key].as_u64().map(|n| n as u32);
+    Ok(ServerStatus {
+        name: chat_text(&status["description"]),
+        map: None,
+        version: status["version"]["name"].as_str().map(String::from),
+        players: count("online"),
+        max_players: count("max"),
+    })
+}
+
+const RAKNET_MAGIC: [u8; 16] = [
+    0x00, 0xFF, 0xFF, 0x00, 0xFE, 0xFE, 0xFE, 0xFE, 0xFD, 0xFD, 0xFD, 0xFD, 0x12, 0x34, 0x56, 0x78,
+];
+
+/// Minecraft: Bedrock Edition RakNet unconnected ping
+async fn query_minecraft_bedrock(addr: SocketAddr) -> std::io::Result<ServerStatus> {
+    let mut request = vec![0x01];
+    request.extend_from_slice(&0i64.to_be_bytes());
+    request.extend_from_slice(&RAKNET_MAGIC);
+    request.extend_from_slice(&rand::random::<i64>().to_be_bytes());
+    let reply = udp_exchange(addr, &request).await?;
+
+    // 0x1c, time, server guid, magic, u16 length, "MCPE;motd;protocol;version;players;max;..."
+    let mut c = Cursor::new(&reply);
+    let parsed = (|| {
+        (c.u8()? == 0x1C).then_some(())?;
+        c.bytes(8 + 8 + 16)?;
+        let len = u16::from_be_bytes(c.bytes(2)?.try_into().ok()?) as usize;
+        let text = String::from_utf8_lossy(c.bytes(len)?).into_owned();
+        let fields: Vec<&str> = text.split(';').collect();
+        Some(ServerStatus {
+            name: fields.get(1)?.to_string(),
+            map: fields.get(7).filter(|m| !m.is_empty()).map(|m| m.to_string()),
+            version: fields.get(3).map(|v| v.to_string()),
+            players: fields.get(4).and_then(|p| p.parse().ok()),
+            max_players: fields.get(5).and_then(|p| p.parse().ok()),
+        })
+    })();
+    parsed.ok_or_else(|| std::io::Error::other("not a RakNet pong"))
+}
+
+/// Drop Quake-style `^1` color codes
+fn strip_colors(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '^' {
+            chars.next();
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// id Tech 3 `getstatus`: an info string of `\key\value` pairs, then one line per player
+async fn query_quake3(addr: SocketAddr) -> std::io::Result<ServerStatus> {
+    let reply = udp_exchange(addr, &[A2S_HEADER, b"getstatus\n"].concat()).await?;
+    let text = String::from_utf8_lossy(reply.get(4..).unwrap_or_default()).into_owned();
+    let mut lines = text.lines();
+    if lines.next() != Some("statusResponse") {
+        return Err(std::io::Error::other("not a statusResponse"));
+    }
+    let info = lines.next().unwrap_or_default();
+    let mut parts = info.trim_start_matches('\\').split('\\');
+    let mut vars = std::collections::HashMap::new();
+    while let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+        vars.insert(key.to_ascii_lowercase(), value.to_string());
+    }
+    let players = lines.filter(|l| !l.trim().is_empty()).count() as u32;
+    Ok(ServerStatus {
+        name: strip_colors(vars.get("sv_hostname").map(String::as_str).unwrap_or_default()),
+        map: vars.get("mapname").cloned(),
+        version: vars.get("version").or_else(|| vars.get("gamename")).cloned(),
+        players: Some(players),
+        max_players: vars.get("sv_maxclients").and_then(|m| m.parse().ok()),
+    })
+}
+
+/// Query one server; the round trip of the status exchange doubles as its ping
+async fn query(protocol: GameProtocol, addr: SocketAddr) -> Option<(ServerStatus, u32)> {
+    let started = Instant::now();
+    let status = tokio::time::timeout(QUERY_TIMEOUT, async {
+        match protocol {
+            GameProtocol::Source => query_source(addr).await,
+            GameProtocol::MinecraftJava => query_minecraft_java(addr).await,
+            GameProtocol::MinecraftBedrock => query_minecraft_bedrock(addr).await,
+            GameProtocol::Quake3 => query_quake3(addr).await,
+        }
+    })
+    .await
+    .ok()?
+    .ok()?;
+    Some((status, started.elapsed().as_millis() as u32))
+}
+
+// =============================================================================
+// ADVERTISING
+// =============================================================================
+
+/// Servers a peer advertises; empty when it has none or runs no listener
+async fn fetch_advertised(ip: IpAddr) -> Vec<AdvertisedServer> {
+    let fetch = async {
+        let stream = TcpStream::connect(SocketAddr::new(ip, GAMES_PORT)).await.ok()?;
+        let mut body = Vec::new();
+        stream.take(MAX_ADVERTISEMENT_BYTES).read_to_end(&mut body).await.ok()?;
+        serde_json::from_slice(&body).ok()
+    };
+    tokio::time::timeout(QUERY_TIMEOUT, fetch).await.ok().flatten().unwrap_or_default()
+}
+
+/// Serve our advertised servers on the virtual IP while there are any
+pub fn spawn(app: AppHandle) {
+    tunnel::serve(
+        app,
+        "Game server advertiser",
+        GAMES_PORT,
+        |app| !app.state::<GameState>().settings.get().advertised.is_empty(),
+        Some(|app| &app.state::<GameState>().inner().changed),
+        |app, mut stream, _| async move {
+            let body = serde_json::to_vec(&app.state::<GameState>().settings.get().advertised).unwrap_or_default();
+            let _ = tokio::time::timeout(QUERY_TIMEOUT, stream.write_all(&body)).await;
+        },
+    );
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_game_settings(state: State<'_, GameState>) -> Result<GameSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_game_settings(state: State<'_, GameState>, settings: GameSettings) -> Result<GameSettings, String> {
+    if settings.advertised.iter().any(|a| a.port == 0) {
+        return Err("advertised servers need a port".to_string());
+    }
+    state.settings.set(settings.clone())?;
+    state.changed.notify_one();
+    Ok(settings)
+}
+
+/// Query every online peer's advertised servers (and default game ports when
+/// enabled), returning the ones that answered, fastest first
+#[tauri::command]
+pub async fn list_game_servers(
+    daemon: State<'_, DaemonState>,
+    state: State<'_, GameState>,
+) -> Result<Vec<GameServer>, String> {
+    let settings = state.settings.get();
+    if !settings.browse_enabled {
+        return Err("game server browsing is off; enable it in settings first".to_string());
+    }
+    let peers = daemon_call!(daemon, "get_peers", |client| client.get_peers())?;
+
+    let mut queries = JoinSet::new();
+    for peer in peers.into_iter().filter(|p| p.connected && !p.is_self) {
+        let Ok(ip) = peer.virtual_ip.parse::<IpAddr>() else { continue };
+        let probe_defaults = settings.probe_default_ports;
+        queries.spawn(async move {
+            let advertised = fetch_advertised(ip).await;
+            let mut targets: Vec<(GameProtocol, u16, Option<String>)> =
+                advertised.iter().map(|a| (a.protocol, a.port, Some(a.label.clone()))).collect();
+            if probe_defaults {
+                for protocol in GameProtocol::ALL {
+                    if !advertised.iter().any(|a| a.protocol == protocol) {
+                        targets.push((protocol, protocol.default_port(), None));
+                    }
+                }
+            }
+
+            let peer_name = if peer.display_name.is_empty() { peer.name.clone() } else { peer.display_name.clone() };
+            let mut found = Vec::new();
+            for (protocol, port, label) in targets {
+                let addr = SocketAddr::new(ip, port);
+                let answered = query(protocol, addr).await;
+                // Advertised servers are listed even when they do not answer the query
+                if answered.is_none() && label.is_none() {
+                    continue;
+                }
+                let (status, ping_ms) = match answered {
+                    Some((status, ping)) => (status, Some(ping)),
+                    None => (ServerStatus::default(), None),
+                };
+                found.push(GameServer {
+                    peer_id: peer.id.clone(),
+                    peer_name: peer_name.clone(),
+                    protocol,
+                    address: addr.to_string(),
+                    name: match (&label, status.name.trim()) {
+                        (_, name) if !name.is_empty() => name.to_string(),
+                        (Some(label), _) => label.clone(),
+                        (None, _) => peer_name.clone(),
+                    },
+                    map: status.map,
+                    version: status.version,
+                    players: status.players,
+                    max_players: status.max_players,
+                    ping_ms,
+                    advertised: label.is_some(),
+                });
+            }
+            found
+        });
+    }
+
+    let mut servers = Vec::new();
+    while let Some(result) = queries.join_next().await {
+        servers.extend(result.unwrap_or_default());
+    }
+    servers.sort_by_key(|s| (s.ping_ms.is_none(), s.ping_ms));
+    Ok(servers)
+}
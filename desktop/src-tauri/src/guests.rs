@@ -0,0 +1,162 @@
+// Guest Membership
+// Joining as a guest is time-boxed: after the chosen number of hours the app
+// leaves the network again by itself, purging what it cached for it, which
+// suits one-off collaboration sessions. The daemon knows nothing about this;
+// the expiry is kept here, checked on every scheduler tick and shown on
+// `NetworkInfo::guest_until`. A guest membership can be extended or made
+// permanent at any time.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::NetworkInfo;
+use crate::i18n::{self, Text};
+use crate::leave::{self, LeaveOptions, LeaveOutcome};
+use crate::notifications::{self, NotificationKind};
+use crate::store::JsonStore;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const GUESTS_FILE: &str = "guest_memberships.json";
+
+const MAX_GUEST_HOURS: u32 = 30 * 24;
+
+#[derive(Debug, Clone, Default, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct JoinOptions {
+    /// Join as a guest and leave again after this many hours
+    pub guest_hours: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct GuestMembership {
+    pub network_id: String,
+    pub network_name: String,
+    /// Unix timestamps (seconds)
+    #[ts(type = "number")]
+    pub joined_at: u64,
+    #[ts(type = "number")]
+    pub expires_at: u64,
+}
+
+/// Managed state holding the persisted guest memberships
+pub struct GuestState {
+    memberships: JsonStore<Vec<GuestMembership>>,
+}
+
+impl GuestState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { memberships: JsonStore::open(app, GUESTS_FILE) }
+    }
+
+    /// Fill in `guest_until` for networks joined as a guest
+    pub fn annotate(&self, networks: &mut [NetworkInfo]) {
+        let memberships = self.memberships.get();
+        for network in networks {
+            network.guest_until = memberships.iter().find(|m| m.network_id == network.id).map(|m| m.expires_at);
+        }
+    }
+
+    /// Start (or restart) a guest membership for a network just joined
+    pub fn register(&self, network: &NetworkInfo, hours: u32) -> Result<u64, String> {
+        validate_hours(hours)?;
+        let now = unix_now();
+        let expires_at = now + u64::from(hours) * 3600;
+        self.memberships.update(|memberships| {
+            memberships.retain(|m| m.network_id != network.id);
+            memberships.push(GuestMembership {
+                network_id: network.id.clone(),
+                network_name: network.name.clone(),
+                joined_at: now,
+                expires_at,
+            });
+        })?;
+        log::info!("Joined {} as a guest for {} hours", network.name, hours);
+        Ok(expires_at)
+    }
+}
+
+pub(crate) fn validate_hours(hours: u32) -> Result<(), String> {
+    if !(1..=MAX_GUEST_HOURS).contains(&hours) {
+        return Err(format!("guest membership must last between 1 and {} hours", MAX_GUEST_HOURS));
+    }
+    Ok(())
+}
+
+/// Leave guest networks whose time is up; run from the scheduler tick
+pub(crate) async fn expire_due(app: &AppHandle) {
+    let state = app.state::<GuestState>();
+    let memberships = state.memberships.get();
+    if memberships.is_empty() {
+        return;
+    }
+    let daemon = app.state::<DaemonState>();
+    let Ok(networks) = daemon_call!(daemon, "list_networks", |client| client.list_networks()) else { return };
+
+    let now = unix_now();
+    for membership in memberships {
+        let joined = networks.iter().any(|n| n.id == membership.network_id);
+        if joined && membership.expires_at > now {
+            continue;
+        }
+        if joined {
+            // Guests never own what they joined, so there is nothing to confirm
+            let options =
+                LeaveOptions { confirmed: true, cancel_transfers: true, purge_chat_history: true, purge_aliases: true };
+            match leave::leave(app, &membership.network_id, options).await {
+                Ok(LeaveOutcome::Left { .. }) => {
+                    let body = i18n::text(Text::GuestLeftBody).replace("{network}", &membership.network_name);
+                    notifications::notify(app, NotificationKind::System, i18n::text(Text::GuestLeftTitle), &body);
+                }
+                Ok(LeaveOutcome::NeedsConfirmation { .. }) => continue,
+                Err(e) => {
+                    log::warn!("Failed to leave guest network {}: {}", membership.network_name, e);
+                    continue;
+                }
+            }
+        }
+        // Left, or already gone by other means
+        if let Err(e) = state.memberships.update(|m| m.retain(|m| m.network_id != membership.network_id)) {
+            log::warn!("Failed to update guest memberships: {}", e);
+        }
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Networks joined as a guest, soonest to expire first
+#[tauri::command]
+pub async fn list_guest_memberships(state: State<'_, GuestState>) -> Result<Vec<GuestMembership>, String> {
+    let mut memberships = state.memberships.get();
+    memberships.sort_by_key(|m| m.expires_at);
+    Ok(memberships)
+}
+
+/// Let a guest membership run `hours` from now, or with `hours` omitted make it permanent
+#[tauri::command]
+pub async fn set_guest_expiry(
+    state: State<'_, GuestState>,
+    network_id: String,
+    hours: Option<u32>,
+) -> Result<Option<GuestMembership>, String> {
+    if let Some(hours) = hours {
+        validate_hours(hours)?;
+    }
+    state.memberships.update(|memberships| {
+        let index = memberships.iter().position(|m| m.network_id == network_id);
+        let index = index.ok_or_else(|| "that network was not joined as a guest".to_string())?;
+        match hours {
+            Some(hours) => {
+                memberships[index].expires_at = unix_now() + u64::from(hours) * 3600;
+                Ok(Some(memberships[index].clone()))
+            }
+            None => {
+                memberships.remove(index);
+                Ok(None)
+            }
+        }
+    })?
+}
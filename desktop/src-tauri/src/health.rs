@@ -0,0 +1,255 @@
+// Network Health
+// A 0-100 score per network built from four factors: share of peers reached
+// through the relay, average latency, packet loss and disconnects in the last day.
+// Loss is measured with UDP echoes to each peer's health responder
+// (HEALTH_PORT); factors without data are left out and the rest reweighted.
+// The active network is scored in the background for the tray tooltip.
+
+use crate::activity::{ActivityState, SessionEnd};
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::PeerInfo;
+use crate::tunnel;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::net::UdpSocket;
+use tokio::task::JoinSet;
+use ts_rs::TS;
+
+pub const HEALTH_PORT: u16 = 34170;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+const ECHO_COUNT: usize = 10;
+const ECHO_SPACING: Duration = Duration::from_millis(50);
+/// Replies later than this count as lost
+const ECHO_WAIT: Duration = Duration::from_millis(1500);
+const ECHO_MAGIC: &[u8; 4] = b"GCHE";
+
+/// Disconnects counted over this period
+const DISCONNECT_WINDOW_SECS: u64 = 86_400;
+
+// Factor weights (points out of 100) and the values at which they cost all of them
+const RELAY_WEIGHT: f64 = 25.0;
+const LATENCY_WEIGHT: f64 = 30.0;
+const LATENCY_GOOD_MS: f64 = 50.0;
+const LATENCY_BAD_MS: f64 = 300.0;
+const LOSS_WEIGHT: f64 = 30.0;
+const LOSS_BAD_PERCENT: f64 = 10.0;
+const DISCONNECT_WEIGHT: f64 = 15.0;
+const DISCONNECTS_BAD: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum HealthFactorKind {
+    RelayRatio,
+    Latency,
+    PacketLoss,
+    Disconnects,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct HealthFactor {
+    pub kind: HealthFactorKind,
+    /// Percent for ratios and loss, milliseconds for latency, a count for disconnects;
+    /// `None` when there was nothing to measure
+    pub value: Option<f64>,
+    /// Points this factor took off the score
+    pub penalty: f64,
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct NetworkHealth {
+    pub network_id: String,
+    pub network_name: String,
+    /// 0-100; `None` when no factor could be measured
+    pub score: Option<u8>,
+    pub factors: Vec<HealthFactor>,
+    pub online_peers: u32,
+    #[ts(type = "number")]
+    pub computed_at: u64,
+}
+
+/// Managed state holding the latest score of the active network
+#[derive(Default)]
+pub struct HealthState {
+    latest: Mutex<Option<NetworkHealth>>,
+}
+
+/// Share of echoes lost to `ip`, or `None` when the peer runs no responder
+async fn measure_loss(ip: IpAddr) -> Option<f64> {
+    let bind: SocketAddr = if ip.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" }.parse().ok()?;
+    let socket = UdpSocket::bind(bind).await.ok()?;
+    socket.connect(SocketAddr::new(ip, HEALTH_PORT)).await.ok()?;
+
+    let nonce: [u8; 4] = rand::random();
+    let send = async {
+        for seq in 0..ECHO_COUNT as u8 {
+            let mut packet = [0u8; 9];
+            packet[..4].copy_from_slice(ECHO_MAGIC);
+            packet[4..8].copy_from_slice(&nonce);
+            packet[8] = seq;
+            let _ = socket.send(&packet).await;
+            tokio::time::sleep(ECHO_SPACING).await;
+        }
+    };
+    let receive = async {
+        let mut seen = [false; ECHO_COUNT];
+        let mut buf = [0u8; 16];
+        let deadline = tokio::time::Instant::now() + ECHO_SPACING * ECHO_COUNT as u32 + ECHO_WAIT;
+        while let Ok(Ok(n)) = tokio::time::timeout_at(deadline, socket.recv(&mut buf)).await {
+            let echo = &buf[..n];
+            if n == 9 && &echo[..4] == ECHO_MAGIC && echo[4..8] == nonce && (echo[8] as usize) < ECHO_COUNT {
+                seen[echo[8] as usize] = true;
+            }
+        }
+        seen.iter().filter(|s| **s).count()
+    };
+    let ((), received) = tokio::join!(send, receive);
+    // No reply at all means no responder (or a dead path), not 100% loss
+    (received > 0).then(|| (ECHO_COUNT - received) as f64 * 100.0 / ECHO_COUNT as f64)
+}
+
+/// Average loss over the connected peers that answer echoes
+async fn average_loss(peers: &[PeerInfo]) -> Option<f64> {
+    let mut probes = JoinSet::new();
+    for ip in peers.iter().filter_map(|p| p.virtual_ip.parse::<IpAddr>().ok()) {
+        probes.spawn(measure_loss(ip));
+    }
+    let losses: Vec<f64> = probes.join_all().await.into_iter().flatten().collect();
+    (!losses.is_empty()).then(|| losses.iter().sum::<f64>() / losses.len() as f64)
+}
+
+fn factor(kind: HealthFactorKind, weight: f64, value: Option<f64>, badness: impl Fn(f64) -> f64) -> HealthFactor {
+    HealthFactor { kind, value, penalty: value.map_or(0.0, |v| weight * badness(v).clamp(0.0, 1.0)), weight }
+}
+
+/// Score `network_id`; latency and loss are only measurable on the active network
+async fn compute(app: &AppHandle, network_id: &str, network_name: &str, active: bool) -> Result<NetworkHealth, String> {
+    let daemon = app.state::<DaemonState>();
+    let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(network_id))?;
+    let online: Vec<PeerInfo> = peers.into_iter().filter(|p| p.connected && !p.is_self).collect();
+
+    let relay_ratio = (!online.is_empty())
+        .then(|| online.iter().filter(|p| p.is_relay).count() as f64 * 100.0 / online.len() as f64);
+    let latencies: Vec<f64> = online.iter().filter(|p| p.latency_ms > 0).map(|p| p.latency_ms as f64).collect();
+    let latency = (!latencies.is_empty()).then(|| latencies.iter().sum::<f64>() / latencies.len() as f64);
+    let loss = if active && !online.is_empty() { average_loss(&online).await } else { None };
+
+    let cutoff = unix_now().saturating_sub(DISCONNECT_WINDOW_SECS);
+    let disconnects = app
+        .state::<ActivityState>()
+        .snapshot()
+        .sessions
+        .iter()
+        .filter(|s| s.network == network_name && s.ended_at.is_some_and(|end| end >= cutoff))
+        // Switching networks on purpose is not a sign of trouble
+        .filter(|s| s.end != Some(SessionEnd::Switched))
+        .count() as f64;
+
+    let factors = vec![
+        factor(HealthFactorKind::RelayRatio, RELAY_WEIGHT, relay_ratio, |r| r / 100.0),
+        factor(HealthFactorKind::Latency, LATENCY_WEIGHT, latency, |l| {
+            (l - LATENCY_GOOD_MS) / (LATENCY_BAD_MS - LATENCY_GOOD_MS)
+        }),
+        factor(HealthFactorKind::PacketLoss, LOSS_WEIGHT, loss, |p| p / LOSS_BAD_PERCENT),
+        factor(HealthFactorKind::Disconnects, DISCONNECT_WEIGHT, Some(disconnects), |d| d / DISCONNECTS_BAD),
+    ];
+
+    // Measured factors are scaled up to cover the full 100 points
+    let measured_weight: f64 = factors.iter().filter(|f| f.value.is_some()).map(|f| f.weight).sum();
+    let penalty: f64 = factors.iter().map(|f| f.penalty).sum();
+    let score = (measured_weight > 0.0)
+        .then(|| (100.0 * (1.0 - penalty / measured_weight)).round().clamp(0.0, 100.0) as u8);
+
+    Ok(NetworkHealth {
+        network_id: network_id.to_string(),
+        network_name: network_name.to_string(),
+        score,
+        factors,
+        online_peers: online.len() as u32,
+        computed_at: unix_now(),
+    })
+}
+
+/// The active network's id and name, if connected
+async fn active_network(app: &AppHandle) -> Option<(String, String)> {
+    let daemon = app.state::<DaemonState>();
+    let name = daemon.latest_status().filter(|s| s.connected)?.network_name;
+    let networks = daemon_call!(daemon, "list_networks", |client| client.list_networks()).ok()?;
+    networks.into_iter().find(|n| n.name == name).map(|n| (n.id, n.name))
+}
+
+/// Echo health probes on the virtual IP
+async fn respond(socket: UdpSocket) {
+    let mut buf = [0u8; 16];
+    loop {
+        let Ok((n, from)) = socket.recv_from(&mut buf).await else { continue };
+        if n == 9 && &buf[..4] == ECHO_MAGIC {
+            let _ = socket.send_to(&buf[..n], from).await;
+        }
+    }
+}
+
+/// Run the echo responder and keep the active network's score fresh
+pub fn spawn(app: AppHandle) {
+    tunnel::follow(app.clone(), |_| true, None, |_, ip| async move {
+        match UdpSocket::bind(SocketAddr::new(ip, HEALTH_PORT)).await {
+            Ok(socket) => respond(socket).await,
+            Err(e) => log::warn!("Health responder could not bind {}: {}", ip, e),
+        }
+    });
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let health = match active_network(&app).await {
+                Some((id, name)) => compute(&app, &id, &name, true).await.ok(),
+                None => None,
+            };
+            crate::tray::set_health(&app, health.as_ref().and_then(|h| Some((h.network_name.clone(), h.score?))));
+            *app.state::<HealthState>().latest.lock().unwrap() = health;
+            tokio::time::sleep(REFRESH_INTERVAL).await;
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Health of `network_id`, or of the active network when omitted
+#[tauri::command]
+pub async fn get_network_health(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    network_id: Option<String>,
+) -> Result<NetworkHealth, String> {
+    let active = active_network(&app).await;
+    let (id, name) = match network_id {
+        Some(id) => {
+            let networks = daemon_call!(daemon, "list_networks", |client| client.list_networks())?;
+            let network = networks.into_iter().find(|n| n.id == id).ok_or_else(|| format!("network '{}' not found", id))?;
+            (network.id, network.name)
+        }
+        None => active.clone().ok_or("not connected to a network")?,
+    };
+    let is_active = active.is_some_and(|(active_id, _)| active_id == id);
+    // The background refresh already probed the active network recently
+    let cached = app.state::<HealthState>().latest.lock().unwrap().clone();
+    if let Some(cached) = cached.filter(|h| is_active && h.network_id == id) {
+        if unix_now().saturating_sub(cached.computed_at) < REFRESH_INTERVAL.as_secs() {
+            return Ok(cached);
+        }
+    }
+    let health = compute(&app, &id, &name, is_active).await?;
+    if is_active {
+        *app.state::<HealthState>().latest.lock().unwrap() = Some(health.clone());
+    }
+    Ok(health)
+}
@@ -0,0 +1,307 @@
+// Transfer History
+// The daemon only lists the transfers it still remembers, which are gone once
+// it restarts, so every transfer that completes, fails or is cancelled is also
+// written to a local SQLite database. Entries are recorded from the transfer
+// stream as they finish, and finished transfers the daemon still lists are
+// picked up once at startup. The history can be paged, searched and cleared.
+
+use crate::activity::ActivityState;
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::{PeerInfo, TransferInfo};
+use crate::peer_index::PeerIndexState;
+use rusqlite::{params, Connection, Row, ToSql};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const DATABASE_FILE: &str = "transfer_history.sqlite3";
+
+const DEFAULT_PAGE_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 500;
+
+/// How often the startup backfill retries while the daemon is unreachable
+const BACKFILL_RETRY: Duration = Duration::from_secs(10);
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS transfers (
+        id TEXT PRIMARY KEY,
+        peer_id TEXT NOT NULL,
+        peer_name TEXT NOT NULL,
+        network TEXT NOT NULL,
+        file_name TEXT NOT NULL,
+        direction TEXT NOT NULL,
+        status TEXT NOT NULL,
+        bytes INTEGER NOT NULL,
+        file_size INTEGER NOT NULL,
+        error TEXT,
+        path TEXT,
+        finished_at INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS transfers_finished_at ON transfers (finished_at);
+";
+
+const COLUMNS: &str =
+    "id, peer_id, peer_name, network, file_name, direction, status, bytes, file_size, error, path, finished_at";
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TransferHistoryEntry {
+    pub id: String,
+    pub peer_id: String,
+    pub peer_name: String,
+    /// Network the client was connected to when the transfer finished
+    pub network: String,
+    pub file_name: String,
+    /// "upload" or "download"
+    pub direction: String,
+    /// "completed", "failed" or "cancelled"
+    pub status: String,
+    /// Bytes actually moved
+    #[ts(type = "number")]
+    pub bytes: u64,
+    #[ts(type = "number")]
+    pub file_size: u64,
+    pub error: Option<String>,
+    /// Where a download was saved, or what was uploaded; `None` when unknown
+    pub path: Option<String>,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub finished_at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TransferHistoryPage {
+    /// Newest first
+    pub items: Vec<TransferHistoryEntry>,
+    /// Matching entries across all pages
+    #[ts(type = "number")]
+    pub total: u64,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl TransferHistoryEntry {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            peer_id: row.get(1)?,
+            peer_name: row.get(2)?,
+            network: row.get(3)?,
+            file_name: row.get(4)?,
+            direction: row.get(5)?,
+            status: row.get(6)?,
+            bytes: row.get::<_, i64>(7)?.max(0) as u64,
+            file_size: row.get::<_, i64>(8)?.max(0) as u64,
+            error: row.get(9)?,
+            path: row.get(10)?,
+            finished_at: row.get::<_, i64>(11)?.max(0) as u64,
+        })
+    }
+}
+
+/// Managed state holding the history database; `None` when it could not be opened
+pub struct HistoryState {
+    db: Mutex<Option<Connection>>,
+}
+
+impl HistoryState {
+    pub fn load(app: &AppHandle) -> Self {
+        let path = crate::store::data_dir(app).join(DATABASE_FILE);
+        let db = Connection::open(&path).and_then(|db| db.execute_batch(SCHEMA).map(|_| db));
+        let db = db
+            .inspect_err(|e| log::warn!("Transfer history is unavailable, failed to open {:?}: {}", path, e))
+            .ok();
+        Self { db: Mutex::new(db) }
+    }
+
+    fn with_db<T>(&self, f: impl FnOnce(&Connection) -> rusqlite::Result<T>) -> Result<T, String> {
+        let db = self.db.lock().unwrap();
+        let db = db.as_ref().ok_or("the transfer history database could not be opened")?;
+        f(db).map_err(|e| format!("transfer history query failed: {}", e))
+    }
+
+    fn record(&self, entry: &TransferHistoryEntry) -> Result<(), String> {
+        self.with_db(|db| {
+            db.execute(
+                &format!(
+                    "INSERT INTO transfers ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+                     ON CONFLICT (id) DO NOTHING",
+                    COLUMNS
+                ),
+                params![
+                    entry.id,
+                    entry.peer_id,
+                    entry.peer_name,
+                    entry.network,
+                    entry.file_name,
+                    entry.direction,
+                    entry.status,
+                    entry.bytes as i64,
+                    entry.file_size as i64,
+                    entry.error,
+                    entry.path,
+                    entry.finished_at as i64,
+                ],
+            )
+            .map(|_| ())
+        })
+    }
+
+    /// One page of the entries matching `filter`, an SQL condition over `args` as `?1`, `?2`, ...
+    fn page(
+        &self,
+        filter: &str,
+        args: &[&dyn ToSql],
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> Result<TransferHistoryPage, String> {
+        let page = page.unwrap_or(0);
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        let offset = i64::from(page) * i64::from(page_size);
+        self.with_db(|db| {
+            let count = format!("SELECT COUNT(*) FROM transfers WHERE {}", filter);
+            let total: i64 = db.query_row(&count, args, |row| row.get(0))?;
+            let select = format!(
+                "SELECT {} FROM transfers WHERE {} ORDER BY finished_at DESC, id LIMIT ?{} OFFSET ?{}",
+                COLUMNS,
+                filter,
+                args.len() + 1,
+                args.len() + 2
+            );
+            let mut paged = args.to_vec();
+            paged.extend([&page_size as &dyn ToSql, &offset]);
+            let items = db
+                .prepare(&select)?
+                .query_map(paged.as_slice(), TransferHistoryEntry::from_row)?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok(TransferHistoryPage { items, total: total.max(0) as u64, page, page_size })
+        })
+    }
+}
+
+fn is_finished(transfer: &TransferInfo) -> bool {
+    matches!(transfer.status.as_str(), "completed" | "failed" | "cancelled")
+}
+
+/// Record finished transfers that are not in the history yet
+async fn record_finished(app: &AppHandle, transfers: Vec<TransferInfo>) {
+    let finished: Vec<TransferInfo> = transfers.into_iter().filter(is_finished).collect();
+    if finished.is_empty() {
+        return;
+    }
+    let daemon = app.state::<DaemonState>();
+    let peer_ids: Vec<String> = finished.iter().map(|t| t.peer_id.clone()).collect();
+    let names = app
+        .state::<PeerIndexState>()
+        .with_snapshot(&daemon, None, |s| {
+            let name = |p: &PeerInfo| if p.display_name.is_empty() { p.name.clone() } else { p.display_name.clone() };
+            peer_ids.iter().map(|id| s.peers.get(id).map(name)).collect::<Vec<_>>()
+        })
+        .await
+        .map(|(_, names)| names)
+        .unwrap_or_default();
+    let network = daemon.latest_status().filter(|s| s.connected).map(|s| s.network_name).unwrap_or_default();
+
+    let activity = app.state::<ActivityState>();
+    let history = app.state::<HistoryState>();
+    let now = unix_now();
+    for (i, transfer) in finished.into_iter().enumerate() {
+        let entry = TransferHistoryEntry {
+            peer_name: names.get(i).cloned().flatten().unwrap_or_else(|| transfer.peer_id.clone()),
+            path: activity.path_of(&transfer.id),
+            network: network.clone(),
+            finished_at: now,
+            id: transfer.id,
+            peer_id: transfer.peer_id,
+            file_name: transfer.file_name,
+            direction: transfer.direction,
+            status: transfer.status,
+            bytes: transfer.transferred,
+            file_size: transfer.file_size,
+            error: transfer.error,
+        };
+        if let Err(e) = history.record(&entry) {
+            log::warn!("Failed to record transfer {} in the history: {}", entry.id, e);
+        }
+    }
+}
+
+/// Record a transfer from the transfer stream once it has finished
+pub(crate) fn on_transfer_update(app: &AppHandle, transfer: &TransferInfo) {
+    if !is_finished(transfer) {
+        return;
+    }
+    let (app, transfer) = (app.clone(), transfer.clone());
+    tauri::async_runtime::spawn(async move {
+        record_finished(&app, vec![transfer]).await;
+    });
+}
+
+/// Pick up transfers that finished while the app was not running
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        loop {
+            if daemon.connection.state().is_usable() {
+                let listed = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None));
+                if let Ok(transfers) = listed {
+                    record_finished(&app, transfers).await;
+                    return;
+                }
+            }
+            tokio::time::sleep(BACKFILL_RETRY).await;
+        }
+    });
+}
+
+/// `query` as a LIKE pattern matching it anywhere, with wildcards escaped
+fn like_pattern(query: &str) -> String {
+    let escaped = query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Page through the history, newest first, optionally only one direction or status
+#[tauri::command]
+pub async fn transfer_history_list(
+    state: State<'_, HistoryState>,
+    direction: Option<String>,
+    status: Option<String>,
+    page: Option<u32>,
+    page_size: Option<u32>,
+) -> Result<TransferHistoryPage, String> {
+    let filter = "(?1 IS NULL OR direction = ?1) AND (?2 IS NULL OR status = ?2)";
+    state.page(filter, &[&direction, &status], page, page_size)
+}
+
+/// Entries whose file name, peer or network contains `query`, case-insensitively
+#[tauri::command]
+pub async fn transfer_history_search(
+    state: State<'_, HistoryState>,
+    query: String,
+    page: Option<u32>,
+    page_size: Option<u32>,
+) -> Result<TransferHistoryPage, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return state.page("1", &[], page, page_size);
+    }
+    let filter = "file_name LIKE ?1 ESCAPE '\\' OR peer_name LIKE ?1 ESCAPE '\\' OR network LIKE ?1 ESCAPE '\\'";
+    state.page(&format!("({})", filter), &[&like_pattern(query)], page, page_size)
+}
+
+/// Delete entries finished before `before` (Unix seconds), or all of them; returns how many went
+#[tauri::command]
+pub async fn transfer_history_clear(state: State<'_, HistoryState>, before: Option<u64>) -> Result<u64, String> {
+    let before = before.map(|b| b as i64);
+    let deleted = state.with_db(|db| {
+        db.execute("DELETE FROM transfers WHERE ?1 IS NULL OR finished_at < ?1", params![before])
+    })?;
+    Ok(deleted as u64)
+}
@@ -0,0 +1,333 @@
+// Local Name Overrides
+// Friendly names for peers (e.g. "nas.home" → 10.7.0.12), kept locally and
+// optionally written to the OS hosts file so browsers and terminals resolve them
+// without daemon DNS. Our entries live in a marked block; the rest of the file
+// is never touched. Writing the hosts file asks for elevation when needed.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::store::{self, JsonStore};
+use crate::undo::{self, Reversal};
+use std::net::IpAddr;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const OVERRIDES_FILE: &str = "host_overrides.json";
+
+const BLOCK_BEGIN: &str = "# BEGIN GoConnect";
+const BLOCK_END: &str = "# END GoConnect";
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct HostOverride {
+    pub name: String,
+    /// Follow this peer's current virtual IP
+    pub peer_id: Option<String>,
+    /// Fixed address, used when `peer_id` is not set
+    pub ip: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct HostOverrides {
+    pub entries: Vec<HostOverride>,
+    /// Keep the OS hosts file in sync with `entries`
+    pub write_hosts_file: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ResolvedOverride {
+    pub name: String,
+    pub peer_id: Option<String>,
+    /// `None` when the peer is unknown to the daemon
+    pub ip: Option<String>,
+}
+
+/// Managed state holding the persisted overrides
+pub struct HostsState {
+    overrides: JsonStore<HostOverrides>,
+}
+
+impl HostsState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { overrides: JsonStore::open(app, OVERRIDES_FILE) }
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    let valid_label = |l: &str| {
+        !l.is_empty()
+            && l.len() <= 63
+            && l.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+            && !l.starts_with('-')
+            && !l.ends_with('-')
+    };
+    if name.len() > 253 || !name.split('.').all(valid_label) {
+        return Err(format!("'{}' is not a valid host name", name));
+    }
+    if name.eq_ignore_ascii_case("localhost") {
+        return Err("localhost cannot be overridden".to_string());
+    }
+    Ok(())
+}
+
+fn hosts_path() -> PathBuf {
+    if cfg!(windows) {
+        let root = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".to_string());
+        PathBuf::from(root).join(r"System32\drivers\etc\hosts")
+    } else {
+        PathBuf::from("/etc/hosts")
+    }
+}
+
+/// Resolve every override against the daemon's current peer list
+async fn resolve(daemon: &DaemonState, entries: &[HostOverride]) -> Vec<ResolvedOverride> {
+    let peers = if entries.iter().any(|e| e.peer_id.is_some()) {
+        daemon_call!(daemon, "get_peers", |client| client.get_peers()).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    entries
+        .iter()
+        .map(|e| ResolvedOverride {
+            name: e.name.clone(),
+            peer_id: e.peer_id.clone(),
+            // A peer without an address yet must not put junk in the hosts file
+            ip: match &e.peer_id {
+                Some(id) => peers.iter().find(|p| &p.id == id).map(|p| p.virtual_ip.clone()),
+                None => e.ip.clone(),
+            }
+            .filter(|ip| ip.parse::<IpAddr>().is_ok()),
+        })
+        .collect()
+}
+
+/// `current` with our block replaced by `resolved` (or removed when empty),
+/// keeping the file's line endings
+fn render_hosts(current: &str, resolved: &[ResolvedOverride]) -> String {
+    let eol = if current.contains("\r\n") || (current.is_empty() && cfg!(windows)) { "\r\n" } else { "\n" };
+    let mut out = String::with_capacity(current.len());
+    let mut inside = false;
+    for line in current.lines() {
+        match line.trim() {
+            BLOCK_BEGIN => inside = true,
+            BLOCK_END => inside = false,
+            _ if !inside => {
+                out.push_str(line);
+                out.push_str(eol);
+            }
+            _ => {}
+        }
+    }
+
+    let lines: Vec<String> = resolved
+        .iter()
+        .filter_map(|r| Some(format!("{}\t{}", r.ip.as_ref()?, r.name)))
+        .collect();
+    if !lines.is_empty() {
+        if !out.is_empty() && !out.ends_with(&eol.repeat(2)) {
+            out.push_str(eol);
+        }
+        out.push_str(BLOCK_BEGIN);
+        out.push_str(eol);
+        for line in lines {
+            out.push_str(&line);
+            out.push_str(eol);
+        }
+        out.push_str(BLOCK_END);
+        out.push_str(eol);
+    }
+    out
+}
+
+/// Copy `staged` over the hosts file with administrator rights
+fn install_elevated(staged: &std::path::Path, target: &std::path::Path) -> Result<(), String> {
+    #[cfg(target_os = "linux")]
+    let status = std::process::Command::new("pkexec")
+        .arg("install")
+        .args(["-m", "644"])
+        .arg(staged)
+        .arg(target)
+        .status();
+
+    #[cfg(target_os = "macos")]
+    let status = {
+        let quote = |p: &std::path::Path| p.to_string_lossy().replace('\'', r"'\''");
+        let script = format!(
+            "do shell script \"cp '{}' '{}'\" with administrator privileges",
+            quote(staged),
+            quote(target)
+        );
+        std::process::Command::new("osascript").args(["-e", &script]).status()
+    };
+
+    #[cfg(windows)]
+    let status = {
+        let command = format!(
+            "Start-Process -FilePath cmd -ArgumentList '/c copy /Y \"{}\" \"{}\"' -Verb RunAs -Wait -WindowStyle Hidden",
+            staged.display(),
+            target.display()
+        );
+        std::process::Command::new("powershell").args(["-NoProfile", "-Command", &command]).status()
+    };
+
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        Ok(_) => Err("elevation was denied or the copy failed".to_string()),
+        Err(e) => Err(format!("failed to request elevation: {}", e)),
+    }
+}
+
+/// Rewrite our block in the hosts file, elevating only if a direct write is refused
+async fn sync_hosts_file(app: &AppHandle, resolved: &[ResolvedOverride]) -> Result<(), String> {
+    let path = hosts_path();
+    let current = std::fs::read_to_string(&path).map_err(|e| format!("failed to read {:?}: {}", path, e))?;
+    let next = render_hosts(&current, resolved);
+    if next == current {
+        return Ok(());
+    }
+
+    match std::fs::write(&path, &next) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+            let staged = store::data_dir(app).join("hosts.staged");
+            std::fs::write(&staged, &next).map_err(|e| e.to_string())?;
+            tauri::async_runtime::spawn_blocking(move || {
+                let result = install_elevated(&staged, &path);
+                let _ = std::fs::remove_file(&staged);
+                result
+            })
+            .await
+            .map_err(|e| e.to_string())?
+        }
+        Err(e) => Err(format!("failed to write {:?}: {}", path, e)),
+    }
+}
+
+/// Apply the stored overrides to the hosts file when syncing is on
+async fn apply(app: &AppHandle, daemon: &DaemonState, overrides: &HostOverrides) -> Result<Vec<ResolvedOverride>, String> {
+    let resolved = resolve(daemon, &overrides.entries).await;
+    if overrides.write_hosts_file {
+        sync_hosts_file(app, &resolved).await?;
+    }
+    Ok(resolved)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn list_host_overrides(
+    daemon: State<'_, DaemonState>,
+    state: State<'_, HostsState>,
+) -> Result<Vec<ResolvedOverride>, String> {
+    Ok(resolve(&daemon, &state.overrides.get().entries).await)
+}
+
+/// Add or replace the override for `name`; give either a peer id or a fixed IP
+#[tauri::command]
+pub async fn set_host_override(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    state: State<'_, HostsState>,
+    name: String,
+    peer_id: Option<String>,
+    ip: Option<String>,
+) -> Result<Vec<ResolvedOverride>, String> {
+    let name = name.trim().to_ascii_lowercase();
+    validate_name(&name)?;
+    match (&peer_id, &ip) {
+        (Some(_), None) => {}
+        (None, Some(ip)) => {
+            ip.parse::<IpAddr>().map_err(|_| format!("'{}' is not an IP address", ip))?;
+        }
+        _ => return Err("give either a peer or an IP address".to_string()),
+    }
+
+    let overrides = state.overrides.update(|o| {
+        o.entries.retain(|e| e.name != name);
+        o.entries.push(HostOverride { name, peer_id, ip });
+        o.clone()
+    })?;
+    apply(&app, &daemon, &overrides).await
+}
+
+#[tauri::command]
+pub async fn remove_host_override(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    state: State<'_, HostsState>,
+    name: String,
+) -> Result<Vec<ResolvedOverride>, String> {
+    let name = name.trim().to_ascii_lowercase();
+    let (removed, overrides) = state.overrides.update(|o| {
+        let removed = o.entries.iter().find(|e| e.name == name).cloned();
+        o.entries.retain(|e| e.name != name);
+        (removed, o.clone())
+    })?;
+    if let Some(removed) = removed {
+        undo::record(&app, format!("Removed alias {}", removed.name), Reversal::HostAlias(removed));
+    }
+    apply(&app, &daemon, &overrides).await
+}
+
+/// Add a removed alias back and rewrite the hosts block
+pub(crate) async fn restore_override(app: &AppHandle, entry: HostOverride) -> Result<(), String> {
+    let state = app.state::<HostsState>();
+    let overrides = state.overrides.update(|o| {
+        if !o.entries.iter().any(|e| e.name == entry.name) {
+            o.entries.push(entry);
+        }
+        o.clone()
+    })?;
+    apply(app, &app.state::<DaemonState>(), &overrides).await.map(|_| ())
+}
+
+/// Names of the aliases that follow one of `peer_ids`
+pub(crate) fn aliases_for(app: &AppHandle, peer_ids: &[String]) -> Vec<String> {
+    let overrides = app.state::<HostsState>().overrides.get();
+    let follows = |e: &HostOverride| e.peer_id.as_ref().is_some_and(|id| peer_ids.contains(id));
+    overrides.entries.iter().filter(|e| follows(e)).map(|e| e.name.clone()).collect()
+}
+
+/// Drop the aliases that follow one of `peer_ids`; returns their names
+pub(crate) async fn remove_for_peers(app: &AppHandle, peer_ids: &[String]) -> Result<Vec<String>, String> {
+    let names = aliases_for(app, peer_ids);
+    if names.is_empty() {
+        return Ok(names);
+    }
+    let overrides = app.state::<HostsState>().overrides.update(|o| {
+        o.entries.retain(|e| !names.contains(&e.name));
+        o.clone()
+    })?;
+    apply(app, &app.state::<DaemonState>(), &overrides).await?;
+    Ok(names)
+}
+
+/// Turn hosts-file syncing on (writes the block) or off (removes it)
+#[tauri::command]
+pub async fn set_hosts_file_sync(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    state: State<'_, HostsState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut overrides = state.overrides.get();
+    let resolved = if enabled { resolve(&daemon, &overrides.entries).await } else { Vec::new() };
+    sync_hosts_file(&app, &resolved).await?;
+    overrides.write_hosts_file = enabled;
+    state.overrides.set(overrides)
+}
+
+/// Re-resolve peer addresses and rewrite the hosts block
+#[tauri::command]
+pub async fn refresh_hosts_file(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    state: State<'_, HostsState>,
+) -> Result<Vec<ResolvedOverride>, String> {
+    apply(&app, &daemon, &state.overrides.get()).await
+}
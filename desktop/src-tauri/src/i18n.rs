@@ -0,0 +1,322 @@
+// Localization
+// Strings shown by the Rust side (tray menu, native notifications), switched at
+// runtime from the daemon's Settings.language without a restart.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::ConnectionState;
+use crate::store::JsonStore;
+use std::sync::RwLock;
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Tauri event emitted with the new locale code after a switch
+pub const CHANGED_EVENT: &str = "locale://changed";
+
+/// Last chosen locale, used until the daemon answers (or when it cannot store it)
+const CACHE_FILE: &str = "locale.json";
+
+const DEFAULT_LOCALE: &str = "en";
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct LocaleInfo {
+    /// BCP 47 language code, as stored in Settings.language
+    pub code: String,
+    /// Name of the language in that language
+    pub name: String,
+}
+
+const SUPPORTED_LOCALES: &[(&str, &str)] = &[("en", "English"), ("tr", "Türkçe")];
+
+static CURRENT: RwLock<&str> = RwLock::new(DEFAULT_LOCALE);
+
+/// Every string the Rust side displays
+#[derive(Debug, Clone, Copy)]
+pub enum Text {
+    TrayChecking,
+    TrayConnected,
+    TrayConnectedMany,
+    TrayDisconnected,
+    TrayState,
+    TrayConnect,
+    TrayDisconnect,
+    TrayCheckUpdate,
+    TrayShow,
+    TrayQuit,
+    TrayTooltip,
+    TrayTroubleshooting,
+    TrayRollback,
+    TrayRollbackTo,
+    TrayHealth,
+    TrayNetwork,
+    TrayPeers,
+    TrayRecentChats,
+    TrayNoRecentChats,
+    TrayQuickReply,
+    UpdateTitle,
+    UpdateAvailable,
+    UpdateLatest,
+    UpdateFailed,
+    UpdateReady,
+    UpdateReadyOnQuit,
+    RollbackTitle,
+    RollbackFailed,
+    A11yConnected,
+    A11yDisconnected,
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    NotificationActions,
+    ActionAccept,
+    ActionDecline,
+    ActionOpen,
+    TransferIncomingTitle,
+    TransferIncomingBody,
+    TransferFailedTitle,
+    AutoAcceptedTitle,
+    AutoAcceptedBody,
+    AutoSkippedTitle,
+    AutoSkippedBody,
+    ChecksumMismatchTitle,
+    ChecksumMismatchBody,
+    ClockSkewTitle,
+    ChatMutedTitle,
+    ChatMutedBody,
+    SlaAlertTitle,
+    GuestLeftTitle,
+    GuestLeftBody,
+    FileFlaggedTitle,
+    FileFlaggedBody,
+    CredentialAttentionTitle,
+    SecurityDeviceAdded,
+    SecurityPeerKeyChanged,
+    SecurityIpcAuthFailed,
+    SecurityOther,
+    State(ConnectionState),
+}
+
+/// Translate into the current locale. Placeholders such as `{network}` are left for the caller.
+pub fn text(key: Text) -> &'static str {
+    match *CURRENT.read().unwrap() {
+        "tr" => turkish(key),
+        _ => english(key),
+    }
+}
+
+fn english(text: Text) -> &'static str {
+    match text {
+        Text::TrayChecking => "Status: Checking...",
+        Text::TrayConnected => "Status: Connected ({network})",
+        Text::TrayConnectedMany => "Status: Connected to {count} networks",
+        Text::TrayDisconnected => "Status: Disconnected",
+        Text::TrayState => "Status: {state}",
+        Text::TrayConnect => "Connect",
+        Text::TrayDisconnect => "Disconnect",
+        Text::TrayCheckUpdate => "Check for Updates",
+        Text::TrayShow => "Show",
+        Text::TrayQuit => "Quit",
+        Text::TrayTooltip => "GoConnect",
+        Text::TrayHealth => "GoConnect - {network} health: {score}/100",
+        Text::TrayNetwork => "GoConnect - {network}",
+        Text::TrayPeers => "{count} peers online",
+        Text::TrayRecentChats => "Recent Chats",
+        Text::TrayNoRecentChats => "No recent chats",
+        Text::TrayQuickReply => "Quick Reply",
+        Text::TrayTroubleshooting => "Troubleshooting",
+        Text::TrayRollback => "Roll Back to Previous Version",
+        Text::TrayRollbackTo => "Roll Back to v{version}",
+        Text::UpdateTitle => "GoConnect Update",
+        Text::UpdateAvailable => "Update available: v{version}",
+        Text::UpdateLatest => "You are on the latest version.",
+        Text::UpdateFailed => "Update check failed: {error}",
+        Text::UpdateReady => "v{version} is downloaded and ready to install.",
+        Text::UpdateReadyOnQuit => "v{version} is downloaded and installs when you quit GoConnect.",
+        Text::RollbackTitle => "GoConnect Rollback",
+        Text::RollbackFailed => "Rollback failed: {error}",
+        Text::A11yConnected => "GoConnect connected to {network}",
+        Text::A11yDisconnected => "GoConnect disconnected",
+        Text::NotificationActions => "Options",
+        Text::ActionAccept => "Accept",
+        Text::ActionDecline => "Decline",
+        Text::ActionOpen => "Open",
+        Text::TransferIncomingTitle => "Incoming file",
+        Text::TransferIncomingBody => "{sender} wants to send you {file} ({size})",
+        Text::TransferFailedTitle => "Transfer request failed",
+        Text::AutoAcceptedTitle => "File accepted automatically",
+        Text::AutoAcceptedBody => "Receiving {file} from a trusted peer",
+        Text::AutoSkippedTitle => "Incoming file skipped",
+        Text::AutoSkippedBody => "{file} has already been received",
+        Text::ChecksumMismatchTitle => "Checksum mismatch",
+        Text::ChecksumMismatchBody => "{file} does not match the file that was sent and may be corrupted",
+        Text::ClockSkewTitle => "System clock is off",
+        Text::ChatMutedTitle => "Conversation muted",
+        Text::ChatMutedBody => "{peer} is sending too many messages and was muted for {minutes} minutes",
+        Text::SlaAlertTitle => "Critical peer alert",
+        Text::GuestLeftTitle => "Left guest network",
+        Text::GuestLeftBody => "Your guest access to {network} has ended",
+        Text::FileFlaggedTitle => "Received file flagged",
+        Text::FileFlaggedBody => "{file} was flagged by {service} and will not be opened",
+        Text::CredentialAttentionTitle => "Credential needs attention",
+        Text::SecurityDeviceAdded => "New device signed in",
+        Text::SecurityPeerKeyChanged => "Peer key changed",
+        Text::SecurityIpcAuthFailed => "Rejected daemon access",
+        Text::SecurityOther => "Security event",
+        Text::State(state) => state.label(),
+    }
+}
+
+fn turkish(text: Text) -> &'static str {
+    match text {
+        Text::TrayChecking => "Durum: Kontrol ediliyor...",
+        Text::TrayConnected => "Durum: Bağlı ({network})",
+        Text::TrayConnectedMany => "Durum: {count} ağa bağlı",
+        Text::TrayDisconnected => "Durum: Bağlı değil",
+        Text::TrayState => "Durum: {state}",
+        Text::TrayConnect => "Bağlan",
+        Text::TrayDisconnect => "Bağlantıyı Kes",
+        Text::TrayCheckUpdate => "Güncellemeleri Denetle",
+        Text::TrayShow => "Göster",
+        Text::TrayQuit => "Çıkış",
+        Text::TrayTooltip => "GoConnect",
+        Text::TrayHealth => "GoConnect - {network} sağlığı: {score}/100",
+        Text::TrayNetwork => "GoConnect - {network}",
+        Text::TrayPeers => "{count} eş çevrimiçi",
+        Text::TrayRecentChats => "Son Sohbetler",
+        Text::TrayNoRecentChats => "Son sohbet yok",
+        Text::TrayQuickReply => "Hızlı Yanıt",
+        Text::TrayTroubleshooting => "Sorun Giderme",
+        Text::TrayRollback => "Önceki Sürüme Geri Dön",
+        Text::TrayRollbackTo => "v{version} Sürümüne Geri Dön",
+        Text::UpdateTitle => "GoConnect Güncellemesi",
+        Text::UpdateAvailable => "Güncelleme mevcut: v{version}",
+        Text::UpdateLatest => "En son sürümü kullanıyorsunuz.",
+        Text::UpdateFailed => "Güncelleme denetimi başarısız: {error}",
+        Text::UpdateReady => "v{version} indirildi ve kurulmaya hazır.",
+        Text::UpdateReadyOnQuit => "v{version} indirildi; GoConnect'ten çıktığınızda kurulacak.",
+        Text::RollbackTitle => "GoConnect Geri Dönüş",
+        Text::RollbackFailed => "Geri dönüş başarısız: {error}",
+        Text::A11yConnected => "GoConnect {network} ağına bağlandı",
+        Text::A11yDisconnected => "GoConnect bağlantısı kesildi",
+        Text::NotificationActions => "Seçenekler",
+        Text::ActionAccept => "Kabul Et",
+        Text::ActionDecline => "Reddet",
+        Text::ActionOpen => "Aç",
+        Text::TransferIncomingTitle => "Gelen dosya",
+        Text::TransferIncomingBody => "{sender} size {file} ({size}) göndermek istiyor",
+        Text::TransferFailedTitle => "Aktarım isteği başarısız",
+        Text::AutoAcceptedTitle => "Dosya otomatik olarak kabul edildi",
+        Text::AutoAcceptedBody => "{file} güvenilen bir eşten alınıyor",
+        Text::AutoSkippedTitle => "Gelen dosya atlandı",
+        Text::AutoSkippedBody => "{file} zaten alındı",
+        Text::ChecksumMismatchTitle => "Sağlama toplamı uyuşmuyor",
+        Text::ChecksumMismatchBody => "{file} gönderilen dosyayla eşleşmiyor ve bozuk olabilir",
+        Text::ClockSkewTitle => "Sistem saati yanlış",
+        Text::ChatMutedTitle => "Sohbet sessize alındı",
+        Text::ChatMutedBody => "{peer} çok fazla mesaj gönderiyor ve {minutes} dakika boyunca sessize alındı",
+        Text::SlaAlertTitle => "Kritik eş uyarısı",
+        Text::GuestLeftTitle => "Misafir ağdan ayrılındı",
+        Text::GuestLeftBody => "{network} ağındaki misafir erişiminiz sona erdi",
+        Text::FileFlaggedTitle => "Alınan dosya işaretlendi",
+        Text::FileFlaggedBody => "{file}, {service} tarafından işaretlendi ve açılmayacak",
+        Text::CredentialAttentionTitle => "Kimlik bilgisi ilgi bekliyor",
+        Text::SecurityDeviceAdded => "Yeni cihazda oturum açıldı",
+        Text::SecurityPeerKeyChanged => "Eş anahtarı değişti",
+        Text::SecurityIpcAuthFailed => "Arka plan hizmetine erişim reddedildi",
+        Text::SecurityOther => "Güvenlik olayı",
+        Text::State(state) => match state {
+            ConnectionState::NoDaemon => "Servis Durduruldu",
+            ConnectionState::Connecting => "Bağlanıyor...",
+            ConnectionState::AuthFailed => "Kimlik Doğrulama Başarısız",
+            ConnectionState::Ready => "Hazır",
+            ConnectionState::Degraded => "Kısıtlı",
+            ConnectionState::Reconnecting => "Yeniden bağlanıyor...",
+        },
+    }
+}
+
+/// Match a requested language tag ("tr-TR", "en_US.UTF-8") against the supported locales
+fn resolve(tag: &str) -> Option<&'static str> {
+    let language = tag.split(['-', '_', '.']).next()?.to_ascii_lowercase();
+    SUPPORTED_LOCALES
+        .iter()
+        .map(|(code, _)| *code)
+        .find(|code| *code == language)
+}
+
+pub fn current() -> &'static str {
+    *CURRENT.read().unwrap()
+}
+
+/// Managed state holding the cached locale
+pub struct LocaleState {
+    cached: JsonStore<String>,
+}
+
+impl LocaleState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { cached: JsonStore::open(app, CACHE_FILE) }
+    }
+}
+
+/// Start with the cached locale (or the OS one), then adopt the daemon's once it is reachable
+pub fn init(app: &AppHandle) {
+    let cached = app.state::<LocaleState>().cached.get();
+    let initial = resolve(&cached)
+        .or_else(|| tauri_plugin_os::locale().as_deref().and_then(resolve))
+        .unwrap_or(DEFAULT_LOCALE);
+    *CURRENT.write().unwrap() = initial;
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<DaemonState>();
+        if let Ok(settings) = daemon_call!(state, "get_settings", |client| client.get_settings()) {
+            adopt(&app, &settings.language);
+        }
+    });
+}
+
+/// Follow the daemon's `Settings.language`, e.g. after it was changed elsewhere
+pub(crate) fn adopt(app: &AppHandle, language: &str) {
+    if let Some(locale) = resolve(language) {
+        switch(app, locale);
+    }
+}
+
+/// Switch the locale and relabel everything the Rust side shows
+fn switch(app: &AppHandle, locale: &'static str) {
+    if let Err(e) = app.state::<LocaleState>().cached.set(locale.to_string()) {
+        log::warn!("Failed to cache locale: {}", e);
+    }
+    let previous = std::mem::replace(&mut *CURRENT.write().unwrap(), locale);
+    if previous != locale {
+        crate::tray::relabel(app);
+        let _ = app.emit(CHANGED_EVENT, locale);
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn list_supported_locales() -> Result<Vec<LocaleInfo>, String> {
+    Ok(SUPPORTED_LOCALES
+        .iter()
+        .map(|(code, name)| LocaleInfo { code: code.to_string(), name: name.to_string() })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_locale() -> Result<String, String> {
+    Ok(current().to_string())
+}
+
+/// Switch immediately and store the choice in the daemon settings
+#[tauri::command]
+pub async fn set_locale(app: AppHandle, daemon: State<'_, DaemonState>, locale: String) -> Result<String, String> {
+    let locale = resolve(&locale).ok_or_else(|| format!("unsupported locale '{}'", locale))?;
+    switch(&app, locale);
+
+    let mut settings = daemon_call!(daemon, "get_settings", |client| client.get_settings())?;
+    settings.language = locale.to_string();
+    daemon_call!(daemon, "update_settings", |client| client.update_settings(&settings))?;
+    Ok(locale.to_string())
+}
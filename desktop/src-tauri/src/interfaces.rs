@@ -0,0 +1,332 @@
+// Tunnel Interface Info
+// What the tunnel looks like from the OS side, for "why can't I reach X"
+// questions without a terminal: which interface carries each network's virtual
+// IP, its addresses, the routes through it and the DNS servers applied to it.
+// Routes and DNS come from the platform tools (ip/resolvectl, netstat/scutil,
+// PowerShell); a tool that is missing only leaves its part empty with a note.
+
+use crate::commands::{daemon_call, DaemonState};
+use if_addrs::IfAddr;
+use ipnet::IpNet;
+use std::net::IpAddr;
+use std::process::Command;
+use tauri::State;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct RouteEntry {
+    /// CIDR or "default"
+    pub destination: String,
+    pub gateway: Option<String>,
+    pub metric: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct DnsConfig {
+    pub servers: Vec<String>,
+    pub search_domains: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TunnelInterface {
+    pub name: String,
+    /// CIDR notation, IPv4 and IPv6
+    pub addresses: Vec<String>,
+    pub routes: Vec<RouteEntry>,
+    pub dns: DnsConfig,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct NetworkAddress {
+    pub network_id: String,
+    pub network_name: String,
+    pub virtual_ip: String,
+    /// `None` when no local interface holds the address (yet)
+    pub interface: Option<String>,
+    pub cidr: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct InterfaceInfo {
+    pub interfaces: Vec<TunnelInterface>,
+    pub networks: Vec<NetworkAddress>,
+    /// Parts that could not be read and why
+    pub notes: Vec<String>,
+}
+
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let mut command = Command::new(program);
+    command.args(args);
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = command.output().map_err(|e| format!("{} is not available: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!("{} failed: {}", program, String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn cidr(addr: &IfAddr) -> Option<IpNet> {
+    match addr {
+        IfAddr::V4(v4) => ipnet::Ipv4Net::with_netmask(v4.ip, v4.netmask).ok().map(IpNet::V4),
+        IfAddr::V6(v6) => ipnet::Ipv6Net::with_netmask(v6.ip, v6.netmask).ok().map(IpNet::V6),
+    }
+}
+
+/// One row of the system routing table
+#[derive(Debug, Clone)]
+pub(crate) struct SystemRoute {
+    pub interface: String,
+    pub route: RouteEntry,
+    /// Linux policy-routing table, `None` for the main table
+    pub table: Option<String>,
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn route_table() -> Result<Vec<SystemRoute>, String> {
+    #[derive(serde::Deserialize)]
+    struct IpRoute {
+        dst: String,
+        dev: Option<String>,
+        gateway: Option<String>,
+        metric: Option<u32>,
+        #[serde(rename = "type")]
+        kind: Option<String>,
+        table: Option<String>,
+    }
+    let mut entries = Vec::new();
+    for family in ["-4", "-6"] {
+        let json = run("ip", &["-j", family, "route", "show", "table", "all"])?;
+        let parsed: Vec<IpRoute> = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        entries.extend(
+            parsed
+                .into_iter()
+                // local/broadcast/multicast entries are not forwarding decisions
+                .filter(|r| r.kind.as_deref().is_none_or(|k| k == "unicast"))
+                .filter_map(|r| {
+                    Some(SystemRoute {
+                        interface: r.dev?,
+                        route: RouteEntry { destination: r.dst, gateway: r.gateway, metric: r.metric },
+                        table: r.table.filter(|t| t != "main"),
+                    })
+                }),
+        );
+    }
+    Ok(entries)
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn route_table() -> Result<Vec<SystemRoute>, String> {
+    // Destination  Gateway  Flags  Netif  Expire
+    let table = run("netstat", &["-rn"])?;
+    Ok(table
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let destination = *fields.first()?;
+            if fields.len() < 4 || destination == "Destination" || destination.ends_with(':') {
+                return None;
+            }
+            Some(SystemRoute {
+                interface: fields[3].to_string(),
+                route: RouteEntry {
+                    destination: destination.to_string(),
+                    gateway: Some(fields[1].to_string()).filter(|g| !g.starts_with("link#")),
+                    metric: None,
+                },
+                table: None,
+            })
+        })
+        .collect())
+}
+
+#[cfg(windows)]
+fn powershell_json(script: &str) -> Result<Vec<serde_json::Value>, String> {
+    let script = format!("{} | ConvertTo-Json", script);
+    let out = run("powershell", &["-NoProfile", "-NonInteractive", "-Command", &script])?;
+    if out.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    // A single object is not wrapped in an array
+    match serde_json::from_str(&out).map_err(|e| e.to_string())? {
+        serde_json::Value::Array(items) => Ok(items),
+        item => Ok(vec![item]),
+    }
+}
+
+#[cfg(windows)]
+pub(crate) fn route_table() -> Result<Vec<SystemRoute>, String> {
+    let script = "Get-NetRoute | Select-Object InterfaceAlias,DestinationPrefix,NextHop,RouteMetric";
+    Ok(powershell_json(script)?
+        .into_iter()
+        .map(|r| SystemRoute {
+            interface: r["InterfaceAlias"].as_str().unwrap_or_default().to_string(),
+            route: RouteEntry {
+                destination: r["DestinationPrefix"].as_str().unwrap_or_default().to_string(),
+                gateway: r["NextHop"].as_str().filter(|h| !matches!(*h, "0.0.0.0" | "::")).map(str::to_string),
+                metric: r["RouteMetric"].as_u64().map(|m| m as u32),
+            },
+            table: None,
+        })
+        .collect())
+}
+
+#[cfg(target_os = "linux")]
+fn dns(interface: &str) -> Result<DnsConfig, String> {
+    // "Link 7 (goconnect0): 100.64.0.1 fd00::1"
+    let values = |output: String| -> Vec<String> {
+        output
+            .split_once("):")
+            .map(|(_, rest)| rest.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default()
+    };
+    Ok(DnsConfig {
+        servers: values(run("resolvectl", &["dns", interface])?),
+        search_domains: values(run("resolvectl", &["domain", interface])?),
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn dns(interface: &str) -> Result<DnsConfig, String> {
+    // scutil lists resolvers as blocks; keep those bound to the interface
+    let output = run("scutil", &["--dns"])?;
+    let mut config = DnsConfig::default();
+    for block in output.split("\nresolver #") {
+        if !block.lines().any(|l| l.contains("if_index") && l.contains(&format!("({})", interface))) {
+            continue;
+        }
+        for line in block.lines().map(str::trim) {
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let value = value.trim().to_string();
+            if key.trim().starts_with("nameserver[") && !config.servers.contains(&value) {
+                config.servers.push(value);
+            } else if (key.trim().starts_with("search domain") || key.trim() == "domain")
+                && !config.search_domains.contains(&value)
+            {
+                config.search_domains.push(value);
+            }
+        }
+    }
+    Ok(config)
+}
+
+#[cfg(windows)]
+fn dns(interface: &str) -> Result<DnsConfig, String> {
+    let alias = interface.replace('\'', "''");
+    let servers = powershell_json(&format!(
+        "Get-DnsClientServerAddress -InterfaceAlias '{}' | Select-Object -ExpandProperty ServerAddresses",
+        alias
+    ))?;
+    let suffixes = powershell_json(&format!(
+        "Get-DnsClient -InterfaceAlias '{}' | Select-Object -ExpandProperty ConnectionSpecificSuffix",
+        alias
+    ))?;
+    let strings = |values: Vec<serde_json::Value>| {
+        values.into_iter().filter_map(|v| v.as_str().filter(|s| !s.is_empty()).map(str::to_string)).collect()
+    };
+    Ok(DnsConfig { servers: strings(servers), search_domains: strings(suffixes) })
+}
+
+/// A local interface address that is one of our virtual IPs
+pub(crate) struct Holder {
+    pub ip: IpAddr,
+    pub interface: String,
+    pub net: IpNet,
+}
+
+/// The interfaces holding `virtual_ips`
+pub(crate) fn holders(virtual_ips: &[IpAddr]) -> Result<Vec<Holder>, String> {
+    let all = if_addrs::get_if_addrs().map_err(|e| format!("failed to list interfaces: {}", e))?;
+    Ok(virtual_ips
+        .iter()
+        .filter_map(|ip| {
+            let iface = all.iter().find(|i| i.ip() == *ip)?;
+            Some(Holder { ip: *ip, interface: iface.name.clone(), net: cidr(&iface.addr)? })
+        })
+        .collect())
+}
+
+struct Inspection {
+    interfaces: Vec<TunnelInterface>,
+    holders: Vec<Holder>,
+    notes: Vec<String>,
+}
+
+/// Interfaces, routes and DNS for the given virtual IPs
+fn inspect(virtual_ips: &[IpAddr]) -> Result<Inspection, String> {
+    let all = if_addrs::get_if_addrs().map_err(|e| format!("failed to list interfaces: {}", e))?;
+    let holders = holders(virtual_ips)?;
+    let mut names: Vec<String> = holders.iter().map(|h| h.interface.clone()).collect();
+    names.sort();
+    names.dedup();
+
+    let mut notes = Vec::new();
+    let table = route_table().unwrap_or_else(|e| {
+        notes.push(format!("routes: {}", e));
+        Vec::new()
+    });
+    let interfaces = names
+        .into_iter()
+        .map(|name| {
+            let addresses = all
+                .iter()
+                .filter(|i| i.name == name)
+                .filter_map(|i| cidr(&i.addr))
+                .map(|net| format!("{}/{}", net.addr(), net.prefix_len()))
+                .collect();
+            let routes = table.iter().filter(|r| r.interface == name).map(|r| r.route.clone()).collect();
+            let dns = dns(&name).unwrap_or_else(|e| {
+                notes.push(format!("DNS for {}: {}", name, e));
+                DnsConfig::default()
+            });
+            TunnelInterface { name, addresses, routes, dns }
+        })
+        .collect();
+    Ok(Inspection { interfaces, holders, notes })
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// The tunnel interfaces of every connected network with their addresses, routes and DNS
+#[tauri::command]
+pub async fn get_interface_info(state: State<'_, DaemonState>) -> Result<InterfaceInfo, String> {
+    let networks: Vec<_> = daemon_call!(state, "get_network_statuses", |client| client.get_network_statuses())?
+        .into_iter()
+        // One entry per address when the network is dual-stack
+        .flat_map(|n| n.addresses().into_iter().map(move |ip| ((n.network_id.clone(), n.name.clone()), ip)))
+        .collect();
+    let ips: Vec<IpAddr> = networks.iter().map(|(_, ip)| *ip).collect();
+
+    let Inspection { interfaces, holders, mut notes } = tauri::async_runtime::spawn_blocking(move || inspect(&ips))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    let networks = networks
+        .into_iter()
+        .map(|((network_id, network_name), ip)| {
+            let holder = holders.iter().find(|h| h.ip == ip);
+            if holder.is_none() {
+                notes.push(format!("no local interface holds {} for {}", ip, network_name));
+            }
+            NetworkAddress {
+                network_id,
+                network_name,
+                virtual_ip: ip.to_string(),
+                interface: holder.map(|h| h.interface.clone()),
+                cidr: holder.map(|h| h.net.trunc().to_string()),
+            }
+        })
+        .collect();
+    Ok(InterfaceInfo { interfaces, networks, notes })
+}
@@ -0,0 +1,144 @@
+// Bulk Invites
+// Generating many invites at once, single-use by default, for onboarding a
+// whole class or team: every person gets their own code, optionally labelled
+// with their name, and the batch can be written to a CSV file to mail-merge or
+// hand out. Codes are requested one after another; one failing does not stop
+// the rest.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::GeneratedInvite;
+use crate::store;
+use chrono::{DateTime, Local};
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const MAX_BATCH: u32 = 500;
+
+/// Where CSV files go when no path is given, inside the app data folder
+const EXPORT_DIR: &str = "invites";
+
+#[derive(Debug, Clone, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct BulkInviteOptions {
+    /// Joins allowed per code; 0 = unlimited
+    pub max_uses: u32,
+    /// 0 = never expires
+    pub expires_hours: u32,
+    /// Given to the codes in order, e.g. the names of the people they are for
+    pub labels: Vec<String>,
+}
+
+impl Default for BulkInviteOptions {
+    fn default() -> Self {
+        Self { max_uses: 1, expires_hours: 7 * 24, labels: Vec::new() }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct LabelledInvite {
+    pub label: Option<String>,
+    pub invite: GeneratedInvite,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct BulkInvites {
+    pub network_id: String,
+    pub invites: Vec<LabelledInvite>,
+    /// Codes the daemon refused to generate, with the first error
+    pub failed: u32,
+    pub error: Option<String>,
+}
+
+/// Quote a CSV field when it needs it; labels starting like a formula are
+/// defused so spreadsheets show them as text
+fn csv_field(value: &str) -> String {
+    if value.starts_with(['=', '+', '-', '@']) {
+        return csv_field(&format!("'{}", value));
+    }
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn to_csv(invites: &[LabelledInvite]) -> String {
+    let mut csv = String::from("label,code,url,max_uses,expires_at\r\n");
+    for item in invites {
+        let expires = item.invite.expires_at.and_then(|at| DateTime::from_timestamp(at as i64, 0));
+        let fields = [
+            csv_field(item.label.as_deref().unwrap_or("")),
+            csv_field(&item.invite.code),
+            csv_field(&item.invite.url),
+            item.invite.max_uses.to_string(),
+            expires.map(|at| at.to_rfc3339()).unwrap_or_default(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Generate `count` invites (at least one per label) for `network_id`
+#[tauri::command]
+pub async fn generate_invites_bulk(
+    state: State<'_, DaemonState>,
+    network_id: String,
+    count: u32,
+    options: Option<BulkInviteOptions>,
+) -> Result<BulkInvites, String> {
+    let options = options.unwrap_or_default();
+    let count = count.max(options.labels.len() as u32);
+    if count == 0 || count > MAX_BATCH {
+        return Err(format!("between 1 and {} invites can be generated at once", MAX_BATCH));
+    }
+
+    let mut result = BulkInvites { network_id: network_id.clone(), invites: Vec::new(), failed: 0, error: None };
+    for i in 0..count as usize {
+        let generated = daemon_call!(state, "create_invite", |client| {
+            client.create_invite(&network_id, options.max_uses, options.expires_hours)
+        });
+        match generated {
+            Ok(invite) => {
+                let label = options.labels.get(i).map(|l| l.trim().to_string()).filter(|l| !l.is_empty());
+                result.invites.push(LabelledInvite { label, invite });
+            }
+            Err(e) => {
+                result.failed += 1;
+                result.error.get_or_insert(e);
+            }
+        }
+    }
+    if result.invites.is_empty() {
+        return Err(result.error.unwrap_or_else(|| "no invites were generated".to_string()));
+    }
+    log::info!("Generated {} invites for {} ({} failed)", result.invites.len(), network_id, result.failed);
+    Ok(result)
+}
+
+/// Write invites to a CSV file at `path`, or to the app data folder; returns where it is
+#[tauri::command]
+pub async fn export_invites_csv(
+    app: AppHandle,
+    invites: Vec<LabelledInvite>,
+    path: Option<String>,
+) -> Result<String, String> {
+    let path = match path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let dir = store::data_dir(&app).join(EXPORT_DIR);
+            std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+            dir.join(format!("goconnect-invites-{}.csv", Local::now().format("%Y%m%d-%H%M%S")))
+        }
+    };
+    std::fs::write(&path, to_csv(&invites)).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+    Ok(path.to_string_lossy().into_owned())
+}
@@ -0,0 +1,157 @@
+// Leaving a Network
+// Before leaving, the network is checked for things the user would regret:
+// being its only owner (nobody could manage it afterwards) and transfers still
+// running with its members. When there is something to warn about, leaving
+// returns the check as a confirmation payload instead of leaving; the UI shows
+// it and calls again with `confirmed`. After leaving, the chat cached for the
+// network and the host aliases of its members can be purged, and the network
+// goes to the `archive`.
+
+use crate::archive;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::TransferInfo;
+use crate::hosts;
+use crate::peer_index::PeerIndexState;
+use crate::prefetch::PrefetchState;
+use tauri::{AppHandle, Manager};
+use ts_rs::TS;
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[ts(export)]
+pub enum LeaveWarning {
+    /// Nobody else could manage the network afterwards; see `transfer_network_ownership`
+    SoleOwner { other_members: u32 },
+    /// Transfers with members that leaving would break off
+    ActiveTransfers { transfers: Vec<TransferInfo> },
+}
+
+/// What is kept locally for the network and can be purged after leaving
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct LocalNetworkData {
+    pub cached_messages: u32,
+    /// Host aliases pointing at members
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct LeaveCheck {
+    pub network_id: String,
+    pub network_name: String,
+    pub member_count: u32,
+    /// Empty when leaving needs no confirmation
+    pub warnings: Vec<LeaveWarning>,
+    pub local_data: LocalNetworkData,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct LeaveOptions {
+    /// Leave despite the warnings of the check
+    pub confirmed: bool,
+    /// Cancel the transfers with members first
+    pub cancel_transfers: bool,
+    pub purge_chat_history: bool,
+    pub purge_aliases: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+#[ts(export)]
+pub enum LeaveOutcome {
+    /// Nothing was done; show the check and ask again with `confirmed`
+    NeedsConfirmation { check: LeaveCheck },
+    Left { cancelled_transfers: u32, purged_messages: u32, removed_aliases: Vec<String> },
+}
+
+/// The check, plus the member ids it was made from and the network's invite code
+async fn preflight(app: &AppHandle, network_id: &str) -> Result<(LeaveCheck, Vec<String>, String), String> {
+    let daemon = app.state::<DaemonState>();
+    let networks = daemon_call!(daemon, "list_networks", |client| client.list_networks())?;
+    let network = networks.into_iter().find(|n| n.id == network_id).ok_or("you are not a member of that network")?;
+    let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(network_id))?;
+    let members: Vec<String> = peers.iter().filter(|p| !p.is_self).map(|p| p.id.clone()).collect();
+
+    let mut warnings = Vec::new();
+    let owner = |role: &str| role.eq_ignore_ascii_case("owner");
+    let self_owner = peers.iter().any(|p| p.is_self && owner(&p.role));
+    if self_owner && !members.is_empty() && !peers.iter().any(|p| !p.is_self && owner(&p.role)) {
+        warnings.push(LeaveWarning::SoleOwner { other_members: members.len() as u32 });
+    }
+    let transfers = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None))?;
+    let transfers: Vec<TransferInfo> = transfers
+        .into_iter()
+        .filter(|t| matches!(t.status.as_str(), "pending" | "active") && members.contains(&t.peer_id))
+        .collect();
+    if !transfers.is_empty() {
+        warnings.push(LeaveWarning::ActiveTransfers { transfers });
+    }
+
+    let local_data = LocalNetworkData {
+        cached_messages: app.state::<PrefetchState>().cached_message_count(network_id) as u32,
+        aliases: hosts::aliases_for(app, &members),
+    };
+    let check = LeaveCheck {
+        network_id: network.id,
+        network_name: network.name,
+        member_count: peers.len() as u32,
+        warnings,
+        local_data,
+    };
+    Ok((check, members, network.invite_code))
+}
+
+/// Leave unless the check wants confirmation, then clean up as asked
+pub(crate) async fn leave(app: &AppHandle, network_id: &str, options: LeaveOptions) -> Result<LeaveOutcome, String> {
+    let daemon = app.state::<DaemonState>();
+    let (check, members, invite_code) = preflight(app, network_id).await?;
+    if !check.warnings.is_empty() && !options.confirmed {
+        return Ok(LeaveOutcome::NeedsConfirmation { check });
+    }
+
+    let mut cancelled_transfers = 0;
+    if options.cancel_transfers {
+        for warning in &check.warnings {
+            let LeaveWarning::ActiveTransfers { transfers } = warning else { continue };
+            for transfer in transfers {
+                match daemon_call!(daemon, "cancel_transfer", |client| client.cancel_transfer(&transfer.id)) {
+                    Ok(()) => cancelled_transfers += 1,
+                    Err(e) => log::warn!("Failed to cancel transfer {} before leaving: {}", transfer.id, e),
+                }
+            }
+        }
+    }
+
+    daemon_call!(daemon, "leave_network", |client| client.leave_network(network_id))?;
+    log::info!("Left network {}", check.network_name);
+    app.state::<PeerIndexState>().evict(&[network_id.to_string()]);
+    archive::record(app, network_id, &check.network_name, &invite_code);
+
+    let mut purged_messages = 0;
+    if options.purge_chat_history {
+        purged_messages = check.local_data.cached_messages;
+        app.state::<PrefetchState>().evict_messages(&[network_id.to_string()]);
+    }
+    let mut removed_aliases = Vec::new();
+    if options.purge_aliases && !check.local_data.aliases.is_empty() {
+        // Already left; a failure here only leaves the aliases behind
+        match hosts::remove_for_peers(app, &members).await {
+            Ok(names) => removed_aliases = names,
+            Err(e) => log::warn!("Failed to remove aliases after leaving: {}", e),
+        }
+    }
+    Ok(LeaveOutcome::Left { cancelled_transfers, purged_messages, removed_aliases })
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// What leaving `network_id` would affect; `daemon_leave_network` runs the same check
+#[tauri::command]
+pub async fn check_leave_network(app: AppHandle, network_id: String) -> Result<LeaveCheck, String> {
+    preflight(&app, &network_id).await.map(|(check, ..)| check)
+}
@@ -1,151 +1,340 @@
 // GoConnect Desktop Client
 // Tauri 2.x application with gRPC daemon communication
 
+mod accessibility;
+mod addressing;
+mod activity;
+mod admin_mode;
+mod alerts;
+mod archive;
+mod attention;
+mod auto_accept;
+mod autostart;
+mod availability;
+mod chat_export;
+mod checksums;
+mod cli;
+mod clock;
+mod clipboard;
+mod coexistence;
 mod daemon;
 mod commands;
+mod connection;
+mod content_filter;
+mod credentials;
+mod dashboard;
+mod delta;
+mod diagnostics;
+mod discovery;
+mod endpoint;
+mod events;
+mod file_drop;
+mod games;
+mod guests;
+mod health;
+mod history;
+mod hosts;
+mod i18n;
+mod interfaces;
+mod invites;
+mod leave;
+mod lifecycle;
+mod live_events;
+mod mdns;
+mod memory;
+mod models;
+mod moderation;
+mod metrics;
+mod mounts;
+mod mqtt;
+mod notifications;
+mod offline_queue;
+mod optimistic;
+mod ownership;
+mod part_files;
+mod peer_index;
+mod peer_monitor;
+mod portable;
+mod prefetch;
+mod presence;
+mod printers;
+mod quick_switch;
+mod recent_chats;
+mod remote_desktop;
+mod remote_files;
+mod report;
+mod reputation;
+mod routes;
+mod save_folders;
+mod save_paths;
+mod scheduler;
+mod security;
+mod settings_watch;
+mod sla;
+mod spam;
+mod sounds;
+mod store;
+mod subnet;
+mod sync;
+mod templates;
+mod theme;
+mod throttle;
+mod topology;
+mod transfer_files;
+mod transfer_prompt;
+mod translation;
+mod tray;
+mod tunnel;
+mod undo;
+mod updates;
+mod versions;
+mod webhooks;
+mod whats_new;
+#[cfg(feature = "ws-bridge")]
+mod bridge;
+#[cfg(feature = "automation")]
+mod automation;
+#[cfg(debug_assertions)]
+mod chaos;
 
 use commands::DaemonState;
-use tauri::{
-    menu::{Menu, MenuItem, PredefinedMenuItem},
-    tray::TrayIconBuilder,
-    Manager,
-};
+use notifications::NotificationCenter;
+use tauri::{Emitter, Manager};
 
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
-use tauri_plugin_updater::UpdaterExt;
-use tauri_plugin_notification::NotificationExt;
+/// Logs go to the OS log folder, or the portable data folder in portable mode
+fn log_plugin() -> tauri::plugin::TauriPlugin<tauri::Wry> {
+    use tauri_plugin_log::{Target, TargetKind};
+    let builder = tauri_plugin_log::Builder::new().level(log::LevelFilter::Info);
+    let builder = match portable::log_dir() {
+        Some(path) => builder.clear_targets().targets([
+            Target::new(TargetKind::Stdout),
+            Target::new(TargetKind::Folder { path, file_name: None }),
+        ]),
+        None => builder,
+    };
+    builder.build()
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    if let Some(code) = cli::run_headless() {
+        std::process::exit(code);
+    }
     tauri::Builder::default()
+        .plugin(log_plugin())
         .plugin(tauri_plugin_os::init())
         .manage(DaemonState::default())
-        .on_window_event(|window, event| {
-            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+        .manage(file_drop::FileDropState::default())
+        .on_window_event(|window, event| match event {
+            // Closing the main window keeps the app running in the tray
+            tauri::WindowEvent::CloseRequested { api, .. } if window.label() == "main" => {
                 window.hide().unwrap();
                 api.prevent_close();
             }
+            tauri::WindowEvent::ThemeChanged(theme) => {
+                theme::system_theme_changed(window.app_handle(), *theme);
+            }
+            tauri::WindowEvent::Focused(true) => {
+                attention::on_focus(window.app_handle());
+                prefetch::on_focus(window.app_handle());
+            }
+            _ => {}
         })
         .setup(|app| {
-            let status_i = MenuItem::with_id(app, "status", "Status: Checking...", false, None::<&str>)?;
-            let check_update_i = MenuItem::with_id(app, "check_update", "Check for Updates", true, None::<&str>)?;
-            let quit_i = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-            let show_i = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
-            let sep1 = PredefinedMenuItem::separator(app)?;
-            let sep2 = PredefinedMenuItem::separator(app)?;
-            
-            let menu = Menu::with_items(app, &[
-                &status_i, 
-                &sep1, 
-                &check_update_i, 
-                &sep2, 
-                &show_i, 
-                &quit_i
-            ])?;
+            portable::log_setup_error();
+            app.manage(endpoint::EndpointState::load(app.handle()));
+            app.manage(addressing::AddressingState::load(app.handle()));
+            app.manage(i18n::LocaleState::load(app.handle()));
+            i18n::init(app.handle());
+            app.manage(updates::UpdateState::load(app.handle()));
+            tray::build(app.handle())?;
+            autostart::init(app.handle());
 
-            let _tray = TrayIconBuilder::with_id("tray")
-                .icon(app.default_window_icon().unwrap().clone())
-                .menu(&menu)
-                .show_menu_on_left_click(true)
-                .on_menu_event(|app, event| match event.id.as_ref() {
-                    "quit" => {
-                        app.exit(0);
-                    }
-                    "show" => {
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
-                    }
-                    "check_update" => {
-                        let handle = app.handle().clone();
-                        tauri::async_runtime::spawn(async move {
-                            match handle.updater().check().await {
-                                Ok(Some(update)) => {
-                                    handle.notification()
-                                        .builder()
-                                        .title("GoConnect Update")
-                                        .body(format!("Update available: v{}", update.version).as_str())
-                                        .show()
-                                        .unwrap();
-                                        
-                                    // Optionally trigger download/install logic here or via dialog
-                                    // For now, just notify.
-                                }
-                                Ok(None) => {
-                                    handle.notification()
-                                        .builder()
-                                        .title("GoConnect")
-                                        .body("You are on the latest version.")
-                                        .show()
-                                        .unwrap();
-                                }
-                                Err(e) => {
-                                    handle.notification()
-                                        .builder()
-                                        .title("Update Check Failed")
-                                        .body(format!("Error: {}", e).as_str())
-                                        .show()
-                                        .unwrap();
-                                }
-                            }
-                        });
-                    }
-                    _ => {}
-                })
-                .build(app)?;
-            
+            // Portable runs leave the host's URL handlers alone
             #[cfg(any(windows, target_os = "linux"))]
-            {
+            if !portable::enabled() {
                 use tauri_plugin_deep_link::DeepLinkExt;
                 app.deep_link().register_all()?;
             }
 
-            // Spawn background task to update status
-            let status_handle = status_i.clone();
+            // Forward connection lifecycle transitions to the frontend
+            let mut connection_rx = app.state::<DaemonState>().connection.subscribe();
+            let event_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
-                loop {
-                    let status_text = match crate::daemon::DaemonClient::connect().await {
-                        Ok(client) => match client.get_status().await {
-                            Ok(status) => {
-                                if status.connected {
-                                    format!("Status: Connected ({})", status.network_name)
-                                } else {
-                                    "Status: Disconnected".to_string()
-                                }
-                            }
-                            Err(_) => "Status: Daemon Error".to_string(),
-                        },
-                        Err(_) => "Status: Daemon Stopped".to_string(),
-                    };
+                while connection_rx.changed().await.is_ok() {
+                    let snapshot = connection_rx.borrow_and_update().clone();
+                    let _ = event_handle.emit(connection::STATE_CHANGED_EVENT, snapshot);
+                }
+            });
 
-                    let _ = status_handle.set_text(status_text);
-                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            // Forward the per-network breakdown to the frontend
+            let mut networks_rx = app.state::<DaemonState>().subscribe_networks();
+            let event_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                while networks_rx.changed().await.is_ok() {
+                    let networks = networks_rx.borrow_and_update().clone();
+                    let _ = event_handle.emit(commands::NETWORKS_CHANGED_EVENT, networks);
                 }
             });
 
+            app.manage(theme::ThemeState::load(app.handle()));
+            theme::init(app.handle());
+
+            app.manage(accessibility::AccessibilityState::load(app.handle()));
+            accessibility::spawn(app.handle().clone());
+
+            app.manage(activity::ActivityState::load(app.handle()));
+            app.manage(checksums::ChecksumState::load(app.handle()));
+            app.manage(part_files::PartFilesState::load(app.handle()));
+            app.manage(reputation::ReputationState::load(app.handle()));
+            activity::spawn(app.handle().clone());
+            app.manage(availability::AvailabilityState::load(app.handle()));
+            availability::spawn(app.handle().clone());
+
+            app.manage(throttle::ThrottleState::load(app.handle()));
+            throttle::spawn(app.handle().clone());
+            app.manage(peer_index::PeerIndexState::load(app.handle()));
+            peer_index::spawn(app.handle().clone());
+            app.manage(prefetch::PrefetchState::default());
+            prefetch::spawn(app.handle().clone());
+            app.manage(history::HistoryState::load(app.handle()));
+            history::spawn(app.handle().clone());
+            app.manage(auto_accept::AutoAcceptState::load(app.handle()));
+            app.manage(save_paths::SavePathsState::load(app.handle()));
+            app.manage(save_folders::SaveFoldersState::load(app.handle()));
+            app.manage(live_events::LiveEventState::default());
+            live_events::spawn(app.handle().clone());
+            part_files::spawn(app.handle().clone());
+            events::spawn_event_bridge(app.handle().clone());
+            app.manage(memory::MemoryState::load(app.handle()));
+            memory::spawn(app.handle().clone());
+            app.manage(offline_queue::OfflineQueueState::load(app.handle()));
+            offline_queue::spawn(app.handle().clone());
+            app.manage(optimistic::OptimisticState::default());
+            settings_watch::spawn(app.handle().clone());
+            app.manage(undo::UndoState::load(app.handle()));
+            app.manage(admin_mode::AdminModeState::load(app.handle()));
+            app.manage(ownership::OwnershipState::load(app.handle()));
+            app.manage(archive::ArchiveState::load(app.handle()));
+            app.manage(guests::GuestState::load(app.handle()));
+            app.manage(templates::TemplateState::load(app.handle()));
+            app.manage(translation::TranslationState::load(app.handle()));
+            app.manage(content_filter::ContentFilterState::load(app.handle()));
+            app.manage(spam::SpamState::load(app.handle()));
+            app.manage(moderation::ModerationState::load(app.handle()));
+            app.manage(recent_chats::RecentChatsState::load(app.handle()));
+            app.manage(attention::AttentionState::load(app.handle()));
+            recent_chats::show_in_tray(app.handle());
+            admin_mode::spawn(app.handle().clone());
+            app.manage(quick_switch::QuickSwitchState::load(app.handle()));
+            app.manage(hosts::HostsState::load(app.handle()));
+            app.manage(coexistence::CoexistenceState::load(app.handle()));
+            coexistence::spawn(app.handle().clone());
+            app.manage(versions::VersionsState::load(app.handle()));
+            app.manage(delta::DeltaState::load(app.handle()));
+            delta::spawn(app.handle().clone());
+            app.manage(scheduler::SchedulerState::load(app.handle()));
+            scheduler::spawn(app.handle().clone());
+            app.manage(printers::PrinterState::load(app.handle()));
+            printers::spawn(app.handle().clone());
+            app.manage(games::GameState::load(app.handle()));
+            games::spawn(app.handle().clone());
+            app.manage(mdns::MdnsState::load(app.handle()));
+            mdns::spawn(app.handle().clone());
+            app.manage(mounts::MountState::load(app.handle()));
+            mounts::spawn(app.handle().clone());
+            app.manage(remote_desktop::RemoteDesktopState::load(app.handle()));
+            app.manage(sla::SlaState::load(app.handle()));
+            sla::spawn(app.handle().clone());
+            app.manage(clock::ClockState::default());
+            clock::spawn(app.handle().clone());
+            app.manage(credentials::CredentialsState::default());
+            credentials::spawn(app.handle().clone());
+            app.manage(health::HealthState::default());
+            health::spawn(app.handle().clone());
+            app.manage(whats_new::WhatsNewState::load(app.handle()));
+            updates::spawn(app.handle().clone());
+            app.manage(sync::SyncState::load(app.handle()));
+            sync::spawn(app.handle().clone());
+            app.manage(NotificationCenter::load(app.handle()));
+            app.manage(security::SecurityFeedState::load(app.handle()));
+            security::spawn(app.handle().clone());
+            app.manage(sounds::SoundState::load(app.handle()));
+            app.manage(presence::PresenceState::load(app.handle()));
+            presence::spawn(app.handle().clone());
+            app.manage(webhooks::WebhookState::load(app.handle()));
+            webhooks::spawn(app.handle().clone());
+            app.manage(mqtt::MqttState::load(app.handle()));
+            mqtt::spawn(app.handle().clone());
+            app.manage(alerts::AlertState::load(app.handle()));
+            alerts::spawn(app.handle().clone());
+            app.manage(peer_monitor::PeerMonitorState::load(app.handle()));
+            peer_monitor::spawn(app.handle().clone());
+
+            #[cfg(feature = "ws-bridge")]
+            bridge::spawn(app.handle().clone());
+            #[cfg(feature = "automation")]
+            automation::spawn(app.handle().clone());
+
+            tray::spawn_status_updates(app.handle().clone());
+
             Ok(())
         })
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .invoke_handler(tauri::generate_handler![
             greet,
             // Daemon commands
             commands::daemon_get_status,
             commands::daemon_get_version,
+            commands::daemon_get_network_statuses,
             commands::daemon_is_running,
+            commands::daemon_get_connection_state,
+            commands::daemon_get_rpc_metrics,
+            commands::daemon_reset_rpc_metrics,
+            endpoint::get_daemon_endpoint,
+            endpoint::set_daemon_endpoint,
+            endpoint::test_daemon_endpoint,
+            endpoint::get_default_token_path,
+            discovery::detect_daemon_candidates,
+            discovery::connect_daemon_candidate,
+            lifecycle::daemon_start,
+            lifecycle::daemon_stop,
+            lifecycle::daemon_restart,
+            clock::get_clock_status,
+            clock::fix_clock_skew,
+            credentials::get_credentials_status,
+            // Security commands
+            security::list_security_events,
+            security::clear_security_events,
             // Network commands
+            commands::daemon_connect,
+            commands::daemon_disconnect,
             commands::daemon_create_network,
             commands::daemon_join_network,
+            commands::daemon_preview_invite,
             commands::daemon_list_networks,
             commands::daemon_leave_network,
+            leave::check_leave_network,
+            ownership::transfer_network_ownership,
+            ownership::list_ownership_transfers,
+            archive::list_archived_networks,
+            archive::set_archived_network_notes,
+            archive::forget_archived_network,
+            archive::rejoin_archived_network,
+            guests::list_guest_memberships,
+            guests::set_guest_expiry,
             commands::daemon_generate_invite,
+            invites::generate_invites_bulk,
+            invites::export_invites_csv,
             commands::daemon_update_network,
             commands::daemon_delete_network,
             // Peer commands
@@ -153,21 +342,310 @@ pub fn run() {
             commands::daemon_kick_peer,
             commands::daemon_ban_peer,
             commands::daemon_unban_peer,
+            peer_index::search_peers,
+            peer_index::get_peers_window,
+            peer_index::get_peer_tags,
+            peer_index::set_peer_tags,
+            // Prefetch commands
+            prefetch::prefetch_network,
+            prefetch::get_cached_messages,
+            prefetch::get_cached_transfers,
+            // Live event commands
+            live_events::subscribe_live_events,
+            live_events::unsubscribe_live_events,
+            live_events::get_bandwidth_history,
+            // Throttle commands
+            throttle::get_throttle_settings,
+            throttle::set_throttle_settings,
+            throttle::get_throttle_stats,
+            // Memory commands
+            memory::get_memory_settings,
+            memory::set_memory_settings,
+            memory::get_memory_usage,
+            memory::clear_caches,
+            // Offline queue commands
+            offline_queue::send_message_or_queue,
+            offline_queue::send_file_or_queue,
+            offline_queue::list_queued_actions,
+            offline_queue::cancel_queued_action,
+            offline_queue::retry_queued_actions,
+            // Optimistic update commands
+            optimistic::get_local_settings,
+            optimistic::toggle_setting,
+            optimistic::send_message_optimistic,
+            optimistic::list_pending_mutations,
+            // Undo commands
+            undo::undo_last_action,
+            undo::list_undo_actions,
+            undo::get_undo_settings,
+            undo::set_undo_settings,
+            // Admin mode commands
+            admin_mode::get_admin_mode_status,
+            admin_mode::enable_admin_mode,
+            admin_mode::disable_admin_mode,
+            admin_mode::get_admin_mode_settings,
+            admin_mode::set_admin_mode_settings,
+            admin_mode::set_admin_pin,
             // Settings commands
             commands::daemon_get_settings,
             commands::daemon_update_settings,
             commands::daemon_reset_settings,
+            // Autostart commands
+            autostart::get_autostart,
+            autostart::set_autostart,
             // Chat commands
             commands::daemon_get_messages,
             commands::daemon_send_message,
+            templates::list_message_templates,
+            templates::save_message_template,
+            templates::delete_message_template,
+            templates::quick_insert_templates,
+            templates::expand_message_template,
+            templates::send_template_message,
+            // Translation commands
+            translation::get_translation_settings,
+            translation::set_translation_settings,
+            translation::set_auto_translate,
+            translation::translate_message,
+            translation::clear_translation_cache,
+            // Content filter commands
+            content_filter::get_content_filter,
+            content_filter::set_content_filter_options,
+            content_filter::set_content_filter_terms,
+            content_filter::preview_content_filter,
+            // Flood protection commands
+            spam::get_spam_settings,
+            spam::set_spam_settings,
+            spam::list_chat_mutes,
+            spam::mute_peer_chat,
+            spam::unmute_peer_chat,
+            spam::report_peer,
+            // Moderation commands
+            moderation::get_moderation_overview,
+            chat_export::export_conversation,
+            // File drop commands
+            file_drop::create_file_drop,
+            file_drop::list_file_drops,
+            file_drop::close_file_drop,
+            // Sync commands
+            sync::list_sync_pairs,
+            sync::add_sync_pair,
+            sync::remove_sync_pair,
+            sync::pause_sync_pair,
+            sync::resume_sync_pair,
+            sync::list_sync_conflicts,
+            sync::dismiss_sync_conflict,
+            // Delta transfer commands
+            delta::get_delta_settings,
+            delta::set_delta_settings,
+            delta::list_delta_transfers,
+            // File version commands
+            versions::list_file_versions,
+            versions::restore_file_version,
+            versions::delete_file_version,
+            versions::get_version_settings,
+            versions::set_version_settings,
             // Transfer commands
             commands::daemon_list_transfers,
+            checksums::daemon_verify_transfer,
+            history::transfer_history_list,
+            history::transfer_history_search,
+            history::transfer_history_clear,
             commands::daemon_get_transfer_stats,
             commands::daemon_cancel_transfer,
             commands::daemon_reject_transfer,
+            commands::daemon_set_transfer_rate_limits,
+            commands::daemon_set_transfer_rate_limit,
             commands::daemon_send_file,
             commands::daemon_accept_transfer,
+            transfer_files::transfer_reveal_in_folder,
+            transfer_files::transfer_open_file,
+            // Remote file browser commands
+            remote_files::list_remote_directory,
+            remote_files::stat_remote_path,
+            // Auto-accept commands
+            auto_accept::get_auto_accept_settings,
+            auto_accept::set_auto_accept_settings,
+            auto_accept::trust_peer,
+            auto_accept::untrust_peer,
+            // Save path commands
+            save_paths::get_save_path_settings,
+            save_paths::set_save_path_settings,
+            // Save folder commands
+            save_folders::list_save_folders,
+            save_folders::set_save_folder,
+            save_folders::remove_save_folder,
+            save_folders::default_save_path,
+            // File reputation commands
+            reputation::get_reputation_settings,
+            reputation::set_reputation_settings,
+            reputation::check_transfer_reputation,
+            // Scheduled transfer commands
+            scheduler::schedule_transfer,
+            scheduler::list_scheduled_transfers,
+            scheduler::update_scheduled_transfer,
+            scheduler::cancel_scheduled_transfer,
+            scheduler::run_scheduled_transfer_now,
+            // Printer sharing commands
+            printers::list_shared_printers,
+            printers::share_printer,
+            printers::unshare_printer,
+            // Game server commands
+            games::get_game_settings,
+            games::set_game_settings,
+            games::list_game_servers,
+            // mDNS reflection commands
+            mdns::get_mdns_reflection,
+            mdns::set_mdns_reflection,
+            mdns::set_mdns_service_types,
+            mdns::list_reflected_services,
+            // Share mount commands
+            mounts::mount_peer_share,
+            mounts::unmount_peer_share,
+            mounts::list_mounted_shares,
+            mounts::forget_share_credentials,
+            // Remote desktop commands
+            remote_desktop::launch_remote_desktop,
+            remote_desktop::list_remote_desktop_profiles,
+            remote_desktop::save_remote_desktop_profile,
+            remote_desktop::delete_remote_desktop_profile,
+            // Peer availability commands
+            availability::get_peer_availability,
+            // SLA alert commands
+            sla::get_sla_settings,
+            sla::set_sla_settings,
+            sla::set_peer_critical,
+            sla::list_sla_alerts,
+            sla::clear_sla_alerts,
+            // Network health commands
+            health::get_network_health,
+            // Session history commands
+            activity::get_session_history,
+            // Diagnostics commands
+            diagnostics::export_diagnostics_bundle,
+            diagnostics::get_metrics_snapshot,
+            // Release notes commands
+            whats_new::get_release_notes,
+            whats_new::get_update_release_notes,
+            whats_new::get_whats_new,
+            whats_new::mark_whats_new_seen,
+            // Update commands
+            updates::get_update_settings,
+            updates::set_update_settings,
+            updates::check_for_updates,
+            updates::install_staged_update,
+            // Portable mode commands
+            portable::get_portable_mode,
+            updates::get_update_history,
+            updates::update_rollback,
+            // Locale commands
+            i18n::list_supported_locales,
+            i18n::get_locale,
+            i18n::set_locale,
+            // Tray commands
+            tray::get_tray_settings,
+            tray::set_tray_settings,
+            // Recent chat commands
+            recent_chats::list_recent_chats,
+            recent_chats::mark_chat_read,
+            recent_chats::open_quick_reply_window,
+            recent_chats::get_quick_reply_target,
+            // Attention commands
+            attention::get_attention_settings,
+            attention::set_attention_settings,
+            attention::test_attention,
+            // Theme commands
+            theme::get_theme,
+            theme::set_theme,
+            // Host override commands
+            hosts::list_host_overrides,
+            hosts::set_host_override,
+            hosts::remove_host_override,
+            hosts::set_hosts_file_sync,
+            hosts::refresh_hosts_file,
+            // Address commands
+            addressing::get_address_family,
+            addressing::set_address_family,
+            addressing::format_address,
+            addressing::copy_peer_address,
+            // Clipboard commands
+            clipboard::format_invite,
+            clipboard::copy_invite,
+            clipboard::copy_address,
+            // Interface commands
+            interfaces::get_interface_info,
+            routes::get_effective_routes,
+            // VPN co-existence commands
+            coexistence::detect_vpn_adapters,
+            coexistence::get_coexistence_settings,
+            coexistence::set_coexistence_settings,
+            coexistence::plan_vpn_coexistence,
+            coexistence::apply_vpn_coexistence,
+            coexistence::revert_vpn_coexistence,
+            // Subnet commands
+            subnet::run_subnet_conflict_check,
+            // Topology commands
+            topology::get_topology,
+            // Dashboard commands
+            dashboard::get_dashboard_summary,
+            // Report commands
+            report::generate_usage_report,
+            // Quick switcher commands
+            quick_switch::quick_switch_query,
+            quick_switch::quick_switch_record,
+            // Accessibility commands
+            accessibility::get_accessibility_settings,
+            accessibility::set_accessibility_settings,
+            // Notification commands
+            notifications::list_notifications,
+            notifications::mark_notification_read,
+            notifications::clear_notifications,
+            notifications::record_notification,
+            // Sound commands
+            sounds::get_sound_settings,
+            sounds::update_sound_settings,
+            sounds::set_notification_sound,
+            sounds::preview_sound,
+            sounds::play_notification_sound,
+            // Presence commands
+            presence::get_presence_settings,
+            presence::set_presence_settings,
+            presence::preview_presence,
+            // Webhook commands
+            webhooks::list_webhooks,
+            webhooks::save_webhook,
+            webhooks::delete_webhook,
+            webhooks::test_webhook,
+            webhooks::list_webhook_deliveries,
+            webhooks::clear_webhook_deliveries,
+            // MQTT commands
+            mqtt::get_mqtt_settings,
+            mqtt::set_mqtt_settings,
+            mqtt::get_mqtt_status,
+            // Alert commands
+            alerts::get_alert_settings,
+            alerts::set_alert_settings,
+            alerts::send_test_alert,
+            // Peer monitor commands
+            peer_monitor::get_peer_monitor_settings,
+            peer_monitor::set_peer_monitor_settings,
+            peer_monitor::pin_peer,
+            peer_monitor::unpin_peer,
+            peer_monitor::list_pinned_peers,
+            peer_monitor::probe_pinned_peer,
+            peer_monitor::get_peer_monitor_history,
+            peer_monitor::get_peer_sla,
+            // Debug commands
+            #[cfg(debug_assertions)]
+            commands::debug_get_chaos,
+            #[cfg(debug_assertions)]
+            commands::debug_set_chaos,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                updates::on_exit(app);
+            }
+        });
 }
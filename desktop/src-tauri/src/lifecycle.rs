@@ -0,0 +1,242 @@
+// Daemon Lifecycle
+// Starting, stopping and restarting the daemon from the app, so a stopped
+// daemon can be recovered without a terminal. The CLI installs it as the
+// `goconnect-daemon` service (see cli/internal/svc): a systemd unit on Linux,
+// a launchd job on macOS and a Windows service. A per-user unit or agent is
+// preferred over the system-wide one, which needs administrator rights and
+// asks for them through the platform's usual prompt. Without any service the
+// daemon binary shipped next to the app is run as a plain process.
+
+use crate::commands::DaemonState;
+use crate::daemon::DaemonStatus;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+use tauri::State;
+use ts_rs::TS;
+
+const SERVICE_NAME: &str = "goconnect-daemon";
+
+/// Where the CLI's installer puts the system-wide launchd job and the binary
+const SYSTEM_PLIST: &str = "/Library/LaunchDaemons/goconnect-daemon.plist";
+const INSTALLED_BINARY: &str = "/usr/local/bin/goconnect-daemon";
+
+/// How long to wait for the daemon to come up or go away after an action
+const SETTLE_TIMEOUT: Duration = Duration::from_secs(15);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ServiceManager {
+    SystemdUser,
+    Systemd,
+    LaunchAgent,
+    LaunchDaemon,
+    WindowsService,
+    /// No service installed; the binary is run directly
+    Process,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct LifecycleOutcome {
+    pub manager: ServiceManager,
+    /// Whether the daemon answers after the action
+    pub running: bool,
+    pub status: Option<DaemonStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl Action {
+    fn verb(self) -> &'static str {
+        match self {
+            Action::Start => "start",
+            Action::Stop => "stop",
+            Action::Restart => "restart",
+        }
+    }
+}
+
+pub(crate) fn command(program: &str, args: &[&str]) -> Command {
+    let mut command = Command::new(program);
+    command.args(args);
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    command
+}
+
+fn succeeds(program: &str, args: &[&str]) -> bool {
+    command(program, args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+fn user_agent_plist() -> Option<PathBuf> {
+    Some(dirs::home_dir()?.join("Library/LaunchAgents").join(format!("{}.plist", SERVICE_NAME)))
+}
+
+/// The daemon binary next to the app, or where the installer puts it
+fn daemon_binary() -> Option<PathBuf> {
+    let name = format!("{}{}", SERVICE_NAME, std::env::consts::EXE_SUFFIX);
+    let bundled = std::env::current_exe().ok()?.parent()?.join(name);
+    if bundled.exists() {
+        return Some(bundled);
+    }
+    Some(PathBuf::from(INSTALLED_BINARY)).filter(|p| !cfg!(windows) && p.exists())
+}
+
+/// How the daemon is installed on this machine
+fn detect() -> Result<ServiceManager, String> {
+    let unit = format!("{}.service", SERVICE_NAME);
+    if cfg!(target_os = "linux") {
+        if succeeds("systemctl", &["--user", "cat", &unit]) {
+            return Ok(ServiceManager::SystemdUser);
+        }
+        if succeeds("systemctl", &["cat", &unit]) {
+            return Ok(ServiceManager::Systemd);
+        }
+    } else if cfg!(target_os = "macos") {
+        if user_agent_plist().is_some_and(|p| p.exists()) {
+            return Ok(ServiceManager::LaunchAgent);
+        }
+        if Path::new(SYSTEM_PLIST).exists() {
+            return Ok(ServiceManager::LaunchDaemon);
+        }
+    } else if cfg!(windows) && succeeds("sc", &["query", SERVICE_NAME]) {
+        return Ok(ServiceManager::WindowsService);
+    }
+    match daemon_binary() {
+        Some(_) => Ok(ServiceManager::Process),
+        None => Err("the GoConnect daemon is not installed; install it with 'goconnect service install'".to_string()),
+    }
+}
+
+/// Shell commands driving a launchd job; loading an already loaded job fails harmlessly
+fn launchd_script(domain: &str, plist: &Path, action: Action) -> String {
+    let target = format!("{}/{}", domain, SERVICE_NAME);
+    let bootstrap = format!("launchctl bootstrap {} '{}' 2>/dev/null", domain, plist.display());
+    match action {
+        Action::Start => format!("{}; launchctl kickstart {}", bootstrap, target),
+        Action::Stop => format!("launchctl bootout {}", target),
+        Action::Restart => format!("{}; launchctl kickstart -k {}", bootstrap, target),
+    }
+}
+
+/// Run or kill the daemon binary when no service manager owns it
+fn control_process(action: Action) -> Result<(), String> {
+    if action != Action::Start {
+        let killed = if cfg!(windows) {
+            succeeds("taskkill", &["/IM", &format!("{}.exe", SERVICE_NAME), "/F"])
+        } else {
+            succeeds("pkill", &["-f", &format!("(^|/){}( |$)", SERVICE_NAME)])
+        };
+        if action == Action::Stop {
+            return if killed { Ok(()) } else { Err("the daemon is not running".to_string()) };
+        }
+        if killed {
+            // Give it a moment to release its socket and port
+            std::thread::sleep(Duration::from_secs(1));
+        }
+    }
+    let binary = daemon_binary().ok_or("the GoConnect daemon binary was not found")?;
+    command(&binary.to_string_lossy(), &[])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("failed to run {}: {}", binary.display(), e))?;
+    Ok(())
+}
+
+fn control(manager: ServiceManager, action: Action) -> Result<(), String> {
+    let verb = action.verb();
+    let unit = format!("{}.service", SERVICE_NAME);
+    let status: std::io::Result<ExitStatus> = match manager {
+        ServiceManager::SystemdUser => command("systemctl", &["--user", verb, &unit]).status(),
+        ServiceManager::Systemd => command("pkexec", &["systemctl", verb, &unit]).status(),
+        ServiceManager::LaunchAgent => {
+            let plist = user_agent_plist().ok_or("no home directory")?;
+            command("sh", &["-c", &launchd_script("gui/$(id -u)", &plist, action)]).status()
+        }
+        ServiceManager::LaunchDaemon => {
+            let script = launchd_script("system", Path::new(SYSTEM_PLIST), action);
+            let script = format!("do shell script \"{}\" with administrator privileges", script);
+            command("osascript", &["-e", &script]).status()
+        }
+        ServiceManager::WindowsService => {
+            let cmdlet = match action {
+                Action::Start => "Start-Service",
+                Action::Stop => "Stop-Service",
+                Action::Restart => "Restart-Service",
+            };
+            let command_line = format!(
+                "Start-Process -FilePath powershell -ArgumentList '-NoProfile -Command {} {}' -Verb RunAs -Wait -WindowStyle Hidden",
+                cmdlet, SERVICE_NAME
+            );
+            command("powershell", &["-NoProfile", "-Command", &command_line]).status()
+        }
+        ServiceManager::Process => return control_process(action),
+    };
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        Ok(_) => Err(format!("could not {} the daemon service, or elevation was denied", verb)),
+        Err(e) => Err(format!("failed to run the service manager: {}", e)),
+    }
+}
+
+async fn run(state: &DaemonState, action: Action) -> Result<LifecycleOutcome, String> {
+    let manager = tauri::async_runtime::spawn_blocking(move || {
+        let manager = detect()?;
+        control(manager, action)?;
+        Ok::<_, String>(manager)
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+    log::info!("Asked {:?} to {} the daemon", manager, action.verb());
+
+    // Whatever we were connected to is gone or about to be
+    state.reset_client();
+    let want_running = action != Action::Stop;
+    let deadline = Instant::now() + SETTLE_TIMEOUT;
+    loop {
+        let status = state.probe_status().await.ok();
+        if status.is_some() == want_running || Instant::now() >= deadline {
+            return Ok(LifecycleOutcome { manager, running: status.is_some(), status });
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Start the daemon and wait until it answers
+#[tauri::command]
+pub async fn daemon_start(state: State<'_, DaemonState>) -> Result<LifecycleOutcome, String> {
+    run(&state, Action::Start).await
+}
+
+/// Stop the daemon and wait until it no longer answers
+#[tauri::command]
+pub async fn daemon_stop(state: State<'_, DaemonState>) -> Result<LifecycleOutcome, String> {
+    run(&state, Action::Stop).await
+}
+
+#[tauri::command]
+pub async fn daemon_restart(state: State<'_, DaemonState>) -> Result<LifecycleOutcome, String> {
+    run(&state, Action::Restart).await
+}
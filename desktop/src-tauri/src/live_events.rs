@@ -0,0 +1,298 @@
+// Live Transfer Events
+// Transfer progress and bandwidth samples fire many times per second during
+// heavy transfers. They go out as regular Tauri events (JSON) and, for views
+// that subscribe with a channel, optionally as compact MessagePack frames sent
+// as raw bytes, which skips JSON serialization on both sides. A frame is
+// `[kind, [fields...]]` with struct fields in declaration order; the decoder
+// lives in src/lib/live-events.ts and must follow any field change here.
+// Events are rate limited by `throttle` before they are delivered. Published
+// bandwidth samples are also kept for the throughput graph, budgeted by `memory`.
+
+use crate::checksums;
+use crate::commands::{daemon_call, DaemonState};
+use crate::history;
+use crate::daemon::TransferInfo;
+use crate::memory::{self, CacheEntry};
+use crate::part_files;
+use crate::throttle;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+pub const PROGRESS_EVENT: &str = "transfers://progress";
+pub const BANDWIDTH_EVENT: &str = "bandwidth://sample";
+
+/// Frame kinds of the binary encoding
+const FRAME_PROGRESS: u8 = 0;
+const FRAME_BANDWIDTH: u8 = 1;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// Weight of the newest measurement in the smoothed transfer rate
+const RATE_SMOOTHING: f64 = 0.5;
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TransferProgress {
+    pub id: String,
+    pub peer_id: String,
+    /// "upload" or "download"
+    pub direction: String,
+    pub status: String,
+    #[ts(type = "number")]
+    pub transferred: u64,
+    #[ts(type = "number")]
+    pub total: u64,
+    #[ts(type = "number")]
+    pub bytes_per_sec: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct BandwidthSample {
+    #[ts(type = "number")]
+    pub at_ms: u64,
+    #[ts(type = "number")]
+    pub up_bps: u64,
+    #[ts(type = "number")]
+    pub down_bps: u64,
+    pub active_transfers: u32,
+}
+
+/// What a JSON channel receives
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+#[ts(export)]
+pub enum LiveEvent {
+    Progress(TransferProgress),
+    Bandwidth(BandwidthSample),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum EventEncoding {
+    #[default]
+    Json,
+    Msgpack,
+}
+
+struct Subscriber {
+    channel: Channel<InvokeResponseBody>,
+    encoding: EventEncoding,
+}
+
+struct Tracked {
+    transferred: u64,
+    at: Instant,
+    /// Smoothed bytes per second
+    rate: f64,
+    /// `transferred` at the last bandwidth sample
+    sampled: u64,
+    upload: bool,
+    finished: bool,
+}
+
+/// Managed state holding channel subscribers and per-transfer rates
+#[derive(Default)]
+pub struct LiveEventState {
+    subscribers: Mutex<Vec<Subscriber>>,
+    transfers: Mutex<HashMap<String, Tracked>>,
+    history: Mutex<VecDeque<(Instant, BandwidthSample)>>,
+}
+
+impl LiveEventState {
+    pub(crate) fn history_entries(&self) -> Vec<CacheEntry> {
+        let history = self.history.lock().unwrap();
+        // Every sample serializes to about the same size
+        let bytes = history.front().map(|(_, s)| memory::approx_size(s)).unwrap_or_default();
+        history.iter().map(|(at, s)| CacheEntry { key: s.at_ms.to_string(), bytes, used: *at }).collect()
+    }
+
+    /// Drop the `count` oldest samples
+    pub(crate) fn evict_history(&self, count: usize) {
+        let mut history = self.history.lock().unwrap();
+        let count = count.min(history.len());
+        history.drain(..count);
+    }
+}
+
+impl LiveEvent {
+    fn encode(&self, encoding: EventEncoding) -> Result<InvokeResponseBody, String> {
+        match encoding {
+            EventEncoding::Json => serde_json::to_string(self).map(InvokeResponseBody::Json).map_err(|e| e.to_string()),
+            // rmp_serde writes structs as arrays, which is what makes the frames compact
+            EventEncoding::Msgpack => match self {
+                Self::Progress(p) => rmp_serde::to_vec(&(FRAME_PROGRESS, p)),
+                Self::Bandwidth(b) => rmp_serde::to_vec(&(FRAME_BANDWIDTH, b)),
+            }
+            .map(InvokeResponseBody::Raw)
+            .map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Emit to event listeners and every channel subscriber
+pub(crate) fn deliver(app: &AppHandle, event: LiveEvent) {
+    let _ = match &event {
+        LiveEvent::Progress(p) => app.emit(PROGRESS_EVENT, p),
+        LiveEvent::Bandwidth(b) => app.emit(BANDWIDTH_EVENT, b),
+    };
+    let state = app.state::<LiveEventState>();
+    let mut subscribers = state.subscribers.lock().unwrap();
+    if subscribers.is_empty() {
+        return;
+    }
+    // Encode once per encoding in use
+    let mut encoded: HashMap<bool, Result<InvokeResponseBody, String>> = HashMap::new();
+    subscribers.retain(|s| {
+        let body = encoded.entry(s.encoding == EventEncoding::Msgpack).or_insert_with(|| event.encode(s.encoding));
+        match body {
+            Ok(body) => s.channel.send(body.clone()).is_ok(),
+            Err(e) => {
+                log::warn!("Failed to encode live event: {}", e);
+                true
+            }
+        }
+    });
+}
+
+fn track(state: &LiveEventState, transfer: &TransferInfo) -> u64 {
+    let finished = !matches!(transfer.status.as_str(), "pending" | "active");
+    let now = Instant::now();
+    let mut transfers = state.transfers.lock().unwrap();
+    let tracked = transfers.entry(transfer.id.clone()).or_insert(Tracked {
+        transferred: transfer.transferred,
+        at: now,
+        rate: 0.0,
+        sampled: transfer.transferred,
+        upload: transfer.direction == "upload",
+        finished,
+    });
+    let elapsed = now.duration_since(tracked.at).as_secs_f64();
+    if elapsed > 0.0 && transfer.transferred >= tracked.transferred {
+        let instant = (transfer.transferred - tracked.transferred) as f64 / elapsed;
+        tracked.rate = RATE_SMOOTHING * instant + (1.0 - RATE_SMOOTHING) * tracked.rate;
+    }
+    tracked.transferred = transfer.transferred;
+    tracked.at = now;
+    tracked.finished = finished;
+    if finished {
+        tracked.rate = 0.0;
+    }
+    tracked.rate as u64
+}
+
+/// Bytes moved per direction since the last sample; finished transfers are dropped once counted
+fn sample(state: &LiveEventState) -> BandwidthSample {
+    let mut transfers = state.transfers.lock().unwrap();
+    let (mut up, mut down, mut active) = (0u64, 0u64, 0u32);
+    for tracked in transfers.values_mut() {
+        let moved = tracked.transferred.saturating_sub(tracked.sampled);
+        tracked.sampled = tracked.transferred;
+        if tracked.upload {
+            up += moved;
+        } else {
+            down += moved;
+        }
+        if !tracked.finished {
+            active += 1;
+        }
+    }
+    transfers.retain(|_, t| !t.finished);
+    let per_sec = |bytes: u64| (bytes as f64 / SAMPLE_INTERVAL.as_secs_f64()) as u64;
+    BandwidthSample {
+        at_ms: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0),
+        up_bps: per_sec(up),
+        down_bps: per_sec(down),
+        active_transfers: active,
+    }
+}
+
+/// Follow the daemon's transfer stream and sample bandwidth while anything moves
+pub fn spawn(app: AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let daemon = handle.state::<DaemonState>();
+        let state = handle.state::<LiveEventState>();
+        loop {
+            let subscribed = daemon_call!(daemon, "subscribe_transfers", |client| client.subscribe_transfers());
+            if let Ok(mut events) = subscribed {
+                while let Ok(Some(transfer)) = events.next().await {
+                    let bytes_per_sec = track(&state, &transfer);
+                    checksums::on_transfer_update(&handle, &transfer);
+                    part_files::on_transfer_update(&handle, &transfer);
+                    history::on_transfer_update(&handle, &transfer);
+                    throttle::offer(
+                        &handle,
+                        LiveEvent::Progress(TransferProgress {
+                            id: transfer.id,
+                            peer_id: transfer.peer_id,
+                            direction: transfer.direction,
+                            status: transfer.status,
+                            transferred: transfer.transferred,
+                            total: transfer.file_size,
+                            bytes_per_sec,
+                        }),
+                    );
+                }
+            }
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    });
+
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<LiveEventState>();
+        let mut interval = tokio::time::interval(SAMPLE_INTERVAL);
+        let mut was_idle = true;
+        loop {
+            interval.tick().await;
+            let sample = sample(&state);
+            // One zero sample after activity settles the graph; after that, stay quiet
+            let idle = sample.up_bps == 0 && sample.down_bps == 0 && sample.active_transfers == 0;
+            if !(idle && was_idle) {
+                crate::tray::set_speed(&app, (!idle).then_some((sample.up_bps, sample.down_bps)));
+                state.history.lock().unwrap().push_back((Instant::now(), sample.clone()));
+                throttle::offer(&app, LiveEvent::Bandwidth(sample));
+            }
+            was_idle = idle;
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Receive progress and bandwidth events on `channel`; returns the id to unsubscribe with
+#[tauri::command]
+pub async fn subscribe_live_events(
+    state: State<'_, LiveEventState>,
+    channel: Channel<InvokeResponseBody>,
+    encoding: Option<EventEncoding>,
+) -> Result<u32, String> {
+    let id = channel.id();
+    state.subscribers.lock().unwrap().push(Subscriber { channel, encoding: encoding.unwrap_or_default() });
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn unsubscribe_live_events(state: State<'_, LiveEventState>, id: u32) -> Result<(), String> {
+    state.subscribers.lock().unwrap().retain(|s| s.channel.id() != id);
+    Ok(())
+}
+
+/// Bandwidth samples kept since startup (or since they were evicted), oldest first
+#[tauri::command]
+pub async fn get_bandwidth_history(
+    state: State<'_, LiveEventState>,
+    since_ms: Option<u64>,
+) -> Result<Vec<BandwidthSample>, String> {
+    let since = since_ms.unwrap_or(0);
+    let history = state.history.lock().unwrap();
+    Ok(history.iter().map(|(_, s)| s).filter(|s| s.at_ms > since).cloned().collect())
+}
@@ -0,0 +1,449 @@
+// mDNS Reflection
+// Makes Bonjour/mDNS services (AirPlay, Chromecast, SMB, printers, ...) hosted on
+// one peer discoverable on the others. The daemon's tunnel does not carry
+// multicast, so reflection happens here: services this machine announces on its
+// LAN are sent to peers of reflection-enabled networks on MDNS_REFLECT_PORT, and
+// services received from those peers are re-announced locally at the peer's
+// virtual IP. Entries expire when a peer stops refreshing them.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use crate::tunnel;
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "mdns.json";
+
+pub const MDNS_REFLECT_PORT: u16 = 34160;
+
+const ANNOUNCE_INTERVAL: Duration = Duration::from_secs(30);
+/// Reflected services not refreshed for this long are withdrawn
+const EXPIRY_SECS: u64 = 95;
+const SEND_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_ANNOUNCEMENT_BYTES: u64 = 256 * 1024;
+const MAX_RECENT: usize = 200;
+
+/// Host names we register end with this, so our own announcements are not reflected back
+const REFLECTED_HOST_SUFFIX: &str = "-goconnect.local.";
+
+const DEFAULT_SERVICE_TYPES: &[&str] = &[
+    "_airplay._tcp.local.",
+    "_raop._tcp.local.",
+    "_googlecast._tcp.local.",
+    "_smb._tcp.local.",
+    "_afpovertcp._tcp.local.",
+    "_ipp._tcp.local.",
+    "_ipps._tcp.local.",
+    "_printer._tcp.local.",
+    "_spotify-connect._tcp.local.",
+    "_http._tcp.local.",
+];
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct MdnsSettings {
+    /// Networks reflection is enabled for
+    pub networks: Vec<String>,
+    /// Service types reflected, e.g. "_airplay._tcp.local."
+    pub service_types: Vec<String>,
+}
+
+impl Default for MdnsSettings {
+    fn default() -> Self {
+        Self { networks: Vec::new(), service_types: DEFAULT_SERVICE_TYPES.iter().map(|t| t.to_string()).collect() }
+    }
+}
+
+/// One service as sent between peers
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct ReflectedService {
+    service_type: String,
+    instance: String,
+    port: u16,
+    txt: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ReflectionDirection {
+    /// Ours, sent to a peer
+    Outgoing,
+    /// A peer's, announced here
+    Incoming,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ReflectionRecord {
+    pub direction: ReflectionDirection,
+    pub network_id: String,
+    pub peer_id: String,
+    pub service_type: String,
+    pub instance: String,
+    pub port: u16,
+    #[ts(type = "number")]
+    pub last_seen: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct MdnsReflectionStatus {
+    pub settings: MdnsSettings,
+    /// Services hosted here that are being sent to peers
+    pub local_services: u32,
+    /// Peer services currently announced here
+    pub reflected_services: u32,
+}
+
+/// A peer service we registered locally
+struct Registration {
+    expires_at: u64,
+}
+
+/// Managed state holding settings, discovered services and registrations
+pub struct MdnsState {
+    settings: JsonStore<MdnsSettings>,
+    daemon: Mutex<Option<ServiceDaemon>>,
+    /// Services hosted on this machine, by full name
+    local: Mutex<HashMap<String, ReflectedService>>,
+    /// Peer services registered here, by full name
+    registered: Mutex<HashMap<String, Registration>>,
+    recent: Mutex<Vec<ReflectionRecord>>,
+    changed: tokio::sync::Notify,
+}
+
+impl MdnsState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            daemon: Mutex::new(None),
+            local: Mutex::new(HashMap::new()),
+            registered: Mutex::new(HashMap::new()),
+            recent: Mutex::new(Vec::new()),
+            changed: tokio::sync::Notify::new(),
+        }
+    }
+
+    fn mdns(&self) -> Result<ServiceDaemon, String> {
+        let mut daemon = self.daemon.lock().unwrap();
+        if let Some(daemon) = &*daemon {
+            return Ok(daemon.clone());
+        }
+        let started = ServiceDaemon::new().map_err(|e| format!("failed to start mDNS: {}", e))?;
+        *daemon = Some(started.clone());
+        Ok(started)
+    }
+
+    fn note(&self, record: ReflectionRecord) {
+        let mut recent = self.recent.lock().unwrap();
+        recent.retain(|r| {
+            !(r.direction == record.direction
+                && r.peer_id == record.peer_id
+                && r.service_type == record.service_type
+                && r.instance == record.instance)
+        });
+        recent.push(record);
+        let excess = recent.len().saturating_sub(MAX_RECENT);
+        recent.drain(..excess);
+    }
+
+    /// Withdraw registrations past their expiry, or all of them
+    fn withdraw(&self, all: bool) {
+        let now = unix_now();
+        let expired: Vec<String> = {
+            let mut registered = self.registered.lock().unwrap();
+            let expired = registered
+                .iter()
+                .filter(|(_, r)| all || r.expires_at <= now)
+                .map(|(name, _)| name.clone())
+                .collect();
+            registered.retain(|_, r| !all && r.expires_at > now);
+            expired
+        };
+        if let Some(daemon) = &*self.daemon.lock().unwrap() {
+            for fullname in expired {
+                let _ = daemon.unregister(&fullname);
+            }
+        }
+    }
+
+    /// Stop browsing and announcing entirely
+    fn shutdown(&self) {
+        self.withdraw(true);
+        self.local.lock().unwrap().clear();
+        if let Some(daemon) = self.daemon.lock().unwrap().take() {
+            let _ = daemon.shutdown();
+        }
+    }
+}
+
+/// Addresses of this machine, except the tunnel's own
+fn own_addresses(virtual_ip: Option<IpAddr>) -> HashSet<IpAddr> {
+    if_addrs::get_if_addrs()
+        .map(|interfaces| {
+            interfaces
+                .into_iter()
+                .filter(|i| !i.is_loopback())
+                .map(|i| i.ip())
+                .filter(|ip| Some(*ip) != virtual_ip)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Track services this machine announces for one service type until browsing stops
+async fn browse(app: AppHandle, daemon: ServiceDaemon, service_type: String) {
+    let state = app.state::<MdnsState>();
+    let Ok(events) = daemon.browse(&service_type) else { return };
+    while let Ok(event) = events.recv_async().await {
+        match event {
+            ServiceEvent::ServiceResolved(info) => {
+                if info.get_hostname().ends_with(REFLECTED_HOST_SUFFIX) {
+                    continue;
+                }
+                let virtual_ip = app
+                    .state::<DaemonState>()
+                    .latest_status()
+                    .and_then(|s| s.virtual_ip.parse::<IpAddr>().ok());
+                let own = own_addresses(virtual_ip);
+                if !info.get_addresses().iter().any(|ip| own.contains(ip)) {
+                    continue;
+                }
+                let instance = info
+                    .get_fullname()
+                    .strip_suffix(&format!(".{}", info.get_type()))
+                    .unwrap_or(info.get_fullname())
+                    .to_string();
+                let service = ReflectedService {
+                    service_type: info.get_type().to_string(),
+                    instance,
+                    port: info.get_port(),
+                    txt: info.get_properties().clone().into_property_map_str(),
+                };
+                state.local.lock().unwrap().insert(info.get_fullname().to_string(), service);
+            }
+            ServiceEvent::ServiceRemoved(_, fullname) => {
+                state.local.lock().unwrap().remove(&fullname);
+            }
+            ServiceEvent::SearchStopped(_) => break,
+            _ => {}
+        }
+    }
+}
+
+/// Send our services to every online peer of the enabled networks
+async fn announce(app: &AppHandle, settings: &MdnsSettings) {
+    let state = app.state::<MdnsState>();
+    let daemon = app.state::<DaemonState>();
+    let services: Vec<ReflectedService> = state.local.lock().unwrap().values().cloned().collect();
+    let Ok(body) = serde_json::to_vec(&services) else { return };
+
+    for network_id in &settings.networks {
+        let Ok(peers) = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(network_id)) else {
+            continue;
+        };
+        for peer in peers.iter().filter(|p| p.connected && !p.is_self) {
+            let Ok(ip) = peer.virtual_ip.parse::<IpAddr>() else { continue };
+            let send = async {
+                let mut stream = TcpStream::connect(SocketAddr::new(ip, MDNS_REFLECT_PORT)).await?;
+                stream.write_all(&body).await?;
+                stream.shutdown().await
+            };
+            if tokio::time::timeout(SEND_TIMEOUT, send).await.is_ok_and(|r| r.is_ok()) {
+                for service in &services {
+                    state.note(ReflectionRecord {
+                        direction: ReflectionDirection::Outgoing,
+                        network_id: network_id.clone(),
+                        peer_id: peer.id.clone(),
+                        service_type: service.service_type.clone(),
+                        instance: service.instance.clone(),
+                        port: service.port,
+                        last_seen: unix_now(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Announce a peer's services locally at its virtual IP
+async fn receive(app: &AppHandle, stream: TcpStream, from: IpAddr) -> Result<(), String> {
+    let mut body = Vec::new();
+    tokio::time::timeout(SEND_TIMEOUT, stream.take(MAX_ANNOUNCEMENT_BYTES).read_to_end(&mut body))
+        .await
+        .map_err(|_| "announcement timed out".to_string())?
+        .map_err(|e| e.to_string())?;
+    let services: Vec<ReflectedService> = serde_json::from_slice(&body).map_err(|e| e.to_string())?;
+
+    // Only peers of networks reflection is enabled for
+    let state = app.state::<MdnsState>();
+    let daemon = app.state::<DaemonState>();
+    let settings = state.settings.get();
+    let mut sender = None;
+    for network_id in &settings.networks {
+        let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(network_id))
+            .unwrap_or_default();
+        if let Some(peer) = peers.into_iter().find(|p| p.virtual_ip.parse::<IpAddr>().ok() == Some(from)) {
+            sender = Some((network_id.clone(), peer));
+            break;
+        }
+    }
+    let (network_id, peer) = sender.ok_or("not a peer of a reflection-enabled network")?;
+
+    let mdns = state.mdns()?;
+    let peer_name = if peer.display_name.is_empty() { &peer.name } else { &peer.display_name };
+    let host_label: String = peer.id.chars().filter(char::is_ascii_alphanumeric).take(16).collect();
+    let host = format!("{}{}", host_label, REFLECTED_HOST_SUFFIX);
+    for service in services.into_iter().filter(|s| settings.service_types.contains(&s.service_type)) {
+        let instance: String = format!("{} ({})", service.instance, peer_name).chars().take(63).collect();
+        let info = ServiceInfo::new(&service.service_type, &instance, &host, from, service.port, service.txt.clone());
+        let info = match info {
+            Ok(info) => info,
+            Err(e) => {
+                log::debug!("Skipping reflected service {}: {}", service.instance, e);
+                continue;
+            }
+        };
+        let fullname = info.get_fullname().to_string();
+        let known = state.registered.lock().unwrap().contains_key(&fullname);
+        if !known {
+            mdns.register(info).map_err(|e| e.to_string())?;
+        }
+        state.registered.lock().unwrap().insert(fullname, Registration { expires_at: unix_now() + EXPIRY_SECS });
+        state.note(ReflectionRecord {
+            direction: ReflectionDirection::Incoming,
+            network_id: network_id.clone(),
+            peer_id: peer.id.clone(),
+            service_type: service.service_type,
+            instance: service.instance,
+            port: service.port,
+            last_seen: unix_now(),
+        });
+    }
+    Ok(())
+}
+
+/// Run browsing, announcing and the reflection listener while any network has it enabled
+pub fn spawn(app: AppHandle) {
+    let engine_app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let app = engine_app;
+        let state = app.state::<MdnsState>();
+        loop {
+            let settings = state.settings.get();
+            if settings.networks.is_empty() {
+                state.shutdown();
+                state.changed.notified().await;
+                continue;
+            }
+            let mdns = match state.mdns() {
+                Ok(mdns) => mdns,
+                Err(e) => {
+                    log::warn!("mDNS reflection unavailable: {}", e);
+                    state.changed.notified().await;
+                    continue;
+                }
+            };
+            for service_type in &settings.service_types {
+                tauri::async_runtime::spawn(browse(app.clone(), mdns.clone(), service_type.clone()));
+            }
+
+            let mut tick = tokio::time::interval(ANNOUNCE_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = tick.tick() => {
+                        state.withdraw(false);
+                        if app.state::<DaemonState>().connection.state().is_usable() {
+                            announce(&app, &settings).await;
+                        }
+                    }
+                    _ = state.changed.notified() => break,
+                }
+            }
+            for service_type in &settings.service_types {
+                let _ = mdns.stop_browse(service_type);
+            }
+            // Reflected services are re-registered on the next announcement if still wanted
+            state.withdraw(true);
+        }
+    });
+
+    tunnel::serve(
+        app,
+        "mDNS reflection listener",
+        MDNS_REFLECT_PORT,
+        |app| !app.state::<MdnsState>().settings.get().networks.is_empty(),
+        Some(|app| &app.state::<MdnsState>().inner().changed),
+        |app, stream, from| async move {
+            if let Err(e) = receive(&app, stream, from.ip()).await {
+                log::debug!("mDNS announcement from {} ignored: {}", from, e);
+            }
+        },
+    );
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_mdns_reflection(state: State<'_, MdnsState>) -> Result<MdnsReflectionStatus, String> {
+    Ok(MdnsReflectionStatus {
+        settings: state.settings.get(),
+        local_services: state.local.lock().unwrap().len() as u32,
+        reflected_services: state.registered.lock().unwrap().len() as u32,
+    })
+}
+
+#[tauri::command]
+pub async fn set_mdns_reflection(
+    state: State<'_, MdnsState>,
+    network_id: String,
+    enabled: bool,
+) -> Result<MdnsSettings, String> {
+    let settings = state.settings.update(|s| {
+        s.networks.retain(|n| n != &network_id);
+        if enabled {
+            s.networks.push(network_id);
+        }
+        s.clone()
+    })?;
+    state.changed.notify_waiters();
+    Ok(settings)
+}
+
+/// Replace the reflected service types; each must look like "_name._tcp.local."
+#[tauri::command]
+pub async fn set_mdns_service_types(
+    state: State<'_, MdnsState>,
+    service_types: Vec<String>,
+) -> Result<MdnsSettings, String> {
+    for t in &service_types {
+        let valid = t.starts_with('_') && (t.ends_with("._tcp.local.") || t.ends_with("._udp.local."));
+        if !valid {
+            return Err(format!("'{}' is not a service type like _airplay._tcp.local.", t));
+        }
+    }
+    let settings = state.settings.update(|s| {
+        s.service_types = service_types;
+        s.clone()
+    })?;
+    state.changed.notify_waiters();
+    Ok(settings)
+}
+
+/// Services reflected in either direction recently, newest first
+#[tauri::command]
+pub async fn list_reflected_services(state: State<'_, MdnsState>) -> Result<Vec<ReflectionRecord>, String> {
+    Ok(state.recent.lock().unwrap().iter().rev().cloned().collect())
+}
@@ -0,0 +1,294 @@
+// Memory Budget
+// The desktop keeps several caches so views render without a round trip:
+// prefetched chat pages and transfer lists, per-network peer snapshots and the
+// bandwidth history behind the throughput graph. Each has a byte budget; when
+// a cache grows past it, entries are evicted least recently used first, and
+// entries nobody touched for `max_idle_mins` go regardless. Sizes are
+// estimates (the JSON size of the cached data), which tracks the real heap
+// use closely enough to budget by.
+
+use crate::live_events::LiveEventState;
+use crate::peer_index::PeerIndexState;
+use crate::prefetch::PrefetchState;
+use crate::store::JsonStore;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "memory.json";
+
+const ENFORCE_INTERVAL: Duration = Duration::from_secs(30);
+
+const MAX_CACHE_MB: u32 = 1024;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct MemorySettings {
+    pub chat_pages_mb: u32,
+    pub transfers_mb: u32,
+    pub peer_snapshots_mb: u32,
+    pub bandwidth_history_mb: u32,
+    /// Entries unused for this long are dropped even within budget; 0 keeps them
+    pub max_idle_mins: u32,
+}
+
+impl Default for MemorySettings {
+    fn default() -> Self {
+        Self { chat_pages_mb: 16, transfers_mb: 4, peer_snapshots_mb: 64, bandwidth_history_mb: 2, max_idle_mins: 30 }
+    }
+}
+
+impl MemorySettings {
+    fn limit_bytes(&self, kind: CacheKind) -> u64 {
+        let mb = match kind {
+            CacheKind::ChatPages => self.chat_pages_mb,
+            CacheKind::Transfers => self.transfers_mb,
+            CacheKind::PeerSnapshots => self.peer_snapshots_mb,
+            CacheKind::BandwidthHistory => self.bandwidth_history_mb,
+        };
+        u64::from(mb) * 1024 * 1024
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum CacheKind {
+    ChatPages,
+    Transfers,
+    PeerSnapshots,
+    BandwidthHistory,
+}
+
+impl CacheKind {
+    const ALL: [Self; 4] = [Self::ChatPages, Self::Transfers, Self::PeerSnapshots, Self::BandwidthHistory];
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CacheUsage {
+    pub kind: CacheKind,
+    pub entries: u32,
+    #[ts(type = "number")]
+    pub bytes: u64,
+    #[ts(type = "number")]
+    pub limit_bytes: u64,
+    /// Entries evicted since startup
+    #[ts(type = "number")]
+    pub evicted: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct MemoryUsage {
+    pub caches: Vec<CacheUsage>,
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+    #[ts(type = "number")]
+    pub limit_bytes: u64,
+    /// Resident size of the whole process, where the OS reports it
+    #[ts(type = "number | null")]
+    pub process_resident_bytes: Option<u64>,
+}
+
+/// One evictable cache entry as reported by its owner
+pub(crate) struct CacheEntry {
+    pub key: String,
+    pub bytes: u64,
+    pub used: Instant,
+}
+
+/// A cached value with what the budget needs to know about it
+pub(crate) struct Cached<T> {
+    pub value: T,
+    pub fetched: Instant,
+    pub used: Instant,
+    pub bytes: u64,
+}
+
+impl<T: Serialize> Cached<T> {
+    pub fn new(value: T) -> Self {
+        let now = Instant::now();
+        Self { bytes: approx_size(&value), value, fetched: now, used: now }
+    }
+
+    /// Mark as used and hand out the value
+    pub fn touch(&mut self) -> &T {
+        self.used = Instant::now();
+        &self.value
+    }
+
+    /// Change the value in place, re-estimating its size
+    pub fn modify(&mut self, f: impl FnOnce(&mut T)) {
+        f(&mut self.value);
+        self.bytes = approx_size(&self.value);
+    }
+
+    pub fn entry(&self, key: &str) -> CacheEntry {
+        CacheEntry { key: key.to_string(), bytes: self.bytes, used: self.used }
+    }
+}
+
+/// Bytes `value` takes as JSON, counted without building the document
+pub(crate) fn approx_size<T: Serialize + ?Sized>(value: &T) -> u64 {
+    struct Counter(u64);
+    impl std::io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0 += buf.len() as u64;
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    let mut counter = Counter(0);
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0
+}
+
+/// Managed state holding the budgets and eviction counters
+pub struct MemoryState {
+    settings: JsonStore<MemorySettings>,
+    evicted: Mutex<[u64; 4]>,
+}
+
+impl MemoryState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), evicted: Mutex::new([0; 4]) }
+    }
+}
+
+fn entries(app: &AppHandle, kind: CacheKind) -> Vec<CacheEntry> {
+    match kind {
+        CacheKind::ChatPages => app.state::<PrefetchState>().message_entries(),
+        CacheKind::Transfers => app.state::<PrefetchState>().transfer_entries(),
+        CacheKind::PeerSnapshots => app.state::<PeerIndexState>().cache_entries(),
+        CacheKind::BandwidthHistory => app.state::<LiveEventState>().history_entries(),
+    }
+}
+
+fn evict(app: &AppHandle, kind: CacheKind, keys: &[String]) {
+    match kind {
+        CacheKind::ChatPages => app.state::<PrefetchState>().evict_messages(keys),
+        CacheKind::Transfers => app.state::<PrefetchState>().evict_transfers(),
+        CacheKind::PeerSnapshots => app.state::<PeerIndexState>().evict(keys),
+        CacheKind::BandwidthHistory => app.state::<LiveEventState>().evict_history(keys.len()),
+    }
+}
+
+/// Bring every cache back within its budget
+pub(crate) fn enforce(app: &AppHandle) {
+    let state = app.state::<MemoryState>();
+    let settings = state.settings.get();
+    let max_idle = (settings.max_idle_mins > 0).then(|| Duration::from_secs(u64::from(settings.max_idle_mins) * 60));
+    for kind in CacheKind::ALL {
+        let mut entries = entries(app, kind);
+        entries.sort_by_key(|e| e.used);
+        let limit = settings.limit_bytes(kind);
+        let mut total: u64 = entries.iter().map(|e| e.bytes).sum();
+        let mut victims = Vec::new();
+        for entry in entries {
+            let idle = max_idle.is_some_and(|max| entry.used.elapsed() > max);
+            if total <= limit && !idle {
+                // Oldest first, so everything after is newer and within budget
+                break;
+            }
+            total -= entry.bytes;
+            victims.push(entry.key);
+        }
+        if !victims.is_empty() {
+            log::debug!("Evicting {} {:?} entries, {} bytes left", victims.len(), kind, total);
+            state.evicted.lock().unwrap()[kind as usize] += victims.len() as u64;
+            evict(app, kind, &victims);
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn process_resident_bytes() -> Option<u64> {
+    // statm counts pages: size resident shared ...
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let pages: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+    Some(pages * 4096)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_resident_bytes() -> Option<u64> {
+    None
+}
+
+/// Enforce the budgets periodically
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(ENFORCE_INTERVAL);
+        loop {
+            interval.tick().await;
+            enforce(&app);
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_memory_settings(state: State<'_, MemoryState>) -> Result<MemorySettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_memory_settings(
+    app: AppHandle,
+    state: State<'_, MemoryState>,
+    settings: MemorySettings,
+) -> Result<MemorySettings, String> {
+    let limits =
+        [settings.chat_pages_mb, settings.transfers_mb, settings.peer_snapshots_mb, settings.bandwidth_history_mb];
+    if limits.iter().any(|&mb| mb > MAX_CACHE_MB) {
+        return Err(format!("cache limits must be at most {} MB", MAX_CACHE_MB));
+    }
+    state.settings.set(settings.clone())?;
+    enforce(&app);
+    Ok(settings)
+}
+
+/// Size of each cache against its budget, for the debug panel
+#[tauri::command]
+pub async fn get_memory_usage(app: AppHandle, state: State<'_, MemoryState>) -> Result<MemoryUsage, String> {
+    let settings = state.settings.get();
+    let evicted = *state.evicted.lock().unwrap();
+    let caches: Vec<CacheUsage> = CacheKind::ALL
+        .iter()
+        .map(|&kind| {
+            let entries = entries(&app, kind);
+            CacheUsage {
+                kind,
+                entries: entries.len() as u32,
+                bytes: entries.iter().map(|e| e.bytes).sum(),
+                limit_bytes: settings.limit_bytes(kind),
+                evicted: evicted[kind as usize],
+            }
+        })
+        .collect();
+    Ok(MemoryUsage {
+        total_bytes: caches.iter().map(|c| c.bytes).sum(),
+        limit_bytes: caches.iter().map(|c| c.limit_bytes).sum(),
+        caches,
+        process_resident_bytes: process_resident_bytes(),
+    })
+}
+
+/// Drop every cached entry now
+#[tauri::command]
+pub async fn clear_caches(app: AppHandle, state: State<'_, MemoryState>) -> Result<(), String> {
+    for kind in CacheKind::ALL {
+        let keys: Vec<String> = entries(&app, kind).into_iter().map(|e| e.key).collect();
+        state.evicted.lock().unwrap()[kind as usize] += keys.len() as u64;
+        evict(&app, kind, &keys);
+    }
+    Ok(())
+}
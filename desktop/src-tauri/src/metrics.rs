@@ -0,0 +1,62 @@
+// RPC Metrics
+// Per-command call counters and latencies collected by the command pipeline
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CommandMetrics {
+    pub command: String,
+    #[ts(type = "number")]
+    pub calls: u64,
+    #[ts(type = "number")]
+    pub failures: u64,
+    /// Calls that were retried after refreshing the IPC token
+    #[ts(type = "number")]
+    pub auth_retries: u64,
+    #[ts(type = "number")]
+    pub total_ms: u64,
+    #[ts(type = "number")]
+    pub max_ms: u64,
+    pub last_error: Option<String>,
+}
+
+/// Thread-safe registry of command metrics, keyed by command name
+#[derive(Default)]
+pub struct RpcMetrics(Mutex<HashMap<&'static str, CommandMetrics>>);
+
+impl RpcMetrics {
+    pub fn record(&self, command: &'static str, elapsed: Duration, error: Option<&str>, retried: bool) {
+        let elapsed_ms = elapsed.as_millis() as u64;
+        let mut map = self.0.lock().unwrap();
+        let entry = map.entry(command).or_insert_with(|| CommandMetrics {
+            command: command.to_string(),
+            ..Default::default()
+        });
+
+        entry.calls += 1;
+        entry.total_ms += elapsed_ms;
+        entry.max_ms = entry.max_ms.max(elapsed_ms);
+        if retried {
+            entry.auth_retries += 1;
+        }
+        if let Some(error) = error {
+            entry.failures += 1;
+            entry.last_error = Some(error.to_string());
+        }
+    }
+
+    /// All metrics, sorted by command name
+    pub fn snapshot(&self) -> Vec<CommandMetrics> {
+        let mut all: Vec<_> = self.0.lock().unwrap().values().cloned().collect();
+        all.sort_by(|a, b| a.command.cmp(&b.command));
+        all
+    }
+
+    pub fn reset(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
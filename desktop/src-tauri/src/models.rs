@@ -0,0 +1,108 @@
+// Output Models
+// Stable JSON shapes for the scripting surfaces (the command line's `--json`
+// output and the automation socket's JSON format). Every reply is wrapped in the
+// same versioned envelope; fields are only ever added within a schema version,
+// so tooling can check `schema_version` once and rely on the rest.
+
+use crate::commands::DaemonState;
+use crate::connection::ConnectionState;
+use crate::daemon::DaemonStatus;
+use ts_rs::TS;
+
+/// Bumped when a field is renamed, removed or changes meaning
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct Envelope<T> {
+    pub schema_version: u32,
+    /// Present on success
+    pub data: Option<T>,
+    /// Present on failure
+    pub error: Option<ErrorBody>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ErrorBody {
+    pub message: String,
+}
+
+impl<T> Envelope<T> {
+    pub fn ok(data: T) -> Self {
+        Self { schema_version: SCHEMA_VERSION, data: Some(data), error: None }
+    }
+
+    pub fn err(message: impl Into<String>) -> Self {
+        Self { schema_version: SCHEMA_VERSION, data: None, error: Some(ErrorBody { message: message.into() }) }
+    }
+
+    pub fn from_result(result: Result<T, String>) -> Self {
+        match result {
+            Ok(data) => Self::ok(data),
+            Err(e) => Self::err(e),
+        }
+    }
+}
+
+impl<T: serde::Serialize> Envelope<T> {
+    /// Single-line JSON
+    pub fn to_line(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|e| {
+            format!(r#"{{"schema_version":{},"data":null,"error":{{"message":"{}"}}}}"#, SCHEMA_VERSION, e)
+        })
+    }
+}
+
+/// Tunnel status as reported to scripts
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct StatusReport {
+    /// Desktop-to-daemon connection state; `None` from the command line, which
+    /// talks to the daemon directly
+    pub state: Option<ConnectionState>,
+    pub connected: bool,
+    pub network_name: Option<String>,
+    /// Address of the preferred family
+    pub virtual_ip: Option<String>,
+    pub virtual_ipv4: Option<String>,
+    pub virtual_ipv6: Option<String>,
+    pub active_peers: Option<u32>,
+}
+
+impl StatusReport {
+    pub fn new(state: Option<ConnectionState>, status: Option<&DaemonStatus>) -> Self {
+        Self {
+            state,
+            connected: status.is_some_and(|s| s.connected),
+            network_name: status.map(|s| s.network_name.clone()),
+            virtual_ip: status.map(|s| s.virtual_ip.clone()),
+            virtual_ipv4: status.and_then(|s| s.virtual_ipv4.clone()),
+            virtual_ipv6: status.and_then(|s| s.virtual_ipv6.clone()),
+            active_peers: status.map(|s| s.active_peers),
+        }
+    }
+
+    /// Status of a running app, asking the daemon fresh
+    #[cfg_attr(not(feature = "automation"), allow(dead_code))]
+    pub async fn probe(daemon: &DaemonState) -> Self {
+        let status = daemon.probe_status().await.ok();
+        Self::new(Some(daemon.connection.state()), status.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct JoinReport {
+    pub network_id: String,
+    pub network_name: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct SendReport {
+    pub transfer_id: String,
+    pub peer_id: String,
+    /// Absolute path of the file being sent
+    pub file: String,
+}
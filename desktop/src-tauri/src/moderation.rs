@@ -0,0 +1,236 @@
+// Moderation Overview
+// One call gathering what an admin of a busy network needs at a glance:
+// kicks and bans made from this device, the queue of join requests, recent
+// messages flagged by the content filter or flood protection, and the members
+// who wrote the most lately. The daemon keeps no moderation history, so kicks
+// and bans are logged here as they are made. Sections are fetched concurrently
+// and one failing leaves the others intact.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::content_filter::ContentFilterState;
+use crate::daemon::{ChatMessage, JoinRequest, PeerInfo};
+use crate::spam::SpamState;
+use crate::store::JsonStore;
+use std::collections::HashMap;
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const LOG_FILE: &str = "moderation_log.json";
+
+/// Oldest log entries are dropped beyond this many
+const MAX_LOG: usize = 500;
+
+/// Log entries and flagged messages returned per overview
+const RECENT_ACTIONS: usize = 50;
+const MAX_FLAGGED: usize = 50;
+
+/// How many of the latest messages flags and activity are computed from
+const SAMPLE_MESSAGES: i32 = 200;
+const MOST_ACTIVE: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ModerationAction {
+    Kick,
+    Ban,
+    Unban,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ModerationRecord {
+    pub network_id: String,
+    pub peer_id: String,
+    /// Filled in from the member list when the overview is built
+    #[serde(default)]
+    pub peer_name: Option<String>,
+    pub action: ModerationAction,
+    pub reason: Option<String>,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub at: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum FlagReason {
+    ContentFilter,
+    Flood,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct FlaggedMessage {
+    pub message: ChatMessage,
+    pub sender_name: String,
+    pub reasons: Vec<FlagReason>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ActiveMember {
+    pub peer_id: String,
+    pub name: String,
+    pub role: String,
+    pub online: bool,
+    pub message_count: u32,
+    /// Unix timestamp (seconds) of their latest message in the sample
+    #[ts(type = "number | null")]
+    pub last_message_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ModerationOverview {
+    pub network_id: String,
+    pub member_count: u32,
+    pub online_count: u32,
+    /// Newest first
+    pub recent_actions: Vec<ModerationRecord>,
+    /// `None` when the daemon cannot list them, e.g. an older version
+    pub join_requests: Option<Vec<JoinRequest>>,
+    /// Newest first
+    pub flagged_messages: Vec<FlaggedMessage>,
+    pub most_active: Vec<ActiveMember>,
+    /// Messages the flags and activity were computed from
+    pub sampled_messages: u32,
+    /// Sections that could not be loaded, with the reason
+    pub errors: Vec<String>,
+    #[ts(type = "number")]
+    pub generated_at: u64,
+}
+
+/// Managed state holding the local moderation log
+pub struct ModerationState {
+    log: JsonStore<Vec<ModerationRecord>>,
+}
+
+impl ModerationState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { log: JsonStore::open(app, LOG_FILE) }
+    }
+
+    /// Log a kick, ban or unban that the daemon carried out
+    pub fn record(&self, network_id: &str, peer_id: &str, action: ModerationAction, reason: Option<&str>) {
+        let result = self.log.update(|log| {
+            log.push(ModerationRecord {
+                network_id: network_id.to_string(),
+                peer_id: peer_id.to_string(),
+                peer_name: None,
+                action,
+                reason: reason.map(str::trim).filter(|r| !r.is_empty()).map(String::from),
+                at: unix_now(),
+            });
+            let excess = log.len().saturating_sub(MAX_LOG);
+            log.drain(..excess);
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to log moderation action: {}", e);
+        }
+    }
+}
+
+fn display_name(peer: &PeerInfo) -> String {
+    if peer.display_name.is_empty() { peer.name.clone() } else { peer.display_name.clone() }
+}
+
+fn most_active(messages: &[ChatMessage], peers: &HashMap<&str, &PeerInfo>) -> Vec<ActiveMember> {
+    let mut counts: HashMap<&str, (u32, Option<u64>)> = HashMap::new();
+    for message in messages {
+        let entry = counts.entry(&message.peer_id).or_default();
+        entry.0 += 1;
+        entry.1 = entry.1.max(message.timestamp.parse().ok());
+    }
+    let mut members: Vec<ActiveMember> = counts
+        .into_iter()
+        .map(|(peer_id, (message_count, last_message_at))| {
+            let peer = peers.get(peer_id);
+            ActiveMember {
+                peer_id: peer_id.to_string(),
+                name: peer.map(|p| display_name(p)).unwrap_or_else(|| peer_id.to_string()),
+                role: peer.map(|p| p.role.clone()).unwrap_or_default(),
+                online: peer.is_some_and(|p| p.connected),
+                message_count,
+                last_message_at,
+            }
+        })
+        .collect();
+    members.sort_by_key(|m| std::cmp::Reverse((m.message_count, m.last_message_at)));
+    members.truncate(MOST_ACTIVE);
+    members
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_moderation_overview(
+    state: State<'_, ModerationState>,
+    daemon: State<'_, DaemonState>,
+    filter: State<'_, ContentFilterState>,
+    spam: State<'_, SpamState>,
+    network_id: String,
+) -> Result<ModerationOverview, String> {
+    let (peers, messages, join_requests) = tokio::join!(
+        async { daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(&network_id)) },
+        async {
+            daemon_call!(daemon, "get_messages", |client| client.get_messages(&network_id, SAMPLE_MESSAGES, None))
+        },
+        async { daemon_call!(daemon, "list_join_requests", |client| client.list_join_requests(&network_id)) },
+    );
+    // Without the member list there is nothing to moderate
+    let peers = peers?;
+    let by_id: HashMap<&str, &PeerInfo> = peers.iter().map(|p| (p.id.as_str(), p)).collect();
+    let name_of = |peer_id: &str| by_id.get(peer_id).map(|p| display_name(p));
+
+    let mut errors = Vec::new();
+    let messages = messages.unwrap_or_else(|e| {
+        errors.push(format!("messages: {}", e));
+        Vec::new()
+    });
+    let join_requests = join_requests.map_err(|e| errors.push(format!("join requests: {}", e))).ok();
+
+    let mut recent_actions: Vec<ModerationRecord> =
+        state.log.get().into_iter().rev().filter(|r| r.network_id == network_id).take(RECENT_ACTIONS).collect();
+    for record in &mut recent_actions {
+        record.peer_name = name_of(&record.peer_id);
+    }
+
+    let evidence = spam.evidence(&network_id);
+    let mut flagged_messages: Vec<FlaggedMessage> = messages
+        .iter()
+        .rev()
+        .filter_map(|message| {
+            let mut reasons = Vec::new();
+            if filter.flags(&network_id, &message.content) {
+                reasons.push(FlagReason::ContentFilter);
+            }
+            if evidence.contains(&message.id) {
+                reasons.push(FlagReason::Flood);
+            }
+            (!reasons.is_empty()).then(|| FlaggedMessage {
+                sender_name: name_of(&message.peer_id).unwrap_or_else(|| message.peer_id.clone()),
+                message: message.clone(),
+                reasons,
+            })
+        })
+        .collect();
+    flagged_messages.truncate(MAX_FLAGGED);
+
+    Ok(ModerationOverview {
+        member_count: peers.len() as u32,
+        online_count: peers.iter().filter(|p| p.connected).count() as u32,
+        recent_actions,
+        join_requests,
+        flagged_messages,
+        most_active: most_active(&messages, &by_id),
+        sampled_messages: messages.len() as u32,
+        errors,
+        generated_at: unix_now(),
+        network_id,
+    })
+}
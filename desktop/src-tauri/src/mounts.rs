@@ -0,0 +1,476 @@
+// Peer Share Mounts
+// Mounts a peer's SMB or NFS share over its virtual IP with the OS tools: a folder
+// under ~/GoConnect/Mounts on macOS and Linux, the first free drive letter on
+// Windows. Credentials can be remembered in the system keyring. Passwords never
+// go on a command line, where other users could read them: Linux gets a private
+// credentials file, macOS the login keychain and Windows `net use` reads it from
+// stdin. Mounts are tracked so they are cleanly unmounted when the tunnel goes down.
+
+use crate::addressing;
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use std::process::Command;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const MOUNTS_FILE: &str = "mounts.json";
+const KEYRING_SERVICE: &str = "goconnect-shares";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ShareProtocol {
+    Smb,
+    Nfs,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ShareCredentials {
+    pub username: String,
+    pub password: String,
+    #[serde(default)]
+    pub domain: Option<String>,
+    /// Store in the system keyring for next time
+    #[serde(default)]
+    pub remember: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct MountedShare {
+    pub id: String,
+    pub peer_id: String,
+    pub protocol: ShareProtocol,
+    /// Share name (SMB) or export path (NFS)
+    pub share: String,
+    /// Folder, or drive letter like "Z:" on Windows
+    pub mountpoint: String,
+    #[ts(type = "number")]
+    pub mounted_at: u64,
+}
+
+/// Managed state tracking what we mounted
+pub struct MountState {
+    mounts: JsonStore<Vec<MountedShare>>,
+}
+
+impl MountState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { mounts: JsonStore::open(app, MOUNTS_FILE) }
+    }
+}
+
+fn keyring_entry(peer_id: &str, share: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYRING_SERVICE, &format!("{}/{}", peer_id, share)).map_err(|e| e.to_string())
+}
+
+fn stored_credentials(peer_id: &str, share: &str) -> Option<ShareCredentials> {
+    let secret = keyring_entry(peer_id, share).ok()?.get_password().ok()?;
+    serde_json::from_str(&secret).ok()
+}
+
+fn remember_credentials(peer_id: &str, share: &str, credentials: &ShareCredentials) -> Result<(), String> {
+    let secret = serde_json::to_string(credentials).map_err(|e| e.to_string())?;
+    keyring_entry(peer_id, share)?.set_password(&secret).map_err(|e| format!("failed to save to the keyring: {}", e))
+}
+
+/// Letters and digits of `text`, for folder names
+#[cfg(not(windows))]
+fn path_safe(text: &str) -> String {
+    let safe: String = text
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    safe.trim_matches('_').to_string()
+}
+
+#[cfg(not(windows))]
+fn mount_root() -> Result<std::path::PathBuf, String> {
+    Ok(dirs::home_dir().ok_or("no home directory")?.join("GoConnect").join("Mounts"))
+}
+
+#[cfg(windows)]
+fn free_drive_letter() -> Result<String, String> {
+    ('D'..='Z')
+        .rev()
+        .map(|l| format!("{}:", l))
+        .find(|d| !std::path::Path::new(&format!("{}\\", d)).exists())
+        .ok_or_else(|| "no free drive letter".to_string())
+}
+
+/// Keychain protocol code mount_smbfs looks passwords up under
+#[cfg(target_os = "macos")]
+const KEYCHAIN_SMB_PROTOCOL: &str = "smb ";
+
+/// Percent-encode for the user part of an smb:// URL
+#[cfg(target_os = "macos")]
+fn url_escape(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn run(mut command: Command) -> Result<(), String> {
+    let output = command.output().map_err(|e| format!("failed to run mount tool: {}", e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Err(if stderr.is_empty() { stdout } else { stderr })
+}
+
+/// Like `run`, feeding `input` to the tool's stdin
+#[cfg(any(target_os = "macos", windows))]
+fn run_with_input(mut command: Command, input: &str) -> Result<(), String> {
+    use std::io::Write;
+    use std::process::Stdio;
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run mount tool: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes()).map_err(|e| format!("failed to write to mount tool: {}", e))?;
+    }
+    let output = child.wait_with_output().map_err(|e| format!("failed to run mount tool: {}", e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Err(if stderr.is_empty() { stdout } else { stderr })
+}
+
+/// Put an SMB password in the login keychain, where `mount_smbfs -N` finds it.
+/// The command goes through `security -i` on stdin to keep it out of `ps`.
+#[cfg(target_os = "macos")]
+fn keychain_add(server: &str, account: &str, password: &str) -> Result<(), String> {
+    let quote = |text: &str| format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+    let line = format!(
+        "add-internet-password -U -r {} -s {} -a {} -w {}\n",
+        quote(KEYCHAIN_SMB_PROTOCOL),
+        quote(server),
+        quote(account),
+        quote(password)
+    );
+    let mut command = Command::new("security");
+    command.arg("-i");
+    run_with_input(command, &line).map_err(|e| format!("failed to store the password in the keychain: {}", e))
+}
+
+#[cfg(target_os = "macos")]
+fn keychain_remove(server: &str, account: &str) {
+    let mut command = Command::new("security");
+    command.args(["delete-internet-password", "-r", KEYCHAIN_SMB_PROTOCOL, "-s", server, "-a", account]);
+    let _ = run(command);
+}
+
+/// Mount `share` from `ip` at `mountpoint` (a drive letter is picked on Windows)
+fn mount(
+    protocol: ShareProtocol,
+    ip: &str,
+    share: &str,
+    mountpoint: &str,
+    credentials: Option<&ShareCredentials>,
+) -> Result<(), String> {
+    // Each value is one line of a credentials file or of a tool's stdin
+    if let Some(c) = credentials {
+        if [&c.username, &c.password].into_iter().chain(&c.domain).any(|v| v.contains(['\n', '\r'])) {
+            return Err("share credentials cannot contain line breaks".to_string());
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let mut command = Command::new("pkexec");
+        command.arg("mount");
+        match protocol {
+            ShareProtocol::Smb => {
+                // Credentials go through a private file rather than the command line
+                let mut options = format!("uid={},gid={}", users_id("-u"), users_id("-g"));
+                let creds_file = match credentials {
+                    Some(c) => {
+                        let path = std::env::temp_dir().join(format!("goconnect-cifs-{}", rand::random::<u64>()));
+                        let mut body = format!("username={}\npassword={}\n", c.username, c.password);
+                        if let Some(domain) = &c.domain {
+                            body.push_str(&format!("domain={}\n", domain));
+                        }
+                        write_private(&path, &body)?;
+                        options.push_str(&format!(",credentials={}", path.display()));
+                        Some(path)
+                    }
+                    None => {
+                        options.push_str(",guest");
+                        None
+                    }
+                };
+                command.args(["-t", "cifs", &format!("//{}/{}", addressing::host(ip), share), mountpoint, "-o", &options]);
+                let result = run(command);
+                if let Some(path) = creds_file {
+                    let _ = std::fs::remove_file(path);
+                }
+                result
+            }
+            ShareProtocol::Nfs => {
+                command.args(["-t", "nfs", &format!("{}:{}", addressing::host(ip), share), mountpoint]);
+                run(command)
+            }
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        match protocol {
+            ShareProtocol::Smb => {
+                let user = match credentials {
+                    Some(c) => {
+                        keychain_add(ip, &c.username, &c.password)?;
+                        match &c.domain {
+                            Some(domain) => format!("{};{}@", url_escape(domain), url_escape(&c.username)),
+                            None => format!("{}@", url_escape(&c.username)),
+                        }
+                    }
+                    None => "guest:@".to_string(),
+                };
+                let mut command = Command::new("mount_smbfs");
+                command.args(["-N", &format!("//{}{}/{}", user, addressing::host(ip), share), mountpoint]);
+                let result = run(command);
+                // A remembered password stays for reconnects after sleep
+                if let Some(c) = credentials.filter(|c| !c.remember) {
+                    keychain_remove(ip, &c.username);
+                }
+                result
+            }
+            ShareProtocol::Nfs => {
+                let mut command = Command::new("mount_nfs");
+                command.args(["-o", "resvport,soft", &format!("{}:{}", addressing::host(ip), share), mountpoint]);
+                run(command)
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        let remote = format!("\\\\{}\\{}", addressing::unc_host(ip), share.trim_start_matches('/').replace('/', "\\"));
+        match protocol {
+            ShareProtocol::Smb => {
+                let mut command = Command::new("net");
+                command.args(["use", mountpoint, &remote]);
+                match credentials {
+                    // "*" makes net use read the password from stdin
+                    Some(c) => {
+                        let user = match &c.domain {
+                            Some(domain) => format!("{}\\{}", domain, c.username),
+                            None => c.username.clone(),
+                        };
+                        command.arg("*").arg(format!("/user:{}", user)).arg("/persistent:no");
+                        run_with_input(command, &format!("{}\r\n", c.password))
+                    }
+                    None => {
+                        command.arg("/user:guest").arg("").arg("/persistent:no");
+                        run(command)
+                    }
+                }
+            }
+            // Needs the optional "Client for NFS" Windows feature
+            ShareProtocol::Nfs => {
+                let mut command = Command::new("mount");
+                command.args(["-o", "anon", &remote, mountpoint]);
+                run(command)
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn users_id(flag: &str) -> String {
+    Command::new("id")
+        .arg(flag)
+        .output()
+        .ok()
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .filter(|id| !id.is_empty())
+        .unwrap_or_else(|| "0".to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn write_private(path: &std::path::Path, body: &str) -> Result<(), String> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+        .map_err(|e| e.to_string())?;
+    file.write_all(body.as_bytes()).map_err(|e| e.to_string())
+}
+
+fn unmount(share: &MountedShare) -> Result<(), String> {
+    #[cfg(target_os = "linux")]
+    let command = {
+        let mut command = Command::new("pkexec");
+        command.args(["umount", &share.mountpoint]);
+        command
+    };
+    #[cfg(target_os = "macos")]
+    let command = {
+        let mut command = Command::new("diskutil");
+        command.args(["unmount", &share.mountpoint]);
+        command
+    };
+    #[cfg(windows)]
+    let command = {
+        let mut command = Command::new(if share.protocol == ShareProtocol::Nfs { "umount" } else { "net" });
+        if share.protocol == ShareProtocol::Nfs {
+            command.arg(&share.mountpoint);
+        } else {
+            command.args(["use", &share.mountpoint, "/delete", "/y"]);
+        }
+        command
+    };
+    run(command)?;
+
+    #[cfg(not(windows))]
+    let _ = std::fs::remove_dir(&share.mountpoint);
+    Ok(())
+}
+
+/// Unmount everything we mounted, keeping entries that refused to unmount
+async fn unmount_all(app: &AppHandle) {
+    let state = app.state::<MountState>();
+    let mounts = state.mounts.get();
+    if mounts.is_empty() {
+        return;
+    }
+    let failed = tauri::async_runtime::spawn_blocking(move || {
+        mounts
+            .into_iter()
+            .filter(|m| match unmount(m) {
+                Ok(()) => false,
+                Err(e) => {
+                    log::warn!("Failed to unmount {}: {}", m.mountpoint, e);
+                    true
+                }
+            })
+            .map(|m| m.id)
+            .collect::<Vec<_>>()
+    })
+    .await
+    .unwrap_or_default();
+    if let Err(e) = state.mounts.update(|mounts| mounts.retain(|m| failed.contains(&m.id))) {
+        log::warn!("Failed to update mounted shares: {}", e);
+    }
+}
+
+/// Unmount peer shares when the tunnel goes down
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut status_rx = app.state::<DaemonState>().subscribe_status();
+        let mut was_connected = false;
+        while status_rx.changed().await.is_ok() {
+            let connected = status_rx.borrow_and_update().as_ref().is_some_and(|s| s.connected);
+            if was_connected && !connected {
+                unmount_all(&app).await;
+            }
+            was_connected = connected;
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Mount a peer's share. Without `credentials`, ones saved in the keyring are used,
+/// falling back to guest access.
+#[tauri::command]
+pub async fn mount_peer_share(
+    daemon: State<'_, DaemonState>,
+    state: State<'_, MountState>,
+    peer_id: String,
+    protocol: ShareProtocol,
+    share: String,
+    credentials: Option<ShareCredentials>,
+) -> Result<MountedShare, String> {
+    let share = share.trim().to_string();
+    if share.is_empty() {
+        return Err("enter the share name or export path".to_string());
+    }
+    if state.mounts.get().iter().any(|m| m.peer_id == peer_id && m.share == share) {
+        return Err(format!("{} is already mounted", share));
+    }
+    let peers = daemon_call!(daemon, "get_peers", |client| client.get_peers())?;
+    let peer = peers
+        .into_iter()
+        .find(|p| p.id == peer_id && p.connected)
+        .ok_or("the peer is not online")?;
+
+    // A portable run must not leave passwords in the host's keyring
+    if let Some(c) = credentials.as_ref().filter(|c| c.remember && !crate::portable::enabled()) {
+        remember_credentials(&peer_id, &share, c)?;
+    }
+    let credentials = credentials.or_else(|| stored_credentials(&peer_id, &share));
+
+    #[cfg(not(windows))]
+    let mountpoint = {
+        let peer_name = if peer.display_name.is_empty() { &peer.name } else { &peer.display_name };
+        let dir = mount_root()?.join(path_safe(peer_name)).join(path_safe(&share));
+        std::fs::create_dir_all(&dir).map_err(|e| format!("failed to create {:?}: {}", dir, e))?;
+        dir.to_string_lossy().into_owned()
+    };
+    #[cfg(windows)]
+    let mountpoint = free_drive_letter()?;
+
+    let (ip, share_name, target) = (peer.virtual_ip.clone(), share.clone(), mountpoint.clone());
+    tauri::async_runtime::spawn_blocking(move || mount(protocol, &ip, &share_name, &target, credentials.as_ref()))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("mount failed: {}", e))?;
+
+    let mounted = MountedShare {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        peer_id,
+        protocol,
+        share,
+        mountpoint,
+        mounted_at: unix_now(),
+    };
+    state.mounts.update(|mounts| mounts.push(mounted.clone()))?;
+    Ok(mounted)
+}
+
+#[tauri::command]
+pub async fn unmount_peer_share(state: State<'_, MountState>, id: String) -> Result<(), String> {
+    let share = state
+        .mounts
+        .get()
+        .into_iter()
+        .find(|m| m.id == id)
+        .ok_or_else(|| format!("mount '{}' not found", id))?;
+    tauri::async_runtime::spawn_blocking(move || unmount(&share))
+        .await
+        .map_err(|e| e.to_string())??;
+    state.mounts.update(|mounts| mounts.retain(|m| m.id != id))
+}
+
+#[tauri::command]
+pub async fn list_mounted_shares(state: State<'_, MountState>) -> Result<Vec<MountedShare>, String> {
+    Ok(state.mounts.get())
+}
+
+/// Remove credentials remembered for a share
+#[tauri::command]
+pub async fn forget_share_credentials(peer_id: String, share: String) -> Result<(), String> {
+    match keyring_entry(&peer_id, &share)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
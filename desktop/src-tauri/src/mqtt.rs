@@ -0,0 +1,352 @@
+// MQTT Publisher
+// Publishes connection state, online peer counts and transfer events to an MQTT
+// broker, with Home Assistant discovery topics so the entities appear on their own.
+//
+// Topics (node = sanitized host name unless configured):
+//   <base>/<node>/availability   "online" / "offline" (retained, last will)
+//   <base>/<node>/state          JSON connection state (retained)
+//   <base>/<node>/transfer       JSON per finished transfer
+//   <discovery>/<component>/<node>/<object>/config   Home Assistant discovery (retained)
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::DaemonStatus;
+use crate::store::JsonStore;
+use rumqttc::{AsyncClient, Event, EventLoop, LastWill, MqttOptions, Packet, QoS, Transport};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::Notify;
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "mqtt.json";
+
+/// Peers and transfers are polled this often while connected to the broker
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+const RECONNECT_DELAY: Duration = Duration::from_secs(10);
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+const REQUEST_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct MqttSettings {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+    pub tls: bool,
+    pub username: String,
+    pub password: String,
+    /// Topic root for state topics
+    pub base_topic: String,
+    /// Home Assistant discovery prefix; empty disables discovery
+    pub discovery_prefix: String,
+    /// Device id in topics; defaults to the host name
+    pub node_id: String,
+}
+
+impl Default for MqttSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: String::new(),
+            port: 1883,
+            tls: false,
+            username: String::new(),
+            password: String::new(),
+            base_topic: "goconnect".to_string(),
+            discovery_prefix: "homeassistant".to_string(),
+            node_id: String::new(),
+        }
+    }
+}
+
+impl MqttSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.enabled && self.host.trim().is_empty() {
+            return Err("MQTT broker host is required".to_string());
+        }
+        if self.port == 0 {
+            return Err("MQTT port must be between 1 and 65535".to_string());
+        }
+        let invalid = |topic: &str| topic.is_empty() || topic.contains(['+', '#']) || topic.ends_with('/');
+        if invalid(&self.base_topic) {
+            return Err("base topic must be non-empty without wildcards or a trailing '/'".to_string());
+        }
+        if !self.discovery_prefix.is_empty() && invalid(&self.discovery_prefix) {
+            return Err("discovery prefix cannot contain wildcards or a trailing '/'".to_string());
+        }
+        Ok(())
+    }
+
+    fn node(&self) -> String {
+        let raw = if self.node_id.trim().is_empty() { tauri_plugin_os::hostname() } else { self.node_id.clone() };
+        let node: String = raw
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if node.is_empty() {
+            "goconnect".to_string()
+        } else {
+            node
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct MqttStatus {
+    pub connected: bool,
+    pub last_error: Option<String>,
+}
+
+/// Managed state holding the persisted settings and the live broker status
+pub struct MqttState {
+    settings: JsonStore<MqttSettings>,
+    status: Mutex<MqttStatus>,
+    changed: Notify,
+}
+
+impl MqttState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            status: Mutex::new(MqttStatus::default()),
+            changed: Notify::new(),
+        }
+    }
+
+    fn set_status(&self, connected: bool, error: Option<String>) {
+        *self.status.lock().unwrap() = MqttStatus { connected, last_error: error };
+    }
+}
+
+struct Topics {
+    availability: String,
+    state: String,
+    transfer: String,
+}
+
+impl Topics {
+    fn new(settings: &MqttSettings, node: &str) -> Self {
+        let root = format!("{}/{}", settings.base_topic, node);
+        Self {
+            availability: format!("{}/availability", root),
+            state: format!("{}/state", root),
+            transfer: format!("{}/transfer", root),
+        }
+    }
+}
+
+/// Home Assistant discovery documents as (topic, payload)
+fn discovery(settings: &MqttSettings, node: &str, topics: &Topics) -> Vec<(String, String)> {
+    if settings.discovery_prefix.is_empty() {
+        return Vec::new();
+    }
+    let device = json!({
+        "identifiers": [format!("goconnect_{}", node)],
+        "name": format!("GoConnect ({})", node),
+        "manufacturer": "GoConnect",
+        "sw_version": env!("CARGO_PKG_VERSION"),
+    });
+    let entity = |component: &str, object: &str, config: serde_json::Value| {
+        let mut config = config;
+        config["unique_id"] = json!(format!("goconnect_{}_{}", node, object));
+        config["object_id"] = json!(format!("goconnect_{}_{}", node, object));
+        config["availability_topic"] = json!(topics.availability);
+        config["device"] = device.clone();
+        (
+            format!("{}/{}/{}/{}/config", settings.discovery_prefix, component, node, object),
+            config.to_string(),
+        )
+    };
+
+    vec![
+        entity("binary_sensor", "connected", json!({
+            "name": "VPN connected",
+            "device_class": "connectivity",
+            "state_topic": topics.state,
+            "value_template": "{{ 'ON' if value_json.connected else 'OFF' }}",
+            "json_attributes_topic": topics.state,
+        })),
+        entity("sensor", "network", json!({
+            "name": "Network",
+            "icon": "mdi:lan",
+            "state_topic": topics.state,
+            "value_template": "{{ value_json.network or 'none' }}",
+        })),
+        entity("sensor", "peers_online", json!({
+            "name": "Peers online",
+            "icon": "mdi:account-multiple",
+            "state_topic": topics.state,
+            "value_template": "{{ value_json.peers_online }}",
+            "state_class": "measurement",
+        })),
+        entity("sensor", "last_transfer", json!({
+            "name": "Last transfer",
+            "icon": "mdi:file-arrow-up-down",
+            "state_topic": topics.transfer,
+            "value_template": "{{ value_json.status }}",
+            "json_attributes_topic": topics.transfer,
+        })),
+    ]
+}
+
+fn state_payload(status: Option<&DaemonStatus>, peers_online: usize, peers_total: usize) -> String {
+    let connected = status.is_some_and(|s| s.connected);
+    json!({
+        "connected": connected,
+        "network": status.filter(|_| connected).map(|s| s.network_name.as_str()),
+        "virtual_ip": status.filter(|_| connected).map(|s| s.virtual_ip.as_str()),
+        "virtual_ipv4": status.filter(|_| connected).and_then(|s| s.virtual_ipv4.as_deref()),
+        "virtual_ipv6": status.filter(|_| connected).and_then(|s| s.virtual_ipv6.as_deref()),
+        "peers_online": peers_online,
+        "peers_total": peers_total,
+    })
+    .to_string()
+}
+
+fn connect(settings: &MqttSettings, node: &str, topics: &Topics) -> (AsyncClient, EventLoop) {
+    let mut options = MqttOptions::new(format!("goconnect-{}", node), settings.host.trim(), settings.port);
+    options.set_keep_alive(KEEP_ALIVE);
+    options.set_last_will(LastWill::new(&topics.availability, "offline", QoS::AtLeastOnce, true));
+    if !settings.username.is_empty() {
+        options.set_credentials(&settings.username, &settings.password);
+    }
+    if settings.tls {
+        options.set_transport(Transport::tls_with_default_config());
+    }
+    AsyncClient::new(options, REQUEST_CAPACITY)
+}
+
+/// Publish without awaiting channel space; the event loop is driven by the same task
+fn publish(client: &AsyncClient, topic: &str, retain: bool, payload: String) {
+    if let Err(e) = client.try_publish(topic, QoS::AtLeastOnce, retain, payload) {
+        log::debug!("Dropped MQTT publish to {}: {}", topic, e);
+    }
+}
+
+/// Run the broker session whenever MQTT is enabled, restarting it on settings changes
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<MqttState>();
+        loop {
+            let settings = state.settings.get();
+            if !settings.enabled {
+                state.set_status(false, None);
+                state.changed.notified().await;
+                continue;
+            }
+
+            tokio::select! {
+                error = run_session(&app, &settings) => {
+                    log::warn!("MQTT session to {} ended: {}", settings.host, error);
+                    state.set_status(false, Some(error));
+                    tokio::select! {
+                        _ = tokio::time::sleep(RECONNECT_DELAY) => {}
+                        _ = state.changed.notified() => {}
+                    }
+                }
+                _ = state.changed.notified() => {}
+            }
+        }
+    });
+}
+
+/// Drive one broker connection until it fails; returns the reason
+async fn run_session(app: &AppHandle, settings: &MqttSettings) -> String {
+    let state = app.state::<MqttState>();
+    let daemon = app.state::<DaemonState>();
+    let node = settings.node();
+    let topics = Topics::new(settings, &node);
+    let (client, mut eventloop) = connect(settings, &node, &topics);
+
+    let mut status_rx = daemon.subscribe_status();
+    let mut ticker = tokio::time::interval(POLL_INTERVAL);
+    let mut peers = (0, 0);
+    // `None` until the first poll, so existing transfers are not replayed
+    let mut transfers: Option<HashMap<String, String>> = None;
+
+    loop {
+        tokio::select! {
+            event = eventloop.poll() => match event {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                    state.set_status(true, None);
+                    for (topic, payload) in discovery(settings, &node, &topics) {
+                        publish(&client, &topic, true, payload);
+                    }
+                    publish(&client, &topics.availability, true, "online".to_string());
+                    publish(&client, &topics.state, true, state_payload(daemon.latest_status().as_ref(), peers.0, peers.1));
+                }
+                Ok(_) => {}
+                Err(e) => return e.to_string(),
+            },
+            changed = status_rx.changed() => {
+                if changed.is_err() {
+                    return "status stream closed".to_string();
+                }
+                let status = status_rx.borrow_and_update().clone();
+                publish(&client, &topics.state, true, state_payload(status.as_ref(), peers.0, peers.1));
+            }
+            _ = ticker.tick() => {
+                if !daemon.connection.state().is_usable() {
+                    continue;
+                }
+                if let Ok(list) = daemon_call!(daemon, "get_peers", |client| client.get_peers()) {
+                    let others: Vec<_> = list.into_iter().filter(|p| !p.is_self).collect();
+                    let next = (others.iter().filter(|p| p.connected).count(), others.len());
+                    if next != peers {
+                        peers = next;
+                        publish(&client, &topics.state, true, state_payload(daemon.latest_status().as_ref(), peers.0, peers.1));
+                    }
+                }
+                if let Ok(list) = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None)) {
+                    if let Some(previous) = &transfers {
+                        let finished = list.iter().filter(|t| {
+                            previous.get(&t.id) != Some(&t.status)
+                                && matches!(t.status.as_str(), "completed" | "failed" | "cancelled")
+                        });
+                        for t in finished {
+                            let payload = json!({
+                                "id": t.id,
+                                "peer_id": t.peer_id,
+                                "file": t.file_name,
+                                "bytes": t.transferred,
+                                "direction": t.direction,
+                                "status": t.status,
+                                "error": t.error,
+                            });
+                            publish(&client, &topics.transfer, false, payload.to_string());
+                        }
+                    }
+                    transfers = Some(list.into_iter().map(|t| (t.id, t.status)).collect());
+                }
+            }
+        }
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_mqtt_settings(state: State<'_, MqttState>) -> Result<MqttSettings, String> {
+    Ok(state.settings.get())
+}
+
+/// Save settings and reconnect with them
+#[tauri::command]
+pub async fn set_mqtt_settings(state: State<'_, MqttState>, settings: MqttSettings) -> Result<MqttSettings, String> {
+    settings.validate()?;
+    state.settings.set(settings.clone())?;
+    state.changed.notify_one();
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn get_mqtt_status(state: State<'_, MqttState>) -> Result<MqttStatus, String> {
+    Ok(state.status.lock().unwrap().clone())
+}
@@ -0,0 +1,338 @@
+// Notification Center
+// Every notification raised by the app is kept in a local history so missed
+// events can be reviewed from the in-app inbox. Notifications can carry buttons
+// that call back into the app: on Linux through the notification server, on
+// macOS as a drop-down on the notification and on Windows on the toast.
+
+use crate::accessibility::{self, Politeness};
+use crate::connection::unix_now;
+use crate::content_filter;
+#[cfg(target_os = "macos")]
+use crate::i18n::{self, Text};
+use crate::store::JsonStore;
+use crate::undo::{self, Reversal};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+use ts_rs::TS;
+
+/// Tauri event emitted with each new `NotificationRecord`
+pub const ADDED_EVENT: &str = "notifications://added";
+
+const HISTORY_FILE: &str = "notifications.json";
+
+/// Action id for a click on the notification itself, as most Linux notification servers send it
+pub(crate) const DEFAULT_ACTION: &str = "default";
+
+/// Oldest entries are dropped beyond this many
+const MAX_HISTORY: usize = 500;
+
+/// Mirrors `NotificationType` in the frontend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum NotificationKind {
+    Message,
+    Voice,
+    Transfer,
+    Member,
+    System,
+    Security,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct NotificationRecord {
+    #[ts(type = "number")]
+    pub id: u64,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub body: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub timestamp: u64,
+    pub read: bool,
+}
+
+#[derive(Default, Clone, serde::Serialize, serde::Deserialize)]
+struct History {
+    next_id: u64,
+    /// Oldest first
+    items: Vec<NotificationRecord>,
+}
+
+/// Managed state holding the persisted notification history
+pub struct NotificationCenter {
+    history: JsonStore<History>,
+}
+
+impl NotificationCenter {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { history: JsonStore::open(app, HISTORY_FILE) }
+    }
+
+    /// Append a notification to the history
+    pub fn record(&self, kind: NotificationKind, title: &str, body: &str) -> Result<NotificationRecord, String> {
+        self.history.update(|history| {
+            history.next_id += 1;
+            let record = NotificationRecord {
+                id: history.next_id,
+                kind,
+                title: title.to_string(),
+                body: body.to_string(),
+                timestamp: unix_now(),
+                read: false,
+            };
+            history.items.push(record.clone());
+            if history.items.len() > MAX_HISTORY {
+                let excess = history.items.len() - MAX_HISTORY;
+                history.items.drain(..excess);
+            }
+            record
+        })
+    }
+
+    /// Put cleared records back, alongside any that arrived since
+    pub(crate) fn restore(&self, items: Vec<NotificationRecord>) -> Result<(), String> {
+        self.history.update(|history| {
+            history.items.extend(items);
+            history.items.sort_by_key(|n| n.id);
+            history.items.dedup_by_key(|n| n.id);
+            let excess = history.items.len().saturating_sub(MAX_HISTORY);
+            history.items.drain(..excess);
+        })
+    }
+
+    /// Newest first
+    pub fn recent(&self, limit: usize) -> Vec<NotificationRecord> {
+        self.history.get().items.into_iter().rev().take(limit).collect()
+    }
+}
+
+/// Record a notification, tell the inbox about it, show it on the desktop and play its sound
+pub fn notify(app: &AppHandle, kind: NotificationKind, title: &str, body: &str) {
+    let body = &keep(app, kind, title, body);
+    show(app, title, body);
+    crate::sounds::play_for(app, kind);
+}
+
+/// Like `notify`, with buttons given as `(id, label)`; `on_action` gets the id of the one
+/// clicked, or `DEFAULT_ACTION` for the notification itself. Where buttons cannot be shown
+/// this is a plain notification and `on_action` never runs.
+pub(crate) fn notify_with_actions(
+    app: &AppHandle,
+    kind: NotificationKind,
+    title: &str,
+    body: &str,
+    actions: &[(&str, &str)],
+    on_action: impl FnOnce(&str) + Send + 'static,
+) {
+    let body = &keep(app, kind, title, body);
+    if let Err(e) = show_with_actions(app, title, body, actions, on_action) {
+        log::debug!("Showing a notification without buttons: {}", e);
+        show(app, title, body);
+    }
+    crate::sounds::play_for(app, kind);
+}
+
+/// Record a notification in the history and tell the inbox; returns the body as it may be shown
+fn keep(app: &AppHandle, kind: NotificationKind, title: &str, body: &str) -> String {
+    let body = content_filter::mask_preview(app, kind, None, body);
+    match app.state::<NotificationCenter>().record(kind, title, &body) {
+        Ok(record) => added(app, &record),
+        Err(e) => log::warn!("Failed to record notification: {}", e),
+    }
+    body
+}
+
+fn show(app: &AppHandle, title: &str, body: &str) {
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        log::warn!("Failed to show notification: {}", e);
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn show_with_actions(
+    app: &AppHandle,
+    title: &str,
+    body: &str,
+    actions: &[(&str, &str)],
+    on_action: impl FnOnce(&str) + Send + 'static,
+) -> Result<(), String> {
+    let mut notification = notify_rust::Notification::new();
+    notification.appname(&app.package_info().name).summary(title).body(body);
+    for (id, label) in actions {
+        notification.action(id, label);
+    }
+    let handle = notification.show().map_err(|e| e.to_string())?;
+    // Waiting blocks until the notification is clicked, dismissed or expires
+    std::thread::spawn(move || {
+        handle.wait_for_action(|action| {
+            if action != "__closed" {
+                on_action(action)
+            }
+        })
+    });
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn show_with_actions(
+    app: &AppHandle,
+    title: &str,
+    body: &str,
+    actions: &[(&str, &str)],
+    on_action: impl FnOnce(&str) + Send + 'static,
+) -> Result<(), String> {
+    use mac_notification_sys::{MainButton, Notification, NotificationResponse};
+
+    static APPLICATION: std::sync::Once = std::sync::Once::new();
+    APPLICATION.call_once(|| {
+        if let Err(e) = mac_notification_sys::set_application(&app.config().identifier) {
+            log::debug!("Failed to set the notifying application: {}", e);
+        }
+    });
+
+    let (title, body) = (title.to_string(), body.to_string());
+    // A click on the notification is the default action, so it needs no button
+    let buttons: Vec<(String, String)> = actions
+        .iter()
+        .filter(|(id, _)| *id != DEFAULT_ACTION)
+        .map(|(id, label)| (id.to_string(), label.to_string()))
+        .collect();
+    // Sending blocks until the notification is clicked, dismissed or expires
+    std::thread::spawn(move || {
+        let labels: Vec<&str> = buttons.iter().map(|(_, label)| label.as_str()).collect();
+        let response = Notification::new()
+            .title(&title)
+            .message(&body)
+            .main_button(MainButton::DropdownActions(i18n::text(Text::NotificationActions), &labels))
+            .wait_for_click(true)
+            .send();
+        match response {
+            Ok(NotificationResponse::Click) => on_action(DEFAULT_ACTION),
+            Ok(NotificationResponse::ActionButton(label)) => {
+                if let Some((id, _)) = buttons.iter().find(|(_, l)| *l == label) {
+                    on_action(id)
+                }
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("Failed to show notification: {}", e),
+        }
+    });
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn show_with_actions(
+    app: &AppHandle,
+    title: &str,
+    body: &str,
+    actions: &[(&str, &str)],
+    on_action: impl FnOnce(&str) + Send + 'static,
+) -> Result<(), String> {
+    use tauri_winrt_notification::Toast;
+
+    // Only installed builds have their identifier registered as an app ID
+    let app_id = if cfg!(debug_assertions) { Toast::POWERSHELL_APP_ID.to_string() } else { app.config().identifier.clone() };
+    let mut toast = Toast::new(&app_id).title(title).text1(body);
+    // A click on the toast is the default action, so it needs no button
+    for (id, label) in actions.iter().filter(|(id, _)| *id != DEFAULT_ACTION) {
+        toast = toast.add_button(label, id);
+    }
+    // A toast is activated at most once
+    let mut on_action = Some(on_action);
+    toast
+        .on_activated(move |action| {
+            if let Some(on_action) = on_action.take() {
+                on_action(action.as_deref().unwrap_or(DEFAULT_ACTION));
+            }
+            Ok(())
+        })
+        .show()
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn show_with_actions(
+    _app: &AppHandle,
+    _title: &str,
+    _body: &str,
+    _actions: &[(&str, &str)],
+    _on_action: impl FnOnce(&str) + Send + 'static,
+) -> Result<(), String> {
+    Err("notification buttons are not supported on this platform".to_string())
+}
+
+/// Publish a new record to the inbox and, for events worth interrupting for, to screen readers
+fn added(app: &AppHandle, record: &NotificationRecord) {
+    let _ = app.emit(ADDED_EVENT, record);
+    if matches!(record.kind, NotificationKind::Transfer | NotificationKind::System | NotificationKind::Security) {
+        let message = format!("{}: {}", record.title, record.body);
+        accessibility::announce(app, &message, Politeness::Polite);
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Newest first
+#[tauri::command]
+pub async fn list_notifications(
+    state: State<'_, NotificationCenter>,
+    unread_only: Option<bool>,
+) -> Result<Vec<NotificationRecord>, String> {
+    let unread_only = unread_only.unwrap_or(false);
+    Ok(state
+        .history
+        .get()
+        .items
+        .into_iter()
+        .rev()
+        .filter(|n| !unread_only || !n.read)
+        .collect())
+}
+
+/// Mark one notification as read, or all of them when `id` is omitted
+#[tauri::command]
+pub async fn mark_notification_read(state: State<'_, NotificationCenter>, id: Option<u64>) -> Result<(), String> {
+    let found = state.history.update(|history| {
+        let mut found = false;
+        for n in history.items.iter_mut().filter(|n| id.is_none_or(|id| n.id == id)) {
+            n.read = true;
+            found = true;
+        }
+        found
+    })?;
+    match id {
+        Some(id) if !found => Err(format!("notification {} not found", id)),
+        _ => Ok(()),
+    }
+}
+
+#[tauri::command]
+pub async fn clear_notifications(app: AppHandle, state: State<'_, NotificationCenter>) -> Result<(), String> {
+    let cleared = state.history.update(|history| std::mem::take(&mut history.items))?;
+    if !cleared.is_empty() {
+        let label = format!("Cleared {} notifications", cleared.len());
+        undo::record(&app, label, Reversal::Notifications(cleared));
+    }
+    Ok(())
+}
+
+/// Record a notification the frontend displays itself. Message previews come
+/// back masked by the content filter of `network_id`; show the returned body.
+#[tauri::command]
+pub async fn record_notification(
+    app: AppHandle,
+    state: State<'_, NotificationCenter>,
+    kind: NotificationKind,
+    title: String,
+    body: String,
+    network_id: Option<String>,
+) -> Result<NotificationRecord, String> {
+    let body = content_filter::mask_preview(&app, kind, network_id.as_deref(), &body);
+    let record = state.record(kind, &title, &body)?;
+    added(&app, &record);
+    Ok(record)
+}
@@ -0,0 +1,220 @@
+// Offline Queue
+// Messages and files sent while the daemon is unreachable are kept in a
+// persisted queue instead of failing, and replayed in order once the
+// connection is usable again (also after a restart of the app). Actions the
+// daemon rejects on replay stay in the queue as failed so the user can retry
+// or cancel them; nothing is dropped silently.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::remote_files;
+use crate::store::JsonStore;
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Tauri event emitted with the full queue whenever it changes
+pub const CHANGED_EVENT: &str = "queue://changed";
+
+const QUEUE_FILE: &str = "offline_queue.json";
+
+const MAX_ITEMS: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[ts(export)]
+pub enum QueuedAction {
+    SendMessage { network_id: String, content: String },
+    SendFile { peer_id: String, file_path: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum QueuedStatus {
+    /// Waiting for the daemon
+    Pending,
+    /// The daemon rejected it on replay; kept until retried or cancelled
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct QueuedItem {
+    pub id: String,
+    pub action: QueuedAction,
+    pub status: QueuedStatus,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub queued_at: u64,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+#[ts(export)]
+pub enum SendOutcome {
+    /// Delivered to the daemon; file sends carry the transfer id
+    Sent { transfer_id: Option<String> },
+    Queued { item: QueuedItem },
+}
+
+/// Managed state holding the persisted queue
+pub struct OfflineQueueState {
+    items: JsonStore<Vec<QueuedItem>>,
+    /// Only one replay runs at a time
+    replaying: tokio::sync::Mutex<()>,
+}
+
+impl OfflineQueueState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { items: JsonStore::open(app, QUEUE_FILE), replaying: tokio::sync::Mutex::new(()) }
+    }
+
+    fn update(&self, app: &AppHandle, f: impl FnOnce(&mut Vec<QueuedItem>)) -> Result<(), String> {
+        let items = self.items.update(|items| {
+            f(items);
+            items.clone()
+        })?;
+        let _ = app.emit(CHANGED_EVENT, items);
+        Ok(())
+    }
+}
+
+/// Whether failures mean the daemon is gone rather than that it said no
+fn offline(daemon: &DaemonState) -> bool {
+    !daemon.connection.state().is_daemon_running()
+}
+
+async fn execute(app: &AppHandle, action: &QueuedAction) -> Result<Option<String>, String> {
+    match action {
+        QueuedAction::SendMessage { network_id, content } => {
+            let daemon = app.state::<DaemonState>();
+            daemon_call!(daemon, "send_message", |client| client.send_message(network_id, content)).map(|_| None)
+        }
+        QueuedAction::SendFile { peer_id, file_path } => {
+            remote_files::send_file(app, peer_id, file_path).await.map(Some)
+        }
+    }
+}
+
+/// Try the action now; queue it when the daemon cannot be reached
+async fn send_or_queue(app: &AppHandle, action: QueuedAction) -> Result<SendOutcome, String> {
+    let daemon = app.state::<DaemonState>();
+    let error = match execute(app, &action).await {
+        Ok(transfer_id) => return Ok(SendOutcome::Sent { transfer_id }),
+        Err(e) if !offline(&daemon) => return Err(e),
+        Err(e) => e,
+    };
+    let state = app.state::<OfflineQueueState>();
+    if state.items.get().len() >= MAX_ITEMS {
+        return Err(format!("{} (the offline queue is full)", error));
+    }
+    let item = QueuedItem {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        action,
+        status: QueuedStatus::Pending,
+        queued_at: unix_now(),
+        attempts: 1,
+        last_error: Some(error),
+    };
+    state.update(app, |items| items.push(item.clone()))?;
+    log::info!("Daemon unreachable, queued {}", item.id);
+    Ok(SendOutcome::Queued { item })
+}
+
+/// Send pending items in order, stopping as soon as the daemon is gone again
+pub(crate) async fn replay(app: &AppHandle) {
+    let state = app.state::<OfflineQueueState>();
+    let daemon = app.state::<DaemonState>();
+    let _replaying = state.replaying.lock().await;
+    let pending: Vec<QueuedItem> =
+        state.items.get().into_iter().filter(|item| item.status == QueuedStatus::Pending).collect();
+    for item in pending {
+        // Cancelled while earlier items were being sent
+        if !state.items.get().iter().any(|i| i.id == item.id) {
+            continue;
+        }
+        let result = execute(app, &item.action).await;
+        if result.is_err() && offline(&daemon) {
+            log::info!("Daemon went away again, replay paused");
+            break;
+        }
+        let updated = state.update(app, |items| match result {
+            Ok(_) => items.retain(|i| i.id != item.id),
+            Err(e) => {
+                if let Some(i) = items.iter_mut().find(|i| i.id == item.id) {
+                    i.status = QueuedStatus::Failed;
+                    i.attempts += 1;
+                    i.last_error = Some(e);
+                }
+            }
+        });
+        if let Err(e) = updated {
+            log::warn!("Failed to update the offline queue: {}", e);
+        }
+    }
+}
+
+/// Replay whenever the connection becomes usable
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut connection_rx = app.state::<DaemonState>().connection.subscribe();
+        while connection_rx.changed().await.is_ok() {
+            let usable = connection_rx.borrow_and_update().state.is_usable();
+            if usable && !app.state::<OfflineQueueState>().items.get().is_empty() {
+                replay(&app).await;
+            }
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Like `daemon_send_message`, but queued when the daemon is unreachable
+#[tauri::command]
+pub async fn send_message_or_queue(app: AppHandle, network_id: String, content: String) -> Result<SendOutcome, String> {
+    send_or_queue(&app, QueuedAction::SendMessage { network_id, content }).await
+}
+
+/// Like `daemon_send_file`, but queued when the daemon is unreachable
+#[tauri::command]
+pub async fn send_file_or_queue(app: AppHandle, peer_id: String, file_path: String) -> Result<SendOutcome, String> {
+    send_or_queue(&app, QueuedAction::SendFile { peer_id, file_path }).await
+}
+
+/// Queued actions, oldest first
+#[tauri::command]
+pub async fn list_queued_actions(state: State<'_, OfflineQueueState>) -> Result<Vec<QueuedItem>, String> {
+    Ok(state.items.get())
+}
+
+#[tauri::command]
+pub async fn cancel_queued_action(
+    app: AppHandle,
+    state: State<'_, OfflineQueueState>,
+    id: String,
+) -> Result<(), String> {
+    if !state.items.get().iter().any(|item| item.id == id) {
+        return Err("no queued action with that id".to_string());
+    }
+    state.update(&app, |items| items.retain(|item| item.id != id))
+}
+
+/// Mark failed actions (one, or all when `id` is omitted) pending again and replay now
+#[tauri::command]
+pub async fn retry_queued_actions(
+    app: AppHandle,
+    state: State<'_, OfflineQueueState>,
+    id: Option<String>,
+) -> Result<(), String> {
+    state.update(&app, |items| {
+        for item in items.iter_mut().filter(|item| id.as_ref().is_none_or(|id| item.id == *id)) {
+            item.status = QueuedStatus::Pending;
+        }
+    })?;
+    replay(&app).await;
+    Ok(())
+}
@@ -0,0 +1,285 @@
+// Optimistic Updates
+// Changes the user expects to be instant, settings toggles and sending a chat
+// message, are applied to the Rust-held copy and announced before the daemon
+// call runs. When the daemon answers, its version replaces the optimistic one;
+// when it fails, the change is rolled back. Every mutation is announced twice,
+// `optimistic://pending` with the optimistic value and `optimistic://settled`
+// with the confirmed or restored one, so the UI can mark unconfirmed state.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::{ChatMessage, Settings};
+use crate::prefetch::PrefetchState;
+use crate::undo::{self, Reversal};
+use std::future::Future;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+pub const PENDING_EVENT: &str = "optimistic://pending";
+pub const SETTLED_EVENT: &str = "optimistic://settled";
+
+/// Prefix of ids given to messages the daemon has not confirmed yet
+const LOCAL_ID_PREFIX: &str = "local-";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum MutationKind {
+    Setting,
+    Message,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct Mutation {
+    pub id: String,
+    pub kind: MutationKind,
+    /// Setting name or network id
+    pub target: String,
+    /// The optimistic value
+    #[ts(type = "unknown")]
+    pub value: serde_json::Value,
+    #[ts(type = "number")]
+    pub started_at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct MutationSettled {
+    pub id: String,
+    pub kind: MutationKind,
+    pub target: String,
+    pub ok: bool,
+    /// The daemon's value, or the restored one after a failure
+    #[ts(type = "unknown")]
+    pub value: serde_json::Value,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SettingToggle {
+    AutoConnect,
+    StartMinimized,
+    NotificationsEnabled,
+    AutoAcceptFiles,
+}
+
+impl SettingToggle {
+    fn name(self) -> &'static str {
+        match self {
+            Self::AutoConnect => "auto_connect",
+            Self::StartMinimized => "start_minimized",
+            Self::NotificationsEnabled => "notifications_enabled",
+            Self::AutoAcceptFiles => "auto_accept_files",
+        }
+    }
+
+    fn get(self, settings: &Settings) -> bool {
+        match self {
+            Self::AutoConnect => settings.auto_connect,
+            Self::StartMinimized => settings.start_minimized,
+            Self::NotificationsEnabled => settings.notifications_enabled,
+            Self::AutoAcceptFiles => settings.auto_accept_files,
+        }
+    }
+
+    fn field(self, settings: &mut Settings) -> &mut bool {
+        match self {
+            Self::AutoConnect => &mut settings.auto_connect,
+            Self::StartMinimized => &mut settings.start_minimized,
+            Self::NotificationsEnabled => &mut settings.notifications_enabled,
+            Self::AutoAcceptFiles => &mut settings.auto_accept_files,
+        }
+    }
+}
+
+/// Managed state holding the Rust-side settings copy and in-flight mutations
+#[derive(Default)]
+pub struct OptimisticState {
+    settings: Mutex<Option<Settings>>,
+    pending: Mutex<Vec<Mutation>>,
+    /// Settings go to the daemon whole, so updates are sent one at a time
+    settings_writes: tokio::sync::Mutex<()>,
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or_default()
+}
+
+/// Apply a change locally and announce it, run `remote`, then let `settle`
+/// reconcile with the answer or roll back; `settle` returns the value to announce
+pub(crate) async fn mutate<T, V: serde::Serialize>(
+    app: &AppHandle,
+    kind: MutationKind,
+    target: &str,
+    optimistic: &V,
+    remote: impl Future<Output = Result<T, String>>,
+    settle: impl FnOnce(Result<&T, &String>) -> serde_json::Value,
+) -> Result<T, String> {
+    let state = app.state::<OptimisticState>();
+    let mutation = Mutation {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        kind,
+        target: target.to_string(),
+        value: to_value(optimistic),
+        started_at: unix_now(),
+    };
+    state.pending.lock().unwrap().push(mutation.clone());
+    let _ = app.emit(PENDING_EVENT, &mutation);
+
+    let result = remote.await;
+    let value = settle(result.as_ref());
+    state.pending.lock().unwrap().retain(|m| m.id != mutation.id);
+    if let Err(e) = &result {
+        log::info!("Rolled back {:?} change to {}: {}", kind, target, e);
+    }
+    let _ = app.emit(
+        SETTLED_EVENT,
+        MutationSettled {
+            id: mutation.id,
+            kind,
+            target: mutation.target,
+            ok: result.is_ok(),
+            value,
+            error: result.as_ref().err().cloned(),
+        },
+    );
+    result
+}
+
+pub(crate) async fn current_settings(state: &OptimisticState, daemon: &DaemonState) -> Result<Settings, String> {
+    if let Some(settings) = state.settings.lock().unwrap().clone() {
+        return Ok(settings);
+    }
+    let settings = daemon_call!(daemon, "get_settings", |client| client.get_settings())?;
+    Ok(state.settings.lock().unwrap().get_or_insert(settings).clone())
+}
+
+/// Take settings changed elsewhere as the held copy, unless a write from here is in flight;
+/// its answer brings the daemon's settings anyway
+pub(crate) fn adopt_settings(state: &OptimisticState, settings: &Settings) {
+    if let Ok(_write) = state.settings_writes.try_lock() {
+        *state.settings.lock().unwrap() = Some(settings.clone());
+    }
+}
+
+/// Change settings outside the toggles: sent in turn with them, and kept in the held copy
+/// so a later toggle does not send the old values back
+pub(crate) async fn update_settings(
+    state: &OptimisticState,
+    daemon: &DaemonState,
+    change: impl Fn(&mut Settings),
+) -> Result<Settings, String> {
+    let _write = state.settings_writes.lock().await;
+    let mut settings = current_settings(state, daemon).await?;
+    change(&mut settings);
+    let confirmed = daemon_call!(daemon, "update_settings", |client| client.update_settings(&settings))?;
+    if let Some(held) = state.settings.lock().unwrap().as_mut() {
+        change(held);
+    }
+    Ok(confirmed)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// The Rust-held settings, including changes the daemon has not confirmed yet
+#[tauri::command]
+pub async fn get_local_settings(
+    state: State<'_, OptimisticState>,
+    daemon: State<'_, DaemonState>,
+) -> Result<Settings, String> {
+    current_settings(&state, &daemon).await
+}
+
+/// Flip a setting right away; resolves with the daemon's settings once it confirmed
+#[tauri::command]
+pub async fn toggle_setting(
+    app: AppHandle,
+    state: State<'_, OptimisticState>,
+    daemon: State<'_, DaemonState>,
+    setting: SettingToggle,
+    enabled: bool,
+) -> Result<Settings, String> {
+    current_settings(&state, &daemon).await?;
+    let (previous, optimistic) = {
+        let mut guard = state.settings.lock().unwrap();
+        let settings = guard.as_mut().ok_or("settings are not loaded")?;
+        let previous = std::mem::replace(setting.field(settings), enabled);
+        (previous, settings.clone())
+    };
+
+    let remote = async {
+        let _write = state.settings_writes.lock().await;
+        // Whatever is held now, so toggles made in the meantime go along
+        let settings = state.settings.lock().unwrap().clone().unwrap_or(optimistic.clone());
+        daemon_call!(daemon, "update_settings", |client| client.update_settings(&settings))
+    };
+    let confirmed = mutate(&app, MutationKind::Setting, setting.name(), &optimistic, remote, |result| {
+        let mut guard = state.settings.lock().unwrap();
+        let Some(settings) = guard.as_mut() else { return serde_json::Value::Null };
+        match result {
+            // Only this field is taken from the answer; later toggles may still be in flight
+            Ok(confirmed) => *setting.field(settings) = setting.get(confirmed),
+            // Unless something changed it since, put the old value back
+            Err(_) if setting.get(settings) == enabled => *setting.field(settings) = previous,
+            Err(_) => {}
+        }
+        to_value(settings)
+    })
+    .await?;
+    if setting == SettingToggle::NotificationsEnabled && previous && !enabled {
+        undo::record(&app, "Muted notifications".to_string(), Reversal::Setting { setting, enabled: true });
+    }
+    Ok(confirmed)
+}
+
+/// Show a message in the chat cache immediately; it is replaced by the daemon's
+/// copy once sent, or removed again if sending failed
+#[tauri::command]
+pub async fn send_message_optimistic(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    prefetch: State<'_, PrefetchState>,
+    network_id: String,
+    content: String,
+) -> Result<ChatMessage, String> {
+    let local = ChatMessage {
+        id: format!("{}{}", LOCAL_ID_PREFIX, hex::encode(rand::random::<[u8; 8]>())),
+        peer_id: String::new(),
+        content: content.clone(),
+        timestamp: unix_now().to_string(),
+        is_self: true,
+    };
+    prefetch.upsert_message(&network_id, local.clone(), &local.id);
+
+    let remote = async {
+        let sent = daemon_call!(daemon, "send_message", |client| client.send_message(&network_id, &content))?;
+        // Older daemons do not echo the message back; keep ours, minus the local marker
+        let id = local.id.trim_start_matches(LOCAL_ID_PREFIX).to_string();
+        Ok(sent.unwrap_or_else(|| ChatMessage { id, ..local.clone() }))
+    };
+    mutate(&app, MutationKind::Message, &network_id, &local, remote, |result| match result {
+        Ok(sent) => {
+            let sent = ChatMessage { is_self: true, ..sent.clone() };
+            prefetch.upsert_message(&network_id, sent.clone(), &local.id);
+            to_value(&sent)
+        }
+        Err(_) => {
+            prefetch.remove_message(&network_id, &local.id);
+            to_value(&local)
+        }
+    })
+    .await
+    .map(|sent| ChatMessage { is_self: true, ..sent })
+}
+
+/// Mutations sent to the daemon and not answered yet
+#[tauri::command]
+pub async fn list_pending_mutations(state: State<'_, OptimisticState>) -> Result<Vec<Mutation>, String> {
+    Ok(state.pending.lock().unwrap().clone())
+}
@@ -0,0 +1,189 @@
+// Network Ownership Transfer
+// An owner can hand a network to another member before leaving, instead of
+// orphaning it. Handing off takes two calls: the first validates the new owner
+// and returns what will happen with a short-lived confirmation token, the
+// second carries the token and performs the transfer. Every completed transfer
+// is appended to a local audit log and announced as an event.
+
+use crate::admin_mode::AdminModeState;
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, State};
+use ts_rs::TS;
+
+/// Tauri event emitted with an `OwnershipTransferRecord` after a transfer
+pub const TRANSFERRED_EVENT: &str = "ownership://transferred";
+
+const AUDIT_FILE: &str = "ownership_audit.json";
+
+/// Oldest records are dropped beyond this many
+const MAX_RECORDS: usize = 200;
+
+/// How long a confirmation token stays valid
+const CONFIRM_TTL: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct OwnershipTransferPrompt {
+    /// Pass back as `confirm_token` to go ahead
+    pub confirm_token: String,
+    pub network_id: String,
+    pub network_name: String,
+    pub new_owner_id: String,
+    pub new_owner_name: String,
+    /// The new owner is not connected right now; the transfer still works
+    pub new_owner_offline: bool,
+    /// Unix timestamp (seconds) the token expires at
+    #[ts(type = "number")]
+    pub expires_at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct OwnershipTransferRecord {
+    pub network_id: String,
+    pub network_name: String,
+    pub previous_owner_id: String,
+    pub new_owner_id: String,
+    pub new_owner_name: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+#[ts(export)]
+pub enum OwnershipOutcome {
+    /// Nothing was done yet; confirm with the prompt's token
+    NeedsConfirmation { prompt: OwnershipTransferPrompt },
+    Transferred { record: OwnershipTransferRecord },
+}
+
+struct PendingTransfer {
+    network_id: String,
+    new_owner_id: String,
+    expires: Instant,
+}
+
+/// Managed state holding the audit log and unconfirmed transfers
+pub struct OwnershipState {
+    audit: JsonStore<Vec<OwnershipTransferRecord>>,
+    pending: Mutex<HashMap<String, PendingTransfer>>,
+}
+
+impl OwnershipState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { audit: JsonStore::open(app, AUDIT_FILE), pending: Mutex::new(HashMap::new()) }
+    }
+
+    /// Whether `token` was issued for this very transfer and is still valid; used up either way
+    fn redeem(&self, token: &str, network_id: &str, new_owner_id: &str) -> bool {
+        let mut pending = self.pending.lock().unwrap();
+        pending.retain(|_, p| p.expires > Instant::now());
+        pending.remove(token).is_some_and(|p| p.network_id == network_id && p.new_owner_id == new_owner_id)
+    }
+}
+
+/// Check that we own the network and the new owner is another member; also
+/// returns whether the new owner is connected
+async fn validate(
+    daemon: &DaemonState,
+    network_id: &str,
+    new_owner_id: &str,
+) -> Result<(OwnershipTransferRecord, bool), String> {
+    let networks = daemon_call!(daemon, "list_networks", |client| client.list_networks())?;
+    let network = networks.into_iter().find(|n| n.id == network_id).ok_or("you are not a member of that network")?;
+    let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(network_id))?;
+    let me = peers.iter().find(|p| p.is_self).ok_or("could not find yourself in the network")?;
+    if !me.role.eq_ignore_ascii_case("owner") {
+        return Err("only the owner can hand off a network".to_string());
+    }
+    let target =
+        peers.iter().find(|p| p.id == new_owner_id).ok_or("the new owner must be a member of the network")?;
+    if target.is_self {
+        return Err("you already own this network".to_string());
+    }
+    let name = if target.display_name.is_empty() { &target.name } else { &target.display_name };
+    let record = OwnershipTransferRecord {
+        network_id: network.id,
+        network_name: network.name,
+        previous_owner_id: me.id.clone(),
+        new_owner_id: target.id.clone(),
+        new_owner_name: name.clone(),
+        at: unix_now(),
+    };
+    Ok((record, target.connected))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Hand a network to another member. Without `confirm_token` this only checks
+/// and returns a prompt; call again with its token to transfer. Needs admin mode.
+#[tauri::command]
+pub async fn transfer_network_ownership(
+    app: AppHandle,
+    state: State<'_, OwnershipState>,
+    daemon: State<'_, DaemonState>,
+    admin: State<'_, AdminModeState>,
+    network_id: String,
+    new_owner_peer_id: String,
+    confirm_token: Option<String>,
+) -> Result<OwnershipOutcome, String> {
+    admin.require()?;
+    let (record, online) = validate(&daemon, &network_id, &new_owner_peer_id).await?;
+
+    let Some(token) = confirm_token else {
+        let confirm_token = hex::encode(rand::random::<[u8; 16]>());
+        let pending = PendingTransfer {
+            network_id: network_id.clone(),
+            new_owner_id: new_owner_peer_id.clone(),
+            expires: Instant::now() + CONFIRM_TTL,
+        };
+        state.pending.lock().unwrap().insert(confirm_token.clone(), pending);
+        let prompt = OwnershipTransferPrompt {
+            confirm_token,
+            network_id,
+            network_name: record.network_name,
+            new_owner_id: record.new_owner_id,
+            new_owner_name: record.new_owner_name,
+            new_owner_offline: !online,
+            expires_at: unix_now() + CONFIRM_TTL.as_secs(),
+        };
+        return Ok(OwnershipOutcome::NeedsConfirmation { prompt });
+    };
+    if !state.redeem(&token, &network_id, &new_owner_peer_id) {
+        return Err("the confirmation has expired or does not match; start over".to_string());
+    }
+
+    daemon_call!(daemon, "transfer_ownership", |client| {
+        client.transfer_ownership(&network_id, &new_owner_peer_id)
+    })?;
+    log::info!("Handed network {} to {}", record.network_name, record.new_owner_name);
+    let record = OwnershipTransferRecord { at: unix_now(), ..record };
+    if let Err(e) = state.audit.update(|audit| {
+        audit.push(record.clone());
+        let excess = audit.len().saturating_sub(MAX_RECORDS);
+        audit.drain(..excess);
+    }) {
+        log::warn!("Failed to write the ownership audit log: {}", e);
+    }
+    let _ = app.emit(TRANSFERRED_EVENT, &record);
+    Ok(OwnershipOutcome::Transferred { record })
+}
+
+/// Ownership transfers made from this device, newest first
+#[tauri::command]
+pub async fn list_ownership_transfers(
+    state: State<'_, OwnershipState>,
+    network_id: Option<String>,
+) -> Result<Vec<OwnershipTransferRecord>, String> {
+    let audit = state.audit.get();
+    Ok(audit.into_iter().rev().filter(|r| network_id.as_ref().is_none_or(|id| r.network_id == *id)).collect())
+}
@@ -0,0 +1,186 @@
+// Partial Downloads
+// Accepted files are written to `<name>.part` next to where they belong, so a
+// half-received or corrupted file never sits under its real name. Once the
+// daemon reports the download complete it is checked against the sender's hash
+// (when there is one) and renamed into place in one step; failed and cancelled
+// downloads have their `.part` file removed. Pending downloads are kept on disk
+// so this survives restarts: at startup, downloads that finished meanwhile are
+// put in place and the `.part` files of those that did not are cleaned up.
+
+use crate::activity::ActivityState;
+use crate::checksums;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::TransferInfo;
+use crate::reputation;
+use crate::save_paths;
+use crate::store::JsonStore;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+const PARTS_FILE: &str = "part_files.json";
+
+const PART_EXTENSION: &str = ".part";
+
+/// How often startup cleanup checks whether the daemon is reachable yet
+const STARTUP_RETRY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct PartFile {
+    transfer_id: String,
+    part_path: String,
+    final_path: String,
+    /// An existing file at `final_path` is to be replaced rather than kept
+    replace: bool,
+    /// Did not match the sender's hash; left for cleanup
+    #[serde(default)]
+    corrupt: bool,
+}
+
+/// Managed state holding the downloads not put in place yet
+pub struct PartFilesState {
+    parts: JsonStore<Vec<PartFile>>,
+}
+
+impl PartFilesState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { parts: JsonStore::open(app, PARTS_FILE) }
+    }
+
+    /// Fill in `TransferInfo.part_path` for downloads still in their temporary file
+    pub(crate) fn annotate(&self, transfers: &mut [TransferInfo]) {
+        let parts = self.parts.get();
+        for transfer in transfers {
+            transfer.part_path = parts.iter().find(|p| p.transfer_id == transfer.id).map(|p| p.part_path.clone());
+        }
+    }
+
+    fn put(&self, part: PartFile) {
+        let result = self.parts.update(|parts| {
+            parts.retain(|p| p.transfer_id != part.transfer_id);
+            parts.push(part);
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record partial download: {}", e);
+        }
+    }
+
+    /// Stop tracking a download, handing back its record so only one caller finishes it
+    fn take(&self, transfer_id: &str) -> Option<PartFile> {
+        self.parts
+            .update(|parts| {
+                let index = parts.iter().position(|p| p.transfer_id == transfer_id)?;
+                Some(parts.remove(index))
+            })
+            .ok()
+            .flatten()
+    }
+}
+
+/// The temporary file a download into `target` is written to
+pub(crate) fn part_path(target: &Path) -> PathBuf {
+    let mut part = target.as_os_str().to_owned();
+    part.push(PART_EXTENSION);
+    PathBuf::from(part)
+}
+
+/// Note that `transfer_id` is being written to `part`, to be renamed to `target` when done
+pub(crate) fn track(app: &AppHandle, transfer_id: &str, part: &Path, target: &Path, replace: bool) {
+    app.state::<PartFilesState>().put(PartFile {
+        transfer_id: transfer_id.to_string(),
+        part_path: part.to_string_lossy().into_owned(),
+        final_path: target.to_string_lossy().into_owned(),
+        replace,
+        corrupt: false,
+    });
+}
+
+/// The download is still in its temporary file
+pub(crate) fn is_partial(app: &AppHandle, transfer_id: &str) -> bool {
+    app.try_state::<PartFilesState>().is_some_and(|s| s.parts.get().iter().any(|p| p.transfer_id == transfer_id))
+}
+
+fn discard(part: &PartFile) {
+    match std::fs::remove_file(&part.part_path) {
+        Ok(()) => log::info!("Removed partial download {}", part.part_path),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => log::warn!("Failed to remove partial download {}: {}", part.part_path, e),
+    }
+}
+
+/// Verify a completed download and rename it into place
+async fn finalize(app: &AppHandle, transfer: &TransferInfo, mut part: PartFile) -> Result<(), String> {
+    if transfer.sha256.is_some() {
+        let result = checksums::verify_file(app, transfer, &part.part_path, part.final_path.clone()).await?;
+        if !result.verified {
+            // Never put in place; its `.part` file goes at the next cleanup
+            part.corrupt = true;
+            app.state::<PartFilesState>().put(part);
+            return Ok(());
+        }
+    }
+    let target = Path::new(&part.final_path);
+    // Something may have taken the name since the transfer was accepted
+    let target = if part.replace { target.to_path_buf() } else { save_paths::unique_path(target) };
+    std::fs::rename(&part.part_path, &target)
+        .map_err(|e| format!("failed to move {} into place: {}", part.part_path, e))?;
+    let target = target.to_string_lossy().into_owned();
+    if target != part.final_path {
+        app.state::<ActivityState>().remember_path(&transfer.id, &target);
+    }
+    log::info!("Download {} saved as {}", transfer.id, target);
+    reputation::on_download_saved(app, transfer, &target);
+    Ok(())
+}
+
+fn settle(app: &AppHandle, transfer: &TransferInfo) {
+    if !matches!(transfer.status.as_str(), "completed" | "failed" | "cancelled") {
+        return;
+    }
+    let Some(part) = app.state::<PartFilesState>().take(&transfer.id) else { return };
+    if transfer.status != "completed" || part.corrupt {
+        discard(&part);
+        return;
+    }
+    let (app, transfer) = (app.clone(), transfer.clone());
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = finalize(&app, &transfer, part).await {
+            log::warn!("Failed to finish download {}: {}", transfer.id, e);
+        }
+    });
+}
+
+/// Put a download in place, or clean it up, once it has finished
+pub(crate) fn on_transfer_update(app: &AppHandle, transfer: &TransferInfo) {
+    if transfer.direction == "download" && app.try_state::<PartFilesState>().is_some() {
+        settle(app, transfer);
+    }
+}
+
+/// Settle the downloads left from the last run once the daemon can say how they ended
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<PartFilesState>();
+        if state.parts.get().is_empty() {
+            return;
+        }
+        let daemon = app.state::<DaemonState>();
+        let transfers = loop {
+            match daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None)) {
+                Ok(transfers) => break transfers,
+                Err(_) => tokio::time::sleep(STARTUP_RETRY).await,
+            }
+        };
+        for part in state.parts.get() {
+            match transfers.iter().find(|t| t.id == part.transfer_id) {
+                Some(transfer) => settle(&app, transfer),
+                // Unknown to the daemon, so nothing will ever finish it
+                None => {
+                    if let Some(part) = state.take(&part.transfer_id) {
+                        discard(&part);
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,515 @@
+// Peer Search Index
+// Networks can have thousands of members; filtering the full list in the UI on
+// every keystroke is slow and ships the whole list over IPC each time. The Rust
+// side keeps one snapshot per network instead, fetched on first use, patched
+// from the daemon's peer join/leave events and fetched again once it is older
+// than REFRESH_AFTER. A sorted token map over names, display names, addresses
+// and local tags answers prefix queries without walking the member list.
+// The same snapshot serves windows of the sorted list for virtual scrolling,
+// so the UI only receives the rows it renders. Joins and leaves also go to the
+// UI as `peers://changed` diffs, batched by `throttle`.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::{NetworkStatus, PeerChange, PeerInfo};
+use crate::memory::{self, CacheEntry};
+use crate::store::JsonStore;
+use crate::throttle;
+use std::collections::{BTreeMap, HashMap};
+use std::net::IpAddr;
+use std::ops::Bound;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+/// Tauri event emitted with a `PeerDiff` as members join and leave
+pub const CHANGED_EVENT: &str = "peers://changed";
+
+const TAGS_FILE: &str = "peer_tags.json";
+
+/// Events can be missed while the stream is down; snapshots are re-fetched after this anyway
+const REFRESH_AFTER: Duration = Duration::from_secs(300);
+
+/// Wait before subscribing again after the event stream ends
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(10);
+
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 500;
+const MAX_TAGS: usize = 20;
+const MAX_TAG_LEN: usize = 32;
+
+/// Most rows one `get_peers_window` call returns
+const MAX_WINDOW: usize = 1000;
+
+static REVISION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum MatchField {
+    Name,
+    DisplayName,
+    Address,
+    Tag,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerMatch {
+    pub peer: PeerInfo,
+    pub tags: Vec<String>,
+    /// Fields the query matched
+    pub matched: Vec<MatchField>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerSearchResult {
+    pub network_id: String,
+    pub matches: Vec<PeerMatch>,
+    /// Matches before `limit`
+    pub total: u32,
+    /// Lookup time, excluding any snapshot fetch
+    #[ts(type = "number")]
+    pub took_us: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PeerSort {
+    #[default]
+    Name,
+    /// Online first, then by name
+    Status,
+    /// Lowest latency first; unknown latency last
+    Latency,
+    /// Owners, then admins, then members
+    Role,
+    Address,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerWindow {
+    pub network_id: String,
+    pub offset: u32,
+    /// Members in the whole list
+    pub total: u32,
+    pub peers: Vec<PeerInfo>,
+    /// Changes whenever the member list does; rows cached under an older
+    /// revision should be fetched again
+    #[ts(type = "number")]
+    pub revision: u64,
+}
+
+/// Membership changes since the last `peers://changed` event
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerDiff {
+    /// Events are about the current network; `None` when there is none
+    pub network_id: Option<String>,
+    /// Members that joined or changed, in their latest state
+    pub joined: Vec<PeerInfo>,
+    /// Ids of members that left
+    pub left: Vec<String>,
+}
+
+impl PeerDiff {
+    /// Fold a change in; a later change to the same peer replaces the earlier one
+    pub fn merge(&mut self, change: PeerChange) {
+        self.joined.retain(|p| p.id != change.peer.id);
+        self.left.retain(|id| *id != change.peer.id);
+        if change.joined {
+            self.joined.push(change.peer);
+        } else {
+            self.left.push(change.peer.id);
+        }
+    }
+}
+
+/// Indexed members of one network
+pub(crate) struct Snapshot {
+    pub peers: HashMap<String, PeerInfo>,
+    /// token -> (peer id, field)
+    tokens: BTreeMap<String, Vec<(String, MatchField)>>,
+    fetched: Instant,
+    used: Instant,
+    revision: u64,
+    /// Peer ids in the last requested order, dropped on any change
+    sorted: Option<(PeerSort, bool, Vec<String>)>,
+}
+
+/// Lower-cased search tokens of one peer: whole values plus their words
+fn tokenize(peer: &PeerInfo, tags: &[String]) -> Vec<(String, MatchField)> {
+    let mut tokens = Vec::new();
+    let mut words = |value: &str, field: MatchField| {
+        let value = value.trim().to_lowercase();
+        if value.is_empty() {
+            return;
+        }
+        for word in value.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty() && *w != value) {
+            tokens.push((word.to_string(), field));
+        }
+        tokens.push((value, field));
+    };
+    words(&peer.name, MatchField::Name);
+    words(&peer.display_name, MatchField::DisplayName);
+    for tag in tags {
+        words(tag, MatchField::Tag);
+    }
+    // Addresses match by prefix as a whole ("100.64.1" or "fd7a:")
+    for ip in [&peer.virtual_ipv4, &peer.virtual_ipv6].into_iter().flatten() {
+        tokens.push((ip.to_lowercase(), MatchField::Address));
+    }
+    tokens.sort();
+    tokens.dedup();
+    tokens
+}
+
+impl Snapshot {
+    fn build(peers: Vec<PeerInfo>, tags: &HashMap<String, Vec<String>>) -> Self {
+        let mut snapshot = Self {
+            peers: HashMap::new(),
+            tokens: BTreeMap::new(),
+            fetched: Instant::now(),
+            used: Instant::now(),
+            revision: 0,
+            sorted: None,
+        };
+        for peer in peers {
+            snapshot.insert(peer, tags);
+        }
+        snapshot
+    }
+
+    fn insert(&mut self, peer: PeerInfo, tags: &HashMap<String, Vec<String>>) {
+        self.remove(&peer.id, tags);
+        for (token, field) in tokenize(&peer, tags.get(&peer.id).map(Vec::as_slice).unwrap_or_default()) {
+            self.tokens.entry(token).or_default().push((peer.id.clone(), field));
+        }
+        self.peers.insert(peer.id.clone(), peer);
+        self.changed();
+    }
+
+    fn remove(&mut self, id: &str, tags: &HashMap<String, Vec<String>>) {
+        let Some(peer) = self.peers.remove(id) else { return };
+        for (token, _) in tokenize(&peer, tags.get(id).map(Vec::as_slice).unwrap_or_default()) {
+            if let Some(entries) = self.tokens.get_mut(&token) {
+                entries.retain(|(peer_id, _)| peer_id != id);
+                if entries.is_empty() {
+                    self.tokens.remove(&token);
+                }
+            }
+        }
+        self.changed();
+    }
+
+    fn changed(&mut self) {
+        // Process-wide, so a re-fetched snapshot never repeats an old revision
+        self.revision = REVISION.fetch_add(1, Ordering::Relaxed) + 1;
+        self.sorted = None;
+    }
+
+    /// Peer ids in `sort` order, computed once per order and revision
+    fn order(&mut self, sort: PeerSort, descending: bool) -> &[String] {
+        if !matches!(&self.sorted, Some((s, d, _)) if *s == sort && *d == descending) {
+            let mut peers: Vec<&PeerInfo> = self.peers.values().collect();
+            let name = |p: &PeerInfo| p.name.to_lowercase();
+            match sort {
+                PeerSort::Name => peers.sort_by_cached_key(|p| name(p)),
+                PeerSort::Status => peers.sort_by_cached_key(|p| (!p.connected, name(p))),
+                // The daemon reports 0 or less when it has no measurement
+                PeerSort::Latency => peers.sort_by_cached_key(|p| (p.latency_ms <= 0, p.latency_ms, name(p))),
+                PeerSort::Role => peers.sort_by_cached_key(|p| {
+                    let rank = match p.role.as_str() {
+                        "owner" => 0,
+                        "admin" => 1,
+                        "member" => 2,
+                        _ => 3,
+                    };
+                    (rank, name(p))
+                }),
+                PeerSort::Address => peers.sort_by_cached_key(|p| (p.virtual_ip.parse::<IpAddr>().ok(), name(p))),
+            }
+            if descending {
+                peers.reverse();
+            }
+            let ids = peers.into_iter().map(|p| p.id.clone()).collect();
+            self.sorted = Some((sort, descending, ids));
+        }
+        self.sorted.as_ref().map(|(_, _, ids)| ids.as_slice()).unwrap_or_default()
+    }
+
+    fn stale(&self) -> bool {
+        self.fetched.elapsed() > REFRESH_AFTER
+    }
+
+    /// Members plus the token index
+    fn approx_bytes(&self) -> u64 {
+        let tokens: usize = self
+            .tokens
+            .iter()
+            .map(|(token, ids)| token.len() + ids.iter().map(|(id, _)| id.len() + 8).sum::<usize>())
+            .sum();
+        memory::approx_size(&self.peers) + tokens as u64
+    }
+
+    /// Peers matching every term by token prefix, best first
+    fn search(&self, query: &str) -> Vec<(&PeerInfo, Vec<MatchField>)> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        // id -> (rank, fields); rank 0 is an exact token match
+        let mut hits: Option<HashMap<&str, (u8, Vec<MatchField>)>> = None;
+        for term in &terms {
+            let mut found: HashMap<&str, (u8, Vec<MatchField>)> = HashMap::new();
+            let prefixed = self
+                .tokens
+                .range::<str, _>((Bound::Included(term.as_str()), Bound::Unbounded))
+                .take_while(|(t, _)| t.starts_with(term.as_str()));
+            for (token, entries) in prefixed {
+                let rank = u8::from(token != term);
+                for (id, field) in entries {
+                    let hit = found.entry(id.as_str()).or_insert((rank, Vec::new()));
+                    hit.0 = hit.0.min(rank);
+                    if !hit.1.contains(field) {
+                        hit.1.push(*field);
+                    }
+                }
+            }
+            hits = Some(match hits {
+                None => found,
+                Some(previous) => previous
+                    .into_iter()
+                    .filter_map(|(id, (rank, mut fields))| {
+                        let (other_rank, other_fields) = found.remove(id)?;
+                        for field in other_fields {
+                            if !fields.contains(&field) {
+                                fields.push(field);
+                            }
+                        }
+                        Some((id, (rank.max(other_rank), fields)))
+                    })
+                    .collect(),
+            });
+        }
+
+        let mut results: Vec<(u8, &PeerInfo, Vec<MatchField>)> = match hits {
+            Some(hits) => hits
+                .into_iter()
+                .filter_map(|(id, (rank, mut fields))| {
+                    fields.sort();
+                    Some((rank, self.peers.get(id)?, fields))
+                })
+                .collect(),
+            None => self.peers.values().map(|p| (0, p, Vec::new())).collect(),
+        };
+        results.sort_by_cached_key(|(rank, peer, _)| (*rank, !peer.connected, peer.name.to_lowercase()));
+        results.into_iter().map(|(_, peer, fields)| (peer, fields)).collect()
+    }
+}
+
+/// Managed state holding the per-network snapshots and local peer tags
+pub struct PeerIndexState {
+    tags: JsonStore<HashMap<String, Vec<String>>>,
+    networks: Mutex<HashMap<String, Snapshot>>,
+}
+
+impl PeerIndexState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { tags: JsonStore::open(app, TAGS_FILE), networks: Mutex::new(HashMap::new()) }
+    }
+
+    /// Run `f` on a fresh snapshot of `network_id` (`None` for the current network),
+    /// fetching it first when missing or stale. Returns the resolved network id.
+    pub(crate) async fn with_snapshot<T>(
+        &self,
+        daemon: &DaemonState,
+        network_id: Option<String>,
+        f: impl FnOnce(&mut Snapshot) -> T,
+    ) -> Result<(String, T), String> {
+        let network_id = match network_id.filter(|id| !id.is_empty()) {
+            Some(id) => id,
+            None => current_network(daemon).await.ok_or("no network is selected")?,
+        };
+        let fresh = self.networks.lock().unwrap().get(&network_id).is_some_and(|s| !s.stale());
+        if !fresh {
+            let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(&network_id))?;
+            let snapshot = Snapshot::build(peers, &self.tags.get());
+            self.networks.lock().unwrap().insert(network_id.clone(), snapshot);
+        }
+        let mut networks = self.networks.lock().unwrap();
+        let snapshot = networks.get_mut(&network_id).ok_or("the network snapshot was dropped")?;
+        snapshot.used = Instant::now();
+        Ok((network_id, f(snapshot)))
+    }
+
+    pub(crate) fn cache_entries(&self) -> Vec<CacheEntry> {
+        let networks = self.networks.lock().unwrap();
+        networks
+            .iter()
+            .map(|(id, snapshot)| CacheEntry { key: id.clone(), bytes: snapshot.approx_bytes(), used: snapshot.used })
+            .collect()
+    }
+
+    pub(crate) fn evict(&self, network_ids: &[String]) {
+        self.networks.lock().unwrap().retain(|id, _| !network_ids.contains(id));
+    }
+
+    fn apply(&self, current: Option<&str>, change: PeerChange) {
+        let tags = self.tags.get();
+        let mut networks = self.networks.lock().unwrap();
+        // Events carry no network; they are about the current one
+        if let Some(snapshot) = current.and_then(|id| networks.get_mut(id)) {
+            if change.joined {
+                snapshot.insert(change.peer.clone(), &tags);
+            } else {
+                snapshot.remove(&change.peer.id, &tags);
+            }
+        }
+        // Keep the same peer up to date where it is a member elsewhere
+        for (id, snapshot) in networks.iter_mut() {
+            if Some(id.as_str()) != current && change.joined && snapshot.peers.contains_key(&change.peer.id) {
+                snapshot.insert(change.peer.clone(), &tags);
+            }
+        }
+    }
+}
+
+pub(crate) async fn current_network(daemon: &DaemonState) -> Option<String> {
+    let find = |networks: Vec<NetworkStatus>| networks.into_iter().find(|n| n.is_current).map(|n| n.network_id);
+    match find(daemon.latest_networks()) {
+        Some(id) => Some(id),
+        None => find(daemon.probe_networks().await.ok()?),
+    }
+}
+
+/// Follow the daemon's peer events for the life of the app
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        let index = app.state::<PeerIndexState>();
+        loop {
+            let subscribed = daemon_call!(daemon, "subscribe_peer_events", |client| client.subscribe_peer_events());
+            if let Ok(mut events) = subscribed {
+                while let Ok(Some(change)) = events.next().await {
+                    let current = current_network(&daemon).await;
+                    index.apply(current.as_deref(), change.clone());
+                    throttle::offer_peer_change(&app, current, change);
+                }
+                // Whatever happened while the stream was down is unknown
+                index.networks.lock().unwrap().clear();
+            }
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Find members by name, display name, address or tag prefix. Every word of the
+/// query must match; an empty query lists everyone.
+#[tauri::command]
+pub async fn search_peers(
+    state: State<'_, PeerIndexState>,
+    daemon: State<'_, DaemonState>,
+    network_id: Option<String>,
+    query: String,
+    limit: Option<u32>,
+) -> Result<PeerSearchResult, String> {
+    let limit = limit.map(|l| l as usize).unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let tags = state.tags.get();
+    let (network_id, (matches, total, took_us)) = state
+        .with_snapshot(&daemon, network_id, |snapshot| {
+            let started = Instant::now();
+            let found = snapshot.search(&query);
+            let total = found.len() as u32;
+            let matches: Vec<PeerMatch> = found
+                .into_iter()
+                .take(limit)
+                .map(|(peer, matched)| PeerMatch {
+                    tags: tags.get(&peer.id).cloned().unwrap_or_default(),
+                    peer: peer.clone(),
+                    matched,
+                })
+                .collect();
+            (matches, total, started.elapsed().as_micros() as u64)
+        })
+        .await?;
+    Ok(PeerSearchResult { network_id, matches, total, took_us })
+}
+
+/// One window of the member list in `sort` order, for virtual scrolling
+#[tauri::command]
+pub async fn get_peers_window(
+    state: State<'_, PeerIndexState>,
+    daemon: State<'_, DaemonState>,
+    network_id: Option<String>,
+    offset: u32,
+    count: u32,
+    sort: Option<PeerSort>,
+    descending: Option<bool>,
+) -> Result<PeerWindow, String> {
+    let count = (count as usize).min(MAX_WINDOW);
+    let (network_id, (total, peers, revision)) = state
+        .with_snapshot(&daemon, network_id, |snapshot| {
+            let ids: Vec<String> = snapshot
+                .order(sort.unwrap_or_default(), descending.unwrap_or(false))
+                .iter()
+                .skip(offset as usize)
+                .take(count)
+                .cloned()
+                .collect();
+            let peers = ids.iter().filter_map(|id| snapshot.peers.get(id).cloned()).collect();
+            (snapshot.peers.len() as u32, peers, snapshot.revision)
+        })
+        .await?;
+    Ok(PeerWindow { network_id, offset, total, peers, revision })
+}
+
+#[tauri::command]
+pub async fn get_peer_tags(state: State<'_, PeerIndexState>, peer_id: String) -> Result<Vec<String>, String> {
+    Ok(state.tags.get().remove(&peer_id).unwrap_or_default())
+}
+
+/// Replace a peer's local tags; they are searchable in every network the peer is in
+#[tauri::command]
+pub async fn set_peer_tags(
+    state: State<'_, PeerIndexState>,
+    peer_id: String,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for tag in tags.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        if tag.chars().count() > MAX_TAG_LEN {
+            return Err(format!("tag '{}' is longer than {} characters", tag, MAX_TAG_LEN));
+        }
+        if !cleaned.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            cleaned.push(tag.to_string());
+        }
+    }
+    if cleaned.len() > MAX_TAGS {
+        return Err(format!("a peer can have at most {} tags", MAX_TAGS));
+    }
+
+    let previous = state.tags.get();
+    let mut all = previous.clone();
+    if cleaned.is_empty() {
+        all.remove(&peer_id);
+    } else {
+        all.insert(peer_id.clone(), cleaned.clone());
+    }
+    state.tags.set(all.clone())?;
+
+    // Re-index the peer under its new tags
+    let mut networks = state.networks.lock().unwrap();
+    for snapshot in networks.values_mut() {
+        if let Some(peer) = snapshot.peers.get(&peer_id).cloned() {
+            snapshot.remove(&peer_id, &previous);
+            snapshot.insert(peer, &all);
+        }
+    }
+    Ok(cleaned)
+}
@@ -0,0 +1,427 @@
+// Pinned Peer Monitor
+// Periodically measures latency and sustained throughput to peers the user pinned,
+// keeping an SLA-style history and warning when a peer exceeds its latency budget.
+//
+// Measurements run over the tunnel against a small responder every desktop client
+// serves on its virtual IP (PROBE_PORT):
+//   'P'                       -> 'P'                    (round trip)
+//   'T' <u64 BE len> <bytes>  -> <u64 BE bytes received> (throughput)
+// Peers without the responder fall back to the daemon's reported latency.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::notifications::{self, NotificationKind};
+use crate::store::JsonStore;
+use crate::tunnel;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "peer_monitor.json";
+const HISTORY_FILE: &str = "peer_monitor_history.json";
+
+pub const PROBE_PORT: u16 = 34110;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(15);
+const PING_COUNT: usize = 3;
+/// The responder refuses larger uploads
+const MAX_PAYLOAD_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Samples older than this are pruned
+const HISTORY_RETENTION_SECS: u64 = 7 * 86_400;
+
+/// Consecutive over-budget samples before warning, so one spike does not alert
+const BREACH_SAMPLES: u32 = 2;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct PinnedPeer {
+    pub peer_id: String,
+    /// Warn when latency stays above this
+    pub latency_budget_ms: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct PeerMonitorSettings {
+    pub pinned: Vec<PinnedPeer>,
+    pub interval_secs: u32,
+    /// Bytes uploaded per throughput measurement; 0 measures latency only
+    pub payload_kib: u32,
+    /// Answer other clients' probes on our virtual IP
+    pub responder_enabled: bool,
+}
+
+impl Default for PeerMonitorSettings {
+    fn default() -> Self {
+        Self { pinned: Vec::new(), interval_secs: 300, payload_kib: 2048, responder_enabled: true }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct PeerSample {
+    #[ts(type = "number")]
+    pub timestamp: u64,
+    pub online: bool,
+    pub latency_ms: Option<f64>,
+    /// Upload throughput; `None` when the peer has no responder
+    pub throughput_mbps: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerSla {
+    pub peer_id: String,
+    pub samples: u32,
+    /// Share of samples with the peer online, 0-100
+    pub uptime_percent: f64,
+    pub avg_latency_ms: Option<f64>,
+    pub p95_latency_ms: Option<f64>,
+    pub avg_throughput_mbps: Option<f64>,
+    /// Samples above the latency budget
+    pub budget_breaches: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PinnedPeerStatus {
+    pub peer: PinnedPeer,
+    pub latest: Option<PeerSample>,
+}
+
+/// Managed state holding pinned peers and their measurement history
+pub struct PeerMonitorState {
+    settings: JsonStore<PeerMonitorSettings>,
+    history: JsonStore<HashMap<String, Vec<PeerSample>>>,
+    /// Consecutive over-budget samples per peer
+    breaches: std::sync::Mutex<HashMap<String, u32>>,
+    changed: tokio::sync::Notify,
+}
+
+impl PeerMonitorState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            history: JsonStore::open(app, HISTORY_FILE),
+            breaches: std::sync::Mutex::new(HashMap::new()),
+            changed: tokio::sync::Notify::new(),
+        }
+    }
+
+    fn record(&self, peer_id: &str, sample: PeerSample) {
+        let cutoff = unix_now().saturating_sub(HISTORY_RETENTION_SECS);
+        let result = self.history.update(|history| {
+            let samples = history.entry(peer_id.to_string()).or_default();
+            samples.push(sample);
+            samples.retain(|s| s.timestamp >= cutoff);
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record peer sample: {}", e);
+        }
+    }
+
+    fn samples(&self, peer_id: &str, hours: u32) -> Vec<PeerSample> {
+        let cutoff = unix_now().saturating_sub(u64::from(hours) * 3600);
+        self.history
+            .get()
+            .remove(peer_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| s.timestamp >= cutoff)
+            .collect()
+    }
+}
+
+async fn ping(stream: &mut TcpStream) -> std::io::Result<Duration> {
+    let started = Instant::now();
+    stream.write_all(b"P").await?;
+    let mut reply = [0u8; 1];
+    stream.read_exact(&mut reply).await?;
+    Ok(started.elapsed())
+}
+
+async fn upload(stream: &mut TcpStream, bytes: u64) -> std::io::Result<Duration> {
+    let chunk = vec![0u8; 64 * 1024];
+    let started = Instant::now();
+    stream.write_all(b"T").await?;
+    stream.write_all(&bytes.to_be_bytes()).await?;
+    let mut remaining = bytes;
+    while remaining > 0 {
+        let n = remaining.min(chunk.len() as u64) as usize;
+        stream.write_all(&chunk[..n]).await?;
+        remaining -= n as u64;
+    }
+    let mut ack = [0u8; 8];
+    stream.read_exact(&mut ack).await?;
+    if u64::from_be_bytes(ack) != bytes {
+        return Err(std::io::Error::other("responder acknowledged a short upload"));
+    }
+    Ok(started.elapsed())
+}
+
+/// Median round trip and throughput over the responder protocol
+async fn probe(ip: IpAddr, payload_bytes: u64) -> std::io::Result<(f64, Option<f64>)> {
+    let mut stream = TcpStream::connect(SocketAddr::new(ip, PROBE_PORT)).await?;
+    stream.set_nodelay(true)?;
+
+    let mut rtts = Vec::with_capacity(PING_COUNT);
+    for _ in 0..PING_COUNT {
+        rtts.push(ping(&mut stream).await?.as_secs_f64() * 1000.0);
+    }
+    rtts.sort_by(f64::total_cmp);
+    let latency = rtts[rtts.len() / 2];
+
+    let throughput = if payload_bytes > 0 {
+        let elapsed = upload(&mut stream, payload_bytes).await?.as_secs_f64();
+        Some(payload_bytes as f64 * 8.0 / elapsed.max(1e-6) / 1_000_000.0)
+    } else {
+        None
+    };
+    Ok((latency, throughput))
+}
+
+/// Serve one prober until it disconnects
+async fn respond(mut stream: TcpStream) -> std::io::Result<()> {
+    stream.set_nodelay(true)?;
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let mut op = [0u8; 1];
+        if stream.read_exact(&mut op).await.is_err() {
+            return Ok(());
+        }
+        match op[0] {
+            b'P' => stream.write_all(b"P").await?,
+            b'T' => {
+                let mut len = [0u8; 8];
+                stream.read_exact(&mut len).await?;
+                let len = u64::from_be_bytes(len);
+                if len > MAX_PAYLOAD_BYTES {
+                    return Err(std::io::Error::other("probe payload too large"));
+                }
+                let mut remaining = len;
+                while remaining > 0 {
+                    let n = remaining.min(buf.len() as u64) as usize;
+                    stream.read_exact(&mut buf[..n]).await?;
+                    remaining -= n as u64;
+                }
+                stream.write_all(&len.to_be_bytes()).await?;
+            }
+            _ => return Err(std::io::Error::other("unknown probe op")),
+        }
+    }
+}
+
+/// Measure one peer now and record the sample
+async fn measure(app: &AppHandle, pinned: &PinnedPeer) -> Result<PeerSample, String> {
+    let state = app.state::<PeerMonitorState>();
+    let daemon = app.state::<DaemonState>();
+    let settings = state.settings.get();
+
+    let peers = daemon_call!(daemon, "get_peers", |client| client.get_peers())?;
+    let peer = peers.into_iter().find(|p| p.id == pinned.peer_id);
+    let online = peer.as_ref().is_some_and(|p| p.connected);
+
+    let mut sample = PeerSample { timestamp: unix_now(), online, latency_ms: None, throughput_mbps: None };
+    if let Some(peer) = peer.filter(|p| p.connected) {
+        let ip = peer.virtual_ip.parse::<IpAddr>().ok();
+        let payload = u64::from(settings.payload_kib) * 1024;
+        let measured = match ip {
+            Some(ip) => tokio::time::timeout(PROBE_TIMEOUT, probe(ip, payload)).await.ok().and_then(Result::ok),
+            None => None,
+        };
+        match measured {
+            Some((latency, throughput)) => {
+                sample.latency_ms = Some(latency);
+                sample.throughput_mbps = throughput;
+            }
+            // No responder on the peer; the daemon's own estimate is better than nothing
+            None => sample.latency_ms = (peer.latency_ms > 0).then_some(peer.latency_ms as f64),
+        }
+
+        let over = sample.latency_ms.is_some_and(|l| l > f64::from(pinned.latency_budget_ms));
+        let streak = {
+            let mut breaches = state.breaches.lock().unwrap();
+            let streak = breaches.entry(pinned.peer_id.clone()).or_default();
+            *streak = if over { *streak + 1 } else { 0 };
+            *streak
+        };
+        if streak == BREACH_SAMPLES {
+            let name = if peer.display_name.is_empty() { &peer.name } else { &peer.display_name };
+            notifications::notify(
+                app,
+                NotificationKind::System,
+                "Peer over latency budget",
+                &format!(
+                    "{} is at {:.0} ms (budget {} ms)",
+                    name,
+                    sample.latency_ms.unwrap_or_default(),
+                    pinned.latency_budget_ms
+                ),
+            );
+        }
+    }
+
+    state.record(&pinned.peer_id, sample.clone());
+    Ok(sample)
+}
+
+/// Run the probe responder and the periodic measurements
+pub fn spawn(app: AppHandle) {
+    tunnel::serve(
+        app.clone(),
+        "Peer probe responder",
+        PROBE_PORT,
+        |app| app.state::<PeerMonitorState>().settings.get().responder_enabled,
+        Some(|app| &app.state::<PeerMonitorState>().inner().changed),
+        |_, stream, _| async move {
+            let _ = tokio::time::timeout(PROBE_TIMEOUT * 4, respond(stream)).await;
+        },
+    );
+
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<PeerMonitorState>();
+        loop {
+            let settings = state.settings.get();
+            let interval = Duration::from_secs(u64::from(settings.interval_secs.max(30)));
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = state.changed.notified() => continue,
+            }
+            if !app.state::<DaemonState>().connection.state().is_usable() {
+                continue;
+            }
+            // Sequential, so throughput tests do not compete for the uplink
+            for pinned in &settings.pinned {
+                if let Err(e) = measure(&app, pinned).await {
+                    log::debug!("Peer measurement for {} failed: {}", pinned.peer_id, e);
+                }
+            }
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_peer_monitor_settings(state: State<'_, PeerMonitorState>) -> Result<PeerMonitorSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_peer_monitor_settings(
+    state: State<'_, PeerMonitorState>,
+    settings: PeerMonitorSettings,
+) -> Result<PeerMonitorSettings, String> {
+    if settings.interval_secs < 30 {
+        return Err("measurement interval must be at least 30 seconds".to_string());
+    }
+    if u64::from(settings.payload_kib) * 1024 > MAX_PAYLOAD_BYTES {
+        return Err(format!("payload cannot exceed {} KiB", MAX_PAYLOAD_BYTES / 1024));
+    }
+    state.settings.set(settings.clone())?;
+    state.changed.notify_waiters();
+    Ok(settings)
+}
+
+/// Pin a peer, or update its latency budget if already pinned
+#[tauri::command]
+pub async fn pin_peer(state: State<'_, PeerMonitorState>, peer_id: String, latency_budget_ms: u32) -> Result<(), String> {
+    state.settings.update(|settings| {
+        match settings.pinned.iter_mut().find(|p| p.peer_id == peer_id) {
+            Some(pinned) => pinned.latency_budget_ms = latency_budget_ms,
+            None => settings.pinned.push(PinnedPeer { peer_id, latency_budget_ms }),
+        }
+    })?;
+    state.changed.notify_waiters();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn unpin_peer(state: State<'_, PeerMonitorState>, peer_id: String) -> Result<(), String> {
+    state.settings.update(|settings| settings.pinned.retain(|p| p.peer_id != peer_id))?;
+    state.history.update(|history| history.remove(&peer_id))?;
+    state.breaches.lock().unwrap().remove(&peer_id);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_pinned_peers(state: State<'_, PeerMonitorState>) -> Result<Vec<PinnedPeerStatus>, String> {
+    let history = state.history.get();
+    Ok(state
+        .settings
+        .get()
+        .pinned
+        .into_iter()
+        .map(|peer| PinnedPeerStatus {
+            latest: history.get(&peer.peer_id).and_then(|s| s.last().cloned()),
+            peer,
+        })
+        .collect())
+}
+
+/// Measure a pinned peer immediately
+#[tauri::command]
+pub async fn probe_pinned_peer(
+    app: AppHandle,
+    state: State<'_, PeerMonitorState>,
+    peer_id: String,
+) -> Result<PeerSample, String> {
+    let pinned = state
+        .settings
+        .get()
+        .pinned
+        .into_iter()
+        .find(|p| p.peer_id == peer_id)
+        .ok_or_else(|| format!("peer '{}' is not pinned", peer_id))?;
+    measure(&app, &pinned).await
+}
+
+/// Samples from the last `hours` (default 24), oldest first
+#[tauri::command]
+pub async fn get_peer_monitor_history(
+    state: State<'_, PeerMonitorState>,
+    peer_id: String,
+    hours: Option<u32>,
+) -> Result<Vec<PeerSample>, String> {
+    Ok(state.samples(&peer_id, hours.unwrap_or(24)))
+}
+
+#[tauri::command]
+pub async fn get_peer_sla(
+    state: State<'_, PeerMonitorState>,
+    peer_id: String,
+    hours: Option<u32>,
+) -> Result<PeerSla, String> {
+    let samples = state.samples(&peer_id, hours.unwrap_or(24));
+    let budget = state
+        .settings
+        .get()
+        .pinned
+        .into_iter()
+        .find(|p| p.peer_id == peer_id)
+        .map(|p| f64::from(p.latency_budget_ms));
+
+    let mut latencies: Vec<f64> = samples.iter().filter_map(|s| s.latency_ms).collect();
+    latencies.sort_by(f64::total_cmp);
+    let throughputs: Vec<f64> = samples.iter().filter_map(|s| s.throughput_mbps).collect();
+    let average = |values: &[f64]| (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64);
+    let online = samples.iter().filter(|s| s.online).count();
+
+    Ok(PeerSla {
+        samples: samples.len() as u32,
+        uptime_percent: if samples.is_empty() { 0.0 } else { online as f64 * 100.0 / samples.len() as f64 },
+        avg_latency_ms: average(&latencies),
+        p95_latency_ms: (!latencies.is_empty()).then(|| latencies[(latencies.len() * 95 / 100).min(latencies.len() - 1)]),
+        avg_throughput_mbps: average(&throughputs),
+        budget_breaches: budget.map_or(0, |b| latencies.iter().filter(|l| **l > b).count() as u32),
+        peer_id,
+    })
+}
@@ -0,0 +1,90 @@
+// Portable Mode
+// For running from a USB stick: a `goconnect.portable` file next to the
+// executable, or the `--portable[=<dir>]` argument, keeps every local store,
+// token and log in a data folder beside the executable (or `<dir>`) instead
+// of the user profile. Portable runs leave the host alone: no auto-update, no
+// deep-link registration, no credentials in the system keyring.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use ts_rs::TS;
+
+/// Marker file next to the executable that turns portable mode on
+const MARKER_FILE: &str = "goconnect.portable";
+
+const ARG: &str = "--portable";
+
+/// Data folder next to the executable when none is given
+const DATA_DIR: &str = "GoConnectData";
+
+static PORTABLE_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Why the data folder could not be set up; found before logging is, so logged later
+static SETUP_ERROR: OnceLock<String> = OnceLock::new();
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PortableMode {
+    pub enabled: bool,
+    /// Where local state lives in portable mode
+    pub data_dir: Option<String>,
+}
+
+fn detect() -> Option<PathBuf> {
+    let exe_dir = std::env::current_exe().ok()?.parent()?.to_path_buf();
+    for arg in std::env::args().skip(1) {
+        if arg == ARG {
+            return Some(exe_dir.join(DATA_DIR));
+        }
+        if let Some(dir) = arg.strip_prefix(ARG).and_then(|rest| rest.strip_prefix('=')) {
+            // Relative paths are relative to the executable, not the working
+            // directory, so a shortcut on the stick works from any drive letter
+            let dir = Path::new(dir);
+            return Some(if dir.is_absolute() { dir.to_path_buf() } else { exe_dir.join(dir) });
+        }
+    }
+    exe_dir.join(MARKER_FILE).is_file().then(|| exe_dir.join(DATA_DIR))
+}
+
+/// The portable data folder, or `None` for a normal install
+pub fn data_dir() -> Option<&'static Path> {
+    PORTABLE_DIR
+        .get_or_init(|| {
+            let dir = detect();
+            if let Some(dir) = &dir {
+                if let Err(e) = std::fs::create_dir_all(dir) {
+                    let _ = SETUP_ERROR.set(format!("Portable data folder {:?} is not writable: {}", dir, e));
+                }
+            }
+            dir
+        })
+        .as_deref()
+}
+
+pub fn enabled() -> bool {
+    data_dir().is_some()
+}
+
+/// Log a problem setting up the data folder, once logging is up
+pub fn log_setup_error() {
+    if let Some(error) = SETUP_ERROR.get() {
+        log::warn!("{}", error);
+    }
+}
+
+/// Log folder inside the portable data folder
+pub fn log_dir() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("logs"))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_portable_mode() -> Result<PortableMode, String> {
+    Ok(PortableMode {
+        enabled: enabled(),
+        data_dir: data_dir().map(|dir| dir.to_string_lossy().into_owned()),
+    })
+}
@@ -0,0 +1,225 @@
+// Prefetching
+// Warms what the next view will ask for so it renders from cache: when the
+// window gains focus, when the current network changes and when the UI
+// selects a network. Peers land in the peer index snapshot, the latest chat
+// messages and the transfer list in caches here. Fetches run concurrently but
+// at most MAX_PARALLEL at a time, so a prefetch never crowds out the commands
+// the user is actually waiting on. The caches are budgeted by `memory`.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::content_filter;
+use crate::daemon::{ChatMessage, TransferInfo};
+use crate::memory::{self, CacheEntry, Cached};
+use crate::peer_index::{self, PeerIndexState};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::Semaphore;
+use ts_rs::TS;
+
+const MAX_PARALLEL: usize = 2;
+
+/// Messages cached per network, matching the chat view's first page
+const RECENT_MESSAGES: i32 = 50;
+
+/// Focus changes faster than data does
+const FOCUS_COOLDOWN: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CachedMessages {
+    pub network_id: String,
+    pub messages: Vec<ChatMessage>,
+    #[ts(type = "number")]
+    pub age_ms: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct CachedTransfers {
+    pub transfers: Vec<TransferInfo>,
+    #[ts(type = "number")]
+    pub age_ms: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PrefetchReport {
+    pub network_id: Option<String>,
+    /// What was warmed: "peers", "chat", "transfers"
+    pub warmed: Vec<String>,
+    pub errors: Vec<String>,
+    #[ts(type = "number")]
+    pub took_ms: u64,
+}
+
+/// Managed state holding the prefetched data
+pub struct PrefetchState {
+    messages: Mutex<HashMap<String, Cached<Vec<ChatMessage>>>>,
+    transfers: Mutex<Option<Cached<Vec<TransferInfo>>>>,
+    permits: Semaphore,
+    last_focus: Mutex<Option<Instant>>,
+}
+
+impl Default for PrefetchState {
+    fn default() -> Self {
+        Self {
+            messages: Mutex::new(HashMap::new()),
+            transfers: Mutex::new(None),
+            permits: Semaphore::new(MAX_PARALLEL),
+            last_focus: Mutex::new(None),
+        }
+    }
+}
+
+impl PrefetchState {
+    pub(crate) fn message_entries(&self) -> Vec<CacheEntry> {
+        self.messages.lock().unwrap().iter().map(|(id, cached)| cached.entry(id)).collect()
+    }
+
+    pub(crate) fn cached_message_count(&self, network_id: &str) -> usize {
+        self.messages.lock().unwrap().get(network_id).map_or(0, |cached| cached.value.len())
+    }
+
+    pub(crate) fn evict_messages(&self, network_ids: &[String]) {
+        self.messages.lock().unwrap().retain(|id, _| !network_ids.contains(id));
+    }
+
+    /// Add a message to the cached page of `network_id`, replacing the one with
+    /// `replace_id` if it is there
+    pub(crate) fn upsert_message(&self, network_id: &str, message: ChatMessage, replace_id: &str) {
+        if let Some(cached) = self.messages.lock().unwrap().get_mut(network_id) {
+            cached.modify(|messages| match messages.iter_mut().find(|m| m.id == replace_id) {
+                Some(existing) => *existing = message,
+                None => messages.push(message),
+            });
+        }
+    }
+
+    pub(crate) fn remove_message(&self, network_id: &str, id: &str) {
+        if let Some(cached) = self.messages.lock().unwrap().get_mut(network_id) {
+            cached.modify(|messages| messages.retain(|m| m.id != id));
+        }
+    }
+
+    pub(crate) fn transfer_entries(&self) -> Vec<CacheEntry> {
+        self.transfers.lock().unwrap().iter().map(|cached| cached.entry("transfers")).collect()
+    }
+
+    pub(crate) fn evict_transfers(&self) {
+        *self.transfers.lock().unwrap() = None;
+    }
+}
+
+/// Warm the caches for `network_id`, or the current network
+pub async fn prefetch(app: &AppHandle, network_id: Option<String>) -> PrefetchReport {
+    let started = Instant::now();
+    let daemon = app.state::<DaemonState>();
+    let state = app.state::<PrefetchState>();
+    let index = app.state::<PeerIndexState>();
+    let network_id = match network_id.filter(|id| !id.is_empty()) {
+        Some(id) => Some(id),
+        None => peer_index::current_network(&daemon).await,
+    };
+
+    let peers = async {
+        let id = network_id.clone().ok_or("no network is selected")?;
+        let _permit = state.permits.acquire().await.map_err(|e| e.to_string())?;
+        index.with_snapshot(&daemon, Some(id), |_| ()).await.map(|_| ())
+    };
+    let chat = async {
+        let id = network_id.clone().ok_or("no network is selected")?;
+        let _permit = state.permits.acquire().await.map_err(|e| e.to_string())?;
+        let mut messages =
+            daemon_call!(daemon, "get_messages", |client| client.get_messages(&id, RECENT_MESSAGES, None))?;
+        for message in &mut messages {
+            if let Some(masked) = content_filter::mask_cached(app, &id, &message.content) {
+                message.content = masked;
+            }
+        }
+        state.messages.lock().unwrap().insert(id, Cached::new(messages));
+        Ok::<_, String>(())
+    };
+    let transfers = async {
+        let _permit = state.permits.acquire().await.map_err(|e| e.to_string())?;
+        let transfers = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None))?;
+        *state.transfers.lock().unwrap() = Some(Cached::new(transfers));
+        Ok::<_, String>(())
+    };
+    let (peers, chat, transfers) = tokio::join!(peers, chat, transfers);
+
+    let mut report = PrefetchReport { network_id, warmed: Vec::new(), errors: Vec::new(), took_ms: 0 };
+    for (name, result) in [("peers", peers), ("chat", chat), ("transfers", transfers)] {
+        match result {
+            Ok(()) => report.warmed.push(name.to_string()),
+            Err(e) => report.errors.push(format!("{}: {}", name, e)),
+        }
+    }
+    memory::enforce(app);
+    report.took_ms = started.elapsed().as_millis() as u64;
+    log::debug!("Prefetched {:?} in {}ms", report.warmed, report.took_ms);
+    report
+}
+
+/// The main window gained focus
+pub fn on_focus(app: &AppHandle) {
+    // Focus events can arrive before setup has run
+    let Some(state) = app.try_state::<PrefetchState>() else { return };
+    let mut last = state.last_focus.lock().unwrap();
+    if last.is_some_and(|at| at.elapsed() < FOCUS_COOLDOWN) {
+        return;
+    }
+    *last = Some(Instant::now());
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        prefetch(&app, None).await;
+    });
+}
+
+/// Prefetch whenever the current network changes
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut networks_rx = app.state::<DaemonState>().subscribe_networks();
+        let mut current: Option<String> = None;
+        while networks_rx.changed().await.is_ok() {
+            let now = networks_rx.borrow_and_update().iter().find(|n| n.is_current).map(|n| n.network_id.clone());
+            if now.is_some() && now != current {
+                prefetch(&app, now.clone()).await;
+            }
+            current = now;
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Warm the caches for a network the UI is about to show
+#[tauri::command]
+pub async fn prefetch_network(app: AppHandle, network_id: Option<String>) -> Result<PrefetchReport, String> {
+    Ok(prefetch(&app, network_id).await)
+}
+
+/// The latest chat messages as last prefetched; `None` when nothing is cached
+#[tauri::command]
+pub async fn get_cached_messages(
+    state: State<'_, PrefetchState>,
+    network_id: String,
+) -> Result<Option<CachedMessages>, String> {
+    Ok(state.messages.lock().unwrap().get_mut(&network_id).map(|cached| CachedMessages {
+        network_id: network_id.clone(),
+        messages: cached.touch().clone(),
+        age_ms: cached.fetched.elapsed().as_millis() as u64,
+    }))
+}
+
+/// The transfer list as last prefetched; `None` when nothing is cached
+#[tauri::command]
+pub async fn get_cached_transfers(state: State<'_, PrefetchState>) -> Result<Option<CachedTransfers>, String> {
+    Ok(state.transfers.lock().unwrap().as_mut().map(|cached| CachedTransfers {
+        transfers: cached.touch().clone(),
+        age_ms: cached.fetched.elapsed().as_millis() as u64,
+    }))
+}
@@ -0,0 +1,335 @@
+// Rich Presence
+// Publishes "Connected to HomeNet • 5 peers" to Discord Rich Presence and/or a
+// local HTTP endpoint, driven by the daemon status stream. Off by default.
+
+use crate::commands::DaemonState;
+use crate::daemon::DaemonStatus;
+use crate::store::JsonStore;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::Notify;
+use ts_rs::TS;
+
+pub const DEFAULT_TEMPLATE: &str = "Connected to {network} • {peers} peers";
+
+const SETTINGS_FILE: &str = "presence.json";
+
+/// Shown instead of the network name and address when details are hidden
+const HIDDEN_NETWORK: &str = "a private network";
+const HIDDEN_IP: &str = "hidden";
+
+/// How often a sink that failed (e.g. Discord not running yet) is retried
+const RETRY_INTERVAL: Duration = Duration::from_secs(30);
+const HTTP_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct PresenceSettings {
+    /// Master switch; nothing is published while false
+    pub enabled: bool,
+    /// Replace the network name and virtual IP with neutral placeholders
+    pub hide_network_details: bool,
+    /// Placeholders: `{network}`, `{peers}`, `{ip}`
+    pub template: String,
+    /// Discord application id; empty disables the Discord sink
+    pub discord_client_id: String,
+    /// Loopback URL that receives each update as a JSON POST; empty disables it
+    pub endpoint_url: String,
+}
+
+impl Default for PresenceSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hide_network_details: false,
+            template: DEFAULT_TEMPLATE.to_string(),
+            discord_client_id: String::new(),
+            endpoint_url: String::new(),
+        }
+    }
+}
+
+impl PresenceSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.template.trim().is_empty() {
+            return Err("presence template cannot be empty".to_string());
+        }
+        if !self.discord_client_id.is_empty() && !self.discord_client_id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err("Discord client id must be numeric".to_string());
+        }
+        if !self.endpoint_url.is_empty() {
+            let url = reqwest::Url::parse(&self.endpoint_url).map_err(|e| format!("invalid endpoint URL: {}", e))?;
+            let loopback = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+            if url.scheme() != "http" || !loopback {
+                return Err("presence endpoint must be an http:// URL on localhost".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Text to publish for a status, or `None` to clear the presence
+    pub fn render(&self, status: Option<&DaemonStatus>) -> Option<String> {
+        let status = status.filter(|s| self.enabled && s.connected)?;
+        let (network, ip) = if self.hide_network_details {
+            (HIDDEN_NETWORK, HIDDEN_IP)
+        } else {
+            (status.network_name.as_str(), status.virtual_ip.as_str())
+        };
+        Some(
+            self.template
+                .replace("{network}", network)
+                .replace("{peers}", &status.active_peers.to_string())
+                .replace("{ip}", ip),
+        )
+    }
+}
+
+/// Managed state: persisted settings plus a wake-up for the publisher task
+pub struct PresenceState {
+    settings: JsonStore<PresenceSettings>,
+    changed: Notify,
+}
+
+impl PresenceState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            changed: Notify::new(),
+        }
+    }
+}
+
+/// Start the publisher; it idles until presence is enabled
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let presence = app.state::<PresenceState>();
+        let mut status_rx = app.state::<DaemonState>().subscribe_status();
+        let mut publisher = Publisher::default();
+
+        loop {
+            let settings = presence.settings.get();
+            let status = status_rx.borrow_and_update().clone();
+            let ok = publisher.publish(&settings, settings.render(status.as_ref())).await;
+
+            tokio::select! {
+                changed = status_rx.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                }
+                _ = presence.changed.notified() => {}
+                _ = tokio::time::sleep(RETRY_INTERVAL), if !ok => {}
+            }
+        }
+    });
+}
+
+/// Pushes presence text to the configured sinks, skipping unchanged updates
+#[derive(Default)]
+struct Publisher {
+    discord: Option<DiscordIpc>,
+    http: reqwest::Client,
+    /// Last successfully published (text, discord client id, endpoint)
+    last: Option<(Option<String>, String, String)>,
+}
+
+impl Publisher {
+    /// Returns false if any sink failed and the update should be retried
+    async fn publish(&mut self, settings: &PresenceSettings, text: Option<String>) -> bool {
+        let key = (text.clone(), settings.discord_client_id.clone(), settings.endpoint_url.clone());
+        if self.last.as_ref() == Some(&key) {
+            return true;
+        }
+
+        let discord = self.publish_discord(&settings.discord_client_id, text.as_deref()).await;
+        let endpoint = publish_endpoint(&self.http, &settings.endpoint_url, text.as_deref()).await;
+
+        let mut ok = true;
+        for (sink, result) in [("Discord", discord), ("endpoint", endpoint)] {
+            if let Err(e) = result {
+                log::debug!("Presence {} update failed: {}", sink, e);
+                ok = false;
+            }
+        }
+        if ok {
+            self.last = Some(key);
+        }
+        ok
+    }
+
+    async fn publish_discord(&mut self, client_id: &str, text: Option<&str>) -> Result<(), String> {
+        // Reconnect when the application id changes; drop the socket once presence is off
+        if self.discord.as_ref().is_some_and(|d| d.client_id != client_id) {
+            self.discord = None;
+        }
+        if client_id.is_empty() {
+            return Ok(());
+        }
+        if self.discord.is_none() {
+            if text.is_none() {
+                return Ok(());
+            }
+            self.discord = Some(DiscordIpc::connect(client_id).await?);
+        }
+
+        let discord = self.discord.as_mut().expect("connected above");
+        let result = discord.set_activity(text).await;
+        if result.is_err() || text.is_none() {
+            self.discord = None;
+        }
+        result
+    }
+}
+
+async fn publish_endpoint(http: &reqwest::Client, url: &str, text: Option<&str>) -> Result<(), String> {
+    if url.is_empty() {
+        return Ok(());
+    }
+    http.post(url)
+        .timeout(HTTP_TIMEOUT)
+        .json(&json!({ "active": text.is_some(), "text": text }))
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+// =============================================================================
+// DISCORD IPC
+// =============================================================================
+
+const OP_HANDSHAKE: u32 = 0;
+const OP_FRAME: u32 = 1;
+const OP_CLOSE: u32 = 2;
+const MAX_FRAME_BYTES: usize = 64 * 1024;
+const DISCORD_PIPES: u32 = 10;
+
+trait IpcStream: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> IpcStream for T {}
+
+/// Minimal Discord RPC client: handshake, then SET_ACTIVITY frames.
+/// Frames are `opcode: u32 LE`, `length: u32 LE`, then a JSON payload.
+struct DiscordIpc {
+    stream: Box<dyn IpcStream>,
+    client_id: String,
+}
+
+impl DiscordIpc {
+    async fn connect(client_id: &str) -> Result<Self, String> {
+        let mut ipc = Self { stream: open_pipe().await?, client_id: client_id.to_string() };
+        ipc.send(OP_HANDSHAKE, json!({ "v": 1, "client_id": client_id })).await?;
+        // Discord answers the handshake with a READY dispatch
+        ipc.receive().await?;
+        Ok(ipc)
+    }
+
+    async fn set_activity(&mut self, text: Option<&str>) -> Result<(), String> {
+        let activity = text.map(|details| json!({ "details": details }));
+        self.send(
+            OP_FRAME,
+            json!({
+                "cmd": "SET_ACTIVITY",
+                "args": { "pid": std::process::id(), "activity": activity },
+                "nonce": hex::encode(rand::random::<[u8; 8]>()),
+            }),
+        )
+        .await?;
+
+        let reply = self.receive().await?;
+        if reply["evt"] == "ERROR" {
+            return Err(reply["data"]["message"].as_str().unwrap_or("SET_ACTIVITY rejected").to_string());
+        }
+        Ok(())
+    }
+
+    async fn send(&mut self, op: u32, payload: Value) -> Result<(), String> {
+        let body = payload.to_string();
+        let mut frame = Vec::with_capacity(8 + body.len());
+        frame.extend_from_slice(&op.to_le_bytes());
+        frame.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        frame.extend_from_slice(body.as_bytes());
+        self.stream.write_all(&frame).await.map_err(|e| e.to_string())
+    }
+
+    async fn receive(&mut self) -> Result<Value, String> {
+        let mut header = [0u8; 8];
+        self.stream.read_exact(&mut header).await.map_err(|e| e.to_string())?;
+        let op = u32::from_le_bytes(header[..4].try_into().unwrap());
+        let len = u32::from_le_bytes(header[4..].try_into().unwrap()) as usize;
+        if len > MAX_FRAME_BYTES {
+            return Err(format!("Discord frame too large ({} bytes)", len));
+        }
+
+        let mut body = vec![0u8; len];
+        self.stream.read_exact(&mut body).await.map_err(|e| e.to_string())?;
+        let payload: Value = serde_json::from_slice(&body).map_err(|e| e.to_string())?;
+        if op == OP_CLOSE {
+            return Err(format!("Discord closed the connection: {}", payload["message"]));
+        }
+        Ok(payload)
+    }
+}
+
+#[cfg(unix)]
+async fn open_pipe() -> Result<Box<dyn IpcStream>, String> {
+    let dirs: Vec<std::path::PathBuf> = ["XDG_RUNTIME_DIR", "TMPDIR", "TMP", "TEMP"]
+        .iter()
+        .filter_map(|var| std::env::var_os(var).map(Into::into))
+        .chain(std::iter::once("/tmp".into()))
+        .collect();
+
+    for dir in &dirs {
+        for i in 0..DISCORD_PIPES {
+            if let Ok(stream) = tokio::net::UnixStream::connect(dir.join(format!("discord-ipc-{}", i))).await {
+                return Ok(Box::new(stream));
+            }
+        }
+    }
+    Err("Discord is not running".to_string())
+}
+
+#[cfg(windows)]
+async fn open_pipe() -> Result<Box<dyn IpcStream>, String> {
+    use tokio::net::windows::named_pipe::ClientOptions;
+
+    for i in 0..DISCORD_PIPES {
+        if let Ok(pipe) = ClientOptions::new().open(format!(r"\\.\pipe\discord-ipc-{}", i)) {
+            return Ok(Box::new(pipe));
+        }
+    }
+    Err("Discord is not running".to_string())
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_presence_settings(state: State<'_, PresenceState>) -> Result<PresenceSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_presence_settings(
+    state: State<'_, PresenceState>,
+    settings: PresenceSettings,
+) -> Result<PresenceSettings, String> {
+    settings.validate()?;
+    state.settings.set(settings.clone())?;
+    state.changed.notify_one();
+    Ok(settings)
+}
+
+/// Render the current status with the given settings without publishing anything
+#[tauri::command]
+pub async fn preview_presence(
+    daemon: State<'_, DaemonState>,
+    settings: PresenceSettings,
+) -> Result<Option<String>, String> {
+    let status = daemon.probe_status().await.ok();
+    Ok(PresenceSettings { enabled: true, ..settings }.render(status.as_ref()))
+}
@@ -0,0 +1,346 @@
+// Printer Sharing
+// Exposes selected local CUPS printers to the user's own devices over the tunnel.
+// IPP requests arriving on the virtual IP (IPP_FORWARD_PORT) are checked against
+// the printer's allowed peers and forwarded to the local scheduler on port 631;
+// peers add the printer as ipp://<virtual ip>:<port>/printers/<name>. Only that
+// exact path is let through, and each connection carries a single request, so
+// nothing else on the scheduler (its admin pages, other printers) is reachable.
+// Windows has no local IPP server, so sharing is only offered on macOS and Linux.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::store::JsonStore;
+use crate::tunnel;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use ts_rs::TS;
+
+const SHARES_FILE: &str = "printer_shares.json";
+
+pub const IPP_FORWARD_PORT: u16 = 34140;
+const CUPS_PORT: u16 = 631;
+
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+const HEADER_TIMEOUT: Duration = Duration::from_secs(15);
+/// Print jobs can be large and slow; idle connections are cut after this
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct PrinterShare {
+    pub name: String,
+    /// Peers (the user's own devices) allowed to print
+    pub allowed_peers: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct SharedPrinterInfo {
+    pub name: String,
+    pub shared: bool,
+    pub allowed_peers: Vec<String>,
+    /// Address peers add the printer with, while shared and connected
+    pub url: Option<String>,
+    /// Shared earlier but no longer installed locally
+    pub missing: bool,
+}
+
+/// Managed state holding the shared printers
+pub struct PrinterState {
+    shares: JsonStore<Vec<PrinterShare>>,
+    changed: tokio::sync::Notify,
+}
+
+impl PrinterState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { shares: JsonStore::open(app, SHARES_FILE), changed: tokio::sync::Notify::new() }
+    }
+}
+
+/// Names of the printers CUPS knows about
+async fn local_printers() -> Result<Vec<String>, String> {
+    if cfg!(windows) {
+        return Err("printer sharing needs CUPS and is available on macOS and Linux".to_string());
+    }
+    let output = tauri::async_runtime::spawn_blocking(|| std::process::Command::new("lpstat").arg("-e").output())
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("failed to run lpstat (is CUPS installed?): {}", e))?;
+    // "No destinations added." goes to stderr with a failure status
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+fn forbidden() -> &'static [u8] {
+    b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+}
+
+fn bad_request() -> &'static [u8] {
+    b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+}
+
+/// How the request body is framed
+enum BodyLength {
+    Fixed(u64),
+    Chunked,
+}
+
+/// Value of every header called `name` in the head
+fn header_values<'a>(head: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> {
+    head.split("\r\n").skip(1).filter_map(move |line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim().eq_ignore_ascii_case(name).then_some(value.trim())
+    })
+}
+
+/// The body framing, or `None` when it is ambiguous (a smuggling attempt or a broken client)
+fn body_length(head: &str) -> Option<BodyLength> {
+    let chunked = header_values(head, "transfer-encoding").any(|v| v.to_ascii_lowercase().contains("chunked"));
+    let mut lengths = header_values(head, "content-length");
+    let length = match lengths.next() {
+        Some(value) => Some(value.parse::<u64>().ok()?),
+        None => None,
+    };
+    if lengths.next().is_some() || (chunked && length.is_some()) {
+        return None;
+    }
+    Some(if chunked { BodyLength::Chunked } else { BodyLength::Fixed(length.unwrap_or(0)) })
+}
+
+/// Read the request head, returning it and any body bytes read past it
+async fn read_head(stream: &mut TcpStream) -> std::io::Result<(String, Vec<u8>)> {
+    let mut buf = Vec::with_capacity(4096);
+    let mut chunk = [0u8; 4096];
+    loop {
+        if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            let rest = buf.split_off(end + 4);
+            return Ok((String::from_utf8_lossy(&buf).into_owned(), rest));
+        }
+        if buf.len() > MAX_HEADER_BYTES {
+            return Err(std::io::Error::other("request head too large"));
+        }
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Err(std::io::Error::other("connection closed before the request head"));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+}
+
+/// Printer named by an IPP request path, which must be exactly `/printers/<name>`
+/// with an optional query; anything that could walk elsewhere is refused
+fn printer_from_path(path: &str) -> Option<&str> {
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    let name = path.strip_prefix("/printers/")?;
+    let walks = name.contains(['/', '\\', '%']) || name.split('.').all(str::is_empty);
+    (!name.is_empty() && !walks).then_some(name)
+}
+
+/// The head with Host pointed at the local scheduler, which rejects foreign hosts,
+/// and the connection limited to this request
+fn rewrite_head(head: &str) -> String {
+    let mut lines = head.split("\r\n").filter(|l| !l.is_empty());
+    let mut out = String::with_capacity(head.len() + 64);
+    if let Some(request_line) = lines.next() {
+        out.push_str(request_line);
+        out.push_str("\r\n");
+    }
+    for line in lines {
+        let name = line.split(':').next().unwrap_or_default().trim();
+        if ["host", "connection", "expect"].iter().any(|h| name.eq_ignore_ascii_case(h)) {
+            continue;
+        }
+        out.push_str(line);
+        out.push_str("\r\n");
+    }
+    out.push_str(&format!("Host: localhost:{}\r\nConnection: close\r\n\r\n", CUPS_PORT));
+    out
+}
+
+/// Copy one chunked body, up to and including its trailer, and nothing after it
+async fn copy_chunked<R, W>(reader: &mut R, upstream: &mut W) -> std::io::Result<()>
+where
+    R: AsyncBufReadExt + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut line = String::new();
+    loop {
+        line.clear();
+        (&mut *reader).take(MAX_HEADER_BYTES as u64).read_line(&mut line).await?;
+        if !line.ends_with("\r\n") {
+            return Err(std::io::Error::other("malformed chunk size"));
+        }
+        let size = line.trim_end().split(';').next().unwrap_or_default().trim();
+        let size = u64::from_str_radix(size, 16).map_err(|_| std::io::Error::other("malformed chunk size"))?;
+        upstream.write_all(line.as_bytes()).await?;
+        if size == 0 {
+            break;
+        }
+        // The chunk and its closing CRLF
+        let wanted = size.saturating_add(2);
+        if tokio::io::copy(&mut (&mut *reader).take(wanted), upstream).await? != wanted {
+            return Err(std::io::Error::other("connection closed inside a chunk"));
+        }
+    }
+    // Trailer fields, then the empty line ending the body
+    loop {
+        line.clear();
+        (&mut *reader).take(MAX_HEADER_BYTES as u64).read_line(&mut line).await?;
+        if !line.ends_with("\r\n") {
+            return Err(std::io::Error::other("malformed chunk trailer"));
+        }
+        upstream.write_all(line.as_bytes()).await?;
+        if line == "\r\n" {
+            return Ok(());
+        }
+    }
+}
+
+/// Copy the body of the one request being forwarded
+async fn copy_body<R, W>(reader: R, upstream: &mut W, length: BodyLength) -> std::io::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    match length {
+        BodyLength::Fixed(length) => {
+            if tokio::io::copy(&mut reader.take(length), upstream).await? != length {
+                return Err(std::io::Error::other("connection closed inside the body"));
+            }
+            Ok(())
+        }
+        BodyLength::Chunked => copy_chunked(&mut BufReader::new(reader), upstream).await,
+    }
+}
+
+/// Check one request against the shares and forward it to CUPS
+async fn forward(app: &AppHandle, mut client: TcpStream, from: IpAddr) -> std::io::Result<()> {
+    let (head, body) = tokio::time::timeout(HEADER_TIMEOUT, read_head(&mut client))
+        .await
+        .map_err(|_| std::io::Error::other("request head timed out"))??;
+    let path = head.split_whitespace().nth(1).unwrap_or_default();
+    let Some(length) = body_length(&head) else {
+        return client.write_all(bad_request()).await;
+    };
+
+    let share = printer_from_path(path)
+        .and_then(|name| app.state::<PrinterState>().shares.get().into_iter().find(|s| s.name == name));
+    let Some(share) = share else {
+        return client.write_all(forbidden()).await;
+    };
+    let daemon = app.state::<DaemonState>();
+    let allowed = daemon_call!(daemon, "get_peers", |client| client.get_peers())
+        .unwrap_or_default()
+        .into_iter()
+        .any(|p| share.allowed_peers.contains(&p.id) && p.virtual_ip.parse::<IpAddr>().ok() == Some(from));
+    if !allowed {
+        log::info!("Refused print request for {} from {}", share.name, from);
+        return client.write_all(forbidden()).await;
+    }
+
+    let mut upstream = TcpStream::connect(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), CUPS_PORT)).await?;
+    // The client waits for this before sending the body; the scheduler is not
+    // asked, so it never sends an interim response of its own
+    if header_values(&head, "expect").any(|v| v.eq_ignore_ascii_case("100-continue")) {
+        client.write_all(b"HTTP/1.1 100 Continue\r\n\r\n").await?;
+    }
+    upstream.write_all(rewrite_head(&head).as_bytes()).await?;
+    let (client_rx, mut client_tx) = client.split();
+    copy_body((&body[..]).chain(client_rx), &mut upstream, length).await?;
+    // The scheduler closes after its response, as asked by `rewrite_head`
+    tokio::io::copy(&mut upstream, &mut client_tx).await?;
+    client_tx.shutdown().await
+}
+
+/// Run the IPP forwarder on the virtual IP while any printer is shared
+pub fn spawn(app: AppHandle) {
+    tunnel::serve(
+        app,
+        "Printer forwarder",
+        IPP_FORWARD_PORT,
+        |app| !app.state::<PrinterState>().shares.get().is_empty(),
+        Some(|app| &app.state::<PrinterState>().inner().changed),
+        |app, stream, from| async move {
+            let forwarded = tokio::time::timeout(CONNECTION_TIMEOUT, forward(&app, stream, from.ip()));
+            if let Ok(Err(e)) = forwarded.await {
+                log::debug!("Print request from {} failed: {}", from, e);
+            }
+        },
+    );
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Local printers with their sharing state, plus shares whose printer is gone
+#[tauri::command]
+pub async fn list_shared_printers(
+    daemon: State<'_, DaemonState>,
+    state: State<'_, PrinterState>,
+) -> Result<Vec<SharedPrinterInfo>, String> {
+    let printers = local_printers().await?;
+    let shares = state.shares.get();
+    let own_ip = daemon.latest_status().filter(|s| s.connected).map(|s| s.virtual_ip);
+    let url = |name: &str| own_ip.as_ref().map(|ip| format!("ipp://{}:{}/printers/{}", ip, IPP_FORWARD_PORT, name));
+
+    let mut list: Vec<SharedPrinterInfo> = printers
+        .iter()
+        .map(|name| {
+            let share = shares.iter().find(|s| &s.name == name);
+            SharedPrinterInfo {
+                name: name.clone(),
+                shared: share.is_some(),
+                allowed_peers: share.map(|s| s.allowed_peers.clone()).unwrap_or_default(),
+                url: share.and_then(|_| url(name)),
+                missing: false,
+            }
+        })
+        .collect();
+    list.extend(shares.iter().filter(|s| !printers.contains(&s.name)).map(|s| SharedPrinterInfo {
+        name: s.name.clone(),
+        shared: true,
+        allowed_peers: s.allowed_peers.clone(),
+        url: None,
+        missing: true,
+    }));
+    Ok(list)
+}
+
+/// Share `name` with the given peers, replacing its previous peer list
+#[tauri::command]
+pub async fn share_printer(
+    state: State<'_, PrinterState>,
+    name: String,
+    allowed_peers: Vec<String>,
+) -> Result<PrinterShare, String> {
+    if allowed_peers.is_empty() {
+        return Err("choose at least one device to share the printer with".to_string());
+    }
+    if !local_printers().await?.contains(&name) {
+        return Err(format!("printer '{}' is not installed", name));
+    }
+    let share = PrinterShare { name, allowed_peers };
+    state.shares.update(|shares| {
+        shares.retain(|s| s.name != share.name);
+        shares.push(share.clone());
+    })?;
+    state.changed.notify_one();
+    Ok(share)
+}
+
+#[tauri::command]
+pub async fn unshare_printer(state: State<'_, PrinterState>, name: String) -> Result<(), String> {
+    state.shares.update(|shares| shares.retain(|s| s.name != name))?;
+    state.changed.notify_one();
+    Ok(())
+}
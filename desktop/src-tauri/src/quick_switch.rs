@@ -0,0 +1,236 @@
+// Quick Switcher
+// Fuzzy search over networks, peers and app actions for the Cmd+K palette.
+// The index lives here so the UI only receives the top matches per keystroke.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const USAGE_FILE: &str = "quick_switch.json";
+
+/// Networks and peers are re-fetched once the index is older than this
+const INDEX_TTL: Duration = Duration::from_secs(15);
+
+/// Selections older than this are dropped from the history
+const USAGE_RETENTION_SECS: u64 = 90 * 86_400;
+
+const DEFAULT_LIMIT: usize = 12;
+const MAX_LIMIT: usize = 50;
+
+/// Palette actions; the frontend maps the id to what it does
+const ACTIONS: &[(&str, &str)] = &[
+    ("create_network", "Create Network"),
+    ("join_network", "Join Network"),
+    ("send_file", "Send File"),
+    ("transfers", "File Transfers"),
+    ("notifications", "Notifications"),
+    ("settings", "Open Settings"),
+    ("check_update", "Check for Updates"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum QuickSwitchKind {
+    Network,
+    Peer,
+    Action,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct QuickSwitchResult {
+    /// `network:<id>`, `peer:<id>` or `action:<name>`; pass back to `quick_switch_record`
+    pub id: String,
+    pub kind: QuickSwitchKind,
+    pub title: String,
+    pub subtitle: String,
+    #[ts(type = "number")]
+    pub score: i64,
+    /// Character positions in `title` matched by the query, for highlighting
+    pub matches: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    id: String,
+    kind: QuickSwitchKind,
+    title: String,
+    subtitle: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct Usage {
+    count: u32,
+    /// Unix timestamp (seconds)
+    last_used: u64,
+}
+
+/// Managed state holding the search index and selection history
+pub struct QuickSwitchState {
+    index: Mutex<(Vec<Entry>, Option<Instant>)>,
+    usage: JsonStore<HashMap<String, Usage>>,
+}
+
+impl QuickSwitchState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            index: Mutex::new((Vec::new(), None)),
+            usage: JsonStore::open(app, USAGE_FILE),
+        }
+    }
+
+    /// Current index, refreshed from the daemon when stale. Actions are always present,
+    /// and a failed refresh keeps the previous networks and peers.
+    async fn entries(&self, daemon: &DaemonState) -> Vec<Entry> {
+        let fresh = self.index.lock().unwrap().1.is_some_and(|at| at.elapsed() < INDEX_TTL);
+        if !fresh {
+            let networks = daemon_call!(daemon, "list_networks", |client| client.list_networks());
+            let peers = daemon_call!(daemon, "get_peers", |client| client.get_peers());
+
+            if let (Ok(networks), Ok(peers)) = (networks, peers) {
+                let mut entries: Vec<Entry> = networks
+                    .into_iter()
+                    .map(|n| Entry {
+                        id: format!("network:{}", n.id),
+                        kind: QuickSwitchKind::Network,
+                        title: n.name,
+                        subtitle: "Network".to_string(),
+                    })
+                    .collect();
+                entries.extend(peers.into_iter().filter(|p| !p.is_self).map(|p| Entry {
+                    id: format!("peer:{}", p.id),
+                    kind: QuickSwitchKind::Peer,
+                    title: if p.display_name.is_empty() { p.name } else { p.display_name },
+                    subtitle: p.virtual_ip,
+                }));
+                *self.index.lock().unwrap() = (entries, Some(Instant::now()));
+            }
+        }
+
+        let mut entries = self.index.lock().unwrap().0.clone();
+        entries.extend(ACTIONS.iter().map(|(name, title)| Entry {
+            id: format!("action:{}", name),
+            kind: QuickSwitchKind::Action,
+            title: title.to_string(),
+            subtitle: "Action".to_string(),
+        }));
+        entries
+    }
+}
+
+/// Subsequence match with bonuses for word starts and consecutive runs.
+/// Returns the score and matched character positions, or `None` if not all query characters appear.
+fn fuzzy_score(query: &[char], text: &str) -> Option<(i64, Vec<u32>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut positions = Vec::with_capacity(query.len());
+    let mut score = 0i64;
+    let mut next = 0usize;
+    let mut previous: Option<usize> = None;
+
+    for &q in query {
+        let found = (next..chars.len()).find(|&i| chars[i].to_lowercase().eq(q.to_lowercase()))?;
+
+        let word_start = found == 0
+            || !chars[found - 1].is_alphanumeric()
+            || (chars[found - 1].is_lowercase() && chars[found].is_uppercase());
+        score += 1;
+        if word_start {
+            score += 10;
+        }
+        match previous {
+            Some(p) if p + 1 == found => score += 8,
+            // Gaps cost a little, capped so long names are not punished too hard
+            Some(p) => score -= ((found - p - 1) as i64).min(5),
+            None => score -= (found as i64).min(5),
+        }
+
+        positions.push(found as u32);
+        previous = Some(found);
+        next = found + 1;
+    }
+
+    if positions.len() == chars.len() {
+        score += 20; // exact match
+    }
+    Some((score, positions))
+}
+
+/// Frequently and recently chosen entries float up
+fn recency_boost(usage: Option<&Usage>, now: u64) -> i64 {
+    let Some(usage) = usage else {
+        return 0;
+    };
+    let age = now.saturating_sub(usage.last_used);
+    let recent = match age {
+        a if a < 3600 => 20,
+        a if a < 86_400 => 10,
+        a if a < 7 * 86_400 => 5,
+        _ => 0,
+    };
+    recent + 3 * i64::from(usage.count.min(10))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn quick_switch_query(
+    daemon: State<'_, DaemonState>,
+    state: State<'_, QuickSwitchState>,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<QuickSwitchResult>, String> {
+    let query: Vec<char> = query.trim().chars().filter(|c| !c.is_whitespace()).collect();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let usage = state.usage.get();
+    let now = unix_now();
+
+    let mut results: Vec<QuickSwitchResult> = state
+        .entries(&daemon)
+        .await
+        .into_iter()
+        .filter_map(|entry| {
+            // Subtitle matches (e.g. a peer's IP) count, but less than title matches
+            let (score, matches) = match fuzzy_score(&query, &entry.title) {
+                Some(hit) => hit,
+                None => (fuzzy_score(&query, &entry.subtitle)?.0 / 2, Vec::new()),
+            };
+            Some(QuickSwitchResult {
+                score: score + recency_boost(usage.get(&entry.id), now),
+                id: entry.id,
+                kind: entry.kind,
+                title: entry.title,
+                subtitle: entry.subtitle,
+                matches,
+            })
+        })
+        .collect();
+
+    results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.title.cmp(&b.title)));
+    results.truncate(limit);
+    Ok(results)
+}
+
+/// Remember a selection so it ranks higher next time
+#[tauri::command]
+pub async fn quick_switch_record(state: State<'_, QuickSwitchState>, id: String) -> Result<(), String> {
+    let now = unix_now();
+    state.usage.update(|usage| {
+        // Forget entries that no longer influence ranking
+        usage.retain(|_, u| now.saturating_sub(u.last_used) < USAGE_RETENTION_SECS);
+        let entry = usage.entry(id).or_default();
+        entry.count = entry.count.saturating_add(1);
+        entry.last_used = now;
+    })
+}
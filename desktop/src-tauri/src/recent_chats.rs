@@ -0,0 +1,231 @@
+// Recent Chats
+// The conversations that last saw a message, newest first, with how many
+// incoming messages arrived since the user last looked. Fed by the event
+// bridge and shown in the tray's "Recent Chats" submenu, from which a
+// conversation opens in the main window or, when enabled, in a small
+// quick-reply window that answers without bringing up the full UI.
+
+use crate::commands::DaemonState;
+use crate::connection::unix_now;
+use crate::content_filter;
+use crate::daemon::ChatMessage;
+use crate::notifications::NotificationKind;
+use crate::peer_index::PeerIndexState;
+use crate::store::JsonStore;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder};
+use ts_rs::TS;
+
+/// Emitted with the full list whenever it changes
+pub const CHANGED_EVENT: &str = "chat://recent";
+/// Emitted with a network id when the main window should show that conversation
+pub const OPEN_EVENT: &str = "chat://open";
+/// Emitted to the quick-reply window with its new `RecentChat` when it is retargeted
+pub const QUICK_REPLY_EVENT: &str = "chat://quick-reply";
+
+pub const QUICK_REPLY_WINDOW: &str = "quick-reply";
+
+const CHATS_FILE: &str = "recent_chats.json";
+
+/// Conversations remembered; the tray lists the first `TRAY_CHATS`
+const MAX_CHATS: usize = 20;
+pub const TRAY_CHATS: usize = 5;
+
+const MAX_PREVIEW_CHARS: usize = 80;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct RecentChat {
+    pub network_id: String,
+    pub network_name: String,
+    /// `None` when the last message was our own
+    pub last_sender: Option<String>,
+    /// Start of the last message, masked by the content filter
+    pub preview: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub last_at: u64,
+    pub unread: u32,
+}
+
+/// Managed state holding the recent conversations and the quick-reply target
+pub struct RecentChatsState {
+    chats: JsonStore<Vec<RecentChat>>,
+    quick_reply: Mutex<Option<String>>,
+}
+
+impl RecentChatsState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { chats: JsonStore::open(app, CHATS_FILE), quick_reply: Mutex::new(None) }
+    }
+
+    /// Newest first
+    pub fn list(&self) -> Vec<RecentChat> {
+        self.chats.get()
+    }
+
+    fn find(&self, network_id: &str) -> Option<RecentChat> {
+        self.chats.get().into_iter().find(|c| c.network_id == network_id)
+    }
+}
+
+fn preview(app: &AppHandle, network_id: &str, content: &str) -> String {
+    let masked = content_filter::mask_preview(app, NotificationKind::Message, Some(network_id), content);
+    let mut preview: String = masked.split_whitespace().collect::<Vec<_>>().join(" ");
+    if let Some((cut, _)) = preview.char_indices().nth(MAX_PREVIEW_CHARS) {
+        preview.truncate(cut);
+        preview.push('…');
+    }
+    preview
+}
+
+/// Publish the list to the frontend and the tray
+fn changed(app: &AppHandle, chats: &[RecentChat]) {
+    let _ = app.emit(CHANGED_EVENT, chats);
+    crate::tray::set_recent_chats(app, &chats[..chats.len().min(TRAY_CHATS)]);
+}
+
+/// Show the stored list in the tray, e.g. at startup or after a locale switch
+pub(crate) fn show_in_tray(app: &AppHandle) {
+    if let Some(state) = app.try_state::<RecentChatsState>() {
+        let chats = state.list();
+        crate::tray::set_recent_chats(app, &chats[..chats.len().min(TRAY_CHATS)]);
+    }
+}
+
+/// Move a conversation to the top for a new message; our own messages mark it read
+pub(crate) async fn observe(app: &AppHandle, network_id: &str, message: &ChatMessage, muted: bool) {
+    // Floods would push every other conversation out of the list
+    if muted {
+        return;
+    }
+    let state = app.state::<RecentChatsState>();
+    let daemon = app.state::<DaemonState>();
+    let known_name = state.find(network_id).map(|c| c.network_name);
+    let network_name = match known_name {
+        Some(name) => name,
+        None => daemon
+            .probe_networks()
+            .await
+            .ok()
+            .and_then(|networks| networks.into_iter().find(|n| n.network_id == network_id))
+            .map(|n| n.name)
+            .unwrap_or_else(|| network_id.to_string()),
+    };
+    let last_sender = if message.is_self {
+        None
+    } else {
+        let peer = app
+            .state::<PeerIndexState>()
+            .with_snapshot(&daemon, Some(network_id.to_string()), |s| s.peers.get(&message.peer_id).cloned())
+            .await
+            .ok()
+            .and_then(|(_, peer)| peer);
+        Some(
+            peer.map(|p| if p.display_name.is_empty() { p.name } else { p.display_name })
+                .unwrap_or_else(|| message.peer_id.clone()),
+        )
+    };
+
+    let preview = preview(app, network_id, &message.content);
+    let result = state.chats.update(|chats| {
+        let unread = chats.iter().find(|c| c.network_id == network_id).map_or(0, |c| c.unread);
+        chats.retain(|c| c.network_id != network_id);
+        chats.insert(
+            0,
+            RecentChat {
+                network_id: network_id.to_string(),
+                network_name,
+                unread: if message.is_self { 0 } else { unread + 1 },
+                last_sender,
+                preview,
+                last_at: unix_now(),
+            },
+        );
+        chats.truncate(MAX_CHATS);
+        chats.clone()
+    });
+    match result {
+        Ok(chats) => changed(app, &chats),
+        Err(e) => log::warn!("Failed to update recent chats: {}", e),
+    }
+}
+
+fn mark_read(app: &AppHandle, network_id: &str) -> Result<(), String> {
+    let state = app.state::<RecentChatsState>();
+    if state.find(network_id).is_none_or(|c| c.unread == 0) {
+        return Ok(());
+    }
+    let chats = state.chats.update(|chats| {
+        for chat in chats.iter_mut().filter(|c| c.network_id == network_id) {
+            chat.unread = 0;
+        }
+        chats.clone()
+    })?;
+    changed(app, &chats);
+    Ok(())
+}
+
+/// Bring up the main window on a conversation
+pub(crate) fn open_chat(app: &AppHandle, network_id: &str) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+    let _ = app.emit(OPEN_EVENT, network_id);
+    if let Err(e) = mark_read(app, network_id) {
+        log::warn!("Failed to mark chat read: {}", e);
+    }
+}
+
+/// Open the quick-reply window on a conversation, or point the open one at it
+pub(crate) fn open_quick_reply(app: &AppHandle, network_id: &str) -> Result<(), String> {
+    let state = app.state::<RecentChatsState>();
+    let chat = state.find(network_id).ok_or("no recent messages in this conversation")?;
+    *state.quick_reply.lock().unwrap() = Some(network_id.to_string());
+
+    if let Some(window) = app.get_webview_window(QUICK_REPLY_WINDOW) {
+        let _ = window.emit(QUICK_REPLY_EVENT, &chat);
+        let _ = window.show();
+        return window.set_focus().map_err(|e| e.to_string());
+    }
+    // The frontend renders the reply form instead of the app for this view
+    WebviewWindowBuilder::new(app, QUICK_REPLY_WINDOW, WebviewUrl::App("index.html?view=quick-reply".into()))
+        .title(format!("GoConnect - {}", chat.network_name))
+        .inner_size(420.0, 220.0)
+        .resizable(false)
+        .always_on_top(true)
+        .center()
+        .build()
+        .map(|_| ())
+        .map_err(|e| format!("failed to open the quick reply window: {}", e))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Newest first
+#[tauri::command]
+pub async fn list_recent_chats(state: State<'_, RecentChatsState>) -> Result<Vec<RecentChat>, String> {
+    Ok(state.list())
+}
+
+/// Clear a conversation's unread count once it is on screen
+#[tauri::command]
+pub async fn mark_chat_read(app: AppHandle, network_id: String) -> Result<(), String> {
+    mark_read(&app, &network_id)
+}
+
+#[tauri::command]
+pub async fn open_quick_reply_window(app: AppHandle, network_id: String) -> Result<(), String> {
+    open_quick_reply(&app, &network_id)
+}
+
+/// The conversation the quick-reply window was opened for
+#[tauri::command]
+pub async fn get_quick_reply_target(state: State<'_, RecentChatsState>) -> Result<Option<RecentChat>, String> {
+    let network_id = state.quick_reply.lock().unwrap().clone();
+    Ok(network_id.and_then(|id| state.find(&id)))
+}
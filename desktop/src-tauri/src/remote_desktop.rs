@@ -0,0 +1,243 @@
+// Remote Desktop Launcher
+// Opens the system RDP or VNC client against a peer's virtual IP. Each peer keeps
+// one saved profile per protocol (port, user name, full screen) that is created
+// on first launch and can be edited afterwards.
+
+use crate::addressing;
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::{data_dir, JsonStore};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::{AppHandle, State};
+use tauri_plugin_opener::OpenerExt;
+use ts_rs::TS;
+
+const PROFILES_FILE: &str = "remote_desktop_profiles.json";
+const RDP_FILES_DIR: &str = "remote_desktop";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum RemoteProtocol {
+    Rdp,
+    Vnc,
+}
+
+impl RemoteProtocol {
+    fn default_port(self) -> u16 {
+        match self {
+            RemoteProtocol::Rdp => 3389,
+            RemoteProtocol::Vnc => 5900,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct RemoteDesktopProfile {
+    pub peer_id: String,
+    pub protocol: RemoteProtocol,
+    /// Defaults to 3389 (RDP) or 5900 (VNC)
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Pre-filled in the client; passwords are always asked by the client
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub fullscreen: bool,
+    #[ts(type = "number | null")]
+    #[serde(default)]
+    pub last_used_at: Option<u64>,
+}
+
+impl RemoteDesktopProfile {
+    fn new(peer_id: String, protocol: RemoteProtocol) -> Self {
+        Self { peer_id, protocol, port: None, username: None, fullscreen: false, last_used_at: None }
+    }
+
+    fn port(&self) -> u16 {
+        self.port.unwrap_or(self.protocol.default_port())
+    }
+}
+
+/// Managed state holding the saved profiles
+pub struct RemoteDesktopState {
+    profiles: JsonStore<Vec<RemoteDesktopProfile>>,
+}
+
+impl RemoteDesktopState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { profiles: JsonStore::open(app, PROFILES_FILE) }
+    }
+
+    fn profile(&self, peer_id: &str, protocol: RemoteProtocol) -> RemoteDesktopProfile {
+        self.profiles
+            .get()
+            .into_iter()
+            .find(|p| p.peer_id == peer_id && p.protocol == protocol)
+            .unwrap_or_else(|| RemoteDesktopProfile::new(peer_id.to_string(), protocol))
+    }
+
+    fn save(&self, profile: RemoteDesktopProfile) -> Result<(), String> {
+        self.profiles.update(|profiles| {
+            profiles.retain(|p| !(p.peer_id == profile.peer_id && p.protocol == profile.protocol));
+            profiles.push(profile);
+        })
+    }
+}
+
+/// Write the .rdp connection file every RDP client understands
+fn write_rdp_file(app: &AppHandle, profile: &RemoteDesktopProfile, ip: &str) -> Result<PathBuf, String> {
+    let dir = data_dir(app).join(RDP_FILES_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let mut body = format!(
+        "full address:s:{}\r\nscreen mode id:i:{}\r\nprompt for credentials:i:1\r\n",
+        addressing::host_port(ip, profile.port()),
+        if profile.fullscreen { 2 } else { 1 }
+    );
+    if let Some(username) = profile.username.as_deref().filter(|u| !u.is_empty()) {
+        body.push_str(&format!("username:s:{}\r\n", username));
+    }
+    let path = dir.join(format!("{}.rdp", profile.peer_id));
+    std::fs::write(&path, body).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Start the first client that is installed, leaving it running on its own
+fn spawn_first(candidates: Vec<Command>) -> bool {
+    for mut command in candidates {
+        match command.spawn() {
+            Ok(mut child) => {
+                // Reap the client when it exits
+                std::thread::spawn(move || child.wait());
+                return true;
+            }
+            Err(e) => log::debug!("Remote desktop client {:?} unavailable: {}", command.get_program(), e),
+        }
+    }
+    false
+}
+
+fn command<I: AsRef<std::ffi::OsStr>>(program: &str, args: impl IntoIterator<Item = I>) -> Command {
+    let mut command = Command::new(program);
+    command.args(args);
+    command
+}
+
+fn launch_rdp(app: &AppHandle, rdp_file: &Path) -> Result<(), String> {
+    let candidates = if cfg!(windows) {
+        vec![command("mstsc", [rdp_file.as_os_str()])]
+    } else if cfg!(target_os = "linux") {
+        vec![
+            command("xfreerdp3", [rdp_file.as_os_str()]),
+            command("xfreerdp", [rdp_file.as_os_str()]),
+            command("remmina", ["-c".as_ref(), rdp_file.as_os_str()]),
+        ]
+    } else {
+        Vec::new()
+    };
+    if spawn_first(candidates) {
+        return Ok(());
+    }
+    // macOS, or no known client: whatever app handles .rdp files
+    app.opener()
+        .open_path(rdp_file.to_string_lossy(), None::<&str>)
+        .map_err(|e| format!("no RDP client found: {}", e))
+}
+
+fn launch_vnc(app: &AppHandle, profile: &RemoteDesktopProfile, ip: &str) -> Result<(), String> {
+    let user = profile.username.as_deref().filter(|u| !u.is_empty()).map(|u| format!("{}@", u)).unwrap_or_default();
+    let url = format!("vnc://{}{}", user, addressing::host_port(ip, profile.port()));
+    let mut candidates = Vec::new();
+    if !cfg!(target_os = "macos") {
+        // TigerVNC, TightVNC and RealVNC all take host::port
+        let mut viewer = command("vncviewer", [format!("{}::{}", addressing::host(ip), profile.port())]);
+        if profile.fullscreen {
+            viewer.arg("-FullScreen");
+        }
+        candidates.push(viewer);
+    }
+    if cfg!(target_os = "linux") {
+        candidates.push(command("remmina", ["-c".to_string(), url.clone()]));
+    }
+    if spawn_first(candidates) {
+        return Ok(());
+    }
+    // Screen Sharing on macOS handles vnc:// itself
+    app.opener().open_url(url, None::<&str>).map_err(|e| format!("no VNC client found: {}", e))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Open the RDP or VNC client against an online peer with its saved profile
+#[tauri::command]
+pub async fn launch_remote_desktop(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    state: State<'_, RemoteDesktopState>,
+    peer_id: String,
+    protocol: RemoteProtocol,
+) -> Result<RemoteDesktopProfile, String> {
+    let peers = daemon_call!(daemon, "get_peers", |client| client.get_peers())?;
+    let peer = peers
+        .into_iter()
+        .find(|p| p.id == peer_id && p.connected)
+        .ok_or("the peer is not online")?;
+
+    let mut profile = state.profile(&peer_id, protocol);
+    match protocol {
+        RemoteProtocol::Rdp => {
+            let rdp_file = write_rdp_file(&app, &profile, &peer.virtual_ip)?;
+            launch_rdp(&app, &rdp_file)?;
+        }
+        RemoteProtocol::Vnc => launch_vnc(&app, &profile, &peer.virtual_ip)?,
+    }
+    profile.last_used_at = Some(unix_now());
+    state.save(profile.clone())?;
+    Ok(profile)
+}
+
+/// Saved profiles, for one peer or all, most recently used first
+#[tauri::command]
+pub async fn list_remote_desktop_profiles(
+    state: State<'_, RemoteDesktopState>,
+    peer_id: Option<String>,
+) -> Result<Vec<RemoteDesktopProfile>, String> {
+    let mut profiles: Vec<_> = state
+        .profiles
+        .get()
+        .into_iter()
+        .filter(|p| peer_id.as_ref().is_none_or(|id| &p.peer_id == id))
+        .collect();
+    profiles.sort_by_key(|p| std::cmp::Reverse(p.last_used_at));
+    Ok(profiles)
+}
+
+#[tauri::command]
+pub async fn save_remote_desktop_profile(
+    state: State<'_, RemoteDesktopState>,
+    profile: RemoteDesktopProfile,
+) -> Result<(), String> {
+    if profile.port == Some(0) {
+        return Err("port must be between 1 and 65535".to_string());
+    }
+    let last_used_at = state.profile(&profile.peer_id, profile.protocol).last_used_at;
+    state.save(RemoteDesktopProfile { last_used_at, ..profile })
+}
+
+#[tauri::command]
+pub async fn delete_remote_desktop_profile(
+    app: AppHandle,
+    state: State<'_, RemoteDesktopState>,
+    peer_id: String,
+    protocol: RemoteProtocol,
+) -> Result<(), String> {
+    state.profiles.update(|profiles| profiles.retain(|p| !(p.peer_id == peer_id && p.protocol == protocol)))?;
+    if protocol == RemoteProtocol::Rdp {
+        let _ = std::fs::remove_file(data_dir(&app).join(RDP_FILES_DIR).join(format!("{}.rdp", peer_id)));
+    }
+    Ok(())
+}
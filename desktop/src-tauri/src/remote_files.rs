@@ -0,0 +1,95 @@
+// Remote File Browser
+// When the daemon runs on another host, the file to send has to be picked from
+// that host's file system: a path from the desktop's own file dialog means
+// nothing there. These commands browse the daemon's host, and sending checks
+// the path on the daemon first so a missing file or a folder is reported
+// clearly instead of as a failed transfer. Daemons without the browsing calls
+// are sent the path unchecked, as before. Every send in the app goes through
+// `send_file`, so none of them checks the desktop's own disk by mistake.
+
+use crate::activity::ActivityState;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::{DaemonClient, DaemonError, RemoteDirectory, RemoteFileEntry};
+use crate::endpoint;
+use tauri::{AppHandle, Manager, State};
+use tonic::Code;
+
+/// The daemon's view of `path`, or `None` when it predates the browsing calls
+async fn stat(client: &DaemonClient, path: &str) -> Result<Option<RemoteFileEntry>, DaemonError> {
+    match client.stat_remote_path(path).await {
+        Err(DaemonError::Rpc(status)) if status.code() == Code::Unimplemented => Ok(None),
+        result => result.map(Some),
+    }
+}
+
+/// Make sure `path` is a readable file on the daemon's host before sending it.
+/// A local daemon sees the same files, so those are checked on disk.
+pub(crate) async fn check_send_path(daemon: &DaemonState, path: &str) -> Result<(), String> {
+    if !endpoint::active().is_remote() {
+        return check_local(path);
+    }
+    let entry = daemon_call!(daemon, "stat_remote_path", |c| stat(&c, path))
+        .map_err(|e| format!("{} is not available on the daemon's host: {}", path, e))?;
+    check_entry(path, entry)
+}
+
+/// `check_send_path` with a client already connected, for headless actions
+pub(crate) async fn check_send_path_with(client: &DaemonClient, path: &str) -> Result<(), String> {
+    if !endpoint::active().is_remote() {
+        return check_local(path);
+    }
+    let entry = stat(client, path)
+        .await
+        .map_err(|e| format!("{} is not available on the daemon's host: {}", path, e))?;
+    check_entry(path, entry)
+}
+
+fn check_local(path: &str) -> Result<(), String> {
+    match std::fs::metadata(path) {
+        Ok(meta) if meta.is_dir() => Err(format!("{} is a folder, not a file", path)),
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("{}: {}", path, e)),
+    }
+}
+
+fn check_entry(path: &str, entry: Option<RemoteFileEntry>) -> Result<(), String> {
+    match entry {
+        Some(entry) if entry.is_dir => Err(format!("{} is a folder on the daemon's host, not a file", path)),
+        Some(entry) if !entry.readable => Err(format!("the daemon cannot read {}", path)),
+        _ => Ok(()),
+    }
+}
+
+/// Send `path` to `peer_id` once it checks out, noting it for the activity log
+pub(crate) async fn send_file(app: &AppHandle, peer_id: &str, path: &str) -> Result<String, String> {
+    let daemon = app.state::<DaemonState>();
+    check_send_path(&daemon, path).await?;
+    let transfer_id = daemon_call!(daemon, "send_file", |client| client.send_file(peer_id, path))?;
+    app.state::<ActivityState>().remember_path(&transfer_id, path);
+    Ok(transfer_id)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// List a directory on the daemon's host; no path for the daemon user's home
+#[tauri::command]
+pub async fn list_remote_directory(
+    state: State<'_, DaemonState>,
+    path: Option<String>,
+    include_hidden: Option<bool>,
+) -> Result<RemoteDirectory, String> {
+    let path = path.unwrap_or_default();
+    let include_hidden = include_hidden.unwrap_or(false);
+    let mut listing =
+        daemon_call!(state, "list_remote_directory", |c| c.list_remote_directory(&path, include_hidden))?;
+    // Folders first, then by name, the way file dialogs show them
+    listing.entries.sort_by_cached_key(|e| (!e.is_dir, e.name.to_lowercase()));
+    Ok(listing)
+}
+
+#[tauri::command]
+pub async fn stat_remote_path(state: State<'_, DaemonState>, path: String) -> Result<RemoteFileEntry, String> {
+    daemon_call!(state, "stat_remote_path", |client| client.stat_remote_path(&path))
+}
@@ -0,0 +1,277 @@
+// Usage Reports
+// Summaries of the local activity log over a period, with an optional
+// self-contained HTML export (printable to PDF from any browser).
+
+use crate::activity::ActivityState;
+use crate::connection::unix_now;
+use crate::store;
+use chrono::{Local, TimeZone};
+use std::collections::HashMap;
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const REPORTS_DIR: &str = "reports";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ReportPeriod {
+    Day,
+    Week,
+    Month,
+}
+
+impl ReportPeriod {
+    fn seconds(self) -> u64 {
+        match self {
+            Self::Day => 86_400,
+            Self::Week => 7 * 86_400,
+            Self::Month => 30 * 86_400,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Day => "Daily",
+            Self::Week => "Weekly",
+            Self::Month => "Monthly",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct NetworkUsage {
+    pub network: String,
+    #[ts(type = "number")]
+    pub connected_secs: u64,
+    #[ts(type = "number")]
+    pub bytes_sent: u64,
+    #[ts(type = "number")]
+    pub bytes_received: u64,
+    pub transfers: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct PeerUsage {
+    pub peer_id: String,
+    pub name: String,
+    #[ts(type = "number")]
+    pub bytes: u64,
+    pub transfers: u32,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TransferCounts {
+    pub completed: u32,
+    pub failed: u32,
+    pub cancelled: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct UsageReport {
+    pub period: ReportPeriod,
+    /// Unix timestamps (seconds) bounding the report
+    #[ts(type = "number")]
+    pub from: u64,
+    #[ts(type = "number")]
+    pub to: u64,
+    #[ts(type = "number")]
+    pub connected_secs: u64,
+    /// Sorted by connected time
+    pub networks: Vec<NetworkUsage>,
+    /// Peer with the most bytes transferred
+    pub busiest_peer: Option<PeerUsage>,
+    pub transfers: TransferCounts,
+    /// Set when an HTML export was requested
+    pub html_path: Option<String>,
+}
+
+fn build(state: &ActivityState, period: ReportPeriod) -> UsageReport {
+    let to = unix_now();
+    let from = to.saturating_sub(period.seconds());
+    let log = state.snapshot();
+
+    let mut networks: HashMap<String, NetworkUsage> = HashMap::new();
+    let mut connected_secs = 0;
+    for session in &log.sessions {
+        let secs = session.overlap(from, to);
+        if secs == 0 {
+            continue;
+        }
+        connected_secs += secs;
+        networks
+            .entry(session.network.clone())
+            .or_insert_with(|| NetworkUsage { network: session.network.clone(), ..Default::default() })
+            .connected_secs += secs;
+    }
+
+    let mut peers: HashMap<&str, PeerUsage> = HashMap::new();
+    let mut counts = TransferCounts::default();
+    for t in log.transfers.iter().filter(|t| (from..to).contains(&t.finished_at)) {
+        match t.status.as_str() {
+            "completed" => counts.completed += 1,
+            "failed" => counts.failed += 1,
+            _ => counts.cancelled += 1,
+        }
+
+        let usage = networks
+            .entry(t.network.clone())
+            .or_insert_with(|| NetworkUsage { network: t.network.clone(), ..Default::default() });
+        usage.transfers += 1;
+        if t.direction == "upload" {
+            usage.bytes_sent += t.bytes;
+        } else {
+            usage.bytes_received += t.bytes;
+        }
+
+        let peer = peers.entry(&t.peer_id).or_insert_with(|| PeerUsage {
+            peer_id: t.peer_id.clone(),
+            name: t.peer_name.clone(),
+            bytes: 0,
+            transfers: 0,
+        });
+        peer.bytes += t.bytes;
+        peer.transfers += 1;
+    }
+
+    let mut networks: Vec<NetworkUsage> = networks.into_values().collect();
+    networks.sort_by(|a, b| b.connected_secs.cmp(&a.connected_secs).then_with(|| a.network.cmp(&b.network)));
+    let busiest_peer = peers
+        .into_values()
+        .max_by(|a, b| a.bytes.cmp(&b.bytes).then_with(|| b.name.cmp(&a.name)));
+
+    UsageReport {
+        period,
+        from,
+        to,
+        connected_secs,
+        networks,
+        busiest_peer,
+        transfers: counts,
+        html_path: None,
+    }
+}
+
+// =============================================================================
+// HTML EXPORT
+// =============================================================================
+
+fn format_date(ts: u64) -> String {
+    Local
+        .timestamp_opt(ts as i64, 0)
+        .single()
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+fn format_duration(secs: u64) -> String {
+    format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+}
+
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_html(report: &UsageReport) -> String {
+    let rows: String = report
+        .networks
+        .iter()
+        .map(|n| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(if n.network.is_empty() { "(not connected)" } else { &n.network }),
+                format_duration(n.connected_secs),
+                format_bytes(n.bytes_sent),
+                format_bytes(n.bytes_received),
+                n.transfers
+            )
+        })
+        .collect();
+    let busiest = match &report.busiest_peer {
+        Some(p) => format!("{} ({}, {} transfers)", escape(&p.name), format_bytes(p.bytes), p.transfers),
+        None => "—".to_string(),
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html><head><meta charset="utf-8"><title>GoConnect {label} Report</title>
+<style>
+body {{ font-family: system-ui, sans-serif; margin: 2rem; color: #1f2937; }}
+h1 {{ margin-bottom: 0; }} .muted {{ color: #6b7280; }}
+table {{ border-collapse: collapse; width: 100%; margin-top: 1rem; }}
+th, td {{ text-align: left; padding: .4rem .6rem; border-bottom: 1px solid #e5e7eb; }}
+dl {{ display: grid; grid-template-columns: max-content 1fr; gap: .3rem 1.5rem; }}
+dt {{ font-weight: 600; }}
+</style></head>
+<body>
+<h1>GoConnect {label} Report</h1>
+<p class="muted">{from} – {to}</p>
+<dl>
+<dt>Time connected</dt><dd>{connected}</dd>
+<dt>Busiest peer</dt><dd>{busiest}</dd>
+<dt>Transfers</dt><dd>{completed} completed, {failed} failed, {cancelled} cancelled</dd>
+</dl>
+<table>
+<thead><tr><th>Network</th><th>Connected</th><th>Sent</th><th>Received</th><th>Transfers</th></tr></thead>
+<tbody>{rows}</tbody>
+</table>
+</body></html>
+"#,
+        label = report.period.label(),
+        from = format_date(report.from),
+        to = format_date(report.to),
+        connected = format_duration(report.connected_secs),
+        busiest = busiest,
+        completed = report.transfers.completed,
+        failed = report.transfers.failed,
+        cancelled = report.transfers.cancelled,
+        rows = rows,
+    )
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Build a report for the period ending now; `export_html` also writes it to the reports folder
+#[tauri::command]
+pub async fn generate_usage_report(
+    app: AppHandle,
+    state: State<'_, ActivityState>,
+    period: ReportPeriod,
+    export_html: Option<bool>,
+) -> Result<UsageReport, String> {
+    let mut report = build(&state, period);
+
+    if export_html.unwrap_or(false) {
+        let dir = store::data_dir(&app).join(REPORTS_DIR);
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        let stamp = Local::now().format("%Y%m%d-%H%M%S");
+        let path = dir.join(format!("usage-{}-{}.html", period.label().to_ascii_lowercase(), stamp));
+        std::fs::write(&path, render_html(&report)).map_err(|e| e.to_string())?;
+        report.html_path = Some(path.to_string_lossy().into_owned());
+    }
+    Ok(report)
+}
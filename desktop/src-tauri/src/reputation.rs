@@ -0,0 +1,355 @@
+// File Reputation
+// Optional lookup of received executables against a reputation service the
+// user sets up, VirusTotal or anything answering the same way, before they are
+// opened. The service is asked about the file's SHA-256 only, never sent the
+// file; its API key is kept in the system keyring (in memory only for portable
+// runs). Verdicts are kept per transfer and shown as `TransferInfo.reputation`.
+// Flagged files are never opened from the app; strict mode also refuses files
+// the service does not know or could not be asked about.
+//
+// An answer is read as VirusTotal's `data.attributes.last_analysis_stats`, or
+// as a plain `{"verdict": "clean" | "malicious" | "unknown"}`; 404 is unknown.
+
+use crate::checksums;
+use crate::connection::unix_now;
+use crate::daemon::TransferInfo;
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::store::JsonStore;
+use crate::transfer_files;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "reputation.json";
+const VERDICTS_FILE: &str = "file_reputation.json";
+
+const KEYRING_SERVICE: &str = "goconnect-reputation";
+const KEYRING_USER: &str = "api-key";
+
+/// Placeholder for the hash in `lookup_url`
+const HASH_PLACEHOLDER: &str = "{sha256}";
+
+/// Oldest verdicts are dropped beyond this many
+const MAX_VERDICTS: usize = 1000;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Extensions run or installed straight from a file manager
+const EXECUTABLE_EXTENSIONS: &[&str] = &[
+    "exe", "msi", "bat", "cmd", "com", "scr", "ps1", "vbs", "jar", "dll", "app", "dmg", "pkg", "sh", "run",
+    "appimage", "deb", "rpm",
+];
+
+/// Leading bytes of PE, ELF and Mach-O binaries
+const EXECUTABLE_MAGIC: &[&[u8]] = &[
+    b"MZ",
+    b"\x7fELF",
+    &[0xfe, 0xed, 0xfa, 0xce],
+    &[0xfe, 0xed, 0xfa, 0xcf],
+    &[0xce, 0xfa, 0xed, 0xfe],
+    &[0xcf, 0xfa, 0xed, 0xfe],
+    &[0xca, 0xfe, 0xba, 0xbe],
+];
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct ReputationSettings {
+    pub enabled: bool,
+    /// URL to look a hash up at, with `{sha256}` where the hash goes
+    pub lookup_url: String,
+    /// Header carrying the API key, e.g. `x-apikey` for VirusTotal
+    pub api_key_header: String,
+    /// Refuse to open executables the service does not vouch for
+    pub strict: bool,
+    /// Set when an API key is stored; the key itself is never returned
+    pub has_api_key: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ReputationVerdict {
+    Clean,
+    Malicious,
+    /// The service has no record of the file
+    Unknown,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct FileReputation {
+    pub transfer_id: String,
+    pub sha256: String,
+    pub verdict: ReputationVerdict,
+    /// Engines that flagged the file, when the service says
+    pub detections: Option<u32>,
+    /// Host of the service asked
+    pub service: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub checked_at: u64,
+}
+
+/// Managed state holding the settings and verdicts
+pub struct ReputationState {
+    settings: JsonStore<ReputationSettings>,
+    verdicts: JsonStore<Vec<FileReputation>>,
+    /// The API key of a portable run, which must not touch the host's keyring
+    session_key: Mutex<Option<String>>,
+    http: reqwest::Client,
+}
+
+impl ReputationState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            verdicts: JsonStore::open(app, VERDICTS_FILE),
+            session_key: Mutex::new(None),
+            http: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .user_agent(concat!("GoConnect-Desktop/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Set `reputation` on transfers whose file was looked up
+    pub(crate) fn annotate(&self, transfers: &mut [TransferInfo]) {
+        let verdicts = self.verdicts.get();
+        for transfer in transfers {
+            transfer.reputation = verdicts.iter().find(|v| v.transfer_id == transfer.id).map(|v| v.verdict);
+        }
+    }
+
+    fn verdict_of(&self, transfer_id: &str) -> Option<FileReputation> {
+        self.verdicts.get().into_iter().find(|v| v.transfer_id == transfer_id)
+    }
+
+    fn api_key(&self) -> Option<String> {
+        if crate::portable::enabled() {
+            return self.session_key.lock().unwrap().clone();
+        }
+        keyring_entry().ok()?.get_password().ok()
+    }
+
+    fn set_api_key(&self, key: Option<&str>) -> Result<(), String> {
+        if crate::portable::enabled() {
+            *self.session_key.lock().unwrap() = key.map(String::from);
+            return Ok(());
+        }
+        let entry = keyring_entry()?;
+        match key {
+            Some(key) => entry.set_password(key).map_err(|e| format!("failed to save to the keyring: {}", e)),
+            None => match entry.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(e) => Err(e.to_string()),
+            },
+        }
+    }
+
+    /// Ask the service about a hash
+    async fn lookup(&self, sha256: &str) -> Result<(ReputationVerdict, Option<u32>, String), String> {
+        let settings = self.settings.get();
+        let url = reqwest::Url::parse(&settings.lookup_url.replace(HASH_PLACEHOLDER, sha256))
+            .map_err(|e| format!("invalid lookup URL: {}", e))?;
+        let service = url.host_str().unwrap_or_default().to_string();
+        let mut request = self.http.get(url);
+        if let Some(key) = self.api_key().filter(|_| !settings.api_key_header.trim().is_empty()) {
+            request = request.header(settings.api_key_header.trim(), key);
+        }
+        let response = request.send().await.map_err(|e| {
+            log::warn!("Reputation lookup at {} failed: {}", service, e);
+            "the reputation service could not be reached".to_string()
+        })?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok((ReputationVerdict::Unknown, None, service));
+        }
+        if !status.is_success() {
+            return Err(format!("the reputation service answered with {}", status));
+        }
+        let body: serde_json::Value =
+            response.json().await.map_err(|e| format!("unexpected answer from the reputation service: {}", e))?;
+
+        if let Some(verdict) = body.get("verdict").and_then(|v| v.as_str()) {
+            let verdict = match verdict.to_lowercase().as_str() {
+                "clean" | "harmless" => ReputationVerdict::Clean,
+                "malicious" | "suspicious" => ReputationVerdict::Malicious,
+                _ => ReputationVerdict::Unknown,
+            };
+            return Ok((verdict, None, service));
+        }
+        let Some(stats) = body.pointer("/data/attributes/last_analysis_stats") else {
+            return Ok((ReputationVerdict::Unknown, None, service));
+        };
+        let count = |key: &str| stats.get(key).and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+        let detections = count("malicious") + count("suspicious");
+        let verdict = if detections > 0 { ReputationVerdict::Malicious } else { ReputationVerdict::Clean };
+        Ok((verdict, Some(detections), service))
+    }
+
+    fn store(&self, reputation: &FileReputation) -> Result<(), String> {
+        self.verdicts.update(|verdicts| {
+            verdicts.retain(|v| v.transfer_id != reputation.transfer_id);
+            verdicts.push(reputation.clone());
+            let excess = verdicts.len().saturating_sub(MAX_VERDICTS);
+            verdicts.drain(..excess);
+        })
+    }
+}
+
+fn keyring_entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER).map_err(|e| e.to_string())
+}
+
+/// Something the OS would run: by extension, or by the binary's header
+pub(crate) fn is_executable(path: &Path) -> bool {
+    let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    if EXECUTABLE_EXTENSIONS.contains(&extension.as_str()) {
+        return true;
+    }
+    let mut header = [0u8; 4];
+    let read = std::fs::File::open(path).and_then(|mut f| f.read(&mut header)).unwrap_or(0);
+    EXECUTABLE_MAGIC.iter().any(|magic| read >= magic.len() && header.starts_with(magic))
+}
+
+fn display_name(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+/// Look up a received file, hashing it here when the daemon reported no hash
+async fn check(
+    app: &AppHandle,
+    transfer_id: &str,
+    sha256: Option<String>,
+    path: &Path,
+) -> Result<FileReputation, String> {
+    let state = app.state::<ReputationState>();
+    let sha256 = match sha256 {
+        Some(hash) => hash,
+        None => {
+            let file = path.to_string_lossy().into_owned();
+            tokio::task::spawn_blocking(move || checksums::sha256_file(&file))
+                .await
+                .map_err(|e| e.to_string())?
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e))?
+        }
+    };
+    let (verdict, detections, service) = state.lookup(&sha256).await?;
+    let reputation = FileReputation {
+        transfer_id: transfer_id.to_string(),
+        sha256,
+        verdict,
+        detections,
+        service,
+        checked_at: unix_now(),
+    };
+    state.store(&reputation)?;
+    if verdict == ReputationVerdict::Malicious {
+        let body = i18n::text(Text::FileFlaggedBody)
+            .replace("{service}", &reputation.service)
+            .replace("{file}", &display_name(path));
+        notifications::notify(app, NotificationKind::Security, i18n::text(Text::FileFlaggedTitle), &body);
+    }
+    Ok(reputation)
+}
+
+/// Look up a download in the background once it is in place, if it is an executable
+pub(crate) fn on_download_saved(app: &AppHandle, transfer: &TransferInfo, path: &str) {
+    let Some(state) = app.try_state::<ReputationState>() else { return };
+    if !state.settings.get().enabled || !is_executable(Path::new(path)) {
+        return;
+    }
+    let (app, transfer, path) = (app.clone(), transfer.clone(), path.to_string());
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = check(&app, &transfer.id, transfer.sha256.clone(), Path::new(&path)).await {
+            log::warn!("Reputation lookup for transfer {} failed: {}", transfer.id, e);
+        }
+    });
+}
+
+/// Refuse to open a received executable the service flagged, or in strict mode did not clear
+pub(crate) async fn guard_open(
+    app: &AppHandle,
+    transfer_id: &str,
+    sha256: Option<String>,
+    path: &Path,
+) -> Result<(), String> {
+    let state = app.state::<ReputationState>();
+    let settings = state.settings.get();
+    if !settings.enabled || !is_executable(path) {
+        return Ok(());
+    }
+    let known = match state.verdict_of(transfer_id) {
+        Some(reputation) => Ok(reputation),
+        None => check(app, transfer_id, sha256, path).await,
+    };
+    let name = display_name(path);
+    match known {
+        Ok(r) if r.verdict == ReputationVerdict::Malicious => {
+            Err(format!("{} was flagged by {}; it will not be opened", name, r.service))
+        }
+        Ok(r) if r.verdict == ReputationVerdict::Unknown && settings.strict => {
+            Err(format!("{} is not known to {}; strict mode blocks opening it", name, r.service))
+        }
+        Err(e) if settings.strict => {
+            Err(format!("{} could not be checked ({}); strict mode blocks opening it", name, e))
+        }
+        Err(e) => {
+            log::warn!("Opening {} unchecked: {}", name, e);
+            Ok(())
+        }
+        Ok(_) => Ok(()),
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_reputation_settings(state: State<'_, ReputationState>) -> Result<ReputationSettings, String> {
+    Ok(state.settings.get())
+}
+
+/// Save the lookup settings. `api_key` replaces the stored key; an empty
+/// string removes it and omitting it keeps it.
+#[tauri::command]
+pub async fn set_reputation_settings(
+    state: State<'_, ReputationState>,
+    settings: ReputationSettings,
+    api_key: Option<String>,
+) -> Result<ReputationSettings, String> {
+    if settings.enabled {
+        if !settings.lookup_url.contains(HASH_PLACEHOLDER) {
+            return Err(format!("the lookup URL must contain {} where the hash goes", HASH_PLACEHOLDER));
+        }
+        let url = reqwest::Url::parse(&settings.lookup_url.replace(HASH_PLACEHOLDER, "0"))
+            .map_err(|e| format!("invalid lookup URL: {}", e))?;
+        if url.scheme() != "https" {
+            return Err("the lookup URL must use https".to_string());
+        }
+    }
+    if let Some(key) = api_key.as_deref() {
+        state.set_api_key(Some(key.trim()).filter(|k| !k.is_empty()))?;
+    }
+    let settings = ReputationSettings { has_api_key: state.api_key().is_some(), ..settings };
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
+
+/// Look up a received file now, whether or not it is an executable
+#[tauri::command]
+pub async fn check_transfer_reputation(app: AppHandle, transfer_id: String) -> Result<FileReputation, String> {
+    if !app.state::<ReputationState>().settings.get().enabled {
+        return Err("set up a reputation service first".to_string());
+    }
+    let path = transfer_files::saved_file(&app, &transfer_id)?;
+    let sha256 = transfer_files::reported_hash(&app, &transfer_id).await;
+    check(&app, &transfer_id, sha256, &path).await
+}
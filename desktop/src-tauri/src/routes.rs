@@ -0,0 +1,222 @@
+// Route Inspection
+// The routes GoConnect's tunnel installed and the routes of other interfaces
+// that compete with them. Another VPN (WireGuard, Tailscale, ZeroTier...) that
+// routes part of a GoConnect range, or the same range at a better metric, takes
+// that traffic; each overlap is annotated with how likely it is to break things.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::interfaces::{self, RouteEntry, SystemRoute};
+use crate::subnet::{interface_kind, InterfaceKind};
+use ipnet::IpNet;
+use std::net::IpAddr;
+use tauri::State;
+use ts_rs::TS;
+
+/// Interface name prefixes of VPNs worth naming in a warning
+const KNOWN_VPNS: &[(&str, &str)] = &[
+    ("tailscale", "Tailscale"),
+    ("wg", "WireGuard"),
+    ("nordlynx", "NordVPN"),
+    ("proton", "Proton VPN"),
+    ("mullvad", "Mullvad"),
+    ("zt", "ZeroTier"),
+    ("utun", "a macOS VPN"),
+    ("tun", "an OpenVPN-style VPN"),
+    ("tap", "an OpenVPN-style VPN"),
+    ("ppp", "a PPP/L2TP VPN"),
+    ("ipsec", "an IPsec VPN"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ConflictSeverity {
+    /// Worth knowing; unlikely to break anything
+    Low,
+    /// Can break traffic in some setups
+    Medium,
+    /// Traffic for (part of) the network goes elsewhere
+    High,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct InstalledRoute {
+    pub interface: String,
+    pub route: RouteEntry,
+    pub table: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct RouteConflict {
+    pub severity: ConflictSeverity,
+    /// The GoConnect route affected
+    pub route: String,
+    pub other_interface: String,
+    pub other_route: RouteEntry,
+    pub other_table: Option<String>,
+    /// Product name when the interface looks like a known VPN
+    pub other_vpn: Option<String>,
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct EffectiveRoutes {
+    pub tunnel_interfaces: Vec<String>,
+    pub routes: Vec<InstalledRoute>,
+    /// Most severe first
+    pub conflicts: Vec<RouteConflict>,
+}
+
+/// "default", bare addresses and CIDRs as a network
+pub(crate) fn parse_destination(route: &RouteEntry) -> Option<IpNet> {
+    match route.destination.as_str() {
+        // The family of a default route shows in its gateway
+        "default" if route.gateway.as_deref().is_some_and(|g| g.contains(':')) => "::/0".parse().ok(),
+        "default" => "0.0.0.0/0".parse().ok(),
+        d if d.contains('/') => d.parse::<IpNet>().ok().map(|n| n.trunc()),
+        d => d.parse::<IpAddr>().ok().map(IpNet::from),
+    }
+}
+
+fn scoped(net: &IpNet) -> bool {
+    match net.network() {
+        IpAddr::V4(ip) => ip.is_link_local() || ip.is_multicast(),
+        IpAddr::V6(ip) => (ip.segments()[0] & 0xffc0) == 0xfe80 || ip.is_multicast(),
+    }
+}
+
+pub(crate) fn vpn_name(interface: &str) -> Option<String> {
+    let lower = interface.to_ascii_lowercase();
+    KNOWN_VPNS
+        .iter()
+        .find(|(prefix, _)| lower.starts_with(prefix))
+        .map(|(_, name)| name.to_string())
+        .or_else(|| (interface_kind(interface) == InterfaceKind::Vpn).then(|| "a VPN".to_string()))
+}
+
+fn classify(ours: (&IpNet, &SystemRoute), other: (&IpNet, &SystemRoute)) -> Option<(ConflictSeverity, String)> {
+    let ((ours_net, ours_route), (other_net, other_route)) = (ours, other);
+    if !(ours_net.contains(other_net) || other_net.contains(ours_net)) {
+        return None;
+    }
+    let vpn = vpn_name(&other_route.interface).is_some();
+    let policy = other_route.table.is_some();
+
+    Some(if other_net.prefix_len() == 0 {
+        // Full-tunnel VPNs: the more specific GoConnect route wins in the main
+        // table, but a policy-routing table is consulted first
+        if policy {
+            (
+                ConflictSeverity::High,
+                "A default route in a policy-routing table can take GoConnect traffic before the main \
+                 table is consulted"
+                    .to_string(),
+            )
+        } else if vpn {
+            (
+                ConflictSeverity::Low,
+                "Another VPN carries all traffic; GoConnect's more specific route still wins".to_string(),
+            )
+        } else {
+            return None;
+        }
+    } else if other_net.prefix_len() > ours_net.prefix_len() {
+        (
+            ConflictSeverity::High,
+            format!("{} is more specific, so traffic to it bypasses GoConnect", other_net),
+        )
+    } else if other_net.prefix_len() == ours_net.prefix_len() {
+        let ours_metric = ours_route.route.metric.unwrap_or(0);
+        match other_route.route.metric {
+            Some(metric) if metric > ours_metric && !policy => (
+                ConflictSeverity::Medium,
+                "The same range is routed elsewhere at a worse metric; it takes over if GoConnect disconnects"
+                    .to_string(),
+            ),
+            _ => (
+                ConflictSeverity::High,
+                "The same range is routed elsewhere at an equal or better priority".to_string(),
+            ),
+        }
+    } else if vpn || policy {
+        (
+            ConflictSeverity::Medium,
+            format!("{} covers the GoConnect range; traffic falls back to it when GoConnect is down", other_net),
+        )
+    } else {
+        (
+            ConflictSeverity::Low,
+            format!("The local route {} overlaps the GoConnect range", other_net),
+        )
+    })
+}
+
+pub(crate) fn inspect(virtual_ips: &[IpAddr]) -> Result<EffectiveRoutes, String> {
+    let holders = interfaces::holders(virtual_ips)?;
+    let mut tunnel_interfaces: Vec<String> = holders.iter().map(|h| h.interface.clone()).collect();
+    tunnel_interfaces.sort();
+    tunnel_interfaces.dedup();
+
+    let table = interfaces::route_table()?;
+    let parsed: Vec<(IpNet, &SystemRoute)> = table
+        .iter()
+        .filter_map(|r| Some((parse_destination(&r.route)?, r)))
+        // Every interface has link-local and multicast routes; they never compete
+        .filter(|(net, _)| !scoped(net))
+        .collect();
+    let (ours, others): (Vec<_>, Vec<_>) =
+        parsed.into_iter().partition(|(_, r)| tunnel_interfaces.contains(&r.interface));
+
+    let mut conflicts = Vec::new();
+    for (ours_net, ours_route) in &ours {
+        for (other_net, other_route) in &others {
+            if let Some((severity, explanation)) = classify((ours_net, ours_route), (other_net, other_route)) {
+                conflicts.push(RouteConflict {
+                    severity,
+                    route: ours_net.to_string(),
+                    other_interface: other_route.interface.clone(),
+                    other_route: other_route.route.clone(),
+                    other_table: other_route.table.clone(),
+                    other_vpn: vpn_name(&other_route.interface),
+                    explanation,
+                });
+            }
+        }
+    }
+    conflicts.sort_by_key(|c| std::cmp::Reverse(c.severity));
+
+    Ok(EffectiveRoutes {
+        routes: ours
+            .into_iter()
+            .map(|(_, r)| InstalledRoute {
+                interface: r.interface.clone(),
+                route: r.route.clone(),
+                table: r.table.clone(),
+            })
+            .collect(),
+        tunnel_interfaces,
+        conflicts,
+    })
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Routes through the GoConnect tunnel and the other routes that overlap them
+#[tauri::command]
+pub async fn get_effective_routes(state: State<'_, DaemonState>) -> Result<EffectiveRoutes, String> {
+    let virtual_ips: Vec<IpAddr> = daemon_call!(state, "get_network_statuses", |client| client.get_network_statuses())?
+        .into_iter()
+        .flat_map(|n| n.addresses())
+        .collect();
+    if virtual_ips.is_empty() {
+        return Err("no network is connected".to_string());
+    }
+    tauri::async_runtime::spawn_blocking(move || inspect(&virtual_ips))
+        .await
+        .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,125 @@
+// Save Folders
+// Default download folders per peer or per network, e.g. everything from a
+// colleague or from the work network into ~/Work. A peer's folder wins over its
+// network's; the network is the current one when the sender is a member of it.
+// Files from anyone else go to the download folder. Auto-accepted files and
+// files accepted from the notification are saved there directly, and the
+// accept dialog opens there. Folders are held to the same bounds as any save
+// path (see `save_paths`), and are kept locally, not in the daemon's settings.
+
+use crate::auto_accept;
+use crate::commands::DaemonState;
+use crate::peer_index::PeerIndexState;
+use crate::save_paths;
+use crate::store::JsonStore;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const FOLDERS_FILE: &str = "save_folders.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SaveFolderScope {
+    Peer,
+    Network,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct SaveFolder {
+    pub scope: SaveFolderScope,
+    /// Peer or network id
+    pub id: String,
+    pub folder: String,
+}
+
+/// Managed state holding the assigned folders
+pub struct SaveFoldersState {
+    folders: JsonStore<Vec<SaveFolder>>,
+}
+
+impl SaveFoldersState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { folders: JsonStore::open(app, FOLDERS_FILE) }
+    }
+
+    fn find(&self, scope: SaveFolderScope, id: &str) -> Option<PathBuf> {
+        self.folders.get().into_iter().find(|f| f.scope == scope && f.id == id).map(|f| PathBuf::from(f.folder))
+    }
+}
+
+/// Every assigned folder, for telling where received files may be
+pub(crate) fn all(app: &AppHandle) -> Vec<PathBuf> {
+    let Some(state) = app.try_state::<SaveFoldersState>() else { return Vec::new() };
+    state.folders.get().into_iter().map(|f| PathBuf::from(f.folder)).collect()
+}
+
+/// The folder files from `peer_id` go to by default
+pub(crate) async fn folder_for(app: &AppHandle, peer_id: &str) -> Result<PathBuf, String> {
+    let state = app.state::<SaveFoldersState>();
+    if let Some(folder) = state.find(SaveFolderScope::Peer, peer_id) {
+        return Ok(folder);
+    }
+    let daemon = app.state::<DaemonState>();
+    let membership =
+        app.state::<PeerIndexState>().with_snapshot(&daemon, None, |s| s.peers.contains_key(peer_id)).await;
+    if let Ok((network_id, true)) = membership {
+        if let Some(folder) = state.find(SaveFolderScope::Network, &network_id) {
+            return Ok(folder);
+        }
+    }
+    auto_accept::download_dir(app)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn list_save_folders(state: State<'_, SaveFoldersState>) -> Result<Vec<SaveFolder>, String> {
+    Ok(state.folders.get())
+}
+
+/// Assign a default folder to a peer or network, replacing any it had
+#[tauri::command]
+pub async fn set_save_folder(
+    app: AppHandle,
+    state: State<'_, SaveFoldersState>,
+    scope: SaveFolderScope,
+    id: String,
+    folder: String,
+) -> Result<Vec<SaveFolder>, String> {
+    let folder = folder.trim().to_string();
+    let path = Path::new(&folder);
+    if !path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+        return Err("the folder must be an absolute path without '..'".to_string());
+    }
+    if !save_paths::within_allowed(&app, path) {
+        return Err(format!("{} is outside the download folder and the home folder", folder));
+    }
+    state.folders.update(|folders| {
+        folders.retain(|f| !(f.scope == scope && f.id == id));
+        folders.push(SaveFolder { scope, id, folder });
+    })?;
+    Ok(state.folders.get())
+}
+
+#[tauri::command]
+pub async fn remove_save_folder(
+    state: State<'_, SaveFoldersState>,
+    scope: SaveFolderScope,
+    id: String,
+) -> Result<Vec<SaveFolder>, String> {
+    state.folders.update(|folders| folders.retain(|f| !(f.scope == scope && f.id == id)))?;
+    Ok(state.folders.get())
+}
+
+/// Where the accept dialog should suggest saving a file from `peer_id`
+#[tauri::command]
+pub async fn default_save_path(app: AppHandle, peer_id: String, file_name: String) -> Result<String, String> {
+    let name = Path::new(&file_name).file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let folder = folder_for(&app, &peer_id).await?;
+    Ok(folder.join(save_paths::sanitize_file_name(&name)).to_string_lossy().into_owned())
+}
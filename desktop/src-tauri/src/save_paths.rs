@@ -0,0 +1,249 @@
+// Save Paths
+// Every path an incoming file is accepted into is checked here before the
+// daemon is told to write it: it must be absolute, free of `..` and inside the
+// download folder or the user's home, its file name is stripped of characters
+// the OS does not allow in names, and there must be room for the file.
+// A file already at that path is handled by the collision policy: overwritten
+// (keeping the old one as a version), kept by numbering the new one
+// `name (n).ext`, kept by declining the transfer, or left for the user to
+// decide. The policy is a setting and can be overridden for a single transfer.
+// The daemon writes to a `.part` file that is renamed into place once complete
+// (see `part_files`).
+// Sync pairs write into their own folders and do not come through here.
+
+use crate::activity::ActivityState;
+use crate::auto_accept;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::TransferInfo;
+use crate::lifecycle::command;
+use crate::part_files;
+use crate::report::format_bytes;
+use crate::store::JsonStore;
+use crate::versions::{self, VersionSource};
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "save_paths.json";
+
+/// Used when nothing of the sender's file name survives
+const FALLBACK_NAME: &str = "download";
+
+/// Names Windows reserves for devices, with or without an extension
+const WINDOWS_RESERVED: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9", "LPT1",
+    "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// What to do when a file is already where an incoming one is to be saved
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum CollisionPolicy {
+    /// Replace it, keeping the old file as a version
+    Overwrite,
+    /// Save the new file as `name (n).ext`
+    #[default]
+    Rename,
+    /// Decline the transfer
+    Skip,
+    /// Leave the transfer pending and ask
+    Ask,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct SavePathSettings {
+    pub collision_policy: CollisionPolicy,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+#[ts(export)]
+pub enum AcceptOutcome {
+    /// Being saved at `path`; `replaced` when it overwrites an existing file
+    Accepted { path: String, replaced: bool },
+    /// A file was already there, so the transfer was declined
+    Skipped { existing: String },
+    /// Nothing was done; ask, then accept again with a collision policy
+    NeedsDecision { existing: String },
+}
+
+/// Managed state holding the settings
+pub struct SavePathsState {
+    settings: JsonStore<SavePathSettings>,
+}
+
+impl SavePathsState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE) }
+    }
+}
+
+/// A file name this OS accepts, with reserved characters replaced by `_`
+pub(crate) fn sanitize_file_name(name: &str) -> String {
+    let reserved: &[char] = if cfg!(windows) {
+        &['<', '>', ':', '"', '/', '\\', '|', '?', '*']
+    } else if cfg!(target_os = "macos") {
+        &['/', ':']
+    } else {
+        &['/']
+    };
+    let mut clean: String =
+        name.chars().map(|c| if c.is_control() || reserved.contains(&c) { '_' } else { c }).collect();
+    if cfg!(windows) {
+        // Windows drops trailing dots and spaces, and refuses device names
+        clean = clean.trim_end_matches(['.', ' ']).to_string();
+        let stem = clean.split('.').next().unwrap_or_default();
+        if WINDOWS_RESERVED.iter().any(|r| r.eq_ignore_ascii_case(stem)) {
+            clean.insert(stem.len(), '_');
+        }
+    }
+    if clean.is_empty() || clean == "." || clean == ".." {
+        FALLBACK_NAME.to_string()
+    } else {
+        clean
+    }
+}
+
+/// `target`, or `name (n).ext` next to it for the first n not taken yet
+pub(crate) fn unique_path(target: &Path) -> PathBuf {
+    let (Some(dir), Some(name)) = (target.parent(), target.file_name().and_then(|n| n.to_str())) else {
+        return target.to_path_buf();
+    };
+    let (stem, ext) = match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => (stem, format!(".{}", ext)),
+        _ => (name, String::new()),
+    };
+    (1..)
+        .map(|n| dir.join(format!("{} ({}){}", stem, n, ext)))
+        .find(|path| !path.exists())
+        .unwrap_or_else(|| target.to_path_buf())
+}
+
+/// Folders files may be saved under, resolved
+fn allowed_bases(app: &AppHandle) -> Vec<PathBuf> {
+    let download = auto_accept::download_dir(app).ok();
+    download.into_iter().chain(dirs::home_dir()).filter_map(|dir| std::fs::canonicalize(dir).ok()).collect()
+}
+
+/// `dir`, or the nearest folder above it that exists, resolves inside the allowed folders
+pub(crate) fn within_allowed(app: &AppHandle, dir: &Path) -> bool {
+    let bases = allowed_bases(app);
+    let existing = dir.ancestors().find_map(|dir| std::fs::canonicalize(dir).ok());
+    existing.is_some_and(|dir| bases.iter().any(|base| dir.starts_with(base)))
+}
+
+/// Bytes free on the volume holding `dir`; `None` when it cannot be told
+fn free_space(dir: &Path) -> Option<u64> {
+    let output = if cfg!(windows) {
+        let root = dir.ancestors().last()?.to_string_lossy().into_owned();
+        let script = "[System.IO.DriveInfo]::new($env:GOCONNECT_DRIVE).AvailableFreeSpace";
+        command("powershell", &["-NoProfile", "-Command", script]).env("GOCONNECT_DRIVE", root).output().ok()?
+    } else {
+        command("df", &["-P", "-k"]).arg(dir).output().ok()?
+    };
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if cfg!(windows) {
+        stdout.trim().parse().ok()
+    } else {
+        // Filesystem 1024-blocks Used Available Capacity Mounted-on
+        let available: u64 = stdout.lines().nth(1)?.split_whitespace().nth(3)?.parse().ok()?;
+        Some(available * 1024)
+    }
+}
+
+/// Check `save_path` for a file of `size` bytes; returns it with the file name sanitized
+fn check(app: &AppHandle, save_path: &str, size: u64) -> Result<PathBuf, String> {
+    let path = Path::new(save_path.trim());
+    if !path.is_absolute() {
+        return Err("the save path must be absolute".to_string());
+    }
+    if path.components().any(|c| c == Component::ParentDir) {
+        return Err("the save path must not contain '..'".to_string());
+    }
+    let name = path.file_name().ok_or("the save path has no file name")?;
+    let name = sanitize_file_name(&name.to_string_lossy());
+    let parent = path.parent().ok_or("the save path has no folder")?;
+
+    // Checked before creating anything, and again once symlinks can be resolved
+    if !within_allowed(app, parent) {
+        return Err(format!("{} is outside the download folder and the home folder", parent.display()));
+    }
+    std::fs::create_dir_all(parent).map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+    let resolved = std::fs::canonicalize(parent).map_err(|e| format!("failed to resolve {}: {}", parent.display(), e))?;
+    if !within_allowed(app, &resolved) {
+        return Err(format!("{} leads outside the download folder and the home folder", path.display()));
+    }
+
+    if let Some(free) = free_space(&resolved) {
+        if free < size {
+            return Err(format!("not enough space: {} needed, {} free", format_bytes(size), format_bytes(free)));
+        }
+    }
+    Ok(parent.join(name))
+}
+
+/// Accept an incoming transfer into `save_path`, minding what is already there.
+/// `policy` overrides the configured collision policy for this transfer.
+pub(crate) async fn accept(
+    app: &AppHandle,
+    transfer: &TransferInfo,
+    save_path: &str,
+    policy: Option<CollisionPolicy>,
+) -> Result<AcceptOutcome, String> {
+    let target = check(app, save_path, transfer.file_size)?;
+    let policy = policy.unwrap_or_else(|| app.state::<SavePathsState>().settings.get().collision_policy);
+    let existing = target.to_string_lossy().into_owned();
+    let daemon = app.state::<DaemonState>();
+
+    let (target, replaced) = match policy {
+        _ if !target.exists() => (target, false),
+        CollisionPolicy::Overwrite if target.is_file() => {
+            // Keep the file being replaced so the overwrite can be undone
+            if let Err(e) = versions::snapshot_before_overwrite(app, &target, VersionSource::Transfer).await {
+                log::warn!("Failed to keep previous version of {}: {}", existing, e);
+            }
+            (target, true)
+        }
+        // A folder in the way is never overwritten
+        CollisionPolicy::Overwrite | CollisionPolicy::Rename => (unique_path(&target), false),
+        CollisionPolicy::Skip => {
+            daemon_call!(daemon, "reject_transfer", |client| client.reject_transfer(&transfer.id))?;
+            log::info!("Declined {}: {} already exists", transfer.file_name, existing);
+            return Ok(AcceptOutcome::Skipped { existing });
+        }
+        CollisionPolicy::Ask => return Ok(AcceptOutcome::NeedsDecision { existing }),
+    };
+
+    // Written under a temporary name and renamed into place once complete
+    let part = part_files::part_path(&target);
+    let part_path = part.to_string_lossy().into_owned();
+    daemon_call!(daemon, "accept_transfer", |client| client.accept_transfer(&transfer.id, &part_path))?;
+    part_files::track(app, &transfer.id, &part, &target, replaced);
+    let path = target.to_string_lossy().into_owned();
+    app.state::<ActivityState>().remember_path(&transfer.id, &path);
+    Ok(AcceptOutcome::Accepted { path, replaced })
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_save_path_settings(state: State<'_, SavePathsState>) -> Result<SavePathSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_save_path_settings(
+    state: State<'_, SavePathsState>,
+    settings: SavePathSettings,
+) -> Result<SavePathSettings, String> {
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
@@ -0,0 +1,297 @@
+// Transfer Scheduler
+// Sends queued to start later: at a given time, inside a daily off-peak window
+// (e.g. 22:00-06:00), or both. Due sends are handed to the daemon while
+// connected; ones that cannot start yet (peer offline) are retried each tick.
+
+use crate::commands::DaemonState;
+use crate::connection::unix_now;
+use crate::guests;
+use crate::remote_files;
+use crate::store::JsonStore;
+use crate::undo::{self, Reversal};
+use chrono::{NaiveTime, Timelike};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SCHEDULE_FILE: &str = "scheduled_transfers.json";
+
+const TICK: Duration = Duration::from_secs(30);
+
+/// Started and failed entries are kept this long for the list view
+const FINISHED_RETENTION_SECS: u64 = 7 * 86_400;
+
+/// Daily local-time window; `end` before `start` wraps past midnight
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct TimeWindow {
+    /// "HH:MM"
+    pub start: String,
+    /// "HH:MM"
+    pub end: String,
+}
+
+impl TimeWindow {
+    fn parse(value: &str) -> Result<NaiveTime, String> {
+        NaiveTime::parse_from_str(value.trim(), "%H:%M").map_err(|_| format!("'{}' is not a HH:MM time", value))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        Self::parse(&self.start)?;
+        Self::parse(&self.end)?;
+        Ok(())
+    }
+
+    fn contains(&self, now: NaiveTime) -> bool {
+        let (Ok(start), Ok(end)) = (Self::parse(&self.start), Self::parse(&self.end)) else { return false };
+        let now = now.with_second(0).unwrap_or(now);
+        if start <= end {
+            start <= now && now < end
+        } else {
+            now >= start || now < end
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ScheduleStatus {
+    Waiting,
+    Started,
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ScheduledTransfer {
+    pub id: String,
+    pub peer_id: String,
+    pub path: String,
+    /// Not before this time
+    #[ts(type = "number | null")]
+    pub start_at: Option<u64>,
+    /// Only while inside this window
+    pub window: Option<TimeWindow>,
+    pub status: ScheduleStatus,
+    /// Daemon transfer id once started
+    pub transfer_id: Option<String>,
+    pub last_error: Option<String>,
+    #[ts(type = "number")]
+    pub created_at: u64,
+    #[ts(type = "number | null")]
+    pub finished_at: Option<u64>,
+}
+
+impl ScheduledTransfer {
+    fn is_due(&self, now: u64, local: NaiveTime) -> bool {
+        self.status == ScheduleStatus::Waiting
+            && self.start_at.is_none_or(|t| now >= t)
+            && self.window.as_ref().is_none_or(|w| w.contains(local))
+    }
+}
+
+/// Managed state holding the queue
+pub struct SchedulerState {
+    queue: JsonStore<Vec<ScheduledTransfer>>,
+    changed: tokio::sync::Notify,
+}
+
+impl SchedulerState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { queue: JsonStore::open(app, SCHEDULE_FILE), changed: tokio::sync::Notify::new() }
+    }
+
+    fn get(&self, id: &str) -> Result<ScheduledTransfer, String> {
+        self.queue
+            .get()
+            .into_iter()
+            .find(|s| s.id == id)
+            .ok_or_else(|| format!("scheduled transfer '{}' not found", id))
+    }
+
+    /// Put a cancelled entry back
+    pub(crate) fn restore(&self, entry: ScheduledTransfer) -> Result<(), String> {
+        self.queue.update(|queue| {
+            if !queue.iter().any(|s| s.id == entry.id) {
+                queue.push(entry);
+            }
+        })?;
+        self.changed.notify_one();
+        Ok(())
+    }
+
+    /// Replace an entry in place; one cancelled meanwhile stays gone
+    fn save(&self, entry: ScheduledTransfer) -> Result<(), String> {
+        self.queue.update(|queue| {
+            if let Some(existing) = queue.iter_mut().find(|s| s.id == entry.id) {
+                *existing = entry;
+            }
+        })
+    }
+}
+
+/// Hand one entry to the daemon. A file the daemon cannot send fails it for good;
+/// anything else (peer offline, daemon busy) leaves it waiting for the next tick.
+async fn start(app: &AppHandle, mut entry: ScheduledTransfer) -> ScheduledTransfer {
+    let daemon = app.state::<DaemonState>();
+    if let Err(e) = remote_files::check_send_path(&daemon, &entry.path).await {
+        // An unreachable remote daemon cannot say whether the file is gone
+        if daemon.connection.state().is_usable() {
+            entry.status = ScheduleStatus::Failed;
+            entry.finished_at = Some(unix_now());
+        }
+        entry.last_error = Some(e);
+        return entry;
+    }
+    match remote_files::send_file(app, &entry.peer_id, &entry.path).await {
+        Ok(transfer_id) => {
+            log::info!("Scheduled transfer {} started as {}", entry.id, transfer_id);
+            entry.status = ScheduleStatus::Started;
+            entry.transfer_id = Some(transfer_id);
+            entry.last_error = None;
+            entry.finished_at = Some(unix_now());
+        }
+        Err(e) => entry.last_error = Some(e),
+    }
+    entry
+}
+
+/// Start due entries, prune old finished ones and end expired guest memberships
+async fn tick(app: &AppHandle) {
+    guests::expire_due(app).await;
+
+    let state = app.state::<SchedulerState>();
+    let now = unix_now();
+    let local = chrono::Local::now().time();
+
+    let due: Vec<ScheduledTransfer> = state.queue.get().into_iter().filter(|s| s.is_due(now, local)).collect();
+    for entry in due {
+        let entry = start(app, entry).await;
+        if let Err(e) = state.save(entry) {
+            log::warn!("Failed to update scheduled transfer: {}", e);
+        }
+    }
+
+    let cutoff = now.saturating_sub(FINISHED_RETENTION_SECS);
+    let result = state.queue.update(|queue| queue.retain(|s| s.finished_at.is_none_or(|t| t >= cutoff)));
+    if let Err(e) = result {
+        log::warn!("Failed to prune scheduled transfers: {}", e);
+    }
+}
+
+/// Run the scheduler loop
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<SchedulerState>();
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(TICK) => {}
+                _ = state.changed.notified() => {}
+            }
+            if app.state::<DaemonState>().connection.state().is_usable() {
+                tick(&app).await;
+            }
+        }
+    });
+}
+
+fn validate(start_at: Option<u64>, window: Option<&TimeWindow>) -> Result<(), String> {
+    if start_at.is_none() && window.is_none() {
+        return Err("give a start time, a time window, or both".to_string());
+    }
+    window.map_or(Ok(()), TimeWindow::validate)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn schedule_transfer(
+    state: State<'_, SchedulerState>,
+    daemon: State<'_, DaemonState>,
+    peer_id: String,
+    path: String,
+    start_at: Option<u64>,
+    window: Option<TimeWindow>,
+) -> Result<ScheduledTransfer, String> {
+    validate(start_at, window.as_ref())?;
+    remote_files::check_send_path(&daemon, &path).await?;
+    let entry = ScheduledTransfer {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        peer_id,
+        path,
+        start_at,
+        window,
+        status: ScheduleStatus::Waiting,
+        transfer_id: None,
+        last_error: None,
+        created_at: unix_now(),
+        finished_at: None,
+    };
+    state.queue.update(|queue| queue.push(entry.clone()))?;
+    state.changed.notify_one();
+    Ok(entry)
+}
+
+/// Waiting entries first, each group in creation order
+#[tauri::command]
+pub async fn list_scheduled_transfers(state: State<'_, SchedulerState>) -> Result<Vec<ScheduledTransfer>, String> {
+    let mut queue = state.queue.get();
+    queue.sort_by_key(|s| (s.status != ScheduleStatus::Waiting, s.created_at));
+    Ok(queue)
+}
+
+/// Change when a waiting entry may start
+#[tauri::command]
+pub async fn update_scheduled_transfer(
+    state: State<'_, SchedulerState>,
+    id: String,
+    start_at: Option<u64>,
+    window: Option<TimeWindow>,
+) -> Result<ScheduledTransfer, String> {
+    validate(start_at, window.as_ref())?;
+    let mut entry = state.get(&id)?;
+    if entry.status != ScheduleStatus::Waiting {
+        return Err("this transfer has already started".to_string());
+    }
+    entry.start_at = start_at;
+    entry.window = window;
+    state.save(entry.clone())?;
+    state.changed.notify_one();
+    Ok(entry)
+}
+
+#[tauri::command]
+pub async fn cancel_scheduled_transfer(
+    app: AppHandle,
+    state: State<'_, SchedulerState>,
+    id: String,
+) -> Result<(), String> {
+    let entry = state.get(&id)?;
+    state.queue.update(|queue| queue.retain(|s| s.id != id))?;
+    let label = format!("Cancelled scheduled transfer of {}", entry.path);
+    undo::record(&app, label, Reversal::Schedule(entry));
+    Ok(())
+}
+
+/// Start a waiting entry now, ignoring its time and window
+#[tauri::command]
+pub async fn run_scheduled_transfer_now(
+    app: AppHandle,
+    state: State<'_, SchedulerState>,
+    id: String,
+) -> Result<ScheduledTransfer, String> {
+    let entry = state.get(&id)?;
+    if entry.status != ScheduleStatus::Waiting {
+        return Err("this transfer has already started".to_string());
+    }
+    let entry = start(&app, entry).await;
+    state.save(entry.clone())?;
+    match (&entry.status, &entry.last_error) {
+        (ScheduleStatus::Started, _) => Ok(entry),
+        (_, Some(e)) => Err(e.clone()),
+        _ => Ok(entry),
+    }
+}
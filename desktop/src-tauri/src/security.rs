@@ -0,0 +1,110 @@
+// Security Feed
+// Security-relevant events reported by the daemon (a new device on the account,
+// a peer whose key changed, a local client calling the daemon with a wrong IPC
+// token) arrive on their own subscription, separate from the general event
+// stream so they are never dropped along with it. Each is kept in a local feed,
+// re-emitted as `SECURITY_EVENT` and raised as a security notification.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::{SecurityEvent, SecurityEventKind};
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::store::JsonStore;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+/// Emitted with each new `SecurityEvent`
+pub const SECURITY_EVENT: &str = "security://event";
+
+const FEED_FILE: &str = "security_events.json";
+
+/// Oldest events are dropped beyond this many
+const MAX_EVENTS: usize = 500;
+
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// Managed state holding the feed, oldest first
+pub struct SecurityFeedState {
+    feed: JsonStore<Vec<SecurityEvent>>,
+}
+
+impl SecurityFeedState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { feed: JsonStore::open(app, FEED_FILE) }
+    }
+
+    /// Add an event unless it is already in the feed, as after resubscribing; true when added
+    fn record(&self, event: &SecurityEvent) -> Result<bool, String> {
+        self.feed.update(|feed| {
+            if !event.id.is_empty() && feed.iter().any(|e| e.id == event.id) {
+                return false;
+            }
+            feed.push(event.clone());
+            let excess = feed.len().saturating_sub(MAX_EVENTS);
+            feed.drain(..excess);
+            true
+        })
+    }
+}
+
+fn title(kind: SecurityEventKind) -> &'static str {
+    i18n::text(match kind {
+        SecurityEventKind::DeviceAdded => Text::SecurityDeviceAdded,
+        SecurityEventKind::PeerKeyChanged => Text::SecurityPeerKeyChanged,
+        SecurityEventKind::IpcAuthFailed => Text::SecurityIpcAuthFailed,
+        SecurityEventKind::Other => Text::SecurityOther,
+    })
+}
+
+fn announce(app: &AppHandle, event: SecurityEvent) {
+    match app.state::<SecurityFeedState>().record(&event) {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(e) => log::warn!("Failed to record security event: {}", e),
+    }
+    log::warn!("Security event {:?}: {}", event.kind, event.message);
+    notifications::notify(app, NotificationKind::Security, title(event.kind), &event.message);
+    let _ = app.emit(SECURITY_EVENT, event);
+}
+
+/// Follow the daemon's security events for the life of the app
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        loop {
+            let subscribed = daemon_call!(daemon, "subscribe_security_events", |c| c.subscribe_security_events());
+            if let Ok(mut events) = subscribed {
+                while let Ok(Some(event)) = events.next().await {
+                    announce(&app, event);
+                }
+            }
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Newest first, optionally only one kind
+#[tauri::command]
+pub async fn list_security_events(
+    state: State<'_, SecurityFeedState>,
+    kind: Option<SecurityEventKind>,
+    limit: Option<usize>,
+) -> Result<Vec<SecurityEvent>, String> {
+    Ok(state
+        .feed
+        .get()
+        .into_iter()
+        .rev()
+        .filter(|e| kind.is_none_or(|kind| e.kind == kind))
+        .take(limit.unwrap_or(MAX_EVENTS))
+        .collect())
+}
+
+#[tauri::command]
+pub async fn clear_security_events(state: State<'_, SecurityFeedState>) -> Result<(), String> {
+    state.feed.set(Vec::new())
+}
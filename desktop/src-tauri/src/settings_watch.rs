@@ -0,0 +1,76 @@
+// Settings Propagation
+// Settings can change outside this window: from the CLI, another desktop
+// client or the daemon itself. The daemon's settings stream is followed and
+// every change is applied here without a restart: the held settings copy, the
+// theme, the language (which relabels the tray) and the autostart entry are
+// brought up to date, and `CHANGED_EVENT` tells the frontend. Daemons without
+// the stream are polled.
+
+use crate::autostart;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::{DaemonError, Settings};
+use crate::i18n;
+use crate::optimistic::{self, OptimisticState};
+use crate::theme;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tonic::Code;
+
+/// Emitted with the new `Settings` whenever they change
+pub const CHANGED_EVENT: &str = "settings://changed";
+
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// How often daemons without the settings stream are asked instead
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Apply settings that differ from the last ones seen
+fn observe(app: &AppHandle, last: &mut Option<Settings>, settings: Settings) {
+    if last.as_ref() == Some(&settings) {
+        return;
+    }
+    // The autostart entry may be stale from a change made while the app was not running
+    autostart::adopt(&settings);
+    // The first settings seen are what everything was set up with
+    let changed = last.is_some();
+    *last = Some(settings.clone());
+    if !changed {
+        return;
+    }
+    log::debug!("Settings changed");
+    optimistic::adopt_settings(&app.state::<OptimisticState>(), &settings);
+    theme::adopt(app, &settings.theme);
+    i18n::adopt(app, &settings.language);
+    let _ = app.emit(CHANGED_EVENT, settings);
+}
+
+/// Follow the daemon's settings for the life of the app
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let daemon = app.state::<DaemonState>();
+        let mut last: Option<Settings> = None;
+        loop {
+            let subscribed = daemon_call!(daemon, "subscribe_settings", |c| async {
+                match c.subscribe_settings().await {
+                    Err(DaemonError::Rpc(status)) if status.code() == Code::Unimplemented => Ok(None),
+                    result => result.map(Some),
+                }
+            });
+            match subscribed {
+                Ok(Some(mut stream)) => {
+                    while let Ok(Some(settings)) = stream.next().await {
+                        observe(&app, &mut last, settings);
+                    }
+                    tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                }
+                Ok(None) => {
+                    if let Ok(settings) = daemon_call!(daemon, "get_settings", |client| client.get_settings()) {
+                        observe(&app, &mut last, settings);
+                    }
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+                Err(_) => tokio::time::sleep(RESUBSCRIBE_DELAY).await,
+            }
+        }
+    });
+}
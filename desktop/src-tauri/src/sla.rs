@@ -0,0 +1,315 @@
+// Critical Peer SLA Alerts
+// Peers the user marks as critical are checked against alert rules such as
+// "offline for more than 5 minutes" or "latency above 200 ms for 10 minutes".
+// A rule fires once per episode with a notification and an `sla_breach` webhook,
+// and the episode is closed (with an `sla_resolved` webhook) when it clears.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::PeerInfo;
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::store::JsonStore;
+use crate::webhooks::{self, EventData, WebhookEvent};
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "sla.json";
+const HISTORY_FILE: &str = "sla_alerts.json";
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Only the newest alerts are kept
+const MAX_ALERTS: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(export)]
+pub enum SlaCondition {
+    Offline,
+    LatencyAbove { ms: u32 },
+}
+
+impl SlaCondition {
+    fn holds(self, peer: Option<&PeerInfo>) -> bool {
+        match self {
+            SlaCondition::Offline => !peer.is_some_and(|p| p.connected),
+            // Unknown latency (0) never counts as a breach
+            SlaCondition::LatencyAbove { ms } => {
+                peer.is_some_and(|p| p.connected && p.latency_ms > 0 && p.latency_ms > i64::from(ms))
+            }
+        }
+    }
+
+    fn describe(self, minutes: u32) -> String {
+        match self {
+            SlaCondition::Offline => format!("offline for more than {} min", minutes),
+            SlaCondition::LatencyAbove { ms } => format!("latency above {} ms for {} min", ms, minutes),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct SlaRule {
+    /// Empty when creating; assigned by `set_sla_settings`
+    #[serde(default)]
+    pub id: String,
+    pub condition: SlaCondition,
+    /// How long the condition must hold before alerting
+    pub minutes: u32,
+    /// Critical peers the rule covers; empty covers all of them
+    #[serde(default)]
+    pub peer_ids: Vec<String>,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct SlaSettings {
+    pub critical_peers: Vec<String>,
+    pub rules: Vec<SlaRule>,
+}
+
+impl Default for SlaSettings {
+    fn default() -> Self {
+        Self {
+            critical_peers: Vec::new(),
+            rules: vec![
+                SlaRule {
+                    id: "offline".to_string(),
+                    condition: SlaCondition::Offline,
+                    minutes: 5,
+                    peer_ids: Vec::new(),
+                    enabled: true,
+                },
+                SlaRule {
+                    id: "latency".to_string(),
+                    condition: SlaCondition::LatencyAbove { ms: 200 },
+                    minutes: 10,
+                    peer_ids: Vec::new(),
+                    enabled: true,
+                },
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct SlaAlert {
+    pub id: String,
+    pub rule_id: String,
+    pub peer_id: String,
+    pub peer_name: String,
+    pub message: String,
+    /// When the condition started holding
+    #[ts(type = "number")]
+    pub since: u64,
+    #[ts(type = "number")]
+    pub raised_at: u64,
+    /// `None` while the breach is ongoing
+    #[ts(type = "number | null")]
+    pub resolved_at: Option<u64>,
+}
+
+/// Managed state holding the rules and alert history
+pub struct SlaState {
+    settings: JsonStore<SlaSettings>,
+    history: JsonStore<Vec<SlaAlert>>,
+}
+
+impl SlaState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), history: JsonStore::open(app, HISTORY_FILE) }
+    }
+}
+
+/// Condition tracking for one (rule, peer) pair
+#[derive(Default)]
+struct Episode {
+    since: Option<u64>,
+    /// Alert raised for the current episode
+    alert_id: Option<String>,
+}
+
+fn event_data(app: &AppHandle, alert: &SlaAlert) -> EventData {
+    EventData {
+        network: app.state::<DaemonState>().latest_status().map(|s| s.network_name).unwrap_or_default(),
+        peer_id: alert.peer_id.clone(),
+        peer_name: alert.peer_name.clone(),
+        message: alert.message.clone(),
+        ..Default::default()
+    }
+}
+
+fn raise(app: &AppHandle, rule: &SlaRule, peer_id: &str, peer_name: &str, since: u64) -> String {
+    let alert = SlaAlert {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        rule_id: rule.id.clone(),
+        peer_id: peer_id.to_string(),
+        peer_name: peer_name.to_string(),
+        message: format!("{} {}", peer_name, rule.condition.describe(rule.minutes)),
+        since,
+        raised_at: unix_now(),
+        resolved_at: None,
+    };
+    let result = app.state::<SlaState>().history.update(|history| {
+        history.push(alert.clone());
+        let excess = history.len().saturating_sub(MAX_ALERTS);
+        history.drain(..excess);
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to record SLA alert: {}", e);
+    }
+    notifications::notify(app, NotificationKind::System, i18n::text(Text::SlaAlertTitle), &alert.message);
+    webhooks::dispatch(app, WebhookEvent::SlaBreach, event_data(app, &alert));
+    alert.id
+}
+
+fn resolve(app: &AppHandle, alert_id: &str) {
+    let now = unix_now();
+    let resolved = app.state::<SlaState>().history.update(|history| {
+        let alert = history.iter_mut().find(|a| a.id == alert_id)?;
+        alert.resolved_at = Some(now);
+        Some(alert.clone())
+    });
+    match resolved {
+        Ok(Some(alert)) => webhooks::dispatch(app, WebhookEvent::SlaResolved, event_data(app, &alert)),
+        Ok(None) => {}
+        Err(e) => log::warn!("Failed to resolve SLA alert: {}", e),
+    }
+}
+
+/// Evaluate the rules against the peer list on an interval
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<SlaState>();
+        let daemon = app.state::<DaemonState>();
+        let mut episodes: HashMap<(String, String), Episode> = HashMap::new();
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+            let settings = state.settings.get();
+            // Without our own tunnel every peer looks offline; judge nothing
+            let connected = daemon.connection.state().is_usable() && daemon.latest_status().is_some_and(|s| s.connected);
+            let peers = if connected {
+                daemon_call!(daemon, "get_peers", |client| client.get_peers()).ok()
+            } else {
+                None
+            };
+            let Some(peers) = peers else {
+                episodes.retain(|_, e| e.alert_id.is_some());
+                for episode in episodes.values_mut() {
+                    episode.since = None;
+                }
+                continue;
+            };
+
+            let now = unix_now();
+            let mut active = Vec::new();
+            for rule in settings.rules.iter().filter(|r| r.enabled) {
+                let covered = settings
+                    .critical_peers
+                    .iter()
+                    .filter(|id| rule.peer_ids.is_empty() || rule.peer_ids.contains(id));
+                for peer_id in covered {
+                    let peer = peers.iter().find(|p| &p.id == peer_id);
+                    let key = (rule.id.clone(), peer_id.clone());
+                    let episode = episodes.entry(key.clone()).or_default();
+                    active.push(key);
+
+                    if !rule.condition.holds(peer) {
+                        if let Some(alert_id) = episode.alert_id.take() {
+                            resolve(&app, &alert_id);
+                        }
+                        episode.since = None;
+                        continue;
+                    }
+                    let since = *episode.since.get_or_insert(now);
+                    if episode.alert_id.is_none() && now.saturating_sub(since) >= u64::from(rule.minutes) * 60 {
+                        let name = peer
+                            .map(|p| if p.display_name.is_empty() { p.name.clone() } else { p.display_name.clone() })
+                            .unwrap_or_else(|| peer_id.clone());
+                        episode.alert_id = Some(raise(&app, rule, peer_id, &name, since));
+                    }
+                }
+            }
+
+            // Rules or peers removed meanwhile close their open alerts
+            episodes.retain(|key, episode| {
+                if active.contains(key) {
+                    return true;
+                }
+                if let Some(alert_id) = &episode.alert_id {
+                    resolve(&app, alert_id);
+                }
+                false
+            });
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_sla_settings(state: State<'_, SlaState>) -> Result<SlaSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_sla_settings(state: State<'_, SlaState>, mut settings: SlaSettings) -> Result<SlaSettings, String> {
+    for rule in &mut settings.rules {
+        if rule.minutes == 0 {
+            return Err("alert rules must hold for at least one minute".to_string());
+        }
+        if rule.condition == (SlaCondition::LatencyAbove { ms: 0 }) {
+            return Err("latency threshold must be above 0 ms".to_string());
+        }
+        if rule.id.is_empty() {
+            rule.id = hex::encode(rand::random::<[u8; 8]>());
+        }
+    }
+    settings.critical_peers.sort();
+    settings.critical_peers.dedup();
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
+
+/// Mark or unmark a peer as critical
+#[tauri::command]
+pub async fn set_peer_critical(state: State<'_, SlaState>, peer_id: String, critical: bool) -> Result<(), String> {
+    state.settings.update(|settings| {
+        settings.critical_peers.retain(|id| id != &peer_id);
+        if critical {
+            settings.critical_peers.push(peer_id);
+        }
+    })
+}
+
+/// Alerts newest first, optionally for one peer
+#[tauri::command]
+pub async fn list_sla_alerts(
+    state: State<'_, SlaState>,
+    peer_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<SlaAlert>, String> {
+    Ok(state
+        .history
+        .get()
+        .into_iter()
+        .rev()
+        .filter(|a| peer_id.as_ref().is_none_or(|id| &a.peer_id == id))
+        .take(limit.unwrap_or(MAX_ALERTS))
+        .collect())
+}
+
+/// Forget resolved alerts; ongoing ones stay until they clear
+#[tauri::command]
+pub async fn clear_sla_alerts(state: State<'_, SlaState>) -> Result<(), String> {
+    state.history.update(|history| history.retain(|a| a.resolved_at.is_none()))
+}
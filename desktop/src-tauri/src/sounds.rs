@@ -0,0 +1,300 @@
+// Notification Sounds
+// Per-event sound selection played from Rust, including custom audio files.
+// Playback is skipped while the system is muted or in Do Not Disturb mode.
+// Sounds are played one after another by a single long-lived thread that owns
+// the audio output; a burst of notifications queues a few and drops the rest.
+
+use crate::notifications::NotificationKind;
+use crate::store::JsonStore;
+use rodio::source::{SineWave, Source};
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "sounds.json";
+
+/// Volume of the built-in tones relative to full scale
+const TONE_VOLUME: f32 = 0.2;
+
+/// Sounds waiting to be played; more than this at once are dropped
+const QUEUE_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(tag = "type", content = "path", rename_all = "snake_case")]
+#[ts(export)]
+pub enum NotificationSound {
+    /// No sound at all
+    Silent,
+    /// Leave it to the OS notification sound
+    System,
+    /// Built-in two-tone chime
+    Chime,
+    /// Built-in short ping
+    Ping,
+    /// Audio file (wav, mp3, ogg, flac)
+    Custom(String),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct SoundSettings {
+    pub enabled: bool,
+    /// Stay quiet while the system output is muted or Do Not Disturb is on
+    pub respect_do_not_disturb: bool,
+    /// Event types without an entry use `System`
+    pub sounds: HashMap<NotificationKind, NotificationSound>,
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            respect_do_not_disturb: true,
+            sounds: HashMap::from([
+                (NotificationKind::Message, NotificationSound::Ping),
+                (NotificationKind::Transfer, NotificationSound::Chime),
+            ]),
+        }
+    }
+}
+
+/// A sound for the playback thread
+struct Playback {
+    sound: NotificationSound,
+    /// Skip it while the system is muted or in Do Not Disturb mode
+    quiet_aware: bool,
+    /// Told how playing it went, for previews
+    done: Option<tokio::sync::oneshot::Sender<Result<(), String>>>,
+}
+
+/// Managed state holding the persisted sound settings and the playback queue
+pub struct SoundState {
+    settings: JsonStore<SoundSettings>,
+    player: SyncSender<Playback>,
+}
+
+impl SoundState {
+    pub fn load(app: &AppHandle) -> Self {
+        let (player, queue) = mpsc::sync_channel(QUEUE_LENGTH);
+        std::thread::Builder::new()
+            .name("notification-sounds".into())
+            .spawn(move || run_player(queue))
+            .map_err(|e| log::warn!("Failed to start the sound player: {}", e))
+            .ok();
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), player }
+    }
+
+    fn queue(&self, playback: Playback) -> Result<(), String> {
+        match self.player.try_send(playback) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(_)) => Err("too many sounds are queued".to_string()),
+            Err(TrySendError::Disconnected(_)) => Err("the sound player is not running".to_string()),
+        }
+    }
+}
+
+/// Play queued sounds for the life of the app. Audio output handles are not
+/// Send, so the output stream lives on this thread, opened on first use and
+/// again after it fails.
+fn run_player(queue: Receiver<Playback>) {
+    let mut output: Option<(OutputStream, OutputStreamHandle)> = None;
+    while let Ok(playback) = queue.recv() {
+        if playback.quiet_aware && system_wants_quiet() {
+            if let Some(done) = playback.done {
+                let _ = done.send(Ok(()));
+            }
+            continue;
+        }
+        if output.is_none() {
+            output = OutputStream::try_default().map_err(|e| log::warn!("No audio output: {}", e)).ok();
+        }
+        let result = match &output {
+            Some((_, handle)) => play(handle, &playback.sound),
+            None => Err("no audio output".to_string()),
+        };
+        if result.is_err() {
+            // The device may have gone away; open it again next time
+            output = None;
+        }
+        match playback.done {
+            Some(done) => {
+                let _ = done.send(result);
+            }
+            None => {
+                if let Err(e) = result {
+                    log::warn!("Failed to play notification sound: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// Play the sound configured for an event type, unless sounds are off or the user asked for quiet
+pub fn play_for(app: &AppHandle, kind: NotificationKind) {
+    let settings = app.state::<SoundState>().settings.get();
+    let sound = settings.sounds.get(&kind).cloned().unwrap_or(NotificationSound::System);
+    if !settings.enabled || matches!(sound, NotificationSound::Silent | NotificationSound::System) {
+        return;
+    }
+
+    let playback = Playback { sound, quiet_aware: settings.respect_do_not_disturb, done: None };
+    if let Err(e) = app.state::<SoundState>().queue(playback) {
+        log::debug!("Notification sound dropped: {}", e);
+    }
+}
+
+fn play(handle: &OutputStreamHandle, sound: &NotificationSound) -> Result<(), String> {
+    let sink = Sink::try_new(handle).map_err(|e| e.to_string())?;
+
+    match sound {
+        NotificationSound::Silent | NotificationSound::System => return Ok(()),
+        NotificationSound::Chime => {
+            sink.append(tone(880.0, 120));
+            sink.append(tone(1320.0, 180));
+        }
+        NotificationSound::Ping => sink.append(tone(1046.5, 150)),
+        NotificationSound::Custom(path) => sink.append(decode(path)?),
+    }
+    sink.sleep_until_end();
+    Ok(())
+}
+
+fn tone(frequency: f32, millis: u64) -> impl Source<Item = f32> + Send {
+    SineWave::new(frequency)
+        .take_duration(Duration::from_millis(millis))
+        .fade_in(Duration::from_millis(10))
+        .amplify(TONE_VOLUME)
+}
+
+fn decode(path: &str) -> Result<Decoder<BufReader<File>>, String> {
+    let file = File::open(path).map_err(|e| format!("cannot open {}: {}", path, e))?;
+    Decoder::new(BufReader::new(file)).map_err(|e| format!("unsupported audio file {}: {}", path, e))
+}
+
+/// Whether the OS output is muted or notifications are silenced (DND / Focus)
+fn system_wants_quiet() -> bool {
+    output_muted() || do_not_disturb()
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn output_muted() -> bool {
+    command_output("pactl", &["get-sink-mute", "@DEFAULT_SINK@"]).is_some_and(|out| out.ends_with("yes"))
+}
+
+#[cfg(target_os = "linux")]
+fn do_not_disturb() -> bool {
+    // GNOME turns banners off while Do Not Disturb is on
+    command_output("gsettings", &["get", "org.gnome.desktop.notifications", "show-banners"])
+        .is_some_and(|out| out == "false")
+}
+
+#[cfg(target_os = "macos")]
+fn output_muted() -> bool {
+    command_output("osascript", &["-e", "output muted of (get volume settings)"]).is_some_and(|out| out == "true")
+}
+
+#[cfg(target_os = "macos")]
+fn do_not_disturb() -> bool {
+    // Focus modes record their active assertions here
+    dirs::home_dir()
+        .and_then(|home| std::fs::read_to_string(home.join("Library/DoNotDisturb/DB/Assertions.json")).ok())
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .and_then(|v| v["data"][0]["storeAssertionRecords"].as_array().map(|records| !records.is_empty()))
+        .unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn output_muted() -> bool {
+    // Querying the endpoint mute state needs COM; rodio plays at the mixer level anyway
+    false
+}
+
+#[cfg(windows)]
+fn do_not_disturb() -> bool {
+    command_output(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Notifications\Settings",
+            "/v",
+            "NOC_GLOBAL_SETTING_TOASTS_ENABLED",
+        ],
+    )
+    .is_some_and(|out| out.ends_with("0x0"))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn output_muted() -> bool {
+    false
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn do_not_disturb() -> bool {
+    false
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_sound_settings(state: State<'_, SoundState>) -> Result<SoundSettings, String> {
+    Ok(state.settings.get())
+}
+
+/// Update the global switches; per-event sounds are kept as they are
+#[tauri::command]
+pub async fn update_sound_settings(
+    state: State<'_, SoundState>,
+    enabled: bool,
+    respect_do_not_disturb: bool,
+) -> Result<SoundSettings, String> {
+    state.settings.update(|settings| {
+        settings.enabled = enabled;
+        settings.respect_do_not_disturb = respect_do_not_disturb;
+        settings.clone()
+    })
+}
+
+#[tauri::command]
+pub async fn set_notification_sound(
+    state: State<'_, SoundState>,
+    kind: NotificationKind,
+    sound: NotificationSound,
+) -> Result<SoundSettings, String> {
+    // Reject unreadable files now rather than failing silently on the next notification
+    if let NotificationSound::Custom(path) = &sound {
+        decode(path)?;
+    }
+    state.settings.update(|settings| {
+        settings.sounds.insert(kind, sound);
+        settings.clone()
+    })
+}
+
+/// Play a sound immediately, ignoring mute/DND so the user can hear their choice
+#[tauri::command]
+pub async fn preview_sound(state: State<'_, SoundState>, sound: NotificationSound) -> Result<(), String> {
+    let (done, result) = tokio::sync::oneshot::channel();
+    state.queue(Playback { sound, quiet_aware: false, done: Some(done) })?;
+    result.await.map_err(|_| "the sound player stopped".to_string())?
+}
+
+/// Play the configured sound for a notification the frontend displayed itself
+#[tauri::command]
+pub async fn play_notification_sound(app: AppHandle, kind: NotificationKind) -> Result<(), String> {
+    play_for(&app, kind);
+    Ok(())
+}
@@ -0,0 +1,273 @@
+// Chat Flood Protection
+// Incoming messages are counted per sender and network over a sliding window;
+// a peer sending more than the configured number mutes their conversation for
+// a while. Muted messages still arrive and are shown, but are flagged so the
+// frontend raises no notifications for them. The ids of the flooding messages
+// are kept with the mute as evidence for `report_peer`, which hands them to the
+// network's admins through the daemon.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::daemon::ChatMessage;
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::peer_index::PeerIndexState;
+use crate::store::JsonStore;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Emitted with the `ChatMute` when a flood mutes a conversation
+pub const MUTED_EVENT: &str = "chat://muted";
+
+const SETTINGS_FILE: &str = "spam_filter.json";
+const MUTES_FILE: &str = "chat_mutes.json";
+
+/// Message ids kept as evidence per mute
+const MAX_EVIDENCE: usize = 100;
+const MAX_REASON_LEN: usize = 500;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct SpamSettings {
+    pub enabled: bool,
+    /// More messages than this within `window_secs` count as a flood
+    pub max_messages: u32,
+    pub window_secs: u32,
+    pub mute_minutes: u32,
+}
+
+impl Default for SpamSettings {
+    fn default() -> Self {
+        Self { enabled: true, max_messages: 10, window_secs: 10, mute_minutes: 10 }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ChatMute {
+    pub network_id: String,
+    pub peer_id: String,
+    pub peer_name: String,
+    /// Unix timestamp (seconds)
+    #[ts(type = "number")]
+    pub until: u64,
+    /// Set by flood detection rather than by the user
+    pub automatic: bool,
+    /// Ids of the messages that caused the mute and arrived during it
+    pub evidence: Vec<String>,
+    pub reported: bool,
+}
+
+/// Arrival time and id of a sender's recent messages
+type Arrivals = VecDeque<(Instant, String)>;
+
+/// Managed state holding the settings, mutes and recent message times
+pub struct SpamState {
+    settings: JsonStore<SpamSettings>,
+    mutes: JsonStore<Vec<ChatMute>>,
+    /// Keyed by (network id, peer id)
+    recent: Mutex<HashMap<(String, String), Arrivals>>,
+}
+
+impl SpamState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            mutes: JsonStore::open(app, MUTES_FILE),
+            recent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Add a message to the evidence of an active mute; false when the sender is not muted
+    fn extend_mute(&self, network_id: &str, peer_id: &str, message_id: &str) -> bool {
+        let now = unix_now();
+        let muted = |m: &ChatMute| m.network_id == network_id && m.peer_id == peer_id && m.until > now;
+        // Most messages come from senders who are not muted; spare them a write
+        if !self.mutes.get().iter().any(muted) {
+            return false;
+        }
+        let result = self.mutes.update(|mutes| {
+            mutes.retain(|m| m.until > now);
+            if let Some(mute) = mutes.iter_mut().find(|m| muted(m)) {
+                if mute.evidence.len() < MAX_EVIDENCE {
+                    mute.evidence.push(message_id.to_string());
+                }
+            }
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to update chat mutes: {}", e);
+        }
+        true
+    }
+
+    /// Record an arrival; returns the window's message ids once it overflows
+    fn count(&self, network_id: &str, peer_id: &str, message_id: &str) -> Option<Vec<String>> {
+        let settings = self.settings.get();
+        if !settings.enabled {
+            return None;
+        }
+        let window = Duration::from_secs(u64::from(settings.window_secs));
+        let mut recent = self.recent.lock().unwrap();
+        let arrivals = recent.entry((network_id.to_string(), peer_id.to_string())).or_default();
+        arrivals.retain(|(at, _)| at.elapsed() < window);
+        arrivals.push_back((Instant::now(), message_id.to_string()));
+        if arrivals.len() <= settings.max_messages as usize {
+            return None;
+        }
+        let evidence = arrivals.drain(..).map(|(_, id)| id).collect();
+        Some(evidence)
+    }
+
+    /// Ids of the messages held as evidence by mutes in `network_id`
+    pub(crate) fn evidence(&self, network_id: &str) -> HashSet<String> {
+        let mutes = self.mutes.get();
+        mutes.into_iter().filter(|m| m.network_id == network_id).flat_map(|m| m.evidence).collect()
+    }
+
+    fn mute(&self, mute: ChatMute) -> Result<ChatMute, String> {
+        self.mutes.update(|mutes| {
+            mutes.retain(|m| !(m.network_id == mute.network_id && m.peer_id == mute.peer_id));
+            mutes.push(mute.clone());
+            mute
+        })
+    }
+}
+
+/// Check an incoming message for flooding; returns whether its conversation is muted
+pub(crate) async fn observe(app: &AppHandle, network_id: &str, message: &ChatMessage) -> bool {
+    let state = app.state::<SpamState>();
+    if state.extend_mute(network_id, &message.peer_id, &message.id) {
+        return true;
+    }
+    let Some(evidence) = state.count(network_id, &message.peer_id, &message.id) else {
+        return false;
+    };
+
+    // Messages of our own come back on the stream too; never mute ourselves
+    let daemon = app.state::<DaemonState>();
+    let peer = app
+        .state::<PeerIndexState>()
+        .with_snapshot(&daemon, Some(network_id.to_string()), |s| s.peers.get(&message.peer_id).cloned())
+        .await
+        .ok()
+        .and_then(|(_, peer)| peer);
+    if peer.as_ref().is_some_and(|p| p.is_self) {
+        return false;
+    }
+    let peer_name = peer
+        .map(|p| if p.display_name.is_empty() { p.name } else { p.display_name })
+        .unwrap_or_else(|| message.peer_id.clone());
+
+    let minutes = state.settings.get().mute_minutes;
+    let mute = ChatMute {
+        network_id: network_id.to_string(),
+        peer_id: message.peer_id.clone(),
+        peer_name,
+        until: unix_now() + u64::from(minutes) * 60,
+        automatic: true,
+        evidence,
+        reported: false,
+    };
+    match state.mute(mute) {
+        Ok(mute) => {
+            log::info!("Muted {} in {} for flooding", mute.peer_id, network_id);
+            let body = i18n::text(Text::ChatMutedBody)
+                .replace("{minutes}", &minutes.to_string())
+                .replace("{peer}", &mute.peer_name);
+            notifications::notify(app, NotificationKind::System, i18n::text(Text::ChatMutedTitle), &body);
+            let _ = app.emit(MUTED_EVENT, mute);
+        }
+        Err(e) => log::warn!("Failed to save chat mute: {}", e),
+    }
+    true
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_spam_settings(state: State<'_, SpamState>) -> Result<SpamSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_spam_settings(state: State<'_, SpamState>, settings: SpamSettings) -> Result<SpamSettings, String> {
+    if settings.max_messages == 0 || settings.window_secs == 0 || settings.mute_minutes == 0 {
+        return Err("the threshold, window and mute duration must all be above zero".to_string());
+    }
+    state.settings.set(settings.clone())?;
+    state.recent.lock().unwrap().clear();
+    Ok(settings)
+}
+
+/// Mutes still in effect
+#[tauri::command]
+pub async fn list_chat_mutes(state: State<'_, SpamState>) -> Result<Vec<ChatMute>, String> {
+    let now = unix_now();
+    Ok(state.mutes.get().into_iter().filter(|m| m.until > now).collect())
+}
+
+/// Mute a peer's messages in a network by hand
+#[tauri::command]
+pub async fn mute_peer_chat(
+    state: State<'_, SpamState>,
+    network_id: String,
+    peer_id: String,
+    peer_name: String,
+    minutes: u32,
+) -> Result<ChatMute, String> {
+    if minutes == 0 {
+        return Err("the mute must last at least a minute".to_string());
+    }
+    state.mute(ChatMute {
+        network_id,
+        peer_id,
+        peer_name,
+        until: unix_now() + u64::from(minutes) * 60,
+        automatic: false,
+        evidence: Vec::new(),
+        reported: false,
+    })
+}
+
+#[tauri::command]
+pub async fn unmute_peer_chat(state: State<'_, SpamState>, network_id: String, peer_id: String) -> Result<(), String> {
+    state.mutes.update(|mutes| mutes.retain(|m| !(m.network_id == network_id && m.peer_id == peer_id)))
+}
+
+/// Report a peer to the network's admins with the ids of the offending
+/// messages; without `message_ids` the evidence collected by their mute is sent
+#[tauri::command]
+pub async fn report_peer(
+    state: State<'_, SpamState>,
+    daemon: State<'_, DaemonState>,
+    network_id: String,
+    peer_id: String,
+    reason: String,
+    message_ids: Option<Vec<String>>,
+) -> Result<(), String> {
+    let reason = reason.trim();
+    if reason.is_empty() || reason.chars().count() > MAX_REASON_LEN {
+        return Err(format!("the reason must have 1 to {} characters", MAX_REASON_LEN));
+    }
+    let mute = state.mutes.get().into_iter().find(|m| m.network_id == network_id && m.peer_id == peer_id);
+    let mut message_ids = message_ids.or_else(|| mute.map(|m| m.evidence)).unwrap_or_default();
+    message_ids.dedup();
+    if message_ids.is_empty() {
+        return Err("pick the messages to report".to_string());
+    }
+    message_ids.truncate(MAX_EVIDENCE);
+
+    daemon_call!(daemon, "report_peer", |client| client.report_peer(&network_id, &peer_id, reason, &message_ids))?;
+    log::info!("Reported {} in {} with {} messages", peer_id, network_id, message_ids.len());
+    state.mutes.update(|mutes| {
+        for mute in mutes.iter_mut().filter(|m| m.network_id == network_id && m.peer_id == peer_id) {
+            mute.reported = true;
+        }
+    })
+}
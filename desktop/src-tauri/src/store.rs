@@ -0,0 +1,83 @@
+// Local Store
+// Small JSON documents in the app data dir for state the daemon does not own
+// (desktop-only preferences, histories, caches)
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+/// A typed JSON document kept in memory and written through on every update
+pub struct JsonStore<T> {
+    path: PathBuf,
+    value: Mutex<T>,
+}
+
+impl<T> JsonStore<T>
+where
+    T: Serialize + DeserializeOwned + Default + Clone,
+{
+    /// Open `<app data dir>/<file_name>`, falling back to defaults when the
+    /// file is missing. A corrupt file is kept aside rather than overwritten.
+    pub fn open(app: &AppHandle, file_name: &str) -> Self {
+        let path = data_dir(app).join(file_name);
+        let value = match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                log::warn!("Discarding unreadable store {:?}: {}", path, e);
+                let _ = std::fs::rename(&path, path.with_extension("corrupt"));
+                T::default()
+            }),
+            Err(_) => T::default(),
+        };
+        Self { path, value: Mutex::new(value) }
+    }
+
+    /// Copy of the current value
+    pub fn get(&self) -> T {
+        self.value.lock().unwrap().clone()
+    }
+
+    /// Mutate the value and persist it. The in-memory value is only replaced
+    /// once the write succeeded, so memory and disk never disagree.
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, String> {
+        let mut guard = self.value.lock().unwrap();
+        let mut next = guard.clone();
+        let result = f(&mut next);
+        self.write(&next)?;
+        *guard = next;
+        Ok(result)
+    }
+
+    /// Replace the whole value
+    pub fn set(&self, value: T) -> Result<(), String> {
+        self.update(|current| *current = value)
+    }
+
+    fn write(&self, value: &T) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let bytes = serde_json::to_vec_pretty(value).map_err(|e| e.to_string())?;
+
+        // Write to a temp file and rename so a crash never leaves half a document
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, bytes).map_err(|e| e.to_string())?;
+        std::fs::rename(&tmp, &self.path).map_err(|e| e.to_string())
+    }
+}
+
+/// Directory holding all desktop-local state (the portable data folder in portable mode)
+pub fn data_dir(app: &AppHandle) -> PathBuf {
+    if let Some(dir) = crate::portable::data_dir() {
+        return dir.to_path_buf();
+    }
+    app.path()
+        .app_data_dir()
+        .unwrap_or_else(|_| std::env::temp_dir().join("goconnect-desktop"))
+}
+
+/// Directory the log plugin writes to
+pub fn log_dir(app: &AppHandle) -> Option<PathBuf> {
+    crate::portable::log_dir().or_else(|| app.path().app_log_dir().ok())
+}
@@ -0,0 +1,149 @@
+// Subnet Conflict Check
+// Compares a network's virtual range with the subnets of local interfaces (LAN
+// and other VPNs). An overlap makes traffic to one side unroutable, so warn and
+// suggest a free private range the admin can use for the network instead.
+
+use crate::commands::{daemon_call, DaemonState};
+use if_addrs::IfAddr;
+use ipnet::Ipv4Net;
+use std::net::Ipv4Addr;
+use tauri::State;
+use ts_rs::TS;
+
+/// Interface name prefixes used by common VPN clients
+const VPN_PREFIXES: &[&str] = &[
+    "tun", "tap", "wg", "utun", "ppp", "ipsec", "tailscale", "zt", "nordlynx", "proton", "mullvad", "wt",
+];
+
+/// Narrowest range assumed when deriving a network's range from its members
+const DERIVED_MAX_PREFIX: u8 = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum InterfaceKind {
+    Lan,
+    Vpn,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct SubnetConflict {
+    pub interface: String,
+    pub kind: InterfaceKind,
+    pub interface_cidr: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct SubnetCheck {
+    pub network_cidr: String,
+    /// True when the range was inferred from member addresses rather than given
+    pub derived: bool,
+    pub conflicts: Vec<SubnetConflict>,
+    /// Free private range of the same size, when there are conflicts
+    pub suggestion: Option<String>,
+}
+
+pub(crate) struct LocalSubnet {
+    pub interface: String,
+    pub kind: InterfaceKind,
+    pub net: Ipv4Net,
+}
+
+pub(crate) fn interface_kind(name: &str) -> InterfaceKind {
+    let lower = name.to_ascii_lowercase();
+    if VPN_PREFIXES.iter().any(|p| lower.starts_with(p)) || lower.contains("vpn") {
+        InterfaceKind::Vpn
+    } else {
+        InterfaceKind::Lan
+    }
+}
+
+/// IPv4 subnets of all non-loopback interfaces, except ones holding `exclude` (our own tunnel)
+pub(crate) fn local_subnets(exclude: Option<Ipv4Addr>) -> Result<Vec<LocalSubnet>, String> {
+    let interfaces = if_addrs::get_if_addrs().map_err(|e| format!("failed to list interfaces: {}", e))?;
+    Ok(interfaces
+        .into_iter()
+        .filter(|i| !i.is_loopback())
+        .filter_map(|i| match &i.addr {
+            IfAddr::V4(v4) if Some(v4.ip) != exclude => {
+                let net = Ipv4Net::with_netmask(v4.ip, v4.netmask).ok()?.trunc();
+                Some(LocalSubnet { kind: interface_kind(&i.name), interface: i.name, net })
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+fn overlaps(a: &Ipv4Net, b: &Ipv4Net) -> bool {
+    a.contains(&b.network()) || b.contains(&a.network())
+}
+
+/// Smallest range (at most /24) holding every member address
+fn derive_range(addresses: &[Ipv4Addr]) -> Option<Ipv4Net> {
+    let first = *addresses.first()?;
+    (0..=DERIVED_MAX_PREFIX)
+        .rev()
+        .filter_map(|prefix| Ipv4Net::new(first, prefix).ok().map(|n| n.trunc()))
+        .find(|net| addresses.iter().all(|a| net.contains(a)))
+}
+
+/// First private range of `prefix_len` that no local subnet touches
+fn suggest(prefix_len: u8, taken: &[Ipv4Net]) -> Option<Ipv4Net> {
+    // Less commonly used blocks first; 192.168/16 is where home LANs live
+    let pools = [
+        Ipv4Net::new(Ipv4Addr::new(10, 128, 0, 0), 9),
+        Ipv4Net::new(Ipv4Addr::new(10, 0, 0, 0), 9),
+        Ipv4Net::new(Ipv4Addr::new(172, 16, 0, 0), 12),
+        Ipv4Net::new(Ipv4Addr::new(192, 168, 0, 0), 16),
+    ];
+    pools
+        .into_iter()
+        .flatten()
+        .filter(|pool| pool.prefix_len() <= prefix_len)
+        .flat_map(|pool| pool.subnets(prefix_len).into_iter().flatten())
+        .find(|candidate| !taken.iter().any(|t| overlaps(candidate, t)))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Check `cidr` (e.g. before creating a network), or the connected network's range
+/// inferred from its members when omitted
+#[tauri::command]
+pub async fn run_subnet_conflict_check(
+    daemon: State<'_, DaemonState>,
+    cidr: Option<String>,
+) -> Result<SubnetCheck, String> {
+    let status = daemon.probe_status().await.ok().filter(|s| s.connected);
+    let own_ip = status.as_ref().and_then(|s| s.virtual_ip.parse::<Ipv4Addr>().ok());
+
+    let (network, derived) = match cidr.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+        Some(cidr) => (cidr.parse::<Ipv4Net>().map_err(|e| format!("invalid CIDR '{}': {}", cidr, e))?.trunc(), false),
+        None => {
+            let own_ip = own_ip.ok_or("not connected to a network; pass a CIDR to check")?;
+            let peers = daemon_call!(daemon, "get_peers", |client| client.get_peers()).unwrap_or_default();
+            let mut addresses = vec![own_ip];
+            addresses.extend(peers.iter().filter_map(|p| p.virtual_ip.parse::<Ipv4Addr>().ok()));
+            (derive_range(&addresses).ok_or("could not determine the network range")?, true)
+        }
+    };
+
+    let subnets = local_subnets(own_ip)?;
+    let conflicts: Vec<SubnetConflict> = subnets
+        .iter()
+        .filter(|s| overlaps(&s.net, &network))
+        .map(|s| SubnetConflict { interface: s.interface.clone(), kind: s.kind, interface_cidr: s.net.to_string() })
+        .collect();
+
+    let suggestion = if conflicts.is_empty() {
+        None
+    } else {
+        let taken: Vec<Ipv4Net> = subnets.iter().map(|s| s.net).collect();
+        suggest(network.prefix_len(), &taken).map(|n| n.to_string())
+    };
+
+    Ok(SubnetCheck { network_cidr: network.to_string(), derived, conflicts, suggestion })
+}
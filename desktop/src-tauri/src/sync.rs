@@ -0,0 +1,600 @@
+// Folder Sync
+// Folder pairs kept in sync with a peer over regular transfers. Local changes are
+// picked up by a file watcher, debounced and sent in batches; incoming transfers
+// from the paired peer are accepted into the folder. A file changed on both sides
+// since the last sync keeps the local copy and saves the incoming one beside it.
+//
+// Transfers carry only a file name, so pairs cover the files directly inside the
+// folder (not nested folders), and deletions are not propagated.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::delta;
+use crate::remote_files;
+use crate::store::JsonStore;
+use crate::versions::{self, VersionSource};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::mpsc;
+use ts_rs::TS;
+
+const SYNC_FILE: &str = "sync.json";
+
+/// Changes are collected for this long before a batch is sent
+const DEBOUNCE: Duration = Duration::from_secs(3);
+/// Incoming transfers and in-flight sends are checked this often
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Files modified more recently than this may still be being written
+const SETTLE_SECS: u64 = 2;
+
+const MAX_CONFLICTS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SyncDirection {
+    /// Local changes are sent; incoming files are not accepted
+    SendOnly,
+    /// Incoming files are accepted; local changes are not sent
+    ReceiveOnly,
+    TwoWay,
+}
+
+impl SyncDirection {
+    fn sends(self) -> bool {
+        matches!(self, Self::SendOnly | Self::TwoWay)
+    }
+
+    fn receives(self) -> bool {
+        matches!(self, Self::ReceiveOnly | Self::TwoWay)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct SyncPair {
+    pub id: String,
+    pub peer_id: String,
+    pub local_path: String,
+    pub direction: SyncDirection,
+    pub paused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct FileState {
+    size: u64,
+    modified: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct SyncConflict {
+    pub pair_id: String,
+    pub file_name: String,
+    /// Where the incoming version was saved
+    pub conflict_copy: String,
+    #[ts(type = "number")]
+    pub detected_at: u64,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct SyncData {
+    pairs: Vec<SyncPair>,
+    /// Per pair, the state of each file as of its last sync
+    files: HashMap<String, HashMap<String, FileState>>,
+    last_synced: HashMap<String, u64>,
+    conflicts: Vec<SyncConflict>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SyncActivity {
+    Idle,
+    Syncing,
+    Paused,
+    Error,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct SyncPairStatus {
+    pub pair: SyncPair,
+    pub activity: SyncActivity,
+    /// Changed files waiting for the next batch
+    pub pending: u32,
+    pub in_flight: u32,
+    #[ts(type = "number | null")]
+    pub last_synced_at: Option<u64>,
+    pub last_error: Option<String>,
+    pub conflicts: u32,
+}
+
+/// A transfer started by sync, tracked until it finishes
+struct InFlight {
+    pair_id: String,
+    file_name: String,
+    /// Final path for incoming files
+    target: Option<PathBuf>,
+}
+
+/// Managed state holding sync pairs and the live engine bookkeeping
+pub struct SyncState {
+    data: JsonStore<SyncData>,
+    pending: Mutex<HashMap<String, HashSet<String>>>,
+    in_flight: Mutex<HashMap<String, InFlight>>,
+    errors: Mutex<HashMap<String, String>>,
+    watchers: Mutex<HashMap<String, RecommendedWatcher>>,
+    changes: mpsc::UnboundedSender<(String, PathBuf)>,
+    changes_rx: Mutex<Option<mpsc::UnboundedReceiver<(String, PathBuf)>>>,
+}
+
+impl SyncState {
+    pub fn load(app: &AppHandle) -> Self {
+        let (changes, changes_rx) = mpsc::unbounded_channel();
+        Self {
+            data: JsonStore::open(app, SYNC_FILE),
+            pending: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+            errors: Mutex::new(HashMap::new()),
+            watchers: Mutex::new(HashMap::new()),
+            changes,
+            changes_rx: Mutex::new(Some(changes_rx)),
+        }
+    }
+
+    fn pair(&self, id: &str) -> Result<SyncPair, String> {
+        self.data
+            .get()
+            .pairs
+            .into_iter()
+            .find(|p| p.id == id)
+            .ok_or_else(|| format!("sync pair '{}' not found", id))
+    }
+
+    /// Start watching a pair's folder and queue everything in it for comparison
+    fn watch(&self, pair: &SyncPair) -> Result<(), String> {
+        if pair.paused || !pair.direction.sends() {
+            self.watchers.lock().unwrap().remove(&pair.id);
+            return Ok(());
+        }
+
+        let tx = self.changes.clone();
+        let pair_id = pair.id.clone();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else { return };
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                for path in event.paths {
+                    let _ = tx.send((pair_id.clone(), path));
+                }
+            }
+        })
+        .map_err(|e| format!("failed to watch folder: {}", e))?;
+        watcher
+            .watch(Path::new(&pair.local_path), RecursiveMode::NonRecursive)
+            .map_err(|e| format!("failed to watch {}: {}", pair.local_path, e))?;
+        self.watchers.lock().unwrap().insert(pair.id.clone(), watcher);
+
+        // Catch up on changes made while not watching
+        let names = list_files(Path::new(&pair.local_path));
+        self.pending.lock().unwrap().entry(pair.id.clone()).or_default().extend(names);
+        Ok(())
+    }
+
+    fn set_error(&self, pair_id: &str, error: Option<String>) {
+        let mut errors = self.errors.lock().unwrap();
+        match error {
+            Some(e) => errors.insert(pair_id.to_string(), e),
+            None => errors.remove(pair_id),
+        };
+    }
+
+    fn record_synced(&self, pair_id: &str, file_name: &str, state: Option<FileState>) {
+        let result = self.data.update(|data| {
+            let files = data.files.entry(pair_id.to_string()).or_default();
+            match state {
+                Some(state) => {
+                    files.insert(file_name.to_string(), state);
+                    data.last_synced.insert(pair_id.to_string(), unix_now());
+                }
+                None => {
+                    files.remove(file_name);
+                }
+            }
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record sync state: {}", e);
+        }
+    }
+}
+
+fn file_state(path: &Path) -> Option<FileState> {
+    let meta = std::fs::metadata(path).ok().filter(|m| m.is_file())?;
+    let modified = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(FileState { size: meta.len(), modified })
+}
+
+/// Regular, non-hidden files directly inside `dir`
+fn list_files(dir: &Path) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.file_type().is_ok_and(|t| t.is_file()))
+                .filter_map(|e| e.file_name().into_string().ok())
+                .filter(|name| is_synced_name(name))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Hidden and temporary files (including our own partial downloads) are skipped
+fn is_synced_name(name: &str) -> bool {
+    !name.starts_with('.') && !name.ends_with(".part") && !name.ends_with('~') && !name.ends_with(".tmp")
+}
+
+/// Whether an incoming file from `peer_id` will be accepted into one of its receiving pairs
+pub(crate) fn receives(app: &AppHandle, peer_id: &str, file_name: &str) -> bool {
+    let Some(state) = app.try_state::<SyncState>() else { return false };
+    let name = Path::new(file_name).file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    is_synced_name(name)
+        && state.data.get().pairs.iter().any(|p| p.peer_id == peer_id && !p.paused && p.direction.receives())
+}
+
+/// `name (conflict YYYY-MM-DD HHMMSS).ext` next to the original
+fn conflict_path(dir: &Path, name: &str) -> PathBuf {
+    let stamp = chrono::Local::now().format("%Y-%m-%d %H%M%S");
+    let file = match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => format!("{} (conflict {}).{}", stem, stamp, ext),
+        _ => format!("{} (conflict {})", name, stamp),
+    };
+    dir.join(file)
+}
+
+/// Where a delta from `peer_id` for `file_name` would apply: the file in the peer's
+/// receiving pair, only while it is unchanged since the last sync. Anything else
+/// goes through a full transfer, which also takes care of conflicts.
+pub(crate) fn delta_basis(app: &AppHandle, peer_id: &str, file_name: &str) -> Result<(String, PathBuf), String> {
+    let state = app.state::<SyncState>();
+    let name = Path::new(file_name).file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    if name != file_name || !is_synced_name(name) {
+        return Err("not a synced file name".to_string());
+    }
+    let data = state.data.get();
+    let pair = data
+        .pairs
+        .iter()
+        .find(|p| p.peer_id == peer_id && !p.paused && p.direction.receives())
+        .ok_or("no receiving sync pair for this peer")?;
+    let target = Path::new(&pair.local_path).join(name);
+    let synced = data.files.get(&pair.id).and_then(|f| f.get(name));
+    match file_state(&target) {
+        Some(local) if synced == Some(&local) => Ok((pair.id.clone(), target)),
+        Some(_) => Err("local copy changed since the last sync".to_string()),
+        None => Err("no local copy to patch".to_string()),
+    }
+}
+
+/// Remember a file rebuilt from a delta so the watcher does not send it back
+pub(crate) fn note_received(app: &AppHandle, pair_id: &str, target: &Path) {
+    let Some(name) = target.file_name().and_then(|n| n.to_str()) else { return };
+    app.state::<SyncState>().record_synced(pair_id, name, file_state(target));
+}
+
+/// Send every settled, changed file queued for the pair
+async fn flush(app: &AppHandle, pair: &SyncPair) {
+    let state = app.state::<SyncState>();
+    let Some(names) = state.pending.lock().unwrap().remove(&pair.id) else { return };
+
+    let dir = Path::new(&pair.local_path);
+    let synced = state.data.get().files.remove(&pair.id).unwrap_or_default();
+    let busy: HashSet<String> = state
+        .in_flight
+        .lock()
+        .unwrap()
+        .values()
+        .filter(|f| f.pair_id == pair.id)
+        .map(|f| f.file_name.clone())
+        .collect();
+    let now = unix_now();
+    let mut requeue = Vec::new();
+
+    for name in names {
+        let Some(current) = file_state(&dir.join(&name)) else { continue };
+        if busy.contains(&name) || now.saturating_sub(current.modified) < SETTLE_SECS {
+            requeue.push(name);
+            continue;
+        }
+        // Unchanged since the last sync, including files we just received
+        if synced.get(&name) == Some(&current) {
+            continue;
+        }
+
+        // The peer already has an older copy, so the changed blocks may be enough
+        if synced.contains_key(&name) && delta::try_send(app, &pair.peer_id, &dir.join(&name)).await {
+            state.record_synced(&pair.id, &name, Some(current));
+            state.set_error(&pair.id, None);
+            continue;
+        }
+
+        let path = dir.join(&name).to_string_lossy().into_owned();
+        match remote_files::send_file(app, &pair.peer_id, &path).await {
+            Ok(transfer_id) => {
+                state.record_synced(&pair.id, &name, Some(current));
+                state.in_flight.lock().unwrap().insert(
+                    transfer_id,
+                    InFlight { pair_id: pair.id.clone(), file_name: name, target: None },
+                );
+                state.set_error(&pair.id, None);
+            }
+            Err(e) => {
+                state.set_error(&pair.id, Some(e));
+                requeue.push(name);
+            }
+        }
+    }
+
+    if !requeue.is_empty() {
+        state.pending.lock().unwrap().entry(pair.id.clone()).or_default().extend(requeue);
+    }
+}
+
+/// Accept incoming files for receiving pairs and settle finished transfers
+async fn poll_transfers(app: &AppHandle) {
+    let state = app.state::<SyncState>();
+    let daemon = app.state::<DaemonState>();
+    let Ok(transfers) = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None)) else {
+        return;
+    };
+    let pairs = state.data.get().pairs;
+
+    for t in &transfers {
+        let tracked = state.in_flight.lock().unwrap().contains_key(&t.id);
+        if tracked {
+            if !matches!(t.status.as_str(), "completed" | "failed" | "cancelled") {
+                continue;
+            }
+            let Some(done) = state.in_flight.lock().unwrap().remove(&t.id) else { continue };
+            match (t.status.as_str(), done.target) {
+                // Remember what arrived so the watcher does not send it straight back
+                ("completed", Some(target)) if !done.file_name.is_empty() => {
+                    state.record_synced(&done.pair_id, &done.file_name, file_state(&target))
+                }
+                ("completed", _) => {}
+                // Forget the failed send so the next change (or restart) retries it
+                (_, _) => {
+                    state.record_synced(&done.pair_id, &done.file_name, None);
+                    state.set_error(&done.pair_id, Some(t.error.clone().unwrap_or_else(|| format!("transfer {}", t.status))));
+                }
+            }
+            continue;
+        }
+
+        if t.direction != "download" || t.status != "pending" || !is_synced_name(&t.file_name) {
+            continue;
+        }
+        // Incoming files from a peer go to its first active receiving pair
+        let Some(pair) = pairs.iter().find(|p| p.peer_id == t.peer_id && !p.paused && p.direction.receives()) else {
+            continue;
+        };
+        let name = Path::new(&t.file_name)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if name.is_empty() {
+            continue;
+        }
+
+        let dir = Path::new(&pair.local_path);
+        let target = dir.join(&name);
+        let synced = state.data.get().files.get(&pair.id).and_then(|f| f.get(&name).cloned());
+        let local = file_state(&target);
+        // Changed locally since the last sync: keep ours, save theirs beside it
+        let save_to = if local.is_some() && local != synced {
+            let copy = conflict_path(dir, &name);
+            let result = state.data.update(|data| {
+                data.conflicts.push(SyncConflict {
+                    pair_id: pair.id.clone(),
+                    file_name: name.clone(),
+                    conflict_copy: copy.to_string_lossy().into_owned(),
+                    detected_at: unix_now(),
+                });
+                let excess = data.conflicts.len().saturating_sub(MAX_CONFLICTS);
+                data.conflicts.drain(..excess);
+            });
+            if let Err(e) = result {
+                log::warn!("Failed to record sync conflict: {}", e);
+            }
+            copy
+        } else {
+            if local.is_some() {
+                if let Err(e) = versions::snapshot_before_overwrite(app, &target, VersionSource::Sync).await {
+                    log::warn!("Failed to keep previous version of {:?}: {}", target, e);
+                }
+            }
+            target
+        };
+
+        let save_path = save_to.to_string_lossy().into_owned();
+        match daemon_call!(daemon, "accept_transfer", |client| client.accept_transfer(&t.id, &save_path)) {
+            Ok(()) => {
+                // Conflict copies are not tracked as the synced version of the file
+                let tracked_name = if save_to == dir.join(&name) { name } else { String::new() };
+                state.in_flight.lock().unwrap().insert(
+                    t.id.clone(),
+                    InFlight { pair_id: pair.id.clone(), file_name: tracked_name, target: Some(save_to) },
+                );
+            }
+            Err(e) => state.set_error(&pair.id, Some(e)),
+        }
+    }
+}
+
+/// Start watchers for all pairs and run the sync engine
+pub fn spawn(app: AppHandle) {
+    let state = app.state::<SyncState>();
+    for pair in state.data.get().pairs {
+        if let Err(e) = state.watch(&pair) {
+            log::warn!("Sync pair {} not watched: {}", pair.id, e);
+            state.set_error(&pair.id, Some(e));
+        }
+    }
+    let Some(mut changes) = state.changes_rx.lock().unwrap().take() else { return };
+
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<SyncState>();
+        let mut debounce = tokio::time::interval(DEBOUNCE);
+        let mut poll = tokio::time::interval(POLL_INTERVAL);
+        loop {
+            tokio::select! {
+                Some((pair_id, path)) = changes.recv() => {
+                    let name = path.file_name().and_then(|n| n.to_str()).filter(|n| is_synced_name(n));
+                    if let Some(name) = name {
+                        state.pending.lock().unwrap().entry(pair_id).or_default().insert(name.to_string());
+                    }
+                }
+                _ = debounce.tick() => {
+                    if !app.state::<DaemonState>().connection.state().is_usable() {
+                        continue;
+                    }
+                    let pairs = state.data.get().pairs;
+                    for pair in pairs.iter().filter(|p| !p.paused && p.direction.sends()) {
+                        flush(&app, pair).await;
+                    }
+                }
+                _ = poll.tick() => {
+                    if app.state::<DaemonState>().connection.state().is_usable() {
+                        poll_transfers(&app).await;
+                    }
+                }
+            }
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn list_sync_pairs(state: State<'_, SyncState>) -> Result<Vec<SyncPairStatus>, String> {
+    let data = state.data.get();
+    let pending = state.pending.lock().unwrap();
+    let in_flight = state.in_flight.lock().unwrap();
+    let errors = state.errors.lock().unwrap();
+
+    Ok(data
+        .pairs
+        .iter()
+        .map(|pair| {
+            let pending = pending.get(&pair.id).map_or(0, |p| p.len() as u32);
+            let in_flight = in_flight.values().filter(|f| f.pair_id == pair.id).count() as u32;
+            let last_error = errors.get(&pair.id).cloned();
+            let activity = if pair.paused {
+                SyncActivity::Paused
+            } else if last_error.is_some() {
+                SyncActivity::Error
+            } else if pending + in_flight > 0 {
+                SyncActivity::Syncing
+            } else {
+                SyncActivity::Idle
+            };
+            SyncPairStatus {
+                activity,
+                pending,
+                in_flight,
+                last_synced_at: data.last_synced.get(&pair.id).copied(),
+                last_error,
+                conflicts: data.conflicts.iter().filter(|c| c.pair_id == pair.id).count() as u32,
+                pair: pair.clone(),
+            }
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn add_sync_pair(
+    state: State<'_, SyncState>,
+    peer_id: String,
+    local_path: String,
+    direction: SyncDirection,
+) -> Result<SyncPair, String> {
+    if !Path::new(&local_path).is_dir() {
+        return Err(format!("{} is not a folder", local_path));
+    }
+    let pair = SyncPair {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        peer_id,
+        local_path,
+        direction,
+        paused: false,
+    };
+    state.watch(&pair)?;
+    state.data.update(|data| data.pairs.push(pair.clone()))?;
+    Ok(pair)
+}
+
+#[tauri::command]
+pub async fn remove_sync_pair(state: State<'_, SyncState>, id: String) -> Result<(), String> {
+    state.watchers.lock().unwrap().remove(&id);
+    state.pending.lock().unwrap().remove(&id);
+    state.set_error(&id, None);
+    state.data.update(|data| {
+        data.pairs.retain(|p| p.id != id);
+        data.files.remove(&id);
+        data.last_synced.remove(&id);
+        data.conflicts.retain(|c| c.pair_id != id);
+    })
+}
+
+/// Stop watching and accepting; transfers already started finish
+#[tauri::command]
+pub async fn pause_sync_pair(state: State<'_, SyncState>, id: String) -> Result<SyncPair, String> {
+    set_paused(&state, &id, true)
+}
+
+/// Resume and catch up on changes made while paused
+#[tauri::command]
+pub async fn resume_sync_pair(state: State<'_, SyncState>, id: String) -> Result<SyncPair, String> {
+    set_paused(&state, &id, false)
+}
+
+fn set_paused(state: &SyncState, id: &str, paused: bool) -> Result<SyncPair, String> {
+    let mut pair = state.pair(id)?;
+    pair.paused = paused;
+    state.watch(&pair)?;
+    if paused {
+        state.pending.lock().unwrap().remove(id);
+    }
+    state.data.update(|data| {
+        if let Some(p) = data.pairs.iter_mut().find(|p| p.id == id) {
+            p.paused = paused;
+        }
+    })?;
+    Ok(pair)
+}
+
+#[tauri::command]
+pub async fn list_sync_conflicts(
+    state: State<'_, SyncState>,
+    pair_id: Option<String>,
+) -> Result<Vec<SyncConflict>, String> {
+    Ok(state
+        .data
+        .get()
+        .conflicts
+        .into_iter()
+        .rev()
+        .filter(|c| pair_id.as_ref().is_none_or(|id| &c.pair_id == id))
+        .collect())
+}
+
+/// Forget a conflict once the user has dealt with the copy
+#[tauri::command]
+pub async fn dismiss_sync_conflict(state: State<'_, SyncState>, conflict_copy: String) -> Result<(), String> {
+    state.data.update(|data| data.conflicts.retain(|c| c.conflict_copy != conflict_copy))
+}
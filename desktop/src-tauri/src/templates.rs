@@ -0,0 +1,222 @@
+// Message Templates
+// Canned responses kept locally. Bodies may contain placeholders that are
+// filled in when the message is sent, not when it is written:
+//   {peer}     the name of the peer the reply is for
+//   {network}  the name of the network it is sent to
+//   {my_ip}    our virtual IP
+// Anything else in braces is left as typed. The quick-insert list puts the
+// templates used most often and most recently first.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use tauri::{AppHandle, State};
+use ts_rs::TS;
+
+const TEMPLATES_FILE: &str = "message_templates.json";
+
+const MAX_TEMPLATES: usize = 200;
+const MAX_NAME_LEN: usize = 60;
+const MAX_BODY_LEN: usize = 4000;
+
+/// Quick-insert entries returned when no limit is given
+const DEFAULT_QUICK_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct MessageTemplate {
+    pub id: String,
+    pub name: String,
+    pub body: String,
+    /// Typed after "/" in the composer, e.g. "brb"
+    pub shortcut: Option<String>,
+    pub use_count: u32,
+    /// Unix timestamp (seconds); `None` if never used
+    #[ts(type = "number | null")]
+    pub last_used: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct TemplateInput {
+    /// Omit to create a new template
+    pub id: Option<String>,
+    pub name: String,
+    pub body: String,
+    pub shortcut: Option<String>,
+}
+
+/// Managed state holding the persisted templates
+pub struct TemplateState {
+    templates: JsonStore<Vec<MessageTemplate>>,
+}
+
+impl TemplateState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { templates: JsonStore::open(app, TEMPLATES_FILE) }
+    }
+
+    fn get(&self, id: &str) -> Result<MessageTemplate, String> {
+        self.templates.get().into_iter().find(|t| t.id == id).ok_or_else(|| "no template with that id".to_string())
+    }
+}
+
+fn validate(input: &TemplateInput) -> Result<(String, String, Option<String>), String> {
+    let name = input.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+        return Err(format!("the name must have 1 to {} characters", MAX_NAME_LEN));
+    }
+    if input.body.trim().is_empty() || input.body.chars().count() > MAX_BODY_LEN {
+        return Err(format!("the text must have 1 to {} characters", MAX_BODY_LEN));
+    }
+    let shortcut = input.shortcut.as_deref().map(|s| s.trim().trim_start_matches('/').to_lowercase());
+    let shortcut = shortcut.filter(|s| !s.is_empty());
+    if shortcut.as_ref().is_some_and(|s| !s.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_')) {
+        return Err("shortcuts can only contain letters, digits, '-' and '_'".to_string());
+    }
+    Ok((name.to_string(), input.body.clone(), shortcut))
+}
+
+/// Fill in the placeholders of `body` for a message to `network_id`, asking the
+/// daemon only for what the body uses
+async fn expand(daemon: &DaemonState, body: &str, network_id: &str, peer_id: Option<&str>) -> Result<String, String> {
+    let mut text = body.to_string();
+    if text.contains("{peer}") {
+        let peer_id = peer_id.ok_or("this template mentions {peer}; pick who it is for")?;
+        let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(network_id))?;
+        let peer = peers.iter().find(|p| p.id == peer_id).ok_or("that peer is not in the network")?;
+        let name = if peer.display_name.is_empty() { &peer.name } else { &peer.display_name };
+        text = text.replace("{peer}", name);
+    }
+    if text.contains("{network}") {
+        let networks = daemon_call!(daemon, "list_networks", |client| client.list_networks())?;
+        let network = networks.iter().find(|n| n.id == network_id).ok_or("you are not a member of that network")?;
+        text = text.replace("{network}", &network.name);
+    }
+    if text.contains("{my_ip}") {
+        let status = daemon_call!(daemon, "get_status", |client| client.get_status())?;
+        if status.virtual_ip.is_empty() {
+            return Err("this template mentions {my_ip}, but there is no virtual IP while disconnected".to_string());
+        }
+        text = text.replace("{my_ip}", &status.virtual_ip);
+    }
+    Ok(text)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// All templates, by name
+#[tauri::command]
+pub async fn list_message_templates(state: State<'_, TemplateState>) -> Result<Vec<MessageTemplate>, String> {
+    let mut templates = state.templates.get();
+    templates.sort_by_key(|t| t.name.to_lowercase());
+    Ok(templates)
+}
+
+/// Create a template, or update the one with `input.id`
+#[tauri::command]
+pub async fn save_message_template(
+    state: State<'_, TemplateState>,
+    input: TemplateInput,
+) -> Result<MessageTemplate, String> {
+    let (name, body, shortcut) = validate(&input)?;
+    state.templates.update(|templates| {
+        let taken = |t: &MessageTemplate| {
+            t.shortcut.is_some() && t.shortcut == shortcut && input.id.as_ref() != Some(&t.id)
+        };
+        if let Some(other) = templates.iter().find(|t| taken(t)) {
+            return Err(format!("the shortcut is already used by '{}'", other.name));
+        }
+        match &input.id {
+            Some(id) => {
+                let template = templates.iter_mut().find(|t| t.id == *id);
+                let template = template.ok_or_else(|| "no template with that id".to_string())?;
+                template.name = name;
+                template.body = body;
+                template.shortcut = shortcut;
+                Ok(template.clone())
+            }
+            None if templates.len() >= MAX_TEMPLATES => {
+                Err(format!("at most {} templates can be kept", MAX_TEMPLATES))
+            }
+            None => {
+                let template = MessageTemplate {
+                    id: hex::encode(rand::random::<[u8; 8]>()),
+                    name,
+                    body,
+                    shortcut,
+                    use_count: 0,
+                    last_used: None,
+                };
+                templates.push(template.clone());
+                Ok(template)
+            }
+        }
+    })?
+}
+
+#[tauri::command]
+pub async fn delete_message_template(state: State<'_, TemplateState>, id: String) -> Result<(), String> {
+    state.get(&id)?;
+    state.templates.update(|templates| templates.retain(|t| t.id != id))
+}
+
+/// Templates for the composer's quick-insert menu, most used first; `query`
+/// matches names and shortcuts
+#[tauri::command]
+pub async fn quick_insert_templates(
+    state: State<'_, TemplateState>,
+    query: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<MessageTemplate>, String> {
+    let query = query.map(|q| q.trim().trim_start_matches('/').to_lowercase()).unwrap_or_default();
+    let mut templates: Vec<MessageTemplate> = state
+        .templates
+        .get()
+        .into_iter()
+        .filter(|t| {
+            query.is_empty()
+                || t.name.to_lowercase().contains(&query)
+                || t.shortcut.as_ref().is_some_and(|s| s.starts_with(&query))
+        })
+        .collect();
+    templates.sort_by_key(|t| std::cmp::Reverse((t.use_count, t.last_used)));
+    templates.truncate(limit.unwrap_or(DEFAULT_QUICK_LIMIT));
+    Ok(templates)
+}
+
+/// The text a template would send right now, for the composer to show or edit
+#[tauri::command]
+pub async fn expand_message_template(
+    state: State<'_, TemplateState>,
+    daemon: State<'_, DaemonState>,
+    id: String,
+    network_id: String,
+    peer_id: Option<String>,
+) -> Result<String, String> {
+    let template = state.get(&id)?;
+    expand(&daemon, &template.body, &network_id, peer_id.as_deref()).await
+}
+
+/// Expand a template and send it to `network_id`; returns the text sent
+#[tauri::command]
+pub async fn send_template_message(
+    state: State<'_, TemplateState>,
+    daemon: State<'_, DaemonState>,
+    id: String,
+    network_id: String,
+    peer_id: Option<String>,
+) -> Result<String, String> {
+    let template = state.get(&id)?;
+    let content = expand(&daemon, &template.body, &network_id, peer_id.as_deref()).await?;
+    daemon_call!(daemon, "send_message", |client| client.send_message(&network_id, &content))?;
+    state.templates.update(|templates| {
+        if let Some(t) = templates.iter_mut().find(|t| t.id == id) {
+            t.use_count += 1;
+            t.last_used = Some(unix_now());
+        }
+    })?;
+    Ok(content)
+}
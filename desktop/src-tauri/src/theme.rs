@@ -0,0 +1,209 @@
+// Theme
+// Follows the OS light/dark appearance, keeps the user's preference in the
+// daemon's Settings.theme, and matches the tray icon to the menu bar.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::store::JsonStore;
+use std::sync::Mutex;
+use tauri::image::Image;
+use tauri::{AppHandle, Emitter, Manager, State, Theme};
+use ts_rs::TS;
+
+/// Tauri event emitted with a `ThemeInfo` whenever the effective theme may have changed
+pub const CHANGED_EVENT: &str = "theme://changed";
+
+/// Last known preference, used until the daemon answers (or when it cannot store it)
+const CACHE_FILE: &str = "theme.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ThemePreference {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemePreference {
+    /// Parse the daemon's free-form `Settings.theme`; anything unknown follows the OS
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "light" => Self::Light,
+            "dark" => Self::Dark,
+            _ => Self::System,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum Appearance {
+    Light,
+    Dark,
+}
+
+impl From<Theme> for Appearance {
+    fn from(theme: Theme) -> Self {
+        match theme {
+            Theme::Dark => Self::Dark,
+            _ => Self::Light,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ThemeInfo {
+    pub preference: ThemePreference,
+    /// Appearance reported by the OS
+    pub system: Appearance,
+    /// Appearance the UI should use
+    pub effective: Appearance,
+}
+
+/// Managed state tracking the preference and the last OS appearance seen
+pub struct ThemeState {
+    preference: JsonStore<ThemePreference>,
+    system: Mutex<Appearance>,
+}
+
+impl ThemeState {
+    pub fn load(app: &AppHandle) -> Self {
+        let system = app
+            .get_webview_window("main")
+            .and_then(|w| w.theme().ok())
+            .map(Appearance::from)
+            .unwrap_or(Appearance::Light);
+        Self {
+            preference: JsonStore::open(app, CACHE_FILE),
+            system: Mutex::new(system),
+        }
+    }
+
+    pub fn info(&self) -> ThemeInfo {
+        let preference = self.preference.get();
+        let system = *self.system.lock().unwrap();
+        let effective = match preference {
+            ThemePreference::System => system,
+            ThemePreference::Light => Appearance::Light,
+            ThemePreference::Dark => Appearance::Dark,
+        };
+        ThemeInfo { preference, system, effective }
+    }
+}
+
+/// Apply the cached preference, then adopt the daemon's once it is reachable
+pub fn init(app: &AppHandle) {
+    apply(app);
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<DaemonState>();
+        if let Ok(settings) = daemon_call!(state, "get_settings", |client| client.get_settings()) {
+            adopt(&app, &settings.theme);
+        }
+    });
+}
+
+/// Follow the daemon's `Settings.theme`, e.g. after it was changed elsewhere
+pub(crate) fn adopt(app: &AppHandle, theme: &str) {
+    if theme.is_empty() {
+        return;
+    }
+    let preference = ThemePreference::parse(theme);
+    let state = app.state::<ThemeState>();
+    if state.preference.get() != preference && state.preference.set(preference).is_ok() {
+        apply(app);
+    }
+}
+
+/// Called from the window event loop when the OS appearance changes
+pub fn system_theme_changed(app: &AppHandle, theme: Theme) {
+    let state = app.state::<ThemeState>();
+
+    // While a theme is forced the window reports that theme, not the OS one
+    if state.preference.get() != ThemePreference::System {
+        return;
+    }
+    *state.system.lock().unwrap() = theme.into();
+    apply(app);
+}
+
+/// Push the current theme to the windows, the tray and the frontend
+fn apply(app: &AppHandle) {
+    let info = app.state::<ThemeState>().info();
+
+    app.set_theme(match info.preference {
+        ThemePreference::System => None,
+        ThemePreference::Light => Some(Theme::Light),
+        ThemePreference::Dark => Some(Theme::Dark),
+    });
+    update_tray_icon(app, info.system);
+    let _ = app.emit(CHANGED_EVENT, info);
+}
+
+/// The tray lives in the OS menu bar, so it follows the OS appearance rather than the app theme.
+/// macOS recolors template icons itself.
+fn update_tray_icon(app: &AppHandle, menu_bar: Appearance) {
+    if cfg!(target_os = "macos") {
+        return;
+    }
+    let (Some(tray), Some(icon)) = (app.tray_by_id(crate::tray::TRAY_ID), app.default_window_icon()) else {
+        return;
+    };
+
+    // A light glyph on dark bars and a dark glyph on light bars, keeping the icon's alpha
+    let shade = match menu_bar {
+        Appearance::Dark => 0xF2,
+        Appearance::Light => 0x20,
+    };
+    let rgba = icon
+        .rgba()
+        .chunks_exact(4)
+        .flat_map(|px| [shade, shade, shade, px[3]])
+        .collect();
+    let _ = tray.set_icon(Some(Image::new_owned(rgba, icon.width(), icon.height())));
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_theme(state: State<'_, ThemeState>) -> Result<ThemeInfo, String> {
+    Ok(state.info())
+}
+
+/// Change the preference, apply it immediately and store it in the daemon settings
+#[tauri::command]
+pub async fn set_theme(
+    app: AppHandle,
+    daemon: State<'_, DaemonState>,
+    state: State<'_, ThemeState>,
+    preference: ThemePreference,
+) -> Result<ThemeInfo, String> {
+    state.preference.set(preference)?;
+    if preference == ThemePreference::System {
+        // Re-read the OS appearance now that the window is no longer forced
+        app.set_theme(None);
+        if let Some(theme) = app.get_webview_window("main").and_then(|w| w.theme().ok()) {
+            *state.system.lock().unwrap() = theme.into();
+        }
+    }
+    apply(&app);
+
+    let mut settings = daemon_call!(daemon, "get_settings", |client| client.get_settings())?;
+    settings.theme = preference.as_str().to_string();
+    daemon_call!(daemon, "update_settings", |client| client.update_settings(&settings))?;
+    Ok(state.info())
+}
@@ -0,0 +1,287 @@
+// Event Throttling
+// Heavy transfers produce dozens of progress events per second per transfer,
+// busy networks a steady stream of joins and leaves; forwarding each one keeps
+// the webview re-rendering. High-frequency events pass through here on their
+// way out. Each type has a rate: the first event after a quiet interval goes
+// out at once, what arrives within the interval is coalesced (latest progress
+// per transfer, latest bandwidth sample, peer changes folded into one diff)
+// and flushed when the interval is up. Transfers reaching a final state are
+// never held back.
+
+use crate::daemon::PeerChange;
+use crate::live_events::{self, BandwidthSample, LiveEvent, TransferProgress};
+use crate::peer_index::{self, PeerDiff};
+use crate::store::JsonStore;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+const SETTINGS_FILE: &str = "throttle.json";
+
+/// How often coalesced events are checked for being due
+const FLUSH_TICK: Duration = Duration::from_millis(50);
+
+/// Highest configurable rate; anything faster is what throttling is for
+const MAX_RATE: u32 = 60;
+
+/// Per-transfer state is dropped after this long without events
+const IDLE_LANE: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct ThrottleSettings {
+    /// Off forwards every event as it arrives
+    pub enabled: bool,
+    /// Progress events per second for each transfer; 0 forwards all
+    pub transfer_progress_per_sec: u32,
+    /// Bandwidth samples per second; 0 forwards all
+    pub bandwidth_per_sec: u32,
+    /// Peer diffs per second; 0 sends one per change
+    pub peer_changes_per_sec: u32,
+}
+
+impl Default for ThrottleSettings {
+    fn default() -> Self {
+        Self { enabled: true, transfer_progress_per_sec: 5, bandwidth_per_sec: 1, peer_changes_per_sec: 2 }
+    }
+}
+
+impl ThrottleSettings {
+    fn interval(&self, per_sec: u32) -> Option<Duration> {
+        (self.enabled && per_sec > 0).then(|| Duration::from_secs(1) / per_sec)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum EventKind {
+    TransferProgress,
+    Bandwidth,
+    PeerChanges,
+}
+
+impl EventKind {
+    const ALL: [Self; 3] = [Self::TransferProgress, Self::Bandwidth, Self::PeerChanges];
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ThrottleStats {
+    pub kind: EventKind,
+    /// Events offered since startup
+    #[ts(type = "number")]
+    pub received: u64,
+    /// Events actually sent to the webview
+    #[ts(type = "number")]
+    pub emitted: u64,
+}
+
+/// Pending value of one rate-limited stream
+struct Lane<T> {
+    pending: Option<T>,
+    last: Option<Instant>,
+}
+
+impl<T> Default for Lane<T> {
+    fn default() -> Self {
+        Self { pending: None, last: None }
+    }
+}
+
+impl<T> Lane<T> {
+    /// Fold a new event into the pending value; returns it when it may go out now
+    fn offer(&mut self, interval: Option<Duration>, now: Instant, fold: impl FnOnce(&mut Option<T>)) -> Option<T> {
+        fold(&mut self.pending);
+        self.flush(interval, now)
+    }
+
+    fn flush(&mut self, interval: Option<Duration>, now: Instant) -> Option<T> {
+        let open = interval.is_none_or(|i| self.last.is_none_or(|at| now.duration_since(at) >= i));
+        if !open || self.pending.is_none() {
+            return None;
+        }
+        self.last = Some(now);
+        self.pending.take()
+    }
+}
+
+#[derive(Default)]
+struct Lanes {
+    progress: HashMap<String, Lane<TransferProgress>>,
+    bandwidth: Lane<BandwidthSample>,
+    peers: Lane<PeerDiff>,
+    /// (received, emitted) per `EventKind::ALL` entry
+    counts: [(u64, u64); 3],
+}
+
+impl Lanes {
+    fn count(&mut self, kind: EventKind, received: u64, emitted: u64) {
+        let counts = &mut self.counts[kind as usize];
+        counts.0 += received;
+        counts.1 += emitted;
+    }
+}
+
+/// Everything due, collected under the lock and sent after releasing it
+#[derive(Default)]
+struct Due {
+    events: Vec<LiveEvent>,
+    peers: Vec<PeerDiff>,
+}
+
+/// Managed state holding throttle settings and coalesced events
+pub struct ThrottleState {
+    settings: JsonStore<ThrottleSettings>,
+    lanes: Mutex<Lanes>,
+}
+
+impl ThrottleState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), lanes: Mutex::new(Lanes::default()) }
+    }
+}
+
+fn send(app: &AppHandle, due: Due) {
+    for event in due.events {
+        live_events::deliver(app, event);
+    }
+    for diff in due.peers {
+        let _ = app.emit(peer_index::CHANGED_EVENT, diff);
+    }
+}
+
+/// Send a progress or bandwidth event now or once its interval is up
+pub(crate) fn offer(app: &AppHandle, event: LiveEvent) {
+    let state = app.state::<ThrottleState>();
+    let settings = state.settings.get();
+    let now = Instant::now();
+    let mut due = Due::default();
+    {
+        let mut lanes = state.lanes.lock().unwrap();
+        match event {
+            LiveEvent::Progress(progress) => {
+                let id = progress.id.clone();
+                let finished = !matches!(progress.status.as_str(), "pending" | "active");
+                let lane = lanes.progress.entry(id.clone()).or_default();
+                if finished {
+                    lane.last = None;
+                }
+                let sent = lane.offer(settings.interval(settings.transfer_progress_per_sec), now, |p| {
+                    *p = Some(progress)
+                });
+                if finished {
+                    lanes.progress.remove(&id);
+                }
+                lanes.count(EventKind::TransferProgress, 1, sent.is_some() as u64);
+                due.events.extend(sent.map(LiveEvent::Progress));
+            }
+            LiveEvent::Bandwidth(sample) => {
+                let sent =
+                    lanes.bandwidth.offer(settings.interval(settings.bandwidth_per_sec), now, |s| *s = Some(sample));
+                lanes.count(EventKind::Bandwidth, 1, sent.is_some() as u64);
+                due.events.extend(sent.map(LiveEvent::Bandwidth));
+            }
+        }
+    }
+    send(app, due);
+}
+
+/// Fold a join or leave into the pending peer diff
+pub(crate) fn offer_peer_change(app: &AppHandle, network_id: Option<String>, change: PeerChange) {
+    let state = app.state::<ThrottleState>();
+    let settings = state.settings.get();
+    let now = Instant::now();
+    let mut due = Due::default();
+    {
+        let mut lanes = state.lanes.lock().unwrap();
+        // A diff never spans networks: send what belongs to the previous one first
+        if lanes.peers.pending.as_ref().is_some_and(|diff| diff.network_id != network_id) {
+            due.peers.extend(lanes.peers.pending.take());
+        }
+        let sent = lanes.peers.offer(settings.interval(settings.peer_changes_per_sec), now, |pending| {
+            pending.get_or_insert_with(|| PeerDiff { network_id, ..Default::default() }).merge(change)
+        });
+        due.peers.extend(sent);
+        let emitted = due.peers.len() as u64;
+        lanes.count(EventKind::PeerChanges, 1, emitted);
+    }
+    send(app, due);
+}
+
+fn flush_due(state: &ThrottleState) -> Due {
+    let settings = state.settings.get();
+    let now = Instant::now();
+    let mut due = Due::default();
+    let mut lanes = state.lanes.lock().unwrap();
+    let interval = settings.interval(settings.transfer_progress_per_sec);
+    for lane in lanes.progress.values_mut() {
+        due.events.extend(lane.flush(interval, now).map(LiveEvent::Progress));
+    }
+    // Transfers that vanished without a final event
+    lanes.progress.retain(|_, lane| lane.pending.is_some() || lane.last.is_some_and(|at| now - at < IDLE_LANE));
+    let progress = due.events.len() as u64;
+    lanes.count(EventKind::TransferProgress, 0, progress);
+
+    let sample = lanes.bandwidth.flush(settings.interval(settings.bandwidth_per_sec), now);
+    lanes.count(EventKind::Bandwidth, 0, sample.is_some() as u64);
+    due.events.extend(sample.map(LiveEvent::Bandwidth));
+
+    due.peers.extend(lanes.peers.flush(settings.interval(settings.peer_changes_per_sec), now));
+    let peers = due.peers.len() as u64;
+    lanes.count(EventKind::PeerChanges, 0, peers);
+    due
+}
+
+/// Flush coalesced events as their intervals run out
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<ThrottleState>();
+        let mut interval = tokio::time::interval(FLUSH_TICK);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            let due = flush_due(&state);
+            send(&app, due);
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_throttle_settings(state: State<'_, ThrottleState>) -> Result<ThrottleSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_throttle_settings(
+    state: State<'_, ThrottleState>,
+    settings: ThrottleSettings,
+) -> Result<ThrottleSettings, String> {
+    for rate in [settings.transfer_progress_per_sec, settings.bandwidth_per_sec, settings.peer_changes_per_sec] {
+        if rate > MAX_RATE {
+            return Err(format!("rates must be between 0 (unthrottled) and {} per second", MAX_RATE));
+        }
+    }
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
+
+/// How much each event type was coalesced since startup
+#[tauri::command]
+pub async fn get_throttle_stats(state: State<'_, ThrottleState>) -> Result<Vec<ThrottleStats>, String> {
+    let lanes = state.lanes.lock().unwrap();
+    Ok(EventKind::ALL
+        .iter()
+        .map(|&kind| {
+            let (received, emitted) = lanes.counts[kind as usize];
+            ThrottleStats { kind, received, emitted }
+        })
+        .collect())
+}
@@ -0,0 +1,138 @@
+// Network Topology
+// Graph of a network as seen from this node, for the topology view: every peer is
+// a node, and links are direct or go through a relay node with latency weights.
+
+use crate::commands::{daemon_call, DaemonState};
+use tauri::State;
+use ts_rs::TS;
+
+/// Id of the synthetic node standing in for the relay server
+pub const RELAY_NODE_ID: &str = "relay";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum NodeKind {
+    Peer,
+    Relay,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum LinkKind {
+    Direct,
+    Relayed,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TopologyNode {
+    pub id: String,
+    pub kind: NodeKind,
+    pub name: String,
+    pub virtual_ip: String,
+    /// "owner", "admin", "member", or empty
+    pub role: String,
+    pub is_self: bool,
+    pub online: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TopologyEdge {
+    pub from: String,
+    pub to: String,
+    pub link: LinkKind,
+    /// Latency reported for the peer at the far end; `None` when unknown
+    #[ts(type = "number | null")]
+    pub latency_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct Topology {
+    pub network_id: String,
+    pub nodes: Vec<TopologyNode>,
+    pub edges: Vec<TopologyEdge>,
+    pub direct_links: u32,
+    pub relayed_links: u32,
+    pub offline_peers: u32,
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Peers of `network_id` and how this node reaches each of them.
+/// Only links from this node are known; peers' links to each other are not reported.
+#[tauri::command]
+pub async fn get_topology(daemon: State<'_, DaemonState>, network_id: String) -> Result<Topology, String> {
+    let peers = daemon_call!(daemon, "get_network_peers", |client| client.get_network_peers(&network_id))?;
+
+    let self_id = peers.iter().find(|p| p.is_self).map(|p| p.id.clone());
+    let mut nodes = Vec::with_capacity(peers.len() + 1);
+    let mut edges = Vec::new();
+    let (mut direct_links, mut relayed_links, mut offline_peers) = (0, 0, 0);
+
+    for peer in &peers {
+        nodes.push(TopologyNode {
+            id: peer.id.clone(),
+            kind: NodeKind::Peer,
+            name: if peer.display_name.is_empty() { peer.name.clone() } else { peer.display_name.clone() },
+            virtual_ip: peer.virtual_ip.clone(),
+            role: peer.role.clone(),
+            is_self: peer.is_self,
+            online: peer.is_self || peer.connected,
+        });
+        if peer.is_self {
+            continue;
+        }
+        if !peer.connected {
+            offline_peers += 1;
+            continue;
+        }
+        let Some(self_id) = &self_id else { continue };
+
+        let latency_ms = (peer.latency_ms > 0).then_some(peer.latency_ms);
+        if peer.is_relay {
+            relayed_links += 1;
+            edges.push(TopologyEdge {
+                from: RELAY_NODE_ID.to_string(),
+                to: peer.id.clone(),
+                link: LinkKind::Relayed,
+                latency_ms,
+            });
+        } else {
+            direct_links += 1;
+            edges.push(TopologyEdge {
+                from: self_id.clone(),
+                to: peer.id.clone(),
+                link: LinkKind::Direct,
+                latency_ms,
+            });
+        }
+    }
+
+    if relayed_links > 0 {
+        if let Some(self_id) = &self_id {
+            nodes.push(TopologyNode {
+                id: RELAY_NODE_ID.to_string(),
+                kind: NodeKind::Relay,
+                name: "Relay".to_string(),
+                virtual_ip: String::new(),
+                role: String::new(),
+                is_self: false,
+                online: true,
+            });
+            edges.push(TopologyEdge {
+                from: self_id.clone(),
+                to: RELAY_NODE_ID.to_string(),
+                link: LinkKind::Relayed,
+                latency_ms: None,
+            });
+        }
+    }
+
+    Ok(Topology { network_id, nodes, edges, direct_links, relayed_links, offline_peers })
+}
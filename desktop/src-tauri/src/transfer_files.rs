@@ -0,0 +1,62 @@
+// Transfer Files
+// Opening a received file, or the folder it is in, straight from the transfer
+// list. The file is found by the path remembered when the transfer was accepted,
+// never by a path from the webview, and only files inside the download folder
+// or a save folder (see `save_folders`) are opened: a file name picked by the
+// sender must not turn into launching something elsewhere on the system.
+// Executables are checked with the reputation service first, when one is set
+// up (see `reputation`).
+
+use crate::activity::ActivityState;
+use crate::auto_accept;
+use crate::commands::{daemon_call, DaemonState};
+use crate::reputation;
+use crate::save_folders;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_opener::OpenerExt;
+
+/// Where a transfer was saved, once it is confirmed to be inside the download folder or a save folder
+pub(crate) fn saved_file(app: &AppHandle, transfer_id: &str) -> Result<PathBuf, String> {
+    let path = app
+        .state::<ActivityState>()
+        .path_of(transfer_id)
+        .ok_or("where this transfer was saved is not known")?;
+    // Resolved so `..` and symlinks cannot lead out of the folder
+    let file = std::fs::canonicalize(&path).map_err(|e| format!("{} is no longer there: {}", path, e))?;
+    let dirs = std::iter::once(auto_accept::download_dir(app)?).chain(save_folders::all(app));
+    let inside = dirs.filter_map(|dir| std::fs::canonicalize(dir).ok()).any(|dir| file.starts_with(dir));
+    if !inside || !file.is_file() {
+        return Err(format!("{} is not a file in the download folder or a save folder", path));
+    }
+    Ok(file)
+}
+
+/// The sender's hash of a transfer's file, while the daemon still lists the transfer
+pub(crate) async fn reported_hash(app: &AppHandle, transfer_id: &str) -> Option<String> {
+    let daemon = app.state::<DaemonState>();
+    let transfers = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None)).ok()?;
+    transfers.into_iter().find(|t| t.id == transfer_id).and_then(|t| t.sha256)
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Show a received file in the system's file manager
+#[tauri::command]
+pub async fn transfer_reveal_in_folder(app: AppHandle, transfer_id: String) -> Result<(), String> {
+    let file = saved_file(&app, &transfer_id)?;
+    app.opener().reveal_item_in_dir(&file).map_err(|e| format!("failed to show {:?}: {}", file, e))
+}
+
+/// Open a received file with its default application
+#[tauri::command]
+pub async fn transfer_open_file(app: AppHandle, transfer_id: String) -> Result<(), String> {
+    let file = saved_file(&app, &transfer_id)?;
+    let sha256 = reported_hash(&app, &transfer_id).await;
+    reputation::guard_open(&app, &transfer_id, sha256, &file).await?;
+    app.opener()
+        .open_path(file.to_string_lossy(), None::<&str>)
+        .map_err(|e| format!("failed to open {:?}: {}", file, e))
+}
@@ -0,0 +1,89 @@
+// Incoming Transfer Prompt
+// A desktop notification for each incoming transfer request, with Accept and
+// Decline buttons so it can be answered without opening the window. Accepted
+// files go to the download folder used for auto-accepted transfers; clicking
+// the notification itself brings up the main window, where the request is
+// waiting too. Where notifications cannot carry buttons it is a plain one.
+
+use crate::auto_accept;
+use crate::commands::{daemon_call, DaemonState};
+use crate::daemon::{PeerInfo, TransferInfo};
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind, DEFAULT_ACTION};
+use crate::peer_index::PeerIndexState;
+use crate::report::format_bytes;
+use crate::save_paths::AcceptOutcome;
+use tauri::{AppHandle, Manager};
+
+const ACCEPT: &str = "accept";
+const DECLINE: &str = "decline";
+/// The notification itself was clicked
+const OPEN: &str = DEFAULT_ACTION;
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+async fn on_action(app: &AppHandle, transfer: &TransferInfo, action: &str) -> Result<(), String> {
+    match action {
+        ACCEPT => match auto_accept::accept(app, transfer).await? {
+            AcceptOutcome::Accepted { path, .. } => {
+                log::info!("Accepted {} from the notification into {}", transfer.file_name, path);
+            }
+            AcceptOutcome::Skipped { existing } => {
+                log::info!("Skipped {} from the notification: {} exists", transfer.file_name, existing);
+            }
+            AcceptOutcome::NeedsDecision { existing } => {
+                show_main_window(app);
+                return Err(format!("{} already exists; choose what to do in the window", existing));
+            }
+        },
+        DECLINE => {
+            let daemon = app.state::<DaemonState>();
+            daemon_call!(daemon, "reject_transfer", |client| client.reject_transfer(&transfer.id))?;
+        }
+        OPEN => show_main_window(app),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Announce an incoming transfer request with buttons to answer it
+pub(crate) async fn offer(app: &AppHandle, transfer: &TransferInfo) {
+    let daemon = app.state::<DaemonState>();
+    let name = |p: &PeerInfo| if p.display_name.is_empty() { p.name.clone() } else { p.display_name.clone() };
+    let sender = app
+        .state::<PeerIndexState>()
+        .with_snapshot(&daemon, None, |s| s.peers.get(&transfer.peer_id).map(name))
+        .await
+        .ok()
+        .and_then(|(_, name)| name)
+        .unwrap_or_else(|| transfer.peer_id.clone());
+    let body = i18n::text(Text::TransferIncomingBody)
+        .replace("{sender}", &sender)
+        .replace("{file}", &transfer.file_name)
+        .replace("{size}", &format_bytes(transfer.file_size));
+
+    let (handle, request) = (app.clone(), transfer.clone());
+    let actions = [
+        (ACCEPT, i18n::text(Text::ActionAccept)),
+        (DECLINE, i18n::text(Text::ActionDecline)),
+        (OPEN, i18n::text(Text::ActionOpen)),
+    ];
+    let answer = move |action: &str| {
+        let action = action.to_string();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = on_action(&handle, &request, &action).await {
+                log::warn!("Failed to {} transfer {} from the notification: {}", action, request.id, e);
+                let title = i18n::text(Text::TransferFailedTitle);
+                notifications::notify(&handle, NotificationKind::Transfer, title, &e);
+            }
+        });
+    };
+    let title = i18n::text(Text::TransferIncomingTitle);
+    notifications::notify_with_actions(app, NotificationKind::Transfer, title, &body, &actions, answer);
+}
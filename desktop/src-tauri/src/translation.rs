@@ -0,0 +1,334 @@
+// Message Translation
+// Optional translation of chat messages through a provider the user sets up:
+// a local model served by Ollama, or a LibreTranslate-compatible API, whose key
+// is kept in the system keyring (in memory only for portable runs). Nothing
+// leaves the machine until a provider is configured. Conversations with
+// auto-translate on have every incoming message translated by the event bridge
+// and announced with `TRANSLATED_EVENT`. Results are cached by text and target
+// language so a message is only ever sent to the provider once.
+
+use crate::i18n;
+use crate::store::JsonStore;
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Emitted with a `TranslatedMessage` for auto-translated incoming messages
+pub const TRANSLATED_EVENT: &str = "chat://translated";
+
+const SETTINGS_FILE: &str = "translation.json";
+const CACHE_FILE: &str = "translation_cache.json";
+
+const KEYRING_SERVICE: &str = "goconnect-translation";
+const KEYRING_USER: &str = "api-key";
+
+/// Oldest translations are dropped beyond this many
+const MAX_CACHED: usize = 2000;
+const MAX_TEXT_LEN: usize = 5000;
+
+/// Local models can be slow to answer the first request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum TranslationProvider {
+    #[default]
+    Disabled,
+    /// A local model served by Ollama, e.g. at http://localhost:11434
+    Ollama,
+    /// A LibreTranslate-compatible API, self-hosted or remote
+    LibreTranslate,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct TranslationSettings {
+    pub provider: TranslationProvider,
+    /// Base URL of the provider
+    pub endpoint: String,
+    /// Model name for Ollama
+    pub model: String,
+    /// Language code to translate into; empty = the app's language
+    pub target_language: String,
+    /// Network or peer ids of conversations translated automatically
+    pub auto_translate: Vec<String>,
+    /// Set when an API key is stored; the key itself is never returned
+    pub has_api_key: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct Translation {
+    pub text: String,
+    pub target_language: String,
+    /// Language the provider detected, when it reports one
+    pub detected_language: Option<String>,
+    /// Answered from the local cache
+    pub cached: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct TranslatedMessage {
+    pub network_id: String,
+    pub message_id: String,
+    pub translation: Translation,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CachedTranslation {
+    /// SHA-256 of the original text
+    source: String,
+    translation: Translation,
+}
+
+/// Managed state holding the translation settings and cache
+pub struct TranslationState {
+    settings: JsonStore<TranslationSettings>,
+    cache: JsonStore<Vec<CachedTranslation>>,
+    /// The API key of a portable run, which must not touch the host's keyring
+    session_key: Mutex<Option<String>>,
+    http: reqwest::Client,
+}
+
+impl TranslationState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            cache: JsonStore::open(app, CACHE_FILE),
+            session_key: Mutex::new(None),
+            http: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .user_agent(concat!("GoConnect-Desktop/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Whether incoming messages of `conversation_id` are translated automatically
+    pub fn auto_translates(&self, conversation_id: &str) -> bool {
+        let settings = self.settings.get();
+        settings.provider != TranslationProvider::Disabled
+            && settings.auto_translate.iter().any(|c| c == conversation_id)
+    }
+
+    fn api_key(&self) -> Option<String> {
+        if crate::portable::enabled() {
+            return self.session_key.lock().unwrap().clone();
+        }
+        keyring_entry().ok()?.get_password().ok()
+    }
+
+    fn set_api_key(&self, key: Option<&str>) -> Result<(), String> {
+        if crate::portable::enabled() {
+            *self.session_key.lock().unwrap() = key.map(String::from);
+            return Ok(());
+        }
+        let entry = keyring_entry()?;
+        match key {
+            Some(key) => entry.set_password(key).map_err(|e| format!("failed to save to the keyring: {}", e)),
+            None => match entry.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(e) => Err(e.to_string()),
+            },
+        }
+    }
+
+    /// Translate `text`, from the cache when it was translated before
+    pub async fn translate(&self, text: &str, target_language: Option<&str>) -> Result<Translation, String> {
+        if text.trim().is_empty() || text.chars().count() > MAX_TEXT_LEN {
+            return Err(format!("only messages of 1 to {} characters can be translated", MAX_TEXT_LEN));
+        }
+        let settings = self.settings.get();
+        let target = [target_language.unwrap_or_default(), &settings.target_language, i18n::current()]
+            .into_iter()
+            .find(|l| !l.trim().is_empty())
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        let source = hex::encode(Sha256::digest(text.as_bytes()));
+        let cache = self.cache.get();
+        let cached = cache.into_iter().find(|c| c.source == source && c.translation.target_language == target);
+        if let Some(cached) = cached {
+            return Ok(Translation { cached: true, ..cached.translation });
+        }
+
+        let translation = match settings.provider {
+            TranslationProvider::Disabled => return Err("translation is not set up".to_string()),
+            TranslationProvider::Ollama => self.ollama(&settings, text, &target).await?,
+            TranslationProvider::LibreTranslate => self.libre_translate(&settings, text, &target).await?,
+        };
+        self.cache.update(|cache| {
+            cache.push(CachedTranslation { source, translation: translation.clone() });
+            let excess = cache.len().saturating_sub(MAX_CACHED);
+            cache.drain(..excess);
+        })?;
+        Ok(translation)
+    }
+
+    async fn ollama(&self, settings: &TranslationSettings, text: &str, target: &str) -> Result<Translation, String> {
+        #[derive(serde::Deserialize)]
+        struct Reply {
+            response: String,
+        }
+        let prompt = format!(
+            "Translate the following chat message into the language with code '{}'. \
+             Reply with the translation only.\n\n{}",
+            target, text
+        );
+        let body = serde_json::json!({ "model": settings.model, "prompt": prompt, "stream": false });
+        let reply: Reply = self.post(&settings.endpoint, "/api/generate", &body).await?;
+        Ok(Translation {
+            text: reply.response.trim().to_string(),
+            target_language: target.to_string(),
+            detected_language: None,
+            cached: false,
+        })
+    }
+
+    async fn libre_translate(
+        &self,
+        settings: &TranslationSettings,
+        text: &str,
+        target: &str,
+    ) -> Result<Translation, String> {
+        #[derive(serde::Deserialize)]
+        struct Detected {
+            language: String,
+        }
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Reply {
+            translated_text: String,
+            detected_language: Option<Detected>,
+        }
+        let mut body = serde_json::json!({ "q": text, "source": "auto", "target": target, "format": "text" });
+        if let Some(key) = self.api_key() {
+            body["api_key"] = key.into();
+        }
+        let reply: Reply = self.post(&settings.endpoint, "/translate", &body).await?;
+        Ok(Translation {
+            text: reply.translated_text,
+            target_language: target.to_string(),
+            detected_language: reply.detected_language.map(|d| d.language),
+            cached: false,
+        })
+    }
+
+    async fn post<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<T, String> {
+        let url = format!("{}{}", endpoint.trim_end_matches('/'), path);
+        let response = self.http.post(&url).json(body).send().await.map_err(|e| {
+            log::warn!("Translation request to {} failed: {}", url, e);
+            "the translation service could not be reached".to_string()
+        })?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("the translation service answered with {}", status));
+        }
+        response.json().await.map_err(|e| format!("unexpected answer from the translation service: {}", e))
+    }
+}
+
+fn keyring_entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER).map_err(|e| e.to_string())
+}
+
+/// Translate an incoming message in the background if its conversation asks for it
+pub(crate) fn offer_incoming(app: &AppHandle, network_id: &str, message_id: &str, content: &str) {
+    if !app.state::<TranslationState>().auto_translates(network_id) {
+        return;
+    }
+    let app = app.clone();
+    let (network_id, message_id, content) = (network_id.to_string(), message_id.to_string(), content.to_string());
+    tauri::async_runtime::spawn(async move {
+        match app.state::<TranslationState>().translate(&content, None).await {
+            Ok(translation) => {
+                let _ = app.emit(TRANSLATED_EVENT, TranslatedMessage { network_id, message_id, translation });
+            }
+            Err(e) => log::debug!("Auto-translation of {} failed: {}", message_id, e),
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_translation_settings(state: State<'_, TranslationState>) -> Result<TranslationSettings, String> {
+    Ok(state.settings.get())
+}
+
+/// Save the provider settings. `api_key` replaces the stored key; an empty
+/// string removes it and omitting it keeps it.
+#[tauri::command]
+pub async fn set_translation_settings(
+    state: State<'_, TranslationState>,
+    settings: TranslationSettings,
+    api_key: Option<String>,
+) -> Result<TranslationSettings, String> {
+    if settings.provider != TranslationProvider::Disabled {
+        let url = reqwest::Url::parse(&settings.endpoint).map_err(|e| format!("invalid endpoint URL: {}", e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("the endpoint must use http or https".to_string());
+        }
+    }
+    if settings.provider == TranslationProvider::Ollama && settings.model.trim().is_empty() {
+        return Err("choose the model Ollama should use".to_string());
+    }
+    if let Some(key) = api_key.as_deref() {
+        state.set_api_key(Some(key.trim()).filter(|k| !k.is_empty()))?;
+    }
+    let has_api_key = state.api_key().is_some();
+    state.settings.update(|current| {
+        *current = TranslationSettings {
+            // Auto-translate is toggled per conversation, not from the settings form
+            auto_translate: std::mem::take(&mut current.auto_translate),
+            has_api_key,
+            ..settings
+        };
+        current.clone()
+    })
+}
+
+/// Turn auto-translate on or off for a network or peer conversation
+#[tauri::command]
+pub async fn set_auto_translate(
+    state: State<'_, TranslationState>,
+    conversation_id: String,
+    enabled: bool,
+) -> Result<(), String> {
+    if enabled && state.settings.get().provider == TranslationProvider::Disabled {
+        return Err("set up a translation provider first".to_string());
+    }
+    state.settings.update(|settings| {
+        settings.auto_translate.retain(|c| *c != conversation_id);
+        if enabled {
+            settings.auto_translate.push(conversation_id);
+        }
+    })
+}
+
+/// Translate one message into `target_language`, or the configured language
+#[tauri::command]
+pub async fn translate_message(
+    state: State<'_, TranslationState>,
+    text: String,
+    target_language: Option<String>,
+) -> Result<Translation, String> {
+    state.translate(&text, target_language.as_deref()).await
+}
+
+#[tauri::command]
+pub async fn clear_translation_cache(state: State<'_, TranslationState>) -> Result<(), String> {
+    state.cache.set(Vec::new())
+}
@@ -0,0 +1,424 @@
+// System Tray
+// Menu construction, the periodic status line, the connect toggle and localized labels.
+// The tooltip follows the status poll and the bandwidth sampler: network, virtual
+// addresses, peers online and transfer speed. The same details can be shown as
+// text beside the icon (the menu bar on macOS, supporting panels on Linux).
+// "Recent Chats" lists the conversations kept by `recent_chats`.
+
+use crate::addressing;
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::ConnectionState;
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::recent_chats::{self, RecentChat};
+use crate::report::format_bytes;
+use crate::store::JsonStore;
+use crate::updates::{self, UpdateState};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager, State, Wry};
+use ts_rs::TS;
+
+pub const TRAY_ID: &str = "tray";
+
+const SETTINGS_FILE: &str = "tray.json";
+
+const STATUS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// What the status line currently shows, kept so it can be re-rendered on a locale switch
+#[derive(Debug, Clone)]
+pub enum TrayStatus {
+    Checking,
+    Connected(String),
+    /// Several networks at once
+    ConnectedMany(usize),
+    Disconnected,
+    State(ConnectionState),
+}
+
+impl TrayStatus {
+    fn connected(&self) -> bool {
+        matches!(self, Self::Connected(_) | Self::ConnectedMany(_))
+    }
+
+    fn text(&self) -> String {
+        match self {
+            Self::Checking => i18n::text(Text::TrayChecking).to_string(),
+            Self::Connected(network) => i18n::text(Text::TrayConnected).replace("{network}", network),
+            Self::ConnectedMany(count) => i18n::text(Text::TrayConnectedMany).replace("{count}", &count.to_string()),
+            Self::Disconnected => i18n::text(Text::TrayDisconnected).to_string(),
+            Self::State(state) => i18n::text(Text::TrayState).replace("{state}", i18n::text(Text::State(*state))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct TraySettings {
+    /// Show the network or transfer speed as text beside the icon; not supported on Windows
+    pub menu_bar_title: bool,
+    /// Offer a quick-reply window for each recent chat
+    pub quick_reply: bool,
+}
+
+/// Everything the tooltip shows besides the app name
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TooltipInfo {
+    /// Active network and its health score
+    health: Option<(String, u8)>,
+    /// The connected network, when there is exactly one
+    network: Option<String>,
+    /// This machine's virtual addresses
+    address: Option<String>,
+    peers: Option<u32>,
+    /// Upload and download bytes per second while transfers run
+    speed: Option<(u64, u64)>,
+}
+
+/// Managed handles to the tray menu items
+pub struct TrayMenu {
+    status: MenuItem<Wry>,
+    /// "Connect" or "Disconnect", following the status line
+    toggle: MenuItem<Wry>,
+    recent: Submenu<Wry>,
+    check_update: MenuItem<Wry>,
+    troubleshooting: Submenu<Wry>,
+    rollback: MenuItem<Wry>,
+    show: MenuItem<Wry>,
+    quit: MenuItem<Wry>,
+    last_status: Mutex<TrayStatus>,
+    tooltip: Mutex<TooltipInfo>,
+    settings: JsonStore<TraySettings>,
+}
+
+pub fn build(app: &AppHandle) -> tauri::Result<()> {
+    let status_i = MenuItem::with_id(app, "status", TrayStatus::Checking.text(), false, None::<&str>)?;
+    let toggle_i = MenuItem::with_id(app, "toggle_connection", i18n::text(Text::TrayConnect), false, None::<&str>)?;
+    // Filled in by `set_recent_chats`
+    let recent_i = Submenu::with_items(app, i18n::text(Text::TrayRecentChats), true, &[])?;
+    // Portable runs never update themselves
+    let updatable = !crate::portable::enabled();
+    let check_update_i =
+        MenuItem::with_id(app, "check_update", i18n::text(Text::TrayCheckUpdate), updatable, None::<&str>)?;
+    let quit_i = MenuItem::with_id(app, "quit", i18n::text(Text::TrayQuit), true, None::<&str>)?;
+    let show_i = MenuItem::with_id(app, "show", i18n::text(Text::TrayShow), true, None::<&str>)?;
+    let rollback_version = app.state::<UpdateState>().rollback_version(app);
+    let rollback_i = MenuItem::with_id(
+        app,
+        "rollback",
+        rollback_text(rollback_version.as_ref()),
+        updatable && rollback_version.is_some(),
+        None::<&str>,
+    )?;
+    let troubleshooting_i =
+        Submenu::with_items(app, i18n::text(Text::TrayTroubleshooting), true, &[&rollback_i])?;
+    let sep1 = PredefinedMenuItem::separator(app)?;
+    let sep2 = PredefinedMenuItem::separator(app)?;
+
+    let menu = Menu::with_items(app, &[
+        &status_i,
+        &toggle_i,
+        &recent_i,
+        &sep1,
+        &check_update_i,
+        &troubleshooting_i,
+        &sep2,
+        &show_i,
+        &quit_i
+    ])?;
+
+    TrayIconBuilder::with_id(TRAY_ID)
+        .icon(app.default_window_icon().unwrap().clone())
+        .tooltip(i18n::text(Text::TrayTooltip))
+        .menu(&menu)
+        .show_menu_on_left_click(true)
+        .on_menu_event(on_menu_event)
+        .build(app)?;
+
+    app.manage(TrayMenu {
+        status: status_i,
+        toggle: toggle_i,
+        recent: recent_i,
+        check_update: check_update_i,
+        troubleshooting: troubleshooting_i,
+        rollback: rollback_i,
+        show: show_i,
+        quit: quit_i,
+        last_status: Mutex::new(TrayStatus::Checking),
+        tooltip: Mutex::new(TooltipInfo::default()),
+        settings: JsonStore::open(app, SETTINGS_FILE),
+    });
+    Ok(())
+}
+
+fn on_menu_event(app: &AppHandle, event: MenuEvent) {
+    match event.id.as_ref() {
+        "quit" => {
+            app.exit(0);
+        }
+        "show" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }
+        "toggle_connection" => {
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                toggle_connection(&handle).await;
+            });
+        }
+        "check_update" => {
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let _ = updates::check_now(&handle, true).await;
+            });
+        }
+        "rollback" => {
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                // Only returns when the rollback could not be installed
+                if let Err(e) = updates::rollback(&handle).await {
+                    let body = i18n::text(Text::RollbackFailed).replace("{error}", &e);
+                    notifications::notify(&handle, NotificationKind::System, i18n::text(Text::RollbackTitle), &body);
+                }
+            });
+        }
+        id => {
+            if let Some(network_id) = id.strip_prefix("chat:") {
+                recent_chats::open_chat(app, network_id);
+            } else if let Some(network_id) = id.strip_prefix("reply:") {
+                if let Err(e) = recent_chats::open_quick_reply(app, network_id) {
+                    log::warn!("{}", e);
+                }
+            }
+        }
+    }
+}
+
+fn rollback_text(version: Option<&semver::Version>) -> String {
+    match version {
+        Some(version) => i18n::text(Text::TrayRollbackTo).replace("{version}", &version.to_string()),
+        None => i18n::text(Text::TrayRollback).to_string(),
+    }
+}
+
+/// Disconnect when connected, otherwise connect to the most recent network
+async fn toggle_connection(app: &AppHandle) {
+    let Some(menu) = app.try_state::<TrayMenu>() else {
+        return;
+    };
+    let connected = menu.last_status.lock().unwrap().connected();
+    let state = app.state::<DaemonState>();
+    let result = if connected {
+        daemon_call!(state, "disconnect_network", |client| client.disconnect_network())
+    } else {
+        daemon_call!(state, "connect_network", |client| client.connect_network())
+    };
+    if let Err(e) = result {
+        notifications::notify(app, NotificationKind::System, i18n::text(Text::TrayTooltip), &e);
+    }
+    refresh_status(app).await;
+}
+
+/// Query the daemon and update the status line, toggle and tooltip
+pub(crate) async fn refresh_status(app: &AppHandle) {
+    let state = app.state::<DaemonState>();
+    let status = match state.probe_status().await {
+        Ok(status) => {
+            let address = status
+                .connected
+                .then(|| addressing::display(status.virtual_ipv4.as_deref(), status.virtual_ipv6.as_deref()))
+                .flatten();
+            let peers = status.connected.then_some(status.active_peers);
+            update_tooltip(app, |info| {
+                info.address = address;
+                info.peers = peers;
+            });
+            // Older daemons without per-network state fall back to the single status
+            let networks = state.probe_networks().await.unwrap_or_default();
+            let connected: Vec<_> = networks.into_iter().filter(|n| n.connected).collect();
+            match connected.as_slice() {
+                [_, _, ..] => TrayStatus::ConnectedMany(connected.len()),
+                [only] => TrayStatus::Connected(only.name.clone()),
+                [] if status.connected => TrayStatus::Connected(status.network_name),
+                [] => TrayStatus::Disconnected,
+            }
+        }
+        Err(_) => {
+            update_tooltip(app, |info| {
+                info.address = None;
+                info.peers = None;
+            });
+            TrayStatus::State(state.connection.state())
+        }
+    };
+    let network = match &status {
+        TrayStatus::Connected(network) => Some(network.clone()),
+        _ => None,
+    };
+    update_tooltip(app, |info| info.network = network);
+    set_status(app, status);
+}
+
+/// Refresh the status line from the daemon every few seconds
+pub fn spawn_status_updates(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            refresh_status(&app).await;
+            tokio::time::sleep(STATUS_INTERVAL).await;
+        }
+    });
+}
+
+fn toggle_text(status: &TrayStatus) -> &'static str {
+    i18n::text(if status.connected() { Text::TrayDisconnect } else { Text::TrayConnect })
+}
+
+pub fn set_status(app: &AppHandle, status: TrayStatus) {
+    if let Some(menu) = app.try_state::<TrayMenu>() {
+        let _ = menu.status.set_text(status.text());
+        let _ = menu.toggle.set_text(toggle_text(&status));
+        // Without a daemon there is nothing to connect
+        let _ = menu.toggle.set_enabled(!matches!(status, TrayStatus::Checking | TrayStatus::State(_)));
+        *menu.last_status.lock().unwrap() = status;
+    }
+}
+
+fn speed_text((up, down): (u64, u64)) -> String {
+    format!("↑ {}/s  ↓ {}/s", format_bytes(up), format_bytes(down))
+}
+
+fn tooltip_text(info: &TooltipInfo) -> String {
+    let title = match (&info.health, &info.network) {
+        (Some((network, score)), _) => i18n::text(Text::TrayHealth)
+            .replace("{network}", network)
+            .replace("{score}", &score.to_string()),
+        (None, Some(network)) => i18n::text(Text::TrayNetwork).replace("{network}", network),
+        (None, None) => i18n::text(Text::TrayTooltip).to_string(),
+    };
+    let peers = info.peers.map(|count| i18n::text(Text::TrayPeers).replace("{count}", &count.to_string()));
+    let lines = [Some(title), info.address.clone(), peers, info.speed.map(speed_text)];
+    lines.into_iter().flatten().collect::<Vec<_>>().join("\n")
+}
+
+/// Short text beside the icon: the speed while transferring, otherwise the network
+fn title_text(info: &TooltipInfo) -> Option<String> {
+    info.speed.map(speed_text).or_else(|| info.network.clone())
+}
+
+fn render_tooltip(app: &AppHandle, menu: &TrayMenu) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    let info = menu.tooltip.lock().unwrap().clone();
+    let _ = tray.set_tooltip(Some(tooltip_text(&info)));
+    let title = if menu.settings.get().menu_bar_title { title_text(&info) } else { None };
+    let _ = tray.set_title(title);
+}
+
+/// Change the tooltip details, re-rendering only when something differs
+fn update_tooltip(app: &AppHandle, change: impl FnOnce(&mut TooltipInfo)) {
+    let Some(menu) = app.try_state::<TrayMenu>() else {
+        return;
+    };
+    let changed = {
+        let mut info = menu.tooltip.lock().unwrap();
+        let before = info.clone();
+        change(&mut info);
+        *info != before
+    };
+    if changed {
+        render_tooltip(app, &menu);
+    }
+}
+
+/// Show the active network's health score in the tooltip, or clear it
+pub fn set_health(app: &AppHandle, health: Option<(String, u8)>) {
+    update_tooltip(app, |info| info.health = health);
+}
+
+/// Show the current transfer speed in the tooltip, or clear it once transfers stop
+pub(crate) fn set_speed(app: &AppHandle, speed: Option<(u64, u64)>) {
+    update_tooltip(app, |info| info.speed = speed);
+}
+
+/// Rebuild the "Recent Chats" submenu, newest first
+pub(crate) fn set_recent_chats(app: &AppHandle, chats: &[RecentChat]) {
+    let Some(menu) = app.try_state::<TrayMenu>() else {
+        return;
+    };
+    if let Err(e) = fill_recent(app, &menu, chats) {
+        log::warn!("Failed to update recent chats in the tray: {}", e);
+    }
+}
+
+fn fill_recent(app: &AppHandle, menu: &TrayMenu, chats: &[RecentChat]) -> tauri::Result<()> {
+    for item in menu.recent.items()? {
+        menu.recent.remove(&item)?;
+    }
+    if chats.is_empty() {
+        let empty = MenuItem::new(app, i18n::text(Text::TrayNoRecentChats), false, None::<&str>)?;
+        return menu.recent.append(&empty);
+    }
+    for chat in chats {
+        let label = match chat.unread {
+            0 => chat.network_name.clone(),
+            unread => format!("{} ({})", chat.network_name, unread),
+        };
+        let item = MenuItem::with_id(app, format!("chat:{}", chat.network_id), label, true, None::<&str>)?;
+        menu.recent.append(&item)?;
+    }
+    if menu.settings.get().quick_reply {
+        let replies = Submenu::new(app, i18n::text(Text::TrayQuickReply), true)?;
+        for chat in chats {
+            let id = format!("reply:{}", chat.network_id);
+            replies.append(&MenuItem::with_id(app, id, &chat.network_name, true, None::<&str>)?)?;
+        }
+        menu.recent.append(&PredefinedMenuItem::separator(app)?)?;
+        menu.recent.append(&replies)?;
+    }
+    Ok(())
+}
+
+/// Re-render every label in the current locale
+pub fn relabel(app: &AppHandle) {
+    let Some(menu) = app.try_state::<TrayMenu>() else {
+        return;
+    };
+    let status = menu.last_status.lock().unwrap().clone();
+    let _ = menu.status.set_text(status.text());
+    let _ = menu.toggle.set_text(toggle_text(&status));
+    let _ = menu.recent.set_text(i18n::text(Text::TrayRecentChats));
+    recent_chats::show_in_tray(app);
+    let _ = menu.check_update.set_text(i18n::text(Text::TrayCheckUpdate));
+    let _ = menu.troubleshooting.set_text(i18n::text(Text::TrayTroubleshooting));
+    let _ = menu.rollback.set_text(rollback_text(app.state::<UpdateState>().rollback_version(app).as_ref()));
+    let _ = menu.show.set_text(i18n::text(Text::TrayShow));
+    let _ = menu.quit.set_text(i18n::text(Text::TrayQuit));
+    render_tooltip(app, &menu);
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_tray_settings(menu: State<'_, TrayMenu>) -> Result<TraySettings, String> {
+    Ok(menu.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_tray_settings(
+    app: AppHandle,
+    menu: State<'_, TrayMenu>,
+    settings: TraySettings,
+) -> Result<TraySettings, String> {
+    menu.settings.set(settings.clone())?;
+    render_tooltip(&app, &menu);
+    recent_chats::show_in_tray(&app);
+    Ok(settings)
+}
@@ -0,0 +1,96 @@
+// Tunnel Listeners
+// Several features serve other peers directly: the probe responder, delta
+// sync, shared printers, game advertisements, mDNS relaying and the health
+// echo. Each listens only on this device's virtual IP, never on LAN
+// interfaces, and only while connected and the feature is enabled. The
+// listener is rebound when the tunnel address changes or the feature is
+// switched, not on every status update the daemon sends.
+
+use crate::commands::DaemonState;
+use std::future::{pending, Future};
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+
+/// Whether the feature currently wants a listener
+pub(crate) type EnabledFn = fn(&AppHandle) -> bool;
+/// Notified by the feature when its enabled state may have changed
+pub(crate) type WakeFn = fn(&AppHandle) -> &Notify;
+
+/// Run `run` on the virtual IP while connected and `enabled`, restarting it when
+/// that address changes. `run` ends only when it gives up (e.g. the bind
+/// failed); it is not retried until the address or the feature changes.
+pub(crate) fn follow<R, Fut>(app: AppHandle, enabled: EnabledFn, wake: Option<WakeFn>, run: R)
+where
+    R: Fn(AppHandle, IpAddr) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    tauri::async_runtime::spawn(async move {
+        let mut status_rx = app.state::<DaemonState>().subscribe_status();
+        let mut bound: Option<IpAddr> = None;
+        let mut server: Pin<Box<dyn Future<Output = ()> + Send>> = Box::pin(pending());
+        loop {
+            let ip = status_rx
+                .borrow_and_update()
+                .as_ref()
+                .filter(|s| s.connected)
+                .and_then(|s| s.virtual_ip.parse::<IpAddr>().ok())
+                .filter(|_| enabled(&app));
+            if ip != bound {
+                bound = ip;
+                // Dropping the old future closes its listener before the new bind
+                server = Box::pin(pending());
+                if let Some(ip) = ip {
+                    server = Box::pin(run(app.clone(), ip));
+                }
+            }
+
+            let woken = async {
+                match wake {
+                    Some(wake) => wake(&app).notified().await,
+                    None => pending().await,
+                }
+            };
+            tokio::select! {
+                _ = &mut server => server = Box::pin(pending()),
+                changed = status_rx.changed() => if changed.is_err() { break },
+                _ = woken => {}
+            }
+        }
+    });
+}
+
+/// Accept TCP connections on `port` of the virtual IP, handing each to `handler`
+pub(crate) fn serve<H, Fut>(
+    app: AppHandle,
+    name: &'static str,
+    port: u16,
+    enabled: EnabledFn,
+    wake: Option<WakeFn>,
+    handler: H,
+) where
+    H: Fn(AppHandle, TcpStream, SocketAddr) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let handler = Arc::new(handler);
+    follow(app, enabled, wake, move |app, ip| {
+        let handler = handler.clone();
+        async move {
+            let listener = match TcpListener::bind(SocketAddr::new(ip, port)).await {
+                Ok(listener) => listener,
+                Err(e) => {
+                    log::warn!("{} could not bind {}: {}", name, ip, e);
+                    return;
+                }
+            };
+            loop {
+                if let Ok((stream, from)) = listener.accept().await {
+                    tauri::async_runtime::spawn(handler(app.clone(), stream, from));
+                }
+            }
+        }
+    });
+}
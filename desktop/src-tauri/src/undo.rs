@@ -0,0 +1,169 @@
+// Undo
+// Destructive local actions keep what they removed on an undo stack: clearing
+// the notification history, removing a host alias, cancelling a scheduled
+// transfer and muting notifications. `undo_last_action` puts the newest one
+// back while it is younger than the undo window. The stack lives in memory
+// only; after a restart there is nothing to undo.
+
+use crate::connection::unix_now;
+use crate::hosts::{self, HostOverride};
+use crate::notifications::{NotificationCenter, NotificationRecord};
+use crate::optimistic::{self, SettingToggle};
+use crate::scheduler::{ScheduledTransfer, SchedulerState};
+use crate::store::JsonStore;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Tauri event emitted with an `UndoEntry` whenever an action can be undone
+pub const AVAILABLE_EVENT: &str = "undo://available";
+
+const SETTINGS_FILE: &str = "undo.json";
+
+/// Older entries are dropped beyond this many
+const MAX_ENTRIES: usize = 20;
+
+const MAX_WINDOW_SECS: u32 = 3600;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct UndoSettings {
+    /// How long an action stays undoable
+    pub window_secs: u32,
+}
+
+impl Default for UndoSettings {
+    fn default() -> Self {
+        Self { window_secs: 30 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum UndoKind {
+    ClearNotifications,
+    RemoveHostAlias,
+    CancelSchedule,
+    MuteNotifications,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct UndoEntry {
+    pub id: String,
+    pub kind: UndoKind,
+    /// What the toast says, e.g. "Removed alias nas.home"
+    pub label: String,
+    #[ts(type = "number")]
+    pub created_at: u64,
+    #[ts(type = "number")]
+    pub expires_at: u64,
+}
+
+/// What it takes to put an action back
+pub(crate) enum Reversal {
+    Notifications(Vec<NotificationRecord>),
+    HostAlias(HostOverride),
+    Schedule(ScheduledTransfer),
+    Setting { setting: SettingToggle, enabled: bool },
+}
+
+impl Reversal {
+    fn kind(&self) -> UndoKind {
+        match self {
+            Self::Notifications(_) => UndoKind::ClearNotifications,
+            Self::HostAlias(_) => UndoKind::RemoveHostAlias,
+            Self::Schedule(_) => UndoKind::CancelSchedule,
+            Self::Setting { .. } => UndoKind::MuteNotifications,
+        }
+    }
+
+    async fn apply(self, app: &AppHandle) -> Result<(), String> {
+        match self {
+            Self::Notifications(items) => app.state::<NotificationCenter>().restore(items),
+            Self::HostAlias(entry) => hosts::restore_override(app, entry).await,
+            Self::Schedule(entry) => app.state::<SchedulerState>().restore(entry),
+            Self::Setting { setting, enabled } => {
+                optimistic::toggle_setting(app.clone(), app.state(), app.state(), setting, enabled).await.map(|_| ())
+            }
+        }
+    }
+}
+
+/// Managed state holding the undo stack
+pub struct UndoState {
+    settings: JsonStore<UndoSettings>,
+    /// Oldest first
+    stack: Mutex<Vec<(UndoEntry, Reversal)>>,
+}
+
+impl UndoState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self { settings: JsonStore::open(app, SETTINGS_FILE), stack: Mutex::new(Vec::new()) }
+    }
+
+    fn prune(stack: &mut Vec<(UndoEntry, Reversal)>, now: u64) {
+        stack.retain(|(entry, _)| entry.expires_at > now);
+        let excess = stack.len().saturating_sub(MAX_ENTRIES);
+        stack.drain(..excess);
+    }
+}
+
+/// Make a just-completed action undoable
+pub(crate) fn record(app: &AppHandle, label: String, reversal: Reversal) {
+    let state = app.state::<UndoState>();
+    let now = unix_now();
+    let entry = UndoEntry {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        kind: reversal.kind(),
+        label,
+        created_at: now,
+        expires_at: now + u64::from(state.settings.get().window_secs),
+    };
+    let mut stack = state.stack.lock().unwrap();
+    stack.push((entry.clone(), reversal));
+    UndoState::prune(&mut stack, now);
+    drop(stack);
+    let _ = app.emit(AVAILABLE_EVENT, entry);
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Reverse the newest action still inside the undo window; returns what was undone
+#[tauri::command]
+pub async fn undo_last_action(app: AppHandle, state: State<'_, UndoState>) -> Result<UndoEntry, String> {
+    let (entry, reversal) = {
+        let mut stack = state.stack.lock().unwrap();
+        UndoState::prune(&mut stack, unix_now());
+        stack.pop().ok_or("nothing to undo")?
+    };
+    reversal.apply(&app).await.map_err(|e| format!("could not undo: {}", e))?;
+    log::info!("Undid: {}", entry.label);
+    Ok(entry)
+}
+
+/// Undoable actions, newest first
+#[tauri::command]
+pub async fn list_undo_actions(state: State<'_, UndoState>) -> Result<Vec<UndoEntry>, String> {
+    let mut stack = state.stack.lock().unwrap();
+    UndoState::prune(&mut stack, unix_now());
+    Ok(stack.iter().rev().map(|(entry, _)| entry.clone()).collect())
+}
+
+#[tauri::command]
+pub async fn get_undo_settings(state: State<'_, UndoState>) -> Result<UndoSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_undo_settings(state: State<'_, UndoState>, settings: UndoSettings) -> Result<UndoSettings, String> {
+    if !(1..=MAX_WINDOW_SECS).contains(&settings.window_secs) {
+        return Err(format!("the undo window must be between 1 and {} seconds", MAX_WINDOW_SECS));
+    }
+    state.settings.set(settings.clone())?;
+    Ok(settings)
+}
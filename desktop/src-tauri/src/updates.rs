@@ -0,0 +1,418 @@
+// App Updates
+// The background update manager and rollback. Updates follow the user's policy:
+// only on request, notify, download in the background, or download and install
+// when the app quits. Downloads are signature-checked by the updater, hashed
+// (SHA-256, compared with the manifest when it publishes one) and staged on disk;
+// the hash is verified again before installing.
+//
+// The versions that have run on this machine are remembered so a bad release can
+// be rolled back: the newest recorded version older than the running one is
+// reinstalled from its own GitHub release manifest.
+
+use crate::connection::unix_now;
+use crate::i18n::{self, Text};
+use crate::notifications::{self, NotificationKind};
+use crate::store::{data_dir, JsonStore};
+use semver::Version;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_updater::{Update, UpdaterExt};
+use ts_rs::TS;
+
+const HISTORY_FILE: &str = "update_history.json";
+const SETTINGS_FILE: &str = "update_settings.json";
+const STAGED_FILE: &str = "update_staged.json";
+const STAGING_DIR: &str = "updates";
+
+/// Versions remembered for rollback
+const MAX_HISTORY: usize = 10;
+
+/// Per-release updater manifest; `{version}` has no leading "v"
+const RELEASE_MANIFEST: &str = "https://github.com/orhaniscoding/goconnect/releases/download/v{version}/latest.json";
+
+/// Let startup settle before the first background check
+const FIRST_CHECK_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum UpdatePolicy {
+    /// Only when the user checks
+    Manual,
+    Notify,
+    /// Download in the background and notify; the user installs
+    AutoDownload,
+    /// Download in the background and install when the app quits
+    AutoInstallOnQuit,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct UpdateSettings {
+    pub policy: UpdatePolicy,
+    pub check_interval_hours: u32,
+}
+
+impl Default for UpdateSettings {
+    fn default() -> Self {
+        Self { policy: UpdatePolicy::Notify, check_interval_hours: 6 }
+    }
+}
+
+/// A downloaded update waiting to be installed
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct StagedUpdate {
+    pub version: String,
+    pub path: String,
+    /// Hex SHA-256 of the package, checked again before installing
+    pub sha256: String,
+    #[ts(type = "number")]
+    pub size: u64,
+    #[ts(type = "number")]
+    pub downloaded_at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct UpdateCheck {
+    pub current: String,
+    /// Newer version offered, if any
+    pub available: Option<String>,
+    pub staged: Option<StagedUpdate>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct InstalledVersion {
+    pub version: String,
+    /// First start of this version
+    #[ts(type = "number")]
+    pub first_run_at: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct UpdateHistory {
+    pub current: String,
+    /// What `update_rollback` would install
+    pub rollback_version: Option<String>,
+    /// Oldest first
+    pub installed: Vec<InstalledVersion>,
+}
+
+/// Managed state holding the update policy, the staged download and the
+/// versions seen on this machine
+pub struct UpdateState {
+    settings: JsonStore<UpdateSettings>,
+    staged: JsonStore<Option<StagedUpdate>>,
+    history: JsonStore<Vec<InstalledVersion>>,
+    /// The updater's handle for the staged version; gone after a restart until
+    /// the next check finds the same version again
+    pending: Mutex<Option<Update>>,
+    changed: tokio::sync::Notify,
+}
+
+impl UpdateState {
+    /// Load the state and record the running version
+    pub fn load(app: &AppHandle) -> Self {
+        let state = Self {
+            settings: JsonStore::open(app, SETTINGS_FILE),
+            staged: JsonStore::open(app, STAGED_FILE),
+            history: JsonStore::open(app, HISTORY_FILE),
+            pending: Mutex::new(None),
+            changed: tokio::sync::Notify::new(),
+        };
+        let current = app.package_info().version.to_string();
+        let result = state.history.update(|history| {
+            if !history.iter().any(|v| v.version == current) {
+                history.push(InstalledVersion { version: current, first_run_at: unix_now() });
+                let excess = history.len().saturating_sub(MAX_HISTORY);
+                history.drain(..excess);
+            }
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record installed version: {}", e);
+        }
+        // A staged package for the version now running (or older) is spent
+        if let Some(staged) = state.staged.get() {
+            let spent = Version::parse(&staged.version).is_ok_and(|v| v <= app.package_info().version);
+            if spent {
+                let _ = std::fs::remove_file(&staged.path);
+                let _ = state.staged.set(None);
+            }
+        }
+        state
+    }
+
+    /// Newest recorded version older than the running one. Going by version
+    /// rather than install order means a rollback never offers the bad release back.
+    pub fn rollback_version(&self, app: &AppHandle) -> Option<Version> {
+        let current = &app.package_info().version;
+        self.history
+            .get()
+            .iter()
+            .filter_map(|v| Version::parse(&v.version).ok())
+            .filter(|v| v < current)
+            .max()
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// SHA-256 the manifest publishes for this platform, if any
+fn manifest_sha256(update: &Update) -> Option<String> {
+    let json = &update.raw_json;
+    json.pointer(&format!("/platforms/{}/sha256", update.target))
+        .or_else(|| json.get("sha256"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_ascii_lowercase())
+}
+
+/// Staged package bytes, if they still match their recorded hash
+fn read_staged(staged: &StagedUpdate) -> Result<Vec<u8>, String> {
+    let bytes = std::fs::read(&staged.path).map_err(|e| format!("staged update is unreadable: {}", e))?;
+    if sha256_hex(&bytes) != staged.sha256 {
+        return Err("staged update failed hash verification".to_string());
+    }
+    Ok(bytes)
+}
+
+/// Download `update` unless the same version is already staged intact
+async fn stage(app: &AppHandle, update: &Update) -> Result<StagedUpdate, String> {
+    let state = app.state::<UpdateState>();
+    if let Some(staged) = state.staged.get().filter(|s| s.version == update.version) {
+        if read_staged(&staged).is_ok() {
+            *state.pending.lock().unwrap() = Some(update.clone());
+            return Ok(staged);
+        }
+    }
+
+    // The updater rejects packages whose signature does not verify
+    let bytes = update.download(|_, _| {}, || {}).await.map_err(|e| format!("download failed: {}", e))?;
+    let sha256 = sha256_hex(&bytes);
+    if let Some(expected) = manifest_sha256(update) {
+        if expected != sha256 {
+            return Err(format!("downloaded v{} does not match the published hash", update.version));
+        }
+    }
+
+    let dir = data_dir(app).join(STAGING_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path: PathBuf = dir.join(format!("goconnect-{}-{}.pkg", update.version, update.target));
+    std::fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+
+    let staged = StagedUpdate {
+        version: update.version.clone(),
+        path: path.to_string_lossy().into_owned(),
+        sha256,
+        size: bytes.len() as u64,
+        downloaded_at: unix_now(),
+    };
+    // Replace any older staged package
+    if let Some(previous) = state.staged.get().filter(|s| s.path != staged.path) {
+        let _ = std::fs::remove_file(previous.path);
+    }
+    state.staged.set(Some(staged.clone()))?;
+    *state.pending.lock().unwrap() = Some(update.clone());
+    Ok(staged)
+}
+
+/// Check for an update and act on it according to the policy. `interactive`
+/// checks (the tray item) always report their outcome.
+pub async fn check_now(app: &AppHandle, interactive: bool) -> Result<UpdateCheck, String> {
+    if crate::portable::enabled() {
+        return Err("updates are disabled in portable mode".to_string());
+    }
+    let state = app.state::<UpdateState>();
+    let policy = state.settings.get().policy;
+    let notify = |body: String| notifications::notify(app, NotificationKind::System, i18n::text(Text::UpdateTitle), &body);
+
+    let result = match app.updater() {
+        Ok(updater) => updater.check().await,
+        Err(e) => Err(e),
+    };
+    let update = match result {
+        Ok(update) => update,
+        Err(e) => {
+            if interactive {
+                notify(i18n::text(Text::UpdateFailed).replace("{error}", &e.to_string()));
+            }
+            return Err(e.to_string());
+        }
+    };
+
+    let mut check = UpdateCheck {
+        current: app.package_info().version.to_string(),
+        available: update.as_ref().map(|u| u.version.clone()),
+        staged: None,
+    };
+    let Some(update) = update else {
+        if interactive {
+            notify(i18n::text(Text::UpdateLatest).to_string());
+        }
+        return Ok(check);
+    };
+
+    match policy {
+        UpdatePolicy::AutoDownload | UpdatePolicy::AutoInstallOnQuit => match stage(app, &update).await {
+            Ok(staged) => {
+                let text = if policy == UpdatePolicy::AutoInstallOnQuit { Text::UpdateReadyOnQuit } else { Text::UpdateReady };
+                notify(i18n::text(text).replace("{version}", &staged.version));
+                check.staged = Some(staged);
+            }
+            Err(e) => {
+                log::warn!("Staging update v{} failed: {}", update.version, e);
+                notify(i18n::text(Text::UpdateAvailable).replace("{version}", &update.version));
+            }
+        },
+        UpdatePolicy::Manual | UpdatePolicy::Notify => {
+            notify(i18n::text(Text::UpdateAvailable).replace("{version}", &update.version));
+        }
+    }
+    Ok(check)
+}
+
+/// Verify and install the staged package. Does not restart.
+fn install_staged(app: &AppHandle) -> Result<(), String> {
+    let state = app.state::<UpdateState>();
+    let staged = state.staged.get().ok_or("no update has been downloaded")?;
+    let update = state
+        .pending
+        .lock()
+        .unwrap()
+        .clone()
+        .filter(|u| u.version == staged.version)
+        .ok_or("check for updates again before installing")?;
+    let bytes = read_staged(&staged)?;
+    log::info!("Installing staged update v{}", staged.version);
+    update.install(bytes).map_err(|e| format!("install failed: {}", e))?;
+    let _ = std::fs::remove_file(&staged.path);
+    state.staged.set(None)
+}
+
+/// Install a staged update as the app exits, under the install-on-quit policy
+pub fn on_exit(app: &AppHandle) {
+    let Some(state) = app.try_state::<UpdateState>() else { return };
+    if state.settings.get().policy != UpdatePolicy::AutoInstallOnQuit || state.staged.get().is_none() {
+        return;
+    }
+    if let Err(e) = install_staged(app) {
+        log::warn!("Could not install the staged update on exit: {}", e);
+    }
+}
+
+/// Run background checks on the configured interval
+pub fn spawn(app: AppHandle) {
+    if crate::portable::enabled() {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<UpdateState>();
+        tokio::time::sleep(FIRST_CHECK_DELAY).await;
+        loop {
+            let settings = state.settings.get();
+            if settings.policy != UpdatePolicy::Manual {
+                if let Err(e) = check_now(&app, false).await {
+                    log::debug!("Background update check failed: {}", e);
+                }
+            }
+            let interval = Duration::from_secs(u64::from(settings.check_interval_hours.max(1)) * 3600);
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = state.changed.notified() => {}
+            }
+        }
+    });
+}
+
+/// Download and install the rollback version, then restart into it
+pub async fn rollback(app: &AppHandle) -> Result<(), String> {
+    if crate::portable::enabled() {
+        return Err("updates are disabled in portable mode".to_string());
+    }
+    let target = app
+        .state::<UpdateState>()
+        .rollback_version(app)
+        .ok_or("no earlier version has been installed on this machine")?;
+    let manifest = RELEASE_MANIFEST.replace("{version}", &target.to_string());
+    let url = tauri::Url::parse(&manifest).map_err(|e| e.to_string())?;
+
+    let wanted = target.clone();
+    let updater = app
+        .updater_builder()
+        .endpoints(vec![url])
+        .map_err(|e| e.to_string())?
+        // Accept the older release instead of only newer ones
+        .version_comparator(move |_, release| release.version == wanted)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let update = updater
+        .check()
+        .await
+        .map_err(|e| format!("could not fetch v{}: {}", target, e))?
+        .ok_or_else(|| format!("v{} has no package for this platform", target))?;
+
+    log::info!("Rolling back to v{}", target);
+    update
+        .download_and_install(|_, _| {}, || {})
+        .await
+        .map_err(|e| format!("rollback to v{} failed: {}", target, e))?;
+    app.restart()
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn get_update_settings(state: State<'_, UpdateState>) -> Result<UpdateSettings, String> {
+    Ok(state.settings.get())
+}
+
+#[tauri::command]
+pub async fn set_update_settings(
+    state: State<'_, UpdateState>,
+    settings: UpdateSettings,
+) -> Result<UpdateSettings, String> {
+    if settings.check_interval_hours == 0 {
+        return Err("check interval must be at least one hour".to_string());
+    }
+    state.settings.set(settings.clone())?;
+    state.changed.notify_one();
+    Ok(settings)
+}
+
+/// Check now, applying the update policy (downloading when it says so)
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<UpdateCheck, String> {
+    check_now(&app, false).await
+}
+
+/// Install the staged update and restart; only returns on failure
+#[tauri::command]
+pub async fn install_staged_update(app: AppHandle) -> Result<(), String> {
+    install_staged(&app)?;
+    app.restart()
+}
+
+#[tauri::command]
+pub async fn get_update_history(app: AppHandle, state: State<'_, UpdateState>) -> Result<UpdateHistory, String> {
+    Ok(UpdateHistory {
+        current: app.package_info().version.to_string(),
+        rollback_version: state.rollback_version(&app).map(|v| v.to_string()),
+        installed: state.history.get(),
+    })
+}
+
+/// Reinstall the previous version and restart; only returns on failure
+#[tauri::command]
+pub async fn update_rollback(app: AppHandle) -> Result<(), String> {
+    rollback(&app).await
+}
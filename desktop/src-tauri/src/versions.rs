@@ -0,0 +1,271 @@
+// File Versions
+// Copies of files taken just before sync or an accepted transfer overwrites them,
+// so an unwanted overwrite can be undone. Versions live in the app data dir; the
+// newest few are kept per file and the oldest go first once the store is full.
+
+use crate::connection::unix_now;
+use crate::store::{self, JsonStore};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, State};
+use ts_rs::TS;
+
+const VERSIONS_FILE: &str = "versions.json";
+const VERSIONS_DIR: &str = "versions";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct VersionSettings {
+    pub enabled: bool,
+    /// Versions kept per file
+    pub keep_versions: u32,
+    /// Total size of the versions store
+    #[ts(type = "number")]
+    pub max_storage_mb: u64,
+}
+
+impl Default for VersionSettings {
+    fn default() -> Self {
+        Self { enabled: true, keep_versions: 5, max_storage_mb: 1024 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum VersionSource {
+    Sync,
+    Transfer,
+    /// Taken when restoring an older version over the file
+    Restore,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct FileVersion {
+    pub id: String,
+    pub path: String,
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Modification time of the file when the copy was taken
+    #[ts(type = "number")]
+    pub modified: u64,
+    #[ts(type = "number")]
+    pub created_at: u64,
+    pub source: VersionSource,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+struct VersionsData {
+    settings: VersionSettings,
+    versions: Vec<FileVersion>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct VersionStoreUsage {
+    pub settings: VersionSettings,
+    pub versions: u32,
+    #[ts(type = "number")]
+    pub used_bytes: u64,
+}
+
+/// Managed state holding the versions index
+pub struct VersionsState {
+    data: JsonStore<VersionsData>,
+    dir: PathBuf,
+}
+
+impl VersionsState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            data: JsonStore::open(app, VERSIONS_FILE),
+            dir: store::data_dir(app).join(VERSIONS_DIR),
+        }
+    }
+
+    fn stored_path(&self, id: &str) -> PathBuf {
+        self.dir.join(id)
+    }
+
+    /// Drop versions beyond the per-file count, then the oldest until under the cap
+    fn prune(&self) -> Result<(), String> {
+        let removed = self.data.update(|data| {
+            let keep = data.settings.keep_versions.max(1) as usize;
+            let cap = data.settings.max_storage_mb.saturating_mul(1024 * 1024);
+
+            // Newest first, so per-file counting keeps the latest copies
+            data.versions.sort_by_key(|v| std::cmp::Reverse(v.created_at));
+            let mut seen: HashMap<String, usize> = HashMap::new();
+            let mut total = 0u64;
+            let mut removed = Vec::new();
+            data.versions.retain(|v| {
+                let count = seen.entry(v.path.clone()).or_default();
+                *count += 1;
+                if *count > keep || total + v.size > cap {
+                    removed.push(v.id.clone());
+                    return false;
+                }
+                total += v.size;
+                true
+            });
+            removed
+        })?;
+        for id in removed {
+            let _ = std::fs::remove_file(self.stored_path(&id));
+        }
+        Ok(())
+    }
+}
+
+fn modified_secs(meta: &std::fs::Metadata) -> u64 {
+    meta.modified()
+        .ok()
+        .and_then(|m| m.duration_since(std::time::UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Keep a copy of `path` if it exists and is about to be overwritten.
+/// Returns `None` when versioning is off or there is nothing to keep.
+pub async fn snapshot_before_overwrite(
+    app: &AppHandle,
+    path: &Path,
+    source: VersionSource,
+) -> Result<Option<FileVersion>, String> {
+    let state = app.state::<VersionsState>();
+    let settings = state.data.get().settings;
+    let Some(meta) = std::fs::metadata(path).ok().filter(|m| m.is_file()) else {
+        return Ok(None);
+    };
+    if !settings.enabled {
+        return Ok(None);
+    }
+    if meta.len() > settings.max_storage_mb.saturating_mul(1024 * 1024) {
+        log::info!("Not versioning {:?}: larger than the versions store", path);
+        return Ok(None);
+    }
+
+    let version = FileVersion {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        path: path.to_string_lossy().into_owned(),
+        size: meta.len(),
+        modified: modified_secs(&meta),
+        created_at: unix_now(),
+        source,
+    };
+    let (from, to) = (path.to_path_buf(), state.stored_path(&version.id));
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Some(parent) = to.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&from, &to)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("failed to copy {:?} to the versions store: {}", path, e))?;
+
+    state.data.update(|data| data.versions.push(version.clone()))?;
+    state.prune()?;
+    Ok(Some(version))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Versions of `path` (or of every file when omitted), newest first
+#[tauri::command]
+pub async fn list_file_versions(
+    state: State<'_, VersionsState>,
+    path: Option<String>,
+) -> Result<Vec<FileVersion>, String> {
+    let mut versions: Vec<FileVersion> = state
+        .data
+        .get()
+        .versions
+        .into_iter()
+        .filter(|v| path.as_ref().is_none_or(|p| &v.path == p))
+        .collect();
+    versions.sort_by_key(|v| std::cmp::Reverse(v.created_at));
+    Ok(versions)
+}
+
+/// Put a version back in place of the current file. The current file is kept
+/// as a version first, so a restore can itself be undone.
+#[tauri::command]
+pub async fn restore_file_version(
+    app: AppHandle,
+    state: State<'_, VersionsState>,
+    version_id: String,
+) -> Result<FileVersion, String> {
+    let version = state
+        .data
+        .get()
+        .versions
+        .into_iter()
+        .find(|v| v.id == version_id)
+        .ok_or_else(|| format!("version '{}' not found", version_id))?;
+    let stored = state.stored_path(&version.id);
+    if !stored.is_file() {
+        let _ = state.data.update(|data| data.versions.retain(|v| v.id != version.id));
+        return Err("the stored copy of this version is missing".to_string());
+    }
+
+    // Stage beside the target first: keeping the current file may prune this version
+    let target = PathBuf::from(&version.path);
+    let mut staging = target.clone().into_os_string();
+    staging.push(".restore");
+    let staging = PathBuf::from(staging);
+    let (from, to) = (stored, staging.clone());
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Some(parent) = to.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&from, &to)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("failed to restore {:?}: {}", target, e))?;
+
+    if let Err(e) = snapshot_before_overwrite(&app, &target, VersionSource::Restore).await {
+        log::warn!("Failed to keep current {:?} before restore: {}", target, e);
+    }
+    std::fs::rename(&staging, &target).map_err(|e| {
+        let _ = std::fs::remove_file(&staging);
+        format!("failed to restore {:?}: {}", target, e)
+    })?;
+    Ok(version)
+}
+
+#[tauri::command]
+pub async fn delete_file_version(state: State<'_, VersionsState>, version_id: String) -> Result<(), String> {
+    state.data.update(|data| data.versions.retain(|v| v.id != version_id))?;
+    let _ = std::fs::remove_file(state.stored_path(&version_id));
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_version_settings(state: State<'_, VersionsState>) -> Result<VersionStoreUsage, String> {
+    let data = state.data.get();
+    Ok(VersionStoreUsage {
+        versions: data.versions.len() as u32,
+        used_bytes: data.versions.iter().map(|v| v.size).sum(),
+        settings: data.settings,
+    })
+}
+
+/// Save settings; lowering the limits prunes existing versions right away
+#[tauri::command]
+pub async fn set_version_settings(
+    state: State<'_, VersionsState>,
+    settings: VersionSettings,
+) -> Result<VersionStoreUsage, String> {
+    if settings.keep_versions == 0 {
+        return Err("keep at least one version, or turn versioning off".to_string());
+    }
+    state.data.update(|data| data.settings = settings)?;
+    state.prune()?;
+    get_version_settings(state).await
+}
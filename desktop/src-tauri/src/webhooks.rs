@@ -0,0 +1,500 @@
+// Webhooks
+// User-configured HTTP endpoints called when selected network events happen.
+// Payloads are rendered from a JSON template, signed with an optional secret
+// (HMAC-SHA256 of the body) and retried with exponential backoff.
+
+use crate::commands::{daemon_call, DaemonState};
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use ts_rs::TS;
+
+/// Tauri event emitted with each finished `WebhookDelivery`
+pub const DELIVERY_EVENT: &str = "webhooks://delivery";
+
+const HOOKS_FILE: &str = "webhooks.json";
+const DELIVERIES_FILE: &str = "webhook_deliveries.json";
+
+/// Only the newest deliveries are kept in the log
+const MAX_DELIVERIES: usize = 200;
+
+const MAX_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often peers and transfers are compared for changes
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+const SIGNATURE_HEADER: &str = "X-GoConnect-Signature";
+const EVENT_HEADER: &str = "X-GoConnect-Event";
+const DELIVERY_HEADER: &str = "X-GoConnect-Delivery";
+
+/// Used when a webhook has no template of its own
+pub const DEFAULT_TEMPLATE: &str = r#"{"event":"{{event}}","timestamp":{{timestamp}},"network":"{{network}}","peer_id":"{{peer_id}}","peer":"{{peer_name}}","file":"{{file_name}}","message":"{{message}}"}"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum WebhookEvent {
+    PeerOnline,
+    PeerOffline,
+    TransferCompleted,
+    TransferFailed,
+    NetworkConnected,
+    NetworkDisconnected,
+    /// A critical peer broke an SLA rule
+    SlaBreach,
+    SlaResolved,
+    /// Only sent by `test_webhook`
+    Test,
+}
+
+impl WebhookEvent {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::PeerOnline => "peer_online",
+            Self::PeerOffline => "peer_offline",
+            Self::TransferCompleted => "transfer_completed",
+            Self::TransferFailed => "transfer_failed",
+            Self::NetworkConnected => "network_connected",
+            Self::NetworkDisconnected => "network_disconnected",
+            Self::SlaBreach => "sla_breach",
+            Self::SlaResolved => "sla_resolved",
+            Self::Test => "test",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct Webhook {
+    /// Empty when creating; assigned by `save_webhook`
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    /// JSON body with `{{placeholder}}`s; `DEFAULT_TEMPLATE` when empty
+    #[serde(default)]
+    pub template: String,
+    /// Signs each body as `X-GoConnect-Signature: sha256=<hex>` when set
+    #[serde(default)]
+    pub secret: String,
+    pub enabled: bool,
+}
+
+impl Webhook {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("webhook name cannot be empty".to_string());
+        }
+        let url = reqwest::Url::parse(&self.url).map_err(|e| format!("invalid webhook URL: {}", e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("webhook URL must use http or https".to_string());
+        }
+        if self.events.is_empty() {
+            return Err("select at least one event".to_string());
+        }
+        let sample = EventData { network: "Home".to_string(), peer_name: "laptop \"a\"".to_string(), ..Default::default() };
+        serde_json::from_str::<serde_json::Value>(&self.render(WebhookEvent::Test, &sample))
+            .map_err(|e| format!("template does not produce valid JSON: {}", e))?;
+        Ok(())
+    }
+
+    fn template(&self) -> &str {
+        if self.template.trim().is_empty() {
+            DEFAULT_TEMPLATE
+        } else {
+            &self.template
+        }
+    }
+
+    /// Fill the template. String values are JSON-escaped, so placeholders belong inside quotes.
+    fn render(&self, event: WebhookEvent, data: &EventData) -> String {
+        let escape = |s: &str| {
+            let quoted = serde_json::to_string(s).unwrap_or_default();
+            quoted[1..quoted.len() - 1].to_string()
+        };
+        // One pass over the template, so a value containing a placeholder is never expanded
+        let mut out = String::with_capacity(self.template().len());
+        let mut rest = self.template();
+        while let Some(start) = rest.find("{{") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let end = after.find("}}");
+            let value = end.and_then(|end| match &after[..end] {
+                "event" => Some(event.as_str().to_string()),
+                "timestamp" => Some(unix_now().to_string()),
+                "network" => Some(escape(&data.network)),
+                "peer_id" => Some(escape(&data.peer_id)),
+                "peer_name" => Some(escape(&data.peer_name)),
+                "file_name" => Some(escape(&data.file_name)),
+                "message" => Some(escape(&data.message)),
+                _ => None,
+            });
+            match (value, end) {
+                (Some(value), Some(end)) => {
+                    out.push_str(&value);
+                    rest = &after[end + 2..];
+                }
+                // Not a placeholder; keep the brace and look again from the next character
+                _ => {
+                    out.push('{');
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Values available to templates
+#[derive(Debug, Clone, Default)]
+pub struct EventData {
+    pub network: String,
+    pub peer_id: String,
+    pub peer_name: String,
+    pub file_name: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub webhook_id: String,
+    pub event: WebhookEvent,
+    /// Unix timestamp (seconds) of the first attempt
+    #[ts(type = "number")]
+    pub timestamp: u64,
+    pub attempts: u32,
+    /// HTTP status of the last attempt, if a response arrived
+    pub status_code: Option<u16>,
+    pub success: bool,
+    pub error: Option<String>,
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+    pub payload: String,
+}
+
+/// Managed state holding webhook definitions and the delivery log
+pub struct WebhookState {
+    hooks: JsonStore<Vec<Webhook>>,
+    deliveries: JsonStore<Vec<WebhookDelivery>>,
+    http: reqwest::Client,
+}
+
+impl WebhookState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            hooks: JsonStore::open(app, HOOKS_FILE),
+            deliveries: JsonStore::open(app, DELIVERIES_FILE),
+            http: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .user_agent(concat!("GoConnect-Desktop/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn subscribers(&self, event: WebhookEvent) -> Vec<Webhook> {
+        self.hooks
+            .get()
+            .into_iter()
+            .filter(|h| h.enabled && h.events.contains(&event))
+            .collect()
+    }
+
+    /// Whether anyone listens for events that need peer or transfer polling
+    fn wants_polling(&self) -> bool {
+        self.hooks.get().iter().filter(|h| h.enabled).any(|h| {
+            h.events.iter().any(|e| {
+                matches!(
+                    e,
+                    WebhookEvent::PeerOnline
+                        | WebhookEvent::PeerOffline
+                        | WebhookEvent::TransferCompleted
+                        | WebhookEvent::TransferFailed
+                )
+            })
+        })
+    }
+
+    fn log(&self, delivery: &WebhookDelivery) {
+        let result = self.deliveries.update(|log| {
+            log.push(delivery.clone());
+            let excess = log.len().saturating_sub(MAX_DELIVERIES);
+            log.drain(..excess);
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to record webhook delivery: {}", e);
+        }
+    }
+}
+
+fn sign(secret: &str, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Send one event to one webhook, retrying transient failures, and log the outcome
+async fn deliver(app: &AppHandle, hook: &Webhook, event: WebhookEvent, data: &EventData) -> WebhookDelivery {
+    let state = app.state::<WebhookState>();
+    let payload = hook.render(event, data);
+    let mut delivery = WebhookDelivery {
+        id: hex::encode(rand::random::<[u8; 8]>()),
+        webhook_id: hook.id.clone(),
+        event,
+        timestamp: unix_now(),
+        attempts: 0,
+        status_code: None,
+        success: false,
+        error: None,
+        duration_ms: 0,
+        payload,
+    };
+    let started = Instant::now();
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        delivery.attempts += 1;
+        let mut request = state
+            .http
+            .post(&hook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, event.as_str())
+            .header(DELIVERY_HEADER, &delivery.id)
+            .body(delivery.payload.clone());
+        if !hook.secret.is_empty() {
+            request = request.header(SIGNATURE_HEADER, sign(&hook.secret, &delivery.payload));
+        }
+
+        let retry = match request.send().await {
+            Ok(response) => {
+                let status = response.status();
+                delivery.status_code = Some(status.as_u16());
+                delivery.success = status.is_success();
+                delivery.error = (!delivery.success).then(|| format!("HTTP {}", status));
+                status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            Err(e) => {
+                delivery.status_code = None;
+                delivery.error = Some(e.to_string());
+                true
+            }
+        };
+        if delivery.success || !retry || delivery.attempts >= MAX_ATTEMPTS {
+            break;
+        }
+        tokio::time::sleep(backoff).await;
+        backoff *= 2;
+    }
+
+    delivery.duration_ms = started.elapsed().as_millis() as u64;
+    state.log(&delivery);
+    let _ = app.emit(DELIVERY_EVENT, &delivery);
+    delivery
+}
+
+/// Fan an event out to every enabled webhook subscribed to it
+pub fn dispatch(app: &AppHandle, event: WebhookEvent, data: EventData) {
+    for hook in app.state::<WebhookState>().subscribers(event) {
+        let app = app.clone();
+        let data = data.clone();
+        tauri::async_runtime::spawn(async move {
+            let delivery = deliver(&app, &hook, event, &data).await;
+            if !delivery.success {
+                log::warn!("Webhook '{}' failed after {} attempts: {:?}", hook.name, delivery.attempts, delivery.error);
+            }
+        });
+    }
+}
+
+/// Turn status changes and polled peer/transfer changes into webhook events
+pub fn spawn(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<WebhookState>();
+        let daemon = app.state::<DaemonState>();
+        let mut status_rx = daemon.subscribe_status();
+        let mut ticker = tokio::time::interval(POLL_INTERVAL);
+
+        // `None` until the first observation, so startup does not fire events
+        let mut network: Option<Option<String>> = None;
+        let mut peers: Option<HashMap<String, (String, bool)>> = None;
+        let mut transfers: Option<HashMap<String, String>> = None;
+
+        loop {
+            tokio::select! {
+                changed = status_rx.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    let status = status_rx.borrow_and_update().clone();
+                    // An unreachable daemon is not a disconnect
+                    let Some(status) = status else { continue };
+                    let now = status.connected.then_some(status.network_name);
+                    let Some(previous) = network.replace(now.clone()) else { continue };
+                    if previous == now {
+                        continue;
+                    }
+                    if let Some(previous) = previous {
+                        dispatch(&app, WebhookEvent::NetworkDisconnected, EventData { network: previous, ..Default::default() });
+                    }
+                    if let Some(now) = now {
+                        dispatch(&app, WebhookEvent::NetworkConnected, EventData { network: now, ..Default::default() });
+                    }
+                }
+                _ = ticker.tick() => {
+                    if !state.wants_polling() || !daemon.connection.state().is_usable() {
+                        // Start from a fresh baseline rather than replaying what was missed
+                        peers = None;
+                        transfers = None;
+                        continue;
+                    }
+                    let current_network = network.clone().flatten().unwrap_or_default();
+
+                    if let Ok(list) = daemon_call!(daemon, "get_peers", |client| client.get_peers()) {
+                        let next: HashMap<String, (String, bool)> = list
+                            .into_iter()
+                            .filter(|p| !p.is_self)
+                            .map(|p| {
+                                let name = if p.display_name.is_empty() { p.name } else { p.display_name };
+                                (p.id, (name, p.connected))
+                            })
+                            .collect();
+                        if let Some(previous) = &peers {
+                            for (id, (name, was_online)) in previous {
+                                let is_online = next.get(id).is_some_and(|(_, c)| *c);
+                                if *was_online != is_online {
+                                    let event = if is_online { WebhookEvent::PeerOnline } else { WebhookEvent::PeerOffline };
+                                    dispatch(&app, event, EventData {
+                                        network: current_network.clone(),
+                                        peer_id: id.clone(),
+                                        peer_name: name.clone(),
+                                        ..Default::default()
+                                    });
+                                }
+                            }
+                            for (id, (name, _)) in next.iter().filter(|(id, (_, c))| *c && !previous.contains_key(*id)) {
+                                dispatch(&app, WebhookEvent::PeerOnline, EventData {
+                                    network: current_network.clone(),
+                                    peer_id: id.clone(),
+                                    peer_name: name.clone(),
+                                    ..Default::default()
+                                });
+                            }
+                        }
+                        peers = Some(next);
+                    }
+
+                    if let Ok(list) = daemon_call!(daemon, "list_transfers", |client| client.list_transfers(None, None)) {
+                        if let Some(previous) = &transfers {
+                            for t in list.iter().filter(|t| previous.get(&t.id) != Some(&t.status)) {
+                                let event = match t.status.as_str() {
+                                    "completed" => WebhookEvent::TransferCompleted,
+                                    "failed" => WebhookEvent::TransferFailed,
+                                    _ => continue,
+                                };
+                                let peer_name = peers
+                                    .as_ref()
+                                    .and_then(|p| p.get(&t.peer_id))
+                                    .map(|(name, _)| name.clone())
+                                    .unwrap_or_else(|| t.peer_id.clone());
+                                dispatch(&app, event, EventData {
+                                    network: current_network.clone(),
+                                    peer_id: t.peer_id.clone(),
+                                    peer_name,
+                                    file_name: t.file_name.clone(),
+                                    message: t.error.clone().unwrap_or_default(),
+                                });
+                            }
+                        }
+                        transfers = Some(list.into_iter().map(|t| (t.id, t.status)).collect());
+                    }
+                }
+            }
+        }
+    });
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+#[tauri::command]
+pub async fn list_webhooks(state: State<'_, WebhookState>) -> Result<Vec<Webhook>, String> {
+    Ok(state.hooks.get())
+}
+
+/// Create (empty id) or replace a webhook
+#[tauri::command]
+pub async fn save_webhook(state: State<'_, WebhookState>, mut webhook: Webhook) -> Result<Webhook, String> {
+    webhook.validate()?;
+    let is_new = webhook.id.is_empty();
+    if is_new {
+        webhook.id = hex::encode(rand::random::<[u8; 8]>());
+    }
+    let saved = webhook.clone();
+    state.hooks.update(|hooks| -> Result<(), String> {
+        if is_new {
+            hooks.push(webhook);
+            return Ok(());
+        }
+        let existing = hooks
+            .iter_mut()
+            .find(|h| h.id == webhook.id)
+            .ok_or_else(|| format!("webhook '{}' not found", webhook.id))?;
+        *existing = webhook;
+        Ok(())
+    })??;
+    Ok(saved)
+}
+
+#[tauri::command]
+pub async fn delete_webhook(state: State<'_, WebhookState>, id: String) -> Result<(), String> {
+    state.hooks.update(|hooks| hooks.retain(|h| h.id != id))?;
+    state.deliveries.update(|log| log.retain(|d| d.webhook_id != id))
+}
+
+/// Send a test event now, waiting for the outcome (including retries)
+#[tauri::command]
+pub async fn test_webhook(app: AppHandle, state: State<'_, WebhookState>, id: String) -> Result<WebhookDelivery, String> {
+    let hook = state
+        .hooks
+        .get()
+        .into_iter()
+        .find(|h| h.id == id)
+        .ok_or_else(|| format!("webhook '{}' not found", id))?;
+    let data = EventData { message: "Test delivery from GoConnect".to_string(), ..Default::default() };
+    Ok(deliver(&app, &hook, WebhookEvent::Test, &data).await)
+}
+
+/// Newest first, optionally for a single webhook
+#[tauri::command]
+pub async fn list_webhook_deliveries(
+    state: State<'_, WebhookState>,
+    webhook_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<WebhookDelivery>, String> {
+    Ok(state
+        .deliveries
+        .get()
+        .into_iter()
+        .rev()
+        .filter(|d| webhook_id.as_ref().is_none_or(|id| &d.webhook_id == id))
+        .take(limit.unwrap_or(MAX_DELIVERIES))
+        .collect())
+}
+
+#[tauri::command]
+pub async fn clear_webhook_deliveries(state: State<'_, WebhookState>, webhook_id: Option<String>) -> Result<(), String> {
+    state
+        .deliveries
+        .update(|log| log.retain(|d| webhook_id.as_ref().is_some_and(|id| &d.webhook_id != id)))
+}
@@ -0,0 +1,245 @@
+// What's New
+// Release notes for the installed version and for an available update, parsed
+// from their Markdown into sections the UI can render. Notes come from the
+// updater manifest when an update is offered and from GitHub releases for the
+// installed version; both are cached so the dialog also works offline.
+// After an upgrade the installed version's notes are reported once as "what's new".
+
+use crate::connection::unix_now;
+use crate::store::JsonStore;
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{AppHandle, State};
+use tauri_plugin_updater::UpdaterExt;
+use ts_rs::TS;
+
+const CACHE_FILE: &str = "whats_new.json";
+
+const RELEASES_API: &str = "https://api.github.com/repos/orhaniscoding/goconnect/releases/tags";
+const FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ReleaseSection {
+    /// Heading text; empty for notes before the first heading
+    pub title: String,
+    /// Bullet points
+    pub items: Vec<String>,
+    /// Paragraph text outside bullet lists
+    pub text: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS)]
+#[ts(export)]
+pub struct ReleaseNotes {
+    pub version: String,
+    pub sections: Vec<ReleaseSection>,
+    /// The unparsed Markdown
+    pub raw: String,
+    pub url: Option<String>,
+    #[ts(type = "number")]
+    pub fetched_at: u64,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+struct WhatsNewCache {
+    notes: HashMap<String, ReleaseNotes>,
+    /// Installed version the user last saw notes for (or first ran)
+    last_seen_version: Option<String>,
+}
+
+/// Managed state holding the cached notes
+pub struct WhatsNewState {
+    cache: JsonStore<WhatsNewCache>,
+    http: reqwest::Client,
+}
+
+impl WhatsNewState {
+    pub fn load(app: &AppHandle) -> Self {
+        Self {
+            cache: JsonStore::open(app, CACHE_FILE),
+            http: reqwest::Client::builder()
+                .timeout(FETCH_TIMEOUT)
+                .user_agent(concat!("GoConnect-Desktop/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn remember(&self, notes: &ReleaseNotes) {
+        let result = self.cache.update(|cache| {
+            cache.notes.insert(notes.version.clone(), notes.clone());
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to cache release notes: {}", e);
+        }
+    }
+}
+
+/// Strip Markdown emphasis and links down to their text
+fn plain(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' | '_' | '`' => {}
+            // [text](url) keeps the text
+            ']' if chars.peek() == Some(&'(') => {
+                for c in chars.by_ref() {
+                    if c == ')' {
+                        break;
+                    }
+                }
+            }
+            '[' => {}
+            _ => out.push(c),
+        }
+    }
+    out.trim().to_string()
+}
+
+/// Split release Markdown into heading sections of bullets and paragraphs
+fn parse(markdown: &str) -> Vec<ReleaseSection> {
+    let mut sections = vec![ReleaseSection::default()];
+    let mut paragraph = String::new();
+    let flush = |paragraph: &mut String, section: &mut ReleaseSection| {
+        if !paragraph.is_empty() {
+            section.text.push(std::mem::take(paragraph));
+        }
+    };
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        let section = sections.last_mut().expect("at least one section");
+        if let Some(heading) = trimmed.strip_prefix('#') {
+            flush(&mut paragraph, section);
+            sections.push(ReleaseSection { title: plain(heading.trim_start_matches('#')), ..Default::default() });
+        } else if let Some(item) = ["- ", "* ", "+ "].iter().find_map(|b| trimmed.strip_prefix(b)) {
+            flush(&mut paragraph, section);
+            section.items.push(plain(item));
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, section);
+        } else if line.starts_with("  ") && paragraph.is_empty() && !section.items.is_empty() {
+            // Wrapped continuation of the previous bullet
+            let last = section.items.last_mut().expect("checked above");
+            last.push(' ');
+            last.push_str(&plain(trimmed));
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(&plain(trimmed));
+        }
+    }
+    let section = sections.last_mut().expect("at least one section");
+    flush(&mut paragraph, section);
+
+    sections.retain(|s| !s.title.is_empty() || !s.items.is_empty() || !s.text.is_empty());
+    sections
+}
+
+fn notes(version: &str, markdown: String, url: Option<String>) -> ReleaseNotes {
+    ReleaseNotes {
+        version: version.to_string(),
+        sections: parse(&markdown),
+        raw: markdown,
+        url,
+        fetched_at: unix_now(),
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct GithubRelease {
+    #[serde(default)]
+    body: Option<String>,
+    html_url: String,
+}
+
+/// Notes for `version` from GitHub, falling back to the cache when offline
+async fn release_notes(state: &WhatsNewState, version: &str) -> Result<ReleaseNotes, String> {
+    let url = format!("{}/v{}", RELEASES_API, version.trim_start_matches('v'));
+    let fetched = async {
+        let release: GithubRelease = state
+            .http
+            .get(&url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok::<_, String>(notes(version, release.body.unwrap_or_default(), Some(release.html_url)))
+    };
+    match fetched.await {
+        Ok(notes) => {
+            state.remember(&notes);
+            Ok(notes)
+        }
+        Err(e) => state
+            .cache
+            .get()
+            .notes
+            .remove(version)
+            .ok_or_else(|| format!("release notes for {} are unavailable: {}", version, e)),
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Notes for `version`, or for the installed version when omitted
+#[tauri::command]
+pub async fn get_release_notes(
+    app: AppHandle,
+    state: State<'_, WhatsNewState>,
+    version: Option<String>,
+) -> Result<ReleaseNotes, String> {
+    let version = version.unwrap_or_else(|| app.package_info().version.to_string());
+    release_notes(&state, &version).await
+}
+
+/// Notes for the update the updater offers, if any
+#[tauri::command]
+pub async fn get_update_release_notes(
+    app: AppHandle,
+    state: State<'_, WhatsNewState>,
+) -> Result<Option<ReleaseNotes>, String> {
+    let update = app.updater().map_err(|e| e.to_string())?.check().await.map_err(|e| e.to_string())?;
+    let Some(update) = update else {
+        return Ok(None);
+    };
+    let notes = match update.body.filter(|b| !b.trim().is_empty()) {
+        Some(body) => {
+            let notes = notes(&update.version, body, None);
+            state.remember(&notes);
+            notes
+        }
+        // Manifests without notes: the release page has them
+        None => release_notes(&state, &update.version).await?,
+    };
+    Ok(Some(notes))
+}
+
+/// The installed version's notes when it was upgraded to since the user last
+/// looked; `None` on a first install or once seen
+#[tauri::command]
+pub async fn get_whats_new(app: AppHandle, state: State<'_, WhatsNewState>) -> Result<Option<ReleaseNotes>, String> {
+    let current = app.package_info().version.to_string();
+    match state.cache.get().last_seen_version {
+        Some(seen) if seen != current => release_notes(&state, &current).await.map(Some),
+        Some(_) => Ok(None),
+        None => {
+            state.cache.update(|cache| cache.last_seen_version = Some(current))?;
+            Ok(None)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn mark_whats_new_seen(app: AppHandle, state: State<'_, WhatsNewState>) -> Result<(), String> {
+    let current = app.package_info().version.to_string();
+    state.cache.update(|cache| cache.last_seen_version = Some(current))
+}
@@ -18,6 +18,7 @@ import { CreateNetworkModal, JoinNetworkModal, RenameNetworkModal, DeleteNetwork
 
 export default function App() {
   const [isDaemonRunning, setIsDaemonRunning] = useState(false);
+  const [isStartingDaemon, setIsStartingDaemon] = useState(false);
   const [isRegistered, setIsRegistered] = useState(false);
   const [networks, setNetworks] = useState<NetworkInfo[]>([]);
   const [selectedNetworkId, setSelectedNetworkId] = useState<string | null>(null);
@@ -118,11 +119,28 @@ export default function App() {
   useEffect(() => {
     if (selectedNetworkId && isDaemonRunning) {
       refreshPeers();
-      const interval = setInterval(refreshPeers, 3000);
-      return () => clearInterval(interval);
+      // Peers are refreshed when the daemon reports a change, not on a timer
+      const unlisten = [tauriApi.onPeerJoined(refreshPeers), tauriApi.onPeerLeft(refreshPeers)];
+      return () => unlisten.forEach(u => u.then(f => f()));
     }
   }, [selectedNetworkId, isDaemonRunning]);
 
+  // The tray's "Recent Chats" menu opens a conversation here
+  useEffect(() => {
+    const unlisten = tauriApi.onOpenChat((networkId) => {
+      setSelectedNetworkId(networkId);
+      setPrivateChatRecipient(null);
+      setActiveTab("chat");
+    });
+    return () => { unlisten.then(f => f()); };
+  }, []);
+
+  useEffect(() => {
+    if (activeTab === "chat" && selectedNetworkId) {
+      tauriApi.markChatRead(selectedNetworkId).catch(() => {});
+    }
+  }, [activeTab, selectedNetworkId]);
+
   const refreshPeers = async () => {
     try {
       const p = await tauriApi.getPeers();
@@ -159,7 +177,16 @@ export default function App() {
     if (!selectedNetworkId) return;
     if (!confirm("Are you sure you want to leave this network? You will need an invite code to rejoin.")) return;
     try {
-      await tauriApi.leaveNetwork(selectedNetworkId);
+      const outcome = await tauriApi.leaveNetwork(selectedNetworkId);
+      if (outcome.outcome === "needs_confirmation") {
+        const reasons = outcome.check.warnings.map((w) =>
+          w.kind === "sole_owner"
+            ? `You are the only owner; ${w.other_members} member(s) will be left without one.`
+            : `${w.transfers.length} transfer(s) with members are still running and will be cancelled.`
+        );
+        if (!confirm(`${reasons.join("\n")}\n\nLeave anyway?`)) return;
+        await tauriApi.leaveNetwork(selectedNetworkId, { confirmed: true, cancel_transfers: true });
+      }
       toast.success("Left network");
       setSelectedNetworkId(null);
       refreshNetworks();
@@ -202,18 +229,43 @@ export default function App() {
     }
   };
 
+  const handleStartDaemon = async () => {
+    setIsStartingDaemon(true);
+    try {
+      const outcome = await tauriApi.startDaemon();
+      if (!outcome.running) {
+        toast.error("The daemon was started but is not answering yet");
+      }
+      await checkDaemon();
+    } catch (e) {
+      handleError(e, "Failed to start the daemon");
+    } finally {
+      setIsStartingDaemon(false);
+    }
+  };
+
   if (!isDaemonRunning) {
     return (
       <div className="h-screen w-screen bg-gc-dark-900 flex flex-col items-center justify-center text-white">
+        <Toaster />
         <div className="text-6xl mb-4 animate-bounce">🦖</div>
         <h1 className="text-2xl font-bold mb-2">Daemon Not Running</h1>
-        <p className="text-gray-400 mb-6">Please start the GoConnect service via terminal.</p>
-        <button
-          onClick={checkDaemon}
-          className="px-6 py-2 bg-gc-primary rounded hover:bg-opacity-80 transition"
-        >
-          Retry Connection
-        </button>
+        <p className="text-gray-400 mb-6">The GoConnect service is stopped or cannot be reached.</p>
+        <div className="flex gap-3">
+          <button
+            onClick={handleStartDaemon}
+            disabled={isStartingDaemon}
+            className="px-6 py-2 bg-gc-primary rounded hover:bg-opacity-80 transition disabled:opacity-50"
+          >
+            {isStartingDaemon ? "Starting..." : "Start Daemon"}
+          </button>
+          <button
+            onClick={checkDaemon}
+            className="px-6 py-2 bg-gc-dark-700 rounded hover:bg-opacity-80 transition"
+          >
+            Retry Connection
+          </button>
+        </div>
       </div>
     );
   }
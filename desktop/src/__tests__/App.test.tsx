@@ -15,6 +15,10 @@ vi.mock('../lib/tauri-api', () => ({
         joinNetwork: vi.fn(),
         leaveNetwork: vi.fn(),
         generateInvite: vi.fn(),
+        onPeerJoined: vi.fn().mockResolvedValue(() => {}),
+        onPeerLeft: vi.fn().mockResolvedValue(() => {}),
+        onOpenChat: vi.fn().mockResolvedValue(() => {}),
+        markChatRead: vi.fn().mockResolvedValue(undefined),
     }
 }));
 
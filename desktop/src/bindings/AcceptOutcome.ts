@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AcceptOutcome = { "outcome": "accepted", path: string, replaced: boolean, } | { "outcome": "skipped", existing: string, } | { "outcome": "needs_decision", existing: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AccessibilitySettings = { announcements_enabled: boolean, 
+/**
+ * Speak through the OS screen reader while the window is hidden
+ */
+native_fallback: boolean, };
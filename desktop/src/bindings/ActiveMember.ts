@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ActiveMember = { peer_id: string, name: string, role: string, online: boolean, message_count: number, 
+/**
+ * Unix timestamp (seconds) of their latest message in the sample
+ */
+last_message_at: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How `format_address` writes an address
+ */
+export type AddressStyle = "plain" | "host_port" | "url" | "unc";
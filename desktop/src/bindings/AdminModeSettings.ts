@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminModeSettings = { 
+/**
+ * Off leaves moderation commands unlocked at all times
+ */
+required: boolean, 
+/**
+ * How long admin mode stays on
+ */
+duration_mins: number, };
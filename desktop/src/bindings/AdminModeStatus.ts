@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminModeStatus = { 
+/**
+ * Moderation commands are allowed right now
+ */
+active: boolean, required: boolean, pin_set: boolean, 
+/**
+ * Unix timestamp (seconds) admin mode turns off at
+ */
+expires_at: number | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GameProtocol } from "./GameProtocol";
+
+export type AdvertisedServer = { protocol: GameProtocol, port: number, 
+/**
+ * Shown when the server does not answer the status query
+ */
+label: string, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AlertKind = "daemon_down" | "tunnel_down" | "auth_failed" | "test";
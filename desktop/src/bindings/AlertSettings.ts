@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AlertKind } from "./AlertKind";
+import type { EmailTarget } from "./EmailTarget";
+import type { PushTarget } from "./PushTarget";
+
+export type AlertSettings = { enabled: boolean, kinds: Array<AlertKind>, daemon_down_minutes: number, tunnel_down_minutes: number, 
+/**
+ * Minimum gap between two alerts of the same kind
+ */
+cooldown_minutes: number, 
+/**
+ * Cap across all kinds
+ */
+max_per_hour: number, email: EmailTarget | null, push: PushTarget | null, };
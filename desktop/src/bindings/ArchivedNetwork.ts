@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ArchivedNetwork = { network_id: string, name: string, 
+/**
+ * Last invite code seen for the network; `None` when we never had one
+ */
+invite_code: string | null, notes: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+left_at: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AttentionLevel } from "./AttentionLevel";
+
+export type AttentionSettings = { mention: AttentionLevel, chat_message: AttentionLevel, incoming_transfer: AttentionLevel, 
+/**
+ * Count missed events on the dock or launcher icon
+ */
+badge: boolean, };
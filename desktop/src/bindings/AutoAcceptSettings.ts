@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TrustedPeer } from "./TrustedPeer";
+
+export type AutoAcceptSettings = { enabled: boolean, trusted_peers: Array<TrustedPeer>, 
+/**
+ * Larger files are still prompted for; 0 for no limit
+ */
+max_size_bytes: number, 
+/**
+ * Where accepted files are saved; empty for the system's Downloads folder
+ */
+download_dir: string, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PeerPresence } from "./PeerPresence";
+
+export type AvailabilitySpan = { state: PeerPresence, start: number, end: number, };
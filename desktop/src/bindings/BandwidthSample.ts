@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BandwidthSample = { at_ms: number, up_bps: number, down_bps: number, active_transfers: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BulkInviteOptions = { 
+/**
+ * Joins allowed per code; 0 = unlimited
+ */
+max_uses: number, 
+/**
+ * 0 = never expires
+ */
+expires_hours: number, 
+/**
+ * Given to the codes in order, e.g. the names of the people they are for
+ */
+labels: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LabelledInvite } from "./LabelledInvite";
+
+export type BulkInvites = { network_id: string, invites: Array<LabelledInvite>, 
+/**
+ * Codes the daemon refused to generate, with the first error
+ */
+failed: number, error: string | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CacheKind } from "./CacheKind";
+
+export type CacheUsage = { kind: CacheKind, entries: number, bytes: number, limit_bytes: number, 
+/**
+ * Entries evicted since startup
+ */
+evicted: number, };
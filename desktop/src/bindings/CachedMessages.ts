@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
+
+export type CachedMessages = { network_id: string, messages: Array<ChatMessage>, age_ms: number, };
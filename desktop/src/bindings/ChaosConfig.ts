@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Fault injection knobs. All probabilities are in the range 0.0..=1.0.
+ */
+export type ChaosConfig = { enabled: boolean, 
+/**
+ * Upper bound of the random delay added before each call
+ */
+max_delay_ms: number, 
+/**
+ * Probability that a completed call's response is discarded
+ */
+drop_rate: number, 
+/**
+ * Probability that a call starts an UNAVAILABLE burst
+ */
+unavailable_rate: number, 
+/**
+ * Number of consecutive calls that fail once a burst starts
+ */
+burst_length: number, };
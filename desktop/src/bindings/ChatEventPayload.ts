@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
+
+export type ChatEventPayload = { network_id: string, message: ChatMessage, 
+/**
+ * The sender is muted in this conversation; show it without notifying
+ */
+muted: boolean, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ChatMute = { network_id: string, peer_id: string, peer_name: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+until: number, 
+/**
+ * Set by flood detection rather than by the user
+ */
+automatic: boolean, 
+/**
+ * Ids of the messages that caused the mute and arrived during it
+ */
+evidence: Array<string>, reported: boolean, };
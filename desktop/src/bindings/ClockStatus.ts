@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ClockReference } from "./ClockReference";
+
+export type ClockStatus = { reference: ClockReference, 
+/**
+ * Seconds the local clock is ahead of the reference; negative when behind
+ */
+skew_secs: number, significant: boolean, 
+/**
+ * What to tell the user, when significant
+ */
+warning: string | null, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CoexistenceSettings } from "./CoexistenceSettings";
+import type { RouteAction } from "./RouteAction";
+import type { RouteConflict } from "./RouteConflict";
+import type { VpnAdapter } from "./VpnAdapter";
+
+export type CoexistencePlan = { adapters: Array<VpnAdapter>, conflicts: Array<RouteConflict>, 
+/**
+ * Settings proposed from the conflicts, or the ones given
+ */
+settings: CoexistenceSettings, 
+/**
+ * What applying `settings` would run
+ */
+actions: Array<RouteAction>, 
+/**
+ * Guidance for the user, in order
+ */
+steps: Array<string>, };
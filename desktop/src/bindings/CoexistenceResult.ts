@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RouteAction } from "./RouteAction";
+
+export type CoexistenceResult = { actions: Array<RouteAction>, 
+/**
+ * Parts that were skipped and why
+ */
+notes: Array<string>, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RouteExclusion } from "./RouteExclusion";
+import type { RoutePriority } from "./RoutePriority";
+
+export type CoexistenceSettings = { priority: RoutePriority, custom_metric: number | null, exclusions: Array<RouteExclusion>, 
+/**
+ * Re-apply after every connect (asks for elevation each time)
+ */
+apply_on_connect: boolean, };
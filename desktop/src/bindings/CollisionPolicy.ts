@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What to do when a file is already where an incoming one is to be saved
+ */
+export type CollisionPolicy = "overwrite" | "rename" | "skip" | "ask";
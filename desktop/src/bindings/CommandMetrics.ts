@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CommandMetrics = { command: string, calls: number, failures: number, 
+/**
+ * Calls that were retried after refreshing the IPC token
+ */
+auth_retries: number, total_ms: number, max_ms: number, last_error: string | null, };
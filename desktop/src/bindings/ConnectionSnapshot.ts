@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectionState } from "./ConnectionState";
+
+/**
+ * Snapshot broadcast to subscribers after each transition
+ */
+export type ConnectionSnapshot = { state: ConnectionState, previous: ConnectionState | null, reason: string, 
+/**
+ * Unix timestamp (seconds) of the last transition
+ */
+since: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ContentFilterSettings = { enabled: boolean, 
+/**
+ * Also mask message bodies kept in the prefetch cache
+ */
+filter_cached_messages: boolean, 
+/**
+ * Terms masked in every network
+ */
+global_terms: Array<string>, 
+/**
+ * Extra terms per network id
+ */
+network_terms: { [key in string]?: Array<string> }, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CredentialInfo = { 
+/**
+ * "ipc_token", "account_token" or "refresh_token"
+ */
+kind: string, name: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+issued_at: number | null, 
+/**
+ * Unix timestamp (seconds); `None` when it does not expire
+ */
+expires_at: number | null, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CredentialState } from "./CredentialState";
+
+export type CredentialStatus = { 
+/**
+ * "ipc_token", "account_token" or "refresh_token"
+ */
+kind: string, name: string, state: CredentialState, 
+/**
+ * Unix timestamp (seconds)
+ */
+issued_at: number | null, 
+/**
+ * Unix timestamp (seconds); `None` when it does not expire
+ */
+expires_at: number | null, 
+/**
+ * What to tell the user, unless valid
+ */
+warning: string | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CredentialStatus } from "./CredentialStatus";
+
+export type CredentialsStatus = { credentials: Array<CredentialStatus>, 
+/**
+ * Why the daemon's own credentials are missing from the list, if they are
+ */
+daemon_error: string | null, 
+/**
+ * Unix timestamp (seconds)
+ */
+checked_at: number, };
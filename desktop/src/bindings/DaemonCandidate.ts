@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CandidateKind } from "./CandidateKind";
+
+export type DaemonCandidate = { kind: CandidateKind, 
+/**
+ * Distro or container name
+ */
+name: string, url: string, 
+/**
+ * Token file as seen from this machine, when one could be mapped
+ */
+token_path: string | null, 
+/**
+ * Something accepts connections on the daemon port
+ */
+reachable: boolean, token_found: boolean, container_id: string | null, 
+/**
+ * The WSL distro's own address, tried when localhost forwarding is off
+ */
+guest_ip: string | null, 
+/**
+ * What to change before this candidate can work
+ */
+hints: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DaemonStatus = { connected: boolean, 
+/**
+ * Address of the preferred family; see `addressing`
+ */
+virtual_ip: string, virtual_ipv4: string | null, virtual_ipv6: string | null, active_peers: number, network_name: string, };
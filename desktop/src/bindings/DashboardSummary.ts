@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
+import type { ConnectionSnapshot } from "./ConnectionSnapshot";
+import type { DaemonStatus } from "./DaemonStatus";
+import type { NotificationRecord } from "./NotificationRecord";
+import type { PeerTraffic } from "./PeerTraffic";
+import type { TransferInfo } from "./TransferInfo";
+
+export type DashboardSummary = { 
+/**
+ * `None` while the daemon cannot be queried
+ */
+status: DaemonStatus | null, connection: ConnectionSnapshot, top_peers: Array<PeerTraffic>, 
+/**
+ * Latest messages of the connected network, oldest first
+ */
+recent_messages: Array<ChatMessage>, active_transfers: Array<TransferInfo>, 
+/**
+ * Newest first
+ */
+recent_events: Array<NotificationRecord>, };
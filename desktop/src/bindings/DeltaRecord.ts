@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DeltaRecord = { peer_id: string, file_name: string, size: number, 
+/**
+ * Bytes that crossed the wire; `None` when it fell back to a full transfer
+ */
+sent_bytes: number | null, 
+/**
+ * Changed data before compression
+ */
+raw_bytes: number | null, 
+/**
+ * Changed data as sent, after compression
+ */
+compressed_bytes: number | null, fallback_reason: string | null, finished_at: number, };
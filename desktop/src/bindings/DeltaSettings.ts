@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DeltaSettings = { 
+/**
+ * Try deltas when re-sending, and accept them from peers
+ */
+enabled: boolean, 
+/**
+ * Smaller files are always sent whole
+ */
+min_size_mb: number, block_size_kib: number, 
+/**
+ * zstd level for changed data of compressible files; 0 sends it raw
+ */
+compression_level: number, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InstalledRoute } from "./InstalledRoute";
+import type { RouteConflict } from "./RouteConflict";
+
+export type EffectiveRoutes = { tunnel_interfaces: Array<string>, routes: Array<InstalledRoute>, 
+/**
+ * Most severe first
+ */
+conflicts: Array<RouteConflict>, };
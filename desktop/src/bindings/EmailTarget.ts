@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SmtpSecurity } from "./SmtpSecurity";
+
+export type EmailTarget = { host: string, port: number, security: SmtpSecurity, username: string, password: string, from: string, to: Array<string>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EndpointSource } from "./EndpointSource";
+
+/**
+ * The endpoint in effect
+ */
+export type Endpoint = { url: string, 
+/**
+ * `None` for the daemon's default token location
+ */
+token_path: string | null, source: EndpointSource, 
+/**
+ * Unix socket tried before `url`; only set for the default endpoint on Linux and macOS
+ */
+socket_path: string | null, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Endpoint } from "./Endpoint";
+import type { EndpointSettings } from "./EndpointSettings";
+
+export type EndpointConfig = { 
+/**
+ * What is saved
+ */
+settings: EndpointSettings, 
+/**
+ * What is used, after environment overrides
+ */
+active: Endpoint, 
+/**
+ * The active endpoint is on another host, whose files are not the desktop's
+ */
+remote: boolean, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type EndpointSettings = { 
+/**
+ * `http://host:port`; `None` for the default
+ */
+url: string | null, 
+/**
+ * IPC token file; `None` for the daemon's default location
+ */
+token_path: string | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type EndpointTest = { 
+/**
+ * The URL actually tried, after normalizing
+ */
+url: string, 
+/**
+ * A gRPC connection could be opened
+ */
+reachable: boolean, 
+/**
+ * The daemon accepted the token
+ */
+authenticated: boolean, daemon_version: string | null, latency_ms: number | null, error: string | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ErrorBody } from "./ErrorBody";
+
+export type Envelope<T> = { schema_version: number, 
+/**
+ * Present on success
+ */
+data: T | null, 
+/**
+ * Present on failure
+ */
+error: ErrorBody | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExportStage } from "./ExportStage";
+
+export type ExportProgress = { export_id: string, stage: ExportStage, done: number, 
+/**
+ * `None` while not known yet (messages are counted as they arrive)
+ */
+total: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unix timestamps (seconds); open ends when omitted
+ */
+export type ExportRange = { from: number | null, to: number | null, };
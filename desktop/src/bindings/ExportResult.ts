@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ExportResult = { export_id: string, 
+/**
+ * Folder created inside the chosen one
+ */
+folder: string, 
+/**
+ * The HTML or JSON file
+ */
+file: string, messages: number, attachments: number, 
+/**
+ * Files exchanged in the range that no longer exist where they were
+ */
+missing_attachments: number, };
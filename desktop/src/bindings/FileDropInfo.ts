@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileDropMode } from "./FileDropMode";
+
+export type FileDropInfo = { id: string, mode: FileDropMode, 
+/**
+ * Token-protected link to hand to the guest
+ */
+url: string, files: Array<string>, save_dir: string | null, expires_at: number, 
+/**
+ * Completed downloads (share) or uploads (receive)
+ */
+transfers: number, };
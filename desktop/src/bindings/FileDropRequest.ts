@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileDropMode } from "./FileDropMode";
+
+export type FileDropRequest = { mode: FileDropMode, 
+/**
+ * Files to serve (share mode)
+ */
+paths: Array<string>, 
+/**
+ * Folder to receive into (receive mode)
+ */
+save_dir: string | null, ttl_minutes: number | null, max_upload_mb: bigint | null, };
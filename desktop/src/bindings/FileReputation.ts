@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReputationVerdict } from "./ReputationVerdict";
+
+export type FileReputation = { transfer_id: string, sha256: string, verdict: ReputationVerdict, 
+/**
+ * Engines that flagged the file, when the service says
+ */
+detections: number | null, 
+/**
+ * Host of the service asked
+ */
+service: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+checked_at: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VersionSource } from "./VersionSource";
+
+export type FileVersion = { id: string, path: string, size: number, 
+/**
+ * Modification time of the file when the copy was taken
+ */
+modified: number, created_at: number, source: VersionSource, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
+import type { FlagReason } from "./FlagReason";
+
+export type FlaggedMessage = { message: ChatMessage, sender_name: string, reasons: Array<FlagReason>, };
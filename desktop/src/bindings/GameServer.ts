@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GameProtocol } from "./GameProtocol";
+
+export type GameServer = { peer_id: string, peer_name: string, protocol: GameProtocol, 
+/**
+ * "ip:port" to connect to
+ */
+address: string, name: string, map: string | null, version: string | null, players: number | null, max_players: number | null, ping_ms: number | null, advertised: boolean, };
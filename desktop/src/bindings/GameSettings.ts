@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AdvertisedServer } from "./AdvertisedServer";
+
+export type GameSettings = { 
+/**
+ * Allow list_game_servers to probe peers
+ */
+browse_enabled: boolean, 
+/**
+ * Also try each game's default port on peers that advertise nothing for it
+ */
+probe_default_ports: boolean, 
+/**
+ * Servers this machine hosts, announced to peers
+ */
+advertised: Array<AdvertisedServer>, };
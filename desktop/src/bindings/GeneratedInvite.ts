@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type GeneratedInvite = { code: string, 
+/**
+ * Join link; empty when the daemon has none
+ */
+url: string, 
+/**
+ * 0 = unlimited
+ */
+max_uses: number, 
+/**
+ * Unix timestamp (seconds); `None` when it never expires
+ */
+expires_at: number | null, };
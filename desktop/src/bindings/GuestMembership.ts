@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type GuestMembership = { network_id: string, network_name: string, 
+/**
+ * Unix timestamps (seconds)
+ */
+joined_at: number, expires_at: number, };
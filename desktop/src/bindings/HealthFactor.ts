@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealthFactorKind } from "./HealthFactorKind";
+
+export type HealthFactor = { kind: HealthFactorKind, 
+/**
+ * Percent for ratios and loss, milliseconds for latency, a count for disconnects;
+ * `None` when there was nothing to measure
+ */
+value: number | null, 
+/**
+ * Points this factor took off the score
+ */
+penalty: number, weight: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HostOverride = { name: string, 
+/**
+ * Follow this peer's current virtual IP
+ */
+peer_id: string | null, 
+/**
+ * Fixed address, used when `peer_id` is not set
+ */
+ip: string | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HostOverride } from "./HostOverride";
+
+export type HostOverrides = { entries: Array<HostOverride>, 
+/**
+ * Keep the OS hosts file in sync with `entries`
+ */
+write_hosts_file: boolean, };
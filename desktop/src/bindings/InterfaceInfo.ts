@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NetworkAddress } from "./NetworkAddress";
+import type { TunnelInterface } from "./TunnelInterface";
+
+export type InterfaceInfo = { interfaces: Array<TunnelInterface>, networks: Array<NetworkAddress>, 
+/**
+ * Parts that could not be read and why
+ */
+notes: Array<string>, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What joining an invite code would lead to
+ */
+export type InvitePreview = { valid: boolean, 
+/**
+ * Why the code cannot be used: expired, used up, revoked, unknown
+ */
+invalid_reason: string | null, network_id: string, network_name: string, description: string, member_count: number, online_count: number, owner_id: string, owner_name: string, 
+/**
+ * Joining waits for an admin to accept
+ */
+requires_approval: boolean, min_trust_level: string | null, 
+/**
+ * What members may do, e.g. "chat", "file_transfer"
+ */
+permissions: Array<string>, 
+/**
+ * Unix timestamp (seconds)
+ */
+expires_at: number | null, 
+/**
+ * `None` when unlimited
+ */
+uses_remaining: number | null, 
+/**
+ * This client is a member already
+ */
+already_joined: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type JoinOptions = { 
+/**
+ * Join as a guest and leave again after this many hours
+ */
+guest_hours: number | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Someone waiting for approval to join a network
+ */
+export type JoinRequest = { peer_id: string, name: string, message: string | null, 
+/**
+ * Unix timestamp (seconds)
+ */
+requested_at: number, };
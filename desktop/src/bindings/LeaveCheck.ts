@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LeaveWarning } from "./LeaveWarning";
+import type { LocalNetworkData } from "./LocalNetworkData";
+
+export type LeaveCheck = { network_id: string, network_name: string, member_count: number, 
+/**
+ * Empty when leaving needs no confirmation
+ */
+warnings: Array<LeaveWarning>, local_data: LocalNetworkData, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LeaveOptions = { 
+/**
+ * Leave despite the warnings of the check
+ */
+confirmed: boolean, 
+/**
+ * Cancel the transfers with members first
+ */
+cancel_transfers: boolean, purge_chat_history: boolean, purge_aliases: boolean, };
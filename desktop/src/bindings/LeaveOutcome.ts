@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LeaveCheck } from "./LeaveCheck";
+
+export type LeaveOutcome = { "outcome": "needs_confirmation", check: LeaveCheck, } | { "outcome": "left", cancelled_transfers: number, purged_messages: number, removed_aliases: Array<string>, };
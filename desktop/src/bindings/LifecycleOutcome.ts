@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DaemonStatus } from "./DaemonStatus";
+import type { ServiceManager } from "./ServiceManager";
+
+export type LifecycleOutcome = { manager: ServiceManager, 
+/**
+ * Whether the daemon answers after the action
+ */
+running: boolean, status: DaemonStatus | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BandwidthSample } from "./BandwidthSample";
+import type { TransferProgress } from "./TransferProgress";
+
+/**
+ * What a JSON channel receives
+ */
+export type LiveEvent = { "kind": "progress", "data": TransferProgress } | { "kind": "bandwidth", "data": BandwidthSample };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What is kept locally for the network and can be purged after leaving
+ */
+export type LocalNetworkData = { cached_messages: number, 
+/**
+ * Host aliases pointing at members
+ */
+aliases: Array<string>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LocaleInfo = { 
+/**
+ * BCP 47 language code, as stored in Settings.language
+ */
+code: string, 
+/**
+ * Name of the language in that language
+ */
+name: string, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MdnsSettings } from "./MdnsSettings";
+
+export type MdnsReflectionStatus = { settings: MdnsSettings, 
+/**
+ * Services hosted here that are being sent to peers
+ */
+local_services: number, 
+/**
+ * Peer services currently announced here
+ */
+reflected_services: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MdnsSettings = { 
+/**
+ * Networks reflection is enabled for
+ */
+networks: Array<string>, 
+/**
+ * Service types reflected, e.g. "_airplay._tcp.local."
+ */
+service_types: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MemorySettings = { chat_pages_mb: number, transfers_mb: number, peer_snapshots_mb: number, bandwidth_history_mb: number, 
+/**
+ * Entries unused for this long are dropped even within budget; 0 keeps them
+ */
+max_idle_mins: number, };
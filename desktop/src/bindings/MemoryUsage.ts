@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CacheUsage } from "./CacheUsage";
+
+export type MemoryUsage = { caches: Array<CacheUsage>, total_bytes: number, limit_bytes: number, 
+/**
+ * Resident size of the whole process, where the OS reports it
+ */
+process_resident_bytes: number | null, };
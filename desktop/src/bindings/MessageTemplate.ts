@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MessageTemplate = { id: string, name: string, body: string, 
+/**
+ * Typed after "/" in the composer, e.g. "brb"
+ */
+shortcut: string | null, use_count: number, 
+/**
+ * Unix timestamp (seconds); `None` if never used
+ */
+last_used: number | null, };
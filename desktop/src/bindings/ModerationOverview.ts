@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ActiveMember } from "./ActiveMember";
+import type { FlaggedMessage } from "./FlaggedMessage";
+import type { JoinRequest } from "./JoinRequest";
+import type { ModerationRecord } from "./ModerationRecord";
+
+export type ModerationOverview = { network_id: string, member_count: number, online_count: number, 
+/**
+ * Newest first
+ */
+recent_actions: Array<ModerationRecord>, 
+/**
+ * `None` when the daemon cannot list them, e.g. an older version
+ */
+join_requests: Array<JoinRequest> | null, 
+/**
+ * Newest first
+ */
+flagged_messages: Array<FlaggedMessage>, most_active: Array<ActiveMember>, 
+/**
+ * Messages the flags and activity were computed from
+ */
+sampled_messages: number, 
+/**
+ * Sections that could not be loaded, with the reason
+ */
+errors: Array<string>, generated_at: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModerationAction } from "./ModerationAction";
+
+export type ModerationRecord = { network_id: string, peer_id: string, 
+/**
+ * Filled in from the member list when the overview is built
+ */
+peer_name: string | null, action: ModerationAction, reason: string | null, 
+/**
+ * Unix timestamp (seconds)
+ */
+at: number, };
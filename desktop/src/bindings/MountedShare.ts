@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ShareProtocol } from "./ShareProtocol";
+
+export type MountedShare = { id: string, peer_id: string, protocol: ShareProtocol, 
+/**
+ * Share name (SMB) or export path (NFS)
+ */
+share: string, 
+/**
+ * Folder, or drive letter like "Z:" on Windows
+ */
+mountpoint: string, mounted_at: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MqttSettings = { enabled: boolean, host: string, port: number, tls: boolean, username: string, password: string, 
+/**
+ * Topic root for state topics
+ */
+base_topic: string, 
+/**
+ * Home Assistant discovery prefix; empty disables discovery
+ */
+discovery_prefix: string, 
+/**
+ * Device id in topics; defaults to the host name
+ */
+node_id: string, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MutationKind } from "./MutationKind";
+
+export type Mutation = { id: string, kind: MutationKind, 
+/**
+ * Setting name or network id
+ */
+target: string, 
+/**
+ * The optimistic value
+ */
+value: unknown, started_at: number, };
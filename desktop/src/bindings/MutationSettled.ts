@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MutationKind } from "./MutationKind";
+
+export type MutationSettled = { id: string, kind: MutationKind, target: string, ok: boolean, 
+/**
+ * The daemon's value, or the restored one after a failure
+ */
+value: unknown, error: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type NetworkAddress = { network_id: string, network_name: string, virtual_ip: string, 
+/**
+ * `None` when no local interface holds the address (yet)
+ */
+interface: string | null, cidr: string | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealthFactor } from "./HealthFactor";
+
+export type NetworkHealth = { network_id: string, network_name: string, 
+/**
+ * 0-100; `None` when no factor could be measured
+ */
+score: number | null, factors: Array<HealthFactor>, online_peers: number, computed_at: number, };
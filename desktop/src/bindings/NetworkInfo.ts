@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type NetworkInfo = { id: string, name: string, invite_code: string, 
+/**
+ * Joined as a guest: the network is left at this Unix timestamp (seconds); see `guests`
+ */
+guest_until: number | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One joined network's state; several can be connected at once
+ */
+export type NetworkStatus = { network_id: string, name: string, connected: boolean, 
+/**
+ * The network `DaemonStatus` reports on
+ */
+is_current: boolean, 
+/**
+ * This machine's address in the network while connected, of the preferred family
+ */
+virtual_ip: string | null, virtual_ipv4: string | null, virtual_ipv6: string | null, peer_count: number, online_count: number, };
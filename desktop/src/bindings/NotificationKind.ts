@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Mirrors `NotificationType` in the frontend
+ */
+export type NotificationKind = "message" | "voice" | "transfer" | "member" | "system" | "security";
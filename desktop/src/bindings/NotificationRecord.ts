@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NotificationKind } from "./NotificationKind";
+
+export type NotificationRecord = { id: number, kind: NotificationKind, title: string, body: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+timestamp: number, read: boolean, };
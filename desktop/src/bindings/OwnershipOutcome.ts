@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OwnershipTransferPrompt } from "./OwnershipTransferPrompt";
+import type { OwnershipTransferRecord } from "./OwnershipTransferRecord";
+
+export type OwnershipOutcome = { "outcome": "needs_confirmation", prompt: OwnershipTransferPrompt, } | { "outcome": "transferred", record: OwnershipTransferRecord, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type OwnershipTransferPrompt = { 
+/**
+ * Pass back as `confirm_token` to go ahead
+ */
+confirm_token: string, network_id: string, network_name: string, new_owner_id: string, new_owner_name: string, 
+/**
+ * The new owner is not connected right now; the transfer still works
+ */
+new_owner_offline: boolean, 
+/**
+ * Unix timestamp (seconds) the token expires at
+ */
+expires_at: number, };
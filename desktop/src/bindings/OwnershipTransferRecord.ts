@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type OwnershipTransferRecord = { network_id: string, network_name: string, previous_owner_id: string, new_owner_id: string, new_owner_name: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+at: number, };
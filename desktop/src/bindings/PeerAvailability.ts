@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AvailabilitySpan } from "./AvailabilitySpan";
+
+export type PeerAvailability = { peer_id: string, from: number, to: number, 
+/**
+ * Share of the observed time the peer was online; `None` if never observed
+ */
+uptime_percent: number | null, observed_secs: number, online_secs: number, 
+/**
+ * Times the peer went offline after being seen online
+ */
+outages: number, 
+/**
+ * Covers the whole window in order, gaps filled with `unknown`
+ */
+timeline: Array<AvailabilitySpan>, };
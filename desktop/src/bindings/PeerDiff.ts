@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PeerInfo } from "./PeerInfo";
+
+/**
+ * Membership changes since the last `peers://changed` event
+ */
+export type PeerDiff = { 
+/**
+ * Events are about the current network; `None` when there is none
+ */
+network_id: string | null, 
+/**
+ * Members that joined or changed, in their latest state
+ */
+joined: Array<PeerInfo>, 
+/**
+ * Ids of members that left
+ */
+left: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PeerInfo } from "./PeerInfo";
+
+export type PeerEventPayload = { 
+/**
+ * The network we were connected to when the event arrived, if known
+ */
+network_id: string | null, peer: PeerInfo, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PeerInfo = { id: string, name: string, display_name: string, 
+/**
+ * Address of the preferred family; see `addressing`
+ */
+virtual_ip: string, virtual_ipv4: string | null, virtual_ipv6: string | null, connected: boolean, is_relay: boolean, latency_ms: number, is_self: boolean, 
+/**
+ * "owner", "admin", "member", or empty when unknown
+ */
+role: string, };
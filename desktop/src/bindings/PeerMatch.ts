@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MatchField } from "./MatchField";
+import type { PeerInfo } from "./PeerInfo";
+
+export type PeerMatch = { peer: PeerInfo, tags: Array<string>, 
+/**
+ * Fields the query matched
+ */
+matched: Array<MatchField>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PinnedPeer } from "./PinnedPeer";
+
+export type PeerMonitorSettings = { pinned: Array<PinnedPeer>, interval_secs: number, 
+/**
+ * Bytes uploaded per throughput measurement; 0 measures latency only
+ */
+payload_kib: number, 
+/**
+ * Answer other clients' probes on our virtual IP
+ */
+responder_enabled: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PeerSample = { timestamp: number, online: boolean, latency_ms: number | null, 
+/**
+ * Upload throughput; `None` when the peer has no responder
+ */
+throughput_mbps: number | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PeerMatch } from "./PeerMatch";
+
+export type PeerSearchResult = { network_id: string, matches: Array<PeerMatch>, 
+/**
+ * Matches before `limit`
+ */
+total: number, 
+/**
+ * Lookup time, excluding any snapshot fetch
+ */
+took_us: number, };
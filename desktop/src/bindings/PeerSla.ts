@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PeerSla = { peer_id: string, samples: number, 
+/**
+ * Share of samples with the peer online, 0-100
+ */
+uptime_percent: number, avg_latency_ms: number | null, p95_latency_ms: number | null, avg_throughput_mbps: number | null, 
+/**
+ * Samples above the latency budget
+ */
+budget_breaches: number, };
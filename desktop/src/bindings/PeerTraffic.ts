@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PeerTraffic = { peer_id: string, name: string, 
+/**
+ * Bytes moved in either direction across all known transfers
+ */
+bytes: number, transfers: number, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PeerInfo } from "./PeerInfo";
+
+export type PeerWindow = { network_id: string, offset: number, 
+/**
+ * Members in the whole list
+ */
+total: number, peers: Array<PeerInfo>, 
+/**
+ * Changes whenever the member list does; rows cached under an older
+ * revision should be fetched again
+ */
+revision: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PinnedPeer = { peer_id: string, 
+/**
+ * Warn when latency stays above this
+ */
+latency_budget_ms: number, };
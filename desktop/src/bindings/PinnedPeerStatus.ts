@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PeerSample } from "./PeerSample";
+import type { PinnedPeer } from "./PinnedPeer";
+
+export type PinnedPeerStatus = { peer: PinnedPeer, latest: PeerSample | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PortableMode = { enabled: boolean, 
+/**
+ * Where local state lives in portable mode
+ */
+data_dir: string | null, };
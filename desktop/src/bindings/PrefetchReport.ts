@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PrefetchReport = { network_id: string | null, 
+/**
+ * What was warmed: "peers", "chat", "transfers"
+ */
+warmed: Array<string>, errors: Array<string>, took_ms: number, };
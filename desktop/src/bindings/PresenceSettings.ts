@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PresenceSettings = { 
+/**
+ * Master switch; nothing is published while false
+ */
+enabled: boolean, 
+/**
+ * Replace the network name and virtual IP with neutral placeholders
+ */
+hide_network_details: boolean, 
+/**
+ * Placeholders: `{network}`, `{peers}`, `{ip}`
+ */
+template: string, 
+/**
+ * Discord application id; empty disables the Discord sink
+ */
+discord_client_id: string, 
+/**
+ * Loopback URL that receives each update as a JSON POST; empty disables it
+ */
+endpoint_url: string, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PushProvider } from "./PushProvider";
+
+export type PushTarget = { provider: PushProvider, url: string, token: string, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type QueuedAction = { "kind": "send_message", network_id: string, content: string, } | { "kind": "send_file", peer_id: string, file_path: string, };
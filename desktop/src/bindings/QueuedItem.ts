@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { QueuedAction } from "./QueuedAction";
+import type { QueuedStatus } from "./QueuedStatus";
+
+export type QueuedItem = { id: string, action: QueuedAction, status: QueuedStatus, 
+/**
+ * Unix timestamp (seconds)
+ */
+queued_at: number, attempts: number, last_error: string | null, };
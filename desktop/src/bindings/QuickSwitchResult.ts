@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { QuickSwitchKind } from "./QuickSwitchKind";
+
+export type QuickSwitchResult = { 
+/**
+ * `network:<id>`, `peer:<id>` or `action:<name>`; pass back to `quick_switch_record`
+ */
+id: string, kind: QuickSwitchKind, title: string, subtitle: string, score: number, 
+/**
+ * Character positions in `title` matched by the query, for highlighting
+ */
+matches: Array<number>, };
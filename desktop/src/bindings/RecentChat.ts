@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RecentChat = { network_id: string, network_name: string, 
+/**
+ * `None` when the last message was our own
+ */
+last_sender: string | null, 
+/**
+ * Start of the last message, masked by the content filter
+ */
+preview: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+last_at: number, unread: number, };
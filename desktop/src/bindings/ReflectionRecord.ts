@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReflectionDirection } from "./ReflectionDirection";
+
+export type ReflectionRecord = { direction: ReflectionDirection, network_id: string, peer_id: string, service_type: string, instance: string, port: number, last_seen: number, };
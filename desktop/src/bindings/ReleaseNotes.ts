@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReleaseSection } from "./ReleaseSection";
+
+export type ReleaseNotes = { version: string, sections: Array<ReleaseSection>, 
+/**
+ * The unparsed Markdown
+ */
+raw: string, url: string | null, fetched_at: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReleaseSection = { 
+/**
+ * Heading text; empty for notes before the first heading
+ */
+title: string, 
+/**
+ * Bullet points
+ */
+items: Array<string>, 
+/**
+ * Paragraph text outside bullet lists
+ */
+text: Array<string>, };
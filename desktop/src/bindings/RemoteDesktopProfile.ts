@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RemoteProtocol } from "./RemoteProtocol";
+
+export type RemoteDesktopProfile = { peer_id: string, protocol: RemoteProtocol, 
+/**
+ * Defaults to 3389 (RDP) or 5900 (VNC)
+ */
+port: number | null, 
+/**
+ * Pre-filled in the client; passwords are always asked by the client
+ */
+username: string | null, fullscreen: boolean, last_used_at: number | null, };
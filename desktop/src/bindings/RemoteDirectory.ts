@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RemoteFileEntry } from "./RemoteFileEntry";
+
+/**
+ * A directory listing from the daemon's host
+ */
+export type RemoteDirectory = { 
+/**
+ * Absolute path, as resolved by the daemon
+ */
+path: string, 
+/**
+ * `None` at the root
+ */
+parent: string | null, entries: Array<RemoteFileEntry>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file or directory on the daemon's host
+ */
+export type RemoteFileEntry = { name: string, 
+/**
+ * Absolute path on the daemon's host
+ */
+path: string, is_dir: boolean, size_bytes: number, 
+/**
+ * Unix timestamp (seconds)
+ */
+modified_at: number | null, 
+/**
+ * The daemon can open it
+ */
+readable: boolean, };
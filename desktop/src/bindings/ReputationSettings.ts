@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReputationSettings = { enabled: boolean, 
+/**
+ * URL to look a hash up at, with `{sha256}` where the hash goes
+ */
+lookup_url: string, 
+/**
+ * Header carrying the API key, e.g. `x-apikey` for VirusTotal
+ */
+api_key_header: string, 
+/**
+ * Refuse to open executables the service does not vouch for
+ */
+strict: boolean, 
+/**
+ * Set when an API key is stored; the key itself is never returned
+ */
+has_api_key: boolean, };
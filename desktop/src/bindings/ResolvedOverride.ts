@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ResolvedOverride = { name: string, peer_id: string | null, 
+/**
+ * `None` when the peer is unknown to the daemon
+ */
+ip: string | null, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConflictSeverity } from "./ConflictSeverity";
+import type { RouteEntry } from "./RouteEntry";
+
+export type RouteConflict = { severity: ConflictSeverity, 
+/**
+ * The GoConnect route affected
+ */
+route: string, other_interface: string, other_route: RouteEntry, other_table: string | null, 
+/**
+ * Product name when the interface looks like a known VPN
+ */
+other_vpn: string | null, explanation: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RouteExclusion = { 
+/**
+ * Range kept off GoConnect
+ */
+cidr: string, 
+/**
+ * Interface to send it through; `None` for wherever it goes without GoConnect
+ */
+via_interface: string | null, };
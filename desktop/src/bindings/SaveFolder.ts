@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SaveFolderScope } from "./SaveFolderScope";
+
+export type SaveFolder = { scope: SaveFolderScope, 
+/**
+ * Peer or network id
+ */
+id: string, folder: string, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScheduleStatus } from "./ScheduleStatus";
+import type { TimeWindow } from "./TimeWindow";
+
+export type ScheduledTransfer = { id: string, peer_id: string, path: string, 
+/**
+ * Not before this time
+ */
+start_at: number | null, 
+/**
+ * Only while inside this window
+ */
+window: TimeWindow | null, status: ScheduleStatus, 
+/**
+ * Daemon transfer id once started
+ */
+transfer_id: string | null, last_error: string | null, created_at: number, finished_at: number | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SecurityEventKind } from "./SecurityEventKind";
+
+export type SecurityEvent = { id: string, kind: SecurityEventKind, message: string, peer_id: string | null, network_id: string | null, device_name: string | null, 
+/**
+ * Where a failed IPC call came from, when known
+ */
+source: string | null, 
+/**
+ * Unix timestamp (seconds)
+ */
+timestamp: number, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { QueuedItem } from "./QueuedItem";
+
+export type SendOutcome = { "outcome": "sent", transfer_id: string | null, } | { "outcome": "queued", item: QueuedItem, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SendReport = { transfer_id: string, peer_id: string, 
+/**
+ * Absolute path of the file being sent
+ */
+file: string, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ServiceManager = "systemd_user" | "systemd" | "launch_agent" | "launch_daemon" | "windows_service" | "process";
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SessionEnd } from "./SessionEnd";
+
+export type SessionEntry = { network: string, started_at: number, 
+/**
+ * `None` while connected
+ */
+ended_at: number | null, end: SessionEnd | null, 
+/**
+ * Up to now for the open session
+ */
+duration_secs: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NetworkTime } from "./NetworkTime";
+import type { SessionEntry } from "./SessionEntry";
+
+export type SessionHistory = { 
+/**
+ * Newest first
+ */
+sessions: Array<SessionEntry>, 
+/**
+ * Connected time inside the filter range across all matching sessions,
+ * not only the returned page
+ */
+total_secs: number, 
+/**
+ * Sorted by connected time
+ */
+networks: Array<NetworkTime>, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type Settings = { auto_connect: boolean, start_minimized: boolean, notifications_enabled: boolean, log_level: string, 
+/**
+ * "system", "light" or "dark"
+ */
+theme: string, 
+/**
+ * Locale code, e.g. "en" or "tr"
+ */
+language: string, 
+/**
+ * Upload speed cap for transfers; 0 for none
+ */
+max_upload_speed_kbps: number, 
+/**
+ * Download speed cap for transfers; 0 for none
+ */
+max_download_speed_kbps: number, 
+/**
+ * The daemon accepts incoming files without asking
+ */
+auto_accept_files: boolean, 
+/**
+ * Where the daemon saves incoming files; empty for its default
+ */
+download_path: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ShareCredentials = { username: string, password: string, domain: string | null, 
+/**
+ * Store in the system keyring for next time
+ */
+remember: boolean, };
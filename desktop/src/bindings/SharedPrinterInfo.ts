@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SharedPrinterInfo = { name: string, shared: boolean, allowed_peers: Array<string>, 
+/**
+ * Address peers add the printer with, while shared and connected
+ */
+url: string | null, 
+/**
+ * Shared earlier but no longer installed locally
+ */
+missing: boolean, };
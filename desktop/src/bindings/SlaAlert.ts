@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SlaAlert = { id: string, rule_id: string, peer_id: string, peer_name: string, message: string, 
+/**
+ * When the condition started holding
+ */
+since: number, raised_at: number, 
+/**
+ * `None` while the breach is ongoing
+ */
+resolved_at: number | null, };
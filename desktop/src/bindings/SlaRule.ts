@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SlaCondition } from "./SlaCondition";
+
+export type SlaRule = { 
+/**
+ * Empty when creating; assigned by `set_sla_settings`
+ */
+id: string, condition: SlaCondition, 
+/**
+ * How long the condition must hold before alerting
+ */
+minutes: number, 
+/**
+ * Critical peers the rule covers; empty covers all of them
+ */
+peer_ids: Array<string>, enabled: boolean, };
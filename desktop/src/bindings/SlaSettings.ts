@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SlaRule } from "./SlaRule";
+
+export type SlaSettings = { critical_peers: Array<string>, rules: Array<SlaRule>, };
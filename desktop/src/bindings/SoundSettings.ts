@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NotificationKind } from "./NotificationKind";
+import type { NotificationSound } from "./NotificationSound";
+
+export type SoundSettings = { enabled: boolean, 
+/**
+ * Stay quiet while the system output is muted or Do Not Disturb is on
+ */
+respect_do_not_disturb: boolean, 
+/**
+ * Event types without an entry use `System`
+ */
+sounds: { [key in NotificationKind]?: NotificationSound }, };
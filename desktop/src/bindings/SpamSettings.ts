@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SpamSettings = { enabled: boolean, 
+/**
+ * More messages than this within `window_secs` count as a flood
+ */
+max_messages: number, window_secs: number, mute_minutes: number, };
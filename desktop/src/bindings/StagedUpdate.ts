@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A downloaded update waiting to be installed
+ */
+export type StagedUpdate = { version: string, path: string, 
+/**
+ * Hex SHA-256 of the package, checked again before installing
+ */
+sha256: string, size: number, downloaded_at: number, };
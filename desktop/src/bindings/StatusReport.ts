@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectionState } from "./ConnectionState";
+
+/**
+ * Tunnel status as reported to scripts
+ */
+export type StatusReport = { 
+/**
+ * Desktop-to-daemon connection state; `None` from the command line, which
+ * talks to the daemon directly
+ */
+state: ConnectionState | null, connected: boolean, network_name: string | null, 
+/**
+ * Address of the preferred family
+ */
+virtual_ip: string | null, virtual_ipv4: string | null, virtual_ipv6: string | null, active_peers: number | null, };
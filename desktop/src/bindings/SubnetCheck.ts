@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SubnetConflict } from "./SubnetConflict";
+
+export type SubnetCheck = { network_cidr: string, 
+/**
+ * True when the range was inferred from member addresses rather than given
+ */
+derived: boolean, conflicts: Array<SubnetConflict>, 
+/**
+ * Free private range of the same size, when there are conflicts
+ */
+suggestion: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SyncConflict = { pair_id: string, file_name: string, 
+/**
+ * Where the incoming version was saved
+ */
+conflict_copy: string, detected_at: number, };
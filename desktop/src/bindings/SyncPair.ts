@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SyncDirection } from "./SyncDirection";
+
+export type SyncPair = { id: string, peer_id: string, local_path: string, direction: SyncDirection, paused: boolean, };
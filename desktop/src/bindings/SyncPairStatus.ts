@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SyncActivity } from "./SyncActivity";
+import type { SyncPair } from "./SyncPair";
+
+export type SyncPairStatus = { pair: SyncPair, activity: SyncActivity, 
+/**
+ * Changed files waiting for the next batch
+ */
+pending: number, in_flight: number, last_synced_at: number | null, last_error: string | null, conflicts: number, };
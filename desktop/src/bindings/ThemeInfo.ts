@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Appearance } from "./Appearance";
+import type { ThemePreference } from "./ThemePreference";
+
+export type ThemeInfo = { preference: ThemePreference, 
+/**
+ * Appearance reported by the OS
+ */
+system: Appearance, 
+/**
+ * Appearance the UI should use
+ */
+effective: Appearance, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ThrottleSettings = { 
+/**
+ * Off forwards every event as it arrives
+ */
+enabled: boolean, 
+/**
+ * Progress events per second for each transfer; 0 forwards all
+ */
+transfer_progress_per_sec: number, 
+/**
+ * Bandwidth samples per second; 0 forwards all
+ */
+bandwidth_per_sec: number, 
+/**
+ * Peer diffs per second; 0 sends one per change
+ */
+peer_changes_per_sec: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventKind } from "./EventKind";
+
+export type ThrottleStats = { kind: EventKind, 
+/**
+ * Events offered since startup
+ */
+received: number, 
+/**
+ * Events actually sent to the webview
+ */
+emitted: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Daily local-time window; `end` before `start` wraps past midnight
+ */
+export type TimeWindow = { 
+/**
+ * "HH:MM"
+ */
+start: string, 
+/**
+ * "HH:MM"
+ */
+end: string, };
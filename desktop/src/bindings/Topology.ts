@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TopologyEdge } from "./TopologyEdge";
+import type { TopologyNode } from "./TopologyNode";
+
+export type Topology = { network_id: string, nodes: Array<TopologyNode>, edges: Array<TopologyEdge>, direct_links: number, relayed_links: number, offline_peers: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LinkKind } from "./LinkKind";
+
+export type TopologyEdge = { from: string, to: string, link: LinkKind, 
+/**
+ * Latency reported for the peer at the far end; `None` when unknown
+ */
+latency_ms: number | null, };
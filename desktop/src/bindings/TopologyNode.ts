@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NodeKind } from "./NodeKind";
+
+export type TopologyNode = { id: string, kind: NodeKind, name: string, virtual_ip: string, 
+/**
+ * "owner", "admin", "member", or empty
+ */
+role: string, is_self: boolean, online: boolean, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TransferHistoryEntry = { id: string, peer_id: string, peer_name: string, 
+/**
+ * Network the client was connected to when the transfer finished
+ */
+network: string, file_name: string, 
+/**
+ * "upload" or "download"
+ */
+direction: string, 
+/**
+ * "completed", "failed" or "cancelled"
+ */
+status: string, 
+/**
+ * Bytes actually moved
+ */
+bytes: number, file_size: number, error: string | null, 
+/**
+ * Where a download was saved, or what was uploaded; `None` when unknown
+ */
+path: string | null, 
+/**
+ * Unix timestamp (seconds)
+ */
+finished_at: number, };
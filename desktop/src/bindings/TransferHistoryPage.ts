@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TransferHistoryEntry } from "./TransferHistoryEntry";
+
+export type TransferHistoryPage = { 
+/**
+ * Newest first
+ */
+items: Array<TransferHistoryEntry>, 
+/**
+ * Matching entries across all pages
+ */
+total: number, page: number, page_size: number, };
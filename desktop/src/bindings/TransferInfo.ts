@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReputationVerdict } from "./ReputationVerdict";
+
+export type TransferInfo = { id: string, peer_id: string, file_name: string, file_size: number, transferred: number, status: string, direction: string, error: string | null, 
+/**
+ * Hex SHA-256 of the sender's file, when the daemon reports one
+ */
+sha256: string | null, 
+/**
+ * The downloaded file was hashed locally and matched `sha256`
+ */
+verified: boolean, 
+/**
+ * Speed cap for this transfer alone; `None` when the settings' limits apply
+ */
+max_speed_kbps: number | null, 
+/**
+ * Temporary file a download is written to until it is complete and verified
+ */
+part_path: string | null, 
+/**
+ * What the reputation service said about the received file, when it was asked
+ */
+reputation: ReputationVerdict | null, };
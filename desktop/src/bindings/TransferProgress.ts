@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TransferProgress = { id: string, peer_id: string, 
+/**
+ * "upload" or "download"
+ */
+direction: string, status: string, transferred: number, total: number, bytes_per_sec: number, };
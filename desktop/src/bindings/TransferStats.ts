@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TransferStats = { total_uploads: number, total_downloads: number, active_transfers: number, completed_transfers: number, failed_transfers: number, total_bytes_sent: number, total_bytes_received: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TransferVerification = { transfer_id: string, path: string, 
+/**
+ * Hash reported by the daemon; `None` when it reports none
+ */
+expected: string | null, actual: string, verified: boolean, 
+/**
+ * Unix timestamp (seconds)
+ */
+checked_at: number, };
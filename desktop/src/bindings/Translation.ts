@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type Translation = { text: string, target_language: string, 
+/**
+ * Language the provider detected, when it reports one
+ */
+detected_language: string | null, 
+/**
+ * Answered from the local cache
+ */
+cached: boolean, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TranslationProvider } from "./TranslationProvider";
+
+export type TranslationSettings = { provider: TranslationProvider, 
+/**
+ * Base URL of the provider
+ */
+endpoint: string, 
+/**
+ * Model name for Ollama
+ */
+model: string, 
+/**
+ * Language code to translate into; empty = the app's language
+ */
+target_language: string, 
+/**
+ * Network or peer ids of conversations translated automatically
+ */
+auto_translate: Array<string>, 
+/**
+ * Set when an API key is stored; the key itself is never returned
+ */
+has_api_key: boolean, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TraySettings = { 
+/**
+ * Show the network or transfer speed as text beside the icon; not supported on Windows
+ */
+menu_bar_title: boolean, 
+/**
+ * Offer a quick-reply window for each recent chat
+ */
+quick_reply: boolean, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TrustedPeer = { peer_id: string, 
+/**
+ * Name when the peer was trusted, for display while it is offline
+ */
+name: string, 
+/**
+ * Unix timestamp (seconds)
+ */
+added_at: number, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DnsConfig } from "./DnsConfig";
+import type { RouteEntry } from "./RouteEntry";
+
+export type TunnelInterface = { name: string, 
+/**
+ * CIDR notation, IPv4 and IPv6
+ */
+addresses: Array<string>, routes: Array<RouteEntry>, dns: DnsConfig, };
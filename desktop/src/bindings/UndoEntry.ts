@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UndoKind } from "./UndoKind";
+
+export type UndoEntry = { id: string, kind: UndoKind, 
+/**
+ * What the toast says, e.g. "Removed alias nas.home"
+ */
+label: string, created_at: number, expires_at: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StagedUpdate } from "./StagedUpdate";
+
+export type UpdateCheck = { current: string, 
+/**
+ * Newer version offered, if any
+ */
+available: string | null, staged: StagedUpdate | null, };
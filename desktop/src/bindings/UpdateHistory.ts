@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InstalledVersion } from "./InstalledVersion";
+
+export type UpdateHistory = { current: string, 
+/**
+ * What `update_rollback` would install
+ */
+rollback_version: string | null, 
+/**
+ * Oldest first
+ */
+installed: Array<InstalledVersion>, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NetworkUsage } from "./NetworkUsage";
+import type { PeerUsage } from "./PeerUsage";
+import type { ReportPeriod } from "./ReportPeriod";
+import type { TransferCounts } from "./TransferCounts";
+
+export type UsageReport = { period: ReportPeriod, 
+/**
+ * Unix timestamps (seconds) bounding the report
+ */
+from: number, to: number, connected_secs: number, 
+/**
+ * Sorted by connected time
+ */
+networks: Array<NetworkUsage>, 
+/**
+ * Peer with the most bytes transferred
+ */
+busiest_peer: PeerUsage | null, transfers: TransferCounts, 
+/**
+ * Set when an HTML export was requested
+ */
+html_path: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type VersionInfo = { version: string, build_date: string, commit: string, go_version: string, os: string, arch: string, 
+/**
+ * Unix timestamp (seconds) of the daemon's clock; `None` from older daemons
+ */
+daemon_time: number | null, 
+/**
+ * Unix timestamp (seconds) of the coordination server's clock, as known to the daemon
+ */
+server_time: number | null, };
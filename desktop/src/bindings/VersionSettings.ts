@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type VersionSettings = { enabled: boolean, 
+/**
+ * Versions kept per file
+ */
+keep_versions: number, 
+/**
+ * Total size of the versions store
+ */
+max_storage_mb: number, };
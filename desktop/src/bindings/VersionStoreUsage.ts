@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VersionSettings } from "./VersionSettings";
+
+export type VersionStoreUsage = { settings: VersionSettings, versions: number, used_bytes: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type VpnAdapter = { interface: string, product: string, addresses: Array<string>, 
+/**
+ * Number of routes through the adapter
+ */
+route_count: number, 
+/**
+ * The adapter carries a default route (full tunnel)
+ */
+full_tunnel: boolean, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebhookEvent } from "./WebhookEvent";
+
+export type Webhook = { 
+/**
+ * Empty when creating; assigned by `save_webhook`
+ */
+id: string, name: string, url: string, events: Array<WebhookEvent>, 
+/**
+ * JSON body with `{{placeholder}}`s; `DEFAULT_TEMPLATE` when empty
+ */
+template: string, 
+/**
+ * Signs each body as `X-GoConnect-Signature: sha256=<hex>` when set
+ */
+secret: string, enabled: boolean, };
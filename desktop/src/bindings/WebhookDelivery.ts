@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebhookEvent } from "./WebhookEvent";
+
+export type WebhookDelivery = { id: string, webhook_id: string, event: WebhookEvent, 
+/**
+ * Unix timestamp (seconds) of the first attempt
+ */
+timestamp: number, attempts: number, 
+/**
+ * HTTP status of the last attempt, if a response arrived
+ */
+status_code: number | null, success: boolean, error: string | null, duration_ms: number, payload: string, };
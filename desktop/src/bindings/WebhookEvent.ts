@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WebhookEvent = "peer_online" | "peer_offline" | "transfer_completed" | "transfer_failed" | "network_connected" | "network_disconnected" | "sla_breach" | "sla_resolved" | "test";
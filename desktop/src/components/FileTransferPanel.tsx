@@ -39,17 +39,28 @@ export default function FileTransferPanel() {
     const handleAccept = async (id: string) => {
         try {
             const transfer = transfers.find(t => t.id === id);
-            const defaultName = transfer ? transfer.file_name : 'download';
+            // Opens in the sender's save folder when one is assigned
+            const defaultPath = transfer
+                ? await tauriApi.defaultSavePath(transfer.peer_id, transfer.file_name).catch(() => transfer.file_name)
+                : 'download';
 
             const path = await save({
-                defaultPath: defaultName,
+                defaultPath,
                 title: 'Save File',
             });
 
             if (!path) return; // User cancelled
 
-            await tauriApi.acceptTransfer(id, path);
-            toast.success("Transfer accepted");
+            let result = await tauriApi.acceptTransfer(id, path);
+            if (result.outcome === 'needs_decision') {
+                const replace = window.confirm(`${result.existing} already exists. Replace it?\n\nCancel keeps both files.`);
+                result = await tauriApi.acceptTransfer(id, path, replace ? 'overwrite' : 'rename');
+            }
+            if (result.outcome === 'skipped') {
+                toast.info(`Skipped: ${result.existing} already exists`);
+            } else if (result.outcome === 'accepted') {
+                toast.success(result.path === path ? "Transfer accepted" : `Transfer accepted, saving as ${result.path}`);
+            }
             loadTransfers();
         } catch (e) {
             handleError(e, "Failed to accept");
@@ -0,0 +1,71 @@
+import { useState, useEffect } from 'react';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import { tauriApi, RecentChat } from '../lib/tauri-api';
+
+// Compact reply form shown in its own window from the tray's "Recent Chats" menu
+export default function QuickReply() {
+    const [chat, setChat] = useState<RecentChat | null>(null);
+    const [reply, setReply] = useState("");
+    const [sending, setSending] = useState(false);
+    const [error, setError] = useState<string | null>(null);
+
+    useEffect(() => {
+        tauriApi.getQuickReplyTarget().then(setChat).catch(e => setError(String(e)));
+        // The tray can point the open window at another conversation
+        const unlisten = tauriApi.onQuickReplyTarget((target) => {
+            setChat(target);
+            setReply("");
+            setError(null);
+        });
+        return () => { unlisten.then(f => f()); };
+    }, []);
+
+    const close = () => getCurrentWindow().close();
+
+    const send = async () => {
+        if (!chat || !reply.trim() || sending) return;
+        setSending(true);
+        try {
+            await tauriApi.sendMessage(chat.network_id, reply.trim());
+            await tauriApi.markChatRead(chat.network_id);
+            await close();
+        } catch (e) {
+            setError(String(e));
+        } finally {
+            setSending(false);
+        }
+    };
+
+    if (!chat) {
+        return <div className="p-4 text-sm text-gray-400 bg-gc-dark-900 h-screen">{error ?? "Loading..."}</div>;
+    }
+
+    return (
+        <div className="flex flex-col gap-3 p-4 bg-gc-dark-900 h-screen text-white">
+            <div className="text-sm">
+                <div className="font-semibold">{chat.network_name}</div>
+                <div className="text-gray-400 truncate">
+                    {chat.last_sender ? `${chat.last_sender}: ` : "You: "}{chat.preview}
+                </div>
+            </div>
+            <textarea
+                className="flex-1 bg-gc-dark-800 border border-gc-dark-600 rounded px-3 py-2 text-sm resize-none outline-none focus:border-gc-primary"
+                placeholder="Type a reply... (Enter to send, Esc to close)"
+                aria-label="Type a reply"
+                value={reply}
+                onChange={e => setReply(e.target.value)}
+                onKeyDown={e => {
+                    if (e.key === "Enter" && !e.shiftKey) {
+                        e.preventDefault();
+                        send();
+                    } else if (e.key === "Escape") {
+                        close();
+                    }
+                }}
+                disabled={sending}
+                autoFocus
+            />
+            {error && <div className="text-xs text-red-400">{error}</div>}
+        </div>
+    );
+}
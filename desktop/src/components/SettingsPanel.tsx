@@ -15,6 +15,9 @@ export default function SettingsPanel({ selectedNetwork = null }: SettingsPanelP
 
     useEffect(() => {
         loadSettings();
+        // Changes made from the CLI or another client show up here too
+        const unlisten = tauriApi.onSettingsChanged(setSettings);
+        return () => { unlisten.then(fn => fn()); };
     }, []);
 
     const loadSettings = async () => {
@@ -40,6 +40,12 @@ describe('FileTransferPanel', () => {
             transferred: 512,
             status: 'pending',
             direction: 'download',
+            error: null,
+            sha256: null,
+            verified: false,
+            max_speed_kbps: null,
+            part_path: null,
+            reputation: null,
         },
         {
             id: 't2',
@@ -49,6 +55,12 @@ describe('FileTransferPanel', () => {
             transferred: 2048,
             status: 'completed',
             direction: 'upload',
+            error: null,
+            sha256: null,
+            verified: false,
+            max_speed_kbps: null,
+            part_path: null,
+            reputation: null,
         }
     ];
 
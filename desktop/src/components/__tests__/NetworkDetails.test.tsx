@@ -3,16 +3,19 @@ import { describe, it, expect, vi } from 'vitest';
 import NetworkDetails from '../NetworkDetails';
 import { NetworkInfo, PeerInfo } from '../../lib/tauri-api';
 
-const mockNetwork: NetworkInfo = { id: 'net-1', name: 'Alpha Corp', invite_code: 'abc-123', owner_id: 'self-1' };
+const mockNetwork: NetworkInfo = { id: 'net-1', name: 'Alpha Corp', invite_code: 'abc-123', guest_until: null, owner_id: 'self-1' };
 const mockSelfPeer: PeerInfo = {
     id: 'self-1',
     name: 'My Device',
     display_name: 'My Device',
     virtual_ip: '10.0.0.1',
+    virtual_ipv4: '10.0.0.1',
+    virtual_ipv6: null,
     connected: true,
     is_relay: false,
     latency_ms: 0,
-    is_self: true
+    is_self: true,
+    role: 'member'
 };
 
 describe('NetworkDetails', () => {
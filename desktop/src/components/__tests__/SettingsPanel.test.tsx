@@ -22,7 +22,13 @@ const mockSettings: Settings = {
     auto_connect: false,
     start_minimized: false,
     notifications_enabled: false,
-    log_level: 'info'
+    log_level: 'info',
+    theme: 'system',
+    language: 'en',
+    max_upload_speed_kbps: 0,
+    max_download_speed_kbps: 0,
+    auto_accept_files: false,
+    download_path: ''
 };
 
 describe('SettingsPanel', () => {
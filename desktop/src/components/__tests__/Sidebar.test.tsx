@@ -4,12 +4,12 @@ import Sidebar from '../Sidebar';
 import { NetworkInfo, PeerInfo } from '../../lib/tauri-api';
 
 const mockNetworks: NetworkInfo[] = [
-    { id: 'net-1', name: 'Alpha Corp', invite_code: 'abc-123', owner_id: 'user-1' },
-    { id: 'net-2', name: 'Beta Team', invite_code: 'xyz-789', owner_id: 'user-2' },
+    { id: 'net-1', name: 'Alpha Corp', invite_code: 'abc-123', guest_until: null, owner_id: 'user-1' },
+    { id: 'net-2', name: 'Beta Team', invite_code: 'xyz-789', guest_until: null, owner_id: 'user-2' },
 ];
 
 const mockPeers: PeerInfo[] = [
-    { id: 'peer-1', name: 'Peer 1', display_name: 'Peer 1', virtual_ip: '10.0.0.1', connected: true, is_relay: false, latency_ms: 10, is_self: false }
+    { id: 'peer-1', name: 'Peer 1', display_name: 'Peer 1', virtual_ip: '10.0.0.1', virtual_ipv4: '10.0.0.1', virtual_ipv6: null, connected: true, is_relay: false, latency_ms: 10, is_self: false, role: 'member' }
 ];
 
 describe('Sidebar', () => {
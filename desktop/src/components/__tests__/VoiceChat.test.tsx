@@ -38,10 +38,13 @@ describe('VoiceChat Component', () => {
         name: 'Test Peer',
         display_name: 'Test Peer',
         virtual_ip: '10.0.0.2',
+        virtual_ipv4: '10.0.0.2',
+        virtual_ipv6: null,
         connected: true,
         is_relay: false,
         latency_ms: 10,
-        is_self: true
+        is_self: true,
+        role: 'member'
     };
 
     const mockConnectedPeers: PeerInfo[] = [
@@ -51,10 +54,13 @@ describe('VoiceChat Component', () => {
             name: 'Other Peer',
             display_name: 'Other Peer',
             virtual_ip: '10.0.0.3',
+            virtual_ipv4: '10.0.0.3',
+            virtual_ipv6: null,
             connected: true,
             is_relay: false,
             latency_ms: 15,
-            is_self: false
+            is_self: false,
+            role: 'member'
         }
     ];
 
@@ -0,0 +1,45 @@
+import { listen } from '@tauri-apps/api/event';
+
+/**
+ * Announcement pushed by the Rust accessibility module
+ */
+interface Announcement {
+    message: string;
+    politeness: 'polite' | 'assertive';
+}
+
+const regions: Partial<Record<Announcement['politeness'], HTMLElement>> = {};
+
+/**
+ * Visually hidden live region that screen readers watch for changes
+ */
+function liveRegion(politeness: Announcement['politeness']): HTMLElement {
+    let region = regions[politeness];
+    if (!region) {
+        region = document.createElement('div');
+        region.setAttribute('aria-live', politeness);
+        region.setAttribute('role', politeness === 'assertive' ? 'alert' : 'status');
+        region.style.cssText = 'position:absolute;width:1px;height:1px;overflow:hidden;clip:rect(0 0 0 0);white-space:nowrap;';
+        document.body.appendChild(region);
+        regions[politeness] = region;
+    }
+    return region;
+}
+
+/**
+ * Relay accessibility announcements from Rust to the live regions
+ */
+export async function initAnnouncer(): Promise<void> {
+    try {
+        await listen<Announcement>('a11y://announce', ({ payload }) => {
+            const region = liveRegion(payload.politeness);
+            // Clear first so repeating the same message is still announced
+            region.textContent = '';
+            window.setTimeout(() => {
+                region.textContent = payload.message;
+            }, 50);
+        });
+    } catch (e) {
+        console.warn('Accessibility announcements not available:', e);
+    }
+}
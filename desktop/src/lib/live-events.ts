@@ -0,0 +1,132 @@
+import { Channel, invoke } from '@tauri-apps/api/core';
+
+/**
+ * Progress of a single transfer (mirrors TransferProgress in live_events.rs)
+ */
+export interface TransferProgress {
+    id: string;
+    peer_id: string;
+    direction: string;
+    status: string;
+    transferred: number;
+    total: number;
+    bytes_per_sec: number;
+}
+
+/**
+ * Aggregate throughput over the last second (mirrors BandwidthSample)
+ */
+export interface BandwidthSample {
+    at_ms: number;
+    up_bps: number;
+    down_bps: number;
+    active_transfers: number;
+}
+
+export type LiveEvent =
+    | { kind: 'progress'; data: TransferProgress }
+    | { kind: 'bandwidth'; data: BandwidthSample };
+
+export type EventEncoding = 'json' | 'msgpack';
+
+// Binary frames are [kind, [fields...]]; field order follows the Rust structs
+const PROGRESS_FIELDS = ['id', 'peer_id', 'direction', 'status', 'transferred', 'total', 'bytes_per_sec'] as const;
+const BANDWIDTH_FIELDS = ['at_ms', 'up_bps', 'down_bps', 'active_transfers'] as const;
+
+const utf8 = new TextDecoder();
+
+/**
+ * Minimal MessagePack reader covering what the backend writes:
+ * integers, floats, strings, nil, booleans and arrays
+ */
+class Reader {
+    private view: DataView;
+    private offset = 0;
+
+    constructor(private bytes: Uint8Array) {
+        this.view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+    }
+
+    read(): unknown {
+        const byte = this.view.getUint8(this.offset++);
+        if (byte <= 0x7f) return byte;
+        if (byte >= 0xe0) return byte - 0x100;
+        if ((byte & 0xf0) === 0x90) return this.array(byte & 0x0f);
+        if ((byte & 0xe0) === 0xa0) return this.str(byte & 0x1f);
+        switch (byte) {
+            case 0xc0: return null;
+            case 0xc2: return false;
+            case 0xc3: return true;
+            case 0xca: return this.take(4, (o) => this.view.getFloat32(o));
+            case 0xcb: return this.take(8, (o) => this.view.getFloat64(o));
+            case 0xcc: return this.take(1, (o) => this.view.getUint8(o));
+            case 0xcd: return this.take(2, (o) => this.view.getUint16(o));
+            case 0xce: return this.take(4, (o) => this.view.getUint32(o));
+            case 0xcf: return this.take(8, (o) => Number(this.view.getBigUint64(o)));
+            case 0xd0: return this.take(1, (o) => this.view.getInt8(o));
+            case 0xd1: return this.take(2, (o) => this.view.getInt16(o));
+            case 0xd2: return this.take(4, (o) => this.view.getInt32(o));
+            case 0xd3: return this.take(8, (o) => Number(this.view.getBigInt64(o)));
+            case 0xd9: return this.str(this.take(1, (o) => this.view.getUint8(o)));
+            case 0xda: return this.str(this.take(2, (o) => this.view.getUint16(o)));
+            case 0xdb: return this.str(this.take(4, (o) => this.view.getUint32(o)));
+            case 0xdc: return this.array(this.take(2, (o) => this.view.getUint16(o)));
+            case 0xdd: return this.array(this.take(4, (o) => this.view.getUint32(o)));
+            default: throw new Error(`unsupported MessagePack type 0x${byte.toString(16)}`);
+        }
+    }
+
+    private take<T>(size: number, get: (offset: number) => T): T {
+        const value = get(this.offset);
+        this.offset += size;
+        return value;
+    }
+
+    private str(length: number): string {
+        const value = utf8.decode(this.bytes.subarray(this.offset, this.offset + length));
+        this.offset += length;
+        return value;
+    }
+
+    private array(length: number): unknown[] {
+        return Array.from({ length }, () => this.read());
+    }
+}
+
+function zip<T>(fields: readonly string[], values: unknown[]): T {
+    return Object.fromEntries(fields.map((field, i) => [field, values[i]])) as T;
+}
+
+/**
+ * Decode a binary live event frame
+ */
+export function decodeFrame(buffer: ArrayBuffer | Uint8Array): LiveEvent {
+    const bytes = buffer instanceof Uint8Array ? buffer : new Uint8Array(buffer);
+    const [kind, values] = new Reader(bytes).read() as [number, unknown[]];
+    switch (kind) {
+        case 0: return { kind: 'progress', data: zip<TransferProgress>(PROGRESS_FIELDS, values) };
+        case 1: return { kind: 'bandwidth', data: zip<BandwidthSample>(BANDWIDTH_FIELDS, values) };
+        default: throw new Error(`unknown live event kind ${kind}`);
+    }
+}
+
+/**
+ * Receive transfer progress and bandwidth samples over a channel.
+ * MessagePack is the default; JSON is there for debugging.
+ * Returns a function that unsubscribes.
+ */
+export async function subscribeLiveEvents(
+    handler: (event: LiveEvent) => void,
+    encoding: EventEncoding = 'msgpack'
+): Promise<() => Promise<void>> {
+    const channel = new Channel<LiveEvent | ArrayBuffer>();
+    channel.onmessage = (message) => {
+        try {
+            handler(message instanceof ArrayBuffer ? decodeFrame(message) : message);
+        } catch (e) {
+            console.warn('Dropped live event:', e);
+        }
+    };
+    const id = await invoke<number>('subscribe_live_events', { channel, encoding });
+    return () => invoke('unsubscribe_live_events', { id });
+}
@@ -1,9 +1,10 @@
+import { invoke } from '@tauri-apps/api/core';
 import { isPermissionGranted, requestPermission, sendNotification } from '@tauri-apps/plugin-notification';
 
 /**
  * NotificationType for categorizing notifications
  */
-export type NotificationType = 'message' | 'voice' | 'transfer' | 'member' | 'system';
+export type NotificationType = 'message' | 'voice' | 'transfer' | 'member' | 'system' | 'security';
 
 /**
  * NotificationOptions for sending notifications
@@ -13,6 +14,8 @@ export interface NotificationOptions {
     title: string;
     body: string;
     icon?: string;
+    /** Network a message preview came from, for its content filter list */
+    networkId?: string;
 }
 
 // Track permission state
@@ -41,6 +44,21 @@ export async function initNotifications(): Promise<boolean> {
  * Send a desktop notification
  */
 export async function sendDesktopNotification(options: NotificationOptions): Promise<void> {
+    // Keep every notification in the inbox history, even ones that are not shown.
+    // Message previews come back with filtered terms masked.
+    let body = options.body;
+    try {
+        const record = await invoke<{ body: string }>('record_notification', {
+            kind: options.type,
+            title: options.title,
+            body: options.body,
+            network_id: options.networkId,
+        });
+        body = record.body;
+    } catch (e) {
+        console.warn('Failed to record notification:', e);
+    }
+
     // Check if notifications are enabled in settings
     const settings = getNotificationSettings();
 
@@ -58,8 +76,9 @@ export async function sendDesktopNotification(options: NotificationOptions): Pro
         if (permissionGranted) {
             await sendNotification({
                 title: options.title,
-                body: options.body,
+                body,
             });
+            await invoke('play_notification_sound', { kind: options.type });
         }
     } catch (e) {
         console.warn('Failed to send notification:', e);
@@ -78,6 +97,7 @@ interface NotificationSettings {
         transfer: boolean;
         member: boolean;
         system: boolean;
+        security: boolean;
     };
 }
 
@@ -90,6 +110,7 @@ const DEFAULT_SETTINGS: NotificationSettings = {
         transfer: true,
         member: true,
         system: true,
+        security: true,
     },
 };
 
@@ -102,7 +123,9 @@ export function getNotificationSettings(): NotificationSettings {
     try {
         const stored = localStorage.getItem(STORAGE_KEY);
         if (stored) {
-            return { ...DEFAULT_SETTINGS, ...JSON.parse(stored) };
+            const parsed = JSON.parse(stored);
+            // Types added since the settings were saved default to on
+            return { ...DEFAULT_SETTINGS, ...parsed, types: { ...DEFAULT_SETTINGS.types, ...parsed.types } };
         }
     } catch (e) {
         console.warn('Failed to load notification settings:', e);
@@ -126,11 +149,12 @@ export function saveNotificationSettings(settings: Partial<NotificationSettings>
 /**
  * Convenience method for message notifications
  */
-export function notifyNewMessage(senderName: string, preview: string, networkName?: string): void {
+export function notifyNewMessage(senderName: string, preview: string, networkName?: string, networkId?: string): void {
     sendDesktopNotification({
         type: 'message',
         title: networkName ? `${networkName} - New Message` : 'New Message',
         body: `${senderName}: ${preview.substring(0, 100)}`,
+        networkId,
     });
 }
 
@@ -1,84 +1,88 @@
 import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import type { AcceptOutcome } from '../bindings/AcceptOutcome';
+import type { ActiveMember } from '../bindings/ActiveMember';
+import type { AutoAcceptSettings } from '../bindings/AutoAcceptSettings';
+import type { AutostartStatus } from '../bindings/AutostartStatus';
+import type { ChatEventPayload } from '../bindings/ChatEventPayload';
+import type { ChatMessage as GeneratedChatMessage } from '../bindings/ChatMessage';
+import type { ChatMute } from '../bindings/ChatMute';
+import type { CollisionPolicy } from '../bindings/CollisionPolicy';
+import type { ContentFilterSettings } from '../bindings/ContentFilterSettings';
+import type { CredentialStatus } from '../bindings/CredentialStatus';
+import type { CredentialsStatus } from '../bindings/CredentialsStatus';
+import type { DaemonStatus } from '../bindings/DaemonStatus';
+import type { FileReputation } from '../bindings/FileReputation';
+import type { FlaggedMessage } from '../bindings/FlaggedMessage';
+import type { JoinRequest } from '../bindings/JoinRequest';
+import type { LeaveCheck } from '../bindings/LeaveCheck';
+import type { LeaveOptions } from '../bindings/LeaveOptions';
+import type { LeaveOutcome } from '../bindings/LeaveOutcome';
+import type { LeaveWarning } from '../bindings/LeaveWarning';
+import type { LifecycleOutcome } from '../bindings/LifecycleOutcome';
+import type { ModerationOverview } from '../bindings/ModerationOverview';
+import type { ModerationRecord } from '../bindings/ModerationRecord';
+import type { NetworkInfo as GeneratedNetworkInfo } from '../bindings/NetworkInfo';
+import type { NotificationRecord } from '../bindings/NotificationRecord';
+import type { PeerEventPayload } from '../bindings/PeerEventPayload';
+import type { PeerInfo } from '../bindings/PeerInfo';
+import type { RecentChat } from '../bindings/RecentChat';
+import type { RemoteDirectory } from '../bindings/RemoteDirectory';
+import type { RemoteFileEntry } from '../bindings/RemoteFileEntry';
+import type { ReputationSettings } from '../bindings/ReputationSettings';
+import type { ReputationVerdict } from '../bindings/ReputationVerdict';
+import type { SaveFolder } from '../bindings/SaveFolder';
+import type { SaveFolderScope } from '../bindings/SaveFolderScope';
+import type { SavePathSettings } from '../bindings/SavePathSettings';
+import type { SecurityEvent } from '../bindings/SecurityEvent';
+import type { SecurityEventKind } from '../bindings/SecurityEventKind';
+import type { Settings as GeneratedSettings } from '../bindings/Settings';
+import type { SpamSettings } from '../bindings/SpamSettings';
+import type { TransferHistoryEntry } from '../bindings/TransferHistoryEntry';
+import type { TransferHistoryPage } from '../bindings/TransferHistoryPage';
+import type { TransferInfo } from '../bindings/TransferInfo';
+import type { TransferStats } from '../bindings/TransferStats';
+import type { TransferVerification } from '../bindings/TransferVerification';
+import type { TranslatedMessage } from '../bindings/TranslatedMessage';
+import type { Translation } from '../bindings/Translation';
+import type { TranslationSettings } from '../bindings/TranslationSettings';
+import type { TrustedPeer } from '../bindings/TrustedPeer';
+import type { VersionInfo } from '../bindings/VersionInfo';
 
 // =============================================================================
-// Type Definitions (Must match Rust structs in commands.rs)
+// Type Definitions
+// Generated from the Rust structs into src/bindings by ts-rs; run `npm run bindings`
+// after changing one. CI fails when the committed bindings are stale.
 // =============================================================================
 
-export interface DaemonStatus {
-    connected: boolean;
-    virtual_ip: string;
-    active_peers: number;
-    network_name: string;
-}
-
-export interface VersionInfo {
-    version: string;
-    build_date: string;
-    commit: string;
-    go_version: string;
-    os: string;
-    arch: string;
-}
-
-export interface NetworkInfo {
-    id: string;
-    name: string;
-    invite_code: string;
-    owner_id?: string;
-}
-
-export interface PeerInfo {
-    id: string;
-    name: string;
-    display_name: string;
-    virtual_ip: string;
-    connected: boolean;
-    is_relay: boolean;
-    latency_ms: number;
-    is_self: boolean;
-}
+export type {
+    AcceptOutcome, ActiveMember, AutoAcceptSettings, AutostartStatus, ChatEventPayload, ChatMute,
+    CollisionPolicy, ContentFilterSettings, CredentialStatus, CredentialsStatus, DaemonStatus,
+    FileReputation, FlaggedMessage, JoinRequest, LeaveCheck, LeaveOptions, LeaveOutcome,
+    LeaveWarning, LifecycleOutcome, ModerationOverview, ModerationRecord, NotificationRecord,
+    PeerEventPayload, PeerInfo, RecentChat, RemoteDirectory, RemoteFileEntry, ReputationSettings,
+    ReputationVerdict, SaveFolder, SaveFolderScope, SavePathSettings, SecurityEvent,
+    SecurityEventKind, SpamSettings, TransferHistoryEntry, TransferHistoryPage, TransferInfo,
+    TransferStats, TransferVerification, TranslatedMessage, Translation, TranslationSettings,
+    TrustedPeer, VersionInfo,
+};
 
-export interface Settings {
-    auto_connect: boolean;
-    start_minimized: boolean;
-    notifications_enabled: boolean;
-    notification_sound?: boolean;
-    do_not_disturb?: boolean;
-    log_level: string;
-}
+// Fields the UI reads that the Rust side does not send yet
+export type NetworkInfo = GeneratedNetworkInfo & { owner_id?: string };
 
-export interface ChatMessage {
-    id: string;
-    peer_id: string;
-    peer_name: string;  // Display name of sender
-    content: string;
-    timestamp: string;
-    is_self: boolean;
+export type ChatMessage = GeneratedChatMessage & {
+    peer_name?: string;   // Display name of sender
     is_edited?: boolean;  // True if message was edited
     is_deleted?: boolean; // True if message was deleted
-}
-
-export interface TransferInfo {
-    id: string;
-    peer_id: string;
-    file_name: string;
-    file_size: number;
-    transferred: number;
-    status: string;
-    direction: string;
-    error?: string;
-}
+};
 
-export interface TransferStats {
-    total_uploads: number;
-    total_downloads: number;
-    active_transfers: number;
-    completed_transfers: number;
-    failed_transfers: number;
-    total_bytes_sent: number;
-    total_bytes_received: number;
-}
+// Toggles shown in the settings panel that the daemon does not store yet
+export type Settings = GeneratedSettings & {
+    notification_sound?: boolean;
+    do_not_disturb?: boolean;
+};
 
+// Not backed by Rust commands; the shapes the UI expects
 export interface VoiceSignal {
     type: 'offer' | 'answer' | 'candidate';
     sender_id: string;
@@ -114,12 +118,19 @@ export const tauriApi = {
     getStatus: () => invoke<DaemonStatus>('daemon_get_status'),
     getVersion: () => invoke<VersionInfo>('daemon_get_version'),
     isRunning: () => invoke<boolean>('daemon_is_running'),
+    connect: () => invoke<DaemonStatus>('daemon_connect'),
+    disconnect: () => invoke<DaemonStatus>('daemon_disconnect'),
+    startDaemon: () => invoke<LifecycleOutcome>('daemon_start'),
+    stopDaemon: () => invoke<LifecycleOutcome>('daemon_stop'),
+    restartDaemon: () => invoke<LifecycleOutcome>('daemon_restart'),
 
     // Networks
     createNetwork: (name: string) => invoke<NetworkInfo>('daemon_create_network', { name }),
     joinNetwork: (invite_code: string) => invoke<NetworkInfo>('daemon_join_network', { invite_code }),
     listNetworks: () => invoke<NetworkInfo[]>('daemon_list_networks'),
-    leaveNetwork: (network_id: string) => invoke<void>('daemon_leave_network', { network_id }),
+    checkLeaveNetwork: (network_id: string) => invoke<LeaveCheck>('check_leave_network', { network_id }),
+    leaveNetwork: (network_id: string, options?: Partial<LeaveOptions>) =>
+        invoke<LeaveOutcome>('daemon_leave_network', { network_id, options }),
     generateInvite: (network_id: string) => invoke<string>('daemon_generate_invite', { network_id }),
     updateNetwork: (network_id: string, name: string) => invoke<NetworkInfo>('daemon_update_network', { network_id, name }),
     deleteNetwork: (network_id: string) => invoke<void>('daemon_delete_network', { network_id }),
@@ -142,6 +153,23 @@ export const tauriApi = {
     getSettings: () => invoke<Settings>('daemon_get_settings'),
     updateSettings: (settings: Settings) => invoke<Settings>('daemon_update_settings', { settings }),
     resetSettings: () => invoke<Settings>('daemon_reset_settings'),
+    onSettingsChanged: (handler: (settings: Settings) => void): Promise<UnlistenFn> =>
+        listen<Settings>('settings://changed', e => handler(e.payload)),
+
+    // Starting at login; the entry follows start_minimized
+    getAutostart: () => invoke<AutostartStatus>('get_autostart'),
+    setAutostart: (enabled: boolean) => invoke<AutostartStatus>('set_autostart', { enabled }),
+
+    getCredentialsStatus: () => invoke<CredentialsStatus>('get_credentials_status'),
+    onCredentialsStatus: (handler: (status: CredentialsStatus) => void): Promise<UnlistenFn> =>
+        listen<CredentialsStatus>('credentials://status', e => handler(e.payload)),
+
+    // Security feed
+    listSecurityEvents: (kind?: SecurityEventKind, limit?: number) =>
+        invoke<SecurityEvent[]>('list_security_events', { kind, limit }),
+    clearSecurityEvents: () => invoke<void>('clear_security_events'),
+    onSecurityEvent: (handler: (event: SecurityEvent) => void): Promise<UnlistenFn> =>
+        listen<SecurityEvent>('security://event', e => handler(e.payload)),
 
     // Chat
     getMessages: (network_id: string, limit?: number, before?: string) => invoke<ChatMessage[]>('daemon_get_messages', { network_id, limit, before }),
@@ -180,10 +208,120 @@ export const tauriApi = {
     },
     cancelTransfer: (transfer_id: string) => invoke<void>('daemon_cancel_transfer', { transfer_id }),
     rejectTransfer: (transfer_id: string) => invoke<void>('daemon_reject_transfer', { transfer_id }),
+    setTransferRateLimits: (upload_kbps: number, download_kbps: number) =>
+        invoke<Settings>('daemon_set_transfer_rate_limits', { upload_kbps, download_kbps }),
+    setTransferRateLimit: (transfer_id: string, max_speed_kbps?: number) =>
+        invoke<void>('daemon_set_transfer_rate_limit', { transfer_id, max_speed_kbps }),
+    verifyTransfer: (transfer_id: string) =>
+        invoke<TransferVerification>('daemon_verify_transfer', { transfer_id }),
+
+    // Transfer history
+    listTransferHistory: (options: { direction?: string; status?: string; page?: number; page_size?: number } = {}) =>
+        invoke<TransferHistoryPage>('transfer_history_list', options),
+    searchTransferHistory: (query: string, page?: number, page_size?: number) =>
+        invoke<TransferHistoryPage>('transfer_history_search', { query, page, page_size }),
+    clearTransferHistory: (before?: number) => invoke<number>('transfer_history_clear', { before }),
     sendFile: (peer_id: string, file_path: string) => invoke<string>('daemon_send_file', { peer_id, file_path }),
-    acceptTransfer: (transfer_id: string, save_path: string) => invoke<void>('daemon_accept_transfer', { transfer_id, save_path }),
+    acceptTransfer: (transfer_id: string, save_path: string, collision?: CollisionPolicy) =>
+        invoke<AcceptOutcome>('daemon_accept_transfer', { transfer_id, save_path, collision }),
+    getSavePathSettings: () => invoke<SavePathSettings>('get_save_path_settings'),
+    setSavePathSettings: (settings: SavePathSettings) => invoke<SavePathSettings>('set_save_path_settings', { settings }),
+    listSaveFolders: () => invoke<SaveFolder[]>('list_save_folders'),
+    setSaveFolder: (scope: SaveFolderScope, id: string, folder: string) =>
+        invoke<SaveFolder[]>('set_save_folder', { scope, id, folder }),
+    removeSaveFolder: (scope: SaveFolderScope, id: string) =>
+        invoke<SaveFolder[]>('remove_save_folder', { scope, id }),
+    defaultSavePath: (peer_id: string, file_name: string) =>
+        invoke<string>('default_save_path', { peer_id, file_name }),
+    revealTransfer: (transfer_id: string) => invoke<void>('transfer_reveal_in_folder', { transfer_id }),
+    openTransferFile: (transfer_id: string) => invoke<void>('transfer_open_file', { transfer_id }),
+
+    // Reputation lookups for received executables
+    getReputationSettings: () => invoke<ReputationSettings>('get_reputation_settings'),
+    setReputationSettings: (settings: ReputationSettings, api_key?: string) =>
+        invoke<ReputationSettings>('set_reputation_settings', { settings, api_key }),
+    checkTransferReputation: (transfer_id: string) =>
+        invoke<FileReputation>('check_transfer_reputation', { transfer_id }),
+
+    // Files on the daemon's host, for sending through a remote daemon
+    listRemoteDirectory: (path?: string, include_hidden?: boolean) =>
+        invoke<RemoteDirectory>('list_remote_directory', { path, include_hidden }),
+    statRemotePath: (path: string) => invoke<RemoteFileEntry>('stat_remote_path', { path }),
+
+    // Auto-accept from trusted peers
+    getAutoAcceptSettings: () => invoke<AutoAcceptSettings>('get_auto_accept_settings'),
+    setAutoAcceptSettings: (settings: AutoAcceptSettings) =>
+        invoke<AutoAcceptSettings>('set_auto_accept_settings', { settings }),
+    trustPeer: (peer_id: string) => invoke<AutoAcceptSettings>('trust_peer', { peer_id }),
+    untrustPeer: (peer_id: string) => invoke<AutoAcceptSettings>('untrust_peer', { peer_id }),
+
+    // Notifications
+    listNotifications: (unread_only?: boolean) => invoke<NotificationRecord[]>('list_notifications', { unread_only }),
+    markNotificationRead: (id?: number) => invoke<void>('mark_notification_read', { id }),
+    clearNotifications: () => invoke<void>('clear_notifications'),
 
     // Voice Chat
     getVoiceSignals: (network_id: string) => invoke<VoiceSignal[]>('daemon_get_voice_signals', { network_id }),
     sendVoiceSignal: (signal: VoiceSignal) => invoke<void>('daemon_send_voice_signal', { signal }),
+
+    // Translation
+    getTranslationSettings: () => invoke<TranslationSettings>('get_translation_settings'),
+    setTranslationSettings: (settings: TranslationSettings, api_key?: string) =>
+        invoke<TranslationSettings>('set_translation_settings', { settings, api_key }),
+    setAutoTranslate: (conversation_id: string, enabled: boolean) =>
+        invoke<void>('set_auto_translate', { conversation_id, enabled }),
+    translateMessage: (text: string, target_language?: string) =>
+        invoke<Translation>('translate_message', { text, target_language }),
+    clearTranslationCache: () => invoke<void>('clear_translation_cache'),
+
+    // Content filter
+    getContentFilter: () => invoke<ContentFilterSettings>('get_content_filter'),
+    setContentFilterOptions: (enabled: boolean, filter_cached_messages: boolean) =>
+        invoke<ContentFilterSettings>('set_content_filter_options', { enabled, filter_cached_messages }),
+    setContentFilterTerms: (terms: string[], network_id?: string) =>
+        invoke<ContentFilterSettings>('set_content_filter_terms', { network_id, terms }),
+    previewContentFilter: (text: string, network_id?: string) =>
+        invoke<string>('preview_content_filter', { network_id, text }),
+
+    // Flood protection
+    getSpamSettings: () => invoke<SpamSettings>('get_spam_settings'),
+    setSpamSettings: (settings: SpamSettings) => invoke<SpamSettings>('set_spam_settings', { settings }),
+    listChatMutes: () => invoke<ChatMute[]>('list_chat_mutes'),
+    mutePeerChat: (network_id: string, peer_id: string, peer_name: string, minutes: number) =>
+        invoke<ChatMute>('mute_peer_chat', { network_id, peer_id, peer_name, minutes }),
+    unmutePeerChat: (network_id: string, peer_id: string) => invoke<void>('unmute_peer_chat', { network_id, peer_id }),
+    reportPeer: (network_id: string, peer_id: string, reason: string, message_ids?: string[]) =>
+        invoke<void>('report_peer', { network_id, peer_id, reason, message_ids }),
+
+    // Moderation
+    getModerationOverview: (network_id: string) =>
+        invoke<ModerationOverview>('get_moderation_overview', { network_id }),
+
+    // Daemon push events
+    onPeerJoined: (handler: (event: PeerEventPayload) => void): Promise<UnlistenFn> =>
+        listen<PeerEventPayload>('peer://joined', e => handler(e.payload)),
+    onPeerLeft: (handler: (event: PeerEventPayload) => void): Promise<UnlistenFn> =>
+        listen<PeerEventPayload>('peer://left', e => handler(e.payload)),
+    onIncomingTransfer: (handler: (transfer: TransferInfo) => void): Promise<UnlistenFn> =>
+        listen<TransferInfo>('transfer://incoming', e => handler(e.payload)),
+    onTransferAutoAccepted: (handler: (transfer: TransferInfo) => void): Promise<UnlistenFn> =>
+        listen<TransferInfo>('transfer://auto-accepted', e => handler(e.payload)),
+    onChatMessage: (handler: (event: ChatEventPayload) => void): Promise<UnlistenFn> =>
+        listen<ChatEventPayload>('chat://message', e => handler(e.payload)),
+    onMessageTranslated: (handler: (event: TranslatedMessage) => void): Promise<UnlistenFn> =>
+        listen<TranslatedMessage>('chat://translated', e => handler(e.payload)),
+    onChatMuted: (handler: (mute: ChatMute) => void): Promise<UnlistenFn> =>
+        listen<ChatMute>('chat://muted', e => handler(e.payload)),
+
+    // Recent chats
+    listRecentChats: () => invoke<RecentChat[]>('list_recent_chats'),
+    markChatRead: (network_id: string) => invoke<void>('mark_chat_read', { network_id }),
+    openQuickReply: (network_id: string) => invoke<void>('open_quick_reply_window', { network_id }),
+    getQuickReplyTarget: () => invoke<RecentChat | null>('get_quick_reply_target'),
+    onRecentChats: (handler: (chats: RecentChat[]) => void): Promise<UnlistenFn> =>
+        listen<RecentChat[]>('chat://recent', e => handler(e.payload)),
+    onOpenChat: (handler: (networkId: string) => void): Promise<UnlistenFn> =>
+        listen<string>('chat://open', e => handler(e.payload)),
+    onQuickReplyTarget: (handler: (chat: RecentChat) => void): Promise<UnlistenFn> =>
+        listen<RecentChat>('chat://quick-reply', e => handler(e.payload)),
 };
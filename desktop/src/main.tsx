@@ -1,10 +1,17 @@
 import React from "react";
 import ReactDOM from "react-dom/client";
 import App from "./App";
+import QuickReply from "./components/QuickReply";
+import { initAnnouncer } from "./lib/announcer";
 import "./index.css";
 
+initAnnouncer();
+
+// The tray opens a small reply window on the same bundle
+const view = new URLSearchParams(window.location.search).get("view");
+
 ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
   <React.StrictMode>
-    <App />
+    {view === "quick-reply" ? <QuickReply /> : <App />}
   </React.StrictMode>,
 );